@@ -0,0 +1,179 @@
+//! SMTP 이메일 sink.
+
+use std::time::Duration;
+
+use arb_config::AlertSinkConfig;
+use async_trait::async_trait;
+use lettre::message::Mailbox;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use tracing::debug;
+
+use crate::error::AlertError;
+use crate::sink::{AlertNotification, AlertSink};
+
+/// SMTP 기본 포트 (STARTTLS submission).
+const DEFAULT_SMTP_PORT: u16 = 587;
+
+/// SMTP 연결 타임아웃 (초).
+const SMTP_TIMEOUT_SECS: u64 = 15;
+
+/// SMTP 이메일 sink (STARTTLS).
+#[derive(Clone)]
+pub struct EmailSink {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+    to: Vec<Mailbox>,
+}
+
+impl EmailSink {
+    /// `kind = "email"` sink 설정으로 생성합니다.
+    ///
+    /// # 에러
+    ///
+    /// 호스트/발신/수신 주소가 없거나 주소 형식이 잘못되면 에러를 반환합니다.
+    pub fn from_config(config: &AlertSinkConfig) -> Result<Self, AlertError> {
+        if config.smtp_host.is_empty() || config.from.is_empty() || config.to.is_empty() {
+            return Err(AlertError::ConfigError(
+                "email sink requires smtp_host, from and to".to_string(),
+            ));
+        }
+
+        let parse = |addr: &str| {
+            addr.parse::<Mailbox>().map_err(|e| {
+                AlertError::ConfigError(format!("invalid email address '{addr}': {e}"))
+            })
+        };
+        let from = parse(&config.from)?;
+        let to = config
+            .to
+            .iter()
+            .map(|addr| parse(addr))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let port = if config.smtp_port == 0 {
+            DEFAULT_SMTP_PORT
+        } else {
+            config.smtp_port
+        };
+        let mut builder = AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.smtp_host)
+            .map_err(|e| AlertError::ConfigError(format!("invalid smtp_host: {e}")))?
+            .port(port)
+            .timeout(Some(Duration::from_secs(SMTP_TIMEOUT_SECS)));
+        if !config.username.is_empty() {
+            builder = builder.credentials(Credentials::new(
+                config.username.clone(),
+                config.password.expose_secret().to_string(),
+            ));
+        }
+
+        Ok(Self {
+            transport: builder.build(),
+            from,
+            to,
+        })
+    }
+
+    /// 알림을 이메일 메시지로 변환합니다.
+    fn build_message(&self, notification: &AlertNotification) -> Result<Message, AlertError> {
+        let subject = match &notification.source {
+            Some(source) => format!(
+                "[arb/{source}] {} {}",
+                notification.level.as_str().to_uppercase(),
+                notification.event_type
+            ),
+            None => format!(
+                "[arb] {} {}",
+                notification.level.as_str().to_uppercase(),
+                notification.event_type
+            ),
+        };
+        let mut body = format!(
+            "{}\n\nsession_id: {}\nat: {}\n",
+            notification.text(),
+            notification.session_id,
+            notification.at.to_rfc3339()
+        );
+        if let Some(payload) = &notification.payload {
+            body.push_str(&format!("payload: {payload}\n"));
+        }
+
+        let mut builder = Message::builder().from(self.from.clone()).subject(subject);
+        for to in &self.to {
+            builder = builder.to(to.clone());
+        }
+        builder
+            .body(body)
+            .map_err(|e| AlertError::Email(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl AlertSink for EmailSink {
+    fn kind(&self) -> &'static str {
+        "email"
+    }
+
+    async fn send(&self, notification: &AlertNotification) -> Result<(), AlertError> {
+        debug!(event_type = %notification.event_type, recipients = self.to.len(), "이메일 알림 전송");
+        let message = self.build_message(notification)?;
+        self.transport
+            .send(message)
+            .await
+            .map_err(|e| AlertError::Email(e.to_string()))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sink::AlertLevel;
+
+    fn config() -> AlertSinkConfig {
+        AlertSinkConfig {
+            kind: "email".to_string(),
+            smtp_host: "smtp.example.com".to_string(),
+            from: "arb@example.com".to_string(),
+            to: vec![
+                "ops@example.com".to_string(),
+                "desk@example.com".to_string(),
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_email_sink_requires_addresses() {
+        assert!(EmailSink::from_config(&config()).is_ok());
+        assert!(
+            EmailSink::from_config(&AlertSinkConfig {
+                to: Vec::new(),
+                ..config()
+            })
+            .is_err()
+        );
+        assert!(
+            EmailSink::from_config(&AlertSinkConfig {
+                from: "not an address".to_string(),
+                ..config()
+            })
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_email_message_headers() {
+        let sink = EmailSink::from_config(&config()).unwrap();
+        let mut notification =
+            AlertNotification::new(3, AlertLevel::Critical, "leg_failure", "LEG FAILURE: BTC");
+        notification.source = Some("main".to_string());
+
+        let raw =
+            String::from_utf8(sink.build_message(&notification).unwrap().formatted()).unwrap();
+        assert!(raw.contains("Subject: [arb/main] CRITICAL leg_failure"));
+        assert!(raw.contains("ops@example.com"));
+        assert!(raw.contains("desk@example.com"));
+        assert!(raw.contains("session_id: 3"));
+    }
+}
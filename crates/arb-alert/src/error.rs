@@ -0,0 +1,36 @@
+//! 알림 sink 관련 에러 타입.
+
+use thiserror::Error;
+
+/// 알림 sink 에러.
+#[derive(Debug, Error)]
+pub enum AlertError {
+    /// 설정 에러 (필수 값 누락, 알 수 없는 sink 종류/이름 등).
+    #[error("Alert configuration error: {0}")]
+    ConfigError(String),
+
+    /// HTTP 요청 에러.
+    #[error("HTTP request error: {0}")]
+    HttpError(#[from] reqwest::Error),
+
+    /// Webhook이 2xx 외 상태 코드를 반환.
+    #[error("Webhook returned {status}: {body}")]
+    WebhookStatus {
+        /// HTTP 상태 코드.
+        status: u16,
+        /// 응답 본문.
+        body: String,
+    },
+
+    /// Telegram 전송 에러.
+    #[error("Telegram error: {0}")]
+    Telegram(#[from] arb_telegram::TelegramError),
+
+    /// 이메일 생성/전송 에러.
+    #[error("Email error: {0}")]
+    Email(String),
+
+    /// 사용자 정의 sink 에러.
+    #[error("{0}")]
+    Other(String),
+}
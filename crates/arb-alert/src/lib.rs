@@ -0,0 +1,42 @@
+//! # Alert Sink Module
+//!
+//! 라이브 트레이딩 알림을 여러 채널로 보내는 sink와 라우팅 규칙입니다.
+//!
+//! - [`AlertSink`]: 알림 전송 대상 trait
+//! - [`TelegramSink`], [`SlackSink`], [`DiscordSink`], [`WebhookSink`], [`EmailSink`]: 기본 구현체
+//! - [`AlertRouter`]: 이벤트 타입/레벨별로 sink를 고르는 라우터
+//!
+//! ## 사용 예시
+//!
+//! ```rust,no_run
+//! use arb_alert::{AlertLevel, AlertNotification, AlertRoute, AlertRouter, SlackSink};
+//!
+//! # async fn run() -> Result<(), arb_alert::AlertError> {
+//! let router = AlertRouter::new()
+//!     .sink("oncall", SlackSink::new("https://hooks.slack.com/services/T0/B0/ONCALL")?)
+//!     .sink("fills", SlackSink::new("https://hooks.slack.com/services/T0/B0/FILLS")?)
+//!     .route(AlertRoute::new(AlertLevel::Critical, &["oncall"]))
+//!     .route(AlertRoute::new(AlertLevel::Info, &["fills"]).events(&["entry_executed"]));
+//!
+//! let notification =
+//!     AlertNotification::new(1, AlertLevel::Critical, "leg_failure", "LEG FAILURE: BTC");
+//! for delivery in router.dispatch(&notification).await {
+//!     println!("{}: {:?}", delivery.sink, delivery.result);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+mod email;
+mod error;
+mod router;
+mod sink;
+mod telegram;
+mod webhook;
+
+pub use email::EmailSink;
+pub use error::AlertError;
+pub use router::{AlertRoute, AlertRouter, Delivery, TELEGRAM_SINK};
+pub use sink::{AlertLevel, AlertNotification, AlertSink, FnSink, SendTextFn};
+pub use telegram::TelegramSink;
+pub use webhook::{DiscordSink, SlackSink, WebhookSink};
@@ -0,0 +1,383 @@
+//! 이벤트 타입/레벨 기반 알림 라우팅.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use arb_config::{AlertsConfig, TelegramConfig};
+use futures_util::future::join_all;
+
+use crate::email::EmailSink;
+use crate::error::AlertError;
+use crate::sink::{AlertLevel, AlertNotification, AlertSink};
+use crate::telegram::TelegramSink;
+use crate::webhook::{DiscordSink, SlackSink, WebhookSink};
+
+/// `[telegram]` 섹션으로 자동 등록되는 sink 이름.
+pub const TELEGRAM_SINK: &str = "telegram";
+
+/// 라우팅 규칙 하나.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlertRoute {
+    /// 대상 이벤트 타입. 비어 있으면 전체.
+    pub events: Vec<String>,
+    /// 최소 레벨.
+    pub min_level: AlertLevel,
+    /// 전송할 sink 이름 목록.
+    pub sinks: Vec<String>,
+}
+
+impl AlertRoute {
+    /// 모든 이벤트를 대상으로 하는 규칙을 생성합니다.
+    pub fn new(min_level: AlertLevel, sinks: &[&str]) -> Self {
+        Self {
+            events: Vec::new(),
+            min_level,
+            sinks: sinks.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    /// 대상 이벤트 타입을 지정합니다.
+    pub fn events(mut self, events: &[&str]) -> Self {
+        self.events = events.iter().map(|s| s.to_string()).collect();
+        self
+    }
+
+    /// 알림이 이 규칙에 해당하는지 확인합니다.
+    pub fn matches(&self, notification: &AlertNotification) -> bool {
+        notification.level >= self.min_level
+            && (self.events.is_empty()
+                || self
+                    .events
+                    .iter()
+                    .any(|e| e == "*" || *e == notification.event_type))
+    }
+}
+
+/// sink 한 곳의 전송 결과.
+#[derive(Debug)]
+pub struct Delivery {
+    /// sink 이름.
+    pub sink: String,
+    /// 전송 결과.
+    pub result: Result<(), AlertError>,
+}
+
+/// 알림 라우터.
+///
+/// 이름 있는 sink와 라우팅 규칙을 보관합니다. 규칙이 없으면 모든 알림을
+/// 모든 sink로 보냅니다. clone 비용이 작으므로 인스턴스마다
+/// [`with_source`](Self::with_source)로 복제해 사용합니다.
+#[derive(Clone, Default)]
+pub struct AlertRouter {
+    sinks: BTreeMap<String, Arc<dyn AlertSink>>,
+    routes: Vec<AlertRoute>,
+    source: Option<String>,
+}
+
+impl AlertRouter {
+    /// 빈 라우터를 생성합니다 (sink 없음 = 외부 전송 없음).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 이름으로 sink를 등록합니다. 같은 이름이 있으면 교체합니다.
+    pub fn sink(mut self, name: impl Into<String>, sink: impl AlertSink + 'static) -> Self {
+        self.sinks.insert(name.into(), Arc::new(sink));
+        self
+    }
+
+    /// 라우팅 규칙을 추가합니다.
+    pub fn route(mut self, route: AlertRoute) -> Self {
+        self.routes.push(route);
+        self
+    }
+
+    /// 알림에 인스턴스 이름을 붙이는 복제본을 반환합니다.
+    pub fn with_source(&self, source: impl Into<String>) -> Self {
+        Self {
+            source: Some(source.into()),
+            ..self.clone()
+        }
+    }
+
+    /// 주어진 종류(e.g., "telegram")의 sink를 뺀 복제본을 반환합니다.
+    ///
+    /// 빠진 sink를 가리키는 라우팅 규칙은 나머지 sink로만 전송합니다.
+    pub fn without_kind(&self, kind: &str) -> Self {
+        let mut router = self.clone();
+        router.sinks.retain(|_, sink| sink.kind() != kind);
+        router
+    }
+
+    /// 설정에서 라우터를 생성합니다.
+    ///
+    /// `[telegram]`이 설정되어 있고 같은 이름의 sink가 없으면 [`TELEGRAM_SINK`]로 등록합니다.
+    ///
+    /// # 에러
+    ///
+    /// sink 설정이 잘못되었거나 라우팅 규칙이 없는 sink/레벨을 참조하면 에러를 반환합니다.
+    pub fn from_config(
+        alerts: &AlertsConfig,
+        telegram: &TelegramConfig,
+    ) -> Result<Self, AlertError> {
+        let mut router = Self::new();
+
+        if telegram.is_configured() && !alerts.sinks.contains_key(TELEGRAM_SINK) {
+            router = router.sink(TELEGRAM_SINK, TelegramSink::new(telegram)?);
+        }
+
+        for (name, sink) in &alerts.sinks {
+            let url = sink.url.expose_secret();
+            router = match sink.kind.as_str() {
+                "telegram" => router.sink(
+                    name.as_str(),
+                    TelegramSink::new(&TelegramConfig {
+                        bot_token: sink.bot_token.clone(),
+                        chat_id: sink.chat_id.clone(),
+                    })?,
+                ),
+                "slack" => router.sink(name.as_str(), SlackSink::new(url)?),
+                "discord" => router.sink(name.as_str(), DiscordSink::new(url)?),
+                "webhook" => router.sink(name.as_str(), WebhookSink::new(url)?),
+                "email" => router.sink(name.as_str(), EmailSink::from_config(sink)?),
+                other => {
+                    return Err(AlertError::ConfigError(format!(
+                        "alerts.sinks.{name}: unknown kind '{other}' \
+                         (expected telegram, slack, discord, webhook, email)"
+                    )));
+                }
+            };
+        }
+
+        for (name, route) in &alerts.routes {
+            let min_level = if route.min_level.is_empty() {
+                AlertLevel::Info
+            } else {
+                route.min_level.parse()?
+            };
+            if let Some(unknown) = route.sinks.iter().find(|s| !router.sinks.contains_key(*s)) {
+                return Err(AlertError::ConfigError(format!(
+                    "alerts.routes.{name}: unknown sink '{unknown}'"
+                )));
+            }
+            router = router.route(AlertRoute {
+                events: route.events.clone(),
+                min_level,
+                sinks: route.sinks.clone(),
+            });
+        }
+
+        Ok(router)
+    }
+
+    /// 등록된 sink 이름 목록.
+    pub fn sink_names(&self) -> Vec<&str> {
+        self.sinks.keys().map(String::as_str).collect()
+    }
+
+    /// 등록된 sink가 없으면 true.
+    pub fn is_empty(&self) -> bool {
+        self.sinks.is_empty()
+    }
+
+    /// 알림을 받을 sink 이름 목록 (중복 제거, 이름순).
+    pub fn targets(&self, notification: &AlertNotification) -> Vec<&str> {
+        if self.routes.is_empty() {
+            return self.sink_names();
+        }
+        let mut targets: Vec<&str> = self
+            .routes
+            .iter()
+            .filter(|route| route.matches(notification))
+            .flat_map(|route| route.sinks.iter().map(String::as_str))
+            .filter(|name| self.sinks.contains_key(*name))
+            .collect();
+        targets.sort_unstable();
+        targets.dedup();
+        targets
+    }
+
+    /// 라우팅 규칙에 맞는 sink로 알림을 동시에 전송합니다.
+    ///
+    /// 대상 sink가 없으면 빈 목록을 반환합니다.
+    pub async fn dispatch(&self, notification: &AlertNotification) -> Vec<Delivery> {
+        let mut notification = notification.clone();
+        if notification.source.is_none() {
+            notification.source = self.source.clone();
+        }
+        let notification = &notification;
+
+        let targets = self.targets(notification);
+        join_all(targets.into_iter().map(|name| async move {
+            let result = self.sinks[name].send(notification).await;
+            Delivery {
+                sink: name.to_string(),
+                result,
+            }
+        }))
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sink::FnSink;
+    use std::sync::Mutex;
+
+    fn recording_sink(log: &Arc<Mutex<Vec<String>>>) -> FnSink {
+        let log = Arc::clone(log);
+        FnSink::new(move |text: String| {
+            log.lock().unwrap().push(text);
+            Box::pin(async { Ok(()) })
+        })
+    }
+
+    fn notification(level: AlertLevel, event_type: &str) -> AlertNotification {
+        AlertNotification::new(1, level, event_type, "msg")
+    }
+
+    fn oncall_router() -> AlertRouter {
+        let noop = || FnSink::new(|_| Box::pin(async { Ok(()) }));
+        AlertRouter::new()
+            .sink("oncall", noop())
+            .sink("quiet", noop())
+            .route(
+                AlertRoute::new(AlertLevel::Critical, &["oncall"])
+                    .events(&["leg_failure", "emergency_close_failure"]),
+            )
+            .route(AlertRoute::new(AlertLevel::Info, &["quiet"]).events(&["entry_executed"]))
+            .route(AlertRoute::new(AlertLevel::Warn, &["quiet"]))
+    }
+
+    #[test]
+    fn test_targets_by_event_and_level() {
+        let router = oncall_router();
+        assert_eq!(
+            router.targets(&notification(AlertLevel::Critical, "leg_failure")),
+            vec!["oncall", "quiet"]
+        );
+        assert_eq!(
+            router.targets(&notification(AlertLevel::Info, "entry_executed")),
+            vec!["quiet"]
+        );
+        // info daily_summary는 어떤 규칙에도 해당하지 않음
+        assert!(
+            router
+                .targets(&notification(AlertLevel::Info, "daily_summary"))
+                .is_empty()
+        );
+        // 이벤트가 맞아도 레벨이 낮으면 제외
+        assert_eq!(
+            router.targets(&notification(AlertLevel::Warn, "leg_failure")),
+            vec!["quiet"]
+        );
+    }
+
+    #[test]
+    fn test_targets_without_routes_fan_out() {
+        let noop = || FnSink::new(|_| Box::pin(async { Ok(()) }));
+        let router = AlertRouter::new().sink("a", noop()).sink("b", noop());
+        assert_eq!(
+            router.targets(&notification(AlertLevel::Info, "exit_executed")),
+            vec!["a", "b"]
+        );
+        assert!(
+            AlertRouter::new()
+                .targets(&notification(AlertLevel::Critical, "error"))
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_tags_source_and_reports_failures() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let router = AlertRouter::new()
+            .sink("ok", recording_sink(&log))
+            .sink(
+                "down",
+                FnSink::new(|_| Box::pin(async { Err("offline".to_string()) })),
+            )
+            .with_source("btc");
+
+        let deliveries = router
+            .dispatch(&notification(AlertLevel::Warn, "error"))
+            .await;
+        assert_eq!(deliveries.len(), 2);
+        assert!(
+            deliveries
+                .iter()
+                .any(|d| d.sink == "ok" && d.result.is_ok())
+        );
+        assert!(
+            deliveries
+                .iter()
+                .any(|d| d.sink == "down" && d.result.is_err())
+        );
+        assert_eq!(*log.lock().unwrap(), vec!["[btc] msg".to_string()]);
+    }
+
+    #[test]
+    fn test_without_kind() {
+        let router = oncall_router()
+            .sink(
+                "tg",
+                TelegramSink::from_client(
+                    arb_telegram::TelegramClient::with_credentials("123:abc", "42").unwrap(),
+                ),
+            )
+            .without_kind("telegram");
+        assert_eq!(router.sink_names(), vec!["oncall", "quiet"]);
+        assert!(oncall_router().without_kind("fn").is_empty());
+    }
+
+    #[test]
+    fn test_from_config_validates_routes() {
+        let telegram = TelegramConfig::default();
+        let mut alerts = AlertsConfig::default();
+        alerts.sinks.insert(
+            "oncall".to_string(),
+            arb_config::AlertSinkConfig {
+                kind: "slack".to_string(),
+                url: "https://hooks.slack.com/services/T0/B0/XYZ".into(),
+                ..Default::default()
+            },
+        );
+        alerts.routes.insert(
+            "page".to_string(),
+            arb_config::AlertRouteConfig {
+                events: vec!["leg_failure".to_string()],
+                min_level: "critical".to_string(),
+                sinks: vec!["oncall".to_string()],
+            },
+        );
+        let router = AlertRouter::from_config(&alerts, &telegram).unwrap();
+        assert_eq!(router.sink_names(), vec!["oncall"]);
+        assert_eq!(
+            router.targets(&notification(AlertLevel::Critical, "leg_failure")),
+            vec!["oncall"]
+        );
+
+        let mut bad_sink = alerts.clone();
+        bad_sink.routes.get_mut("page").unwrap().sinks = vec!["pager".to_string()];
+        assert!(AlertRouter::from_config(&bad_sink, &telegram).is_err());
+
+        let mut bad_level = alerts.clone();
+        bad_level.routes.get_mut("page").unwrap().min_level = "fatal".to_string();
+        assert!(AlertRouter::from_config(&bad_level, &telegram).is_err());
+
+        let mut bad_kind = alerts;
+        bad_kind.sinks.get_mut("oncall").unwrap().kind = "pagerduty".to_string();
+        assert!(AlertRouter::from_config(&bad_kind, &telegram).is_err());
+    }
+
+    #[test]
+    fn test_from_config_registers_telegram_section() {
+        let telegram = TelegramConfig {
+            bot_token: "123:abc".to_string(),
+            chat_id: "42".to_string(),
+        };
+        let router = AlertRouter::from_config(&AlertsConfig::default(), &telegram).unwrap();
+        assert_eq!(router.sink_names(), vec![TELEGRAM_SINK]);
+    }
+}
@@ -0,0 +1,157 @@
+//! 알림 sink trait 및 공통 타입.
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::error::AlertError;
+
+/// 알림 심각도 레벨. 라우팅 규칙의 `min_level` 비교에 사용합니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AlertLevel {
+    /// 체결, 일일 요약 등 참고용 알림.
+    Info,
+    /// 연결 끊김, 잔고 부족 등 확인이 필요한 알림.
+    Warn,
+    /// Kill switch, 레그 실패 등 즉시 대응이 필요한 알림.
+    Critical,
+}
+
+impl AlertLevel {
+    /// 레벨 문자열 ("info", "warn", "critical").
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Warn => "warn",
+            Self::Critical => "critical",
+        }
+    }
+}
+
+impl fmt::Display for AlertLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for AlertLevel {
+    type Err = AlertError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "info" => Ok(Self::Info),
+            "warn" | "warning" => Ok(Self::Warn),
+            "critical" => Ok(Self::Critical),
+            other => Err(AlertError::ConfigError(format!(
+                "unknown alert level '{other}' (expected info, warn, critical)"
+            ))),
+        }
+    }
+}
+
+/// sink로 전달되는 알림 한 건.
+#[derive(Debug, Clone, Serialize)]
+pub struct AlertNotification {
+    /// 트레이딩 세션 ID.
+    pub session_id: i64,
+    /// 심각도 레벨.
+    pub level: AlertLevel,
+    /// 이벤트 타입 (e.g., "leg_failure").
+    pub event_type: String,
+    /// 사람이 읽는 메시지.
+    pub message: String,
+    /// 알림을 보낸 전략 인스턴스 이름 (인스턴스가 여럿일 때만 설정).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// 이벤트 원본 (디버그 표현).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload: Option<String>,
+    /// 생성 시각 (UTC).
+    pub at: DateTime<Utc>,
+}
+
+impl AlertNotification {
+    /// 새 알림을 생성합니다 (`source`, `payload` 없음).
+    pub fn new(
+        session_id: i64,
+        level: AlertLevel,
+        event_type: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            session_id,
+            level,
+            event_type: event_type.into(),
+            message: message.into(),
+            source: None,
+            payload: None,
+            at: Utc::now(),
+        }
+    }
+
+    /// 채팅형 sink에 보낼 본문. `source`가 있으면 `[인스턴스]` 접두사를 붙입니다.
+    pub fn text(&self) -> String {
+        match &self.source {
+            Some(source) => format!("[{source}] {}", self.message),
+            None => self.message.clone(),
+        }
+    }
+}
+
+/// 알림 전송 대상.
+///
+/// 구현체는 [`AlertRouter`](crate::AlertRouter)에 이름으로 등록되어
+/// 라우팅 규칙에 맞는 알림만 받습니다.
+#[async_trait]
+pub trait AlertSink: Send + Sync {
+    /// sink 종류 (e.g., "slack"). 로그 및 진단용.
+    fn kind(&self) -> &'static str;
+
+    /// 알림 한 건을 전송합니다.
+    async fn send(&self, notification: &AlertNotification) -> Result<(), AlertError>;
+}
+
+/// 본문 전송 함수 타입 ([`FnSink`]용).
+pub type SendTextFn =
+    Box<dyn Fn(String) -> Pin<Box<dyn Future<Output = Result<(), String>> + Send>> + Send + Sync>;
+
+/// 클로저 기반 sink.
+///
+/// [`AlertNotification::text`]를 그대로 넘기므로 테스트 mock이나
+/// 기존 전송 함수를 sink로 감쌀 때 사용합니다.
+pub struct FnSink {
+    send_fn: SendTextFn,
+}
+
+impl FnSink {
+    /// 전송 함수로 sink를 생성합니다.
+    pub fn new(
+        send_fn: impl Fn(String) -> Pin<Box<dyn Future<Output = Result<(), String>> + Send>>
+        + Send
+        + Sync
+        + 'static,
+    ) -> Self {
+        Self {
+            send_fn: Box::new(send_fn),
+        }
+    }
+}
+
+#[async_trait]
+impl AlertSink for FnSink {
+    fn kind(&self) -> &'static str {
+        "fn"
+    }
+
+    async fn send(&self, notification: &AlertNotification) -> Result<(), AlertError> {
+        (self.send_fn)(notification.text())
+            .await
+            .map_err(AlertError::Other)
+    }
+}
@@ -0,0 +1,49 @@
+//! Telegram sink.
+
+use arb_config::TelegramConfig;
+use arb_telegram::{SendMessageOptions, TelegramClient};
+use async_trait::async_trait;
+
+use crate::error::AlertError;
+use crate::sink::{AlertLevel, AlertNotification, AlertSink};
+
+/// Telegram Bot API sink.
+///
+/// info 레벨 알림은 무음(`disable_notification`)으로 전송합니다.
+#[derive(Debug, Clone)]
+pub struct TelegramSink {
+    client: TelegramClient,
+}
+
+impl TelegramSink {
+    /// Telegram 설정으로 sink를 생성합니다.
+    pub fn new(config: &TelegramConfig) -> Result<Self, AlertError> {
+        Ok(Self {
+            client: TelegramClient::new(config)?,
+        })
+    }
+
+    /// 기존 클라이언트로 sink를 생성합니다.
+    pub fn from_client(client: TelegramClient) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl AlertSink for TelegramSink {
+    fn kind(&self) -> &'static str {
+        "telegram"
+    }
+
+    async fn send(&self, notification: &AlertNotification) -> Result<(), AlertError> {
+        let options = if notification.level == AlertLevel::Info {
+            SendMessageOptions::new().silent()
+        } else {
+            SendMessageOptions::new()
+        };
+        self.client
+            .send_message_with_options(&notification.text(), options)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,242 @@
+//! HTTP webhook sink (Slack, Discord, 일반 JSON).
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde_json::json;
+use tracing::debug;
+
+use crate::error::AlertError;
+use crate::sink::{AlertNotification, AlertSink};
+
+/// Discord 메시지 본문 최대 길이 (문자).
+const DISCORD_CONTENT_LIMIT: usize = 2000;
+
+/// Webhook 요청 타임아웃 (초).
+const WEBHOOK_TIMEOUT_SECS: u64 = 10;
+
+/// 공통 webhook 전송기.
+#[derive(Debug, Clone)]
+struct WebhookClient {
+    client: Client,
+    url: String,
+}
+
+impl WebhookClient {
+    fn new(url: &str) -> Result<Self, AlertError> {
+        if url.is_empty() {
+            return Err(AlertError::ConfigError(
+                "webhook url must be provided".to_string(),
+            ));
+        }
+        let client = Client::builder()
+            .timeout(std::time::Duration::from_secs(WEBHOOK_TIMEOUT_SECS))
+            .build()?;
+        Ok(Self {
+            client,
+            url: url.to_string(),
+        })
+    }
+
+    /// JSON 본문을 POST하고 2xx가 아니면 에러를 반환합니다.
+    async fn post(&self, body: &serde_json::Value) -> Result<(), AlertError> {
+        let response = self.client.post(&self.url).json(body).send().await?;
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        let body = response.text().await.unwrap_or_default();
+        Err(AlertError::WebhookStatus {
+            status: status.as_u16(),
+            body,
+        })
+    }
+}
+
+/// Slack Incoming Webhook sink.
+#[derive(Debug, Clone)]
+pub struct SlackSink {
+    webhook: WebhookClient,
+}
+
+impl SlackSink {
+    /// Incoming Webhook URL로 sink를 생성합니다.
+    pub fn new(url: &str) -> Result<Self, AlertError> {
+        Ok(Self {
+            webhook: WebhookClient::new(url)?,
+        })
+    }
+}
+
+#[async_trait]
+impl AlertSink for SlackSink {
+    fn kind(&self) -> &'static str {
+        "slack"
+    }
+
+    async fn send(&self, notification: &AlertNotification) -> Result<(), AlertError> {
+        debug!(event_type = %notification.event_type, "Slack 알림 전송");
+        self.webhook
+            .post(&json!({ "text": notification.text() }))
+            .await
+    }
+}
+
+/// Discord Webhook sink.
+#[derive(Debug, Clone)]
+pub struct DiscordSink {
+    webhook: WebhookClient,
+}
+
+impl DiscordSink {
+    /// Discord Webhook URL로 sink를 생성합니다.
+    pub fn new(url: &str) -> Result<Self, AlertError> {
+        Ok(Self {
+            webhook: WebhookClient::new(url)?,
+        })
+    }
+}
+
+#[async_trait]
+impl AlertSink for DiscordSink {
+    fn kind(&self) -> &'static str {
+        "discord"
+    }
+
+    async fn send(&self, notification: &AlertNotification) -> Result<(), AlertError> {
+        debug!(event_type = %notification.event_type, "Discord 알림 전송");
+        // Discord는 2000자를 넘는 content를 거부
+        let content: String = notification
+            .text()
+            .chars()
+            .take(DISCORD_CONTENT_LIMIT)
+            .collect();
+        self.webhook.post(&json!({ "content": content })).await
+    }
+}
+
+/// 일반 JSON webhook sink.
+///
+/// [`AlertNotification`]을 그대로 직렬화해 POST합니다
+/// (`session_id`, `level`, `event_type`, `message`, `source`, `payload`, `at`).
+#[derive(Debug, Clone)]
+pub struct WebhookSink {
+    webhook: WebhookClient,
+}
+
+impl WebhookSink {
+    /// 수신 URL로 sink를 생성합니다.
+    pub fn new(url: &str) -> Result<Self, AlertError> {
+        Ok(Self {
+            webhook: WebhookClient::new(url)?,
+        })
+    }
+}
+
+#[async_trait]
+impl AlertSink for WebhookSink {
+    fn kind(&self) -> &'static str {
+        "webhook"
+    }
+
+    async fn send(&self, notification: &AlertNotification) -> Result<(), AlertError> {
+        debug!(event_type = %notification.event_type, "Webhook 알림 전송");
+        let body = serde_json::to_value(notification)
+            .map_err(|e| AlertError::Other(format!("failed to serialize alert: {e}")))?;
+        self.webhook.post(&body).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sink::AlertLevel;
+    use mockito::Matcher;
+    use serde_json::json;
+
+    fn leg_failure() -> AlertNotification {
+        let mut notification =
+            AlertNotification::new(7, AlertLevel::Critical, "leg_failure", "LEG FAILURE: BTC");
+        notification.source = Some("main".to_string());
+        notification
+    }
+
+    #[test]
+    fn test_empty_url_rejected() {
+        assert!(SlackSink::new("").is_err());
+        assert!(DiscordSink::new("").is_err());
+        assert!(WebhookSink::new("").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_slack_sink_posts_text() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/hook")
+            .match_body(Matcher::Json(json!({ "text": "[main] LEG FAILURE: BTC" })))
+            .with_body("ok")
+            .create_async()
+            .await;
+
+        let sink = SlackSink::new(&format!("{}/hook", server.url())).unwrap();
+        sink.send(&leg_failure()).await.unwrap();
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_discord_sink_truncates_content() {
+        let mut server = mockito::Server::new_async().await;
+        let long = "x".repeat(DISCORD_CONTENT_LIMIT + 10);
+        let mock = server
+            .mock("POST", "/hook")
+            .match_body(Matcher::Json(
+                json!({ "content": "x".repeat(DISCORD_CONTENT_LIMIT) }),
+            ))
+            .with_status(204)
+            .create_async()
+            .await;
+
+        let sink = DiscordSink::new(&format!("{}/hook", server.url())).unwrap();
+        let notification = AlertNotification::new(1, AlertLevel::Info, "error", long);
+        sink.send(&notification).await.unwrap();
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_webhook_sink_posts_notification_json() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/alerts")
+            .match_body(Matcher::PartialJson(json!({
+                "session_id": 7,
+                "level": "critical",
+                "event_type": "leg_failure",
+                "source": "main",
+            })))
+            .create_async()
+            .await;
+
+        let sink = WebhookSink::new(&format!("{}/alerts", server.url())).unwrap();
+        sink.send(&leg_failure()).await.unwrap();
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_webhook_error_status() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", "/hook")
+            .with_status(404)
+            .with_body("no_service")
+            .create_async()
+            .await;
+
+        let sink = SlackSink::new(&format!("{}/hook", server.url())).unwrap();
+        match sink.send(&leg_failure()).await {
+            Err(AlertError::WebhookStatus { status, body }) => {
+                assert_eq!(status, 404);
+                assert_eq!(body, "no_service");
+            }
+            other => panic!("expected WebhookStatus, got {other:?}"),
+        }
+    }
+}
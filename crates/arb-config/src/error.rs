@@ -16,4 +16,8 @@ pub enum ConfigError {
     /// 설정 파일 파싱 실패.
     #[error("Failed to parse configuration: {0}")]
     ParseError(String),
+
+    /// 시크릿 로드/복호화 실패. 메시지에 시크릿 값은 포함하지 않습니다.
+    #[error("Failed to load secret: {0}")]
+    Secret(String),
 }
@@ -0,0 +1,301 @@
+//! 시크릿 값 래퍼와 시크릿 소스.
+//!
+//! - [`SecretString`]: drop 시 메모리를 0으로 덮어쓰고 `Debug`에서 값을 가리는 문자열
+//! - [`seal_secrets`] / [`open_secrets`]: 패스프레이즈 기반 암호화 시크릿 파일
+//!   (PBKDF2-HMAC-SHA256 키 유도 + AES-256-GCM)
+//! - [`read_secret_path`]: 시크릿 마운트 경로 또는 파일 디스크립터(`fd:N`)에서 읽기
+
+use std::fmt;
+use std::num::NonZeroU32;
+
+use ring::aead::{AES_256_GCM, Aad, LessSafeKey, NONCE_LEN, Nonce, UnboundKey};
+use ring::pbkdf2;
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::{Deserialize, Deserializer};
+use zeroize::Zeroize;
+
+use crate::error::ConfigError;
+
+/// 암호화 시크릿 파일 헤더.
+const SEALED_HEADER: &str = "arb-secrets v1";
+/// 신규 파일의 PBKDF2 반복 횟수.
+pub const PBKDF2_ITERATIONS: u32 = 600_000;
+/// PBKDF2 salt 길이 (바이트).
+const SALT_LEN: usize = 16;
+
+/// drop 시 0으로 덮어쓰고 `Debug`에서 값을 숨기는 문자열.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct SecretString(String);
+
+impl SecretString {
+    /// 새 시크릿을 생성합니다.
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    /// 원문을 반환합니다. 서명/헤더 생성 등 실제 사용 지점에서만 호출하세요.
+    #[must_use]
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+
+    /// 값이 비어 있으면 true를 반환합니다.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// SHA-256 지문 (hex 앞 16자). 원문 대신 맵 키나 로그에서 값을 구별할 때 사용합니다.
+    #[must_use]
+    pub fn fingerprint(&self) -> String {
+        let digest = ring::digest::digest(&ring::digest::SHA256, self.0.as_bytes());
+        hex::encode(&digest.as_ref()[..8])
+    }
+}
+
+impl Drop for SecretString {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            f.write_str("SecretString(\"\")")
+        } else {
+            f.write_str("SecretString(****)")
+        }
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SecretString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self)
+    }
+}
+
+/// 평문을 패스프레이즈로 암호화한 시크릿 파일 내용을 생성합니다.
+///
+/// 평문은 `config.toml`과 같은 형식의 거래소 섹션입니다
+/// (`[upbit] api_key = "..."` 등).
+pub fn seal_secrets(plaintext: &str, passphrase: &str) -> Result<String, ConfigError> {
+    seal_with_iterations(plaintext, passphrase, PBKDF2_ITERATIONS)
+}
+
+pub(crate) fn seal_with_iterations(
+    plaintext: &str,
+    passphrase: &str,
+    iterations: u32,
+) -> Result<String, ConfigError> {
+    let iterations = NonZeroU32::new(iterations)
+        .ok_or_else(|| ConfigError::Secret("iterations must be greater than 0".to_string()))?;
+    let rng = SystemRandom::new();
+    let mut salt = [0u8; SALT_LEN];
+    let mut nonce = [0u8; NONCE_LEN];
+    rng.fill(&mut salt)
+        .and_then(|()| rng.fill(&mut nonce))
+        .map_err(|_| ConfigError::Secret("random generation failed".to_string()))?;
+
+    let key = derive_key(passphrase, &salt, iterations)?;
+    let mut buf = plaintext.as_bytes().to_vec();
+    let sealed = key.seal_in_place_append_tag(
+        Nonce::assume_unique_for_key(nonce),
+        Aad::from(SEALED_HEADER.as_bytes()),
+        &mut buf,
+    );
+    if sealed.is_err() {
+        buf.zeroize();
+        return Err(ConfigError::Secret("encryption failed".to_string()));
+    }
+
+    Ok(format!(
+        "{SEALED_HEADER}\niterations = {iterations}\nsalt = {}\nnonce = {}\nciphertext = {}\n",
+        hex::encode(salt),
+        hex::encode(nonce),
+        hex::encode(&buf)
+    ))
+}
+
+/// 암호화 시크릿 파일 내용을 복호화합니다.
+///
+/// 패스프레이즈가 틀렸거나 내용이 변조되었으면 에러를 반환합니다.
+pub fn open_secrets(sealed: &str, passphrase: &str) -> Result<SecretString, ConfigError> {
+    let mut lines = sealed.lines().map(str::trim).filter(|l| !l.is_empty());
+    if lines.next() != Some(SEALED_HEADER) {
+        return Err(ConfigError::Secret(format!(
+            "not an encrypted secrets file (expected header '{SEALED_HEADER}')"
+        )));
+    }
+
+    let (mut iterations, mut salt, mut nonce, mut ciphertext) = (None, None, None, None);
+    for line in lines {
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let value = value.trim();
+        match key.trim() {
+            "iterations" => iterations = value.parse::<u32>().ok().and_then(NonZeroU32::new),
+            "salt" => salt = hex::decode(value).ok(),
+            "nonce" => nonce = hex::decode(value).ok(),
+            "ciphertext" => ciphertext = hex::decode(value).ok(),
+            _ => {}
+        }
+    }
+    let field = |name: &str| ConfigError::Secret(format!("missing or invalid field: {name}"));
+    let iterations = iterations.ok_or_else(|| field("iterations"))?;
+    let salt = salt.ok_or_else(|| field("salt"))?;
+    let nonce: [u8; NONCE_LEN] = nonce
+        .and_then(|n| n.try_into().ok())
+        .ok_or_else(|| field("nonce"))?;
+    let mut buf = ciphertext.ok_or_else(|| field("ciphertext"))?;
+
+    let key = derive_key(passphrase, &salt, iterations)?;
+    let opened = key
+        .open_in_place(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::from(SEALED_HEADER.as_bytes()),
+            &mut buf,
+        )
+        .map(|plain| std::str::from_utf8(plain).map(str::to_string));
+    let result = match opened {
+        Ok(Ok(plaintext)) => Ok(SecretString::from(plaintext)),
+        Ok(Err(_)) => Err(ConfigError::Secret(
+            "decrypted secrets are not valid UTF-8".to_string(),
+        )),
+        Err(_) => Err(ConfigError::Secret(
+            "decryption failed (wrong passphrase or corrupted file)".to_string(),
+        )),
+    };
+    buf.zeroize();
+    result
+}
+
+/// 시크릿 마운트 경로에서 값을 읽습니다.
+///
+/// `fd:N` 형식이면 상속받은 파일 디스크립터 N(`/dev/fd/N`)에서 읽습니다.
+/// 끝의 개행은 제거합니다.
+pub fn read_secret_path(spec: &str) -> Result<SecretString, ConfigError> {
+    let path = match spec.strip_prefix("fd:") {
+        Some(fd) => {
+            let fd: u32 = fd
+                .trim()
+                .parse()
+                .map_err(|_| ConfigError::Secret(format!("invalid file descriptor: {spec}")))?;
+            format!("/dev/fd/{fd}")
+        }
+        None => spec.to_string(),
+    };
+    let mut content = std::fs::read_to_string(&path)
+        .map_err(|e| ConfigError::Secret(format!("failed to read secret from {path}: {e}")))?;
+    let trimmed_len = content.trim_end_matches(['\r', '\n']).len();
+    content.truncate(trimmed_len);
+    Ok(SecretString::from(content))
+}
+
+fn derive_key(
+    passphrase: &str,
+    salt: &[u8],
+    iterations: NonZeroU32,
+) -> Result<LessSafeKey, ConfigError> {
+    if passphrase.is_empty() {
+        return Err(ConfigError::Secret("passphrase is empty".to_string()));
+    }
+    let mut key = [0u8; 32];
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        iterations,
+        salt,
+        passphrase.as_bytes(),
+        &mut key,
+    );
+    let unbound = UnboundKey::new(&AES_256_GCM, &key);
+    key.zeroize();
+    unbound
+        .map(LessSafeKey::new)
+        .map_err(|_| ConfigError::Secret("invalid key".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::Write;
+
+    #[test]
+    fn test_secret_string_fingerprint() {
+        let a = SecretString::from("key-a");
+        assert_eq!(a.fingerprint(), SecretString::from("key-a").fingerprint());
+        assert_ne!(a.fingerprint(), SecretString::from("key-b").fingerprint());
+        assert_eq!(a.fingerprint().len(), 16);
+        assert!(!a.fingerprint().contains("key-a"));
+    }
+
+    #[test]
+    fn test_secret_string_debug_redacted() {
+        let secret = SecretString::from("my_super_secret");
+        let debug = format!("{secret:?}");
+        assert!(!debug.contains("my_super_secret"));
+        assert_eq!(debug, "SecretString(****)");
+        assert_eq!(secret.expose_secret(), "my_super_secret");
+        assert_eq!(
+            format!("{:?}", SecretString::default()),
+            "SecretString(\"\")"
+        );
+    }
+
+    #[test]
+    fn test_seal_open_round_trip() {
+        let plaintext = "[upbit]\napi_key = \"k\"\nsecret_key = \"s\"\n";
+        let sealed = seal_with_iterations(plaintext, "correct horse", 1_000).unwrap();
+        assert!(sealed.starts_with(SEALED_HEADER));
+        assert!(!sealed.contains("secret_key"));
+
+        let opened = open_secrets(&sealed, "correct horse").unwrap();
+        assert_eq!(opened.expose_secret(), plaintext);
+
+        let err = open_secrets(&sealed, "wrong").unwrap_err();
+        assert!(err.to_string().contains("decryption failed"));
+    }
+
+    #[test]
+    fn test_open_rejects_tampered_file() {
+        let sealed = seal_with_iterations("data", "pw", 1_000).unwrap();
+        let tampered: String = sealed
+            .lines()
+            .map(|line| match line.strip_prefix("ciphertext = ") {
+                Some(hex) => {
+                    let flipped = if hex.starts_with('0') { "1" } else { "0" };
+                    format!("ciphertext = {flipped}{}", &hex[1..])
+                }
+                None => line.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(open_secrets(&tampered, "pw").is_err());
+        assert!(open_secrets("plain text", "pw").is_err());
+    }
+
+    #[test]
+    fn test_read_secret_path_trims_newline() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        writeln!(file, "mounted_secret").unwrap();
+        let secret = read_secret_path(file.path().to_str().unwrap()).unwrap();
+        assert_eq!(secret.expose_secret(), "mounted_secret");
+
+        assert!(read_secret_path("fd:abc").is_err());
+        assert!(read_secret_path("/nonexistent/secret").is_err());
+    }
+}
@@ -0,0 +1 @@
+ALTER TABLE sessions ADD COLUMN instance_name VARCHAR(64) NOT NULL DEFAULT 'default';
@@ -0,0 +1 @@
+CREATE INDEX idx_sessions_instance_status ON sessions (instance_name, status);
@@ -0,0 +1,33 @@
+CREATE TABLE execution_quality (
+    id                             BIGINT       NOT NULL AUTO_INCREMENT PRIMARY KEY,
+    session_id                     BIGINT       NOT NULL,
+    position_id                    BIGINT       NOT NULL,
+    coin                           VARCHAR(20)  NOT NULL,
+    side                           VARCHAR(10)  NOT NULL COMMENT 'entry | exit',
+    order_type                     VARCHAR(20)  NOT NULL COMMENT 'limit_ioc | market',
+    max_slippage_pct               DOUBLE       NULL     COMMENT 'IOC 지정가 슬리피지 허용폭 (시장가는 NULL)',
+    expected_upbit_krw             DECIMAL(20,4) NOT NULL COMMENT '시그널 시점 Upbit 기대가',
+    actual_upbit_krw               DECIMAL(20,4) NOT NULL COMMENT 'Upbit 평균 체결가',
+    expected_bybit                 DECIMAL(20,8) NOT NULL COMMENT '시그널 시점 Bybit 기대가',
+    actual_bybit                   DECIMAL(20,8) NOT NULL COMMENT 'Bybit 평균 체결가',
+    upbit_qty                      DECIMAL(20,8) NOT NULL,
+    bybit_qty                      DECIMAL(20,8) NOT NULL,
+    usd_krw                        DOUBLE       NOT NULL,
+    upbit_slippage_bps             DOUBLE       NOT NULL COMMENT '불리한 방향이 양수',
+    bybit_slippage_bps             DOUBLE       NOT NULL COMMENT '불리한 방향이 양수',
+    slippage_cost_usdt             DECIMAL(20,8) NOT NULL,
+    fees_usdt                      DECIMAL(20,8) NOT NULL,
+    implementation_shortfall_usdt  DECIMAL(20,8) NOT NULL COMMENT '슬리피지 비용 + 수수료',
+    implementation_shortfall_bps   DOUBLE       NOT NULL,
+    expected_profit_pct            DOUBLE       NULL     COMMENT '진입 전용: adjusted_profit_pct',
+    safe_volume_usdt               DOUBLE       NULL     COMMENT '진입 전용: 오더북 안전 볼륨',
+    upbit_latency_ms               BIGINT       NOT NULL,
+    bybit_latency_ms               BIGINT       NOT NULL,
+    fill_latency_ms                BIGINT       NOT NULL COMMENT '양 레그 체결 응답까지 소요 시간',
+    leg_gap_ms                     BIGINT       NOT NULL COMMENT '레그 간 체결 시차',
+    executed_at                    DATETIME(3)  NOT NULL,
+
+    INDEX idx_session (session_id),
+    INDEX idx_position (position_id),
+    INDEX idx_coin_order_type (coin, order_type)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
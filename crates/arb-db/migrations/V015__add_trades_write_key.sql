@@ -0,0 +1 @@
+ALTER TABLE trades ADD COLUMN write_key VARCHAR(36) NULL COMMENT 'DbWriter 멱등 키', ADD UNIQUE INDEX uk_trades_write_key (write_key);
@@ -0,0 +1 @@
+ALTER TABLE alerts ADD COLUMN write_key VARCHAR(36) NULL COMMENT 'DbWriter 멱등 키', ADD UNIQUE INDEX uk_alerts_write_key (write_key);
@@ -0,0 +1 @@
+ALTER TABLE balance_snapshots ADD COLUMN write_key VARCHAR(36) NULL COMMENT 'DbWriter 멱등 키', ADD UNIQUE INDEX uk_balance_snapshots_write_key (write_key);
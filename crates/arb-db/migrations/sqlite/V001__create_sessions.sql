@@ -0,0 +1,8 @@
+CREATE TABLE sessions (
+    id                  INTEGER PRIMARY KEY AUTOINCREMENT,
+    parent_session_id   INTEGER NULL,
+    started_at          TEXT NOT NULL,
+    ended_at            TEXT,
+    config_json         TEXT NOT NULL,
+    status              TEXT NOT NULL
+);
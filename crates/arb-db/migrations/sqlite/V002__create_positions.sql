@@ -0,0 +1,40 @@
+-- DECIMAL 컬럼은 TEXT로 저장 (sqlx SQLite 드라이버 Decimal 미지원, 정밀도 보존)
+CREATE TABLE positions (
+    id                  INTEGER PRIMARY KEY AUTOINCREMENT,
+    session_id          INTEGER NOT NULL,
+    coin                TEXT NOT NULL,
+    state               TEXT NOT NULL,
+    upbit_qty           TEXT NOT NULL,
+    bybit_qty           TEXT NOT NULL,
+    upbit_entry_price   TEXT,
+    bybit_entry_price   TEXT,
+    upbit_order_id      TEXT,
+    bybit_order_id      TEXT,
+    entry_spread_pct    REAL,
+    entry_z_score       REAL,
+    entry_usd_krw       REAL,
+    opened_at           TEXT,
+    closed_at           TEXT,
+    realized_pnl        TEXT,
+    exit_upbit_order_id TEXT,
+    exit_bybit_order_id TEXT,
+    client_order_id     TEXT,
+    exit_client_order_id TEXT NULL,
+    in_flight           BOOLEAN DEFAULT FALSE,
+    succeeded_leg       TEXT,
+    emergency_attempts  INTEGER DEFAULT 0,
+    created_at          TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')),
+    updated_at          TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now'))
+);
+
+CREATE INDEX idx_positions_session_state ON positions (session_id, state);
+CREATE INDEX idx_positions_coin_state ON positions (coin, state);
+
+-- MySQL ON UPDATE CURRENT_TIMESTAMP(3) 대응
+CREATE TRIGGER trg_positions_updated_at
+AFTER UPDATE ON positions
+BEGIN
+    UPDATE positions
+    SET updated_at = strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')
+    WHERE id = NEW.id;
+END;
@@ -0,0 +1,21 @@
+CREATE TABLE trades (
+    id                  INTEGER PRIMARY KEY AUTOINCREMENT,
+    session_id          INTEGER NOT NULL,
+    position_id         INTEGER NOT NULL,
+    coin                TEXT NOT NULL,
+    side                TEXT NOT NULL,
+    qty                 TEXT NOT NULL,
+    upbit_price_krw     TEXT,
+    bybit_price_usdt    TEXT,
+    upbit_fee           TEXT,
+    bybit_fee           TEXT,
+    spread_pct          REAL,
+    z_score             REAL,
+    realized_pnl        TEXT,
+    adjustment_cost     TEXT,
+    exit_usd_krw        REAL,
+    executed_at         TEXT NOT NULL
+);
+
+CREATE INDEX idx_trades_session ON trades (session_id);
+CREATE INDEX idx_trades_position ON trades (position_id);
@@ -0,0 +1,14 @@
+CREATE TABLE minutes (
+    id              INTEGER PRIMARY KEY AUTOINCREMENT,
+    session_id      INTEGER NOT NULL,
+    coin            TEXT NOT NULL,
+    ts              TEXT NOT NULL,
+    upbit_close     TEXT,
+    bybit_close     TEXT,
+    spread_pct      REAL,
+    z_score         REAL,
+    mean            REAL,
+    stddev          REAL
+);
+
+CREATE INDEX idx_minutes_session_coin_ts ON minutes (session_id, coin, ts);
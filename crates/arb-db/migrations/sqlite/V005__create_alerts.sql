@@ -0,0 +1,11 @@
+CREATE TABLE alerts (
+    id              INTEGER PRIMARY KEY AUTOINCREMENT,
+    session_id      INTEGER NOT NULL,
+    level           TEXT NOT NULL,
+    event_type      TEXT NOT NULL,
+    message         TEXT NOT NULL,
+    payload_json    TEXT,
+    created_at      TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now'))
+);
+
+CREATE INDEX idx_alerts_session_level ON alerts (session_id, level);
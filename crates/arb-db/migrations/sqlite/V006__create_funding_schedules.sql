@@ -0,0 +1,19 @@
+CREATE TABLE funding_schedules (
+    id                  INTEGER PRIMARY KEY AUTOINCREMENT,
+    coin                TEXT NOT NULL,
+    interval_hours      INTEGER NOT NULL,
+    next_funding_time   TEXT NOT NULL,
+    current_rate        REAL NOT NULL,
+    updated_at          TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now'))
+);
+
+CREATE UNIQUE INDEX idx_funding_schedules_coin ON funding_schedules (coin);
+
+-- MySQL ON UPDATE CURRENT_TIMESTAMP(3) 대응
+CREATE TRIGGER trg_funding_schedules_updated_at
+AFTER UPDATE ON funding_schedules
+BEGIN
+    UPDATE funding_schedules
+    SET updated_at = strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')
+    WHERE id = NEW.id;
+END;
@@ -0,0 +1,25 @@
+-- record_type: PERIODIC | POS_ENT | POS_EXT, cex: UPBIT | BYBIT, currency: KRW | USDT
+-- 금액 컬럼은 TEXT(Decimal 문자열)로 저장
+CREATE TABLE balance_snapshots (
+    id                 INTEGER PRIMARY KEY AUTOINCREMENT,
+    created_at         TEXT    NOT NULL,
+    snapshot_group_id  INTEGER NOT NULL,
+    session_id         INTEGER NOT NULL,
+    record_type        TEXT    NOT NULL,
+    cex                TEXT    NOT NULL,
+    currency           TEXT    NOT NULL,
+    available          TEXT    NOT NULL,
+    locked             TEXT    NOT NULL,
+    coin_value         TEXT    NOT NULL DEFAULT '0',
+    total              TEXT    NOT NULL,
+    position_id        INTEGER NULL,
+    usd_krw            REAL    NOT NULL,
+    usdt_krw           REAL    NOT NULL,
+    total_usd          TEXT    NOT NULL,
+    total_usdt         TEXT    NOT NULL
+);
+
+CREATE INDEX idx_balance_snapshots_session_created ON balance_snapshots (session_id, created_at);
+CREATE INDEX idx_balance_snapshots_session_type ON balance_snapshots (session_id, record_type);
+CREATE INDEX idx_balance_snapshots_group ON balance_snapshots (snapshot_group_id);
+CREATE INDEX idx_balance_snapshots_position ON balance_snapshots (position_id);
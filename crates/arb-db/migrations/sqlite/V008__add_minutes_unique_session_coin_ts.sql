@@ -0,0 +1 @@
+CREATE UNIQUE INDEX uk_minutes_session_coin_ts ON minutes (session_id, coin, ts);
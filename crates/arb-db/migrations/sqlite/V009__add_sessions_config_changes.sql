@@ -0,0 +1 @@
+ALTER TABLE sessions ADD COLUMN config_changes TEXT NULL;
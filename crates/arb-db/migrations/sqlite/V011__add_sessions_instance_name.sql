@@ -0,0 +1 @@
+ALTER TABLE sessions ADD COLUMN instance_name TEXT NOT NULL DEFAULT 'default';
@@ -0,0 +1,35 @@
+-- side: entry | exit, order_type: limit_ioc | market
+-- 금액/가격 컬럼은 TEXT(Decimal 문자열)로 저장
+CREATE TABLE execution_quality (
+    id                             INTEGER PRIMARY KEY AUTOINCREMENT,
+    session_id                     INTEGER NOT NULL,
+    position_id                    INTEGER NOT NULL,
+    coin                           TEXT    NOT NULL,
+    side                           TEXT    NOT NULL,
+    order_type                     TEXT    NOT NULL,
+    max_slippage_pct               REAL    NULL,
+    expected_upbit_krw             TEXT    NOT NULL,
+    actual_upbit_krw               TEXT    NOT NULL,
+    expected_bybit                 TEXT    NOT NULL,
+    actual_bybit                   TEXT    NOT NULL,
+    upbit_qty                      TEXT    NOT NULL,
+    bybit_qty                      TEXT    NOT NULL,
+    usd_krw                        REAL    NOT NULL,
+    upbit_slippage_bps             REAL    NOT NULL,
+    bybit_slippage_bps             REAL    NOT NULL,
+    slippage_cost_usdt             TEXT    NOT NULL,
+    fees_usdt                      TEXT    NOT NULL,
+    implementation_shortfall_usdt  TEXT    NOT NULL,
+    implementation_shortfall_bps   REAL    NOT NULL,
+    expected_profit_pct            REAL    NULL,
+    safe_volume_usdt               REAL    NULL,
+    upbit_latency_ms               INTEGER NOT NULL,
+    bybit_latency_ms               INTEGER NOT NULL,
+    fill_latency_ms                INTEGER NOT NULL,
+    leg_gap_ms                     INTEGER NOT NULL,
+    executed_at                    TEXT    NOT NULL
+);
+
+CREATE INDEX idx_execution_quality_session ON execution_quality (session_id);
+CREATE INDEX idx_execution_quality_position ON execution_quality (position_id);
+CREATE INDEX idx_execution_quality_coin_order_type ON execution_quality (coin, order_type);
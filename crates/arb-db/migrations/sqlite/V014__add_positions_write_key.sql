@@ -0,0 +1,3 @@
+-- DbWriter 멱등 키 (NULL은 중복 허용)
+ALTER TABLE positions ADD COLUMN write_key TEXT NULL;
+CREATE UNIQUE INDEX uk_positions_write_key ON positions (write_key);
@@ -0,0 +1,3 @@
+-- DbWriter 멱등 키 (NULL은 중복 허용)
+ALTER TABLE trades ADD COLUMN write_key TEXT NULL;
+CREATE UNIQUE INDEX uk_trades_write_key ON trades (write_key);
@@ -0,0 +1,3 @@
+-- DbWriter 멱등 키 (NULL은 중복 허용)
+ALTER TABLE alerts ADD COLUMN write_key TEXT NULL;
+CREATE UNIQUE INDEX uk_alerts_write_key ON alerts (write_key);
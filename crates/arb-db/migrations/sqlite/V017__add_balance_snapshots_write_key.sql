@@ -0,0 +1,3 @@
+-- DbWriter 멱등 키 (NULL은 중복 허용)
+ALTER TABLE balance_snapshots ADD COLUMN write_key TEXT NULL;
+CREATE UNIQUE INDEX uk_balance_snapshots_write_key ON balance_snapshots (write_key);
@@ -0,0 +1,3 @@
+-- DbWriter 멱등 키 (NULL은 중복 허용)
+ALTER TABLE execution_quality ADD COLUMN write_key TEXT NULL;
+CREATE UNIQUE INDEX uk_execution_quality_write_key ON execution_quality (write_key);
@@ -3,12 +3,13 @@
 //! 알림 기록 저장 (기존 alerts.log 대체).
 
 use crate::error::DbError;
+use crate::pool::{DbPool, InsertedId};
 use chrono::{DateTime, Utc};
-use sqlx::MySqlPool;
+use serde::{Deserialize, Serialize};
 use tracing::debug;
 
 /// 알림 레코드.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AlertRecord {
     pub id: Option<i64>,
     pub session_id: i64,
@@ -22,17 +23,28 @@ pub struct AlertRecord {
 /// alerts 테이블 Repository.
 #[derive(Debug, Clone)]
 pub struct AlertRepository {
-    pool: MySqlPool,
+    pool: DbPool,
 }
 
 impl AlertRepository {
     /// 새 Repository 생성.
-    pub fn new(pool: MySqlPool) -> Self {
+    pub fn new(pool: DbPool) -> Self {
         Self { pool }
     }
 
     /// 알림 기록 INSERT. 생성된 ID 반환.
     pub async fn insert_alert(&self, alert: &AlertRecord) -> Result<i64, DbError> {
+        self.insert_alert_keyed(alert, None).await
+    }
+
+    /// 알림 기록 INSERT (DbWriter 멱등 키 지정).
+    ///
+    /// 같은 `write_key`의 행이 이미 있으면 새 행 없이 성공하며, 이때 반환 ID는 의미가 없습니다.
+    pub(crate) async fn insert_alert_keyed(
+        &self,
+        alert: &AlertRecord,
+        write_key: Option<&str>,
+    ) -> Result<i64, DbError> {
         debug!(
             session_id = alert.session_id,
             level = %alert.level,
@@ -40,23 +52,28 @@ impl AlertRepository {
             "알림 INSERT"
         );
 
-        let result = sqlx::query(
+        let sql = format!(
             r#"
             INSERT INTO alerts (
-                session_id, level, event_type, message, payload_json, created_at
-            ) VALUES (?, ?, ?, ?, ?, ?)
+                session_id, level, event_type, message, payload_json, created_at, write_key
+            ) VALUES (?, ?, ?, ?, ?, ?, ?)
+            {}
             "#,
-        )
-        .bind(alert.session_id)
-        .bind(&alert.level)
-        .bind(&alert.event_type)
-        .bind(&alert.message)
-        .bind(&alert.payload_json)
-        .bind(alert.created_at)
-        .execute(&self.pool)
-        .await?;
-
-        let id = result.last_insert_id() as i64;
+            self.pool.backend().write_key_conflict_sql()
+        );
+        let id = crate::with_pool!(&self.pool, pool => {
+            sqlx::query(&sql)
+            .bind(alert.session_id)
+            .bind(&alert.level)
+            .bind(&alert.event_type)
+            .bind(&alert.message)
+            .bind(&alert.payload_json)
+            .bind(alert.created_at)
+            .bind(write_key)
+            .execute(pool)
+            .await?
+            .inserted_id()
+        });
         debug!(alert_id = id, "알림 INSERT 완료");
         Ok(id)
     }
@@ -95,4 +112,21 @@ mod tests {
         assert_eq!(record.id, Some(42));
         assert!(record.payload_json.is_none());
     }
+
+    #[tokio::test]
+    async fn test_insert_alert_sqlite() {
+        let repo = AlertRepository::new(crate::pool::test_pool().await);
+        let alert = AlertRecord {
+            id: None,
+            session_id: 1,
+            level: "WARN".to_string(),
+            event_type: "test".to_string(),
+            message: "msg".to_string(),
+            payload_json: None,
+            created_at: Utc::now(),
+        };
+        let first = repo.insert_alert(&alert).await.unwrap();
+        let second = repo.insert_alert(&alert).await.unwrap();
+        assert_eq!(second, first + 1);
+    }
 }
@@ -3,17 +3,19 @@
 //! 잔고 스냅샷 저장 및 조회.
 //! 라이브 전략 수행 중 계좌 가치 변동을 추적하는 감사(audit) 보조 도구.
 
+use crate::decimal::DbDecimal;
 use crate::error::DbError;
+use crate::pool::{DbPool, InsertedId};
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
-use sqlx::MySqlPool;
+use serde::{Deserialize, Serialize};
 use tracing::debug;
 
 /// 잔고 스냅샷 레코드.
 ///
 /// 하나의 스냅샷 트리거(PERIODIC, POS_ENT, POS_EXT)당
 /// Upbit(KRW) + Bybit(USDT) 2행이 동일한 `snapshot_group_id`로 기록된다.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BalanceSnapshotRow {
     /// 기록 시각 (application level에서 동일 값 강제).
     pub created_at: DateTime<Utc>,
@@ -50,17 +52,28 @@ pub struct BalanceSnapshotRow {
 /// balance_snapshots 테이블 Repository.
 #[derive(Debug, Clone)]
 pub struct BalanceSnapshotRepository {
-    pool: MySqlPool,
+    pool: DbPool,
 }
 
 impl BalanceSnapshotRepository {
     /// 새 Repository 생성.
-    pub fn new(pool: MySqlPool) -> Self {
+    pub fn new(pool: DbPool) -> Self {
         Self { pool }
     }
 
     /// 단일 스냅샷 INSERT. 생성된 ID 반환.
     pub async fn insert_snapshot(&self, row: &BalanceSnapshotRow) -> Result<i64, DbError> {
+        self.insert_snapshot_keyed(row, None).await
+    }
+
+    /// 단일 스냅샷 INSERT (DbWriter 멱등 키 지정).
+    ///
+    /// 같은 `write_key`의 행이 이미 있으면 새 행 없이 성공하며, 이때 반환 ID는 의미가 없습니다.
+    pub(crate) async fn insert_snapshot_keyed(
+        &self,
+        row: &BalanceSnapshotRow,
+        write_key: Option<&str>,
+    ) -> Result<i64, DbError> {
         debug!(
             session_id = row.session_id,
             snapshot_group_id = row.snapshot_group_id,
@@ -71,7 +84,7 @@ impl BalanceSnapshotRepository {
             "잔고 스냅샷 INSERT"
         );
 
-        let result = sqlx::query(
+        let sql = format!(
             r#"
             INSERT INTO balance_snapshots (
                 created_at, snapshot_group_id, session_id,
@@ -79,29 +92,34 @@ impl BalanceSnapshotRepository {
                 available, locked, coin_value, total,
                 position_id,
                 usd_krw, usdt_krw,
-                total_usd, total_usdt
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                total_usd, total_usdt, write_key
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            {}
             "#,
-        )
-        .bind(row.created_at)
-        .bind(row.snapshot_group_id)
-        .bind(row.session_id)
-        .bind(&row.record_type)
-        .bind(&row.cex)
-        .bind(&row.currency)
-        .bind(row.available)
-        .bind(row.locked)
-        .bind(row.coin_value)
-        .bind(row.total)
-        .bind(row.position_id)
-        .bind(row.usd_krw)
-        .bind(row.usdt_krw)
-        .bind(row.total_usd)
-        .bind(row.total_usdt)
-        .execute(&self.pool)
-        .await?;
-
-        let id = result.last_insert_id() as i64;
+            self.pool.backend().write_key_conflict_sql()
+        );
+        let id = crate::with_pool!(&self.pool, pool => {
+            sqlx::query(&sql)
+            .bind(row.created_at)
+            .bind(row.snapshot_group_id)
+            .bind(row.session_id)
+            .bind(&row.record_type)
+            .bind(&row.cex)
+            .bind(&row.currency)
+            .bind(DbDecimal(row.available))
+            .bind(DbDecimal(row.locked))
+            .bind(DbDecimal(row.coin_value))
+            .bind(DbDecimal(row.total))
+            .bind(row.position_id)
+            .bind(row.usd_krw)
+            .bind(row.usdt_krw)
+            .bind(DbDecimal(row.total_usd))
+            .bind(DbDecimal(row.total_usdt))
+            .bind(write_key)
+            .execute(pool)
+            .await?
+            .inserted_id()
+        });
         debug!(
             snapshot_id = id,
             cex = %row.cex,
@@ -198,9 +216,8 @@ mod tests {
     /// BalanceSnapshotRepository 생성 테스트 (pool mock 없이 구조체 확인).
     #[test]
     fn test_balance_snapshot_repository_debug() {
-        // MySqlPool은 mock 없이 직접 생성 불가하므로
         // Debug trait 구현 여부와 Clone 여부만 확인
-        // 실제 DB 연동 테스트는 통합 테스트에서 수행
+        // 실제 DB 연동은 test_insert_snapshot_sqlite에서 확인
         fn assert_debug_clone<T: std::fmt::Debug + Clone>() {}
         assert_debug_clone::<BalanceSnapshotRepository>();
     }
@@ -334,4 +351,41 @@ mod tests {
         assert_eq!(row.locked, Decimal::new(1, 8));
         assert_eq!(row.available, Decimal::new(123_456_789_012, 8));
     }
+
+    /// SQLite 백엔드 INSERT (Decimal TEXT 저장, 정밀도 보존) 테스트.
+    #[tokio::test]
+    async fn test_insert_snapshot_sqlite() {
+        let pool = crate::pool::test_pool().await;
+        let repo = BalanceSnapshotRepository::new(pool.clone());
+        let row = BalanceSnapshotRow {
+            created_at: Utc::now(),
+            snapshot_group_id: 1,
+            session_id: 1,
+            record_type: "POS_ENT".to_string(),
+            cex: "BYBIT".to_string(),
+            currency: "USDT".to_string(),
+            available: Decimal::new(123_456_789_012, 8),
+            locked: Decimal::new(1, 8),
+            coin_value: Decimal::new(-50, 0),
+            total: Decimal::new(123_456_789_013, 8),
+            position_id: Some(42),
+            usd_krw: 1350.0,
+            usdt_krw: 1380.0,
+            total_usd: Decimal::ONE,
+            total_usdt: Decimal::ONE,
+        };
+        let id = repo.insert_snapshot(&row).await.unwrap();
+
+        let crate::pool::DbPool::Sqlite(pool) = pool else {
+            panic!("expected sqlite pool");
+        };
+        let (available, coin_value): (DbDecimal, DbDecimal) =
+            sqlx::query_as("SELECT available, coin_value FROM balance_snapshots WHERE id = ?")
+                .bind(id)
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(available.0, row.available);
+        assert_eq!(coin_value.0, row.coin_value);
+    }
 }
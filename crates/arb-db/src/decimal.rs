@@ -0,0 +1,142 @@
+//! 백엔드 공통 Decimal 컬럼 인코딩.
+//!
+//! MySQL은 `DECIMAL(20,8)` 컬럼에 `rust_decimal::Decimal`을 그대로 바인딩하지만,
+//! sqlx SQLite 드라이버는 Decimal을 지원하지 않으므로 TEXT 컬럼에 문자열로 저장한다.
+//! Repository는 두 백엔드에서 같은 쿼리 본문을 쓰기 위해 이 래퍼로 바인딩/디코딩한다.
+
+use rust_decimal::Decimal;
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::mysql::{MySql, MySqlTypeInfo, MySqlValueRef};
+use sqlx::sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef};
+use sqlx::{Decode, Encode, Type};
+use std::str::FromStr;
+
+/// DB 바인딩/디코딩용 Decimal 래퍼.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DbDecimal(pub Decimal);
+
+impl From<Decimal> for DbDecimal {
+    fn from(value: Decimal) -> Self {
+        Self(value)
+    }
+}
+
+impl From<DbDecimal> for Decimal {
+    fn from(value: DbDecimal) -> Self {
+        value.0
+    }
+}
+
+impl Type<MySql> for DbDecimal {
+    fn type_info() -> MySqlTypeInfo {
+        <Decimal as Type<MySql>>::type_info()
+    }
+
+    fn compatible(ty: &MySqlTypeInfo) -> bool {
+        <Decimal as Type<MySql>>::compatible(ty)
+    }
+}
+
+impl Encode<'_, MySql> for DbDecimal {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> Result<IsNull, BoxDynError> {
+        <Decimal as Encode<MySql>>::encode_by_ref(&self.0, buf)
+    }
+}
+
+impl<'r> Decode<'r, MySql> for DbDecimal {
+    fn decode(value: MySqlValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(Self(<Decimal as Decode<MySql>>::decode(value)?))
+    }
+}
+
+impl Type<Sqlite> for DbDecimal {
+    fn type_info() -> SqliteTypeInfo {
+        <String as Type<Sqlite>>::type_info()
+    }
+
+    fn compatible(ty: &SqliteTypeInfo) -> bool {
+        // TEXT 외에 수동 입력 등으로 숫자 타입이 저장된 경우도 허용
+        <String as Type<Sqlite>>::compatible(ty)
+            || <f64 as Type<Sqlite>>::compatible(ty)
+            || <i64 as Type<Sqlite>>::compatible(ty)
+    }
+}
+
+impl<'q> Encode<'q, Sqlite> for DbDecimal {
+    fn encode_by_ref(&self, buf: &mut Vec<SqliteArgumentValue<'q>>) -> Result<IsNull, BoxDynError> {
+        <String as Encode<Sqlite>>::encode(self.0.to_string(), buf)
+    }
+}
+
+impl<'r> Decode<'r, Sqlite> for DbDecimal {
+    fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+        let text = <&str as Decode<Sqlite>>::decode(value)?;
+        let decimal = Decimal::from_str(text)
+            .or_else(|_| Decimal::from_scientific(text))
+            .map_err(|e| format!("invalid decimal '{}': {}", text, e))?;
+        Ok(Self(decimal))
+    }
+}
+
+/// `Option<Decimal>` 바인딩 헬퍼.
+pub(crate) fn opt(value: Option<Decimal>) -> Option<DbDecimal> {
+    value.map(DbDecimal)
+}
+
+/// `Option<DbDecimal>` 디코딩 결과 변환 헬퍼.
+pub(crate) fn unwrap_opt(value: Option<DbDecimal>) -> Option<Decimal> {
+    value.map(|d| d.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pool::{DbPool, DbPoolConfig};
+
+    #[test]
+    fn test_db_decimal_conversion() {
+        let d = Decimal::new(123_456_789, 8);
+        let wrapped: DbDecimal = d.into();
+        assert_eq!(Decimal::from(wrapped), d);
+        assert_eq!(opt(Some(d)), Some(DbDecimal(d)));
+        assert_eq!(unwrap_opt(None), None);
+    }
+
+    #[tokio::test]
+    async fn test_db_decimal_sqlite_roundtrip() {
+        let pool = DbPool::connect("sqlite::memory:", &DbPoolConfig::default())
+            .await
+            .unwrap();
+        let DbPool::Sqlite(pool) = pool else {
+            panic!("expected sqlite pool");
+        };
+
+        let value = Decimal::new(-123_456_789_012, 8);
+        let (decoded,): (DbDecimal,) = sqlx::query_as("SELECT ?")
+            .bind(DbDecimal(value))
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(decoded.0, value);
+        // 소수점 자리수 보존 (문자열 저장)
+        assert_eq!(decoded.0.to_string(), "-1234.56789012");
+    }
+
+    #[tokio::test]
+    async fn test_db_decimal_sqlite_decodes_numeric() {
+        let pool = DbPool::connect("sqlite::memory:", &DbPoolConfig::default())
+            .await
+            .unwrap();
+        let DbPool::Sqlite(pool) = pool else {
+            panic!("expected sqlite pool");
+        };
+
+        let (int_value, real_value): (DbDecimal, DbDecimal) = sqlx::query_as("SELECT 42, 0.5")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(int_value.0, Decimal::new(42, 0));
+        assert_eq!(real_value.0, Decimal::new(5, 1));
+    }
+}
@@ -24,4 +24,43 @@ pub enum DbError {
     /// 직렬화/역직렬화 오류.
     #[error("serialization error: {0}")]
     SerializationError(String),
+
+    /// spill journal 파일 I/O 실패.
+    #[error("spill journal error: {0}")]
+    SpillFailed(String),
+}
+
+impl DbError {
+    /// DB에 도달할 수 없는 상태(연결 끊김, 풀 고갈/종료 등)로 인한 에러인지 여부.
+    ///
+    /// `true`면 같은 요청을 나중에 다시 실행하면 성공할 수 있으므로
+    /// [`crate::writer::DbWriter`]가 spill journal에 보관합니다.
+    /// 제약 조건 위반 같은 쿼리 자체의 에러는 `false`입니다.
+    pub fn is_unavailable(&self) -> bool {
+        match self {
+            Self::ConnectionFailed(_) | Self::PoolExhausted => true,
+            Self::QueryFailed(e) => matches!(
+                e,
+                sqlx::Error::Io(_)
+                    | sqlx::Error::Tls(_)
+                    | sqlx::Error::PoolTimedOut
+                    | sqlx::Error::PoolClosed
+                    | sqlx::Error::WorkerCrashed
+            ),
+            Self::MigrationFailed(_) | Self::SerializationError(_) | Self::SpillFailed(_) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_unavailable() {
+        assert!(DbError::ConnectionFailed("refused".to_string()).is_unavailable());
+        assert!(DbError::QueryFailed(sqlx::Error::PoolClosed).is_unavailable());
+        assert!(!DbError::QueryFailed(sqlx::Error::RowNotFound).is_unavailable());
+        assert!(!DbError::SerializationError("bad".to_string()).is_unavailable());
+    }
 }
@@ -0,0 +1,273 @@
+//! execution_quality 테이블 Repository.
+//!
+//! 진입/청산 1회당 기대가 대비 체결 품질(TCA) 기록 저장 및 조회.
+//! `max_slippage_pct`/주문 유형 튜닝을 위해 (coin, order_type) 인덱스를 둔다.
+
+use crate::decimal::DbDecimal;
+use crate::error::DbError;
+use crate::pool::{DbPool, InsertedId};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::Row;
+use tracing::debug;
+
+/// 체결 품질 레코드.
+///
+/// 슬리피지/shortfall은 불리한 방향이 양수.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionQualityRecord {
+    pub id: Option<i64>,
+    pub session_id: i64,
+    pub position_id: i64,
+    pub coin: String,
+    /// "entry" | "exit".
+    pub side: String,
+    /// "limit_ioc" | "market".
+    pub order_type: String,
+    pub max_slippage_pct: Option<f64>,
+    pub expected_upbit_krw: Decimal,
+    pub actual_upbit_krw: Decimal,
+    pub expected_bybit: Decimal,
+    pub actual_bybit: Decimal,
+    pub upbit_qty: Decimal,
+    pub bybit_qty: Decimal,
+    pub usd_krw: f64,
+    pub upbit_slippage_bps: f64,
+    pub bybit_slippage_bps: f64,
+    pub slippage_cost_usdt: Decimal,
+    pub fees_usdt: Decimal,
+    pub implementation_shortfall_usdt: Decimal,
+    pub implementation_shortfall_bps: f64,
+    pub expected_profit_pct: Option<f64>,
+    pub safe_volume_usdt: Option<f64>,
+    pub upbit_latency_ms: i64,
+    pub bybit_latency_ms: i64,
+    pub fill_latency_ms: i64,
+    pub leg_gap_ms: i64,
+    pub executed_at: DateTime<Utc>,
+}
+
+/// execution_quality 테이블 Repository.
+#[derive(Debug, Clone)]
+pub struct ExecutionQualityRepository {
+    pool: DbPool,
+}
+
+impl ExecutionQualityRepository {
+    /// 새 Repository 생성.
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    /// 체결 품질 INSERT. 생성된 ID 반환.
+    pub async fn insert(&self, record: &ExecutionQualityRecord) -> Result<i64, DbError> {
+        self.insert_keyed(record, None).await
+    }
+
+    /// 체결 품질 INSERT (DbWriter 멱등 키 지정).
+    ///
+    /// 같은 `write_key`의 행이 이미 있으면 새 행 없이 성공하며, 이때 반환 ID는 의미가 없습니다.
+    pub(crate) async fn insert_keyed(
+        &self,
+        record: &ExecutionQualityRecord,
+        write_key: Option<&str>,
+    ) -> Result<i64, DbError> {
+        debug!(
+            session_id = record.session_id,
+            position_id = record.position_id,
+            coin = %record.coin,
+            side = %record.side,
+            order_type = %record.order_type,
+            "체결 품질 INSERT"
+        );
+
+        let sql = format!(
+            r#"
+            INSERT INTO execution_quality (
+                session_id, position_id, coin, side, order_type, max_slippage_pct,
+                expected_upbit_krw, actual_upbit_krw, expected_bybit, actual_bybit,
+                upbit_qty, bybit_qty, usd_krw,
+                upbit_slippage_bps, bybit_slippage_bps,
+                slippage_cost_usdt, fees_usdt,
+                implementation_shortfall_usdt, implementation_shortfall_bps,
+                expected_profit_pct, safe_volume_usdt,
+                upbit_latency_ms, bybit_latency_ms, fill_latency_ms, leg_gap_ms,
+                executed_at, write_key
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            {}
+            "#,
+            self.pool.backend().write_key_conflict_sql()
+        );
+        let id = crate::with_pool!(&self.pool, pool => {
+            sqlx::query(&sql)
+            .bind(record.session_id)
+            .bind(record.position_id)
+            .bind(&record.coin)
+            .bind(&record.side)
+            .bind(&record.order_type)
+            .bind(record.max_slippage_pct)
+            .bind(DbDecimal(record.expected_upbit_krw))
+            .bind(DbDecimal(record.actual_upbit_krw))
+            .bind(DbDecimal(record.expected_bybit))
+            .bind(DbDecimal(record.actual_bybit))
+            .bind(DbDecimal(record.upbit_qty))
+            .bind(DbDecimal(record.bybit_qty))
+            .bind(record.usd_krw)
+            .bind(record.upbit_slippage_bps)
+            .bind(record.bybit_slippage_bps)
+            .bind(DbDecimal(record.slippage_cost_usdt))
+            .bind(DbDecimal(record.fees_usdt))
+            .bind(DbDecimal(record.implementation_shortfall_usdt))
+            .bind(record.implementation_shortfall_bps)
+            .bind(record.expected_profit_pct)
+            .bind(record.safe_volume_usdt)
+            .bind(record.upbit_latency_ms)
+            .bind(record.bybit_latency_ms)
+            .bind(record.fill_latency_ms)
+            .bind(record.leg_gap_ms)
+            .bind(record.executed_at)
+            .bind(write_key)
+            .execute(pool)
+            .await?
+            .inserted_id()
+        });
+        debug!(execution_quality_id = id, "체결 품질 INSERT 완료");
+        Ok(id)
+    }
+
+    /// 특정 세션의 체결 품질 기록 조회.
+    pub async fn get_by_session(
+        &self,
+        session_id: i64,
+    ) -> Result<Vec<ExecutionQualityRecord>, DbError> {
+        debug!(session_id = session_id, "세션별 체결 품질 조회");
+
+        let records: Vec<ExecutionQualityRecord> = crate::with_pool!(&self.pool, pool => {
+            sqlx::query(
+                r#"
+                SELECT
+                    id, session_id, position_id, coin, side, order_type, max_slippage_pct,
+                    expected_upbit_krw, actual_upbit_krw, expected_bybit, actual_bybit,
+                    upbit_qty, bybit_qty, usd_krw,
+                    upbit_slippage_bps, bybit_slippage_bps,
+                    slippage_cost_usdt, fees_usdt,
+                    implementation_shortfall_usdt, implementation_shortfall_bps,
+                    expected_profit_pct, safe_volume_usdt,
+                    upbit_latency_ms, bybit_latency_ms, fill_latency_ms, leg_gap_ms,
+                    executed_at
+                FROM execution_quality
+                WHERE session_id = ?
+                ORDER BY id
+                "#,
+            )
+            .bind(session_id)
+            .fetch_all(pool)
+            .await?
+            .into_iter()
+            .map(|r| ExecutionQualityRecord {
+                id: Some(r.get("id")),
+                session_id: r.get("session_id"),
+                position_id: r.get("position_id"),
+                coin: r.get("coin"),
+                side: r.get("side"),
+                order_type: r.get("order_type"),
+                max_slippage_pct: r.get("max_slippage_pct"),
+                expected_upbit_krw: r.get::<DbDecimal, _>("expected_upbit_krw").0,
+                actual_upbit_krw: r.get::<DbDecimal, _>("actual_upbit_krw").0,
+                expected_bybit: r.get::<DbDecimal, _>("expected_bybit").0,
+                actual_bybit: r.get::<DbDecimal, _>("actual_bybit").0,
+                upbit_qty: r.get::<DbDecimal, _>("upbit_qty").0,
+                bybit_qty: r.get::<DbDecimal, _>("bybit_qty").0,
+                usd_krw: r.get("usd_krw"),
+                upbit_slippage_bps: r.get("upbit_slippage_bps"),
+                bybit_slippage_bps: r.get("bybit_slippage_bps"),
+                slippage_cost_usdt: r.get::<DbDecimal, _>("slippage_cost_usdt").0,
+                fees_usdt: r.get::<DbDecimal, _>("fees_usdt").0,
+                implementation_shortfall_usdt: r
+                    .get::<DbDecimal, _>("implementation_shortfall_usdt")
+                    .0,
+                implementation_shortfall_bps: r.get("implementation_shortfall_bps"),
+                expected_profit_pct: r.get("expected_profit_pct"),
+                safe_volume_usdt: r.get("safe_volume_usdt"),
+                upbit_latency_ms: r.get("upbit_latency_ms"),
+                bybit_latency_ms: r.get("bybit_latency_ms"),
+                fill_latency_ms: r.get("fill_latency_ms"),
+                leg_gap_ms: r.get("leg_gap_ms"),
+                executed_at: r.get("executed_at"),
+            })
+            .collect()
+        });
+
+        debug!(
+            session_id = session_id,
+            count = records.len(),
+            "세션별 체결 품질 조회 완료"
+        );
+        Ok(records)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_record(session_id: i64, side: &str) -> ExecutionQualityRecord {
+        ExecutionQualityRecord {
+            id: None,
+            session_id,
+            position_id: 10,
+            coin: "BTC".to_string(),
+            side: side.to_string(),
+            order_type: "limit_ioc".to_string(),
+            max_slippage_pct: Some(0.1),
+            expected_upbit_krw: Decimal::new(100_000_000, 0),
+            actual_upbit_krw: Decimal::new(1_001_000_005, 1),
+            expected_bybit: Decimal::new(70_000, 0),
+            actual_bybit: Decimal::new(6_996_512, 2),
+            upbit_qty: Decimal::new(123_456_789, 8),
+            bybit_qty: Decimal::new(123_400_000, 8),
+            usd_krw: 1400.5,
+            upbit_slippage_bps: 10.0,
+            bybit_slippage_bps: 4.98,
+            slippage_cost_usdt: Decimal::new(106_412_345, 8),
+            fees_usdt: Decimal::new(-12_345, 8),
+            implementation_shortfall_usdt: Decimal::new(106_400_000, 8),
+            implementation_shortfall_bps: 15.2,
+            expected_profit_pct: Some(0.25),
+            safe_volume_usdt: None,
+            upbit_latency_ms: 180,
+            bybit_latency_ms: 60,
+            fill_latency_ms: 180,
+            leg_gap_ms: 120,
+            executed_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execution_quality_roundtrip_sqlite() {
+        let repo = ExecutionQualityRepository::new(crate::pool::test_pool().await);
+        let record = make_record(7, "entry");
+        let id = repo.insert(&record).await.unwrap();
+
+        let records = repo.get_by_session(7).await.unwrap();
+        assert_eq!(records.len(), 1);
+        let r = &records[0];
+        assert_eq!(r.id, Some(id));
+        assert_eq!(r.side, "entry");
+        assert_eq!(r.order_type, "limit_ioc");
+        assert_eq!(r.max_slippage_pct, Some(0.1));
+        assert_eq!(r.actual_upbit_krw, record.actual_upbit_krw);
+        assert_eq!(r.actual_bybit, record.actual_bybit);
+        assert_eq!(r.upbit_qty, record.upbit_qty);
+        assert_eq!(r.fees_usdt, record.fees_usdt);
+        assert_eq!(
+            r.implementation_shortfall_usdt,
+            record.implementation_shortfall_usdt
+        );
+        assert!(r.safe_volume_usdt.is_none());
+        assert_eq!(r.leg_gap_ms, 120);
+        assert_eq!(r.executed_at, record.executed_at);
+        assert!(repo.get_by_session(8).await.unwrap().is_empty());
+    }
+}
@@ -3,12 +3,13 @@
 //! 종목별 펀딩 스케줄 관리 (UPSERT).
 
 use crate::error::DbError;
+use crate::pool::{DbBackend, DbPool};
 use chrono::{DateTime, Utc};
-use sqlx::MySqlPool;
+use serde::{Deserialize, Serialize};
 use tracing::debug;
 
 /// 펀딩 스케줄 레코드.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FundingScheduleRecord {
     pub id: Option<i64>,
     pub coin: String,
@@ -20,12 +21,12 @@ pub struct FundingScheduleRecord {
 /// funding_schedules 테이블 Repository.
 #[derive(Debug, Clone)]
 pub struct FundingRepository {
-    pool: MySqlPool,
+    pool: DbPool,
 }
 
 impl FundingRepository {
     /// 새 Repository 생성.
-    pub fn new(pool: MySqlPool) -> Self {
+    pub fn new(pool: DbPool) -> Self {
         Self { pool }
     }
 
@@ -39,22 +40,37 @@ impl FundingRepository {
             "펀딩 스케줄 UPSERT"
         );
 
-        sqlx::query(
-            r#"
-            INSERT INTO funding_schedules (coin, interval_hours, next_funding_time, current_rate)
-            VALUES (?, ?, ?, ?)
-            ON DUPLICATE KEY UPDATE
-                interval_hours = VALUES(interval_hours),
-                next_funding_time = VALUES(next_funding_time),
-                current_rate = VALUES(current_rate)
-            "#,
-        )
-        .bind(&record.coin)
-        .bind(record.interval_hours)
-        .bind(record.next_funding_time)
-        .bind(record.current_rate)
-        .execute(&self.pool)
-        .await?;
+        let sql = match self.pool.backend() {
+            DbBackend::MySql => {
+                r#"
+                INSERT INTO funding_schedules (coin, interval_hours, next_funding_time, current_rate)
+                VALUES (?, ?, ?, ?)
+                ON DUPLICATE KEY UPDATE
+                    interval_hours = VALUES(interval_hours),
+                    next_funding_time = VALUES(next_funding_time),
+                    current_rate = VALUES(current_rate)
+                "#
+            }
+            DbBackend::Sqlite => {
+                r#"
+                INSERT INTO funding_schedules (coin, interval_hours, next_funding_time, current_rate)
+                VALUES (?, ?, ?, ?)
+                ON CONFLICT (coin) DO UPDATE SET
+                    interval_hours = excluded.interval_hours,
+                    next_funding_time = excluded.next_funding_time,
+                    current_rate = excluded.current_rate
+                "#
+            }
+        };
+        crate::with_pool!(&self.pool, pool => {
+            sqlx::query(sql)
+                .bind(&record.coin)
+                .bind(record.interval_hours)
+                .bind(record.next_funding_time)
+                .bind(record.current_rate)
+                .execute(pool)
+                .await?;
+        });
 
         debug!(coin = %record.coin, "펀딩 스케줄 UPSERT 완료");
         Ok(())
@@ -64,15 +80,17 @@ impl FundingRepository {
     pub async fn get_all_funding(&self) -> Result<Vec<FundingScheduleRecord>, DbError> {
         debug!("전체 펀딩 스케줄 조회");
 
-        let rows = sqlx::query_as::<_, (i64, String, i32, DateTime<Utc>, f64)>(
-            r#"
-            SELECT id, coin, interval_hours, next_funding_time, current_rate
-            FROM funding_schedules
-            ORDER BY coin
-            "#,
-        )
-        .fetch_all(&self.pool)
-        .await?;
+        let rows = crate::with_pool!(&self.pool, pool => {
+            sqlx::query_as::<_, (i64, String, i32, DateTime<Utc>, f64)>(
+                r#"
+                SELECT id, coin, interval_hours, next_funding_time, current_rate
+                FROM funding_schedules
+                ORDER BY coin
+                "#,
+            )
+            .fetch_all(pool)
+            .await?
+        });
 
         let records: Vec<FundingScheduleRecord> = rows
             .into_iter()
@@ -123,4 +141,26 @@ mod tests {
         assert_eq!(record.id, Some(1));
         assert!(record.current_rate < 0.0);
     }
+
+    #[tokio::test]
+    async fn test_upsert_funding_sqlite() {
+        let repo = FundingRepository::new(crate::pool::test_pool().await);
+        let mut record = FundingScheduleRecord {
+            id: None,
+            coin: "BTC".to_string(),
+            interval_hours: 8,
+            next_funding_time: Utc::now(),
+            current_rate: 0.0001,
+        };
+        repo.upsert_funding(&record).await.unwrap();
+
+        record.interval_hours = 4;
+        record.current_rate = -0.0002;
+        repo.upsert_funding(&record).await.unwrap();
+
+        let all = repo.get_all_funding().await.unwrap();
+        assert_eq!(all.len(), 1);
+        assert_eq!(all[0].interval_hours, 4);
+        assert_eq!(all[0].current_rate, -0.0002);
+    }
 }
@@ -1,14 +1,16 @@
 //! # arb-db
 //!
-//! MySQL(sqlx) 기반 영속화 레이어.
+//! sqlx 기반 영속화 레이어 (MySQL / SQLite).
 //!
 //! 라이브 트레이딩 시스템의 세션, 포지션, 거래, 분봉, 알림, 펀딩 스케줄,
-//! 잔고 스냅샷을 MySQL DB에 영속화하는 모듈.
+//! 잔고 스냅샷, 체결 품질(TCA)을 DB에 영속화하는 모듈. 백엔드는 `database.url` scheme으로 선택한다
+//! (`mysql://...` 또는 `sqlite://...`).
 //!
 //! ## 구성
 //!
-//! - [`pool`]: MySQL 커넥션 풀 관리
+//! - [`pool`]: 백엔드별 커넥션 풀 관리
 //! - [`error`]: DB 에러 타입
+//! - [`decimal`]: 백엔드 공통 Decimal 컬럼 인코딩
 //! - [`sessions`]: 세션 CRUD
 //! - [`positions`]: 포지션 상태 머신 영속화 (PositionStore trait)
 //! - [`trades`]: 거래 기록
@@ -16,17 +18,36 @@
 //! - [`alerts`]: 알림 기록
 //! - [`funding`]: 펀딩 스케줄
 //! - [`balance_snapshots`]: 잔고 스냅샷 (계좌 가치 변동 추적)
+//! - [`execution_quality`]: 체결 품질 (기대가 대비 슬리피지/체결 지연)
 //! - [`writer`]: Background DB Writer (mpsc 채널 기반)
+//! - [`spill`]: DbWriter spill journal (DB 장애/채널 포화 시 디스크 보관 후 재생)
 //! - [`migration`]: 커스텀 마이그레이션 러너
 
+/// 백엔드별 풀로 같은 쿼리 본문을 실행.
+///
+/// 본문은 MySQL/SQLite 각각에 대해 한 번씩 전개되므로 양쪽 모두 지원하는 타입만 바인딩해야 한다.
+/// `Decimal`은 [`decimal::DbDecimal`]로 감싸서 사용한다.
+macro_rules! with_pool {
+    ($db:expr, $pool:ident => $body:expr) => {
+        match $db {
+            $crate::pool::DbPool::MySql($pool) => $body,
+            $crate::pool::DbPool::Sqlite($pool) => $body,
+        }
+    };
+}
+pub(crate) use with_pool;
+
 pub mod alerts;
 pub mod balance_snapshots;
+pub mod decimal;
 pub mod error;
+pub mod execution_quality;
 pub mod funding;
 pub mod migration;
 pub mod minutes;
 pub mod pool;
 pub mod positions;
 pub mod sessions;
+pub mod spill;
 pub mod trades;
 pub mod writer;
@@ -2,9 +2,11 @@
 //!
 //! `_migrations` 테이블을 자동 생성하고, `migrations/` 디렉토리의 SQL 파일을 순차 적용.
 //! MySQL DDL은 암묵적 COMMIT을 발생시키므로 파일당 하나의 DDL만 포함해야 함.
+//! SQLite DDL은 `migrations/sqlite/`에 같은 버전 번호로 두며, 트랜잭션 안에서 적용되므로
+//! 파일 하나에 여러 문장(인덱스, 트리거 등)을 포함할 수 있음.
 
 use crate::error::DbError;
-use sqlx::MySqlPool;
+use crate::pool::DbPool;
 use std::path::Path;
 use tracing::{debug, info, warn};
 
@@ -20,8 +22,9 @@ struct MigrationFile {
 ///
 /// # 인자
 ///
-/// * `pool` - MySQL 커넥션 풀
+/// * `pool` - DB 커넥션 풀
 /// * `migrations_dir` - 마이그레이션 SQL 파일 디렉토리 경로
+///   (백엔드별 경로는 [`DbBackend::migrations_dir`](crate::pool::DbBackend::migrations_dir) 참고)
 ///
 /// # 동작
 ///
@@ -30,7 +33,7 @@ struct MigrationFile {
 /// 3. 미적용 버전 필터링 + 버전 오름차순 정렬
 /// 4. 각 파일: BEGIN -> SQL 실행 -> INSERT INTO _migrations -> COMMIT
 /// 5. 실패 시 ROLLBACK + 에러 반환
-pub async fn run_migrations(pool: &MySqlPool, migrations_dir: &Path) -> Result<(), DbError> {
+pub async fn run_migrations(pool: &DbPool, migrations_dir: &Path) -> Result<(), DbError> {
     info!(
         dir = %migrations_dir.display(),
         "마이그레이션 시작"
@@ -81,31 +84,50 @@ pub async fn run_migrations(pool: &MySqlPool, migrations_dir: &Path) -> Result<(
 }
 
 /// `_migrations` 테이블 생성 (이미 존재하면 무시).
-async fn ensure_migrations_table(pool: &MySqlPool) -> Result<(), DbError> {
+async fn ensure_migrations_table(pool: &DbPool) -> Result<(), DbError> {
     debug!("_migrations 테이블 확인");
 
-    sqlx::query(
-        r#"
-        CREATE TABLE IF NOT EXISTS _migrations (
-            version     INT PRIMARY KEY,
-            name        VARCHAR(255) NOT NULL,
-            applied_at  DATETIME(3) NOT NULL DEFAULT CURRENT_TIMESTAMP(3)
+    let result = match pool {
+        DbPool::MySql(pool) => sqlx::query(
+            r#"
+                CREATE TABLE IF NOT EXISTS _migrations (
+                    version     INT PRIMARY KEY,
+                    name        VARCHAR(255) NOT NULL,
+                    applied_at  DATETIME(3) NOT NULL DEFAULT CURRENT_TIMESTAMP(3)
+                )
+                "#,
         )
-        "#,
-    )
-    .execute(pool)
-    .await
-    .map_err(|e| DbError::MigrationFailed(format!("failed to create _migrations table: {}", e)))?;
+        .execute(pool)
+        .await
+        .map(|_| ()),
+        DbPool::Sqlite(pool) => sqlx::query(
+            r#"
+                CREATE TABLE IF NOT EXISTS _migrations (
+                    version     INTEGER PRIMARY KEY,
+                    name        TEXT NOT NULL,
+                    applied_at  TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now'))
+                )
+                "#,
+        )
+        .execute(pool)
+        .await
+        .map(|_| ()),
+    };
+    result.map_err(|e| {
+        DbError::MigrationFailed(format!("failed to create _migrations table: {}", e))
+    })?;
 
     Ok(())
 }
 
 /// 적용된 마이그레이션 버전 목록 조회.
-async fn get_applied_versions(pool: &MySqlPool) -> Result<Vec<i32>, DbError> {
-    let rows = sqlx::query_as::<_, (i32,)>("SELECT version FROM _migrations ORDER BY version")
-        .fetch_all(pool)
-        .await
-        .map_err(|e| DbError::MigrationFailed(format!("failed to query _migrations: {}", e)))?;
+async fn get_applied_versions(pool: &DbPool) -> Result<Vec<i32>, DbError> {
+    let rows = crate::with_pool!(pool, pool => {
+        sqlx::query_as::<_, (i32,)>("SELECT version FROM _migrations ORDER BY version")
+            .fetch_all(pool)
+            .await
+    })
+    .map_err(|e| DbError::MigrationFailed(format!("failed to query _migrations: {}", e)))?;
 
     Ok(rows.into_iter().map(|(v,)| v).collect())
 }
@@ -180,7 +202,7 @@ fn parse_migration_filename(filename: &str) -> Result<(i32, String), DbError> {
 }
 
 /// 단일 마이그레이션 파일 적용.
-async fn apply_migration(pool: &MySqlPool, file: &MigrationFile) -> Result<(), DbError> {
+async fn apply_migration(pool: &DbPool, file: &MigrationFile) -> Result<(), DbError> {
     debug!(
         version = file.version,
         name = %file.name,
@@ -188,26 +210,52 @@ async fn apply_migration(pool: &MySqlPool, file: &MigrationFile) -> Result<(), D
         "마이그레이션 적용 시작"
     );
 
-    // MySQL DDL은 암묵적 COMMIT을 발생시키므로
-    // DDL 실행 후 INSERT INTO _migrations를 별도 실행
-    sqlx::query(&file.sql).execute(pool).await.map_err(|e| {
+    let execute_err = |e: sqlx::Error| {
         DbError::MigrationFailed(format!(
             "failed to execute V{:03}__{}: {}",
             file.version, file.name, e
         ))
-    })?;
-
-    sqlx::query("INSERT INTO _migrations (version, name) VALUES (?, ?)")
-        .bind(file.version)
-        .bind(&file.name)
-        .execute(pool)
-        .await
-        .map_err(|e| {
-            DbError::MigrationFailed(format!(
-                "failed to record migration V{:03}__{}: {}",
-                file.version, file.name, e
-            ))
-        })?;
+    };
+    let record_err = |e: sqlx::Error| {
+        DbError::MigrationFailed(format!(
+            "failed to record migration V{:03}__{}: {}",
+            file.version, file.name, e
+        ))
+    };
+
+    match pool {
+        DbPool::MySql(pool) => {
+            // MySQL DDL은 암묵적 COMMIT을 발생시키므로
+            // DDL 실행 후 INSERT INTO _migrations를 별도 실행
+            sqlx::query(&file.sql)
+                .execute(pool)
+                .await
+                .map_err(execute_err)?;
+
+            sqlx::query("INSERT INTO _migrations (version, name) VALUES (?, ?)")
+                .bind(file.version)
+                .bind(&file.name)
+                .execute(pool)
+                .await
+                .map_err(record_err)?;
+        }
+        DbPool::Sqlite(pool) => {
+            // SQLite DDL은 트랜잭션 안에서 실행 가능 → 실패 시 파일 단위 ROLLBACK
+            let mut tx = pool.begin().await.map_err(execute_err)?;
+            sqlx::raw_sql(&file.sql)
+                .execute(&mut *tx)
+                .await
+                .map_err(execute_err)?;
+
+            sqlx::query("INSERT INTO _migrations (version, name) VALUES (?, ?)")
+                .bind(file.version)
+                .bind(&file.name)
+                .execute(&mut *tx)
+                .await
+                .map_err(record_err)?;
+            tx.commit().await.map_err(record_err)?;
+        }
+    }
 
     Ok(())
 }
@@ -259,4 +307,55 @@ mod tests {
         let files = scan_migration_files(Path::new("/nonexistent/path")).unwrap();
         assert!(files.is_empty());
     }
+
+    #[test]
+    fn test_sqlite_migrations_match_mysql_versions() {
+        let base = Path::new(env!("CARGO_MANIFEST_DIR")).join("migrations");
+        let versions = |dir: &Path| {
+            let mut v: Vec<i32> = scan_migration_files(dir)
+                .unwrap()
+                .into_iter()
+                .map(|f| f.version)
+                .collect();
+            v.sort();
+            v
+        };
+        let mysql = versions(&base);
+        assert!(!mysql.is_empty());
+        assert_eq!(mysql, versions(&base.join("sqlite")));
+    }
+
+    #[test]
+    fn test_mysql_migrations_single_statement() {
+        // MySQL은 파일 전체를 한 번의 쿼리로 실행하므로 다중 문장이면 적용 실패
+        let base = Path::new(env!("CARGO_MANIFEST_DIR")).join("migrations");
+        for file in scan_migration_files(&base).unwrap() {
+            let statements = file
+                .sql
+                .lines()
+                .filter(|line| !line.trim_start().starts_with("--"))
+                .collect::<Vec<_>>()
+                .join("\n")
+                .split(';')
+                .filter(|stmt| !stmt.trim().is_empty())
+                .count();
+            assert_eq!(
+                statements, 1,
+                "V{:03}__{}: 문장 {}개",
+                file.version, file.name, statements
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_migrations_sqlite_idempotent() {
+        let pool = crate::pool::test_pool().await;
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("migrations/sqlite");
+        // 두 번째 실행은 모든 버전을 건너뜀
+        run_migrations(&pool, &dir).await.unwrap();
+
+        let applied = get_applied_versions(&pool).await.unwrap();
+        let files = scan_migration_files(&dir).unwrap();
+        assert_eq!(applied.len(), files.len());
+    }
 }
@@ -2,14 +2,16 @@
 //!
 //! 분봉 스프레드 데이터 저장 및 조회 (기존 minutes.csv 대체).
 
+use crate::decimal::{self, DbDecimal};
 use crate::error::DbError;
+use crate::pool::{DbPool, InsertedId};
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
-use sqlx::MySqlPool;
+use serde::{Deserialize, Serialize};
 use tracing::debug;
 
 /// 분봉 레코드.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MinuteRecord {
     pub id: Option<i64>,
     pub session_id: i64,
@@ -26,12 +28,12 @@ pub struct MinuteRecord {
 /// minutes 테이블 Repository.
 #[derive(Debug, Clone)]
 pub struct MinuteRepository {
-    pool: MySqlPool,
+    pool: DbPool,
 }
 
 impl MinuteRepository {
     /// 새 Repository 생성.
-    pub fn new(pool: MySqlPool) -> Self {
+    pub fn new(pool: DbPool) -> Self {
         Self { pool }
     }
 
@@ -45,36 +47,69 @@ impl MinuteRepository {
             "분봉 INSERT"
         );
 
-        let result = sqlx::query(
-            r#"
-            INSERT INTO minutes (
-                session_id, coin, ts,
-                upbit_close, bybit_close,
-                spread_pct, z_score, mean, stddev
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
-            ON DUPLICATE KEY UPDATE
-                id = LAST_INSERT_ID(id),
-                upbit_close = VALUES(upbit_close),
-                bybit_close = VALUES(bybit_close),
-                spread_pct = VALUES(spread_pct),
-                z_score = VALUES(z_score),
-                mean = VALUES(mean),
-                stddev = VALUES(stddev)
-            "#,
-        )
-        .bind(minute.session_id)
-        .bind(&minute.coin)
-        .bind(minute.ts)
-        .bind(minute.upbit_close)
-        .bind(minute.bybit_close)
-        .bind(minute.spread_pct)
-        .bind(minute.z_score)
-        .bind(minute.mean)
-        .bind(minute.stddev)
-        .execute(&self.pool)
-        .await?;
-
-        let id = result.last_insert_id() as i64;
+        // 같은 (session_id, coin, ts) 재기록 시 기존 행을 갱신하고 그 ID를 반환
+        let id = match &self.pool {
+            DbPool::MySql(pool) => sqlx::query(
+                r#"
+                INSERT INTO minutes (
+                    session_id, coin, ts,
+                    upbit_close, bybit_close,
+                    spread_pct, z_score, mean, stddev
+                ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+                ON DUPLICATE KEY UPDATE
+                    id = LAST_INSERT_ID(id),
+                    upbit_close = VALUES(upbit_close),
+                    bybit_close = VALUES(bybit_close),
+                    spread_pct = VALUES(spread_pct),
+                    z_score = VALUES(z_score),
+                    mean = VALUES(mean),
+                    stddev = VALUES(stddev)
+                "#,
+            )
+            .bind(minute.session_id)
+            .bind(&minute.coin)
+            .bind(minute.ts)
+            .bind(decimal::opt(minute.upbit_close))
+            .bind(decimal::opt(minute.bybit_close))
+            .bind(minute.spread_pct)
+            .bind(minute.z_score)
+            .bind(minute.mean)
+            .bind(minute.stddev)
+            .execute(pool)
+            .await?
+            .inserted_id(),
+            // SQLite upsert는 UPDATE 경로에서 last_insert_rowid를 갱신하지 않으므로 RETURNING 사용
+            DbPool::Sqlite(pool) => {
+                sqlx::query_scalar::<_, i64>(
+                    r#"
+                INSERT INTO minutes (
+                    session_id, coin, ts,
+                    upbit_close, bybit_close,
+                    spread_pct, z_score, mean, stddev
+                ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+                ON CONFLICT (session_id, coin, ts) DO UPDATE SET
+                    upbit_close = excluded.upbit_close,
+                    bybit_close = excluded.bybit_close,
+                    spread_pct = excluded.spread_pct,
+                    z_score = excluded.z_score,
+                    mean = excluded.mean,
+                    stddev = excluded.stddev
+                RETURNING id
+                "#,
+                )
+                .bind(minute.session_id)
+                .bind(&minute.coin)
+                .bind(minute.ts)
+                .bind(decimal::opt(minute.upbit_close))
+                .bind(decimal::opt(minute.bybit_close))
+                .bind(minute.spread_pct)
+                .bind(minute.z_score)
+                .bind(minute.mean)
+                .bind(minute.stddev)
+                .fetch_one(pool)
+                .await?
+            }
+        };
         debug!(minute_id = id, "분봉 INSERT 완료");
         Ok(id)
     }
@@ -86,34 +121,36 @@ impl MinuteRepository {
     ) -> Result<Vec<MinuteRecord>, DbError> {
         debug!(session_id = session_id, "세션별 분봉 조회");
 
-        let rows = sqlx::query_as::<
-            _,
-            (
-                i64,
-                i64,
-                String,
-                DateTime<Utc>,
-                Option<Decimal>,
-                Option<Decimal>,
-                Option<f64>,
-                Option<f64>,
-                Option<f64>,
-                Option<f64>,
-            ),
-        >(
-            r#"
-            SELECT
-                id, session_id, coin, ts,
-                upbit_close, bybit_close,
-                spread_pct, z_score, mean, stddev
-            FROM minutes
-            WHERE session_id = ?
-            ORDER BY ts
-            "#,
-        )
-        .bind(session_id)
-        .fetch_all(&self.pool)
-        .await?;
+        let rows = crate::with_pool!(&self.pool, pool => {
+            sqlx::query_as::<
+                _,
+                (
+                    i64,
+                    i64,
+                    String,
+                    DateTime<Utc>,
+                    Option<DbDecimal>,
+                    Option<DbDecimal>,
+                    Option<f64>,
+                    Option<f64>,
+                    Option<f64>,
+                    Option<f64>,
+                ),
+            >(
+                r#"
+                SELECT
+                    id, session_id, coin, ts,
+                    upbit_close, bybit_close,
+                    spread_pct, z_score, mean, stddev
+                FROM minutes
+                WHERE session_id = ?
+                ORDER BY ts
+                "#,
+            )
+            .bind(session_id)
+            .fetch_all(pool)
+            .await?
+        });
 
         let minutes: Vec<MinuteRecord> = rows
             .into_iter()
@@ -122,8 +159,8 @@ impl MinuteRepository {
                 session_id: r.1,
                 coin: r.2,
                 ts: r.3,
-                upbit_close: r.4,
-                bybit_close: r.5,
+                upbit_close: decimal::unwrap_opt(r.4),
+                bybit_close: decimal::unwrap_opt(r.5),
                 spread_pct: r.6,
                 z_score: r.7,
                 mean: r.8,
@@ -179,4 +216,41 @@ mod tests {
         assert!(record.upbit_close.is_none());
         assert!(record.z_score.is_none());
     }
+
+    #[tokio::test]
+    async fn test_insert_minute_upsert_sqlite() {
+        use chrono::TimeZone;
+
+        let repo = MinuteRepository::new(crate::pool::test_pool().await);
+        let ts = Utc.with_ymd_and_hms(2026, 3, 1, 0, 1, 0).unwrap();
+        let mut record = MinuteRecord {
+            id: None,
+            session_id: 1,
+            coin: "BTC".to_string(),
+            ts,
+            upbit_close: Some(Decimal::new(50_000_000, 0)),
+            bybit_close: Some(Decimal::new(35000, 0)),
+            spread_pct: Some(0.15),
+            z_score: None,
+            mean: None,
+            stddev: None,
+        };
+        let first = repo.insert_minute(&record).await.unwrap();
+
+        // 같은 (session_id, coin, ts) 재기록 → 기존 행 갱신, 같은 ID
+        record.z_score = Some(1.5);
+        record.bybit_close = Some(Decimal::new(350_015, 1));
+        let again = repo.insert_minute(&record).await.unwrap();
+        assert_eq!(again, first);
+
+        record.ts = ts + chrono::Duration::minutes(1);
+        let next = repo.insert_minute(&record).await.unwrap();
+        assert_ne!(next, first);
+
+        let minutes = repo.get_minutes_by_session(1).await.unwrap();
+        assert_eq!(minutes.len(), 2);
+        assert_eq!(minutes[0].ts, ts);
+        assert_eq!(minutes[0].z_score, Some(1.5));
+        assert_eq!(minutes[0].bybit_close, Some(Decimal::new(350_015, 1)));
+    }
 }
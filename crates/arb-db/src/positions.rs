@@ -2,10 +2,13 @@
 //!
 //! 포지션 상태 머신의 영속화를 담당. 낙관적 잠금 기반 상태 전이.
 
+use crate::decimal::{self, DbDecimal};
 use crate::error::DbError;
+use crate::pool::{DbPool, InsertedId};
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
-use sqlx::{MySqlPool, Row};
+use serde::{Deserialize, Serialize};
+use sqlx::Row;
 use std::future::Future;
 use tracing::debug;
 
@@ -19,7 +22,7 @@ pub enum TransitionResult {
 }
 
 /// 포지션 DB 레코드.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PositionRecord {
     pub id: Option<i64>,
     pub session_id: i64,
@@ -47,7 +50,7 @@ pub struct PositionRecord {
 }
 
 /// 포지션 업데이트 필드 (부분 업데이트용).
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct UpdateFields {
     pub upbit_order_id: Option<String>,
     pub bybit_order_id: Option<String>,
@@ -96,21 +99,26 @@ pub trait PositionStore: Send + Sync {
     fn remove(&self, id: i64) -> impl Future<Output = Result<(), DbError>> + Send;
 }
 
-/// MySQL 기반 PositionStore 구현.
+/// DB(MySQL/SQLite) 기반 PositionStore 구현.
 #[derive(Debug, Clone)]
 pub struct DbPositionStore {
-    pool: MySqlPool,
+    pool: DbPool,
 }
 
 impl DbPositionStore {
     /// 새 DbPositionStore 생성.
-    pub fn new(pool: MySqlPool) -> Self {
+    pub fn new(pool: DbPool) -> Self {
         Self { pool }
     }
-}
 
-impl PositionStore for DbPositionStore {
-    async fn save(&self, pos: &PositionRecord) -> Result<i64, DbError> {
+    /// 포지션 INSERT (DbWriter 멱등 키 지정).
+    ///
+    /// 같은 `write_key`의 행이 이미 있으면 새 행 없이 성공하며, 이때 반환 ID는 의미가 없습니다.
+    pub(crate) async fn save_keyed(
+        &self,
+        pos: &PositionRecord,
+        write_key: Option<&str>,
+    ) -> Result<i64, DbError> {
         debug!(
             session_id = pos.session_id,
             coin = %pos.coin,
@@ -121,7 +129,7 @@ impl PositionStore for DbPositionStore {
             "포지션 INSERT"
         );
 
-        let result = sqlx::query(
+        let sql = format!(
             r#"
             INSERT INTO positions (
                 session_id, coin, state, upbit_qty, bybit_qty,
@@ -131,39 +139,50 @@ impl PositionStore for DbPositionStore {
                 opened_at, closed_at, realized_pnl,
                 exit_upbit_order_id, exit_bybit_order_id,
                 client_order_id, exit_client_order_id,
-                in_flight, succeeded_leg, emergency_attempts
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                in_flight, succeeded_leg, emergency_attempts, write_key
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            {}
             "#,
-        )
-        .bind(pos.session_id)
-        .bind(&pos.coin)
-        .bind(&pos.state)
-        .bind(pos.upbit_qty)
-        .bind(pos.bybit_qty)
-        .bind(pos.upbit_entry_price)
-        .bind(pos.bybit_entry_price)
-        .bind(&pos.upbit_order_id)
-        .bind(&pos.bybit_order_id)
-        .bind(pos.entry_spread_pct)
-        .bind(pos.entry_z_score)
-        .bind(pos.entry_usd_krw)
-        .bind(pos.opened_at)
-        .bind(pos.closed_at)
-        .bind(pos.realized_pnl)
-        .bind(&pos.exit_upbit_order_id)
-        .bind(&pos.exit_bybit_order_id)
-        .bind(&pos.client_order_id)
-        .bind(&pos.exit_client_order_id)
-        .bind(pos.in_flight)
-        .bind(&pos.succeeded_leg)
-        .bind(pos.emergency_attempts)
-        .execute(&self.pool)
-        .await?;
-
-        let id = result.last_insert_id() as i64;
+            self.pool.backend().write_key_conflict_sql()
+        );
+        let id = crate::with_pool!(&self.pool, pool => {
+            sqlx::query(&sql)
+            .bind(pos.session_id)
+            .bind(&pos.coin)
+            .bind(&pos.state)
+            .bind(DbDecimal(pos.upbit_qty))
+            .bind(DbDecimal(pos.bybit_qty))
+            .bind(decimal::opt(pos.upbit_entry_price))
+            .bind(decimal::opt(pos.bybit_entry_price))
+            .bind(&pos.upbit_order_id)
+            .bind(&pos.bybit_order_id)
+            .bind(pos.entry_spread_pct)
+            .bind(pos.entry_z_score)
+            .bind(pos.entry_usd_krw)
+            .bind(pos.opened_at)
+            .bind(pos.closed_at)
+            .bind(decimal::opt(pos.realized_pnl))
+            .bind(&pos.exit_upbit_order_id)
+            .bind(&pos.exit_bybit_order_id)
+            .bind(&pos.client_order_id)
+            .bind(&pos.exit_client_order_id)
+            .bind(pos.in_flight)
+            .bind(&pos.succeeded_leg)
+            .bind(pos.emergency_attempts)
+            .bind(write_key)
+            .execute(pool)
+            .await?
+            .inserted_id()
+        });
         debug!(position_id = id, "포지션 INSERT 완료");
         Ok(id)
     }
+}
+
+impl PositionStore for DbPositionStore {
+    async fn save(&self, pos: &PositionRecord) -> Result<i64, DbError> {
+        self.save_keyed(pos, None).await
+    }
 
     async fn update_state(
         &self,
@@ -241,81 +260,89 @@ impl PositionStore for DbPositionStore {
             set_parts.join(", ")
         );
 
-        let mut query = sqlx::query(&sql);
-
-        // state (to) 바인딩
-        query = query.bind(to);
-
-        // 동적 필드 바인딩 (SET 절 추가 순서와 동일하게)
-        if let Some(ref v) = fields.upbit_order_id {
-            query = query.bind(v);
-        }
-        if let Some(ref v) = fields.bybit_order_id {
-            query = query.bind(v);
-        }
-        if let Some(v) = fields.upbit_qty {
-            query = query.bind(v);
-        }
-        if let Some(v) = fields.bybit_qty {
-            query = query.bind(v);
-        }
-        if let Some(v) = fields.upbit_entry_price {
-            query = query.bind(v);
-        }
-        if let Some(v) = fields.bybit_entry_price {
-            query = query.bind(v);
-        }
-        if let Some(v) = fields.entry_spread_pct {
-            query = query.bind(v);
-        }
-        if let Some(v) = fields.entry_z_score {
-            query = query.bind(v);
-        }
-        if let Some(v) = fields.entry_usd_krw {
-            query = query.bind(v);
-        }
-        if let Some(v) = fields.opened_at {
-            query = query.bind(v);
-        }
-        if let Some(v) = fields.closed_at {
-            query = query.bind(v);
-        }
-        if let Some(v) = fields.realized_pnl {
-            query = query.bind(v);
-        }
-        if let Some(ref v) = fields.exit_upbit_order_id {
-            query = query.bind(v);
-        }
-        if let Some(ref v) = fields.exit_bybit_order_id {
-            query = query.bind(v);
-        }
-        if let Some(ref v) = fields.exit_client_order_id {
-            query = query.bind(v);
-        }
-        if let Some(v) = fields.in_flight {
-            query = query.bind(v);
-        }
-        if let Some(ref v) = fields.succeeded_leg {
-            query = query.bind(v);
-        }
-        if let Some(v) = fields.emergency_attempts {
-            query = query.bind(v);
-        }
-
-        // WHERE 절 바인딩
-        query = query.bind(id);
-        query = query.bind(from);
-
-        let result = query.execute(&self.pool).await?;
-
-        if result.rows_affected() == 0 {
-            // 낙관적 잠금 실패: 현재 상태 조회
-            let current_state: Option<(String,)> =
-                sqlx::query_as("SELECT state FROM positions WHERE id = ?")
-                    .bind(id)
-                    .fetch_optional(&self.pool)
-                    .await?;
-
+        // 전이 실패(rows_affected == 0) 시에만 현재 상태 조회 결과를 Some으로 반환
+        let current_state: Option<Option<(String,)>> = crate::with_pool!(&self.pool, pool => {
+            let mut query = sqlx::query(&sql);
+
+            // state (to) 바인딩
+            query = query.bind(to);
+
+            // 동적 필드 바인딩 (SET 절 추가 순서와 동일하게)
+            if let Some(ref v) = fields.upbit_order_id {
+                query = query.bind(v);
+            }
+            if let Some(ref v) = fields.bybit_order_id {
+                query = query.bind(v);
+            }
+            if let Some(v) = fields.upbit_qty {
+                query = query.bind(DbDecimal(v));
+            }
+            if let Some(v) = fields.bybit_qty {
+                query = query.bind(DbDecimal(v));
+            }
+            if let Some(v) = fields.upbit_entry_price {
+                query = query.bind(DbDecimal(v));
+            }
+            if let Some(v) = fields.bybit_entry_price {
+                query = query.bind(DbDecimal(v));
+            }
+            if let Some(v) = fields.entry_spread_pct {
+                query = query.bind(v);
+            }
+            if let Some(v) = fields.entry_z_score {
+                query = query.bind(v);
+            }
+            if let Some(v) = fields.entry_usd_krw {
+                query = query.bind(v);
+            }
+            if let Some(v) = fields.opened_at {
+                query = query.bind(v);
+            }
+            if let Some(v) = fields.closed_at {
+                query = query.bind(v);
+            }
+            if let Some(v) = fields.realized_pnl {
+                query = query.bind(DbDecimal(v));
+            }
+            if let Some(ref v) = fields.exit_upbit_order_id {
+                query = query.bind(v);
+            }
+            if let Some(ref v) = fields.exit_bybit_order_id {
+                query = query.bind(v);
+            }
+            if let Some(ref v) = fields.exit_client_order_id {
+                query = query.bind(v);
+            }
+            if let Some(v) = fields.in_flight {
+                query = query.bind(v);
+            }
+            if let Some(ref v) = fields.succeeded_leg {
+                query = query.bind(v);
+            }
+            if let Some(v) = fields.emergency_attempts {
+                query = query.bind(v);
+            }
+
+            // WHERE 절 바인딩
+            query = query.bind(id);
+            query = query.bind(from);
+
+            let result = query.execute(pool).await?;
+
+            if result.rows_affected() == 0 {
+                // 낙관적 잠금 실패: 현재 상태 조회
+                Some(
+                    sqlx::query_as("SELECT state FROM positions WHERE id = ?")
+                        .bind(id)
+                        .fetch_optional(pool)
+                        .await?,
+                )
+            } else {
+                None
+            }
+        });
+
+        if let Some(current_state) = current_state {
             let state = current_state
                 .map(|(s,)| s)
                 .unwrap_or_else(|| "DELETED".to_string());
@@ -341,39 +368,38 @@ impl PositionStore for DbPositionStore {
     async fn load_open(&self, session_id: i64) -> Result<Vec<PositionRecord>, DbError> {
         debug!(session_id = session_id, "오픈 포지션 조회");
 
-        let rows = sqlx::query(
-            r#"
-            SELECT
-                id, session_id, coin, state,
-                upbit_qty, bybit_qty,
-                upbit_entry_price, bybit_entry_price,
-                upbit_order_id, bybit_order_id,
-                entry_spread_pct, entry_z_score, entry_usd_krw,
-                opened_at, closed_at,
-                realized_pnl,
-                exit_upbit_order_id, exit_bybit_order_id,
-                client_order_id, exit_client_order_id,
-                in_flight, succeeded_leg, emergency_attempts
-            FROM positions
-            WHERE session_id = ? AND state != 'Closed'
-            ORDER BY id
-            "#,
-        )
-        .bind(session_id)
-        .fetch_all(&self.pool)
-        .await?;
-
-        let positions: Vec<PositionRecord> = rows
+        let positions: Vec<PositionRecord> = crate::with_pool!(&self.pool, pool => {
+            sqlx::query(
+                r#"
+                SELECT
+                    id, session_id, coin, state,
+                    upbit_qty, bybit_qty,
+                    upbit_entry_price, bybit_entry_price,
+                    upbit_order_id, bybit_order_id,
+                    entry_spread_pct, entry_z_score, entry_usd_krw,
+                    opened_at, closed_at,
+                    realized_pnl,
+                    exit_upbit_order_id, exit_bybit_order_id,
+                    client_order_id, exit_client_order_id,
+                    in_flight, succeeded_leg, emergency_attempts
+                FROM positions
+                WHERE session_id = ? AND state != 'Closed'
+                ORDER BY id
+                "#,
+            )
+            .bind(session_id)
+            .fetch_all(pool)
+            .await?
             .into_iter()
             .map(|r| PositionRecord {
                 id: Some(r.get("id")),
                 session_id: r.get("session_id"),
                 coin: r.get("coin"),
                 state: r.get("state"),
-                upbit_qty: r.get("upbit_qty"),
-                bybit_qty: r.get("bybit_qty"),
-                upbit_entry_price: r.get("upbit_entry_price"),
-                bybit_entry_price: r.get("bybit_entry_price"),
+                upbit_qty: r.get::<DbDecimal, _>("upbit_qty").0,
+                bybit_qty: r.get::<DbDecimal, _>("bybit_qty").0,
+                upbit_entry_price: decimal::unwrap_opt(r.get("upbit_entry_price")),
+                bybit_entry_price: decimal::unwrap_opt(r.get("bybit_entry_price")),
                 upbit_order_id: r.get("upbit_order_id"),
                 bybit_order_id: r.get("bybit_order_id"),
                 entry_spread_pct: r.get("entry_spread_pct"),
@@ -381,7 +407,7 @@ impl PositionStore for DbPositionStore {
                 entry_usd_krw: r.get("entry_usd_krw"),
                 opened_at: r.get("opened_at"),
                 closed_at: r.get("closed_at"),
-                realized_pnl: r.get("realized_pnl"),
+                realized_pnl: decimal::unwrap_opt(r.get("realized_pnl")),
                 exit_upbit_order_id: r.get("exit_upbit_order_id"),
                 exit_bybit_order_id: r.get("exit_bybit_order_id"),
                 client_order_id: r.get("client_order_id"),
@@ -390,7 +416,8 @@ impl PositionStore for DbPositionStore {
                 succeeded_leg: r.get("succeeded_leg"),
                 emergency_attempts: r.get("emergency_attempts"),
             })
-            .collect();
+            .collect()
+        });
 
         debug!(
             session_id = session_id,
@@ -403,10 +430,12 @@ impl PositionStore for DbPositionStore {
     async fn remove(&self, id: i64) -> Result<(), DbError> {
         debug!(position_id = id, "포지션 DELETE");
 
-        sqlx::query("DELETE FROM positions WHERE id = ?")
-            .bind(id)
-            .execute(&self.pool)
-            .await?;
+        crate::with_pool!(&self.pool, pool => {
+            sqlx::query("DELETE FROM positions WHERE id = ?")
+                .bind(id)
+                .execute(pool)
+                .await?;
+        });
 
         debug!(position_id = id, "포지션 DELETE 완료");
         Ok(())
@@ -481,4 +510,98 @@ mod tests {
         assert_eq!(fields.in_flight, Some(true));
         assert!(fields.bybit_order_id.is_none());
     }
+
+    fn opening_record(session_id: i64, coin: &str) -> PositionRecord {
+        PositionRecord {
+            id: None,
+            session_id,
+            coin: coin.to_string(),
+            state: "Opening".to_string(),
+            upbit_qty: Decimal::new(123_456_789, 8),
+            bybit_qty: Decimal::new(123_456_789, 8),
+            upbit_entry_price: None,
+            bybit_entry_price: None,
+            upbit_order_id: None,
+            bybit_order_id: None,
+            entry_spread_pct: None,
+            entry_z_score: None,
+            entry_usd_krw: None,
+            opened_at: None,
+            closed_at: None,
+            realized_pnl: None,
+            exit_upbit_order_id: None,
+            exit_bybit_order_id: None,
+            client_order_id: Some(format!("{}-entry", coin)),
+            exit_client_order_id: None,
+            in_flight: true,
+            succeeded_leg: None,
+            emergency_attempts: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_position_store_sqlite_lifecycle() {
+        let store = DbPositionStore::new(crate::pool::test_pool().await);
+
+        let id = store.save(&opening_record(1, "BTC")).await.unwrap();
+        let other = store.save(&opening_record(1, "ETH")).await.unwrap();
+
+        let fields = UpdateFields {
+            upbit_entry_price: Some(Decimal::new(50_000_000, 0)),
+            bybit_entry_price: Some(Decimal::new(3_500_012, 2)),
+            bybit_qty: Some(Decimal::new(123_000_000, 8)),
+            entry_z_score: Some(2.5),
+            opened_at: Some(Utc::now()),
+            in_flight: Some(false),
+            ..Default::default()
+        };
+        let result = store
+            .update_state(id, "Opening", "Open", fields)
+            .await
+            .unwrap();
+        assert_eq!(result, TransitionResult::Applied);
+
+        // 낙관적 잠금: from 상태가 다르면 현재 상태 반환
+        let result = store
+            .update_state(id, "Opening", "Open", UpdateFields::default())
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            TransitionResult::AlreadyTransitioned("Open".to_string())
+        );
+
+        let open = store.load_open(1).await.unwrap();
+        assert_eq!(open.len(), 2);
+        let btc = open.iter().find(|p| p.id == Some(id)).unwrap();
+        assert_eq!(btc.state, "Open");
+        assert_eq!(btc.upbit_qty, Decimal::new(123_456_789, 8));
+        assert_eq!(btc.bybit_qty, Decimal::new(123_000_000, 8));
+        assert_eq!(btc.bybit_entry_price, Some(Decimal::new(3_500_012, 2)));
+        assert_eq!(btc.entry_z_score, Some(2.5));
+        assert!(btc.opened_at.is_some());
+        assert!(!btc.in_flight);
+        assert_eq!(btc.client_order_id.as_deref(), Some("BTC-entry"));
+
+        let fields = UpdateFields {
+            realized_pnl: Some(Decimal::new(-1234, 2)),
+            closed_at: Some(Utc::now()),
+            ..Default::default()
+        };
+        store
+            .update_state(id, "Open", "Closed", fields)
+            .await
+            .unwrap();
+        store.remove(other).await.unwrap();
+        assert!(store.load_open(1).await.unwrap().is_empty());
+
+        let result = store
+            .update_state(other, "Opening", "Open", UpdateFields::default())
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            TransitionResult::AlreadyTransitioned("DELETED".to_string())
+        );
+    }
 }
@@ -1,10 +1,14 @@
 //! sessions 테이블 Repository.
 //!
 //! 세션 생성, 종료, heartbeat 업데이트, crash 복구 처리.
+//! 전략 설정 핫 리로드 이력은 `config_changes` 컬럼에 JSON Lines로 누적한다.
+//! `config_json`은 세션 시작 시점의 전체 유효 설정이며, `config_hash`로 동일 설정 여부를 비교한다.
+//! 한 프로세스가 여러 전략 인스턴스를 실행하므로 세션은 `instance_name`으로 구분하고,
+//! crash recovery도 같은 인스턴스의 세션만 이어받는다.
 
 use crate::error::DbError;
+use crate::pool::{DbBackend, DbPool, InsertedId};
 use chrono::{DateTime, Utc};
-use sqlx::MySqlPool;
 use tracing::debug;
 
 /// 세션 레코드.
@@ -16,17 +20,66 @@ pub struct SessionRecord {
     pub ended_at: Option<DateTime<Utc>>,
     pub config_json: String,
     pub status: String,
+    /// 핫 리로드로 적용된 설정 변경 이력 (JSON Lines, 없으면 None).
+    pub config_changes: Option<String>,
+    /// `config_json` 해시 (SHA-256 hex). 해시 도입 이전 세션은 None.
+    pub config_hash: Option<String>,
+    /// 전략 인스턴스 이름 (단일 인스턴스 실행은 "default").
+    pub instance_name: String,
+}
+
+/// sessions 조회 컬럼 (`SessionRow` 순서와 일치).
+const SESSION_COLUMNS: &str = "id, parent_session_id, started_at, ended_at, config_json, status, \
+                               config_changes, config_hash, instance_name";
+
+type SessionRow = (
+    i64,
+    Option<i64>,
+    DateTime<Utc>,
+    Option<DateTime<Utc>>,
+    String,
+    String,
+    Option<String>,
+    Option<String>,
+    String,
+);
+
+impl From<SessionRow> for SessionRecord {
+    fn from(row: SessionRow) -> Self {
+        let (
+            id,
+            parent_session_id,
+            started_at,
+            ended_at,
+            config_json,
+            status,
+            config_changes,
+            config_hash,
+            instance_name,
+        ) = row;
+        Self {
+            id,
+            parent_session_id,
+            started_at,
+            ended_at,
+            config_json,
+            status,
+            config_changes,
+            config_hash,
+            instance_name,
+        }
+    }
 }
 
 /// sessions 테이블 Repository.
 #[derive(Debug, Clone)]
 pub struct SessionRepository {
-    pool: MySqlPool,
+    pool: DbPool,
 }
 
 impl SessionRepository {
     /// 새 Repository 생성.
-    pub fn new(pool: MySqlPool) -> Self {
+    pub fn new(pool: DbPool) -> Self {
         Self { pool }
     }
 
@@ -34,31 +87,43 @@ impl SessionRepository {
     ///
     /// # 인자
     ///
+    /// * `instance_name` - 전략 인스턴스 이름
     /// * `config_json` - 세션 설정 JSON (민감 필드 redact 완료 상태)
+    /// * `config_hash` - `config_json` 해시
     /// * `parent_session_id` - crash recovery 시 이전 세션 ID
     pub async fn create_session(
         &self,
+        instance_name: &str,
         config_json: &str,
+        config_hash: &str,
         parent_session_id: Option<i64>,
     ) -> Result<i64, DbError> {
         debug!(
+            instance_name,
             parent_session_id = ?parent_session_id,
             config_len = config_json.len(),
+            config_hash,
             "세션 INSERT"
         );
 
-        let result = sqlx::query(
+        let sql = format!(
             r#"
-            INSERT INTO sessions (parent_session_id, started_at, config_json, status)
-            VALUES (?, NOW(3), ?, 'Running')
+            INSERT INTO sessions
+                (parent_session_id, started_at, config_json, config_hash, instance_name, status)
+            VALUES (?, {}, ?, ?, ?, 'Running')
             "#,
-        )
-        .bind(parent_session_id)
-        .bind(config_json)
-        .execute(&self.pool)
-        .await?;
-
-        let id = result.last_insert_id() as i64;
+            self.pool.backend().now_sql()
+        );
+        let id = crate::with_pool!(&self.pool, pool => {
+            sqlx::query(&sql)
+                .bind(parent_session_id)
+                .bind(config_json)
+                .bind(config_hash)
+                .bind(instance_name)
+                .execute(pool)
+                .await?
+                .inserted_id()
+        });
         debug!(session_id = id, "세션 생성 완료");
         Ok(id)
     }
@@ -72,16 +137,20 @@ impl SessionRepository {
     pub async fn end_session(&self, id: i64, status: &str) -> Result<(), DbError> {
         debug!(session_id = id, status = status, "세션 종료 UPDATE");
 
-        sqlx::query(
+        let sql = format!(
             r#"
-            UPDATE sessions SET ended_at = NOW(3), status = ?
+            UPDATE sessions SET ended_at = {}, status = ?
             WHERE id = ?
             "#,
-        )
-        .bind(status)
-        .bind(id)
-        .execute(&self.pool)
-        .await?;
+            self.pool.backend().now_sql()
+        );
+        crate::with_pool!(&self.pool, pool => {
+            sqlx::query(&sql)
+                .bind(status)
+                .bind(id)
+                .execute(pool)
+                .await?;
+        });
 
         debug!(session_id = id, "세션 종료 완료");
         Ok(())
@@ -91,15 +160,56 @@ impl SessionRepository {
     pub async fn update_heartbeat(&self, id: i64) -> Result<(), DbError> {
         debug!(session_id = id, "heartbeat UPDATE");
 
-        sqlx::query(
+        let sql = format!(
             r#"
-            UPDATE sessions SET ended_at = NOW(3)
+            UPDATE sessions SET ended_at = {}
             WHERE id = ? AND status = 'Running'
             "#,
-        )
-        .bind(id)
-        .execute(&self.pool)
-        .await?;
+            self.pool.backend().now_sql()
+        );
+        crate::with_pool!(&self.pool, pool => {
+            sqlx::query(&sql)
+                .bind(id)
+                .execute(pool)
+                .await?;
+        });
+
+        Ok(())
+    }
+
+    /// 세션에 설정 변경 이력 한 건을 추가.
+    ///
+    /// 같은 줄이 이미 기록되어 있으면 추가하지 않습니다 (DbWriter 재시도/spill 재생 시 멱등).
+    /// 변경 내역에는 적용 시각이 들어 있어 서로 다른 변경이 같은 줄이 되지 않습니다.
+    ///
+    /// # 인자
+    ///
+    /// * `id` - 세션 ID
+    /// * `change_json` - 변경 내역 JSON 한 줄 (개행은 여기서 붙임)
+    pub async fn append_config_change(&self, id: i64, change_json: &str) -> Result<(), DbError> {
+        debug!(
+            session_id = id,
+            change_len = change_json.len(),
+            "설정 변경 이력 UPDATE"
+        );
+
+        let append = match self.pool.backend() {
+            DbBackend::MySql => "CONCAT(COALESCE(config_changes, ''), ?)",
+            DbBackend::Sqlite => "COALESCE(config_changes, '') || ?",
+        };
+        let sql = format!(
+            "UPDATE sessions SET config_changes = {append} \
+             WHERE id = ? AND INSTR(COALESCE(config_changes, ''), ?) = 0"
+        );
+        let line = format!("{change_json}\n");
+        crate::with_pool!(&self.pool, pool => {
+            sqlx::query(&sql)
+                .bind(&line)
+                .bind(id)
+                .bind(&line)
+                .execute(pool)
+                .await?;
+        });
 
         Ok(())
     }
@@ -117,60 +227,61 @@ impl SessionRepository {
             "세션 Crashed 마킹"
         );
 
-        sqlx::query(
+        let sql = format!(
             r#"
-            UPDATE sessions SET status = 'Crashed', ended_at = NOW(3)
+            UPDATE sessions SET status = 'Crashed', ended_at = {}
             WHERE id = ?
             "#,
-        )
-        .bind(id)
-        .execute(&self.pool)
-        .await?;
+            self.pool.backend().now_sql()
+        );
+        crate::with_pool!(&self.pool, pool => {
+            sqlx::query(&sql)
+                .bind(id)
+                .execute(pool)
+                .await?;
+        });
 
         debug!(session_id = id, "Crashed 마킹 완료");
         Ok(())
     }
 
-    /// 가장 최근 Running 세션 조회 (crash recovery용).
-    pub async fn find_last_running(&self) -> Result<Option<SessionRecord>, DbError> {
-        debug!("마지막 Running 세션 조회");
-
-        let row = sqlx::query_as::<
-            _,
-            (
-                i64,
-                Option<i64>,
-                DateTime<Utc>,
-                Option<DateTime<Utc>>,
-                String,
-                String,
-            ),
-        >(
-            r#"
-            SELECT id, parent_session_id, started_at, ended_at, config_json, status
-            FROM sessions
-            WHERE status = 'Running'
-            ORDER BY id DESC
-            LIMIT 1
-            "#,
-        )
-        .fetch_optional(&self.pool)
-        .await?;
-
-        let record = row.map(
-            |(id, parent_session_id, started_at, ended_at, config_json, status)| SessionRecord {
-                id,
-                parent_session_id,
-                started_at,
-                ended_at,
-                config_json,
-                status,
-            },
+    /// 인스턴스의 가장 최근 Running 세션 조회 (crash recovery용).
+    pub async fn find_last_running(
+        &self,
+        instance_name: &str,
+    ) -> Result<Option<SessionRecord>, DbError> {
+        debug!(instance_name, "마지막 Running 세션 조회");
+
+        let sql = format!(
+            "SELECT {SESSION_COLUMNS} FROM sessions \
+             WHERE status = 'Running' AND instance_name = ? ORDER BY id DESC LIMIT 1"
         );
+        let row = crate::with_pool!(&self.pool, pool => {
+            sqlx::query_as::<_, SessionRow>(&sql)
+                .bind(instance_name)
+                .fetch_optional(pool)
+                .await?
+        });
 
+        let record = row.map(SessionRecord::from);
         debug!(found = record.is_some(), "Running 세션 조회 완료");
         Ok(record)
     }
+
+    /// ID로 세션 조회 (설정 비교 도구용).
+    pub async fn find_by_id(&self, id: i64) -> Result<Option<SessionRecord>, DbError> {
+        debug!(session_id = id, "세션 조회");
+
+        let sql = format!("SELECT {SESSION_COLUMNS} FROM sessions WHERE id = ?");
+        let row = crate::with_pool!(&self.pool, pool => {
+            sqlx::query_as::<_, SessionRow>(&sql)
+                .bind(id)
+                .fetch_optional(pool)
+                .await?
+        });
+
+        Ok(row.map(SessionRecord::from))
+    }
 }
 
 #[cfg(test)]
@@ -186,6 +297,9 @@ mod tests {
             ended_at: None,
             config_json: "{}".to_string(),
             status: "Running".to_string(),
+            config_changes: None,
+            config_hash: None,
+            instance_name: "default".to_string(),
         };
         assert_eq!(record.id, 1);
         assert!(record.parent_session_id.is_none());
@@ -201,7 +315,83 @@ mod tests {
             ended_at: None,
             config_json: r#"{"key": "value"}"#.to_string(),
             status: "Running".to_string(),
+            config_changes: None,
+            config_hash: None,
+            instance_name: "default".to_string(),
         };
         assert_eq!(record.parent_session_id, Some(1));
     }
+
+    #[tokio::test]
+    async fn test_session_lifecycle_sqlite() {
+        let repo = SessionRepository::new(crate::pool::test_pool().await);
+
+        let first = repo
+            .create_session("default", "{}", "h1", None)
+            .await
+            .unwrap();
+        let second = repo
+            .create_session("default", r#"{"k":1}"#, "h2", Some(first))
+            .await
+            .unwrap();
+        assert!(second > first);
+
+        let by_id = repo.find_by_id(first).await.unwrap().unwrap();
+        assert_eq!(by_id.config_json, "{}");
+        assert_eq!(by_id.config_hash.as_deref(), Some("h1"));
+        assert!(repo.find_by_id(second + 1).await.unwrap().is_none());
+
+        let running = repo.find_last_running("default").await.unwrap().unwrap();
+        assert_eq!(running.id, second);
+        assert_eq!(running.parent_session_id, Some(first));
+        assert_eq!(running.status, "Running");
+        assert!(running.ended_at.is_none());
+
+        assert!(running.config_changes.is_none());
+        repo.append_config_change(second, r#"{"n":1}"#)
+            .await
+            .unwrap();
+        repo.append_config_change(second, r#"{"n":2}"#)
+            .await
+            .unwrap();
+        // 재생으로 같은 변경이 다시 들어와도 한 번만 기록
+        repo.append_config_change(second, r#"{"n":1}"#)
+            .await
+            .unwrap();
+        let running = repo.find_last_running("default").await.unwrap().unwrap();
+        assert_eq!(
+            running.config_changes.as_deref(),
+            Some("{\"n\":1}\n{\"n\":2}\n")
+        );
+
+        repo.update_heartbeat(second).await.unwrap();
+        let running = repo.find_last_running("default").await.unwrap().unwrap();
+        assert!(running.ended_at.unwrap() >= running.started_at);
+
+        repo.mark_crashed(second, first).await.unwrap();
+        assert_eq!(
+            repo.find_last_running("default").await.unwrap().unwrap().id,
+            first
+        );
+
+        repo.end_session(first, "Completed").await.unwrap();
+        assert!(repo.find_last_running("default").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_find_last_running_per_instance() {
+        let repo = SessionRepository::new(crate::pool::test_pool().await);
+
+        let btc = repo.create_session("btc", "{}", "h", None).await.unwrap();
+        let alts = repo.create_session("alts", "{}", "h", None).await.unwrap();
+
+        let running = repo.find_last_running("btc").await.unwrap().unwrap();
+        assert_eq!(running.id, btc);
+        assert_eq!(running.instance_name, "btc");
+        assert_eq!(
+            repo.find_last_running("alts").await.unwrap().unwrap().id,
+            alts
+        );
+        assert!(repo.find_last_running("default").await.unwrap().is_none());
+    }
 }
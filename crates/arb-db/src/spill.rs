@@ -0,0 +1,392 @@
+//! DbWriter spill journal.
+//!
+//! DB에 도달할 수 없거나 writer 채널이 가득 찬 동안 들어온 [`DbWriteRequest`]를
+//! 로컬 디스크에 append-only로 기록해 두고, DB가 복구되면 순서대로 재생한다.
+//! 프로세스가 재시작되어도 journal이 남아 있으므로 다음 기동 시 이어서 재생한다.
+//!
+//! ## 파일 구성 (`dir` 아래)
+//!
+//! - `journal.jsonl`: 한 줄에 엔트리 하나 (`{"seq":..,"write_key":..,"request":{..}}`), 기록마다 fsync
+//! - `cursor`: DB 반영이 끝난 마지막 seq (임시 파일에 쓴 뒤 rename으로 교체)
+//!
+//! ## 순서와 멱등성
+//!
+//! seq는 채널 경로를 포함한 모든 요청에 [`SpillJournal::next_seq`]로 부여된다.
+//! 채널에 있던 요청이 나중에 spill되더라도 seq 위치에 끼워 넣으므로 재생 순서는 항상
+//! 원래 요청 순서와 같다. 재생이 끝난 엔트리는 cursor로 표시되고, 로드 시 cursor 이하의
+//! 엔트리는 버린다. 대기 엔트리가 모두 반영되면 journal 파일을 비운다
+//! (cursor는 유지해 seq가 되돌아가지 않게 함).
+//!
+//! DB 반영 직후 cursor 갱신 전에 프로세스가 죽거나, 커밋된 쿼리의 응답이 유실되어
+//! 재시도하면 같은 엔트리가 다시 실행된다. 그래서 모든 요청이 DB 수준에서 멱등이어야 한다.
+//!
+//! - INSERT(포지션/거래/알림/잔고 스냅샷/체결 품질): 요청 생성 시 발급한 `write_key`(UUID)를
+//!   엔트리와 함께 기록하고 UNIQUE 인덱스로 중복 행을 막는다
+//! - 설정 변경 이력: 이미 기록된 줄이면 추가하지 않음
+//! - 포지션 상태 전이(낙관적 잠금), 분봉/펀딩 UPSERT, heartbeat, 세션 종료: 원래 멱등
+//!
+//! `write_key` 도입 전에 기록된 엔트리는 로드 시 새 키를 받으므로, 재시작을 넘는 중복은
+//! 막지 못한다.
+
+use crate::error::DbError;
+use crate::writer::{DbWriteRequest, new_write_key};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+
+/// journal 파일 이름.
+const JOURNAL_FILE: &str = "journal.jsonl";
+/// cursor 파일 이름.
+const CURSOR_FILE: &str = "cursor";
+
+/// DbWriter 요청 단위 (채널 전달 및 journal 엔트리).
+#[derive(Debug)]
+pub(crate) struct SpillEntry {
+    /// 요청 순번 (spill journal이 없으면 0).
+    pub(crate) seq: u64,
+    /// INSERT 요청의 멱등 키. 재시도/재생에도 같은 값을 사용.
+    pub(crate) write_key: Option<String>,
+    /// 원본 요청.
+    pub(crate) request: DbWriteRequest,
+}
+
+impl SpillEntry {
+    /// 순번을 붙이고, INSERT 요청이면 새 멱등 키를 발급합니다.
+    pub(crate) fn new(seq: u64, request: DbWriteRequest) -> Self {
+        Self {
+            seq,
+            write_key: new_write_key(&request),
+            request,
+        }
+    }
+}
+
+/// journal 한 줄 (기록용).
+#[derive(Serialize)]
+struct EntryRef<'a> {
+    seq: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    write_key: Option<&'a str>,
+    request: &'a DbWriteRequest,
+}
+
+/// journal 한 줄 (로드용).
+#[derive(Deserialize)]
+struct EntryOwned {
+    seq: u64,
+    #[serde(default)]
+    write_key: Option<String>,
+    request: DbWriteRequest,
+}
+
+/// 로컬 append-only spill journal.
+#[derive(Debug)]
+pub struct SpillJournal {
+    dir: PathBuf,
+    file: File,
+    pending: VecDeque<SpillEntry>,
+    /// 재생을 위해 꺼낸 엔트리 seq (ack/restore 전까지 활성 상태 유지).
+    replaying: Option<u64>,
+    next_seq: u64,
+}
+
+impl SpillJournal {
+    /// 디렉토리의 journal을 열고, 아직 반영되지 않은 엔트리를 로드합니다.
+    ///
+    /// 디렉토리가 없으면 생성합니다. 파싱할 수 없는 줄(기록 중 종료로 잘린 마지막 줄 등)은
+    /// 경고 후 건너뜁니다.
+    pub fn open(dir: impl AsRef<Path>) -> Result<Self, DbError> {
+        let dir = dir.as_ref().to_path_buf();
+        std::fs::create_dir_all(&dir).map_err(|e| io_error("create dir", &dir, e))?;
+
+        let cursor = read_cursor(&dir.join(CURSOR_FILE))?;
+        let journal_path = dir.join(JOURNAL_FILE);
+        let content = match std::fs::read(&journal_path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(io_error("read", &journal_path, e)),
+        };
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&journal_path)
+            .map_err(|e| io_error("open", &journal_path, e))?;
+        // 잘린 마지막 줄 뒤에 새 엔트리가 이어 붙지 않도록 줄바꿈 보정
+        if content.last().is_some_and(|b| *b != b'\n') {
+            file.write_all(b"\n")
+                .map_err(|e| io_error("append", &journal_path, e))?;
+        }
+
+        let mut entries = Vec::new();
+        let mut max_seq = cursor;
+        for (line_no, line) in content.split(|b| *b == b'\n').enumerate() {
+            if line.iter().all(u8::is_ascii_whitespace) {
+                continue;
+            }
+            match serde_json::from_slice::<EntryOwned>(line) {
+                Ok(entry) => {
+                    max_seq = max_seq.max(entry.seq);
+                    if entry.seq > cursor {
+                        let write_key = entry.write_key.or_else(|| new_write_key(&entry.request));
+                        entries.push(SpillEntry {
+                            seq: entry.seq,
+                            write_key,
+                            request: entry.request,
+                        });
+                    }
+                }
+                Err(e) => warn!(
+                    path = %journal_path.display(),
+                    line = line_no + 1,
+                    error = %e,
+                    "spill journal 엔트리 파싱 실패, 건너뜀"
+                ),
+            }
+        }
+        entries.sort_by_key(|e| e.seq);
+        entries.dedup_by_key(|e| e.seq);
+
+        let journal = Self {
+            dir,
+            file,
+            pending: entries.into(),
+            replaying: None,
+            next_seq: max_seq + 1,
+        };
+        if journal.pending.is_empty() {
+            journal.truncate()?;
+        } else {
+            info!(
+                dir = %journal.dir.display(),
+                pending = journal.pending.len(),
+                cursor = cursor,
+                "spill journal 미반영 엔트리 로드"
+            );
+        }
+        Ok(journal)
+    }
+
+    /// journal 디렉토리.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// 재생 대기 엔트리 수.
+    pub fn pending_len(&self) -> usize {
+        self.pending.len() + usize::from(self.replaying.is_some())
+    }
+
+    /// 대기 또는 재생 중인 엔트리가 있는지 여부.
+    ///
+    /// 활성 상태에서는 순서 보장을 위해 새 요청도 채널 대신 journal로 보내야 합니다.
+    pub fn is_active(&self) -> bool {
+        self.pending_len() > 0
+    }
+
+    /// 가장 앞선 미반영 엔트리의 seq.
+    pub(crate) fn first_seq(&self) -> Option<u64> {
+        self.replaying
+            .or_else(|| self.pending.front().map(|e| e.seq))
+    }
+
+    /// 새 요청 순번 발급.
+    pub(crate) fn next_seq(&mut self) -> u64 {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        seq
+    }
+
+    /// 엔트리를 journal에 기록하고 seq 순서 위치에 대기열로 넣습니다.
+    ///
+    /// 파일 기록이 실패해도 메모리 대기열에는 넣어 프로세스가 살아 있는 동안의 순서는 유지합니다.
+    /// 직렬화할 수 없는 요청(Shutdown)은 대기열에 넣지 않습니다.
+    pub(crate) fn append(&mut self, entry: SpillEntry) -> Result<(), DbError> {
+        let mut line = serde_json::to_string(&EntryRef {
+            seq: entry.seq,
+            write_key: entry.write_key.as_deref(),
+            request: &entry.request,
+        })
+        .map_err(|e| DbError::SerializationError(e.to_string()))?;
+        line.push('\n');
+
+        let written = self
+            .file
+            .write_all(line.as_bytes())
+            .and_then(|()| self.file.sync_data());
+
+        let pos = self.pending.partition_point(|e| e.seq < entry.seq);
+        self.pending.insert(pos, entry);
+
+        written.map_err(|e| io_error("append", &self.dir.join(JOURNAL_FILE), e))
+    }
+
+    /// 재생할 다음 엔트리를 꺼냅니다. 결과에 따라 [`Self::ack`] 또는 [`Self::restore`]를 호출해야 합니다.
+    pub(crate) fn take_front(&mut self) -> Option<SpillEntry> {
+        let entry = self.pending.pop_front()?;
+        self.replaying = Some(entry.seq);
+        Some(entry)
+    }
+
+    /// 재생에 실패한 엔트리를 맨 앞으로 되돌립니다.
+    pub(crate) fn restore(&mut self, entry: SpillEntry) {
+        self.replaying = None;
+        self.pending.push_front(entry);
+    }
+
+    /// 엔트리 반영 완료를 기록합니다. 대기열이 비면 journal 파일을 비웁니다.
+    pub(crate) fn ack(&mut self, seq: u64) -> Result<(), DbError> {
+        self.replaying = None;
+        write_cursor(&self.dir, seq)?;
+        if self.pending.is_empty() {
+            self.truncate()?;
+        }
+        Ok(())
+    }
+
+    fn truncate(&self) -> Result<(), DbError> {
+        self.file
+            .set_len(0)
+            .map_err(|e| io_error("truncate", &self.dir.join(JOURNAL_FILE), e))
+    }
+}
+
+fn io_error(op: &str, path: &Path, e: std::io::Error) -> DbError {
+    DbError::SpillFailed(format!("{op} {}: {e}", path.display()))
+}
+
+fn read_cursor(path: &Path) -> Result<u64, DbError> {
+    match std::fs::read_to_string(path) {
+        Ok(s) => s
+            .trim()
+            .parse()
+            .map_err(|e| DbError::SpillFailed(format!("invalid cursor {}: {e}", path.display()))),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(0),
+        Err(e) => Err(io_error("read", path, e)),
+    }
+}
+
+fn write_cursor(dir: &Path, seq: u64) -> Result<(), DbError> {
+    let tmp = dir.join(format!("{CURSOR_FILE}.tmp"));
+    let path = dir.join(CURSOR_FILE);
+    let mut file = File::create(&tmp).map_err(|e| io_error("create", &tmp, e))?;
+    file.write_all(seq.to_string().as_bytes())
+        .and_then(|()| file.sync_data())
+        .map_err(|e| io_error("write", &tmp, e))?;
+    std::fs::rename(&tmp, &path).map_err(|e| io_error("rename", &path, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("arb-db-spill-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn heartbeat(session_id: i64) -> DbWriteRequest {
+        DbWriteRequest::Heartbeat { session_id }
+    }
+
+    fn session_id(entry: &SpillEntry) -> i64 {
+        match entry.request {
+            DbWriteRequest::Heartbeat { session_id } => session_id,
+            _ => panic!("unexpected request"),
+        }
+    }
+
+    #[test]
+    fn test_reopen_keeps_unacked_entries_in_order() {
+        let dir = temp_dir("reopen");
+        {
+            let mut journal = SpillJournal::open(&dir).unwrap();
+            for id in 1..=3 {
+                let seq = journal.next_seq();
+                journal.append(SpillEntry::new(seq, heartbeat(id))).unwrap();
+            }
+            let first = journal.take_front().unwrap();
+            journal.ack(first.seq).unwrap();
+            assert_eq!(journal.pending_len(), 2);
+        }
+
+        // 재시작: 반영된 1번은 버리고 2, 3번만 재생 대상
+        let mut journal = SpillJournal::open(&dir).unwrap();
+        assert_eq!(journal.pending_len(), 2);
+        assert_eq!(session_id(&journal.take_front().unwrap()), 2);
+        assert!(journal.next_seq() > 3);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_older_seq_inserted_before_newer() {
+        let dir = temp_dir("order");
+        let mut journal = SpillJournal::open(&dir).unwrap();
+        let older = journal.next_seq();
+        let newer = journal.next_seq();
+        journal
+            .append(SpillEntry::new(newer, heartbeat(2)))
+            .unwrap();
+        journal
+            .append(SpillEntry::new(older, heartbeat(1)))
+            .unwrap();
+        assert_eq!(journal.first_seq(), Some(older));
+        drop(journal);
+
+        let mut journal = SpillJournal::open(&dir).unwrap();
+        assert_eq!(session_id(&journal.take_front().unwrap()), 1);
+        assert_eq!(journal.first_seq(), Some(older));
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_drained_journal_truncated_and_seq_monotonic() {
+        let dir = temp_dir("drain");
+        let mut journal = SpillJournal::open(&dir).unwrap();
+        let seq = journal.next_seq();
+        journal.append(SpillEntry::new(seq, heartbeat(1))).unwrap();
+
+        let entry = journal.take_front().unwrap();
+        assert!(journal.is_active());
+        journal.restore(entry);
+        let entry = journal.take_front().unwrap();
+        journal.ack(entry.seq).unwrap();
+        assert!(!journal.is_active());
+        assert_eq!(std::fs::metadata(dir.join(JOURNAL_FILE)).unwrap().len(), 0);
+        drop(journal);
+
+        let mut journal = SpillJournal::open(&dir).unwrap();
+        assert!(!journal.is_active());
+        assert!(journal.next_seq() > seq);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_torn_line_skipped() {
+        let dir = temp_dir("torn");
+        let mut journal = SpillJournal::open(&dir).unwrap();
+        let seq = journal.next_seq();
+        journal.append(SpillEntry::new(seq, heartbeat(1))).unwrap();
+        drop(journal);
+
+        let mut file = OpenOptions::new()
+            .append(true)
+            .open(dir.join(JOURNAL_FILE))
+            .unwrap();
+        file.write_all(br#"{"seq":2,"request":{"Heart"#).unwrap();
+
+        let mut journal = SpillJournal::open(&dir).unwrap();
+        assert_eq!(journal.pending_len(), 1);
+        let seq = journal.next_seq();
+        journal.append(SpillEntry::new(seq, heartbeat(3))).unwrap();
+        drop(journal);
+
+        // 잘린 줄 뒤에 기록한 엔트리도 온전히 로드됨
+        let journal = SpillJournal::open(&dir).unwrap();
+        assert_eq!(journal.pending_len(), 2);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -2,14 +2,16 @@
 //!
 //! 거래 기록 저장 및 조회.
 
+use crate::decimal::{self, DbDecimal};
 use crate::error::DbError;
+use crate::pool::{DbPool, InsertedId};
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
-use sqlx::MySqlPool;
+use serde::{Deserialize, Serialize};
 use tracing::debug;
 
 /// 거래 레코드.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TradeRecord {
     pub id: Option<i64>,
     pub session_id: i64,
@@ -32,17 +34,28 @@ pub struct TradeRecord {
 /// trades 테이블 Repository.
 #[derive(Debug, Clone)]
 pub struct TradeRepository {
-    pool: MySqlPool,
+    pool: DbPool,
 }
 
 impl TradeRepository {
     /// 새 Repository 생성.
-    pub fn new(pool: MySqlPool) -> Self {
+    pub fn new(pool: DbPool) -> Self {
         Self { pool }
     }
 
     /// 거래 기록 INSERT. 생성된 ID 반환.
     pub async fn insert_trade(&self, trade: &TradeRecord) -> Result<i64, DbError> {
+        self.insert_trade_keyed(trade, None).await
+    }
+
+    /// 거래 기록 INSERT (DbWriter 멱등 키 지정).
+    ///
+    /// 같은 `write_key`의 행이 이미 있으면 새 행 없이 성공하며, 이때 반환 ID는 의미가 없습니다.
+    pub(crate) async fn insert_trade_keyed(
+        &self,
+        trade: &TradeRecord,
+        write_key: Option<&str>,
+    ) -> Result<i64, DbError> {
         debug!(
             session_id = trade.session_id,
             position_id = trade.position_id,
@@ -52,36 +65,41 @@ impl TradeRepository {
             "거래 INSERT"
         );
 
-        let result = sqlx::query(
+        let sql = format!(
             r#"
             INSERT INTO trades (
                 session_id, position_id, coin, side, qty,
                 upbit_price_krw, bybit_price_usdt,
                 upbit_fee, bybit_fee,
                 spread_pct, z_score, realized_pnl, adjustment_cost,
-                exit_usd_krw, executed_at
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                exit_usd_krw, executed_at, write_key
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            {}
             "#,
-        )
-        .bind(trade.session_id)
-        .bind(trade.position_id)
-        .bind(&trade.coin)
-        .bind(&trade.side)
-        .bind(trade.qty)
-        .bind(trade.upbit_price_krw)
-        .bind(trade.bybit_price_usdt)
-        .bind(trade.upbit_fee)
-        .bind(trade.bybit_fee)
-        .bind(trade.spread_pct)
-        .bind(trade.z_score)
-        .bind(trade.realized_pnl)
-        .bind(trade.adjustment_cost)
-        .bind(trade.exit_usd_krw)
-        .bind(trade.executed_at)
-        .execute(&self.pool)
-        .await?;
-
-        let id = result.last_insert_id() as i64;
+            self.pool.backend().write_key_conflict_sql()
+        );
+        let id = crate::with_pool!(&self.pool, pool => {
+            sqlx::query(&sql)
+            .bind(trade.session_id)
+            .bind(trade.position_id)
+            .bind(&trade.coin)
+            .bind(&trade.side)
+            .bind(DbDecimal(trade.qty))
+            .bind(decimal::opt(trade.upbit_price_krw))
+            .bind(decimal::opt(trade.bybit_price_usdt))
+            .bind(decimal::opt(trade.upbit_fee))
+            .bind(decimal::opt(trade.bybit_fee))
+            .bind(trade.spread_pct)
+            .bind(trade.z_score)
+            .bind(decimal::opt(trade.realized_pnl))
+            .bind(decimal::opt(trade.adjustment_cost))
+            .bind(trade.exit_usd_krw)
+            .bind(trade.executed_at)
+            .bind(write_key)
+            .execute(pool)
+            .await?
+            .inserted_id()
+        });
         debug!(trade_id = id, "거래 INSERT 완료");
         Ok(id)
     }
@@ -93,44 +111,46 @@ impl TradeRepository {
     ) -> Result<Vec<TradeRecord>, DbError> {
         debug!(session_id = session_id, "세션별 거래 조회");
 
-        let rows = sqlx::query_as::<
-            _,
-            (
-                i64,
-                i64,
-                i64,
-                String,
-                String,
-                Decimal,
-                Option<Decimal>,
-                Option<Decimal>,
-                Option<Decimal>,
-                Option<Decimal>,
-                Option<f64>,
-                Option<f64>,
-                Option<Decimal>,
-                Option<Decimal>,
-                Option<f64>,
-                DateTime<Utc>,
-            ),
-        >(
-            r#"
-            SELECT
-                id, session_id, position_id, coin, side,
-                qty,
-                upbit_price_krw, bybit_price_usdt,
-                upbit_fee, bybit_fee,
-                spread_pct, z_score,
-                realized_pnl, adjustment_cost,
-                exit_usd_krw, executed_at
-            FROM trades
-            WHERE session_id = ?
-            ORDER BY id
-            "#,
-        )
-        .bind(session_id)
-        .fetch_all(&self.pool)
-        .await?;
+        let rows = crate::with_pool!(&self.pool, pool => {
+            sqlx::query_as::<
+                _,
+                (
+                    i64,
+                    i64,
+                    i64,
+                    String,
+                    String,
+                    DbDecimal,
+                    Option<DbDecimal>,
+                    Option<DbDecimal>,
+                    Option<DbDecimal>,
+                    Option<DbDecimal>,
+                    Option<f64>,
+                    Option<f64>,
+                    Option<DbDecimal>,
+                    Option<DbDecimal>,
+                    Option<f64>,
+                    DateTime<Utc>,
+                ),
+            >(
+                r#"
+                SELECT
+                    id, session_id, position_id, coin, side,
+                    qty,
+                    upbit_price_krw, bybit_price_usdt,
+                    upbit_fee, bybit_fee,
+                    spread_pct, z_score,
+                    realized_pnl, adjustment_cost,
+                    exit_usd_krw, executed_at
+                FROM trades
+                WHERE session_id = ?
+                ORDER BY id
+                "#,
+            )
+            .bind(session_id)
+            .fetch_all(pool)
+            .await?
+        });
 
         let trades: Vec<TradeRecord> = rows
             .into_iter()
@@ -140,15 +160,15 @@ impl TradeRepository {
                 position_id: r.2,
                 coin: r.3,
                 side: r.4,
-                qty: r.5,
-                upbit_price_krw: r.6,
-                bybit_price_usdt: r.7,
-                upbit_fee: r.8,
-                bybit_fee: r.9,
+                qty: r.5.0,
+                upbit_price_krw: decimal::unwrap_opt(r.6),
+                bybit_price_usdt: decimal::unwrap_opt(r.7),
+                upbit_fee: decimal::unwrap_opt(r.8),
+                bybit_fee: decimal::unwrap_opt(r.9),
                 spread_pct: r.10,
                 z_score: r.11,
-                realized_pnl: r.12,
-                adjustment_cost: r.13,
+                realized_pnl: decimal::unwrap_opt(r.12),
+                adjustment_cost: decimal::unwrap_opt(r.13),
                 exit_usd_krw: r.14,
                 executed_at: r.15,
             })
@@ -217,4 +237,40 @@ mod tests {
         assert!(record.realized_pnl.is_some());
         assert!(record.exit_usd_krw.is_some());
     }
+
+    #[tokio::test]
+    async fn test_trade_roundtrip_sqlite() {
+        let repo = TradeRepository::new(crate::pool::test_pool().await);
+        let record = TradeRecord {
+            id: None,
+            session_id: 7,
+            position_id: 10,
+            coin: "BTC".to_string(),
+            side: "exit".to_string(),
+            qty: Decimal::new(123_456_789, 8),
+            upbit_price_krw: Some(Decimal::new(1_234_567_891, 4)),
+            bybit_price_usdt: Some(Decimal::new(3_500_012, 2)),
+            upbit_fee: Some(Decimal::new(25, 2)),
+            bybit_fee: None,
+            spread_pct: Some(0.15),
+            z_score: Some(-1.0),
+            realized_pnl: Some(Decimal::new(-500, 2)),
+            adjustment_cost: None,
+            exit_usd_krw: Some(1350.5),
+            executed_at: Utc::now(),
+        };
+        let id = repo.insert_trade(&record).await.unwrap();
+
+        let trades = repo.get_trades_by_session(7).await.unwrap();
+        assert_eq!(trades.len(), 1);
+        let t = &trades[0];
+        assert_eq!(t.id, Some(id));
+        assert_eq!(t.qty, record.qty);
+        assert_eq!(t.upbit_price_krw, record.upbit_price_krw);
+        assert_eq!(t.bybit_price_usdt, record.bybit_price_usdt);
+        assert!(t.bybit_fee.is_none());
+        assert_eq!(t.realized_pnl, record.realized_pnl);
+        assert_eq!(t.executed_at, record.executed_at);
+        assert!(repo.get_trades_by_session(8).await.unwrap().is_empty());
+    }
 }
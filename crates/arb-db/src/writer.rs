@@ -2,25 +2,33 @@
 //!
 //! mpsc 채널을 통해 DB 쓰기 요청을 수신하고 단일 consumer task에서 처리.
 //! 전체 직렬 순서 보장 (단일 consumer).
+//!
+//! [`SpillJournal`]을 붙이면([`DbWriter::with_spill_journal`]) 채널 포화나 DB 장애 시
+//! 요청을 버리지 않고 디스크에 기록했다가 DB 복구 후 순서대로 재생한다.
 
 use crate::alerts::{AlertRecord, AlertRepository};
 use crate::balance_snapshots::{BalanceSnapshotRepository, BalanceSnapshotRow};
 use crate::error::DbError;
+use crate::execution_quality::{ExecutionQualityRecord, ExecutionQualityRepository};
 use crate::funding::{FundingRepository, FundingScheduleRecord};
 use crate::minutes::{MinuteRecord, MinuteRepository};
 use crate::positions::{DbPositionStore, PositionRecord, PositionStore, UpdateFields};
 use crate::sessions::SessionRepository;
+use crate::spill::{SpillEntry, SpillJournal};
 use crate::trades::{TradeRecord, TradeRepository};
-use std::sync::Arc;
-use std::sync::Mutex;
+use serde::{Deserialize, Serialize};
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
 use tokio::sync::mpsc;
 use tokio::sync::oneshot;
 use tokio::task::JoinHandle;
-use tracing::{debug, error, warn};
+use tracing::{debug, error, info, warn};
 
 /// DB 쓰기 요청 타입.
-#[derive(Debug)]
+///
+/// `Shutdown`을 제외한 요청은 spill journal에 JSON으로 기록된다.
+#[derive(Debug, Serialize, Deserialize)]
 pub enum DbWriteRequest {
     /// 포지션 INSERT.
     InsertPosition(PositionRecord),
@@ -41,11 +49,19 @@ pub enum DbWriteRequest {
     UpsertFunding(FundingScheduleRecord),
     /// 잔고 스냅샷 INSERT.
     InsertBalanceSnapshot(BalanceSnapshotRow),
+    /// 체결 품질(TCA) INSERT.
+    InsertExecutionQuality(ExecutionQualityRecord),
     /// 세션 상태 UPDATE.
     UpdateSession { id: i64, status: String },
     /// 세션 heartbeat UPDATE.
     Heartbeat { session_id: i64 },
+    /// 세션 설정 변경 이력 추가 (핫 리로드).
+    AppendSessionConfigChange {
+        session_id: i64,
+        change_json: String,
+    },
     /// Consumer 종료 요청.
+    #[serde(skip)]
     Shutdown {
         /// 종료 완료 ack 채널.
         ack: oneshot::Sender<()>,
@@ -61,6 +77,12 @@ pub struct DbWriterStats {
     pub final_failure_count: AtomicU64,
     /// 처리 완료 수.
     pub processed_count: AtomicU64,
+    /// spill journal에 기록된 요청 수.
+    pub spilled_count: AtomicU64,
+    /// spill journal에서 재생되어 반영된 요청 수.
+    pub replayed_count: AtomicU64,
+    /// spill journal 재생 대기 엔트리 수 (gauge).
+    pub spill_pending: AtomicU64,
 }
 
 impl Default for DbWriterStats {
@@ -69,18 +91,40 @@ impl Default for DbWriterStats {
             overflow_count: AtomicU64::new(0),
             processed_count: AtomicU64::new(0),
             final_failure_count: AtomicU64::new(0),
+            spilled_count: AtomicU64::new(0),
+            replayed_count: AtomicU64::new(0),
+            spill_pending: AtomicU64::new(0),
         }
     }
 }
 
+/// 채널로 전달되는 요청 (spill journal 순번, 멱등 키, 요청).
+///
+/// spill journal이 없으면 순번은 항상 0.
+type QueuedRequest = SpillEntry;
+
+/// Consumer가 사용하는 Repository 묶음.
+struct WriterRepos {
+    session: SessionRepository,
+    position: DbPositionStore,
+    trade: TradeRepository,
+    minute: MinuteRepository,
+    alert: AlertRepository,
+    funding: FundingRepository,
+    balance_snapshot: BalanceSnapshotRepository,
+    execution_quality: ExecutionQualityRepository,
+}
+
 /// Background DB Writer.
 ///
 /// mpsc::channel(256) bounded 채널로 요청 수신.
-/// 채널 full 시 요청 중요도에 따라 drop 또는 재대기 전송.
+/// spill journal이 없으면 채널 full 시 요청 중요도에 따라 drop 또는 재대기 전송,
+/// 있으면 모든 요청을 journal에 기록.
 #[derive(Debug, Clone)]
 pub struct DbWriter {
-    tx: mpsc::Sender<DbWriteRequest>,
+    tx: mpsc::Sender<QueuedRequest>,
     stats: Arc<DbWriterStats>,
+    spill: Option<Arc<Mutex<SpillJournal>>>,
     consumer_handle: Arc<Mutex<Option<JoinHandle<()>>>>,
 }
 
@@ -96,6 +140,7 @@ impl DbWriter {
     /// * `alert_repo` - 알림 Repository
     /// * `funding_repo` - 펀딩 Repository
     /// * `balance_snapshot_repo` - 잔고 스냅샷 Repository
+    /// * `execution_quality_repo` - 체결 품질 Repository
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         session_repo: SessionRepository,
@@ -105,30 +150,72 @@ impl DbWriter {
         alert_repo: AlertRepository,
         funding_repo: FundingRepository,
         balance_snapshot_repo: BalanceSnapshotRepository,
+        execution_quality_repo: ExecutionQualityRepository,
     ) -> Self {
+        let repos = WriterRepos {
+            session: session_repo,
+            position: position_store,
+            trade: trade_repo,
+            minute: minute_repo,
+            alert: alert_repo,
+            funding: funding_repo,
+            balance_snapshot: balance_snapshot_repo,
+            execution_quality: execution_quality_repo,
+        };
+        Self::spawn(repos, None)
+    }
+
+    /// spill journal을 사용하는 DbWriter 생성 및 consumer task 시작.
+    ///
+    /// 채널 포화 또는 DB 장애(재시도 초과) 시 요청을 `journal`에 기록하고,
+    /// DB가 응답하면 seq 순서대로 재생합니다. journal에 이전 실행의 미반영 엔트리가
+    /// 남아 있으면 기동 직후부터 재생합니다. 인자는 [`Self::new`]와 같습니다.
+    #[allow(clippy::too_many_arguments)]
+    pub fn with_spill_journal(
+        session_repo: SessionRepository,
+        position_store: DbPositionStore,
+        trade_repo: TradeRepository,
+        minute_repo: MinuteRepository,
+        alert_repo: AlertRepository,
+        funding_repo: FundingRepository,
+        balance_snapshot_repo: BalanceSnapshotRepository,
+        execution_quality_repo: ExecutionQualityRepository,
+        journal: SpillJournal,
+    ) -> Self {
+        let repos = WriterRepos {
+            session: session_repo,
+            position: position_store,
+            trade: trade_repo,
+            minute: minute_repo,
+            alert: alert_repo,
+            funding: funding_repo,
+            balance_snapshot: balance_snapshot_repo,
+            execution_quality: execution_quality_repo,
+        };
+        Self::spawn(repos, Some(journal))
+    }
+
+    fn spawn(repos: WriterRepos, journal: Option<SpillJournal>) -> Self {
         let (tx, rx) = mpsc::channel(256);
         let stats = Arc::new(DbWriterStats::default());
+        let spill = journal.map(|journal| {
+            stats
+                .spill_pending
+                .store(journal.pending_len() as u64, Ordering::Relaxed);
+            Arc::new(Mutex::new(journal))
+        });
 
         let consumer_stats = Arc::clone(&stats);
+        let consumer_spill = spill.clone();
         let handle = tokio::spawn(async move {
-            run_consumer(
-                rx,
-                session_repo,
-                position_store,
-                trade_repo,
-                minute_repo,
-                alert_repo,
-                funding_repo,
-                balance_snapshot_repo,
-                consumer_stats,
-            )
-            .await;
+            run_consumer(rx, repos, consumer_spill, consumer_stats).await;
         });
         let consumer_handle = Arc::new(Mutex::new(Some(handle)));
 
         Self {
             tx,
             stats,
+            spill,
             consumer_handle,
         }
     }
@@ -138,24 +225,32 @@ impl DbWriter {
     /// 채널이 가득 찬 경우:
     /// - 중요 요청(trades/alerts/positions/session): async send로 재대기
     /// - 비중요 요청(minutes/funding/snapshot/heartbeat): newest drop
+    ///
+    /// spill journal이 있으면 채널 full/closed 시 요청을 journal에 기록하고,
+    /// journal에 미반영 엔트리가 남아 있는 동안에는 순서 유지를 위해 채널을 거치지 않습니다.
     pub fn send(&self, request: DbWriteRequest) {
-        match self.tx.try_send(request) {
+        if let Some(spill) = &self.spill {
+            self.send_with_spill(spill, request);
+            return;
+        }
+
+        match self.tx.try_send(SpillEntry::new(0, request)) {
             Ok(()) => {}
-            Err(mpsc::error::TrySendError::Full(req)) => {
+            Err(mpsc::error::TrySendError::Full(entry)) => {
                 let overflow_count = self.stats.overflow_count.fetch_add(1, Ordering::Relaxed) + 1;
 
-                if is_critical_request(&req) {
+                if is_critical_request(&entry.request) {
                     if should_log_overflow(overflow_count) {
                         warn!(
                             overflow_count = overflow_count,
-                            request_type = describe_request(&req),
+                            request_type = describe_request(&entry.request),
                             "DB writer channel full, queueing critical request with async send"
                         );
                     }
                     let tx = self.tx.clone();
                     let stats = Arc::clone(&self.stats);
                     tokio::spawn(async move {
-                        if tx.send(req).await.is_err() {
+                        if tx.send(entry).await.is_err() {
                             stats.final_failure_count.fetch_add(1, Ordering::Relaxed);
                             error!("DB writer channel closed while sending critical request");
                         }
@@ -163,23 +258,60 @@ impl DbWriter {
                 } else if should_log_overflow(overflow_count) {
                     warn!(
                         overflow_count = overflow_count,
-                        request_type = describe_request(&req),
+                        request_type = describe_request(&entry.request),
                         "DB writer channel full, dropping newest request"
                     );
                 }
             }
-            Err(mpsc::error::TrySendError::Closed(req)) => {
+            Err(mpsc::error::TrySendError::Closed(entry)) => {
                 self.stats
                     .final_failure_count
                     .fetch_add(1, Ordering::Relaxed);
                 error!(
-                    request_type = describe_request(&req),
+                    request_type = describe_request(&entry.request),
                     "DB writer channel closed, request dropped"
                 );
             }
         }
     }
 
+    fn send_with_spill(&self, spill: &Mutex<SpillJournal>, request: DbWriteRequest) {
+        // 순번 발급과 채널 전송을 같은 잠금 안에서 수행해야 seq 순서 = 채널 순서가 보장됨
+        let mut journal = lock_journal(spill);
+        let entry = SpillEntry::new(journal.next_seq(), request);
+        if journal.is_active() {
+            spill_request(&mut journal, &self.stats, entry);
+            return;
+        }
+
+        match self.tx.try_send(entry) {
+            Ok(()) => {}
+            Err(mpsc::error::TrySendError::Full(entry)) => {
+                let overflow_count = self.stats.overflow_count.fetch_add(1, Ordering::Relaxed) + 1;
+                if should_log_overflow(overflow_count) {
+                    warn!(
+                        overflow_count = overflow_count,
+                        request_type = describe_request(&entry.request),
+                        "DB writer channel full, spilling request to journal"
+                    );
+                }
+                spill_request(&mut journal, &self.stats, entry);
+            }
+            Err(mpsc::error::TrySendError::Closed(entry)) => {
+                error!(
+                    request_type = describe_request(&entry.request),
+                    "DB writer channel closed, spilling request to journal"
+                );
+                spill_request(&mut journal, &self.stats, entry);
+            }
+        }
+    }
+
+    /// 통계 핸들 (외부 메트릭 수집용).
+    pub fn stats(&self) -> Arc<DbWriterStats> {
+        Arc::clone(&self.stats)
+    }
+
     /// 오버플로우 카운트 조회.
     pub fn overflow_count(&self) -> u64 {
         self.stats.overflow_count.load(Ordering::Relaxed)
@@ -195,6 +327,16 @@ impl DbWriter {
         self.stats.final_failure_count.load(Ordering::Relaxed)
     }
 
+    /// spill journal 기록 카운트 조회.
+    pub fn spilled_count(&self) -> u64 {
+        self.stats.spilled_count.load(Ordering::Relaxed)
+    }
+
+    /// spill journal 재생 대기 엔트리 수 조회.
+    pub fn spill_pending(&self) -> u64 {
+        self.stats.spill_pending.load(Ordering::Relaxed)
+    }
+
     /// Background consumer를 graceful shutdown 합니다.
     ///
     /// 1. Shutdown 요청 enqueue
@@ -212,7 +354,7 @@ impl DbWriter {
 
         let (ack_tx, ack_rx) = oneshot::channel();
         self.tx
-            .send(DbWriteRequest::Shutdown { ack: ack_tx })
+            .send(SpillEntry::new(0, DbWriteRequest::Shutdown { ack: ack_tx }))
             .await
             .map_err(|_| "db writer channel closed before shutdown request".to_string())?;
 
@@ -274,8 +416,10 @@ fn describe_request(req: &DbWriteRequest) -> &'static str {
         DbWriteRequest::InsertAlert(_) => "InsertAlert",
         DbWriteRequest::UpsertFunding(_) => "UpsertFunding",
         DbWriteRequest::InsertBalanceSnapshot(_) => "InsertBalanceSnapshot",
+        DbWriteRequest::InsertExecutionQuality(_) => "InsertExecutionQuality",
         DbWriteRequest::UpdateSession { .. } => "UpdateSession",
         DbWriteRequest::Heartbeat { .. } => "Heartbeat",
+        DbWriteRequest::AppendSessionConfigChange { .. } => "AppendSessionConfigChange",
         DbWriteRequest::Shutdown { .. } => "Shutdown",
     }
 }
@@ -289,7 +433,24 @@ fn is_critical_request(req: &DbWriteRequest) -> bool {
             | DbWriteRequest::InsertTrade(_)
             | DbWriteRequest::InsertAlert(_)
             | DbWriteRequest::UpdateSession { .. }
+            | DbWriteRequest::AppendSessionConfigChange { .. }
+    )
+}
+
+/// INSERT 요청이면 새 멱등 키(UUID)를 발급.
+///
+/// 키는 요청과 함께 재시도/spill 재생까지 유지되어 같은 행이 두 번 INSERT되지 않게 한다.
+/// 나머지 요청은 쿼리 자체가 멱등이므로 키가 없다.
+pub(crate) fn new_write_key(req: &DbWriteRequest) -> Option<String> {
+    matches!(
+        req,
+        DbWriteRequest::InsertPosition(_)
+            | DbWriteRequest::InsertTrade(_)
+            | DbWriteRequest::InsertAlert(_)
+            | DbWriteRequest::InsertBalanceSnapshot(_)
+            | DbWriteRequest::InsertExecutionQuality(_)
     )
+    .then(|| uuid::Uuid::new_v4().to_string())
 }
 
 /// overflow 로그 스팸 방지를 위한 샘플링 정책.
@@ -297,97 +458,234 @@ fn should_log_overflow(overflow_count: u64) -> bool {
     overflow_count <= 10 || overflow_count.is_multiple_of(100)
 }
 
+/// journal 잠금 (poison 시에도 내부 상태 사용).
+fn lock_journal(spill: &Mutex<SpillJournal>) -> MutexGuard<'_, SpillJournal> {
+    spill.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// 요청을 journal에 기록하고 통계를 갱신.
+fn spill_request(journal: &mut SpillJournal, stats: &DbWriterStats, entry: SpillEntry) {
+    let seq = entry.seq;
+    let request_type = describe_request(&entry.request);
+    let was_active = journal.is_active();
+    let before = journal.pending_len();
+
+    if let Err(e) = journal.append(entry) {
+        error!(
+            seq = seq,
+            request_type = request_type,
+            error = %e,
+            "spill journal 기록 실패"
+        );
+    }
+    if journal.pending_len() > before {
+        stats.spilled_count.fetch_add(1, Ordering::Relaxed);
+    } else {
+        stats.final_failure_count.fetch_add(1, Ordering::Relaxed);
+    }
+    stats
+        .spill_pending
+        .store(journal.pending_len() as u64, Ordering::Relaxed);
+
+    if !was_active {
+        warn!(
+            seq = seq,
+            request_type = request_type,
+            dir = %journal.dir().display(),
+            "DB 쓰기 spill 시작: DB 복구 시까지 요청을 journal에 기록"
+        );
+    }
+}
+
 /// 최대 재시도 횟수.
 const MAX_RETRIES: u32 = 3;
 
+/// spill journal 재생 재시도 간격 (최소/최대).
+const REPLAY_BACKOFF_MIN: Duration = Duration::from_secs(1);
+const REPLAY_BACKOFF_MAX: Duration = Duration::from_secs(30);
+
 /// Consumer task: 채널에서 요청을 받아 순차 처리.
-#[allow(clippy::too_many_arguments)]
+///
+/// spill journal이 활성 상태면 채널이 비어 있을 때 journal 엔트리를 하나씩 재생.
+/// 채널의 요청은 journal 엔트리보다 seq가 앞설 때만 바로 실행하고, 그렇지 않으면 journal로 보냄.
 async fn run_consumer(
-    mut rx: mpsc::Receiver<DbWriteRequest>,
-    session_repo: SessionRepository,
-    position_store: DbPositionStore,
-    trade_repo: TradeRepository,
-    minute_repo: MinuteRepository,
-    alert_repo: AlertRepository,
-    funding_repo: FundingRepository,
-    balance_snapshot_repo: BalanceSnapshotRepository,
+    mut rx: mpsc::Receiver<QueuedRequest>,
+    repos: WriterRepos,
+    spill: Option<Arc<Mutex<SpillJournal>>>,
     stats: Arc<DbWriterStats>,
 ) {
     debug!("DB writer consumer task 시작");
+    let mut replay_delay = Duration::ZERO;
+
+    loop {
+        let first_spilled = spill.as_deref().and_then(|s| lock_journal(s).first_seq());
+        let received = match (&spill, first_spilled) {
+            (Some(spill), Some(_)) => {
+                tokio::select! {
+                    biased;
+                    received = rx.recv() => received,
+                    _ = tokio::time::sleep(replay_delay) => {
+                        replay_delay = replay_next(spill, &repos, &stats, replay_delay).await;
+                        continue;
+                    }
+                }
+            }
+            _ => rx.recv().await,
+        };
+        let Some(entry) = received else {
+            break;
+        };
 
-    while let Some(request) = rx.recv().await {
-        if let DbWriteRequest::Shutdown { ack } = request {
+        if let DbWriteRequest::Shutdown { ack } = entry.request {
+            if let Some(spill) = &spill {
+                let pending = lock_journal(spill).pending_len();
+                if pending > 0 {
+                    warn!(
+                        pending = pending,
+                        "DB writer 종료: spill journal 미반영 엔트리는 다음 기동 시 재생"
+                    );
+                }
+            }
             let _ = ack.send(());
             debug!("DB writer shutdown 요청 수신");
             break;
         }
 
-        let request_type = describe_request(&request);
-        let mut last_err: Option<DbError> = None;
-
-        for attempt in 1..=MAX_RETRIES {
-            let result = execute_request(
-                &request,
-                &session_repo,
-                &position_store,
-                &trade_repo,
-                &minute_repo,
-                &alert_repo,
-                &funding_repo,
-                &balance_snapshot_repo,
-            )
-            .await;
-
-            match result {
-                Ok(()) => {
-                    stats.processed_count.fetch_add(1, Ordering::Relaxed);
-                    last_err = None;
-                    break;
+        // 앞선 요청이 journal에 있으면 순서 유지를 위해 뒤에 이어 기록
+        if let Some(spill) = &spill {
+            let mut journal = lock_journal(spill);
+            if journal.first_seq().is_some_and(|first| first < entry.seq) {
+                spill_request(&mut journal, &stats, entry);
+                continue;
+            }
+        }
+
+        let request_type = describe_request(&entry.request);
+        match execute_with_retry(&entry, &repos).await {
+            Ok(()) => {
+                stats.processed_count.fetch_add(1, Ordering::Relaxed);
+            }
+            Err(e) => match &spill {
+                Some(spill) if e.is_unavailable() => {
+                    spill_request(&mut lock_journal(spill), &stats, entry);
+                    replay_delay = REPLAY_BACKOFF_MIN;
                 }
-                Err(e) => {
-                    warn!(
+                _ => {
+                    stats.final_failure_count.fetch_add(1, Ordering::Relaxed);
+                    error!(
                         request_type = request_type,
-                        attempt = attempt,
                         error = %e,
-                        "DB write failed, retrying"
+                        "DB write failed after {} retries, giving up",
+                        MAX_RETRIES
                     );
-                    last_err = Some(e);
-                    // 재시도 전 짧은 대기
-                    tokio::time::sleep(std::time::Duration::from_millis(100 * attempt as u64))
-                        .await;
                 }
-            }
+            },
         }
+    }
 
-        if let Some(e) = last_err {
+    debug!("DB writer consumer task 종료");
+}
+
+/// journal 맨 앞 엔트리 하나를 재생하고 다음 재생까지의 대기 시간을 반환.
+///
+/// DB에 도달할 수 없으면 엔트리를 되돌리고 대기 시간을 늘림 (최대 [`REPLAY_BACKOFF_MAX`]).
+/// 쿼리 자체가 실패하는 엔트리는 재시도 초과 시 건너뜀 (채널 경로의 give-up과 동일).
+async fn replay_next(
+    spill: &Mutex<SpillJournal>,
+    repos: &WriterRepos,
+    stats: &DbWriterStats,
+    delay: Duration,
+) -> Duration {
+    let Some(entry) = lock_journal(spill).take_front() else {
+        return Duration::ZERO;
+    };
+    let seq = entry.seq;
+    let request_type = describe_request(&entry.request);
+
+    let result = execute_with_retry(&entry, repos).await;
+    let mut journal = lock_journal(spill);
+    let next_delay = match result {
+        Ok(()) => {
+            stats.processed_count.fetch_add(1, Ordering::Relaxed);
+            stats.replayed_count.fetch_add(1, Ordering::Relaxed);
+            if let Err(e) = journal.ack(seq) {
+                error!(seq = seq, error = %e, "spill journal cursor 기록 실패");
+            }
+            if !journal.is_active() {
+                info!(
+                    replayed = stats.replayed_count.load(Ordering::Relaxed),
+                    "spill journal 재생 완료"
+                );
+            }
+            Duration::ZERO
+        }
+        Err(e) if e.is_unavailable() => {
+            journal.restore(entry);
+            let next = (delay * 2).clamp(REPLAY_BACKOFF_MIN, REPLAY_BACKOFF_MAX);
+            warn!(
+                pending = journal.pending_len(),
+                retry_in_secs = next.as_secs(),
+                error = %e,
+                "DB 미복구, spill journal 재생 대기"
+            );
+            next
+        }
+        Err(e) => {
             stats.final_failure_count.fetch_add(1, Ordering::Relaxed);
             error!(
+                seq = seq,
                 request_type = request_type,
                 error = %e,
-                "DB write failed after {} retries, giving up",
-                MAX_RETRIES
+                "spill journal 엔트리 재생 실패, 건너뜀"
             );
+            if let Err(e) = journal.ack(seq) {
+                error!(seq = seq, error = %e, "spill journal cursor 기록 실패");
+            }
+            Duration::ZERO
+        }
+    };
+    stats
+        .spill_pending
+        .store(journal.pending_len() as u64, Ordering::Relaxed);
+    next_delay
+}
+
+/// 요청 실행 (최대 [`MAX_RETRIES`]회 재시도). 마지막 에러를 반환.
+///
+/// 재시도에도 같은 멱등 키를 사용하므로, 커밋 후 응답만 유실된 INSERT가 중복되지 않는다.
+async fn execute_with_retry(entry: &SpillEntry, repos: &WriterRepos) -> Result<(), DbError> {
+    let request_type = describe_request(&entry.request);
+    let mut last_err: Option<DbError> = None;
+
+    for attempt in 1..=MAX_RETRIES {
+        match execute_request(&entry.request, entry.write_key.as_deref(), repos).await {
+            Ok(()) => return Ok(()),
+            Err(e) => {
+                warn!(
+                    request_type = request_type,
+                    attempt = attempt,
+                    error = %e,
+                    "DB write failed, retrying"
+                );
+                last_err = Some(e);
+                // 재시도 전 짧은 대기
+                tokio::time::sleep(Duration::from_millis(100 * attempt as u64)).await;
+            }
         }
     }
 
-    debug!("DB writer consumer task 종료");
+    Err(last_err.unwrap_or(DbError::PoolExhausted))
 }
 
 /// 개별 요청 실행.
-#[allow(clippy::too_many_arguments)]
 async fn execute_request(
     request: &DbWriteRequest,
-    session_repo: &SessionRepository,
-    position_store: &DbPositionStore,
-    trade_repo: &TradeRepository,
-    minute_repo: &MinuteRepository,
-    alert_repo: &AlertRepository,
-    funding_repo: &FundingRepository,
-    balance_snapshot_repo: &BalanceSnapshotRepository,
+    write_key: Option<&str>,
+    repos: &WriterRepos,
 ) -> Result<(), DbError> {
     match request {
         DbWriteRequest::InsertPosition(pos) => {
-            position_store.save(pos).await?;
+            repos.position.save_keyed(pos, write_key).await?;
         }
         DbWriteRequest::UpdatePositionState {
             id,
@@ -395,30 +693,49 @@ async fn execute_request(
             to,
             fields,
         } => {
-            position_store
+            repos
+                .position
                 .update_state(*id, from, to, fields.clone())
                 .await?;
         }
         DbWriteRequest::InsertTrade(trade) => {
-            trade_repo.insert_trade(trade).await?;
+            repos.trade.insert_trade_keyed(trade, write_key).await?;
         }
         DbWriteRequest::InsertMinute(minute) => {
-            minute_repo.insert_minute(minute).await?;
+            repos.minute.insert_minute(minute).await?;
         }
         DbWriteRequest::InsertAlert(alert) => {
-            alert_repo.insert_alert(alert).await?;
+            repos.alert.insert_alert_keyed(alert, write_key).await?;
         }
         DbWriteRequest::UpsertFunding(funding) => {
-            funding_repo.upsert_funding(funding).await?;
+            repos.funding.upsert_funding(funding).await?;
         }
         DbWriteRequest::InsertBalanceSnapshot(row) => {
-            balance_snapshot_repo.insert_snapshot(row).await?;
+            repos
+                .balance_snapshot
+                .insert_snapshot_keyed(row, write_key)
+                .await?;
+        }
+        DbWriteRequest::InsertExecutionQuality(record) => {
+            repos
+                .execution_quality
+                .insert_keyed(record, write_key)
+                .await?;
         }
         DbWriteRequest::UpdateSession { id, status } => {
-            session_repo.end_session(*id, status).await?;
+            repos.session.end_session(*id, status).await?;
         }
         DbWriteRequest::Heartbeat { session_id } => {
-            session_repo.update_heartbeat(*session_id).await?;
+            repos.session.update_heartbeat(*session_id).await?;
+        }
+        DbWriteRequest::AppendSessionConfigChange {
+            session_id,
+            change_json,
+        } => {
+            repos
+                .session
+                .append_config_change(*session_id, change_json)
+                .await?;
         }
         DbWriteRequest::Shutdown { .. } => {
             // run_consumer 루프에서 선처리됨.
@@ -470,6 +787,45 @@ mod tests {
         assert_eq!(describe_request(&req), "InsertBalanceSnapshot");
     }
 
+    #[test]
+    fn test_describe_request_insert_execution_quality() {
+        use chrono::Utc;
+        use rust_decimal::Decimal;
+
+        let record = ExecutionQualityRecord {
+            id: None,
+            session_id: 1,
+            position_id: 2,
+            coin: "BTC".to_string(),
+            side: "exit".to_string(),
+            order_type: "market".to_string(),
+            max_slippage_pct: None,
+            expected_upbit_krw: Decimal::new(100_000_000, 0),
+            actual_upbit_krw: Decimal::new(99_990_000, 0),
+            expected_bybit: Decimal::new(70_000, 0),
+            actual_bybit: Decimal::new(70_007, 0),
+            upbit_qty: Decimal::new(1, 2),
+            bybit_qty: Decimal::new(1, 2),
+            usd_krw: 1400.0,
+            upbit_slippage_bps: 1.0,
+            bybit_slippage_bps: 1.0,
+            slippage_cost_usdt: Decimal::new(14, 2),
+            fees_usdt: Decimal::new(77, 2),
+            implementation_shortfall_usdt: Decimal::new(91, 2),
+            implementation_shortfall_bps: 13.0,
+            expected_profit_pct: None,
+            safe_volume_usdt: None,
+            upbit_latency_ms: 40,
+            bybit_latency_ms: 90,
+            fill_latency_ms: 90,
+            leg_gap_ms: 50,
+            executed_at: Utc::now(),
+        };
+        let req = DbWriteRequest::InsertExecutionQuality(record);
+        assert_eq!(describe_request(&req), "InsertExecutionQuality");
+        assert!(!is_critical_request(&req));
+    }
+
     #[test]
     fn test_describe_request_shutdown() {
         let (ack_tx, _ack_rx) = oneshot::channel();
@@ -493,7 +849,130 @@ mod tests {
         };
         assert!(is_critical_request(&critical));
 
+        let config_change = DbWriteRequest::AppendSessionConfigChange {
+            session_id: 1,
+            change_json: "{}".to_string(),
+        };
+        assert!(is_critical_request(&config_change));
+
         let non_critical = DbWriteRequest::Heartbeat { session_id: 1 };
         assert!(!is_critical_request(&non_critical));
     }
+
+    fn writer_with_spill(pool: &crate::pool::DbPool, journal: SpillJournal) -> DbWriter {
+        DbWriter::with_spill_journal(
+            SessionRepository::new(pool.clone()),
+            DbPositionStore::new(pool.clone()),
+            TradeRepository::new(pool.clone()),
+            MinuteRepository::new(pool.clone()),
+            AlertRepository::new(pool.clone()),
+            FundingRepository::new(pool.clone()),
+            BalanceSnapshotRepository::new(pool.clone()),
+            ExecutionQualityRepository::new(pool.clone()),
+            journal,
+        )
+    }
+
+    fn alert(message: &str) -> DbWriteRequest {
+        DbWriteRequest::InsertAlert(AlertRecord {
+            id: None,
+            session_id: 1,
+            level: "WARN".to_string(),
+            event_type: "test".to_string(),
+            message: message.to_string(),
+            payload_json: None,
+            created_at: chrono::Utc::now(),
+        })
+    }
+
+    async fn alert_messages(pool: &crate::pool::DbPool) -> Vec<String> {
+        crate::with_pool!(pool, p => {
+            sqlx::query_scalar("SELECT message FROM alerts ORDER BY id")
+                .fetch_all(p)
+                .await
+                .unwrap()
+        })
+    }
+
+    fn temp_spill_dir(name: &str) -> std::path::PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("arb-db-writer-spill-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[tokio::test]
+    async fn test_spill_on_outage_and_replay_after_restart() {
+        let dir = temp_spill_dir("outage");
+        let shutdown = |writer: DbWriter| async move {
+            writer
+                .shutdown_with_timeouts(Duration::from_secs(5), Duration::from_secs(10))
+                .await
+                .unwrap();
+        };
+
+        // DB 장애: 풀이 닫혀 있으면 요청이 journal에 보관됨
+        let down = crate::pool::test_pool().await;
+        if let crate::pool::DbPool::Sqlite(p) = &down {
+            p.close().await;
+        }
+        let writer = writer_with_spill(&down, SpillJournal::open(&dir).unwrap());
+        writer.send(alert("first"));
+        writer.send(alert("second"));
+        while writer.spill_pending() < 2 {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        assert_eq!(writer.spilled_count(), 2);
+        assert_eq!(writer.final_failure_count(), 0);
+        shutdown(writer).await;
+
+        // 재기동 후 정상 DB: 순서대로 재생하고 journal을 비움
+        let healthy = crate::pool::test_pool().await;
+        let writer = writer_with_spill(&healthy, SpillJournal::open(&dir).unwrap());
+        assert_eq!(writer.spill_pending(), 2);
+        writer.send(alert("third"));
+        while writer.processed_count() < 3 {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        assert_eq!(alert_messages(&healthy).await, ["first", "second", "third"]);
+        assert_eq!(writer.spill_pending(), 0);
+        shutdown(writer).await;
+
+        // 한 번 더 재기동해도 이미 반영된 엔트리는 재생되지 않음
+        assert!(!SpillJournal::open(&dir).unwrap().is_active());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_replay_of_applied_entry_does_not_duplicate() {
+        let dir = temp_spill_dir("applied");
+        let pool = crate::pool::test_pool().await;
+
+        // DB 반영 후 cursor 갱신 전에 종료된 상황: 엔트리는 journal에 남아 있고 행도 존재
+        let mut journal = SpillJournal::open(&dir).unwrap();
+        let entry = SpillEntry::new(journal.next_seq(), alert("applied"));
+        let DbWriteRequest::InsertAlert(record) = &entry.request else {
+            unreachable!()
+        };
+        AlertRepository::new(pool.clone())
+            .insert_alert_keyed(record, entry.write_key.as_deref())
+            .await
+            .unwrap();
+        journal.append(entry).unwrap();
+        drop(journal);
+
+        let writer = writer_with_spill(&pool, SpillJournal::open(&dir).unwrap());
+        assert_eq!(writer.spill_pending(), 1);
+        writer.send(alert("next"));
+        while writer.processed_count() < 2 {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        assert_eq!(alert_messages(&pool).await, ["applied", "next"]);
+        assert_eq!(writer.final_failure_count(), 0);
+        writer
+            .shutdown_with_timeouts(Duration::from_secs(5), Duration::from_secs(10))
+            .await
+            .unwrap();
+        let _ = std::fs::remove_dir_all(&dir);
+    }
 }
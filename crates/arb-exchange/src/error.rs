@@ -97,6 +97,32 @@ impl ExchangeError {
             Self::UnknownError { .. } => false,
         }
     }
+
+    /// 주문 발주 실패 시 거래소 접수 여부를 알 수 없는 에러인지 판별합니다.
+    ///
+    /// 응답 유실/파싱 실패, 거래소 내부 에러처럼 요청이 거래소에 도달했을 수 있는 경우 true이며,
+    /// 이때는 재발주 전에 client order id로 주문을 조회해야 합니다.
+    /// 연결 자체가 실패했거나 거래소가 명시적으로 거부한 경우는 false입니다.
+    pub fn is_ambiguous(&self) -> bool {
+        match self {
+            Self::HttpError(e) => !(e.is_connect() || e.is_builder()),
+            Self::JsonError(_) => true,
+            Self::ParseError(_) => true,
+            Self::ExchangeOffline(_) => true,
+            Self::InternalError(_) => true,
+            Self::UnknownError { .. } => true,
+            Self::RateLimitExceeded(_) => false,
+            Self::WebSocketError(_) => false,
+            Self::AuthError(_) => false,
+            Self::InsufficientFunds(_) => false,
+            Self::InvalidParameter(_) => false,
+            Self::OrderNotFound(_) => false,
+            Self::MarketNotFound(_) => false,
+            Self::ConfigError(_) => false,
+            Self::Unsupported(_) => false,
+            Self::ApiError(_) => false,
+        }
+    }
 }
 
 /// 거래소 작업을 위한 Result 타입 별칭.
@@ -230,4 +256,26 @@ mod tests {
         };
         assert!(!err.is_retryable());
     }
+
+    #[test]
+    fn test_is_ambiguous() {
+        // 응답 파싱 실패/거래소 내부 에러: 접수 여부 불명
+        let err = ExchangeError::InternalError("system busy".to_string());
+        assert!(err.is_ambiguous());
+        let err = ExchangeError::UnknownError {
+            code: "502".to_string(),
+            message: "bad gateway".to_string(),
+        };
+        assert!(err.is_ambiguous());
+        let result: Result<serde_json::Value, _> = serde_json::from_str("{");
+        assert!(ExchangeError::from(result.unwrap_err()).is_ambiguous());
+
+        // 명시적 거부: 접수되지 않음
+        let err = ExchangeError::InsufficientFunds("not enough".to_string());
+        assert!(!err.is_ambiguous());
+        let err = ExchangeError::RateLimitExceeded("too many requests".to_string());
+        assert!(!err.is_ambiguous());
+        let err = ExchangeError::ApiError("rejected".to_string());
+        assert!(!err.is_ambiguous());
+    }
 }
@@ -11,6 +11,10 @@
 //! - **Upbit**: `{QUOTE}-{BASE}` (예: "KRW-BTC") - 내부 형식과 동일
 //! - **Bithumb**: `{QUOTE}-{BASE}` (예: "KRW-BTC") - 내부 형식과 동일
 //! - **Bybit**: `{BASE}{QUOTE}` (예: "BTCUSDT")
+//! - **Binance**: `{BASE}{QUOTE}` (예: "BTCUSDT") - Bybit과 동일
+//! - **OKX**: `{BASE}-{QUOTE}-SWAP` (예: "BTC-USDT-SWAP") - 무기한 스왑 instId
+//! - **Coinone**: `{QUOTE}-{BASE}` (예: "KRW-BTC") - API 경로에서 quote/target으로 분리
+//! - **Korbit**: `{base}_{quote}` 소문자 (예: "btc_krw")
 //!
 //! 내부 형식은 `{QUOTE}-{BASE}` 규칙을 따릅니다:
 //! - QUOTE: 지불에 사용하는 통화 (KRW, USDT 등)
@@ -25,6 +29,14 @@ pub enum ExchangeName {
     Bithumb,
     /// Bybit (글로벌 거래소).
     Bybit,
+    /// Binance USDⓈ-M 선물 (글로벌 거래소).
+    Binance,
+    /// OKX 무기한 스왑 (글로벌 거래소).
+    Okx,
+    /// Coinone (한국 거래소).
+    Coinone,
+    /// Korbit (한국 거래소).
+    Korbit,
 }
 
 impl ExchangeName {
@@ -34,12 +46,24 @@ impl ExchangeName {
             Self::Upbit => "upbit",
             Self::Bithumb => "bithumb",
             Self::Bybit => "bybit",
+            Self::Binance => "binance",
+            Self::Okx => "okx",
+            Self::Coinone => "coinone",
+            Self::Korbit => "korbit",
         }
     }
 
     /// 지원되는 모든 거래소 이름을 반환합니다.
     pub fn all() -> &'static [Self] {
-        &[Self::Upbit, Self::Bithumb, Self::Bybit]
+        &[
+            Self::Upbit,
+            Self::Bithumb,
+            Self::Bybit,
+            Self::Binance,
+            Self::Okx,
+            Self::Coinone,
+            Self::Korbit,
+        ]
     }
 
     /// 문자열에서 거래소 이름을 파싱합니다 (편의 메서드).
@@ -58,6 +82,10 @@ impl std::str::FromStr for ExchangeName {
             "upbit" => Ok(Self::Upbit),
             "bithumb" => Ok(Self::Bithumb),
             "bybit" => Ok(Self::Bybit),
+            "binance" => Ok(Self::Binance),
+            "okx" => Ok(Self::Okx),
+            "coinone" => Ok(Self::Coinone),
+            "korbit" => Ok(Self::Korbit),
             _ => Err(format!(
                 "Unknown exchange: {}. Supported exchanges: {:?}",
                 s,
@@ -94,14 +122,22 @@ impl std::fmt::Display for ExchangeName {
 /// ```
 pub fn to_internal_format(exchange: ExchangeName, market: &str) -> String {
     match exchange {
-        ExchangeName::Upbit | ExchangeName::Bithumb => {
+        ExchangeName::Upbit | ExchangeName::Bithumb | ExchangeName::Coinone => {
             // 이미 내부 형식임
             market.to_uppercase()
         }
-        ExchangeName::Bybit => {
-            // Bybit는 "BTCUSDT" 형식을 사용, "USDT-BTC"로 변환
+        ExchangeName::Bybit | ExchangeName::Binance => {
+            // Bybit/Binance는 "BTCUSDT" 형식을 사용, "USDT-BTC"로 변환
             bybit_to_internal(market)
         }
+        ExchangeName::Okx => {
+            // OKX는 "BTC-USDT-SWAP" 형식을 사용, "USDT-BTC"로 변환
+            okx_to_internal(market)
+        }
+        ExchangeName::Korbit => {
+            // Korbit은 "btc_krw" 형식을 사용, "KRW-BTC"로 변환
+            korbit_to_internal(market)
+        }
     }
 }
 
@@ -126,14 +162,22 @@ pub fn to_internal_format(exchange: ExchangeName, market: &str) -> String {
 /// ```
 pub fn to_exchange_format(exchange: ExchangeName, market: &str) -> String {
     match exchange {
-        ExchangeName::Upbit | ExchangeName::Bithumb => {
+        ExchangeName::Upbit | ExchangeName::Bithumb | ExchangeName::Coinone => {
             // 이미 내부 형식임
             market.to_uppercase()
         }
-        ExchangeName::Bybit => {
+        ExchangeName::Bybit | ExchangeName::Binance => {
             // "USDT-BTC"를 "BTCUSDT"로 변환
             internal_to_bybit(market)
         }
+        ExchangeName::Okx => {
+            // "USDT-BTC"를 "BTC-USDT-SWAP"으로 변환
+            internal_to_okx(market)
+        }
+        ExchangeName::Korbit => {
+            // "KRW-BTC"를 "btc_krw"로 변환
+            internal_to_korbit(market)
+        }
     }
 }
 
@@ -277,6 +321,57 @@ fn internal_to_bybit(market: &str) -> String {
     }
 }
 
+/// OKX 스왑 instId 접미사.
+const OKX_SWAP_SUFFIX: &str = "-SWAP";
+
+/// OKX 스왑 instId를 내부 형식으로 변환합니다.
+///
+/// OKX "BTC-USDT-SWAP" -> 내부 "USDT-BTC"
+fn okx_to_internal(inst_id: &str) -> String {
+    let inst_id = inst_id.to_uppercase();
+    let pair = inst_id.strip_suffix(OKX_SWAP_SUFFIX).unwrap_or(&inst_id);
+    match pair.split_once('-') {
+        Some((base, quote)) => format!("{}-{}", quote, base),
+        None => inst_id.clone(),
+    }
+}
+
+/// 내부 형식을 OKX 스왑 instId로 변환합니다.
+///
+/// 내부 "USDT-BTC" -> OKX "BTC-USDT-SWAP"
+fn internal_to_okx(market: &str) -> String {
+    if let Some((quote, base)) = market.split_once('-') {
+        format!(
+            "{}-{}{}",
+            base.to_uppercase(),
+            quote.to_uppercase(),
+            OKX_SWAP_SUFFIX
+        )
+    } else {
+        market.to_uppercase()
+    }
+}
+
+/// Korbit 심볼을 내부 형식으로 변환합니다.
+///
+/// Korbit "btc_krw" -> 내부 "KRW-BTC"
+fn korbit_to_internal(symbol: &str) -> String {
+    match symbol.split_once('_') {
+        Some((base, quote)) => format!("{}-{}", quote.to_uppercase(), base.to_uppercase()),
+        None => symbol.to_uppercase(),
+    }
+}
+
+/// 내부 형식을 Korbit 심볼로 변환합니다.
+///
+/// 내부 "KRW-BTC" -> Korbit "btc_krw"
+fn internal_to_korbit(market: &str) -> String {
+    match market.split_once('-') {
+        Some((quote, base)) => format!("{}_{}", base.to_lowercase(), quote.to_lowercase()),
+        None => market.to_lowercase(),
+    }
+}
+
 /// 편리한 마켓 코드 생성을 위한 빌더.
 #[derive(Debug, Clone)]
 pub struct MarketCodeBuilder {
@@ -353,6 +448,7 @@ mod tests {
         assert_eq!(ExchangeName::Upbit.as_str(), "upbit");
         assert_eq!(ExchangeName::Bithumb.as_str(), "bithumb");
         assert_eq!(ExchangeName::Bybit.as_str(), "bybit");
+        assert_eq!(ExchangeName::Binance.as_str(), "binance");
     }
 
     #[test]
@@ -408,6 +504,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_binance_market_format() {
+        assert_eq!(ExchangeName::parse("BINANCE"), Some(ExchangeName::Binance));
+        assert_eq!(
+            to_internal_format(ExchangeName::Binance, "BTCUSDT"),
+            "USDT-BTC"
+        );
+        assert_eq!(
+            to_exchange_format(ExchangeName::Binance, "USDT-ETH"),
+            "ETHUSDT"
+        );
+        assert_eq!(
+            convert_market_code(ExchangeName::Bybit, ExchangeName::Binance, "SOLUSDT"),
+            "SOLUSDT"
+        );
+    }
+
+    #[test]
+    fn test_okx_market_format() {
+        assert_eq!(ExchangeName::parse("OKX"), Some(ExchangeName::Okx));
+        assert_eq!(ExchangeName::Okx.as_str(), "okx");
+        assert_eq!(
+            to_internal_format(ExchangeName::Okx, "BTC-USDT-SWAP"),
+            "USDT-BTC"
+        );
+        assert_eq!(
+            to_exchange_format(ExchangeName::Okx, "USDT-ETH"),
+            "ETH-USDT-SWAP"
+        );
+        assert_eq!(
+            convert_market_code(ExchangeName::Bybit, ExchangeName::Okx, "SOLUSDT"),
+            "SOL-USDT-SWAP"
+        );
+    }
+
+    #[test]
+    fn test_korean_venue_market_format() {
+        assert_eq!(ExchangeName::parse("Coinone"), Some(ExchangeName::Coinone));
+        assert_eq!(ExchangeName::Korbit.as_str(), "korbit");
+        assert_eq!(
+            to_internal_format(ExchangeName::Coinone, "krw-btc"),
+            "KRW-BTC"
+        );
+        assert_eq!(
+            to_exchange_format(ExchangeName::Coinone, "KRW-ETH"),
+            "KRW-ETH"
+        );
+        assert_eq!(
+            to_internal_format(ExchangeName::Korbit, "btc_krw"),
+            "KRW-BTC"
+        );
+        assert_eq!(
+            to_exchange_format(ExchangeName::Korbit, "KRW-ETH"),
+            "eth_krw"
+        );
+        assert_eq!(
+            convert_market_code(ExchangeName::Upbit, ExchangeName::Korbit, "KRW-XRP"),
+            "xrp_krw"
+        );
+    }
+
     #[test]
     fn test_convert_market_code() {
         // Upbit에서 Bybit로
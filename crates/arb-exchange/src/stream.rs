@@ -35,6 +35,15 @@ pub enum MarketEvent {
     },
 }
 
+impl MarketEvent {
+    /// 거래소가 기록한 이벤트 시각을 반환합니다.
+    pub fn timestamp(&self) -> DateTime<Utc> {
+        match self {
+            Self::Trade { timestamp, .. } | Self::BestQuote { timestamp, .. } => *timestamp,
+        }
+    }
+}
+
 /// WebSocket 재연결 정책 설정.
 #[derive(Debug, Clone)]
 pub struct StreamConfig {
@@ -124,6 +124,19 @@ pub trait OrderManagement: Send + Sync {
     /// * `order_id` - 거래소에서 부여한 주문 ID
     fn get_order(&self, order_id: &str) -> impl Future<Output = ExchangeResult<Order>> + Send;
 
+    /// 발주 시 지정한 client order id로 주문을 조회합니다.
+    ///
+    /// 발주 응답을 받지 못한 경우 실제 접수 여부를 확인하는 데 사용합니다.
+    /// 주문이 없으면 [`ExchangeError::OrderNotFound`](crate::ExchangeError::OrderNotFound)를 반환합니다.
+    ///
+    /// # 인자
+    ///
+    /// * `client_order_id` - [`OrderRequest::identifier`]로 보낸 값
+    fn get_order_by_client_id(
+        &self,
+        client_order_id: &str,
+    ) -> impl Future<Output = ExchangeResult<Order>> + Send;
+
     /// 특정 마켓의 미체결 주문을 조회합니다.
     ///
     /// # 인자
@@ -183,6 +196,18 @@ pub trait LinearOrderManagement: Send + Sync {
         order_id: &str,
     ) -> impl Future<Output = ExchangeResult<Order>> + Send;
 
+    /// 발주 시 지정한 client order id로 선물 주문을 조회합니다.
+    ///
+    /// 주문이 없으면 [`ExchangeError::OrderNotFound`](crate::ExchangeError::OrderNotFound)를 반환합니다.
+    ///
+    /// # 인자
+    ///
+    /// * `client_order_id` - [`OrderRequest::identifier`]로 보낸 값
+    fn get_order_linear_by_client_id(
+        &self,
+        client_order_id: &str,
+    ) -> impl Future<Output = ExchangeResult<Order>> + Send;
+
     /// 선물 주문을 취소합니다.
     ///
     /// # 인자
@@ -450,14 +450,19 @@ pub struct PositionInfo {
 pub struct InstrumentInfoResponse {
     /// 가격 최소 단위.
     pub tick_size: Decimal,
-    /// 수량 최소 단위.
+    /// 수량 최소 단위 (거래소 주문 단위).
     pub qty_step: Decimal,
-    /// 최소 주문 수량.
+    /// 최소 주문 수량 (거래소 주문 단위).
     pub min_order_qty: Decimal,
-    /// 최대 주문 수량.
+    /// 최대 주문 수량 (거래소 주문 단위).
     pub max_order_qty: Decimal,
     /// 최소 주문 금액 (USDT).
     pub min_notional: Decimal,
+    /// 주문 수량 1단위가 나타내는 기준 코인 수량 (계약 승수).
+    ///
+    /// 코인 수량으로 주문하는 거래소(Bybit, Binance)는 1이고,
+    /// 계약 수로 주문하는 OKX 스왑은 계약당 코인 수(`ctVal`)입니다.
+    pub contract_multiplier: Decimal,
 }
 
 #[cfg(test)]
@@ -552,6 +557,7 @@ mod tests {
             min_order_qty: Decimal::new(1, 3), // 0.001
             max_order_qty: Decimal::from(100),
             min_notional: Decimal::from(5),
+            contract_multiplier: Decimal::ONE,
         };
         assert_eq!(info.tick_size, Decimal::new(1, 2));
         assert_eq!(info.qty_step, Decimal::new(1, 3));
@@ -568,6 +574,7 @@ mod tests {
             min_order_qty: Decimal::from(10),
             max_order_qty: Decimal::from(10000),
             min_notional: Decimal::from(1),
+            contract_multiplier: Decimal::ONE,
         };
         let cloned = info.clone();
         assert_eq!(cloned.tick_size, info.tick_size);
@@ -585,6 +592,7 @@ mod tests {
             min_order_qty: Decimal::new(1, 3),
             max_order_qty: Decimal::from(100),
             min_notional: Decimal::from(5),
+            contract_multiplier: Decimal::ONE,
         };
         let debug_str = format!("{:?}", info);
         assert!(debug_str.contains("InstrumentInfoResponse"));
@@ -0,0 +1,157 @@
+//! Binance HMAC-SHA256 인증 모듈.
+//!
+//! Binance USDⓈ-M 선물 API는 쿼리 문자열(`recvWindow`, `timestamp` 포함) 전체를
+//! HMAC-SHA256으로 서명하고, 결과를 `signature` 파라미터로 덧붙입니다.
+//! API 키는 `X-MBX-APIKEY` 헤더로 전달합니다.
+
+use arb_config::SecretString;
+use arb_exchange::ExchangeError;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// 기본 수신 윈도우 (밀리초 단위).
+pub const DEFAULT_RECV_WINDOW: u64 = 5000;
+
+/// API 키 헤더 이름.
+pub const HEADER_API_KEY: &str = "X-MBX-APIKEY";
+
+/// Binance API 인증을 위한 자격 증명.
+#[derive(Clone)]
+pub struct BinanceCredentials {
+    api_key: String,
+    secret_key: SecretString,
+    recv_window: u64,
+}
+
+impl std::fmt::Debug for BinanceCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let masked_api_key = if self.api_key.len() >= 4 {
+            format!("{}****", &self.api_key[..4])
+        } else {
+            "****".to_string()
+        };
+        f.debug_struct("BinanceCredentials")
+            .field("api_key", &masked_api_key)
+            .field("secret_key", &"****")
+            .field("recv_window", &self.recv_window)
+            .finish()
+    }
+}
+
+impl BinanceCredentials {
+    /// 기본 수신 윈도우로 새 자격 증명을 생성합니다.
+    ///
+    /// # 인자
+    ///
+    /// * `api_key` - Binance API 키
+    /// * `secret_key` - Binance API 시크릿 키
+    pub fn new(api_key: impl Into<String>, secret_key: impl Into<String>) -> Self {
+        Self {
+            api_key: api_key.into(),
+            secret_key: SecretString::new(secret_key),
+            recv_window: DEFAULT_RECV_WINDOW,
+        }
+    }
+
+    /// API 키를 반환합니다.
+    #[inline]
+    pub fn api_key(&self) -> &str {
+        &self.api_key
+    }
+
+    /// 쿼리 문자열을 서명합니다.
+    ///
+    /// # 반환값
+    ///
+    /// 소문자 16진수 HMAC-SHA256 서명
+    pub fn sign(&self, query_string: &str) -> Result<String, ExchangeError> {
+        let mut mac = HmacSha256::new_from_slice(self.secret_key.expose_secret().as_bytes())
+            .map_err(|e| ExchangeError::AuthError(format!("Invalid secret key: {e}")))?;
+        mac.update(query_string.as_bytes());
+        Ok(hex::encode(mac.finalize().into_bytes()))
+    }
+
+    /// 요청 파라미터에 `recvWindow`, `timestamp`, `signature`를 붙인 쿼리 문자열을 만듭니다.
+    ///
+    /// 서명 대상과 실제 전송 문자열이 같아야 하므로, 반환값을 그대로 URL에 사용합니다.
+    ///
+    /// # 인자
+    ///
+    /// * `params` - 요청 파라미터 (전송 순서 유지)
+    /// * `timestamp` - 서버 시각 기준 타임스탬프 (밀리초)
+    pub fn signed_query(
+        &self,
+        params: &[(&str, &str)],
+        timestamp: u64,
+    ) -> Result<String, ExchangeError> {
+        let recv_window = self.recv_window.to_string();
+        let timestamp = timestamp.to_string();
+        let query = build_query_string(params.iter().copied().chain([
+            ("recvWindow", recv_window.as_str()),
+            ("timestamp", &timestamp),
+        ]));
+        let signature = self.sign(&query)?;
+        Ok(format!("{query}&signature={signature}"))
+    }
+}
+
+/// 파라미터로 URL 인코딩된 쿼리 문자열을 만듭니다.
+pub fn build_query_string<'a>(params: impl IntoIterator<Item = (&'a str, &'a str)>) -> String {
+    url::form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(params)
+        .finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_matches_binance_docs_example() {
+        // Binance API 문서의 HMAC 서명 예제
+        let creds = BinanceCredentials::new(
+            "vmPUZE6mv9SD5VNHk4HlWFsOr6aKE2zvsw0MuIgwCIPy6utIco14y7Ju91duEh8A",
+            "NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j",
+        );
+        let signature = creds
+            .sign("symbol=LTCBTC&side=BUY&type=LIMIT&timeInForce=GTC&quantity=1&price=0.1&recvWindow=5000&timestamp=1499827319559")
+            .unwrap();
+        assert_eq!(
+            signature,
+            "c8db56825ae71d6d79447849e617115f4a920fa2acdcab2b053c4b2838bd6b71"
+        );
+    }
+
+    #[test]
+    fn test_signed_query_appends_window_timestamp_and_signature() {
+        let creds = BinanceCredentials::new("key", "secret");
+        let query = creds
+            .signed_query(&[("symbol", "BTCUSDT"), ("side", "BUY")], 1700000000000)
+            .unwrap();
+
+        let (payload, signature) = query.rsplit_once("&signature=").unwrap();
+        assert_eq!(
+            payload,
+            "symbol=BTCUSDT&side=BUY&recvWindow=5000&timestamp=1700000000000"
+        );
+        assert_eq!(signature, creds.sign(payload).unwrap());
+    }
+
+    #[test]
+    fn test_build_query_string_encodes_values() {
+        assert_eq!(
+            build_query_string([("newClientOrderId", "arb-1/2"), ("symbol", "BTCUSDT")]),
+            "newClientOrderId=arb-1%2F2&symbol=BTCUSDT"
+        );
+    }
+
+    #[test]
+    fn test_credentials_debug_masks_secret() {
+        let creds = BinanceCredentials::new("abcdefgh", "super-secret");
+        let debug = format!("{creds:?}");
+        assert!(debug.contains("abcd****"));
+        assert!(!debug.contains("super-secret"));
+    }
+}
@@ -0,0 +1,1233 @@
+//! Binance USDⓈ-M 선물 REST API 클라이언트 구현.
+//!
+//! 이 모듈은 Binance `/fapi` API와 상호작용하기 위한 메인 클라이언트를 제공합니다.
+
+use crate::binance::auth::{BinanceCredentials, HEADER_API_KEY};
+use crate::binance::stream::BinanceStreamInner;
+use crate::binance::types::{
+    Binance24hrTicker, BinanceBalance, BinanceDepth, BinanceErrorResponse, BinanceExchangeInfo,
+    BinanceKline, BinanceOrder, BinancePositionRisk, BinancePremiumIndex, BinanceServerTime,
+    BinanceSymbolFilter,
+};
+use crate::bybit::LinearTickerInfo;
+use crate::clock::{ClockSample, ServerClock, ServerTimeSync, local_now_ms};
+use crate::credentials::CredentialSlot;
+use crate::order_cache::OrderKeyCache;
+use crate::rate_limit::RateLimiter;
+use arb_exchange::{
+    Balance, Candle, CandleInterval, ExchangeError, ExchangeResult, InstrumentDataProvider,
+    InstrumentInfoResponse, LinearOrderManagement, MarketData, Order, OrderBook, OrderBookLevel,
+    OrderManagement, OrderRequest, OrderSide, OrderStatus, OrderType, PositionInfo, PriceChange,
+    StreamConfig, Ticker, TimeInForce,
+};
+use chrono::{DateTime, TimeZone, Utc};
+use reqwest::{Client, Method};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use std::sync::Arc;
+use tracing::{debug, warn};
+
+/// Binance USDⓈ-M 선물 REST API 기본 URL (메인넷).
+const BASE_URL_MAINNET: &str = "https://fapi.binance.com";
+
+/// Binance USDⓈ-M 선물 REST API 기본 URL (테스트넷).
+const BASE_URL_TESTNET: &str = "https://testnet.binancefuture.com";
+
+/// Binance 공개 API 레이트 리밋 (초당 요청 수).
+/// 분당 2400 weight 한도 안에서 시세, 오더북, 캔들 조회에 사용.
+const BINANCE_PUBLIC_RATE_LIMIT: u32 = 20;
+/// 공개 API 최대 버스트 용량.
+const BINANCE_PUBLIC_BURST: u32 = 5;
+
+/// Binance 비공개 API 레이트 리밋 (초당 요청 수).
+/// 주문 한도(10초당 300건)보다 보수적으로 설정.
+const BINANCE_PRIVATE_RATE_LIMIT: u32 = 10;
+/// 비공개 API 최대 버스트 용량.
+const BINANCE_PRIVATE_BURST: u32 = 5;
+
+/// 주문 ID → 심볼 캐시 최대 항목 수.
+///
+/// Binance 주문 조회/취소는 심볼이 필수이므로, 발주 시 심볼을 기억해 둡니다.
+const ORDER_SYMBOL_CACHE_CAPACITY: usize = 4096;
+
+/// 최소 주문 금액 필터가 없을 때의 기본값 (USDT).
+const DEFAULT_MIN_NOTIONAL: i64 = 5;
+
+/// Binance depth API가 허용하는 limit 값.
+const DEPTH_LIMITS: [u32; 7] = [5, 10, 20, 50, 100, 500, 1000];
+
+/// 단일/전체 조회에 따라 객체 또는 배열로 오는 응답.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OneOrMany<T> {
+    One(T),
+    Many(Vec<T>),
+}
+
+impl<T> OneOrMany<T> {
+    fn into_vec(self) -> Vec<T> {
+        match self {
+            Self::One(item) => vec![item],
+            Self::Many(items) => items,
+        }
+    }
+}
+
+/// Binance USDⓈ-M 선물 API 클라이언트.
+///
+/// Bybit linear의 대체 헤지 거래소로 사용할 수 있도록 공개(시장 데이터) API와
+/// 선물 주문/포지션 API를 지원합니다. 비공개 API를 사용하려면 인증 정보를 제공해야 합니다.
+pub struct BinanceClient {
+    client: Client,
+    /// 인증 정보. 복제본 간에 공유되어 [`Self::set_credentials`]로 교체됩니다.
+    credentials: CredentialSlot<BinanceCredentials>,
+    base_url: String,
+    /// WebSocket 스트림 내부 상태.
+    pub(crate) stream: Arc<BinanceStreamInner>,
+    /// 서버 시계 추정기 (서명 타임스탬프 보정, 피드 지연 측정).
+    pub(crate) clock: Arc<ServerClock>,
+    /// 공개 API (시세, 오더북) 레이트 리밋터.
+    public_limiter: Arc<RateLimiter>,
+    /// 비공개 API (주문, 잔고, 포지션) 레이트 리밋터.
+    private_limiter: Arc<RateLimiter>,
+    /// 주문 조회/취소에 필요한 심볼 캐시.
+    order_symbols: OrderKeyCache,
+}
+
+impl std::fmt::Debug for BinanceClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BinanceClient")
+            .field("base_url", &self.base_url)
+            .field("credentials", &self.credentials.is_set())
+            .finish()
+    }
+}
+
+impl Clone for BinanceClient {
+    /// 클라이언트를 복제합니다.
+    ///
+    /// 커넥션 풀, rate limiter 상태, 주문 심볼 캐시를 복제본과 공유합니다.
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            credentials: self.credentials.clone(),
+            base_url: self.base_url.clone(),
+            stream: Arc::clone(&self.stream),
+            clock: Arc::clone(&self.clock),
+            public_limiter: Arc::clone(&self.public_limiter),
+            private_limiter: Arc::clone(&self.private_limiter),
+            order_symbols: self.order_symbols.clone(),
+        }
+    }
+}
+
+impl BinanceClient {
+    /// 메인넷용 인증되지 않은 새 Binance 클라이언트를 생성합니다.
+    ///
+    /// # 에러
+    ///
+    /// HTTP 클라이언트를 생성할 수 없는 경우 에러를 반환합니다.
+    pub fn new() -> ExchangeResult<Self> {
+        Self::new_internal(None, BASE_URL_MAINNET)
+    }
+
+    /// 테스트넷용 인증되지 않은 새 Binance 클라이언트를 생성합니다.
+    ///
+    /// # 에러
+    ///
+    /// HTTP 클라이언트를 생성할 수 없는 경우 에러를 반환합니다.
+    pub fn new_testnet() -> ExchangeResult<Self> {
+        Self::new_internal(None, BASE_URL_TESTNET)
+    }
+
+    /// 메인넷용 인증된 새 Binance 클라이언트를 생성합니다.
+    ///
+    /// # 인자
+    ///
+    /// * `api_key` - Binance API 키
+    /// * `secret_key` - Binance API 시크릿 키
+    ///
+    /// # 에러
+    ///
+    /// HTTP 클라이언트를 생성할 수 없는 경우 에러를 반환합니다.
+    pub fn with_credentials(
+        api_key: impl Into<String>,
+        secret_key: impl Into<String>,
+    ) -> ExchangeResult<Self> {
+        let creds = BinanceCredentials::new(api_key, secret_key);
+        Self::new_internal(Some(creds), BASE_URL_MAINNET)
+    }
+
+    /// 테스트넷용 인증된 새 Binance 클라이언트를 생성합니다.
+    ///
+    /// # 인자
+    ///
+    /// * `api_key` - Binance 테스트넷 API 키
+    /// * `secret_key` - Binance 테스트넷 API 시크릿 키
+    ///
+    /// # 에러
+    ///
+    /// HTTP 클라이언트를 생성할 수 없는 경우 에러를 반환합니다.
+    pub fn with_credentials_testnet(
+        api_key: impl Into<String>,
+        secret_key: impl Into<String>,
+    ) -> ExchangeResult<Self> {
+        let creds = BinanceCredentials::new(api_key, secret_key);
+        Self::new_internal(Some(creds), BASE_URL_TESTNET)
+    }
+
+    /// 내부 생성자.
+    fn new_internal(
+        credentials: Option<BinanceCredentials>,
+        base_url: &str,
+    ) -> ExchangeResult<Self> {
+        let client = Client::builder()
+            .timeout(std::time::Duration::from_secs(30))
+            .build()
+            .map_err(ExchangeError::HttpError)?;
+
+        Ok(Self {
+            client,
+            credentials: CredentialSlot::new(credentials),
+            base_url: base_url.to_string(),
+            stream: Arc::new(BinanceStreamInner::new(StreamConfig::default())),
+            clock: Arc::new(ServerClock::new("binance")),
+            public_limiter: Arc::new(RateLimiter::new(
+                "binance-public",
+                BINANCE_PUBLIC_RATE_LIMIT,
+                BINANCE_PUBLIC_BURST,
+            )),
+            private_limiter: Arc::new(RateLimiter::new(
+                "binance-private",
+                BINANCE_PRIVATE_RATE_LIMIT,
+                BINANCE_PRIVATE_BURST,
+            )),
+            order_symbols: OrderKeyCache::new(ORDER_SYMBOL_CACHE_CAPACITY),
+        })
+    }
+
+    /// WebSocket 스트림 내부 상태에 접근합니다.
+    pub(crate) fn stream_inner(&self) -> &BinanceStreamInner {
+        &self.stream
+    }
+
+    /// 서버 시각 오프셋을 보정한 서명 타임스탬프 (밀리초).
+    fn auth_timestamp(&self) -> u64 {
+        u64::try_from(self.clock.now_ms()).unwrap_or_default()
+    }
+
+    /// 실행 중 API 키를 교체합니다 (키 로테이션).
+    ///
+    /// 이 클라이언트의 모든 복제본에 즉시 반영되며, 이미 서명된 요청은 이전 키로 완료됩니다.
+    ///
+    /// # 인자
+    ///
+    /// * `api_key` - Binance API 키
+    /// * `secret_key` - Binance API 시크릿 키
+    pub fn set_credentials(&self, api_key: impl Into<String>, secret_key: impl Into<String>) {
+        self.credentials
+            .set(BinanceCredentials::new(api_key, secret_key));
+    }
+
+    /// 인증 정보가 설정되어 있으면 true를 반환합니다.
+    pub fn has_credentials(&self) -> bool {
+        self.credentials.is_set()
+    }
+
+    /// 주문 키(주문 ID 또는 client order ID)의 심볼을 기억합니다.
+    fn remember_symbol(&self, key: &str, symbol: &str) {
+        self.order_symbols.insert(key, symbol);
+    }
+
+    /// 기억해 둔 주문 키의 심볼을 반환합니다.
+    fn cached_symbol(&self, key: &str) -> Option<String> {
+        self.order_symbols.get(key)
+    }
+
+    /// 주문 키의 심볼을 찾습니다.
+    ///
+    /// 캐시에 없으면(재시작 등) 전체 미체결 주문에서 찾습니다. 그래도 없으면
+    /// 주문 부재를 확정할 수 없으므로 `OrderNotFound`가 아닌 `InvalidParameter`를 반환합니다.
+    async fn resolve_symbol(&self, key: &str) -> ExchangeResult<String> {
+        if let Some(symbol) = self.cached_symbol(key) {
+            return Ok(symbol);
+        }
+
+        debug!(key, "Binance 주문 심볼 캐시 미스 — 미체결 주문에서 검색");
+        let open: Vec<BinanceOrder> = self
+            .send_signed(Method::GET, "/fapi/v1/openOrders", &[])
+            .await?;
+        for order in &open {
+            self.remember_order(order);
+        }
+
+        self.cached_symbol(key).ok_or_else(|| {
+            ExchangeError::InvalidParameter(format!(
+                "Binance order lookup requires a known symbol: {key}"
+            ))
+        })
+    }
+
+    /// 주문 응답의 ID와 client order ID를 심볼 캐시에 반영합니다.
+    fn remember_order(&self, order: &BinanceOrder) {
+        self.remember_symbol(&order.order_id.to_string(), &order.symbol);
+        if let Some(ref client_order_id) = order.client_order_id {
+            self.remember_symbol(client_order_id, &order.symbol);
+        }
+    }
+
+    /// 공개 엔드포인트에 GET 요청을 보냅니다.
+    async fn get_public<T: serde::de::DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        params: &[(&str, &str)],
+    ) -> ExchangeResult<T> {
+        self.public_limiter.acquire().await;
+        let url = format!("{}{}", self.base_url, endpoint);
+        debug!(endpoint, ?params, "Binance public GET 요청");
+        let response = self
+            .client
+            .get(&url)
+            .query(params)
+            .send()
+            .await
+            .map_err(ExchangeError::HttpError)?;
+
+        self.handle_response(response).await
+    }
+
+    /// 서명이 필요한 엔드포인트에 요청을 보냅니다.
+    ///
+    /// 서명 대상과 전송 문자열이 일치하도록 쿼리 문자열을 직접 URL에 붙입니다.
+    async fn send_signed<T: serde::de::DeserializeOwned>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &[(&str, &str)],
+    ) -> ExchangeResult<T> {
+        self.private_limiter.acquire().await;
+        let creds = self.credentials.get()?;
+        let query = creds.signed_query(params, self.auth_timestamp())?;
+        let url = format!("{}{}?{}", self.base_url, endpoint, query);
+        debug!(endpoint, %method, ?params, "Binance signed 요청");
+
+        let response = self
+            .client
+            .request(method, &url)
+            .header(HEADER_API_KEY, creds.api_key())
+            .send()
+            .await
+            .map_err(ExchangeError::HttpError)?;
+
+        self.handle_response(response).await
+    }
+
+    /// API 응답을 처리하고 에러를 변환합니다.
+    async fn handle_response<T: serde::de::DeserializeOwned>(
+        &self,
+        response: reqwest::Response,
+    ) -> ExchangeResult<T> {
+        let status = response.status();
+        let body = response.text().await.map_err(ExchangeError::HttpError)?;
+
+        debug!(
+            status = status.as_u16(),
+            body_len = body.len(),
+            body_preview = %if body.len() > 200 { &body[..200] } else { &body },
+            "Binance API 응답 수신"
+        );
+
+        if !status.is_success() {
+            warn!(status = status.as_u16(), body = %body, "Binance API HTTP 에러");
+            return Err(parse_error(&body, status.as_u16()));
+        }
+
+        serde_json::from_str(&body).map_err(ExchangeError::JsonError)
+    }
+
+    /// Binance 심볼 형식을 공통 마켓 형식으로 변환합니다.
+    ///
+    /// "BTCUSDT" -> "USDT-BTC"
+    fn to_market_code(symbol: &str) -> String {
+        let quotes = ["USDT", "USDC", "BUSD", "BTC"];
+        for quote in quotes {
+            if let Some(base) = symbol.strip_suffix(quote)
+                && !base.is_empty()
+            {
+                return format!("{}-{}", quote, base);
+            }
+        }
+
+        symbol.to_string()
+    }
+
+    /// 공통 마켓 형식을 Binance 심볼 형식으로 변환합니다.
+    ///
+    /// "USDT-BTC" -> "BTCUSDT"
+    fn to_binance_symbol(market: &str) -> String {
+        if let Some((quote, base)) = market.split_once('-') {
+            format!("{}{}", base, quote)
+        } else {
+            market.to_string()
+        }
+    }
+}
+
+impl ServerTimeSync for BinanceClient {
+    fn server_clock(&self) -> &ServerClock {
+        &self.clock
+    }
+
+    async fn sync_server_clock(&self) -> ExchangeResult<ClockSample> {
+        // rate limiter 대기가 RTT에 섞이지 않도록 토큰 확보 후 송신 시각을 측정합니다.
+        self.public_limiter.acquire().await;
+        let url = format!("{}/fapi/v1/time", self.base_url);
+        let sent_ms = local_now_ms();
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(ExchangeError::HttpError)?;
+        let received_ms = local_now_ms();
+
+        let result: BinanceServerTime = self.handle_response(response).await?;
+        Ok(self
+            .clock
+            .record_probe(sent_ms, result.server_time, received_ms))
+    }
+}
+
+impl MarketData for BinanceClient {
+    fn name(&self) -> &str {
+        "Binance"
+    }
+
+    async fn get_ticker(&self, markets: &[&str]) -> ExchangeResult<Vec<Ticker>> {
+        let mut tickers = Vec::with_capacity(markets.len());
+
+        for market in markets {
+            let symbol = Self::to_binance_symbol(market);
+            let ticker: Binance24hrTicker = self
+                .get_public("/fapi/v1/ticker/24hr", &[("symbol", &symbol)])
+                .await?;
+            tickers.push(convert_ticker(ticker, market));
+        }
+
+        Ok(tickers)
+    }
+
+    async fn get_orderbook(&self, market: &str, depth: Option<u32>) -> ExchangeResult<OrderBook> {
+        let symbol = Self::to_binance_symbol(market);
+        let limit_str = depth_limit(depth.unwrap_or(20)).to_string();
+        let params = [("symbol", symbol.as_str()), ("limit", &limit_str)];
+
+        let result: BinanceDepth = self.get_public("/fapi/v1/depth", &params).await?;
+
+        Ok(convert_orderbook(result, market))
+    }
+
+    async fn get_candles(
+        &self,
+        market: &str,
+        interval: CandleInterval,
+        count: u32,
+    ) -> ExchangeResult<Vec<Candle>> {
+        let symbol = Self::to_binance_symbol(market);
+        let limit_str = count.min(1500).to_string();
+        let params = [
+            ("symbol", symbol.as_str()),
+            ("interval", interval_to_binance(interval)),
+            ("limit", &limit_str),
+        ];
+
+        // Binance는 오름차순으로 반환
+        let klines: Vec<BinanceKline> = self.get_public("/fapi/v1/klines", &params).await?;
+        Ok(klines
+            .into_iter()
+            .map(|k| convert_candle(k, market))
+            .collect())
+    }
+
+    async fn get_candles_before(
+        &self,
+        market: &str,
+        interval: CandleInterval,
+        count: u32,
+        before: DateTime<Utc>,
+    ) -> ExchangeResult<Vec<Candle>> {
+        let symbol = Self::to_binance_symbol(market);
+        let limit_str = count.min(1500).to_string();
+        // Binance의 `endTime`은 inclusive (밀리초)이므로 1ms를 빼서 exclusive 시맨틱을 구현
+        let end_ms = (before.timestamp_millis() - 1).to_string();
+        let params = [
+            ("symbol", symbol.as_str()),
+            ("interval", interval_to_binance(interval)),
+            ("limit", &limit_str),
+            ("endTime", &end_ms),
+        ];
+
+        let klines: Vec<BinanceKline> = self.get_public("/fapi/v1/klines", &params).await?;
+        Ok(klines
+            .into_iter()
+            .map(|k| convert_candle(k, market))
+            .collect())
+    }
+
+    async fn get_all_tickers(&self) -> ExchangeResult<Vec<Ticker>> {
+        let result: Vec<Binance24hrTicker> = self.get_public("/fapi/v1/ticker/24hr", &[]).await?;
+
+        // USDT 페어만 필터하여 변환
+        Ok(result
+            .into_iter()
+            .filter(|t| t.symbol.ends_with("USDT"))
+            .map(|t| {
+                let market = Self::to_market_code(&t.symbol);
+                convert_ticker(t, &market)
+            })
+            .collect())
+    }
+
+    fn market_code(base: &str, quote: &str) -> String {
+        // Binance 형식: "{BASE}{QUOTE}" (예: "BTCUSDT")
+        format!("{}{}", base.to_uppercase(), quote.to_uppercase())
+    }
+}
+
+/// 선물 전용 거래소이므로 일반 주문 API는 linear 주문 API에 위임합니다.
+impl OrderManagement for BinanceClient {
+    async fn place_order(&self, request: &OrderRequest) -> ExchangeResult<Order> {
+        self.place_order_linear(request, false).await
+    }
+
+    async fn cancel_order(&self, order_id: &str) -> ExchangeResult<Order> {
+        self.cancel_order_linear(order_id, None).await
+    }
+
+    async fn get_order(&self, order_id: &str) -> ExchangeResult<Order> {
+        self.get_order_linear(order_id).await
+    }
+
+    async fn get_order_by_client_id(&self, client_order_id: &str) -> ExchangeResult<Order> {
+        self.get_order_linear_by_client_id(client_order_id).await
+    }
+
+    async fn get_open_orders(&self, market: Option<&str>) -> ExchangeResult<Vec<Order>> {
+        let symbol = market.map(Self::to_binance_symbol);
+        let params: Vec<(&str, &str)> = symbol
+            .as_deref()
+            .map(|s| vec![("symbol", s)])
+            .unwrap_or_default();
+
+        let orders: Vec<BinanceOrder> = self
+            .send_signed(Method::GET, "/fapi/v1/openOrders", &params)
+            .await?;
+
+        Ok(orders
+            .into_iter()
+            .map(|o| {
+                self.remember_order(&o);
+                convert_order(o)
+            })
+            .collect())
+    }
+
+    async fn get_balances(&self) -> ExchangeResult<Vec<Balance>> {
+        let balances: Vec<BinanceBalance> = self
+            .send_signed(Method::GET, "/fapi/v2/balance", &[])
+            .await?;
+        Ok(balances.into_iter().map(convert_balance).collect())
+    }
+
+    async fn get_balance(&self, currency: &str) -> ExchangeResult<Balance> {
+        let balances = self.get_balances().await?;
+        balances
+            .into_iter()
+            .find(|b| b.currency == currency)
+            .ok_or_else(|| {
+                ExchangeError::InvalidParameter(format!("Currency not found: {}", currency))
+            })
+    }
+}
+
+impl InstrumentDataProvider for BinanceClient {
+    async fn get_instrument_info(&self, symbol: &str) -> ExchangeResult<InstrumentInfoResponse> {
+        // 선물 exchangeInfo는 심볼 필터를 지원하지 않아 전체 목록에서 찾습니다.
+        let info: BinanceExchangeInfo = self.get_public("/fapi/v1/exchangeInfo", &[]).await?;
+
+        let item = info
+            .symbols
+            .into_iter()
+            .find(|s| s.symbol == symbol)
+            .ok_or_else(|| {
+                ExchangeError::ApiError(format!("No instrument info found for symbol: {}", symbol))
+            })?;
+
+        let mut tick_size = None;
+        let mut lot_size = None;
+        let mut min_notional = Decimal::from(DEFAULT_MIN_NOTIONAL);
+        for filter in item.filters {
+            match filter {
+                BinanceSymbolFilter::Price { tick_size: t } => tick_size = Some(t),
+                BinanceSymbolFilter::LotSize {
+                    step_size,
+                    min_qty,
+                    max_qty,
+                } => lot_size = Some((step_size, min_qty, max_qty)),
+                BinanceSymbolFilter::MinNotional { notional } => min_notional = notional,
+                BinanceSymbolFilter::Other => {}
+            }
+        }
+
+        let tick_size = tick_size.ok_or_else(|| {
+            ExchangeError::ParseError(format!("PRICE_FILTER missing for {}", symbol))
+        })?;
+        let (qty_step, min_order_qty, max_order_qty) = lot_size
+            .ok_or_else(|| ExchangeError::ParseError(format!("LOT_SIZE missing for {}", symbol)))?;
+
+        Ok(InstrumentInfoResponse {
+            tick_size,
+            qty_step,
+            min_order_qty,
+            max_order_qty,
+            min_notional,
+            contract_multiplier: Decimal::ONE,
+        })
+    }
+}
+
+impl LinearOrderManagement for BinanceClient {
+    async fn place_order_linear(
+        &self,
+        request: &OrderRequest,
+        reduce_only: bool,
+    ) -> ExchangeResult<Order> {
+        let symbol = Self::to_binance_symbol(&request.market);
+
+        let side = match request.side {
+            OrderSide::Buy => "BUY",
+            OrderSide::Sell => "SELL",
+        };
+
+        let order_type = match request.order_type {
+            OrderType::Limit | OrderType::Best => "LIMIT",
+            OrderType::Market => "MARKET",
+            OrderType::Price => {
+                return Err(ExchangeError::InvalidParameter(
+                    "Binance futures does not support quote-amount market orders".to_string(),
+                ));
+            }
+        };
+
+        let quantity = request
+            .volume
+            .ok_or_else(|| ExchangeError::InvalidParameter("volume is required".to_string()))?
+            .normalize()
+            .to_string();
+
+        let price = if order_type == "LIMIT" {
+            let price = request.price.ok_or_else(|| {
+                ExchangeError::InvalidParameter("price is required for limit orders".to_string())
+            })?;
+            Some(price.normalize().to_string())
+        } else {
+            None
+        };
+
+        // 시장가 주문은 timeInForce를 허용하지 않음
+        let time_in_force = price.as_ref().map(|_| match request.time_in_force {
+            None | Some(TimeInForce::Gtc) => "GTC",
+            Some(TimeInForce::Ioc) => "IOC",
+            Some(TimeInForce::Fok) => "FOK",
+            Some(TimeInForce::PostOnly) => "GTX",
+        });
+
+        let mut params: Vec<(&str, &str)> = vec![
+            ("symbol", &symbol),
+            ("side", side),
+            ("type", order_type),
+            ("quantity", &quantity),
+        ];
+        if let Some(ref p) = price {
+            params.push(("price", p));
+        }
+        if let Some(tif) = time_in_force {
+            params.push(("timeInForce", tif));
+        }
+        if reduce_only {
+            params.push(("reduceOnly", "true"));
+        }
+        if let Some(ref client_order_id) = request.identifier {
+            params.push(("newClientOrderId", client_order_id));
+            // 응답 유실 시 client order ID 조회에 심볼이 필요하므로 전송 전에 기억
+            self.remember_symbol(client_order_id, &symbol);
+        }
+        params.push(("newOrderRespType", "RESULT"));
+
+        debug!(
+            symbol = %symbol,
+            side,
+            quantity = %quantity,
+            price = ?price,
+            order_type,
+            time_in_force = ?time_in_force,
+            reduce_only,
+            "Binance 선물 주문 생성 요청"
+        );
+
+        let order: BinanceOrder = self
+            .send_signed(Method::POST, "/fapi/v1/order", &params)
+            .await?;
+        self.remember_order(&order);
+
+        debug!(
+            order_id = order.order_id,
+            status = %order.status,
+            "Binance 선물 주문 생성 완료"
+        );
+
+        Ok(convert_order(order))
+    }
+
+    async fn get_order_linear(&self, order_id: &str) -> ExchangeResult<Order> {
+        let symbol = self.resolve_symbol(order_id).await?;
+        let params = [("symbol", symbol.as_str()), ("orderId", order_id)];
+
+        let order: BinanceOrder = self
+            .send_signed(Method::GET, "/fapi/v1/order", &params)
+            .await?;
+        Ok(convert_order(order))
+    }
+
+    async fn get_order_linear_by_client_id(&self, client_order_id: &str) -> ExchangeResult<Order> {
+        let symbol = self.resolve_symbol(client_order_id).await?;
+        let params = [
+            ("symbol", symbol.as_str()),
+            ("origClientOrderId", client_order_id),
+        ];
+
+        let order: BinanceOrder = self
+            .send_signed(Method::GET, "/fapi/v1/order", &params)
+            .await?;
+        self.remember_order(&order);
+        Ok(convert_order(order))
+    }
+
+    async fn cancel_order_linear(
+        &self,
+        order_id: &str,
+        symbol: Option<&str>,
+    ) -> ExchangeResult<Order> {
+        let symbol = match symbol {
+            Some(s) => s.to_string(),
+            None => self.resolve_symbol(order_id).await?,
+        };
+
+        debug!(order_id, symbol = %symbol, "Binance 선물 주문 취소 요청");
+
+        let params = [("symbol", symbol.as_str()), ("orderId", order_id)];
+        let order: BinanceOrder = self
+            .send_signed(Method::DELETE, "/fapi/v1/order", &params)
+            .await?;
+        Ok(convert_order(order))
+    }
+
+    async fn get_positions_linear(&self, symbol: &str) -> ExchangeResult<Vec<PositionInfo>> {
+        let params: Vec<(&str, &str)> = if symbol.is_empty() {
+            Vec::new()
+        } else {
+            vec![("symbol", symbol)]
+        };
+
+        let positions: Vec<BinancePositionRisk> = self
+            .send_signed(Method::GET, "/fapi/v2/positionRisk", &params)
+            .await?;
+
+        // 전체 조회 시 Binance는 모든 심볼을 반환하므로 보유 포지션만 남김
+        Ok(positions
+            .into_iter()
+            .filter(|p| !symbol.is_empty() || !p.position_amt.is_zero())
+            .map(convert_position)
+            .collect())
+    }
+}
+
+/// Binance 선물 전용 API 메서드.
+impl BinanceClient {
+    /// 선물 심볼의 레버리지를 설정합니다.
+    ///
+    /// # 인자
+    ///
+    /// * `symbol` - 심볼 (예: "BTCUSDT")
+    /// * `leverage` - 설정할 레버리지 배수 (1~125)
+    pub async fn set_leverage(&self, symbol: &str, leverage: u32) -> ExchangeResult<()> {
+        let lev_str = leverage.to_string();
+        debug!(symbol, leverage, "Binance 레버리지 설정 요청");
+
+        let _result: serde_json::Value = self
+            .send_signed(
+                Method::POST,
+                "/fapi/v1/leverage",
+                &[("symbol", symbol), ("leverage", &lev_str)],
+            )
+            .await?;
+
+        debug!(symbol, leverage, "Binance 레버리지 설정 완료");
+        Ok(())
+    }
+
+    /// 선물 펀딩 정보를 조회합니다 (마크 가격 인덱스 기반).
+    ///
+    /// Bybit의 [`crate::BybitClient::get_tickers_linear`]와 같은 형식으로 반환하여
+    /// 펀딩 필터를 그대로 사용할 수 있게 합니다.
+    ///
+    /// # 인자
+    ///
+    /// * `symbol` - 조회할 심볼 (None이면 전체 조회)
+    pub async fn get_tickers_linear(
+        &self,
+        symbol: Option<&str>,
+    ) -> ExchangeResult<Vec<LinearTickerInfo>> {
+        let params: Vec<(&str, &str)> = symbol.map(|s| vec![("symbol", s)]).unwrap_or_default();
+
+        let result: OneOrMany<BinancePremiumIndex> =
+            self.get_public("/fapi/v1/premiumIndex", &params).await?;
+
+        Ok(result
+            .into_vec()
+            .into_iter()
+            .map(|p| LinearTickerInfo {
+                symbol: p.symbol,
+                funding_rate: p
+                    .last_funding_rate
+                    .to_string()
+                    .parse::<f64>()
+                    .unwrap_or(0.0),
+                next_funding_time: p.next_funding_time,
+            })
+            .collect())
+    }
+}
+
+// 변환 함수들
+
+/// 응답 본문에서 에러를 파싱합니다.
+fn parse_error(body: &str, status: u16) -> ExchangeError {
+    if let Ok(resp) = serde_json::from_str::<BinanceErrorResponse>(body) {
+        return convert_binance_error(resp.code, &resp.msg);
+    }
+
+    match status {
+        // 429: 요청 제한 초과, 418: 제한 위반 반복으로 IP 차단
+        429 | 418 => ExchangeError::RateLimitExceeded(body.to_string()),
+        _ => ExchangeError::UnknownError {
+            code: status.to_string(),
+            message: body.to_string(),
+        },
+    }
+}
+
+/// Binance 에러 코드를 ExchangeError로 변환합니다.
+fn convert_binance_error(code: i64, message: &str) -> ExchangeError {
+    match code {
+        // 인증 에러 (서명 오류, API 키 무효, 권한 없음)
+        -1022 | -2014 | -2015 => ExchangeError::AuthError(message.to_string()),
+        // 잔고(마진) 부족
+        -2019 => ExchangeError::InsufficientFunds(message.to_string()),
+        // 주문을 찾을 수 없음 (취소 거부, 주문 없음)
+        -2011 | -2013 => ExchangeError::OrderNotFound(message.to_string()),
+        // 요청 제한 초과
+        -1003 => ExchangeError::RateLimitExceeded(message.to_string()),
+        // 존재하지 않는 심볼
+        -1121 => ExchangeError::MarketNotFound(message.to_string()),
+        // 잘못된 파라미터
+        -1130..=-1100 => ExchangeError::InvalidParameter(message.to_string()),
+        // 시스템 에러
+        -1000 | -1001 => ExchangeError::InternalError(message.to_string()),
+        _ => ExchangeError::UnknownError {
+            code: code.to_string(),
+            message: message.to_string(),
+        },
+    }
+}
+
+/// 요청 depth를 Binance가 허용하는 가장 가까운 상위 limit으로 맞춥니다.
+fn depth_limit(depth: u32) -> u32 {
+    DEPTH_LIMITS
+        .into_iter()
+        .find(|&limit| limit >= depth)
+        .unwrap_or(DEPTH_LIMITS[DEPTH_LIMITS.len() - 1])
+}
+
+fn convert_ticker(t: Binance24hrTicker, market: &str) -> Ticker {
+    let change = if t.price_change_percent > Decimal::ZERO {
+        PriceChange::Rise
+    } else if t.price_change_percent < Decimal::ZERO {
+        PriceChange::Fall
+    } else {
+        PriceChange::Even
+    };
+
+    let timestamp = Utc
+        .timestamp_millis_opt(t.close_time)
+        .single()
+        .unwrap_or_else(Utc::now);
+
+    Ticker {
+        market: market.to_string(),
+        trade_price: t.last_price,
+        opening_price: t.open_price,
+        high_price: t.high_price,
+        low_price: t.low_price,
+        prev_closing_price: t.open_price, // 24시간 롤링 시가를 전일 종가로 사용
+        change,
+        change_rate: t.price_change_percent / Decimal::ONE_HUNDRED,
+        change_price: t.price_change,
+        acc_trade_volume_24h: t.volume,
+        acc_trade_price_24h: t.quote_volume,
+        timestamp,
+    }
+}
+
+fn convert_orderbook(ob: BinanceDepth, market: &str) -> OrderBook {
+    let to_levels = |levels: Vec<crate::binance::types::BinanceDepthLevel>| -> Vec<OrderBookLevel> {
+        levels
+            .into_iter()
+            .map(|level| OrderBookLevel {
+                price: level.0,
+                size: level.1,
+            })
+            .collect()
+    };
+    let asks = to_levels(ob.asks);
+    let bids = to_levels(ob.bids);
+
+    let total_ask_size = asks.iter().fold(Decimal::ZERO, |acc, l| acc + l.size);
+    let total_bid_size = bids.iter().fold(Decimal::ZERO, |acc, l| acc + l.size);
+
+    let timestamp = Utc
+        .timestamp_millis_opt(ob.transaction_time)
+        .single()
+        .filter(|_| ob.transaction_time > 0)
+        .unwrap_or_else(Utc::now);
+
+    OrderBook {
+        market: market.to_string(),
+        asks,
+        bids,
+        total_ask_size,
+        total_bid_size,
+        timestamp,
+    }
+}
+
+fn convert_candle(k: BinanceKline, market: &str) -> Candle {
+    let timestamp = Utc
+        .timestamp_millis_opt(k.open_time)
+        .single()
+        .unwrap_or_else(Utc::now);
+
+    Candle {
+        market: market.to_string(),
+        timestamp,
+        open: k.open,
+        high: k.high,
+        low: k.low,
+        close: k.close,
+        volume: k.volume,
+    }
+}
+
+fn convert_order(o: BinanceOrder) -> Order {
+    let market = BinanceClient::to_market_code(&o.symbol);
+
+    let side = match o.side.as_str() {
+        "BUY" => OrderSide::Buy,
+        _ => OrderSide::Sell,
+    };
+
+    let order_type = match o.order_type.as_str() {
+        "MARKET" => OrderType::Market,
+        _ => OrderType::Limit,
+    };
+
+    let status = match o.status.as_str() {
+        "NEW" => OrderStatus::Wait,
+        "PARTIALLY_FILLED" => OrderStatus::PartiallyFilled,
+        "FILLED" => OrderStatus::Filled,
+        // 일부 체결 후 취소/만료된 주문은 체결분이 남아 있으므로 부분 체결로 분류
+        "CANCELED" | "EXPIRED" | "EXPIRED_IN_MATCH" if o.executed_qty > Decimal::ZERO => {
+            OrderStatus::PartiallyFilled
+        }
+        "CANCELED" | "EXPIRED" | "EXPIRED_IN_MATCH" => OrderStatus::Cancelled,
+        "REJECTED" => OrderStatus::Rejected,
+        _ => OrderStatus::Wait,
+    };
+
+    let created_at = o
+        .time
+        .or(o.update_time)
+        .and_then(|ms| Utc.timestamp_millis_opt(ms).single())
+        .unwrap_or_else(Utc::now);
+
+    Order {
+        id: o.order_id.to_string(),
+        market,
+        side,
+        order_type,
+        status,
+        volume: o.orig_qty,
+        remaining_volume: (o.orig_qty - o.executed_qty).max(Decimal::ZERO),
+        executed_volume: o.executed_qty,
+        price: (!o.price.is_zero()).then_some(o.price),
+        avg_price: o.avg_price,
+        paid_fee: Decimal::ZERO, // 주문 응답에 수수료가 없음 (체결 내역 API에서만 제공)
+        created_at,
+        identifier: o.client_order_id,
+    }
+}
+
+fn convert_balance(b: BinanceBalance) -> Balance {
+    Balance {
+        currency: b.asset,
+        balance: b.available_balance,
+        locked: (b.balance - b.available_balance).max(Decimal::ZERO),
+        avg_buy_price: Decimal::ZERO,
+        unit_currency: "USDT".to_string(),
+        equity: Some(b.balance + b.cross_un_pnl),
+        unrealised_pnl: Some(b.cross_un_pnl),
+    }
+}
+
+fn convert_position(p: BinancePositionRisk) -> PositionInfo {
+    // one-way 모드: 수량 부호가 방향
+    let side = if p.position_amt > Decimal::ZERO {
+        "Buy"
+    } else if p.position_amt < Decimal::ZERO {
+        "Sell"
+    } else {
+        ""
+    };
+
+    PositionInfo {
+        symbol: p.symbol,
+        side: side.to_string(),
+        size: p.position_amt.abs(),
+        entry_price: p.entry_price,
+        leverage: p.leverage,
+        unrealised_pnl: p.un_realized_profit,
+        liq_price: p.liquidation_price,
+    }
+}
+
+/// CandleInterval을 Binance 간격 문자열로 변환합니다.
+fn interval_to_binance(interval: CandleInterval) -> &'static str {
+    match interval {
+        CandleInterval::Minute1 => "1m",
+        CandleInterval::Minute3 => "3m",
+        CandleInterval::Minute5 => "5m",
+        CandleInterval::Minute10 => "15m", // Binance에는 10분 간격이 없어 15분 사용
+        CandleInterval::Minute15 => "15m",
+        CandleInterval::Minute30 => "30m",
+        CandleInterval::Minute60 => "1h",
+        CandleInterval::Minute240 => "4h",
+        CandleInterval::Day => "1d",
+        CandleInterval::Week => "1w",
+        CandleInterval::Month => "1M",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{MockClient, VenueSymbol, mock_client};
+    use mockito::Matcher;
+
+    impl MockClient for BinanceClient {
+        fn public() -> Self {
+            Self::new().unwrap()
+        }
+
+        fn with_mock_url(base_url: &str) -> Self {
+            let creds = BinanceCredentials::new("test-key", "test-secret");
+            Self::new_internal(Some(creds), base_url).unwrap()
+        }
+
+        fn has_credentials(&self) -> bool {
+            BinanceClient::has_credentials(self)
+        }
+
+        fn parse_error(body: &str, status: u16) -> ExchangeError {
+            parse_error(body, status)
+        }
+    }
+
+    impl VenueSymbol for BinanceClient {
+        fn to_venue_symbol(market: &str) -> String {
+            Self::to_binance_symbol(market)
+        }
+
+        fn from_venue_symbol(symbol: &str) -> String {
+            Self::to_market_code(symbol)
+        }
+    }
+
+    #[test]
+    fn test_depth_limit_snaps_up() {
+        assert_eq!(depth_limit(1), 5);
+        assert_eq!(depth_limit(20), 20);
+        assert_eq!(depth_limit(25), 50);
+        assert_eq!(depth_limit(5000), 1000);
+    }
+
+    #[test]
+    fn test_interval_to_binance() {
+        assert_eq!(interval_to_binance(CandleInterval::Minute1), "1m");
+        assert_eq!(interval_to_binance(CandleInterval::Minute60), "1h");
+        assert_eq!(interval_to_binance(CandleInterval::Month), "1M");
+    }
+
+    #[tokio::test]
+    async fn test_get_ticker() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/fapi/v1/ticker/24hr")
+            .match_query(Matcher::UrlEncoded("symbol".into(), "BTCUSDT".into()))
+            .with_body(
+                r#"{"symbol": "BTCUSDT", "priceChange": "-94.99999800",
+                    "priceChangePercent": "-2.50", "weightedAvgPrice": "0.29628482",
+                    "lastPrice": "4000.00", "lastQty": "200.00", "openPrice": "4094.99",
+                    "highPrice": "4100.00", "lowPrice": "3900.00", "volume": "8913.30",
+                    "quoteVolume": "15.30", "openTime": 1499783499040,
+                    "closeTime": 1499869899040, "firstId": 28385, "lastId": 28460,
+                    "count": 76}"#,
+            )
+            .create_async()
+            .await;
+
+        let client: BinanceClient = mock_client(&server);
+        let tickers = client.get_ticker(&["USDT-BTC"]).await.unwrap();
+        assert_eq!(tickers.len(), 1);
+        assert_eq!(tickers[0].market, "USDT-BTC");
+        assert_eq!(tickers[0].trade_price, Decimal::new(4000, 0));
+        assert_eq!(tickers[0].change, PriceChange::Fall);
+        assert_eq!(tickers[0].change_rate, Decimal::new(-25, 3));
+    }
+
+    #[tokio::test]
+    async fn test_place_order_linear_signed_request() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/fapi/v1/order")
+            .match_header(HEADER_API_KEY, "test-key")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("symbol".into(), "BTCUSDT".into()),
+                Matcher::UrlEncoded("side".into(), "SELL".into()),
+                Matcher::UrlEncoded("type".into(), "LIMIT".into()),
+                Matcher::UrlEncoded("quantity".into(), "0.01".into()),
+                Matcher::UrlEncoded("price".into(), "42000".into()),
+                Matcher::UrlEncoded("timeInForce".into(), "IOC".into()),
+                Matcher::UrlEncoded("reduceOnly".into(), "true".into()),
+                Matcher::UrlEncoded("newClientOrderId".into(), "arb-abc".into()),
+                Matcher::UrlEncoded("recvWindow".into(), "5000".into()),
+                Matcher::Regex("timestamp=\\d+&signature=[0-9a-f]{64}$".into()),
+            ]))
+            // IOC 잔량이 만료된 부분 체결 응답
+            .with_body(
+                r#"{"orderId": 8389765, "symbol": "BTCUSDT", "status": "EXPIRED",
+                    "clientOrderId": "arb-abc", "price": "42000.0", "avgPrice": "42000.0",
+                    "origQty": "0.010", "executedQty": "0.004", "type": "LIMIT",
+                    "side": "SELL", "reduceOnly": true, "updateTime": 1707177600000}"#,
+            )
+            .create_async()
+            .await;
+
+        let client: BinanceClient = mock_client(&server);
+        let mut request =
+            OrderRequest::limit_sell("USDT-BTC", Decimal::new(420000, 1), Decimal::new(10, 3));
+        request.time_in_force = Some(TimeInForce::Ioc);
+        request.identifier = Some("arb-abc".to_string());
+
+        let order = client.place_order_linear(&request, true).await.unwrap();
+        mock.assert_async().await;
+        assert_eq!(order.id, "8389765");
+        assert_eq!(order.status, OrderStatus::PartiallyFilled);
+        assert_eq!(order.remaining_volume, Decimal::new(6, 3));
+        assert_eq!(order.market, "USDT-BTC");
+        assert_eq!(order.identifier.as_deref(), Some("arb-abc"));
+        assert_eq!(order.price, Some(Decimal::new(42000, 0)));
+        // 발주 후에는 주문 ID만으로 심볼을 찾을 수 있어야 함
+        assert_eq!(client.cached_symbol("8389765").as_deref(), Some("BTCUSDT"));
+        assert_eq!(client.cached_symbol("arb-abc").as_deref(), Some("BTCUSDT"));
+    }
+
+    #[tokio::test]
+    async fn test_place_order_linear_maps_error_response() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", "/fapi/v1/order")
+            .match_query(Matcher::Any)
+            .with_status(400)
+            .with_body(r#"{"code": -2019, "msg": "Margin is insufficient."}"#)
+            .create_async()
+            .await;
+
+        let client: BinanceClient = mock_client(&server);
+        let request = OrderRequest::market_sell("USDT-BTC", Decimal::new(1, 3));
+        let err = client
+            .place_order_linear(&request, false)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ExchangeError::InsufficientFunds(_)));
+    }
+
+    #[tokio::test]
+    async fn test_get_positions_linear_filters_flat_positions() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/fapi/v2/positionRisk")
+            .match_query(Matcher::Any)
+            .with_body(
+                r#"[
+                    {"symbol": "BTCUSDT", "positionAmt": "-0.010", "entryPrice": "42000.0",
+                     "markPrice": "41900.0", "unRealizedProfit": "1.00000000",
+                     "liquidationPrice": "60000.0", "leverage": "2", "positionSide": "BOTH"},
+                    {"symbol": "ETHUSDT", "positionAmt": "0.000", "entryPrice": "0.0",
+                     "markPrice": "3000.0", "unRealizedProfit": "0.00000000",
+                     "liquidationPrice": "0", "leverage": "20", "positionSide": "BOTH"}
+                ]"#,
+            )
+            .create_async()
+            .await;
+
+        let client: BinanceClient = mock_client(&server);
+        let positions = client.get_positions_linear("").await.unwrap();
+        assert_eq!(positions.len(), 1);
+        assert_eq!(positions[0].symbol, "BTCUSDT");
+        assert_eq!(positions[0].side, "Sell");
+        assert_eq!(positions[0].size, Decimal::new(10, 3));
+    }
+
+    #[tokio::test]
+    async fn test_get_instrument_info_from_filters() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/fapi/v1/exchangeInfo")
+            .with_body(
+                r#"{"timezone": "UTC", "symbols": [{"symbol": "BTCUSDT", "filters": [
+                    {"filterType": "PRICE_FILTER", "tickSize": "0.10"},
+                    {"filterType": "LOT_SIZE", "stepSize": "0.001", "minQty": "0.001", "maxQty": "1000"},
+                    {"filterType": "MIN_NOTIONAL", "notional": "100"}
+                ]}]}"#,
+            )
+            .create_async()
+            .await;
+
+        let client: BinanceClient = mock_client(&server);
+        let info = client.get_instrument_info("BTCUSDT").await.unwrap();
+        assert_eq!(info.tick_size, Decimal::new(1, 1));
+        assert_eq!(info.qty_step, Decimal::new(1, 3));
+        assert_eq!(info.min_notional, Decimal::from(100));
+        assert!(client.get_instrument_info("ETHUSDT").await.is_err());
+    }
+}
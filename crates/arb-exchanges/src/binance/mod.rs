@@ -0,0 +1,39 @@
+//! Binance USDⓈ-M 선물 거래소 SDK 구현.
+//!
+//! 이 모듈은 Bybit linear의 대체 헤지 거래소로 사용할 수 있는
+//! Binance USDⓈ-M 무기한 선물 클라이언트를 제공합니다.
+//!
+//! # 기능
+//!
+//! - 시장 데이터 API: 시세(Ticker), 호가창, 캔들(klines)
+//! - 선물 주문 API: 주문 생성(reduce-only 포함), 취소, 조회, 포지션 (인증 필요)
+//! - 계정 API: 선물 지갑 잔고 (인증 필요)
+//! - 쿼리 문자열 HMAC-SHA256 인증
+//! - `bookTicker` WebSocket 실시간 best bid/ask
+//!
+//! # 예제
+//!
+//! ```no_run
+//! use arb_exchanges::BinanceClient;
+//! use arb_exchange::MarketData;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = BinanceClient::new()?;
+//!
+//!     // 시세 조회 (공통 마켓 형식 QUOTE-BASE 사용)
+//!     let tickers = client.get_ticker(&["USDT-BTC"]).await?;
+//!     println!("BTC Price: {}", tickers[0].trade_price);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod auth;
+mod client;
+mod stream;
+mod types;
+
+pub use auth::BinanceCredentials;
+pub use client::BinanceClient;
+pub use types::*;
@@ -0,0 +1,511 @@
+//! Binance USDⓈ-M 선물 WebSocket 실시간 마켓 데이터 스트림 구현.
+//!
+//! `MarketStream` trait을 구현하여 Binance의 `bookTicker` (best bid/ask)
+//! 데이터를 WebSocket으로 실시간 수신합니다.
+
+use arb_exchange::error::{ExchangeError, ExchangeResult};
+use arb_exchange::stream::{MarketEvent, MarketStream, StreamCommand, StreamConfig};
+use async_trait::async_trait;
+use chrono::{TimeZone, Utc};
+use futures_util::{SinkExt, StreamExt};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::sync::{Mutex, mpsc};
+use tokio_tungstenite::connect_async;
+use tokio_tungstenite::tungstenite::Message;
+use tracing::{debug, error, info, trace, warn};
+
+use crate::binance::client::BinanceClient;
+use crate::clock::ServerClock;
+
+/// Binance USDⓈ-M 선물 WebSocket URL (메인넷).
+const BINANCE_WS_FUTURES_URL: &str = "wss://fstream.binance.com/ws";
+
+/// Binance bookTicker 이벤트 (`{symbol}@bookTicker`).
+#[derive(Debug, Deserialize)]
+struct BinanceBookTicker {
+    /// 이벤트 유형 ("bookTicker").
+    #[serde(rename = "e")]
+    event_type: Option<String>,
+    /// 심볼 (예: "BTCUSDT").
+    #[serde(rename = "s")]
+    symbol: String,
+    /// Best bid price.
+    #[serde(rename = "b")]
+    bid_price: String,
+    /// Best ask price.
+    #[serde(rename = "a")]
+    ask_price: String,
+    /// 거래 엔진 시각 (ms).
+    #[serde(rename = "T")]
+    transaction_time: Option<i64>,
+    /// 이벤트 송신 시각 (ms).
+    #[serde(rename = "E")]
+    event_time: Option<i64>,
+}
+
+/// WebSocket task의 내부 상태.
+struct StreamState {
+    /// 구독 해제 시그널을 보내는 sender.
+    shutdown_tx: Option<tokio::sync::oneshot::Sender<()>>,
+    /// WebSocket task join handle.
+    task_handle: Option<tokio::task::JoinHandle<()>>,
+    /// 동적 구독 변경 명령을 보내는 sender.
+    command_tx: Option<mpsc::Sender<StreamCommand>>,
+}
+
+/// Binance MarketStream 구현을 위한 내부 상태.
+pub(crate) struct BinanceStreamInner {
+    state: Mutex<Option<StreamState>>,
+    config: StreamConfig,
+}
+
+impl BinanceStreamInner {
+    pub(crate) fn new(config: StreamConfig) -> Self {
+        Self {
+            state: Mutex::new(None),
+            config,
+        }
+    }
+}
+
+#[async_trait]
+impl MarketStream for BinanceClient {
+    fn stream_name(&self) -> &str {
+        "Binance"
+    }
+
+    async fn subscribe(&self, markets: &[&str]) -> ExchangeResult<mpsc::Receiver<MarketEvent>> {
+        let inner = self.stream_inner();
+
+        // 기존 구독이 있으면 먼저 해제
+        {
+            let mut state_guard = inner.state.lock().await;
+            if let Some(old_state) = state_guard.take() {
+                if let Some(tx) = old_state.shutdown_tx {
+                    let _ = tx.send(());
+                }
+                if let Some(handle) = old_state.task_handle {
+                    handle.abort();
+                }
+                debug!("기존 Binance WebSocket 구독 해제");
+            }
+        }
+
+        let buffer_size = inner.config.channel_buffer_size;
+        let (event_tx, event_rx) = mpsc::channel(buffer_size);
+        let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel();
+        let (command_tx, command_rx) = mpsc::channel::<StreamCommand>(64);
+
+        let topics: Vec<String> = markets.iter().map(|m| book_ticker_topic(m)).collect();
+        let config = inner.config.clone();
+        let clock = Arc::clone(&self.clock);
+
+        info!(topics = ?topics, "Binance WebSocket 구독 시작");
+
+        let task_handle = tokio::spawn(async move {
+            binance_ws_loop(topics, event_tx, shutdown_rx, command_rx, config, clock).await;
+        });
+
+        let mut state_guard = inner.state.lock().await;
+        *state_guard = Some(StreamState {
+            shutdown_tx: Some(shutdown_tx),
+            task_handle: Some(task_handle),
+            command_tx: Some(command_tx),
+        });
+
+        Ok(event_rx)
+    }
+
+    async fn unsubscribe(&self) -> ExchangeResult<()> {
+        let inner = self.stream_inner();
+        let mut state_guard = inner.state.lock().await;
+
+        if let Some(state) = state_guard.take() {
+            info!("Binance WebSocket 구독 해제");
+            if let Some(tx) = state.shutdown_tx {
+                let _ = tx.send(());
+            }
+            if let Some(handle) = state.task_handle {
+                handle.abort();
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn subscribe_markets(&self, markets: &[&str]) -> ExchangeResult<()> {
+        self.send_stream_command(StreamCommand::Subscribe(
+            markets.iter().map(|m| m.to_string()).collect(),
+        ))
+        .await
+    }
+
+    async fn unsubscribe_markets(&self, markets: &[&str]) -> ExchangeResult<()> {
+        self.send_stream_command(StreamCommand::Unsubscribe(
+            markets.iter().map(|m| m.to_string()).collect(),
+        ))
+        .await
+    }
+}
+
+impl BinanceClient {
+    /// 실행 중인 WebSocket 루프에 동적 구독 명령을 전달합니다.
+    async fn send_stream_command(&self, command: StreamCommand) -> ExchangeResult<()> {
+        let state_guard = self.stream_inner().state.lock().await;
+        let tx = state_guard
+            .as_ref()
+            .and_then(|state| state.command_tx.as_ref())
+            .ok_or_else(|| ExchangeError::WebSocketError("not subscribed".into()))?;
+        tx.send(command)
+            .await
+            .map_err(|_| ExchangeError::WebSocketError("command channel closed".into()))
+    }
+}
+
+/// 심볼의 bookTicker 스트림 이름을 만듭니다 (Binance 스트림 이름은 소문자).
+fn book_ticker_topic(symbol: &str) -> String {
+    format!("{}@bookTicker", symbol.to_lowercase())
+}
+
+/// 신규 구독 대상 토픽만 추출하고 현재 목록에 반영합니다.
+fn build_subscribe_topics(symbols: &[String], current_topics: &mut Vec<String>) -> Vec<String> {
+    let mut subscribe_topics = Vec::new();
+    for symbol in symbols {
+        let topic = book_ticker_topic(symbol);
+        if !current_topics.contains(&topic) {
+            current_topics.push(topic.clone());
+            subscribe_topics.push(topic);
+        }
+    }
+    subscribe_topics
+}
+
+/// 현재 구독 중인 토픽만 해제 대상으로 추출하고 현재 목록에서 제거합니다.
+fn build_unsubscribe_topics(symbols: &[String], current_topics: &mut Vec<String>) -> Vec<String> {
+    let remove_topics: Vec<String> = symbols
+        .iter()
+        .map(|s| book_ticker_topic(s))
+        .filter(|topic| current_topics.contains(topic))
+        .collect();
+
+    if !remove_topics.is_empty() {
+        current_topics.retain(|topic| !remove_topics.contains(topic));
+    }
+
+    remove_topics
+}
+
+/// SUBSCRIBE/UNSUBSCRIBE 요청 메시지를 만듭니다.
+fn build_request(method: &str, topics: &[String], id: u64) -> String {
+    serde_json::json!({
+        "method": method,
+        "params": topics,
+        "id": id
+    })
+    .to_string()
+}
+
+/// Binance WebSocket 이벤트 루프 (재연결 + 동적 구독 포함).
+///
+/// Binance는 서버가 ping 프레임을 보내고 클라이언트가 pong으로 응답하는 방식이므로
+/// 별도의 애플리케이션 heartbeat는 보내지 않습니다.
+async fn binance_ws_loop(
+    initial_topics: Vec<String>,
+    event_tx: mpsc::Sender<MarketEvent>,
+    mut shutdown_rx: tokio::sync::oneshot::Receiver<()>,
+    mut command_rx: mpsc::Receiver<StreamCommand>,
+    config: StreamConfig,
+    clock: Arc<ServerClock>,
+) {
+    let mut retry_count: u32 = 0;
+    let mut backoff = config.initial_backoff;
+    // 현재 구독 중인 토픽 목록 (재연결 시 사용)
+    let mut current_topics = initial_topics;
+    // 요청 ID (응답 매칭용, 연결 간 단조 증가)
+    let mut request_id: u64 = 0;
+
+    loop {
+        // 종료 확인
+        if shutdown_rx.try_recv().is_ok() {
+            info!("Binance WebSocket 종료 요청");
+            break;
+        }
+
+        request_id += 1;
+        match connect_and_subscribe(&current_topics, request_id).await {
+            Ok(ws_stream) => {
+                info!("Binance WebSocket 연결 성공");
+                retry_count = 0;
+                backoff = config.initial_backoff;
+
+                let (mut write, mut read) = ws_stream.split();
+
+                loop {
+                    tokio::select! {
+                        _ = &mut shutdown_rx => {
+                            info!("Binance WebSocket 종료 요청 (루프 내)");
+                            let _ = write.close().await;
+                            return;
+                        }
+                        cmd = command_rx.recv() => {
+                            let (method, topics) = match cmd {
+                                Some(StreamCommand::Subscribe(symbols)) => {
+                                    ("SUBSCRIBE", build_subscribe_topics(&symbols, &mut current_topics))
+                                }
+                                Some(StreamCommand::Unsubscribe(symbols)) => {
+                                    ("UNSUBSCRIBE", build_unsubscribe_topics(&symbols, &mut current_topics))
+                                }
+                                None => {
+                                    debug!("Binance command 채널 닫힘");
+                                    continue;
+                                }
+                            };
+
+                            if topics.is_empty() {
+                                debug!(method, "Binance 동적 구독 변경 스킵: 변경 대상 없음");
+                                continue;
+                            }
+
+                            info!(method, topics = ?topics, "Binance 동적 구독 변경");
+                            request_id += 1;
+                            let msg = build_request(method, &topics, request_id);
+                            if let Err(e) = write.send(Message::Text(msg.into())).await {
+                                error!(error = %e, method, "Binance 구독 메시지 전송 실패");
+                                break;
+                            }
+                        }
+                        msg = read.next() => {
+                            match msg {
+                                Some(Ok(Message::Text(text))) => {
+                                    if let Some(event) = parse_binance_book_ticker(&text) {
+                                        clock.observe_feed_latency(event.timestamp().timestamp_millis());
+                                        match event_tx.try_send(event) {
+                                            Ok(()) => {
+                                                trace!("Binance 이벤트 전송 성공");
+                                            }
+                                            Err(mpsc::error::TrySendError::Full(_)) => {
+                                                warn!("Binance 이벤트 채널 가득 참 — 이벤트 드롭");
+                                            }
+                                            Err(mpsc::error::TrySendError::Closed(_)) => {
+                                                debug!("Binance 이벤트 채널 닫힘 — 종료");
+                                                return;
+                                            }
+                                        }
+                                    }
+                                }
+                                Some(Ok(Message::Ping(data))) => {
+                                    let _ = write.send(Message::Pong(data)).await;
+                                }
+                                Some(Ok(Message::Close(_))) => {
+                                    warn!("Binance WebSocket 서버에서 Close 수신");
+                                    break;
+                                }
+                                Some(Err(e)) => {
+                                    error!(error = %e, "Binance WebSocket 수신 에러");
+                                    break;
+                                }
+                                None => {
+                                    warn!("Binance WebSocket 스트림 종료");
+                                    break;
+                                }
+                                _ => {}
+                            }
+                        }
+                    }
+                }
+            }
+            Err(e) => {
+                error!(error = %e, "Binance WebSocket 연결 실패");
+            }
+        }
+
+        // 재연결 로직
+        retry_count += 1;
+        arb_metrics::global()
+            .counter(
+                "arb_ws_reconnects_total",
+                "WebSocket 재연결 시도 횟수",
+                &[("exchange", "binance")],
+            )
+            .inc();
+        if config.max_retries > 0 && retry_count > config.max_retries {
+            error!(
+                retries = retry_count,
+                "Binance WebSocket 최대 재시도 초과 — 스트림 종료"
+            );
+            break;
+        }
+
+        warn!(
+            retry = retry_count,
+            backoff_ms = backoff.as_millis(),
+            "Binance WebSocket 재연결 대기"
+        );
+
+        tokio::select! {
+            _ = &mut shutdown_rx => {
+                info!("Binance WebSocket 재연결 대기 중 종료 요청");
+                return;
+            }
+            _ = tokio::time::sleep(backoff) => {}
+        }
+
+        // exponential backoff (최대값 제한)
+        backoff = std::cmp::min(backoff * 2, config.max_backoff);
+    }
+}
+
+/// Binance WebSocket에 연결하고 구독 메시지를 보냅니다.
+async fn connect_and_subscribe(
+    topics: &[String],
+    request_id: u64,
+) -> Result<
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>,
+    Box<dyn std::error::Error + Send + Sync>,
+> {
+    let (mut ws_stream, response) = connect_async(BINANCE_WS_FUTURES_URL).await?;
+
+    debug!(status = ?response.status(), "Binance WebSocket 핸드셰이크 완료");
+
+    if !topics.is_empty() {
+        // 구독 메시지: {"method": "SUBSCRIBE", "params": ["btcusdt@bookTicker", ...], "id": 1}
+        let subscribe_msg = build_request("SUBSCRIBE", topics, request_id);
+        debug!(msg = %subscribe_msg, "Binance 구독 메시지 전송");
+        ws_stream.send(Message::Text(subscribe_msg.into())).await?;
+    }
+
+    Ok(ws_stream)
+}
+
+/// Binance WebSocket 메시지를 MarketEvent::BestQuote로 파싱합니다.
+///
+/// 구독 응답(`{"result": null, "id": 1}`)과 에러 응답은 None을 반환합니다.
+fn parse_binance_book_ticker(text: &str) -> Option<MarketEvent> {
+    let value: serde_json::Value = serde_json::from_str(text).ok()?;
+
+    if value.get("id").is_some() {
+        if let Some(err) = value.get("error") {
+            warn!(error = %err, "Binance WebSocket 요청 실패");
+        }
+        return None;
+    }
+
+    let ticker: BinanceBookTicker = serde_json::from_value(value).ok()?;
+    if ticker
+        .event_type
+        .as_deref()
+        .is_some_and(|e| e != "bookTicker")
+    {
+        return None;
+    }
+
+    let bid = Decimal::from_str(&ticker.bid_price).ok()?;
+    let ask = Decimal::from_str(&ticker.ask_price).ok()?;
+
+    let timestamp = ticker
+        .transaction_time
+        .or(ticker.event_time)
+        .and_then(|ts| Utc.timestamp_millis_opt(ts).single())
+        .unwrap_or_else(Utc::now);
+
+    Some(MarketEvent::BestQuote {
+        market: ticker.symbol.to_uppercase(),
+        bid,
+        ask,
+        timestamp,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_binance_book_ticker() {
+        let json = r#"{
+            "e": "bookTicker",
+            "u": 400900217,
+            "E": 1707177600005,
+            "T": 1707177600000,
+            "s": "BTCUSDT",
+            "b": "99500.50",
+            "B": "31.21000000",
+            "a": "99501.00",
+            "A": "40.66000000"
+        }"#;
+
+        let event = parse_binance_book_ticker(json);
+        if let Some(MarketEvent::BestQuote {
+            market,
+            bid,
+            ask,
+            timestamp,
+        }) = event
+        {
+            assert_eq!(market, "BTCUSDT");
+            assert_eq!(bid, Decimal::from_str("99500.50").unwrap());
+            assert_eq!(ask, Decimal::from_str("99501.00").unwrap());
+            assert_eq!(timestamp.timestamp_millis(), 1707177600000);
+        } else {
+            panic!("Expected BestQuote event");
+        }
+    }
+
+    #[test]
+    fn test_parse_binance_subscribe_response() {
+        assert!(parse_binance_book_ticker(r#"{"result": null, "id": 1}"#).is_none());
+    }
+
+    #[test]
+    fn test_parse_binance_error_response() {
+        let json = r#"{"error": {"code": 2, "msg": "Invalid request"}, "id": 3}"#;
+        assert!(parse_binance_book_ticker(json).is_none());
+    }
+
+    #[test]
+    fn test_parse_binance_other_event_ignored() {
+        let json = r#"{"e": "markPriceUpdate", "E": 1707177600000, "s": "BTCUSDT",
+            "p": "99500.0", "b": "1", "a": "2"}"#;
+        assert!(parse_binance_book_ticker(json).is_none());
+    }
+
+    #[test]
+    fn test_book_ticker_topic_lowercase() {
+        assert_eq!(book_ticker_topic("BTCUSDT"), "btcusdt@bookTicker");
+    }
+
+    #[test]
+    fn test_build_subscribe_topics_dedup() {
+        let mut current_topics = vec!["btcusdt@bookTicker".to_string()];
+        let symbols = vec!["BTCUSDT".to_string(), "ETHUSDT".to_string()];
+
+        let subscribe_topics = build_subscribe_topics(&symbols, &mut current_topics);
+        assert_eq!(subscribe_topics, vec!["ethusdt@bookTicker".to_string()]);
+        assert_eq!(current_topics.len(), 2);
+    }
+
+    #[test]
+    fn test_build_unsubscribe_topics_only_existing() {
+        let mut current_topics = vec![
+            "btcusdt@bookTicker".to_string(),
+            "ethusdt@bookTicker".to_string(),
+        ];
+        let symbols = vec!["BTCUSDT".to_string(), "XRPUSDT".to_string()];
+
+        let remove_topics = build_unsubscribe_topics(&symbols, &mut current_topics);
+        assert_eq!(remove_topics, vec!["btcusdt@bookTicker".to_string()]);
+        assert_eq!(current_topics, vec!["ethusdt@bookTicker".to_string()]);
+    }
+
+    #[test]
+    fn test_build_request() {
+        let msg = build_request("SUBSCRIBE", &["btcusdt@bookTicker".to_string()], 7);
+        let value: serde_json::Value = serde_json::from_str(&msg).unwrap();
+        assert_eq!(value["method"], "SUBSCRIBE");
+        assert_eq!(value["params"][0], "btcusdt@bookTicker");
+        assert_eq!(value["id"], 7);
+    }
+}
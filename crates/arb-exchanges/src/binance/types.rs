@@ -0,0 +1,373 @@
+//! Binance USDⓈ-M 선물 전용 타입 및 API 응답 구조체.
+//!
+//! 이 타입들은 Binance `/fapi` 응답을 역직렬화하는 데 사용되며,
+//! 이후 공통 거래소 타입으로 변환됩니다.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer};
+
+/// Binance 에러 응답 본문.
+///
+/// ```json
+/// {"code": -2011, "msg": "Unknown order sent."}
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct BinanceErrorResponse {
+    /// 에러 코드 (음수).
+    pub code: i64,
+    /// 에러 메시지.
+    pub msg: String,
+}
+
+/// Binance 서버 시각 (`/fapi/v1/time`).
+#[derive(Debug, Deserialize)]
+pub struct BinanceServerTime {
+    /// 서버 시각 (밀리초).
+    #[serde(rename = "serverTime")]
+    pub server_time: i64,
+}
+
+/// Binance 24시간 티커 (`/fapi/v1/ticker/24hr`).
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Binance24hrTicker {
+    /// 심볼 (예: "BTCUSDT").
+    pub symbol: String,
+    /// 24시간 가격 변동 (절대값).
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub price_change: Decimal,
+    /// 24시간 가격 변동률 (백분율, 예: "1.23" = 1.23%).
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub price_change_percent: Decimal,
+    /// 최근 체결가.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub last_price: Decimal,
+    /// 24시간 시가.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub open_price: Decimal,
+    /// 24시간 최고가.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub high_price: Decimal,
+    /// 24시간 최저가.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub low_price: Decimal,
+    /// 24시간 거래량 (기준 통화).
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub volume: Decimal,
+    /// 24시간 거래 금액 (USDT).
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub quote_volume: Decimal,
+    /// 통계 종료 시각 (밀리초).
+    pub close_time: i64,
+}
+
+/// Binance 호가창 (`/fapi/v1/depth`).
+#[derive(Debug, Deserialize)]
+pub struct BinanceDepth {
+    /// 호가창 업데이트 ID.
+    #[serde(rename = "lastUpdateId")]
+    pub last_update_id: i64,
+    /// 거래 엔진 시각 (밀리초).
+    #[serde(rename = "T", default)]
+    pub transaction_time: i64,
+    /// 매수 호가 [가격, 수량].
+    pub bids: Vec<BinanceDepthLevel>,
+    /// 매도 호가 [가격, 수량].
+    pub asks: Vec<BinanceDepthLevel>,
+}
+
+/// Binance 호가 레벨 [가격, 수량].
+#[derive(Debug, Deserialize)]
+pub struct BinanceDepthLevel(
+    #[serde(deserialize_with = "deserialize_decimal_string")] pub Decimal,
+    #[serde(deserialize_with = "deserialize_decimal_string")] pub Decimal,
+);
+
+/// Binance 캔들 (`/fapi/v1/klines`).
+///
+/// API는 `[openTime, open, high, low, close, volume, closeTime, ...]` 배열을 반환합니다.
+#[derive(Debug)]
+pub struct BinanceKline {
+    /// 시작 시각 (밀리초).
+    pub open_time: i64,
+    /// 시가.
+    pub open: Decimal,
+    /// 고가.
+    pub high: Decimal,
+    /// 저가.
+    pub low: Decimal,
+    /// 종가.
+    pub close: Decimal,
+    /// 거래량 (기준 통화).
+    pub volume: Decimal,
+}
+
+impl<'de> Deserialize<'de> for BinanceKline {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let arr: Vec<serde_json::Value> = Vec::deserialize(deserializer)?;
+        if arr.len() < 6 {
+            return Err(serde::de::Error::custom("kline array too short"));
+        }
+
+        let parse_decimal = |v: &serde_json::Value| -> Result<Decimal, D::Error> {
+            v.as_str()
+                .ok_or_else(|| serde::de::Error::custom("expected string"))?
+                .parse::<Decimal>()
+                .map_err(serde::de::Error::custom)
+        };
+
+        Ok(Self {
+            open_time: arr[0]
+                .as_i64()
+                .ok_or_else(|| serde::de::Error::custom("expected open time"))?,
+            open: parse_decimal(&arr[1])?,
+            high: parse_decimal(&arr[2])?,
+            low: parse_decimal(&arr[3])?,
+            close: parse_decimal(&arr[4])?,
+            volume: parse_decimal(&arr[5])?,
+        })
+    }
+}
+
+/// Binance 주문 (`/fapi/v1/order` 응답).
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceOrder {
+    /// 주문 ID.
+    pub order_id: i64,
+    /// 심볼.
+    pub symbol: String,
+    /// 주문 상태 (NEW, PARTIALLY_FILLED, FILLED, CANCELED, REJECTED, EXPIRED, EXPIRED_IN_MATCH).
+    pub status: String,
+    /// 클라이언트 주문 ID.
+    #[serde(default)]
+    pub client_order_id: Option<String>,
+    /// 주문 가격 (시장가는 "0").
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub price: Decimal,
+    /// 평균 체결가 (미체결은 "0").
+    #[serde(default, deserialize_with = "deserialize_optional_decimal_string")]
+    pub avg_price: Option<Decimal>,
+    /// 주문 수량.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub orig_qty: Decimal,
+    /// 체결 수량.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub executed_qty: Decimal,
+    /// 주문 유형 (LIMIT, MARKET 등).
+    #[serde(rename = "type")]
+    pub order_type: String,
+    /// 주문 방향 (BUY, SELL).
+    pub side: String,
+    /// 포지션 감소 전용 여부.
+    #[serde(default)]
+    pub reduce_only: bool,
+    /// 주문 생성 시각 (밀리초, 조회 응답에만 포함).
+    #[serde(default)]
+    pub time: Option<i64>,
+    /// 마지막 갱신 시각 (밀리초).
+    #[serde(default)]
+    pub update_time: Option<i64>,
+}
+
+/// Binance 선물 자산 잔고 (`/fapi/v2/balance`).
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceBalance {
+    /// 자산 (예: "USDT").
+    pub asset: String,
+    /// 지갑 잔고.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub balance: Decimal,
+    /// 주문 가능 잔고.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub available_balance: Decimal,
+    /// 교차 마진 미실현 손익.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub cross_un_pnl: Decimal,
+}
+
+/// Binance 포지션 (`/fapi/v2/positionRisk`).
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinancePositionRisk {
+    /// 심볼.
+    pub symbol: String,
+    /// 포지션 수량 (롱 양수, 숏 음수).
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub position_amt: Decimal,
+    /// 평균 진입가.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub entry_price: Decimal,
+    /// 레버리지.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub leverage: Decimal,
+    /// 미실현 손익.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub un_realized_profit: Decimal,
+    /// 청산 가격.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub liquidation_price: Decimal,
+}
+
+/// Binance 거래 규격 (`/fapi/v1/exchangeInfo`).
+#[derive(Debug, Deserialize)]
+pub struct BinanceExchangeInfo {
+    /// 심볼 목록.
+    pub symbols: Vec<BinanceSymbolInfo>,
+}
+
+/// Binance 심볼 거래 규격.
+#[derive(Debug, Deserialize)]
+pub struct BinanceSymbolInfo {
+    /// 심볼.
+    pub symbol: String,
+    /// 거래 필터 목록.
+    pub filters: Vec<BinanceSymbolFilter>,
+}
+
+/// Binance 심볼 필터.
+///
+/// 사용하지 않는 필터 유형은 [`BinanceSymbolFilter::Other`]로 수용합니다.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "filterType")]
+pub enum BinanceSymbolFilter {
+    /// 가격 규격.
+    #[serde(rename = "PRICE_FILTER", rename_all = "camelCase")]
+    Price {
+        #[serde(deserialize_with = "deserialize_decimal_string")]
+        tick_size: Decimal,
+    },
+    /// 지정가 수량 규격.
+    #[serde(rename = "LOT_SIZE", rename_all = "camelCase")]
+    LotSize {
+        #[serde(deserialize_with = "deserialize_decimal_string")]
+        step_size: Decimal,
+        #[serde(deserialize_with = "deserialize_decimal_string")]
+        min_qty: Decimal,
+        #[serde(deserialize_with = "deserialize_decimal_string")]
+        max_qty: Decimal,
+    },
+    /// 최소 주문 금액.
+    #[serde(rename = "MIN_NOTIONAL")]
+    MinNotional {
+        #[serde(deserialize_with = "deserialize_decimal_string")]
+        notional: Decimal,
+    },
+    /// 그 외 필터.
+    #[serde(other)]
+    Other,
+}
+
+/// Binance 마크 가격 및 펀딩 정보 (`/fapi/v1/premiumIndex`).
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinancePremiumIndex {
+    /// 심볼.
+    pub symbol: String,
+    /// 마크 가격.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub mark_price: Decimal,
+    /// 직전 펀딩레이트.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub last_funding_rate: Decimal,
+    /// 다음 펀딩 시각 (밀리초).
+    pub next_funding_time: i64,
+}
+
+/// 문자열에서 Decimal로 역직렬화.
+fn deserialize_decimal_string<'de, D>(deserializer: D) -> Result<Decimal, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    if s.is_empty() {
+        return Ok(Decimal::ZERO);
+    }
+    s.parse::<Decimal>().map_err(serde::de::Error::custom)
+}
+
+/// 문자열에서 Optional Decimal로 역직렬화 (빈 문자열과 0은 None).
+fn deserialize_optional_decimal_string<'de, D>(deserializer: D) -> Result<Option<Decimal>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let opt: Option<String> = Option::deserialize(deserializer)?;
+    match opt {
+        Some(s) if !s.is_empty() => {
+            let value = s.parse::<Decimal>().map_err(serde::de::Error::custom)?;
+            Ok((!value.is_zero()).then_some(value))
+        }
+        _ => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_binance_kline() {
+        let json = r#"[1499040000000, "0.01634790", "0.80000000", "0.01575800", "0.01577100",
+            "148976.11427815", 1499644799999, "2434.19055334", 308, "1756.87402397",
+            "28.46694368", "17928899.62484339"]"#;
+        let kline: BinanceKline = serde_json::from_str(json).unwrap();
+        assert_eq!(kline.open_time, 1499040000000);
+        assert_eq!(kline.close, Decimal::new(1577100, 8));
+    }
+
+    #[test]
+    fn test_deserialize_binance_order() {
+        let json = r#"{
+            "orderId": 22542179,
+            "symbol": "BTCUSDT",
+            "status": "FILLED",
+            "clientOrderId": "arb-0190",
+            "price": "0",
+            "avgPrice": "42000.10",
+            "origQty": "0.010",
+            "executedQty": "0.010",
+            "cumQuote": "420.0010",
+            "timeInForce": "GTC",
+            "type": "MARKET",
+            "reduceOnly": true,
+            "side": "SELL",
+            "updateTime": 1707177600000
+        }"#;
+        let order: BinanceOrder = serde_json::from_str(json).unwrap();
+        assert_eq!(order.order_id, 22542179);
+        assert_eq!(order.avg_price, Some(Decimal::new(4200010, 2)));
+        assert!(order.reduce_only);
+        assert_eq!(order.time, None);
+    }
+
+    #[test]
+    fn test_deserialize_binance_order_zero_avg_price() {
+        let json = r#"{"orderId": 1, "symbol": "BTCUSDT", "status": "NEW",
+            "price": "41000", "avgPrice": "0.00000", "origQty": "1", "executedQty": "0",
+            "type": "LIMIT", "side": "BUY", "time": 1707177600000}"#;
+        let order: BinanceOrder = serde_json::from_str(json).unwrap();
+        assert_eq!(order.avg_price, None);
+        assert_eq!(order.client_order_id, None);
+    }
+
+    #[test]
+    fn test_deserialize_symbol_filters() {
+        let json = r#"{"symbol": "BTCUSDT", "filters": [
+            {"filterType": "PRICE_FILTER", "minPrice": "556.80", "maxPrice": "4529764", "tickSize": "0.10"},
+            {"filterType": "LOT_SIZE", "stepSize": "0.001", "minQty": "0.001", "maxQty": "1000"},
+            {"filterType": "MARKET_LOT_SIZE", "stepSize": "0.001", "minQty": "0.001", "maxQty": "120"},
+            {"filterType": "MIN_NOTIONAL", "notional": "100"}
+        ]}"#;
+        let info: BinanceSymbolInfo = serde_json::from_str(json).unwrap();
+        assert_eq!(info.filters.len(), 4);
+        assert!(matches!(info.filters[2], BinanceSymbolFilter::Other));
+        assert!(matches!(
+            info.filters[3],
+            BinanceSymbolFilter::MinNotional { notional } if notional == Decimal::from(100)
+        ));
+    }
+}
@@ -3,6 +3,7 @@
 //! 이 모듈은 Bithumb API 인증을 위한 JWT 토큰 생성을 처리합니다.
 //! Bithumb은 비공개 API 요청에 SHA512 쿼리 해시와 함께 JWT (HS256)를 사용합니다.
 
+use arb_config::SecretString;
 use arb_exchange::ExchangeError;
 use jsonwebtoken::{EncodingKey, Header, encode};
 use serde::Serialize;
@@ -26,7 +27,7 @@ struct JwtPayload {
 #[derive(Clone)]
 pub struct BithumbCredentials {
     access_key: String,
-    secret_key: String,
+    secret_key: SecretString,
 }
 
 impl std::fmt::Debug for BithumbCredentials {
@@ -53,7 +54,7 @@ impl BithumbCredentials {
     pub fn new(access_key: impl Into<String>, secret_key: impl Into<String>) -> Self {
         Self {
             access_key: access_key.into(),
-            secret_key: secret_key.into(),
+            secret_key: SecretString::new(secret_key),
         }
     }
 
@@ -118,7 +119,7 @@ impl BithumbCredentials {
     /// JWT 페이로드를 인코딩합니다.
     fn encode_token(&self, payload: &JwtPayload) -> Result<String, ExchangeError> {
         let header = Header::default(); // 기본적으로 HS256 사용
-        let key = EncodingKey::from_secret(self.secret_key.as_bytes());
+        let key = EncodingKey::from_secret(self.secret_key.expose_secret().as_bytes());
 
         encode(&header, payload, &key).map_err(|e| ExchangeError::AuthError(e.to_string()))
     }
@@ -13,6 +13,7 @@ use crate::bithumb::types::{
     BithumbBalance, BithumbCancelV2Response, BithumbCandle, BithumbError, BithumbOrder,
     BithumbOrderRequest, BithumbOrderV2Response, BithumbOrderbook, BithumbTicker,
 };
+use crate::credentials::CredentialSlot;
 use crate::rate_limit::RateLimiter;
 use chrono::{DateTime, NaiveDateTime, Utc};
 use reqwest::Client;
@@ -35,7 +36,8 @@ const BITHUMB_BURST: u32 = 2;
 /// Private API를 사용하려면 인증 정보를 제공해야 합니다.
 pub struct BithumbClient {
     client: Client,
-    pub(crate) credentials: Option<BithumbCredentials>,
+    /// 인증 정보. 실행 중 [`Self::set_credentials`]로 교체됩니다.
+    credentials: CredentialSlot<BithumbCredentials>,
     /// API 레이트 리밋터.
     limiter: Arc<RateLimiter>,
 }
@@ -43,7 +45,7 @@ pub struct BithumbClient {
 impl std::fmt::Debug for BithumbClient {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("BithumbClient")
-            .field("credentials", &self.credentials.is_some())
+            .field("credentials", &self.credentials.is_set())
             .finish()
     }
 }
@@ -64,7 +66,7 @@ impl BithumbClient {
 
         Ok(Self {
             client,
-            credentials: None,
+            credentials: CredentialSlot::new(None),
             limiter: Arc::new(RateLimiter::new(
                 "bithumb",
                 BITHUMB_RATE_LIMIT,
@@ -96,7 +98,7 @@ impl BithumbClient {
 
         Ok(Self {
             client,
-            credentials: Some(BithumbCredentials::new(access_key, secret_key)),
+            credentials: CredentialSlot::new(Some(BithumbCredentials::new(access_key, secret_key))),
             limiter: Arc::new(RateLimiter::new(
                 "bithumb",
                 BITHUMB_RATE_LIMIT,
@@ -106,10 +108,26 @@ impl BithumbClient {
     }
 
     /// 인증 정보가 있으면 반환합니다.
-    fn credentials(&self) -> ExchangeResult<&BithumbCredentials> {
+    fn credentials(&self) -> ExchangeResult<Arc<BithumbCredentials>> {
+        self.credentials.get()
+    }
+
+    /// 실행 중 API 키를 교체합니다 (키 로테이션).
+    ///
+    /// 이미 서명된 요청은 이전 키로 완료됩니다.
+    ///
+    /// # 인자
+    ///
+    /// * `access_key` - Bithumb API 액세스 키
+    /// * `secret_key` - Bithumb API 시크릿 키
+    pub fn set_credentials(&self, access_key: impl Into<String>, secret_key: impl Into<String>) {
         self.credentials
-            .as_ref()
-            .ok_or_else(|| ExchangeError::AuthError("Credentials not provided".to_string()))
+            .set(BithumbCredentials::new(access_key, secret_key));
+    }
+
+    /// 인증 정보가 설정되어 있으면 true를 반환합니다.
+    pub fn has_credentials(&self) -> bool {
+        self.credentials.is_set()
     }
 
     /// Public 엔드포인트에 GET 요청을 보냅니다.
@@ -266,6 +284,7 @@ impl BithumbClient {
                 ExchangeError::InsufficientFunds(message.clone())
             }
             (_, "notfoundmarket") => ExchangeError::MarketNotFound(message.clone()),
+            (_, "order_not_found") => ExchangeError::OrderNotFound(message.clone()),
             (403, "market_offline") => ExchangeError::ExchangeOffline(message.clone()),
             (429, _) | (418, _) => ExchangeError::RateLimitExceeded(message.clone()),
             _ => ExchangeError::UnknownError {
@@ -462,6 +481,12 @@ impl OrderManagement for BithumbClient {
         Ok(convert_order(bithumb_order))
     }
 
+    async fn get_order_by_client_id(&self, client_order_id: &str) -> ExchangeResult<Order> {
+        let params = [("identifier", client_order_id)];
+        let bithumb_order: BithumbOrder = self.get_private("/v1/order", Some(&params)).await?;
+        Ok(convert_order(bithumb_order))
+    }
+
     async fn get_open_orders(&self, market: Option<&str>) -> ExchangeResult<Vec<Order>> {
         let mut params = vec![("state", "wait")];
         if let Some(m) = market {
@@ -551,8 +576,8 @@ fn convert_orderbook(ob: BithumbOrderbook) -> OrderBook {
         .collect();
 
     // 매도호가는 오름차순, 매수호가는 내림차순 정렬
-    asks.sort_by(|a, b| a.price.cmp(&b.price));
-    bids.sort_by(|a, b| b.price.cmp(&a.price));
+    asks.sort_by_key(|a| a.price);
+    bids.sort_by_key(|b| std::cmp::Reverse(b.price));
 
     OrderBook {
         market: ob.market,
@@ -645,7 +670,7 @@ mod tests {
         let client = BithumbClient::new();
         assert!(client.is_ok());
         let client = client.unwrap();
-        assert!(client.credentials.is_none());
+        assert!(!client.has_credentials());
     }
 
     #[test]
@@ -653,7 +678,7 @@ mod tests {
         let client = BithumbClient::with_credentials("access_key", "secret_key");
         assert!(client.is_ok());
         let client = client.unwrap();
-        assert!(client.credentials.is_some());
+        assert!(client.has_credentials());
     }
 
     #[test]
@@ -10,6 +10,7 @@
 //!
 //! 서명은 소문자 16진수로 변환됩니다.
 
+use arb_config::SecretString;
 use arb_exchange::ExchangeError;
 use hmac::{Hmac, Mac};
 use sha2::Sha256;
@@ -25,7 +26,7 @@ pub const DEFAULT_RECV_WINDOW: u64 = 5000;
 #[derive(Clone)]
 pub struct BybitCredentials {
     api_key: String,
-    secret_key: String,
+    secret_key: SecretString,
     recv_window: u64,
 }
 
@@ -54,7 +55,7 @@ impl BybitCredentials {
     pub fn new(api_key: impl Into<String>, secret_key: impl Into<String>) -> Self {
         Self {
             api_key: api_key.into(),
-            secret_key: secret_key.into(),
+            secret_key: SecretString::new(secret_key),
             recv_window: DEFAULT_RECV_WINDOW,
         }
     }
@@ -74,14 +75,13 @@ impl BybitCredentials {
     ) -> Self {
         Self {
             api_key: api_key.into(),
-            secret_key: secret_key.into(),
+            secret_key: SecretString::new(secret_key),
             recv_window,
         }
     }
 
     /// API 키를 반환합니다.
     #[inline]
-    #[allow(dead_code)]
     pub fn api_key(&self) -> &str {
         &self.api_key
     }
@@ -141,9 +141,16 @@ impl BybitCredentials {
         self.hmac_sign(&payload)
     }
 
+    /// private WebSocket `auth` 요청 서명을 생성합니다.
+    ///
+    /// 페이로드는 `GET/realtime{expires}` 입니다.
+    pub fn sign_ws_auth(&self, expires: u64) -> Result<String, ExchangeError> {
+        self.hmac_sign(&format!("GET/realtime{expires}"))
+    }
+
     /// HMAC-SHA256 서명을 계산합니다.
     fn hmac_sign(&self, payload: &str) -> Result<String, ExchangeError> {
-        let mut mac = HmacSha256::new_from_slice(self.secret_key.as_bytes())
+        let mut mac = HmacSha256::new_from_slice(self.secret_key.expose_secret().as_bytes())
             .map_err(|e| ExchangeError::AuthError(format!("HMAC key error: {}", e)))?;
 
         mac.update(payload.as_bytes());
@@ -160,8 +167,22 @@ impl BybitCredentials {
     /// # 반환값
     ///
     /// (timestamp, api_key, recv_window, signature) 튜플
+    #[allow(dead_code)]
     pub fn auth_headers_get(&self, query_string: &str) -> Result<AuthHeaders, ExchangeError> {
-        let timestamp = Self::timestamp();
+        self.auth_headers_get_at(Self::timestamp(), query_string)
+    }
+
+    /// 지정한 타임스탬프로 GET 요청 인증 헤더를 생성합니다.
+    ///
+    /// # 인자
+    ///
+    /// * `timestamp` - 서버 시각 오프셋을 보정한 밀리초 타임스탬프
+    /// * `query_string` - URL 쿼리 문자열 (앞의 `?` 제외)
+    pub fn auth_headers_get_at(
+        &self,
+        timestamp: u64,
+        query_string: &str,
+    ) -> Result<AuthHeaders, ExchangeError> {
         let signature = self.sign_get(timestamp, query_string)?;
 
         Ok(AuthHeaders {
@@ -181,8 +202,22 @@ impl BybitCredentials {
     /// # 반환값
     ///
     /// 인증 헤더 구조체
+    #[allow(dead_code)]
     pub fn auth_headers_post(&self, body: &str) -> Result<AuthHeaders, ExchangeError> {
-        let timestamp = Self::timestamp();
+        self.auth_headers_post_at(Self::timestamp(), body)
+    }
+
+    /// 지정한 타임스탬프로 POST 요청 인증 헤더를 생성합니다.
+    ///
+    /// # 인자
+    ///
+    /// * `timestamp` - 서버 시각 오프셋을 보정한 밀리초 타임스탬프
+    /// * `body` - JSON 본문 문자열
+    pub fn auth_headers_post_at(
+        &self,
+        timestamp: u64,
+        body: &str,
+    ) -> Result<AuthHeaders, ExchangeError> {
         let signature = self.sign_post(timestamp, body)?;
 
         Ok(AuthHeaders {
@@ -265,6 +300,16 @@ mod tests {
         assert!(ts > 1577836800000);
     }
 
+    #[test]
+    fn test_sign_ws_auth() {
+        let creds = BybitCredentials::new("my_api_key", "my_secret_key");
+        let signature = creds.sign_ws_auth(1672531210000).unwrap();
+
+        assert_eq!(signature.len(), 64);
+        assert_eq!(signature, creds.sign_ws_auth(1672531210000).unwrap());
+        assert_ne!(signature, creds.sign_ws_auth(1672531210001).unwrap());
+    }
+
     #[test]
     fn test_sign_get() {
         let creds = BybitCredentials::new("my_api_key", "my_secret_key");
@@ -316,6 +361,17 @@ mod tests {
         assert_eq!(headers.signature.len(), 64);
     }
 
+    #[test]
+    fn test_auth_headers_get_at() {
+        let creds = BybitCredentials::new("api_key", "secret_key");
+        let timestamp = 1672531200000u64;
+        let query = "symbol=BTCUSDT";
+        let headers = creds.auth_headers_get_at(timestamp, query).unwrap();
+
+        assert_eq!(headers.timestamp, timestamp);
+        assert_eq!(headers.signature, creds.sign_get(timestamp, query).unwrap());
+    }
+
     #[test]
     fn test_auth_headers_post() {
         let creds = BybitCredentials::new("api_key", "secret_key");
@@ -328,6 +384,17 @@ mod tests {
         assert_eq!(headers.signature.len(), 64);
     }
 
+    #[test]
+    fn test_auth_headers_post_at() {
+        let creds = BybitCredentials::new("api_key", "secret_key");
+        let timestamp = 1672531200000u64;
+        let body = r#"{"symbol":"BTCUSDT"}"#;
+        let headers = creds.auth_headers_post_at(timestamp, body).unwrap();
+
+        assert_eq!(headers.timestamp, timestamp);
+        assert_eq!(headers.signature, creds.sign_post(timestamp, body).unwrap());
+    }
+
     #[test]
     fn test_build_query_string() {
         let params = vec![("category", "spot"), ("symbol", "BTCUSDT"), ("limit", "10")];
@@ -5,12 +5,14 @@
 use crate::bybit::auth::{AuthHeaders, BybitCredentials, build_query_string};
 use crate::bybit::stream::BybitStreamInner;
 use crate::bybit::types::{
-    BybitCancelOrderRequest, BybitCancelOrderResult, BybitCreateOrderResult,
-    BybitInstrumentInfoList, BybitKlineList, BybitLinearTickerList, BybitOrder, BybitOrderList,
-    BybitOrderRequest, BybitOrderbookResult, BybitPositionList, BybitResponse,
-    BybitSetLeverageRequest, BybitSwitchIsolatedRequest, BybitTickerList, BybitWalletBalanceResult,
-    LinearTickerInfo,
+    BybitApiKeyInfo, BybitCancelOrderRequest, BybitCancelOrderResult, BybitCreateOrderResult,
+    BybitDcpRequest, BybitInstrumentInfoList, BybitKlineList, BybitLinearTickerList, BybitOrder,
+    BybitOrderList, BybitOrderRequest, BybitOrderbookResult, BybitPositionList, BybitResponse,
+    BybitServerTime, BybitSetLeverageRequest, BybitSwitchIsolatedRequest, BybitTickerList,
+    BybitWalletBalanceResult, LinearTickerInfo,
 };
+use crate::clock::{ClockSample, ServerClock, ServerTimeSync, local_now_ms};
+use crate::credentials::CredentialSlot;
 use crate::rate_limit::RateLimiter;
 use arb_exchange::{
     Balance, Candle, CandleInterval, ExchangeError, ExchangeResult, InstrumentDataProvider,
@@ -22,7 +24,7 @@ use chrono::{DateTime, TimeZone, Utc};
 use reqwest::Client;
 use rust_decimal::Decimal;
 use std::sync::Arc;
-use tracing::{debug, warn};
+use tracing::{debug, info, warn};
 
 /// Bybit V5 REST API 기본 URL (메인넷).
 const BASE_URL_MAINNET: &str = "https://api.bybit.com";
@@ -57,11 +59,14 @@ const BYBIT_EMERGENCY_BURST: u32 = 5;
 /// 비공개 API를 사용하려면 인증 정보를 제공해야 합니다.
 pub struct BybitClient {
     client: Client,
-    pub(crate) credentials: Option<BybitCredentials>,
+    /// 인증 정보. 복제본 간에 공유되어 [`Self::set_credentials`]로 교체됩니다.
+    credentials: CredentialSlot<BybitCredentials>,
     base_url: String,
     category: String,
     /// WebSocket 스트림 내부 상태.
     pub(crate) stream: Arc<BybitStreamInner>,
+    /// 서버 시계 추정기 (인증 타임스탬프 보정, 피드 지연 측정).
+    pub(crate) clock: Arc<ServerClock>,
     /// 공개 API (시세, 오더북) 레이트 리밋터.
     public_limiter: Arc<RateLimiter>,
     /// 비공개 API (주문, 잔고, 포지션) 레이트 리밋터.
@@ -77,7 +82,7 @@ impl std::fmt::Debug for BybitClient {
         f.debug_struct("BybitClient")
             .field("base_url", &self.base_url)
             .field("category", &self.category)
-            .field("credentials", &self.credentials.is_some())
+            .field("credentials", &self.credentials.is_set())
             .finish()
     }
 }
@@ -94,6 +99,7 @@ impl Clone for BybitClient {
             base_url: self.base_url.clone(),
             category: self.category.clone(),
             stream: Arc::clone(&self.stream),
+            clock: Arc::clone(&self.clock),
             public_limiter: Arc::clone(&self.public_limiter),
             private_limiter: Arc::clone(&self.private_limiter),
             emergency_limiter: Arc::clone(&self.emergency_limiter),
@@ -177,10 +183,11 @@ impl BybitClient {
 
         Ok(Self {
             client,
-            credentials,
+            credentials: CredentialSlot::new(credentials),
             base_url,
             category: DEFAULT_CATEGORY.to_string(),
             stream: Arc::new(BybitStreamInner::new(StreamConfig::default())),
+            clock: Arc::new(ServerClock::new("bybit")),
             public_limiter: Arc::new(RateLimiter::new(
                 "bybit-public",
                 BYBIT_PUBLIC_RATE_LIMIT,
@@ -204,6 +211,16 @@ impl BybitClient {
         &self.stream
     }
 
+    /// 테스트넷 클라이언트인지 반환합니다.
+    pub(crate) fn is_testnet(&self) -> bool {
+        self.base_url == BASE_URL_TESTNET
+    }
+
+    /// 서버 시각 오프셋을 보정한 인증 타임스탬프 (밀리초).
+    pub(crate) fn auth_timestamp(&self) -> u64 {
+        u64::try_from(self.clock.now_ms()).unwrap_or_else(|_| BybitCredentials::timestamp())
+    }
+
     /// 거래 카테고리를 설정합니다 (spot, linear, inverse, option).
     ///
     /// # 인자
@@ -216,10 +233,26 @@ impl BybitClient {
     }
 
     /// 사용 가능한 경우 인증 정보를 반환합니다.
-    fn credentials(&self) -> ExchangeResult<&BybitCredentials> {
+    pub(crate) fn credentials(&self) -> ExchangeResult<Arc<BybitCredentials>> {
+        self.credentials.get()
+    }
+
+    /// 실행 중 API 키를 교체합니다 (키 로테이션).
+    ///
+    /// 이 클라이언트의 모든 복제본에 즉시 반영되며, 이미 서명된 요청은 이전 키로 완료됩니다.
+    ///
+    /// # 인자
+    ///
+    /// * `api_key` - Bybit API 키
+    /// * `secret_key` - Bybit API 시크릿 키
+    pub fn set_credentials(&self, api_key: impl Into<String>, secret_key: impl Into<String>) {
         self.credentials
-            .as_ref()
-            .ok_or_else(|| ExchangeError::AuthError("Credentials not provided".to_string()))
+            .set(BybitCredentials::new(api_key, secret_key));
+    }
+
+    /// 인증 정보가 설정되어 있으면 true를 반환합니다.
+    pub fn has_credentials(&self) -> bool {
+        self.credentials.is_set()
     }
 
     /// 공개 엔드포인트에 GET 요청을 보냅니다.
@@ -254,7 +287,7 @@ impl BybitClient {
         debug!(endpoint, ?params, "Bybit private GET 요청");
 
         let query_string = build_query_string(params.iter().map(|(k, v)| (*k, *v)));
-        let auth = creds.auth_headers_get(&query_string)?;
+        let auth = creds.auth_headers_get_at(self.auth_timestamp(), &query_string)?;
 
         let response = self
             .client
@@ -286,7 +319,7 @@ impl BybitClient {
         debug!(endpoint, "Bybit private POST 요청");
 
         let body_json = serde_json::to_string(body).map_err(ExchangeError::JsonError)?;
-        let auth = creds.auth_headers_post(&body_json)?;
+        let auth = creds.auth_headers_post_at(self.auth_timestamp(), &body_json)?;
 
         let response = self
             .client
@@ -407,6 +440,32 @@ impl BybitClient {
     }
 }
 
+impl ServerTimeSync for BybitClient {
+    fn server_clock(&self) -> &ServerClock {
+        &self.clock
+    }
+
+    async fn sync_server_clock(&self) -> ExchangeResult<ClockSample> {
+        // rate limiter 대기가 RTT에 섞이지 않도록 토큰 확보 후 송신 시각을 측정합니다.
+        self.public_limiter.acquire().await;
+        let url = format!("{}/v5/market/time", self.base_url);
+        let sent_ms = local_now_ms();
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(ExchangeError::HttpError)?;
+        let received_ms = local_now_ms();
+
+        let result: BybitServerTime = self.handle_response(response).await?;
+        let server_ms = result.to_millis().ok_or_else(|| {
+            ExchangeError::ParseError(format!("invalid Bybit server time: {}", result.time_nano))
+        })?;
+        Ok(self.clock.record_probe(sent_ms, server_ms, received_ms))
+    }
+}
+
 impl MarketData for BybitClient {
     fn name(&self) -> &str {
         "Bybit"
@@ -615,6 +674,11 @@ impl OrderManagement for BybitClient {
             .ok_or_else(|| ExchangeError::OrderNotFound(order_id.to_string()))
     }
 
+    async fn get_order_by_client_id(&self, client_order_id: &str) -> ExchangeResult<Order> {
+        self.get_order_by_link_id(&self.category, client_order_id)
+            .await
+    }
+
     async fn get_open_orders(&self, market: Option<&str>) -> ExchangeResult<Vec<Order>> {
         let symbol;
         let mut params = vec![("category", self.category.as_str())];
@@ -622,6 +686,9 @@ impl OrderManagement for BybitClient {
         if let Some(m) = market {
             symbol = Self::to_bybit_symbol(m);
             params.push(("symbol", symbol.as_str()));
+        } else if self.category == "linear" {
+            // linear 전체 조회는 symbol 대신 settleCoin이 필수
+            params.push(("settleCoin", "USDT"));
         }
 
         let result: BybitOrderList = self.get_private("/v5/order/realtime", &params).await?;
@@ -725,6 +792,7 @@ impl InstrumentDataProvider for BybitClient {
             min_order_qty,
             max_order_qty,
             min_notional,
+            contract_multiplier: Decimal::ONE,
         })
     }
 }
@@ -742,6 +810,10 @@ impl arb_exchange::LinearOrderManagement for BybitClient {
         self.get_order_linear_impl(order_id).await
     }
 
+    async fn get_order_linear_by_client_id(&self, client_order_id: &str) -> ExchangeResult<Order> {
+        self.get_order_by_link_id("linear", client_order_id).await
+    }
+
     async fn cancel_order_linear(
         &self,
         order_id: &str,
@@ -850,6 +922,32 @@ impl BybitClient {
             .ok_or_else(|| ExchangeError::OrderNotFound(order_id.to_string()))
     }
 
+    /// `orderLinkId`(client order id)로 주문을 조회합니다.
+    ///
+    /// 실시간 주문 목록에 없으면(IOC 체결/취소 후 정리된 경우) 주문 이력에서 찾습니다.
+    ///
+    /// # 인자
+    ///
+    /// * `category` - 상품 카테고리 ("spot", "linear" 등)
+    /// * `order_link_id` - 발주 시 지정한 orderLinkId
+    pub async fn get_order_by_link_id(
+        &self,
+        category: &str,
+        order_link_id: &str,
+    ) -> ExchangeResult<Order> {
+        let params = [("category", category), ("orderLinkId", order_link_id)];
+
+        debug!(category, order_link_id, "Bybit orderLinkId 주문 조회 요청");
+
+        for endpoint in ["/v5/order/realtime", "/v5/order/history"] {
+            let result: BybitOrderList = self.get_private(endpoint, &params).await?;
+            if let Some(order) = result.list.into_iter().next() {
+                return Ok(convert_order(order));
+            }
+        }
+        Err(ExchangeError::OrderNotFound(order_link_id.to_string()))
+    }
+
     /// 선물(linear) 주문을 취소합니다.
     ///
     /// # 인자
@@ -983,6 +1081,40 @@ impl BybitClient {
         }
     }
 
+    /// 현재 API 키가 속한 계정의 UID를 조회합니다.
+    ///
+    /// 서브 계정 API 키면 서브 계정 UID를 반환하므로, 계정 프로필이 의도한
+    /// 서브 계정의 키인지 기동 시 확인하는 데 사용합니다.
+    pub async fn get_account_uid(&self) -> ExchangeResult<String> {
+        let info: BybitApiKeyInfo = self.get_private("/v5/user/query-api", &[]).await?;
+        debug!(
+            uid = info.user_id,
+            read_only = info.read_only,
+            "Bybit API 키 정보 조회 완료"
+        );
+        Ok(info.user_id.to_string())
+    }
+
+    /// Disconnect Cancel All(DCP) 시간창을 설정합니다 (선물/파생 상품).
+    ///
+    /// `dcp` 토픽을 구독한 private WebSocket 연결이 `time_window_sec` 이상 끊기면
+    /// Bybit이 미체결 파생 주문을 모두 취소합니다. 연결 유지는
+    /// [`Self::run_dcp_keepalive`]가 담당합니다.
+    pub async fn set_disconnect_cancel_all(&self, time_window_sec: u32) -> ExchangeResult<()> {
+        let body = BybitDcpRequest {
+            product: "DERIVATIVES".to_string(),
+            time_window: time_window_sec,
+        };
+        let _result: serde_json::Value = self
+            .post_private("/v5/order/disconnected-cancel-all", &body)
+            .await?;
+        info!(
+            time_window_sec = time_window_sec,
+            "Bybit DCP 시간창 설정 완료"
+        );
+        Ok(())
+    }
+
     /// 선물 포지션 정보를 조회합니다.
     ///
     /// # 인자
@@ -1082,7 +1214,7 @@ impl BybitClient {
         debug!(endpoint, "Bybit emergency POST 요청");
 
         let body_json = serde_json::to_string(body).map_err(ExchangeError::JsonError)?;
-        let auth = creds.auth_headers_post(&body_json)?;
+        let auth = creds.auth_headers_post_at(self.auth_timestamp(), &body_json)?;
 
         let response = self
             .client
@@ -1276,7 +1408,7 @@ mod tests {
         let client = BybitClient::new();
         assert!(client.is_ok());
         let client = client.unwrap();
-        assert!(client.credentials.is_none());
+        assert!(!client.has_credentials());
         assert_eq!(client.base_url, BASE_URL_MAINNET);
     }
 
@@ -1293,7 +1425,7 @@ mod tests {
         let client = BybitClient::with_credentials("api_key", "secret_key");
         assert!(client.is_ok());
         let client = client.unwrap();
-        assert!(client.credentials.is_some());
+        assert!(client.has_credentials());
     }
 
     #[test]
@@ -0,0 +1,185 @@
+//! Bybit Disconnect Cancel All (DCP) 보호.
+//!
+//! DCP 시간창을 설정한 뒤 `dcp` 토픽을 구독한 private WebSocket 연결을 유지합니다.
+//! 프로세스 크래시나 네트워크 단절로 연결이 시간창 이상 끊기면 Bybit이 미체결
+//! 파생 주문을 모두 취소하므로, 발주와 취소 사이에 죽어도 주문이 호가창에 남지 않습니다.
+
+use std::future::Future;
+use std::time::Duration;
+
+use futures_util::{SinkExt, StreamExt};
+use tokio_tungstenite::connect_async;
+use tokio_tungstenite::tungstenite::Message;
+use tracing::{debug, info, trace, warn};
+
+use crate::bybit::client::BybitClient;
+
+/// Bybit private WebSocket URL (메인넷).
+const BYBIT_WS_PRIVATE_URL: &str = "wss://stream.bybit.com/v5/private";
+
+/// Bybit private WebSocket URL (테스트넷).
+const BYBIT_WS_PRIVATE_TESTNET_URL: &str = "wss://stream-testnet.bybit.com/v5/private";
+
+/// private 연결 ping 주기 (Bybit 권장 20초).
+const DCP_PING_INTERVAL: Duration = Duration::from_secs(20);
+
+/// `auth` 요청 서명 유효 시간 (밀리초).
+const DCP_AUTH_EXPIRES_MS: u64 = 10_000;
+
+/// 재연결 최대 백오프.
+const DCP_MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// 이 시간 이상 유지된 연결이 끊기면 백오프를 초기화합니다.
+const DCP_STABLE_SESSION: Duration = Duration::from_secs(60);
+
+type DcpError = Box<dyn std::error::Error + Send + Sync>;
+
+impl BybitClient {
+    /// DCP 시간창을 설정하고 `shutdown`이 완료될 때까지 private 연결을 유지합니다.
+    ///
+    /// 연결이 끊기면 지수 백오프로 재연결합니다. 시간창 설정이 실패해도 연결은
+    /// 유지하며 (기존 계정 설정 사용), 인증 정보가 없으면 즉시 반환합니다.
+    pub async fn run_dcp_keepalive<F>(&self, time_window_sec: u32, shutdown: F)
+    where
+        F: Future<Output = ()>,
+    {
+        if !self.has_credentials() {
+            warn!("Bybit 인증 정보 없음 — DCP 비활성화");
+            return;
+        }
+        if let Err(e) = self.set_disconnect_cancel_all(time_window_sec).await {
+            warn!(error = %e, "Bybit DCP 시간창 설정 실패, 계정 기본값으로 연결 유지");
+        }
+
+        tokio::pin!(shutdown);
+        let mut backoff = Duration::from_secs(1);
+
+        loop {
+            let started_at = tokio::time::Instant::now();
+            tokio::select! {
+                _ = &mut shutdown => {
+                    info!("Bybit DCP 연결 종료 요청");
+                    return;
+                }
+                result = self.dcp_session() => match result {
+                    Ok(()) => warn!("Bybit DCP private 연결 종료"),
+                    Err(e) => warn!(error = %e, "Bybit DCP private 연결 실패"),
+                },
+            }
+
+            arb_metrics::global()
+                .counter(
+                    "arb_ws_reconnects_total",
+                    "WebSocket 재연결 시도 횟수",
+                    &[("exchange", "bybit_private")],
+                )
+                .inc();
+            if started_at.elapsed() >= DCP_STABLE_SESSION {
+                backoff = Duration::from_secs(1);
+            }
+
+            tokio::select! {
+                _ = &mut shutdown => {
+                    info!("Bybit DCP 재연결 대기 중 종료 요청");
+                    return;
+                }
+                _ = tokio::time::sleep(backoff) => {}
+            }
+            backoff = (backoff * 2).min(DCP_MAX_BACKOFF);
+        }
+    }
+
+    /// private 연결 하나를 인증/`dcp` 구독 후 끊길 때까지 유지합니다.
+    async fn dcp_session(&self) -> Result<(), DcpError> {
+        let creds = self.credentials()?;
+        let url = if self.is_testnet() {
+            BYBIT_WS_PRIVATE_TESTNET_URL
+        } else {
+            BYBIT_WS_PRIVATE_URL
+        };
+        let (ws_stream, _) = connect_async(url).await?;
+        let (mut write, mut read) = ws_stream.split();
+
+        let expires = self.auth_timestamp() + DCP_AUTH_EXPIRES_MS;
+        let signature = creds.sign_ws_auth(expires)?;
+        let auth = serde_json::json!({
+            "op": "auth",
+            "args": [creds.api_key(), expires, signature]
+        });
+        write.send(Message::Text(auth.to_string().into())).await?;
+        let subscribe = serde_json::json!({"op": "subscribe", "args": ["dcp"]});
+        write
+            .send(Message::Text(subscribe.to_string().into()))
+            .await?;
+
+        let mut ping = tokio::time::interval(DCP_PING_INTERVAL);
+        ping.tick().await;
+
+        loop {
+            tokio::select! {
+                _ = ping.tick() => {
+                    let msg = serde_json::json!({"op": "ping"});
+                    write.send(Message::Text(msg.to_string().into())).await?;
+                    trace!("Bybit DCP ping 전송");
+                }
+                msg = read.next() => match msg {
+                    Some(Ok(Message::Text(text))) => check_op_response(&text)?,
+                    Some(Ok(Message::Ping(data))) => {
+                        write.send(Message::Pong(data)).await?;
+                    }
+                    Some(Ok(Message::Close(_))) | None => return Ok(()),
+                    Some(Err(e)) => return Err(e.into()),
+                    _ => {}
+                },
+            }
+        }
+    }
+}
+
+/// private 연결의 오퍼레이션 응답을 확인합니다.
+///
+/// `auth`/`subscribe` 실패는 에러로 반환해 재연결하게 하고, 나머지는 무시합니다.
+fn check_op_response(text: &str) -> Result<(), DcpError> {
+    let Ok(value) = serde_json::from_str::<serde_json::Value>(text) else {
+        return Ok(());
+    };
+    let Some(op) = value.get("op").and_then(|v| v.as_str()) else {
+        return Ok(());
+    };
+    let success = value
+        .get("success")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(true);
+    match op {
+        "auth" | "subscribe" if !success => {
+            let ret_msg = value.get("ret_msg").and_then(|v| v.as_str()).unwrap_or("");
+            Err(format!("Bybit private {op} failed: {ret_msg}").into())
+        }
+        "auth" => {
+            info!("Bybit DCP private 연결 인증 완료");
+            Ok(())
+        }
+        "subscribe" => {
+            debug!("Bybit DCP 토픽 구독 완료");
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_op_response() {
+        assert!(check_op_response(r#"{"op":"auth","success":true,"ret_msg":""}"#).is_ok());
+        assert!(check_op_response(r#"{"op":"pong","success":true}"#).is_ok());
+        assert!(check_op_response("not json").is_ok());
+
+        let err = check_op_response(r#"{"op":"auth","success":false,"ret_msg":"Invalid sign"}"#)
+            .unwrap_err();
+        assert!(err.to_string().contains("Invalid sign"));
+        assert!(check_op_response(r#"{"op":"subscribe","success":false,"ret_msg":"x"}"#).is_err());
+    }
+}
@@ -41,6 +41,7 @@
 
 mod auth;
 mod client;
+mod dcp;
 mod stream;
 mod types;
 
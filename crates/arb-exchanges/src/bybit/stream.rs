@@ -11,6 +11,7 @@ use futures_util::{SinkExt, StreamExt};
 use rust_decimal::Decimal;
 use serde::Deserialize;
 use std::str::FromStr;
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::{Mutex, mpsc};
 use tokio_tungstenite::connect_async;
@@ -18,6 +19,7 @@ use tokio_tungstenite::tungstenite::Message;
 use tracing::{debug, error, info, trace, warn};
 
 use crate::bybit::client::BybitClient;
+use crate::clock::ServerClock;
 
 /// Bybit Linear WebSocket URL (메인넷).
 const BYBIT_WS_LINEAR_URL: &str = "wss://stream.bybit.com/v5/public/linear";
@@ -117,6 +119,7 @@ impl MarketStream for BybitClient {
         // tickers.{symbol} 형식으로 구독 토픽 생성
         let topics: Vec<String> = markets.iter().map(|m| format!("tickers.{m}")).collect();
         let config = inner.config.clone();
+        let clock = Arc::clone(&self.clock);
 
         info!(
             topics = ?topics,
@@ -124,7 +127,7 @@ impl MarketStream for BybitClient {
         );
 
         let task_handle = tokio::spawn(async move {
-            bybit_ws_loop(topics, event_tx, shutdown_rx, command_rx, config).await;
+            bybit_ws_loop(topics, event_tx, shutdown_rx, command_rx, config, clock).await;
         });
 
         let mut state_guard = inner.state.lock().await;
@@ -229,6 +232,7 @@ async fn bybit_ws_loop(
     mut shutdown_rx: tokio::sync::oneshot::Receiver<()>,
     mut command_rx: mpsc::Receiver<StreamCommand>,
     config: StreamConfig,
+    clock: Arc<ServerClock>,
 ) {
     let mut retry_count: u32 = 0;
     let mut backoff = config.initial_backoff;
@@ -334,6 +338,7 @@ async fn bybit_ws_loop(
                             match msg {
                                 Some(Ok(Message::Text(text))) => {
                                     if let Some(event) = parse_bybit_ticker(&text) {
+                                        clock.observe_feed_latency(event.timestamp().timestamp_millis());
                                         match event_tx.try_send(event) {
                                             Ok(()) => {
                                                 trace!("Bybit 이벤트 전송 성공");
@@ -377,6 +382,13 @@ async fn bybit_ws_loop(
 
         // 재연결 로직
         retry_count += 1;
+        arb_metrics::global()
+            .counter(
+                "arb_ws_reconnects_total",
+                "WebSocket 재연결 시도 횟수",
+                &[("exchange", "bybit")],
+            )
+            .inc();
         if config.max_retries > 0 && retry_count > config.max_retries {
             error!(
                 retries = retry_count,
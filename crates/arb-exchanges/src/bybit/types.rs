@@ -44,6 +44,30 @@ impl<T> BybitResponse<T> {
     }
 }
 
+/// Bybit 서버 시각 결과 (`/v5/market/time`).
+#[derive(Debug, Deserialize)]
+pub struct BybitServerTime {
+    /// 서버 시각 (초, 문자열).
+    #[serde(rename = "timeSecond")]
+    pub time_second: String,
+    /// 서버 시각 (나노초, 문자열).
+    #[serde(rename = "timeNano")]
+    pub time_nano: String,
+}
+
+impl BybitServerTime {
+    /// 서버 시각을 밀리초로 반환합니다 (나노초 우선, 실패 시 초 단위).
+    pub fn to_millis(&self) -> Option<i64> {
+        if let Ok(nanos) = self.time_nano.parse::<i128>() {
+            return i64::try_from(nanos / 1_000_000).ok();
+        }
+        self.time_second
+            .parse::<i64>()
+            .ok()
+            .and_then(|secs| secs.checked_mul(1000))
+    }
+}
+
 /// Bybit 티커 목록 결과.
 #[derive(Debug, Deserialize)]
 pub struct BybitTickerList {
@@ -435,6 +459,16 @@ pub struct BybitCancelOrderRequest {
     pub order_link_id: Option<String>,
 }
 
+/// Bybit Disconnect Cancel All 설정 요청 본문.
+#[derive(Debug, Serialize)]
+pub struct BybitDcpRequest {
+    /// 상품: OPTIONS, DERIVATIVES, SPOT.
+    pub product: String,
+    /// 연결 끊김 후 취소까지의 시간창 (초, 3~300).
+    #[serde(rename = "timeWindow")]
+    pub time_window: u32,
+}
+
 /// Bybit instrument info API 응답 (instruments-info endpoint).
 #[derive(Debug, Deserialize)]
 pub struct BybitInstrumentInfoList {
@@ -522,6 +556,17 @@ pub struct BybitPositionItem {
     pub liq_price: Option<Decimal>,
 }
 
+/// Bybit API 키 정보 (`/v5/user/query-api`).
+#[derive(Debug, Deserialize)]
+pub struct BybitApiKeyInfo {
+    /// API 키 소유 계정 UID (서브 계정 키면 서브 계정 UID).
+    #[serde(rename = "userID")]
+    pub user_id: i64,
+    /// 읽기 전용 여부 (0=읽기/쓰기, 1=읽기 전용).
+    #[serde(rename = "readOnly", default)]
+    pub read_only: i32,
+}
+
 /// Bybit 선물(linear) 티커 목록 결과.
 #[derive(Debug, Deserialize)]
 pub struct BybitLinearTickerList {
@@ -636,6 +681,14 @@ where
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_deserialize_api_key_info() {
+        let json = r#"{"id":"13770661","note":"sub","apiKey":"XXXX","readOnly":0,"secret":"","permissions":{"ContractTrade":["Order","Position"]},"ips":["*"],"type":1,"deadlineDay":83,"expiredAt":"2026-12-31T00:00:00Z","createdAt":"2026-01-01T00:00:00Z","unified":0,"uta":1,"userID":24617703,"inviterID":0,"vipLevel":"No VIP","mktMakerLevel":"0","affiliateID":0}"#;
+        let info: BybitApiKeyInfo = serde_json::from_str(json).unwrap();
+        assert_eq!(info.user_id, 24617703);
+        assert_eq!(info.read_only, 0);
+    }
+
     #[test]
     fn test_deserialize_bybit_response() {
         let json = r#"{
@@ -651,6 +704,19 @@ mod tests {
         assert_eq!(resp.ret_msg, "OK");
     }
 
+    #[test]
+    fn test_deserialize_bybit_server_time() {
+        let json = r#"{"timeSecond": "1688639403", "timeNano": "1688639403423213947"}"#;
+        let time: BybitServerTime = serde_json::from_str(json).unwrap();
+        assert_eq!(time.to_millis(), Some(1_688_639_403_423));
+
+        let fallback = BybitServerTime {
+            time_second: "1688639403".to_string(),
+            time_nano: String::new(),
+        };
+        assert_eq!(fallback.to_millis(), Some(1_688_639_403_000));
+    }
+
     #[test]
     fn test_deserialize_bybit_ticker() {
         let json = r#"{
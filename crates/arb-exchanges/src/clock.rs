@@ -0,0 +1,230 @@
+//! 거래소 서버 시각 동기화 모듈.
+//!
+//! 주기적인 서버 시각 probe로 로컬 시계와 거래소 시계의 차이(오프셋)를 추정합니다.
+//! probe마다 요청 송신/응답 수신 로컬 시각과 서버 시각을 기록하고, 최근 샘플 중
+//! 왕복 지연(RTT)이 가장 짧은 샘플의 오프셋을 채택합니다 (NTP 방식 최소 RTT 필터).
+//!
+//! 추정 오프셋은 다음에 사용됩니다.
+//! - 인증 타임스탬프 보정 (Bybit `X-BAPI-TIMESTAMP`)
+//! - 시계 오차 임계값 초과 감지 (알림은 호출자가 담당)
+//! - 실시간 피드 이벤트 시각과 로컬 수신 시각 비교 (피드 지연 측정)
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+
+use arb_exchange::ExchangeResult;
+use arb_metrics::LATENCY_BUCKETS;
+use chrono::Utc;
+use tracing::debug;
+
+/// 오프셋 추정에 사용하는 최근 샘플 수.
+pub const CLOCK_SAMPLE_WINDOW: usize = 8;
+
+/// 서버 시각 probe 샘플 하나의 측정 결과.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClockSample {
+    /// 서버 시각 - 로컬 시각 (밀리초, 양수면 로컬 시계가 느림).
+    pub offset_ms: i64,
+    /// 요청 왕복 지연 (밀리초).
+    pub rtt_ms: i64,
+}
+
+/// 거래소 하나의 서버 시계 추정기.
+///
+/// 클라이언트 복제본 간에 `Arc`로 공유되며, 모든 메서드는 `&self`로 동작합니다.
+#[derive(Debug)]
+pub struct ServerClock {
+    /// 거래소 이름 (메트릭 라벨).
+    exchange: &'static str,
+    /// 현재 채택된 오프셋 (밀리초).
+    offset_ms: AtomicI64,
+    /// 한 번이라도 probe에 성공했는지 여부.
+    synced: AtomicBool,
+    /// 최근 probe 샘플 (최대 [`CLOCK_SAMPLE_WINDOW`]개).
+    samples: Mutex<VecDeque<ClockSample>>,
+}
+
+impl ServerClock {
+    /// 오프셋 0으로 시작하는 새 추정기를 생성합니다.
+    pub fn new(exchange: &'static str) -> Self {
+        Self {
+            exchange,
+            offset_ms: AtomicI64::new(0),
+            synced: AtomicBool::new(false),
+            samples: Mutex::new(VecDeque::with_capacity(CLOCK_SAMPLE_WINDOW)),
+        }
+    }
+
+    /// 거래소 이름을 반환합니다.
+    pub fn exchange(&self) -> &'static str {
+        self.exchange
+    }
+
+    /// 현재 채택된 오프셋(서버 - 로컬, 밀리초)을 반환합니다.
+    pub fn offset_ms(&self) -> i64 {
+        self.offset_ms.load(Ordering::Relaxed)
+    }
+
+    /// 한 번이라도 probe에 성공했는지 반환합니다.
+    pub fn is_synced(&self) -> bool {
+        self.synced.load(Ordering::Relaxed)
+    }
+
+    /// 오프셋을 보정한 현재 서버 시각 추정값 (Unix epoch 밀리초).
+    pub fn now_ms(&self) -> i64 {
+        local_now_ms() + self.offset_ms()
+    }
+
+    /// probe 결과를 기록하고 오프셋 추정값을 갱신합니다.
+    ///
+    /// # 인자
+    ///
+    /// * `sent_ms` - 요청 송신 직전 로컬 시각
+    /// * `server_ms` - 응답에 담긴 서버 시각
+    /// * `received_ms` - 응답 수신 직후 로컬 시각
+    ///
+    /// 서버 시각은 왕복 구간의 중간 시점에 찍혔다고 가정합니다.
+    pub fn record_probe(&self, sent_ms: i64, server_ms: i64, received_ms: i64) -> ClockSample {
+        let rtt_ms = (received_ms - sent_ms).max(0);
+        let sample = ClockSample {
+            offset_ms: server_ms - (sent_ms + rtt_ms / 2),
+            rtt_ms,
+        };
+
+        let best = {
+            let mut samples = self.samples.lock().unwrap_or_else(|e| e.into_inner());
+            if samples.len() == CLOCK_SAMPLE_WINDOW {
+                samples.pop_front();
+            }
+            samples.push_back(sample);
+            samples
+                .iter()
+                .min_by_key(|s| s.rtt_ms)
+                .copied()
+                .unwrap_or(sample)
+        };
+
+        self.offset_ms.store(best.offset_ms, Ordering::Relaxed);
+        self.synced.store(true, Ordering::Relaxed);
+
+        let labels = [("exchange", self.exchange)];
+        arb_metrics::global()
+            .gauge(
+                "arb_exchange_clock_offset_ms",
+                "거래소 서버 시각 - 로컬 시각 추정 오프셋 (밀리초)",
+                &labels,
+            )
+            .set(best.offset_ms as f64);
+        arb_metrics::global()
+            .gauge(
+                "arb_exchange_clock_probe_rtt_ms",
+                "마지막 서버 시각 probe 왕복 지연 (밀리초)",
+                &labels,
+            )
+            .set(rtt_ms as f64);
+
+        debug!(
+            exchange = self.exchange,
+            sample_offset_ms = sample.offset_ms,
+            rtt_ms = rtt_ms,
+            offset_ms = best.offset_ms,
+            "서버 시각 probe 기록"
+        );
+
+        best
+    }
+
+    /// 피드 이벤트 시각과 로컬 수신 시각의 차이(밀리초)를 기록하고 반환합니다.
+    ///
+    /// 로컬 수신 시각에 오프셋을 더해 거래소 시계 기준으로 비교하므로,
+    /// 시계 오차가 피드 지연으로 잘못 집계되지 않습니다.
+    pub fn observe_feed_latency(&self, event_ms: i64) -> i64 {
+        let latency_ms = self.now_ms() - event_ms;
+        arb_metrics::global()
+            .histogram(
+                "arb_market_feed_latency_seconds",
+                "거래소 이벤트 시각부터 로컬 수신까지의 피드 지연 (초, 시계 오프셋 보정)",
+                &[("exchange", self.exchange)],
+                LATENCY_BUCKETS,
+            )
+            .observe(latency_ms.max(0) as f64 / 1000.0);
+        latency_ms
+    }
+}
+
+/// 서버 시각 probe를 지원하는 거래소 클라이언트.
+pub trait ServerTimeSync: Send + Sync {
+    /// 클라이언트가 공유하는 서버 시계 추정기를 반환합니다.
+    fn server_clock(&self) -> &ServerClock;
+
+    /// 서버 시각을 한 번 probe하고 추정기를 갱신합니다.
+    ///
+    /// 반환값은 갱신 후 채택된 샘플입니다.
+    fn sync_server_clock(&self) -> impl Future<Output = ExchangeResult<ClockSample>> + Send;
+}
+
+/// 로컬 시계 기준 현재 시각 (Unix epoch 밀리초).
+pub fn local_now_ms() -> i64 {
+    Utc::now().timestamp_millis()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_probe_midpoint_offset() {
+        let clock = ServerClock::new("test");
+        assert!(!clock.is_synced());
+
+        // 로컬 1000 송신, 1100 수신, 서버 1550 → 중간 1050 대비 +500
+        let sample = clock.record_probe(1000, 1550, 1100);
+        assert_eq!(sample.offset_ms, 500);
+        assert_eq!(sample.rtt_ms, 100);
+        assert_eq!(clock.offset_ms(), 500);
+        assert!(clock.is_synced());
+    }
+
+    #[test]
+    fn test_record_probe_prefers_lowest_rtt() {
+        let clock = ServerClock::new("test");
+        clock.record_probe(0, 40, 20); // offset 30, rtt 20
+        // RTT가 큰 샘플은 오프셋 추정에 채택되지 않음
+        let best = clock.record_probe(1000, 1900, 1800); // offset 500, rtt 800
+        assert_eq!(best.offset_ms, 30);
+        assert_eq!(clock.offset_ms(), 30);
+    }
+
+    #[test]
+    fn test_record_probe_window_evicts_old_samples() {
+        let clock = ServerClock::new("test");
+        clock.record_probe(0, 0, 0); // offset 0, rtt 0
+        for i in 0..CLOCK_SAMPLE_WINDOW as i64 {
+            let sent = 10_000 * (i + 1);
+            clock.record_probe(sent, sent + 250, sent + 100); // offset 200, rtt 100
+        }
+        // rtt 0 샘플이 윈도우 밖으로 밀려나 새 오프셋 채택
+        assert_eq!(clock.offset_ms(), 200);
+    }
+
+    #[test]
+    fn test_now_ms_applies_offset() {
+        let clock = ServerClock::new("test");
+        let local = local_now_ms();
+        clock.record_probe(local, local + 60_000, local);
+        let diff = clock.now_ms() - local_now_ms();
+        assert!((59_000..=60_000).contains(&diff), "diff={diff}");
+    }
+
+    #[test]
+    fn test_observe_feed_latency_uses_server_time() {
+        let clock = ServerClock::new("test");
+        let local = local_now_ms();
+        // 로컬 시계가 서버보다 5초 빠름 → 보정 없이는 지연이 5초 과대 집계
+        clock.record_probe(local, local - 5_000, local);
+        let latency = clock.observe_feed_latency(local - 5_000 - 100);
+        assert!((100..1_000).contains(&latency), "latency={latency}");
+    }
+}
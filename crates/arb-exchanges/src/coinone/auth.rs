@@ -0,0 +1,156 @@
+//! Coinone HMAC-SHA512 인증 모듈.
+//!
+//! Coinone Private API(v2.1)는 `access_token`과 `nonce`(UUID v4)를 포함한 JSON 본문을
+//! Base64로 인코딩해 `X-COINONE-PAYLOAD` 헤더로 보내고, 인코딩된 페이로드를
+//! 시크릿 키로 HMAC-SHA512 서명한 16진수 문자열을 `X-COINONE-SIGNATURE` 헤더로 보냅니다.
+
+use arb_config::SecretString;
+use arb_exchange::ExchangeError;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+
+type HmacSha512 = Hmac<Sha512>;
+
+/// 페이로드 헤더 이름.
+pub const HEADER_PAYLOAD: &str = "X-COINONE-PAYLOAD";
+/// 서명 헤더 이름.
+pub const HEADER_SIGNATURE: &str = "X-COINONE-SIGNATURE";
+
+/// 서명된 Private API 요청 본문.
+#[derive(Debug)]
+pub struct SignedPayload {
+    /// 요청 본문 (JSON).
+    pub body: String,
+    /// Base64 인코딩된 본문 (`X-COINONE-PAYLOAD`).
+    pub payload: String,
+    /// 16진수 HMAC-SHA512 서명 (`X-COINONE-SIGNATURE`).
+    pub signature: String,
+}
+
+/// Coinone API 인증을 위한 자격 증명.
+#[derive(Clone)]
+pub struct CoinoneCredentials {
+    access_token: String,
+    secret_key: SecretString,
+}
+
+impl std::fmt::Debug for CoinoneCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let masked_token = if self.access_token.len() >= 4 {
+            format!("{}****", &self.access_token[..4])
+        } else {
+            "****".to_string()
+        };
+        f.debug_struct("CoinoneCredentials")
+            .field("access_token", &masked_token)
+            .field("secret_key", &"****")
+            .finish()
+    }
+}
+
+impl CoinoneCredentials {
+    /// 새 자격 증명을 생성합니다.
+    ///
+    /// # 인자
+    ///
+    /// * `access_token` - Coinone 액세스 토큰
+    /// * `secret_key` - Coinone 시크릿 키
+    pub fn new(access_token: impl Into<String>, secret_key: impl Into<String>) -> Self {
+        Self {
+            access_token: access_token.into(),
+            secret_key: SecretString::new(secret_key),
+        }
+    }
+
+    /// 액세스 토큰을 반환합니다.
+    #[inline]
+    pub fn access_token(&self) -> &str {
+        &self.access_token
+    }
+
+    /// 인코딩된 페이로드를 서명합니다.
+    ///
+    /// # 반환값
+    ///
+    /// 소문자 16진수 HMAC-SHA512 서명
+    pub fn sign(&self, payload: &str) -> Result<String, ExchangeError> {
+        let mut mac = HmacSha512::new_from_slice(self.secret_key.expose_secret().as_bytes())
+            .map_err(|e| ExchangeError::AuthError(format!("Invalid secret key: {e}")))?;
+        mac.update(payload.as_bytes());
+        Ok(hex::encode(mac.finalize().into_bytes()))
+    }
+
+    /// 요청 파라미터에 `access_token`과 `nonce`를 더해 본문을 만들고 서명합니다.
+    ///
+    /// # 인자
+    ///
+    /// * `params` - 요청 파라미터 (JSON 객체)
+    /// * `nonce` - 요청마다 새로 만든 UUID v4 문자열
+    pub fn signed_payload(
+        &self,
+        mut params: serde_json::Map<String, serde_json::Value>,
+        nonce: &str,
+    ) -> Result<SignedPayload, ExchangeError> {
+        params.insert(
+            "access_token".to_string(),
+            serde_json::Value::String(self.access_token.clone()),
+        );
+        params.insert(
+            "nonce".to_string(),
+            serde_json::Value::String(nonce.to_string()),
+        );
+
+        let body = serde_json::Value::Object(params).to_string();
+        let payload = BASE64.encode(body.as_bytes());
+        let signature = self.sign(&payload)?;
+        Ok(SignedPayload {
+            body,
+            payload,
+            signature,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_is_hex_hmac_sha512() {
+        let creds = CoinoneCredentials::new("token", "secret");
+        let signature = creds.sign("eyJhIjoxfQ==").unwrap();
+
+        let mut mac = HmacSha512::new_from_slice(b"secret").unwrap();
+        mac.update(b"eyJhIjoxfQ==");
+        assert_eq!(signature, hex::encode(mac.finalize().into_bytes()));
+        // SHA-512 다이제스트(64바이트)의 16진수 길이
+        assert_eq!(signature.len(), 128);
+    }
+
+    #[test]
+    fn test_signed_payload_includes_token_and_nonce() {
+        let creds = CoinoneCredentials::new("token", "secret");
+        let mut params = serde_json::Map::new();
+        params.insert("order_id".to_string(), "abc".into());
+
+        let signed = creds.signed_payload(params, "nonce-1").unwrap();
+        let body: serde_json::Value = serde_json::from_str(&signed.body).unwrap();
+        assert_eq!(body["access_token"], "token");
+        assert_eq!(body["nonce"], "nonce-1");
+        assert_eq!(body["order_id"], "abc");
+
+        let decoded = BASE64.decode(&signed.payload).unwrap();
+        assert_eq!(decoded, signed.body.as_bytes());
+        assert_eq!(signed.signature, creds.sign(&signed.payload).unwrap());
+    }
+
+    #[test]
+    fn test_credentials_debug_masks_secrets() {
+        let creds = CoinoneCredentials::new("abcdefgh", "super-secret");
+        let debug = format!("{creds:?}");
+        assert!(debug.contains("abcd****"));
+        assert!(!debug.contains("super-secret"));
+    }
+}
@@ -0,0 +1,948 @@
+//! Coinone REST API 클라이언트 구현.
+//!
+//! 이 모듈은 Coinone Public API(v2)와 Private API(v2.1)와 상호작용하기 위한
+//! 메인 클라이언트를 제공합니다.
+//!
+//! Coinone은 마켓을 `{quote_currency}/{target_currency}` 쌍으로 지정하므로,
+//! 내부 마켓 코드("KRW-BTC")를 quote/target으로 분리해 요청합니다.
+//! 주문 조회·취소에도 마켓이 필요하므로 발주 시 주문 ID별 마켓을 기억해 둡니다.
+
+use crate::clock::ServerClock;
+use crate::coinone::auth::{CoinoneCredentials, HEADER_PAYLOAD, HEADER_SIGNATURE};
+use crate::coinone::stream::CoinoneStreamInner;
+use crate::coinone::types::{
+    CoinoneActiveOrders, CoinoneBalance, CoinoneBalances, CoinoneCandle, CoinoneChart,
+    CoinoneOrder, CoinoneOrderAck, CoinoneOrderDetail, CoinoneOrderbook, CoinoneStatus,
+    CoinoneTicker, CoinoneTickers,
+};
+use crate::credentials::CredentialSlot;
+use crate::order_cache::OrderKeyCache;
+use crate::rate_limit::RateLimiter;
+use arb_exchange::{
+    Balance, Candle, CandleInterval, ExchangeError, ExchangeName, ExchangeResult, MarketData,
+    Order, OrderBook, OrderBookLevel, OrderManagement, OrderRequest, OrderSide, OrderStatus,
+    OrderType, PriceChange, StreamConfig, Ticker, TimeInForce, create_market_code,
+    parse_market_code, to_exchange_format,
+};
+use chrono::{DateTime, TimeZone, Utc};
+use reqwest::Client;
+use rust_decimal::Decimal;
+use serde_json::{Map, Value};
+use std::sync::Arc;
+use tracing::{debug, info, warn};
+
+/// Coinone REST API 기본 URL.
+const BASE_URL: &str = "https://api.coinone.co.kr";
+
+/// Coinone API 레이트 리밋 (초당 요청 수).
+/// Private API 한도(초당 10건 내외)보다 보수적으로 초당 8회 적용.
+const COINONE_RATE_LIMIT: u32 = 8;
+/// Coinone API 최대 버스트 용량.
+const COINONE_BURST: u32 = 2;
+
+/// 주문 ID → 마켓 캐시 최대 항목 수.
+const ORDER_MARKET_CACHE_CAPACITY: usize = 4096;
+
+/// `/public/v2/chart` 1회 최대 개수.
+const MAX_CANDLES: u32 = 500;
+/// `/public/v2/orderbook`이 허용하는 `size` 값.
+const ORDERBOOK_SIZES: [u32; 3] = [5, 10, 15];
+
+/// Coinone API 클라이언트.
+///
+/// 이 클라이언트는 Public API와 Private API 모두 지원합니다.
+/// Private API를 사용하려면 액세스 토큰과 시크릿 키가 필요합니다.
+pub struct CoinoneClient {
+    client: Client,
+    /// 인증 정보. 복제본 간에 공유되어 [`Self::set_credentials`]로 교체됩니다.
+    credentials: CredentialSlot<CoinoneCredentials>,
+    base_url: String,
+    /// WebSocket 스트림 내부 상태.
+    pub(crate) stream: Arc<CoinoneStreamInner>,
+    /// 서버 시계 추정기 (피드 지연 측정).
+    pub(crate) clock: Arc<ServerClock>,
+    /// API 레이트 리밋터.
+    limiter: Arc<RateLimiter>,
+    /// 주문 ID / `user_order_id` → 마켓 코드 (주문 조회/취소에 필요).
+    order_markets: OrderKeyCache,
+}
+
+impl std::fmt::Debug for CoinoneClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CoinoneClient")
+            .field("base_url", &self.base_url)
+            .field("credentials", &self.credentials.is_set())
+            .finish()
+    }
+}
+
+impl Clone for CoinoneClient {
+    /// 클라이언트를 복제합니다.
+    ///
+    /// 커넥션 풀, rate limiter 상태, 주문 마켓 캐시를 복제본과 공유합니다.
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            credentials: self.credentials.clone(),
+            base_url: self.base_url.clone(),
+            stream: Arc::clone(&self.stream),
+            clock: Arc::clone(&self.clock),
+            limiter: Arc::clone(&self.limiter),
+            order_markets: self.order_markets.clone(),
+        }
+    }
+}
+
+impl CoinoneClient {
+    /// 인증되지 않은 새 Coinone 클라이언트를 생성합니다.
+    ///
+    /// 이 클라이언트는 Public API만 접근할 수 있습니다.
+    ///
+    /// # 오류
+    ///
+    /// HTTP 클라이언트를 생성할 수 없는 경우 오류를 반환합니다.
+    pub fn new() -> ExchangeResult<Self> {
+        Self::new_internal(None, BASE_URL)
+    }
+
+    /// 인증된 새 Coinone 클라이언트를 생성합니다.
+    ///
+    /// # 인자
+    ///
+    /// * `access_token` - Coinone 액세스 토큰
+    /// * `secret_key` - Coinone 시크릿 키
+    ///
+    /// # 오류
+    ///
+    /// HTTP 클라이언트를 생성할 수 없는 경우 오류를 반환합니다.
+    pub fn with_credentials(
+        access_token: impl Into<String>,
+        secret_key: impl Into<String>,
+    ) -> ExchangeResult<Self> {
+        let creds = CoinoneCredentials::new(access_token, secret_key);
+        Self::new_internal(Some(creds), BASE_URL)
+    }
+
+    /// 내부 생성자.
+    fn new_internal(
+        credentials: Option<CoinoneCredentials>,
+        base_url: &str,
+    ) -> ExchangeResult<Self> {
+        let client = Client::builder()
+            .timeout(std::time::Duration::from_secs(30))
+            .build()
+            .map_err(ExchangeError::HttpError)?;
+
+        Ok(Self {
+            client,
+            credentials: CredentialSlot::new(credentials),
+            base_url: base_url.to_string(),
+            stream: Arc::new(CoinoneStreamInner::new(StreamConfig::default())),
+            clock: Arc::new(ServerClock::new("coinone")),
+            limiter: Arc::new(RateLimiter::new(
+                "coinone",
+                COINONE_RATE_LIMIT,
+                COINONE_BURST,
+            )),
+            order_markets: OrderKeyCache::new(ORDER_MARKET_CACHE_CAPACITY),
+        })
+    }
+
+    /// WebSocket 스트림 내부 상태에 접근합니다.
+    pub(crate) fn stream_inner(&self) -> &CoinoneStreamInner {
+        &self.stream
+    }
+
+    /// 실행 중 API 키를 교체합니다 (키 로테이션).
+    ///
+    /// 이미 서명된 요청은 이전 키로 완료됩니다.
+    ///
+    /// # 인자
+    ///
+    /// * `access_token` - Coinone 액세스 토큰
+    /// * `secret_key` - Coinone 시크릿 키
+    pub fn set_credentials(&self, access_token: impl Into<String>, secret_key: impl Into<String>) {
+        self.credentials
+            .set(CoinoneCredentials::new(access_token, secret_key));
+    }
+
+    /// 인증 정보가 설정되어 있으면 true를 반환합니다.
+    pub fn has_credentials(&self) -> bool {
+        self.credentials.is_set()
+    }
+
+    /// Public 엔드포인트에 GET 요청을 보냅니다.
+    async fn get_public<T: serde::de::DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        params: &[(&str, &str)],
+    ) -> ExchangeResult<T> {
+        self.limiter.acquire().await;
+        let url = format!("{}{}", self.base_url, endpoint);
+        debug!(endpoint, ?params, "Coinone public GET 요청");
+        let response = self
+            .client
+            .get(&url)
+            .query(params)
+            .send()
+            .await
+            .map_err(ExchangeError::HttpError)?;
+
+        self.handle_response(response).await
+    }
+
+    /// Private 엔드포인트에 서명된 POST 요청을 보냅니다.
+    ///
+    /// Coinone Private API는 조회를 포함한 모든 요청이 POST입니다.
+    async fn post_private<T: serde::de::DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        params: Map<String, Value>,
+    ) -> ExchangeResult<T> {
+        self.limiter.acquire().await;
+        let creds = self.credentials.get()?;
+        let nonce = uuid::Uuid::new_v4().to_string();
+        let signed = creds.signed_payload(params, &nonce)?;
+        debug!(endpoint, "Coinone private POST 요청");
+
+        let response = self
+            .client
+            .post(format!("{}{}", self.base_url, endpoint))
+            .header(HEADER_PAYLOAD, signed.payload)
+            .header(HEADER_SIGNATURE, signed.signature)
+            .header("Content-Type", "application/json")
+            .body(signed.body)
+            .send()
+            .await
+            .map_err(ExchangeError::HttpError)?;
+
+        self.handle_response(response).await
+    }
+
+    /// API 응답을 처리하고 오류를 변환합니다.
+    ///
+    /// Coinone은 HTTP 200에도 `result: "error"`로 실패를 알리므로 본문까지 확인합니다.
+    async fn handle_response<T: serde::de::DeserializeOwned>(
+        &self,
+        response: reqwest::Response,
+    ) -> ExchangeResult<T> {
+        let status = response.status();
+        let body = response.text().await.map_err(ExchangeError::HttpError)?;
+
+        if !status.is_success() {
+            warn!(status = status.as_u16(), body = %body, "Coinone API HTTP 에러");
+            return Err(parse_error(&body, status.as_u16()));
+        }
+
+        let header: CoinoneStatus =
+            serde_json::from_str(&body).map_err(ExchangeError::JsonError)?;
+        if header.result != "success" {
+            warn!(
+                code = %header.error_code,
+                msg = %header.error_msg,
+                "Coinone API 에러 응답"
+            );
+            return Err(convert_coinone_error(&header.error_code, &header.error_msg));
+        }
+
+        serde_json::from_str(&body).map_err(ExchangeError::JsonError)
+    }
+
+    /// 마켓 코드를 (quote, target) 통화 쌍으로 분리합니다.
+    ///
+    /// "KRW-BTC" -> ("KRW", "BTC")
+    pub(super) fn split_market(market: &str) -> ExchangeResult<(String, String)> {
+        parse_market_code(&to_exchange_format(ExchangeName::Coinone, market))
+            .ok_or_else(|| ExchangeError::InvalidParameter(format!("Invalid market: {market}")))
+    }
+
+    /// 마켓의 quote/target 통화를 요청 파라미터로 만듭니다.
+    fn market_params(market: &str) -> ExchangeResult<Map<String, Value>> {
+        let (quote, target) = Self::split_market(market)?;
+        let mut params = Map::new();
+        params.insert("quote_currency".to_string(), Value::String(quote));
+        params.insert("target_currency".to_string(), Value::String(target));
+        Ok(params)
+    }
+
+    /// 주문 응답의 ID와 `user_order_id`를 마켓 캐시에 반영합니다.
+    fn remember_order(&self, order: &CoinoneOrder) {
+        let market = create_market_code(&order.quote_currency, &order.target_currency);
+        self.order_markets.insert(&order.order_id, &market);
+        if let Some(ref user_order_id) = order.user_order_id
+            && !user_order_id.is_empty()
+        {
+            self.order_markets.insert(user_order_id, &market);
+        }
+    }
+
+    /// 주문 키(주문 ID 또는 `user_order_id`)의 마켓을 찾습니다.
+    ///
+    /// 캐시에 없으면(재시작 등) 전체 미체결 주문에서 찾습니다. 그래도 없으면
+    /// 주문 부재를 확정할 수 없으므로 `OrderNotFound`가 아닌 `InvalidParameter`를 반환합니다.
+    async fn resolve_market(&self, key: &str) -> ExchangeResult<String> {
+        if let Some(market) = self.order_markets.get(key) {
+            return Ok(market);
+        }
+
+        debug!(key, "Coinone 주문 마켓 캐시 미스 — 미체결 주문에서 검색");
+        let active: CoinoneActiveOrders = self
+            .post_private("/v2.1/order/active_orders", Map::new())
+            .await?;
+        for order in &active.active_orders {
+            self.remember_order(order);
+        }
+
+        self.order_markets.get(key).ok_or_else(|| {
+            ExchangeError::InvalidParameter(format!(
+                "Coinone order lookup requires a known market: {key}"
+            ))
+        })
+    }
+
+    /// 주문 상세를 조회합니다.
+    ///
+    /// * `key` - `("order_id", ..)` 또는 `("user_order_id", ..)`
+    async fn fetch_order(&self, market: &str, key: (&str, &str)) -> ExchangeResult<Order> {
+        let mut params = Self::market_params(market)?;
+        params.insert(key.0.to_string(), Value::String(key.1.to_string()));
+
+        let detail: CoinoneOrderDetail = self.post_private("/v2.1/order/detail", params).await?;
+        self.remember_order(&detail.order);
+        Ok(convert_order(detail.order))
+    }
+
+    /// 주문 취소를 요청합니다 (취소 후 상태는 조회하지 않음).
+    async fn request_cancel(&self, market: &str, order_id: &str) -> ExchangeResult<()> {
+        let mut params = Self::market_params(market)?;
+        params.insert("order_id".to_string(), Value::String(order_id.to_string()));
+
+        let _: Value = self.post_private("/v2.1/order/cancel", params).await?;
+        Ok(())
+    }
+}
+
+impl MarketData for CoinoneClient {
+    fn name(&self) -> &str {
+        "Coinone"
+    }
+
+    async fn get_ticker(&self, markets: &[&str]) -> ExchangeResult<Vec<Ticker>> {
+        let mut tickers = Vec::with_capacity(markets.len());
+
+        for market in markets {
+            let (quote, target) = Self::split_market(market)?;
+            let endpoint = format!("/public/v2/ticker_new/{quote}/{target}");
+            let response: CoinoneTickers = self.get_public(&endpoint, &[]).await?;
+            let ticker = response
+                .tickers
+                .into_iter()
+                .next()
+                .ok_or_else(|| ExchangeError::MarketNotFound(market.to_string()))?;
+            tickers.push(convert_ticker(ticker));
+        }
+
+        Ok(tickers)
+    }
+
+    async fn get_orderbook(&self, market: &str, depth: Option<u32>) -> ExchangeResult<OrderBook> {
+        let (quote, target) = Self::split_market(market)?;
+        let depth = depth.unwrap_or(15);
+        // 허용된 size 중 요청 depth를 담을 수 있는 가장 작은 값
+        let size = ORDERBOOK_SIZES
+            .iter()
+            .copied()
+            .find(|s| *s >= depth)
+            .unwrap_or(ORDERBOOK_SIZES[ORDERBOOK_SIZES.len() - 1])
+            .to_string();
+
+        let endpoint = format!("/public/v2/orderbook/{quote}/{target}");
+        let orderbook: CoinoneOrderbook = self.get_public(&endpoint, &[("size", &size)]).await?;
+
+        Ok(convert_orderbook(orderbook, depth as usize))
+    }
+
+    async fn get_candles(
+        &self,
+        market: &str,
+        interval: CandleInterval,
+        count: u32,
+    ) -> ExchangeResult<Vec<Candle>> {
+        let (quote, target) = Self::split_market(market)?;
+        let size = count.min(MAX_CANDLES).to_string();
+        let params = [("interval", interval_to_coinone(interval)), ("size", &size)];
+
+        let endpoint = format!("/public/v2/chart/{quote}/{target}");
+        let chart: CoinoneChart = self.get_public(&endpoint, &params).await?;
+
+        // Coinone은 최신순으로 반환하므로 오름차순으로 뒤집음
+        Ok(chart
+            .chart
+            .into_iter()
+            .rev()
+            .map(|c| convert_candle(c, market))
+            .collect())
+    }
+
+    async fn get_candles_before(
+        &self,
+        market: &str,
+        interval: CandleInterval,
+        count: u32,
+        before: DateTime<Utc>,
+    ) -> ExchangeResult<Vec<Candle>> {
+        let (quote, target) = Self::split_market(market)?;
+        let size = count.min(MAX_CANDLES).to_string();
+        let before_ms = before.timestamp_millis();
+        let timestamp = before_ms.to_string();
+        let params = [
+            ("interval", interval_to_coinone(interval)),
+            ("size", &size),
+            ("timestamp", &timestamp),
+        ];
+
+        let endpoint = format!("/public/v2/chart/{quote}/{target}");
+        let chart: CoinoneChart = self.get_public(&endpoint, &params).await?;
+
+        // 기준 시각의 캔들이 포함될 수 있으므로 before 이전(exclusive)만 남김
+        Ok(chart
+            .chart
+            .into_iter()
+            .rev()
+            .filter(|c| c.timestamp < before_ms)
+            .map(|c| convert_candle(c, market))
+            .collect())
+    }
+
+    async fn get_all_tickers(&self) -> ExchangeResult<Vec<Ticker>> {
+        let response: CoinoneTickers = self.get_public("/public/v2/ticker_new/KRW", &[]).await?;
+        Ok(response.tickers.into_iter().map(convert_ticker).collect())
+    }
+
+    fn market_code(base: &str, quote: &str) -> String {
+        // 내부 형식과 동일: "{QUOTE}-{BASE}" (예: "KRW-BTC")
+        format!("{}-{}", quote.to_uppercase(), base.to_uppercase())
+    }
+}
+
+impl OrderManagement for CoinoneClient {
+    /// 주문을 생성합니다.
+    ///
+    /// Coinone 지정가 주문은 GTC/post-only만 지원하므로, IOC는 접수 직후 잔량을
+    /// 취소하는 방식으로 처리합니다 (원자적이지 않음). FOK와 최유리 주문은 지원하지 않습니다.
+    async fn place_order(&self, request: &OrderRequest) -> ExchangeResult<Order> {
+        let mut params = Self::market_params(&request.market)?;
+        let side = match request.side {
+            OrderSide::Buy => "BUY",
+            OrderSide::Sell => "SELL",
+        };
+        params.insert("side".to_string(), Value::String(side.to_string()));
+
+        let mut cancel_remaining = false;
+        match request.order_type {
+            OrderType::Limit => {
+                let price = request.price.ok_or_else(|| {
+                    ExchangeError::InvalidParameter("Limit order requires a price".to_string())
+                })?;
+                let volume = request.volume.ok_or_else(|| {
+                    ExchangeError::InvalidParameter("Limit order requires a volume".to_string())
+                })?;
+                let post_only = match request.time_in_force {
+                    None | Some(TimeInForce::Gtc) => false,
+                    Some(TimeInForce::PostOnly) => true,
+                    Some(TimeInForce::Ioc) => {
+                        cancel_remaining = true;
+                        false
+                    }
+                    Some(TimeInForce::Fok) => {
+                        return Err(ExchangeError::Unsupported(
+                            "Coinone does not support FOK orders".to_string(),
+                        ));
+                    }
+                };
+                params.insert("type".to_string(), Value::String("LIMIT".to_string()));
+                params.insert("price".to_string(), Value::String(price.to_string()));
+                params.insert("qty".to_string(), Value::String(volume.to_string()));
+                params.insert("post_only".to_string(), Value::Bool(post_only));
+            }
+            OrderType::Market => {
+                // 시장가 매수는 총액 기준(OrderType::Price)만 가능
+                if request.side == OrderSide::Buy {
+                    return Err(ExchangeError::InvalidParameter(
+                        "Coinone market buy requires OrderType::Price with a total amount"
+                            .to_string(),
+                    ));
+                }
+                let volume = request.volume.ok_or_else(|| {
+                    ExchangeError::InvalidParameter("Market sell requires a volume".to_string())
+                })?;
+                params.insert("type".to_string(), Value::String("MARKET".to_string()));
+                params.insert("qty".to_string(), Value::String(volume.to_string()));
+            }
+            OrderType::Price => {
+                if request.side == OrderSide::Sell {
+                    return Err(ExchangeError::InvalidParameter(
+                        "OrderType::Price is only valid for buy orders".to_string(),
+                    ));
+                }
+                let total = request.price.ok_or_else(|| {
+                    ExchangeError::InvalidParameter(
+                        "Market buy requires a total amount".to_string(),
+                    )
+                })?;
+                params.insert("type".to_string(), Value::String("MARKET".to_string()));
+                params.insert("amount".to_string(), Value::String(total.to_string()));
+            }
+            OrderType::Best => {
+                return Err(ExchangeError::Unsupported(
+                    "Coinone does not support best-price orders".to_string(),
+                ));
+            }
+        }
+        if let Some(ref identifier) = request.identifier {
+            params.insert(
+                "user_order_id".to_string(),
+                Value::String(identifier.clone()),
+            );
+        }
+
+        debug!(
+            market = %request.market,
+            side,
+            order_type = ?request.order_type,
+            volume = ?request.volume,
+            price = ?request.price,
+            time_in_force = ?request.time_in_force,
+            identifier = ?request.identifier,
+            "Coinone 주문 생성 요청"
+        );
+
+        let ack: CoinoneOrderAck = self.post_private("/v2.1/order", params).await?;
+        self.order_markets.insert(&ack.order_id, &request.market);
+        if let Some(ref identifier) = request.identifier {
+            self.order_markets.insert(identifier, &request.market);
+        }
+
+        info!(
+            order_id = %ack.order_id,
+            market = %request.market,
+            side,
+            "Coinone 주문 생성 완료"
+        );
+
+        if cancel_remaining {
+            // 이미 전량 체결되었으면 취소가 실패하므로 결과는 후속 조회로 판단
+            if let Err(e) = self.request_cancel(&request.market, &ack.order_id).await {
+                debug!(order_id = %ack.order_id, error = %e, "Coinone IOC 잔량 취소 실패");
+            }
+        }
+
+        // 접수 응답에는 주문 ID만 있으므로 상세 조회로 전체 정보를 얻음
+        self.fetch_order(&request.market, ("order_id", &ack.order_id))
+            .await
+    }
+
+    async fn cancel_order(&self, order_id: &str) -> ExchangeResult<Order> {
+        let market = self.resolve_market(order_id).await?;
+        debug!(order_id, market = %market, "Coinone 주문 취소 요청");
+
+        self.request_cancel(&market, order_id).await?;
+        info!(order_id, "Coinone 주문 취소 접수 완료");
+
+        self.fetch_order(&market, ("order_id", order_id)).await
+    }
+
+    async fn get_order(&self, order_id: &str) -> ExchangeResult<Order> {
+        let market = self.resolve_market(order_id).await?;
+        self.fetch_order(&market, ("order_id", order_id)).await
+    }
+
+    async fn get_order_by_client_id(&self, client_order_id: &str) -> ExchangeResult<Order> {
+        let market = self.resolve_market(client_order_id).await?;
+        self.fetch_order(&market, ("user_order_id", client_order_id))
+            .await
+    }
+
+    async fn get_open_orders(&self, market: Option<&str>) -> ExchangeResult<Vec<Order>> {
+        let params = match market {
+            Some(m) => Self::market_params(m)?,
+            None => Map::new(),
+        };
+
+        let active: CoinoneActiveOrders = self
+            .post_private("/v2.1/order/active_orders", params)
+            .await?;
+        Ok(active
+            .active_orders
+            .into_iter()
+            .map(|o| {
+                self.remember_order(&o);
+                convert_order(o)
+            })
+            .collect())
+    }
+
+    async fn get_balances(&self) -> ExchangeResult<Vec<Balance>> {
+        let balances: CoinoneBalances = self
+            .post_private("/v2.1/account/balance/all", Map::new())
+            .await?;
+        Ok(balances.balances.into_iter().map(convert_balance).collect())
+    }
+
+    async fn get_balance(&self, currency: &str) -> ExchangeResult<Balance> {
+        let balances = self.get_balances().await?;
+        balances
+            .into_iter()
+            .find(|b| b.currency == currency)
+            .ok_or_else(|| {
+                ExchangeError::InvalidParameter(format!("Currency not found: {currency}"))
+            })
+    }
+}
+
+/// 에러 응답 본문을 ExchangeError로 변환합니다.
+fn parse_error(body: &str, status: u16) -> ExchangeError {
+    if let Ok(header) = serde_json::from_str::<CoinoneStatus>(body)
+        && !header.error_code.is_empty()
+        && header.error_code != "0"
+    {
+        return convert_coinone_error(&header.error_code, &header.error_msg);
+    }
+
+    match status {
+        429 => ExchangeError::RateLimitExceeded(body.to_string()),
+        _ => ExchangeError::UnknownError {
+            code: status.to_string(),
+            message: body.to_string(),
+        },
+    }
+}
+
+/// Coinone 에러 코드를 ExchangeError로 변환합니다.
+fn convert_coinone_error(code: &str, message: &str) -> ExchangeError {
+    match code {
+        // 인증 에러 (토큰 누락/무효, 권한, 서명, 세션 만료)
+        "11" | "12" | "40" | "50" | "51" | "53" | "100" => {
+            ExchangeError::AuthError(message.to_string())
+        }
+        // 잔고 부족
+        "103" => ExchangeError::InsufficientFunds(message.to_string()),
+        // 주문을 찾을 수 없음
+        "104" => ExchangeError::OrderNotFound(message.to_string()),
+        // 잘못된 형식/파라미터, 최소 수량 미달
+        "101" | "107" | "113" | "114" => ExchangeError::InvalidParameter(message.to_string()),
+        // 존재하지 않는 통화
+        "108" => ExchangeError::MarketNotFound(message.to_string()),
+        // 서버 점검
+        "405" => ExchangeError::ExchangeOffline(message.to_string()),
+        _ => ExchangeError::UnknownError {
+            code: code.to_string(),
+            message: message.to_string(),
+        },
+    }
+}
+
+/// CandleInterval을 Coinone `interval` 파라미터로 변환합니다.
+fn interval_to_coinone(interval: CandleInterval) -> &'static str {
+    match interval {
+        CandleInterval::Minute1 => "1m",
+        CandleInterval::Minute3 => "3m",
+        CandleInterval::Minute5 => "5m",
+        CandleInterval::Minute10 => "10m",
+        CandleInterval::Minute15 => "15m",
+        CandleInterval::Minute30 => "30m",
+        CandleInterval::Minute60 => "1h",
+        CandleInterval::Minute240 => "4h",
+        CandleInterval::Day => "1d",
+        CandleInterval::Week => "1w",
+        CandleInterval::Month => "1mon",
+    }
+}
+
+/// 밀리초 타임스탬프를 DateTime으로 변환합니다 (범위 밖이면 현재 시각).
+fn millis_to_datetime(millis: i64) -> DateTime<Utc> {
+    Utc.timestamp_millis_opt(millis)
+        .single()
+        .unwrap_or_else(Utc::now)
+}
+
+// 변환 함수들
+
+fn convert_ticker(t: CoinoneTicker) -> Ticker {
+    let prev_close = t.yesterday_last.unwrap_or(t.first);
+    let change_price = t.last - prev_close;
+    let change = if change_price > Decimal::ZERO {
+        PriceChange::Rise
+    } else if change_price < Decimal::ZERO {
+        PriceChange::Fall
+    } else {
+        PriceChange::Even
+    };
+    let change_rate = if prev_close.is_zero() {
+        Decimal::ZERO
+    } else {
+        change_price / prev_close
+    };
+
+    Ticker {
+        market: create_market_code(&t.quote_currency, &t.target_currency),
+        trade_price: t.last,
+        opening_price: t.first,
+        high_price: t.high,
+        low_price: t.low,
+        prev_closing_price: prev_close,
+        change,
+        change_rate: change_rate.abs(),
+        change_price: change_price.abs(),
+        acc_trade_volume_24h: t.target_volume,
+        acc_trade_price_24h: t.quote_volume,
+        timestamp: millis_to_datetime(t.timestamp),
+    }
+}
+
+fn convert_orderbook(ob: CoinoneOrderbook, depth: usize) -> OrderBook {
+    let to_levels = |levels: Vec<_>| -> Vec<OrderBookLevel> {
+        levels
+            .into_iter()
+            .take(depth)
+            .map(
+                |l: crate::coinone::types::CoinonePriceLevel| OrderBookLevel {
+                    price: l.price,
+                    size: l.qty,
+                },
+            )
+            .collect()
+    };
+    let mut asks = to_levels(ob.asks);
+    let mut bids = to_levels(ob.bids);
+
+    // 매도호가는 오름차순, 매수호가는 내림차순 정렬
+    asks.sort_by_key(|a| a.price);
+    bids.sort_by_key(|b| std::cmp::Reverse(b.price));
+
+    OrderBook {
+        market: create_market_code(&ob.quote_currency, &ob.target_currency),
+        total_ask_size: asks.iter().map(|l| l.size).sum(),
+        total_bid_size: bids.iter().map(|l| l.size).sum(),
+        asks,
+        bids,
+        timestamp: millis_to_datetime(ob.timestamp),
+    }
+}
+
+fn convert_candle(c: CoinoneCandle, market: &str) -> Candle {
+    Candle {
+        market: market.to_string(),
+        timestamp: millis_to_datetime(c.timestamp),
+        open: c.open,
+        high: c.high,
+        low: c.low,
+        close: c.close,
+        volume: c.target_volume,
+    }
+}
+
+fn convert_order(o: CoinoneOrder) -> Order {
+    let side = match o.side.as_str() {
+        "BUY" => OrderSide::Buy,
+        _ => OrderSide::Sell,
+    };
+
+    let order_type = match (o.order_type.as_str(), side) {
+        ("MARKET", OrderSide::Buy) => OrderType::Price,
+        ("MARKET", OrderSide::Sell) => OrderType::Market,
+        _ => OrderType::Limit,
+    };
+
+    let status = match o.status.as_str() {
+        "PARTIALLY_FILLED" => OrderStatus::PartiallyFilled,
+        "FILLED" => OrderStatus::Filled,
+        "CANCELED" | "PARTIALLY_CANCELED" => OrderStatus::Cancelled,
+        // "LIVE", "NOT_TRIGGERED", "TRIGGERED"
+        _ => OrderStatus::Wait,
+    };
+
+    let executed_volume = o.executed_qty.unwrap_or_default();
+    let remaining_volume = o.remain_qty.unwrap_or_default();
+    let volume = o.original_qty.unwrap_or(executed_volume + remaining_volume);
+
+    Order {
+        id: o.order_id,
+        market: create_market_code(&o.quote_currency, &o.target_currency),
+        side,
+        order_type,
+        status,
+        volume,
+        remaining_volume,
+        executed_volume,
+        price: o.price,
+        avg_price: o.average_executed_price.filter(|p| !p.is_zero()),
+        paid_fee: o.fee.unwrap_or_default(),
+        created_at: millis_to_datetime(o.ordered_at),
+        identifier: o.user_order_id.filter(|id| !id.is_empty()),
+    }
+}
+
+fn convert_balance(b: CoinoneBalance) -> Balance {
+    Balance {
+        currency: b.currency.to_uppercase(),
+        balance: b.available,
+        locked: b.limit,
+        avg_buy_price: b.average_price.unwrap_or_default(),
+        unit_currency: "KRW".to_string(),
+        equity: None,
+        unrealised_pnl: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{MockClient, mock_client};
+    use mockito::Matcher;
+
+    impl MockClient for CoinoneClient {
+        fn public() -> Self {
+            Self::new().unwrap()
+        }
+
+        fn with_mock_url(base_url: &str) -> Self {
+            let creds = CoinoneCredentials::new("test-token", "test-secret");
+            Self::new_internal(Some(creds), base_url).unwrap()
+        }
+
+        fn has_credentials(&self) -> bool {
+            CoinoneClient::has_credentials(self)
+        }
+
+        fn parse_error(body: &str, status: u16) -> ExchangeError {
+            parse_error(body, status)
+        }
+    }
+
+    #[test]
+    fn test_split_market() {
+        assert_eq!(
+            CoinoneClient::split_market("KRW-BTC").unwrap(),
+            ("KRW".to_string(), "BTC".to_string())
+        );
+        assert_eq!(
+            CoinoneClient::split_market("krw-eth").unwrap(),
+            ("KRW".to_string(), "ETH".to_string())
+        );
+        assert!(matches!(
+            CoinoneClient::split_market("BTCKRW"),
+            Err(ExchangeError::InvalidParameter(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_ticker_converts_change() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/public/v2/ticker_new/KRW/BTC")
+            .with_body(
+                r#"{"result": "success", "error_code": "0", "tickers": [{
+                    "quote_currency": "KRW", "target_currency": "BTC",
+                    "timestamp": 1707177600000, "high": "72000000", "low": "70000000",
+                    "first": "70500000", "last": "70000000",
+                    "quote_volume": "1000000000", "target_volume": "14.2",
+                    "yesterday_last": "71000000"}]}"#,
+            )
+            .create_async()
+            .await;
+
+        let client: CoinoneClient = mock_client(&server);
+        let tickers = client.get_ticker(&["KRW-BTC"]).await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(tickers[0].market, "KRW-BTC");
+        assert_eq!(tickers[0].change, PriceChange::Fall);
+        assert_eq!(tickers[0].change_price, Decimal::from(1_000_000));
+    }
+
+    #[tokio::test]
+    async fn test_place_ioc_order_cancels_remaining() {
+        let mut server = mockito::Server::new_async().await;
+        let place = server
+            .mock("POST", "/v2.1/order")
+            .match_header(HEADER_PAYLOAD, Matcher::Any)
+            .match_header(HEADER_SIGNATURE, Matcher::Regex("^[0-9a-f]{128}$".into()))
+            .match_body(Matcher::PartialJsonString(
+                r#"{"access_token": "test-token", "side": "BUY", "type": "LIMIT",
+                    "quote_currency": "KRW", "target_currency": "BTC",
+                    "price": "71500000", "qty": "0.01", "user_order_id": "arb-1"}"#
+                    .into(),
+            ))
+            .with_body(r#"{"result": "success", "error_code": "0", "order_id": "ord-1"}"#)
+            .create_async()
+            .await;
+        let cancel = server
+            .mock("POST", "/v2.1/order/cancel")
+            .match_body(Matcher::PartialJsonString(
+                r#"{"order_id": "ord-1"}"#.into(),
+            ))
+            .with_body(r#"{"result": "success", "error_code": "0", "order_id": "ord-1"}"#)
+            .create_async()
+            .await;
+        let detail = server
+            .mock("POST", "/v2.1/order/detail")
+            .with_body(
+                r#"{"result": "success", "error_code": "0", "order": {
+                    "order_id": "ord-1", "type": "LIMIT",
+                    "quote_currency": "KRW", "target_currency": "BTC",
+                    "status": "PARTIALLY_CANCELED", "side": "BUY", "fee": "143",
+                    "average_executed_price": "71500000", "price": "71500000",
+                    "original_qty": "0.01", "executed_qty": "0.004", "remain_qty": "0",
+                    "ordered_at": 1707177600000, "user_order_id": "arb-1"}}"#,
+            )
+            .create_async()
+            .await;
+
+        let client: CoinoneClient = mock_client(&server);
+        let request = OrderRequest {
+            time_in_force: Some(TimeInForce::Ioc),
+            identifier: Some("arb-1".to_string()),
+            ..OrderRequest::limit_buy("KRW-BTC", Decimal::from(71_500_000), Decimal::new(1, 2))
+        };
+        let order = client.place_order(&request).await.unwrap();
+
+        place.assert_async().await;
+        cancel.assert_async().await;
+        detail.assert_async().await;
+        assert_eq!(order.id, "ord-1");
+        assert_eq!(order.market, "KRW-BTC");
+        assert_eq!(order.status, OrderStatus::Cancelled);
+        assert_eq!(order.order_type, OrderType::Limit);
+        assert_eq!(order.executed_volume, Decimal::new(4, 3));
+        assert_eq!(order.paid_fee, Decimal::from(143));
+        assert_eq!(order.identifier.as_deref(), Some("arb-1"));
+
+        // 발주한 주문은 마켓 캐시로 바로 조회 가능
+        assert_eq!(client.resolve_market("arb-1").await.unwrap(), "KRW-BTC");
+    }
+
+    #[tokio::test]
+    async fn test_error_result_on_http_200() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", "/v2.1/account/balance/all")
+            .with_body(
+                r#"{"result": "error", "error_code": "12", "error_msg": "Invalid access token"}"#,
+            )
+            .create_async()
+            .await;
+
+        let client: CoinoneClient = mock_client(&server);
+        let result = client.get_balances().await;
+        assert!(matches!(result, Err(ExchangeError::AuthError(_))));
+    }
+
+    #[test]
+    fn test_interval_to_coinone() {
+        assert_eq!(interval_to_coinone(CandleInterval::Minute1), "1m");
+        assert_eq!(interval_to_coinone(CandleInterval::Minute60), "1h");
+        assert_eq!(interval_to_coinone(CandleInterval::Minute240), "4h");
+        assert_eq!(interval_to_coinone(CandleInterval::Month), "1mon");
+    }
+}
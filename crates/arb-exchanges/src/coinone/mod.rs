@@ -0,0 +1,36 @@
+//! Coinone 거래소 SDK 구현.
+//!
+//! 이 모듈은 Coinone KRW 현물 마켓 클라이언트를 제공합니다.
+//!
+//! # 기능
+//!
+//! - Public API(v2): 시세(Ticker), 호가창, 캔들
+//! - Private API(v2.1): 주문 생성, 취소, 조회, 잔고 (인증 필요)
+//! - HMAC-SHA512 페이로드 서명 인증
+//! - TRADE 채널 WebSocket 실시간 체결
+//!
+//! # 예제
+//!
+//! ```no_run
+//! use arb_exchanges::CoinoneClient;
+//! use arb_exchange::MarketData;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = CoinoneClient::new()?;
+//!
+//!     let tickers = client.get_ticker(&["KRW-BTC"]).await?;
+//!     println!("BTC Price: {}", tickers[0].trade_price);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod auth;
+mod client;
+mod stream;
+mod types;
+
+pub use auth::CoinoneCredentials;
+pub use client::CoinoneClient;
+pub use types::*;
@@ -0,0 +1,519 @@
+//! Coinone WebSocket 실시간 마켓 데이터 스트림 구현.
+//!
+//! `MarketStream` trait을 구현하여 Coinone의 체결(TRADE) 데이터를
+//! WebSocket으로 실시간 수신합니다.
+
+use arb_exchange::create_market_code;
+use arb_exchange::error::{ExchangeError, ExchangeResult};
+use arb_exchange::stream::{MarketEvent, MarketStream, StreamCommand, StreamConfig};
+use async_trait::async_trait;
+use chrono::{TimeZone, Utc};
+use futures_util::{SinkExt, StreamExt};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Mutex, mpsc};
+use tokio_tungstenite::connect_async;
+use tokio_tungstenite::tungstenite::Message;
+use tracing::{debug, error, info, trace, warn};
+
+use crate::clock::ServerClock;
+use crate::coinone::client::CoinoneClient;
+
+/// Coinone WebSocket URL.
+const COINONE_WS_URL: &str = "wss://stream.coinone.co.kr";
+
+/// 구독 채널 이름.
+const TRADE_CHANNEL: &str = "TRADE";
+
+/// heartbeat 주기. Coinone은 30분간 요청이 없으면 연결을 끊습니다.
+const PING_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Coinone WebSocket 응답.
+#[derive(Debug, Deserialize)]
+struct CoinoneWsResponse {
+    /// 응답 유형 ("DATA", "SUBSCRIBED", "PONG", "ERROR" 등).
+    response_type: String,
+    /// 채널 이름.
+    #[serde(default)]
+    channel: Option<String>,
+    /// 채널 데이터.
+    #[serde(default)]
+    data: Option<CoinoneWsTrade>,
+}
+
+/// Coinone 체결 데이터.
+#[derive(Debug, Deserialize)]
+struct CoinoneWsTrade {
+    /// 마켓 기준 통화 (예: "KRW").
+    quote_currency: String,
+    /// 종목 통화 (예: "BTC").
+    target_currency: String,
+    /// 체결 시각 (밀리초).
+    timestamp: i64,
+    /// 체결 가격.
+    price: String,
+    /// 체결 수량.
+    qty: String,
+}
+
+/// WebSocket task의 내부 상태.
+struct StreamState {
+    /// 구독 해제 시그널을 보내는 sender.
+    shutdown_tx: Option<tokio::sync::oneshot::Sender<()>>,
+    /// WebSocket task join handle.
+    task_handle: Option<tokio::task::JoinHandle<()>>,
+    /// 동적 구독 변경 명령을 보내는 sender.
+    command_tx: Option<mpsc::Sender<StreamCommand>>,
+}
+
+/// Coinone MarketStream 구현을 위한 내부 상태.
+pub(crate) struct CoinoneStreamInner {
+    state: Mutex<Option<StreamState>>,
+    config: StreamConfig,
+}
+
+impl CoinoneStreamInner {
+    pub(crate) fn new(config: StreamConfig) -> Self {
+        Self {
+            state: Mutex::new(None),
+            config,
+        }
+    }
+}
+
+#[async_trait]
+impl MarketStream for CoinoneClient {
+    fn stream_name(&self) -> &str {
+        "Coinone"
+    }
+
+    async fn subscribe(&self, markets: &[&str]) -> ExchangeResult<mpsc::Receiver<MarketEvent>> {
+        let inner = self.stream_inner();
+
+        // 기존 구독이 있으면 먼저 해제
+        {
+            let mut state_guard = inner.state.lock().await;
+            if let Some(old_state) = state_guard.take() {
+                if let Some(tx) = old_state.shutdown_tx {
+                    let _ = tx.send(());
+                }
+                if let Some(handle) = old_state.task_handle {
+                    handle.abort();
+                }
+                debug!("기존 Coinone WebSocket 구독 해제");
+            }
+        }
+
+        let buffer_size = inner.config.channel_buffer_size;
+        let (event_tx, event_rx) = mpsc::channel(buffer_size);
+        let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel();
+        let (command_tx, command_rx) = mpsc::channel::<StreamCommand>(64);
+
+        let market_codes: Vec<String> = markets.iter().map(|m| m.to_uppercase()).collect();
+        let config = inner.config.clone();
+        let clock = Arc::clone(&self.clock);
+
+        info!(markets = ?market_codes, "Coinone WebSocket 구독 시작");
+
+        let task_handle = tokio::spawn(async move {
+            coinone_ws_loop(
+                market_codes,
+                event_tx,
+                shutdown_rx,
+                command_rx,
+                config,
+                clock,
+            )
+            .await;
+        });
+
+        let mut state_guard = inner.state.lock().await;
+        *state_guard = Some(StreamState {
+            shutdown_tx: Some(shutdown_tx),
+            task_handle: Some(task_handle),
+            command_tx: Some(command_tx),
+        });
+
+        Ok(event_rx)
+    }
+
+    async fn unsubscribe(&self) -> ExchangeResult<()> {
+        let inner = self.stream_inner();
+        let mut state_guard = inner.state.lock().await;
+
+        if let Some(state) = state_guard.take() {
+            info!("Coinone WebSocket 구독 해제");
+            if let Some(tx) = state.shutdown_tx {
+                let _ = tx.send(());
+            }
+            if let Some(handle) = state.task_handle {
+                handle.abort();
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn subscribe_markets(&self, markets: &[&str]) -> ExchangeResult<()> {
+        self.send_stream_command(StreamCommand::Subscribe(
+            markets.iter().map(|m| m.to_string()).collect(),
+        ))
+        .await
+    }
+
+    async fn unsubscribe_markets(&self, markets: &[&str]) -> ExchangeResult<()> {
+        self.send_stream_command(StreamCommand::Unsubscribe(
+            markets.iter().map(|m| m.to_string()).collect(),
+        ))
+        .await
+    }
+}
+
+impl CoinoneClient {
+    /// 실행 중인 WebSocket 루프에 동적 구독 명령을 전달합니다.
+    async fn send_stream_command(&self, command: StreamCommand) -> ExchangeResult<()> {
+        let state_guard = self.stream_inner().state.lock().await;
+        let tx = state_guard
+            .as_ref()
+            .and_then(|state| state.command_tx.as_ref())
+            .ok_or_else(|| ExchangeError::WebSocketError("not subscribed".into()))?;
+        tx.send(command)
+            .await
+            .map_err(|_| ExchangeError::WebSocketError("command channel closed".into()))
+    }
+}
+
+/// 신규 구독 대상 마켓만 추출하고 현재 목록에 반영합니다.
+fn build_subscribe_markets(markets: &[String], current: &mut Vec<String>) -> Vec<String> {
+    let mut subscribe = Vec::new();
+    for market in markets {
+        let market = market.to_uppercase();
+        if !current.contains(&market) {
+            current.push(market.clone());
+            subscribe.push(market);
+        }
+    }
+    subscribe
+}
+
+/// 현재 구독 중인 마켓만 해제 대상으로 추출하고 현재 목록에서 제거합니다.
+fn build_unsubscribe_markets(markets: &[String], current: &mut Vec<String>) -> Vec<String> {
+    let remove: Vec<String> = markets
+        .iter()
+        .map(|m| m.to_uppercase())
+        .filter(|m| current.contains(m))
+        .collect();
+
+    if !remove.is_empty() {
+        current.retain(|m| !remove.contains(m));
+    }
+
+    remove
+}
+
+/// SUBSCRIBE/UNSUBSCRIBE 요청 메시지를 만듭니다.
+///
+/// Coinone은 요청 하나에 마켓 하나만 지정할 수 있으므로 잘못된 코드는 건너뜁니다.
+fn build_request(request_type: &str, market: &str) -> Option<String> {
+    let (quote, target) = CoinoneClient::split_market(market).ok()?;
+    Some(
+        serde_json::json!({
+            "request_type": request_type,
+            "channel": TRADE_CHANNEL,
+            "topic": {"quote_currency": quote, "target_currency": target},
+        })
+        .to_string(),
+    )
+}
+
+/// Coinone WebSocket 이벤트 루프 (재연결 + 동적 구독 + heartbeat 포함).
+async fn coinone_ws_loop(
+    initial_markets: Vec<String>,
+    event_tx: mpsc::Sender<MarketEvent>,
+    mut shutdown_rx: tokio::sync::oneshot::Receiver<()>,
+    mut command_rx: mpsc::Receiver<StreamCommand>,
+    config: StreamConfig,
+    clock: Arc<ServerClock>,
+) {
+    let mut retry_count: u32 = 0;
+    let mut backoff = config.initial_backoff;
+    // 현재 구독 중인 마켓 목록 (재연결 시 사용)
+    let mut current_markets = initial_markets;
+
+    loop {
+        // 종료 확인
+        if shutdown_rx.try_recv().is_ok() {
+            info!("Coinone WebSocket 종료 요청");
+            break;
+        }
+
+        match connect_and_subscribe(&current_markets).await {
+            Ok(ws_stream) => {
+                info!("Coinone WebSocket 연결 성공");
+                retry_count = 0;
+                backoff = config.initial_backoff;
+
+                let (mut write, mut read) = ws_stream.split();
+                let mut ping_interval = tokio::time::interval(PING_INTERVAL);
+                // 첫 tick은 즉시 발생하므로 소비
+                ping_interval.tick().await;
+
+                'conn: loop {
+                    tokio::select! {
+                        _ = &mut shutdown_rx => {
+                            info!("Coinone WebSocket 종료 요청 (루프 내)");
+                            let _ = write.close().await;
+                            return;
+                        }
+                        _ = ping_interval.tick() => {
+                            let ping = serde_json::json!({"request_type": "PING"}).to_string();
+                            if let Err(e) = write.send(Message::Text(ping.into())).await {
+                                error!(error = %e, "Coinone heartbeat 전송 실패");
+                                break;
+                            }
+                        }
+                        cmd = command_rx.recv() => {
+                            let (request_type, markets) = match cmd {
+                                Some(StreamCommand::Subscribe(markets)) => {
+                                    ("SUBSCRIBE", build_subscribe_markets(&markets, &mut current_markets))
+                                }
+                                Some(StreamCommand::Unsubscribe(markets)) => {
+                                    ("UNSUBSCRIBE", build_unsubscribe_markets(&markets, &mut current_markets))
+                                }
+                                None => {
+                                    debug!("Coinone command 채널 닫힘");
+                                    continue;
+                                }
+                            };
+
+                            if markets.is_empty() {
+                                debug!(request_type, "Coinone 동적 구독 변경 스킵: 변경 대상 없음");
+                                continue;
+                            }
+
+                            info!(request_type, markets = ?markets, "Coinone 동적 구독 변경");
+                            for market in &markets {
+                                let Some(msg) = build_request(request_type, market) else {
+                                    warn!(market = %market, "Coinone 구독 요청 생성 실패: 잘못된 마켓 코드");
+                                    continue;
+                                };
+                                if let Err(e) = write.send(Message::Text(msg.into())).await {
+                                    error!(error = %e, request_type, "Coinone 구독 메시지 전송 실패");
+                                    break 'conn;
+                                }
+                            }
+                        }
+                        msg = read.next() => {
+                            match msg {
+                                Some(Ok(Message::Text(text))) => {
+                                    if let Some(event) = parse_coinone_trade(&text) {
+                                        clock.observe_feed_latency(event.timestamp().timestamp_millis());
+                                        match event_tx.try_send(event) {
+                                            Ok(()) => {
+                                                trace!("Coinone 이벤트 전송 성공");
+                                            }
+                                            Err(mpsc::error::TrySendError::Full(_)) => {
+                                                warn!("Coinone 이벤트 채널 가득 참 — 이벤트 드롭");
+                                            }
+                                            Err(mpsc::error::TrySendError::Closed(_)) => {
+                                                debug!("Coinone 이벤트 채널 닫힘 — 종료");
+                                                return;
+                                            }
+                                        }
+                                    }
+                                }
+                                Some(Ok(Message::Ping(data))) => {
+                                    let _ = write.send(Message::Pong(data)).await;
+                                }
+                                Some(Ok(Message::Close(_))) => {
+                                    warn!("Coinone WebSocket 서버에서 Close 수신");
+                                    break;
+                                }
+                                Some(Err(e)) => {
+                                    error!(error = %e, "Coinone WebSocket 수신 에러");
+                                    break;
+                                }
+                                None => {
+                                    warn!("Coinone WebSocket 스트림 종료");
+                                    break;
+                                }
+                                _ => {}
+                            }
+                        }
+                    }
+                }
+            }
+            Err(e) => {
+                error!(error = %e, "Coinone WebSocket 연결 실패");
+            }
+        }
+
+        // 재연결 로직
+        retry_count += 1;
+        arb_metrics::global()
+            .counter(
+                "arb_ws_reconnects_total",
+                "WebSocket 재연결 시도 횟수",
+                &[("exchange", "coinone")],
+            )
+            .inc();
+        if config.max_retries > 0 && retry_count > config.max_retries {
+            error!(
+                retries = retry_count,
+                "Coinone WebSocket 최대 재시도 초과 — 스트림 종료"
+            );
+            break;
+        }
+
+        warn!(
+            retry = retry_count,
+            backoff_ms = backoff.as_millis(),
+            "Coinone WebSocket 재연결 대기"
+        );
+
+        tokio::select! {
+            _ = &mut shutdown_rx => {
+                info!("Coinone WebSocket 재연결 대기 중 종료 요청");
+                return;
+            }
+            _ = tokio::time::sleep(backoff) => {}
+        }
+
+        // exponential backoff (최대값 제한)
+        backoff = std::cmp::min(backoff * 2, config.max_backoff);
+    }
+}
+
+/// Coinone WebSocket에 연결하고 마켓별 구독 메시지를 보냅니다.
+async fn connect_and_subscribe(
+    markets: &[String],
+) -> Result<
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>,
+    Box<dyn std::error::Error + Send + Sync>,
+> {
+    let (mut ws_stream, response) = connect_async(COINONE_WS_URL).await?;
+
+    debug!(status = ?response.status(), "Coinone WebSocket 핸드셰이크 완료");
+
+    // 구독 메시지: {"request_type": "SUBSCRIBE", "channel": "TRADE",
+    //              "topic": {"quote_currency": "KRW", "target_currency": "BTC"}}
+    for market in markets {
+        if let Some(msg) = build_request("SUBSCRIBE", market) {
+            debug!(msg = %msg, "Coinone 구독 메시지 전송");
+            ws_stream.send(Message::Text(msg.into())).await?;
+        }
+    }
+
+    Ok(ws_stream)
+}
+
+/// Coinone WebSocket 메시지를 MarketEvent로 파싱합니다.
+///
+/// 체결 데이터 외의 응답(SUBSCRIBED, PONG 등)은 None을 반환합니다.
+fn parse_coinone_trade(text: &str) -> Option<MarketEvent> {
+    let response: CoinoneWsResponse = serde_json::from_str(text).ok()?;
+    if response.response_type == "ERROR" {
+        warn!(msg = %text, "Coinone WebSocket 요청 실패");
+        return None;
+    }
+    if response.response_type != "DATA" || response.channel.as_deref() != Some(TRADE_CHANNEL) {
+        return None;
+    }
+
+    let trade = response.data?;
+    let price = Decimal::from_str(&trade.price).ok()?;
+    let volume = Decimal::from_str(&trade.qty).ok()?;
+    let timestamp = Utc
+        .timestamp_millis_opt(trade.timestamp)
+        .single()
+        .unwrap_or_else(Utc::now);
+
+    Some(MarketEvent::Trade {
+        market: create_market_code(&trade.quote_currency, &trade.target_currency),
+        price,
+        volume,
+        timestamp,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_coinone_trade() {
+        let json = r#"{
+            "response_type": "DATA",
+            "channel": "TRADE",
+            "data": {
+                "quote_currency": "KRW",
+                "target_currency": "BTC",
+                "id": "1707177600000001",
+                "timestamp": 1707177600000,
+                "price": "71500000",
+                "qty": "0.0012",
+                "is_seller_maker": false
+            }
+        }"#;
+
+        let event = parse_coinone_trade(json);
+        if let Some(MarketEvent::Trade {
+            market,
+            price,
+            volume,
+            timestamp,
+        }) = event
+        {
+            assert_eq!(market, "KRW-BTC");
+            assert_eq!(price, Decimal::from(71_500_000));
+            assert_eq!(volume, Decimal::new(12, 4));
+            assert_eq!(timestamp.timestamp_millis(), 1707177600000);
+        } else {
+            panic!("Expected Trade event");
+        }
+    }
+
+    #[test]
+    fn test_parse_coinone_non_data_ignored() {
+        assert!(parse_coinone_trade(r#"{"response_type": "PONG"}"#).is_none());
+        let subscribed = r#"{"response_type": "SUBSCRIBED", "channel": "TRADE",
+            "data": null}"#;
+        assert!(parse_coinone_trade(subscribed).is_none());
+        let error = r#"{"response_type": "ERROR", "error_code": 160012, "message": "Invalid"}"#;
+        assert!(parse_coinone_trade(error).is_none());
+    }
+
+    #[test]
+    fn test_build_subscribe_markets_dedup() {
+        let mut current = vec!["KRW-BTC".to_string()];
+        let markets = vec!["KRW-BTC".to_string(), "krw-eth".to_string()];
+
+        let subscribe = build_subscribe_markets(&markets, &mut current);
+        assert_eq!(subscribe, vec!["KRW-ETH".to_string()]);
+        assert_eq!(current.len(), 2);
+    }
+
+    #[test]
+    fn test_build_unsubscribe_markets_only_existing() {
+        let mut current = vec!["KRW-BTC".to_string(), "KRW-ETH".to_string()];
+        let markets = vec!["KRW-BTC".to_string(), "KRW-XRP".to_string()];
+
+        let remove = build_unsubscribe_markets(&markets, &mut current);
+        assert_eq!(remove, vec!["KRW-BTC".to_string()]);
+        assert_eq!(current, vec!["KRW-ETH".to_string()]);
+    }
+
+    #[test]
+    fn test_build_request() {
+        let msg = build_request("SUBSCRIBE", "KRW-BTC").unwrap();
+        let value: serde_json::Value = serde_json::from_str(&msg).unwrap();
+        assert_eq!(value["request_type"], "SUBSCRIBE");
+        assert_eq!(value["channel"], "TRADE");
+        assert_eq!(value["topic"]["quote_currency"], "KRW");
+        assert_eq!(value["topic"]["target_currency"], "BTC");
+        assert!(build_request("SUBSCRIBE", "BTCKRW").is_none());
+    }
+}
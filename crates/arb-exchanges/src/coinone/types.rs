@@ -0,0 +1,301 @@
+//! Coinone 전용 타입 및 API 응답 구조체.
+//!
+//! Coinone은 가격/수량을 문자열로, 시각을 밀리초 숫자로 반환합니다.
+//! 모든 응답에는 `result`("success" / "error")와 `error_code`가 포함되며,
+//! 실패도 HTTP 200으로 내려오므로 본문의 `result`를 확인해야 합니다.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer};
+
+/// Coinone API 응답 헤더 (성공/실패 판정용).
+///
+/// ```json
+/// {"result": "error", "error_code": "107", "error_msg": "Parameter error"}
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct CoinoneStatus {
+    /// 결과 ("success" 또는 "error").
+    pub result: String,
+    /// 에러 코드 ("0" = 성공).
+    #[serde(default)]
+    pub error_code: String,
+    /// 에러 메시지.
+    #[serde(default)]
+    pub error_msg: String,
+}
+
+/// 호가 레벨 (`{"price": "...", "qty": "..."}`).
+#[derive(Debug, Deserialize)]
+pub struct CoinonePriceLevel {
+    /// 가격.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub price: Decimal,
+    /// 수량.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub qty: Decimal,
+}
+
+/// 티커 목록 응답 (`/public/v2/ticker_new/{quote}[/{target}]`).
+#[derive(Debug, Deserialize)]
+pub struct CoinoneTickers {
+    /// 티커 목록.
+    #[serde(default)]
+    pub tickers: Vec<CoinoneTicker>,
+}
+
+/// Coinone 티커.
+#[derive(Debug, Deserialize)]
+pub struct CoinoneTicker {
+    /// 마켓 기준 통화 (예: "KRW").
+    pub quote_currency: String,
+    /// 종목 통화 (예: "BTC").
+    pub target_currency: String,
+    /// 티커 시각 (밀리초).
+    pub timestamp: i64,
+    /// 24시간 고가.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub high: Decimal,
+    /// 24시간 저가.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub low: Decimal,
+    /// 24시간 시가.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub first: Decimal,
+    /// 최근 체결가.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub last: Decimal,
+    /// 24시간 거래대금 (quote 통화).
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub quote_volume: Decimal,
+    /// 24시간 거래량 (target 통화).
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub target_volume: Decimal,
+    /// 전일 종가.
+    #[serde(default, deserialize_with = "deserialize_optional_decimal_string")]
+    pub yesterday_last: Option<Decimal>,
+}
+
+/// 오더북 응답 (`/public/v2/orderbook/{quote}/{target}`).
+#[derive(Debug, Deserialize)]
+pub struct CoinoneOrderbook {
+    /// 오더북 시각 (밀리초).
+    pub timestamp: i64,
+    /// 마켓 기준 통화.
+    pub quote_currency: String,
+    /// 종목 통화.
+    pub target_currency: String,
+    /// 매도 호가 (가격 오름차순).
+    #[serde(default)]
+    pub asks: Vec<CoinonePriceLevel>,
+    /// 매수 호가 (가격 내림차순).
+    #[serde(default)]
+    pub bids: Vec<CoinonePriceLevel>,
+}
+
+/// 캔들 응답 (`/public/v2/chart/{quote}/{target}`).
+#[derive(Debug, Deserialize)]
+pub struct CoinoneChart {
+    /// 캔들 목록 (최신순).
+    #[serde(default)]
+    pub chart: Vec<CoinoneCandle>,
+}
+
+/// Coinone 캔들.
+#[derive(Debug, Deserialize)]
+pub struct CoinoneCandle {
+    /// 캔들 시작 시각 (밀리초).
+    pub timestamp: i64,
+    /// 시가.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub open: Decimal,
+    /// 고가.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub high: Decimal,
+    /// 저가.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub low: Decimal,
+    /// 종가.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub close: Decimal,
+    /// 거래량 (target 통화).
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub target_volume: Decimal,
+}
+
+/// 주문 접수 응답 (`/v2.1/order`).
+#[derive(Debug, Deserialize)]
+pub struct CoinoneOrderAck {
+    /// 주문 ID.
+    pub order_id: String,
+}
+
+/// 주문 상세 응답 (`/v2.1/order/detail`).
+#[derive(Debug, Deserialize)]
+pub struct CoinoneOrderDetail {
+    /// 주문 정보.
+    pub order: CoinoneOrder,
+}
+
+/// 미체결 주문 목록 응답 (`/v2.1/order/active_orders`).
+#[derive(Debug, Deserialize)]
+pub struct CoinoneActiveOrders {
+    /// 미체결 주문 목록.
+    #[serde(default)]
+    pub active_orders: Vec<CoinoneOrder>,
+}
+
+/// Coinone 주문.
+#[derive(Debug, Deserialize)]
+pub struct CoinoneOrder {
+    /// 주문 ID.
+    pub order_id: String,
+    /// 주문 유형 ("LIMIT", "MARKET", "STOP_LIMIT").
+    #[serde(rename = "type")]
+    pub order_type: String,
+    /// 마켓 기준 통화.
+    pub quote_currency: String,
+    /// 종목 통화.
+    pub target_currency: String,
+    /// 주문 상태 ("LIVE", "PARTIALLY_FILLED", "FILLED", "CANCELED" 등).
+    pub status: String,
+    /// 주문 방향 ("BUY", "SELL").
+    pub side: String,
+    /// 주문 가격 (시장가 주문은 빈 값).
+    #[serde(default, deserialize_with = "deserialize_optional_decimal_string")]
+    pub price: Option<Decimal>,
+    /// 원 주문 수량.
+    #[serde(default, deserialize_with = "deserialize_optional_decimal_string")]
+    pub original_qty: Option<Decimal>,
+    /// 체결 수량.
+    #[serde(default, deserialize_with = "deserialize_optional_decimal_string")]
+    pub executed_qty: Option<Decimal>,
+    /// 미체결 수량.
+    #[serde(default, deserialize_with = "deserialize_optional_decimal_string")]
+    pub remain_qty: Option<Decimal>,
+    /// 평균 체결가.
+    #[serde(
+        default,
+        alias = "avg_price",
+        deserialize_with = "deserialize_optional_decimal_string"
+    )]
+    pub average_executed_price: Option<Decimal>,
+    /// 수수료.
+    #[serde(default, deserialize_with = "deserialize_optional_decimal_string")]
+    pub fee: Option<Decimal>,
+    /// 주문 시각 (밀리초).
+    pub ordered_at: i64,
+    /// 사용자 지정 주문 ID.
+    #[serde(default)]
+    pub user_order_id: Option<String>,
+}
+
+/// 전체 잔고 응답 (`/v2.1/account/balance/all`).
+#[derive(Debug, Deserialize)]
+pub struct CoinoneBalances {
+    /// 통화별 잔고.
+    #[serde(default)]
+    pub balances: Vec<CoinoneBalance>,
+}
+
+/// Coinone 통화별 잔고.
+#[derive(Debug, Deserialize)]
+pub struct CoinoneBalance {
+    /// 통화 코드 (예: "KRW", "BTC").
+    pub currency: String,
+    /// 주문 가능 수량.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub available: Decimal,
+    /// 주문에 묶인 수량.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub limit: Decimal,
+    /// 평균 매수가.
+    #[serde(default, deserialize_with = "deserialize_optional_decimal_string")]
+    pub average_price: Option<Decimal>,
+}
+
+/// 문자열에서 Decimal로 역직렬화 (빈 문자열은 0).
+fn deserialize_decimal_string<'de, D>(deserializer: D) -> Result<Decimal, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    if s.is_empty() {
+        return Ok(Decimal::ZERO);
+    }
+    s.parse::<Decimal>().map_err(serde::de::Error::custom)
+}
+
+/// 문자열에서 Option<Decimal>로 역직렬화 (null과 빈 문자열은 None).
+fn deserialize_optional_decimal_string<'de, D>(deserializer: D) -> Result<Option<Decimal>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let opt: Option<String> = Option::deserialize(deserializer)?;
+    match opt {
+        Some(s) if !s.is_empty() => s
+            .parse::<Decimal>()
+            .map(Some)
+            .map_err(serde::de::Error::custom),
+        _ => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_coinone_ticker() {
+        let json = r#"{
+            "result": "success", "error_code": "0", "server_time": 1707177600000,
+            "tickers": [{
+                "quote_currency": "KRW", "target_currency": "BTC",
+                "timestamp": 1707177600000,
+                "high": "72000000.0", "low": "70000000.0",
+                "first": "70500000.0", "last": "71500000.0",
+                "quote_volume": "1234567890.0", "target_volume": "17.5",
+                "yesterday_last": "70400000.0",
+                "best_asks": [{"price": "71510000.0", "qty": "0.1"}],
+                "best_bids": [{"price": "71490000.0", "qty": "0.2"}],
+                "id": "1707177600000001"
+            }]
+        }"#;
+
+        let response: CoinoneTickers = serde_json::from_str(json).unwrap();
+        let ticker = &response.tickers[0];
+        assert_eq!(ticker.target_currency, "BTC");
+        assert_eq!(ticker.last, Decimal::from(71_500_000));
+        assert_eq!(ticker.yesterday_last, Some(Decimal::from(70_400_000)));
+    }
+
+    #[test]
+    fn test_deserialize_coinone_order() {
+        let json = r#"{
+            "result": "success", "error_code": "0",
+            "order": {
+                "order_id": "0e30219d-1e2b-11ee-a4f6-0242ac110002",
+                "type": "LIMIT", "quote_currency": "KRW", "target_currency": "BTC",
+                "status": "PARTIALLY_FILLED", "side": "BUY",
+                "fee": "100", "fee_rate": "0.002",
+                "average_executed_price": "71500000",
+                "price": "71500000", "original_qty": "0.01",
+                "executed_qty": "0.004", "canceled_qty": "0", "remain_qty": "0.006",
+                "ordered_at": 1707177600000, "updated_at": 1707177601000,
+                "user_order_id": "arb-1"
+            }
+        }"#;
+
+        let detail: CoinoneOrderDetail = serde_json::from_str(json).unwrap();
+        assert_eq!(detail.order.status, "PARTIALLY_FILLED");
+        assert_eq!(detail.order.executed_qty, Some(Decimal::new(4, 3)));
+        assert_eq!(detail.order.user_order_id.as_deref(), Some("arb-1"));
+    }
+
+    #[test]
+    fn test_deserialize_coinone_error_status() {
+        let json = r#"{"result": "error", "error_code": "107", "error_msg": "Parameter error"}"#;
+        let status: CoinoneStatus = serde_json::from_str(json).unwrap();
+        assert_eq!(status.result, "error");
+        assert_eq!(status.error_code, "107");
+    }
+}
@@ -0,0 +1,63 @@
+//! 교체 가능한 자격 증명 슬롯.
+//!
+//! 클라이언트의 복제본들이 같은 슬롯을 공유하므로, 실행 중 한 곳에서 키를 교체하면
+//! 이후 요청부터 모든 복제본(`LiveExecutor`, 모니터 등)이 새 키로 서명합니다.
+
+use std::sync::{Arc, PoisonError, RwLock};
+
+use arb_exchange::{ExchangeError, ExchangeResult};
+
+/// 공유 자격 증명 슬롯.
+pub(crate) struct CredentialSlot<C>(Arc<RwLock<Option<Arc<C>>>>);
+
+impl<C> Clone for CredentialSlot<C> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<C> CredentialSlot<C> {
+    /// 새 슬롯을 생성합니다.
+    pub(crate) fn new(initial: Option<C>) -> Self {
+        Self(Arc::new(RwLock::new(initial.map(Arc::new))))
+    }
+
+    /// 현재 자격 증명을 반환합니다.
+    pub(crate) fn get(&self) -> ExchangeResult<Arc<C>> {
+        self.0
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+            .ok_or_else(|| ExchangeError::AuthError("Credentials not provided".to_string()))
+    }
+
+    /// 자격 증명을 교체합니다. 진행 중인 요청은 이전 키로 끝납니다.
+    pub(crate) fn set(&self, credentials: C) {
+        *self.0.write().unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(credentials));
+    }
+
+    /// 자격 증명이 설정되어 있으면 true를 반환합니다.
+    pub(crate) fn is_set(&self) -> bool {
+        self.0
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_credential_slot_shared_between_clones() {
+        let slot: CredentialSlot<String> = CredentialSlot::new(None);
+        let clone = slot.clone();
+        assert!(!clone.is_set());
+        assert!(clone.get().is_err());
+
+        slot.set("rotated".to_string());
+        assert!(clone.is_set());
+        assert_eq!(clone.get().unwrap().as_str(), "rotated");
+    }
+}
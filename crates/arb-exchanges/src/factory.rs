@@ -13,7 +13,9 @@ use chrono::{DateTime, Utc};
 use std::sync::Arc;
 use tracing::{debug, info, warn};
 
-use crate::{BithumbClient, BybitClient, UpbitClient};
+use crate::{
+    BinanceClient, BithumbClient, BybitClient, CoinoneClient, KorbitClient, OkxClient, UpbitClient,
+};
 
 // ==================== 거래소 어댑터 ====================
 
@@ -46,7 +48,10 @@ impl UpbitAdapter {
     /// 거래소 설정으로부터 생성합니다.
     pub fn from_config(config: &ExchangeConfig) -> ExchangeResult<Self> {
         if config.has_credentials() {
-            Self::authenticated(&config.api_key, &config.secret_key)
+            Self::authenticated(
+                config.api_key.expose_secret(),
+                config.secret_key.expose_secret(),
+            )
         } else {
             Self::public()
         }
@@ -60,7 +65,7 @@ impl ExchangeAdapter for UpbitAdapter {
     }
 
     fn is_authenticated(&self) -> bool {
-        self.client.credentials.is_some()
+        self.client.has_credentials()
     }
 
     fn native_quote_currency(&self) -> &str {
@@ -160,7 +165,10 @@ impl BithumbAdapter {
     /// 거래소 설정으로부터 생성합니다.
     pub fn from_config(config: &ExchangeConfig) -> ExchangeResult<Self> {
         if config.has_credentials() {
-            Self::authenticated(&config.api_key, &config.secret_key)
+            Self::authenticated(
+                config.api_key.expose_secret(),
+                config.secret_key.expose_secret(),
+            )
         } else {
             Self::public()
         }
@@ -174,7 +182,7 @@ impl ExchangeAdapter for BithumbAdapter {
     }
 
     fn is_authenticated(&self) -> bool {
-        self.client.credentials.is_some()
+        self.client.has_credentials()
     }
 
     fn native_quote_currency(&self) -> &str {
@@ -280,7 +288,10 @@ impl BybitAdapter {
     /// 거래소 설정으로부터 생성합니다.
     pub fn from_config(config: &ExchangeConfig) -> ExchangeResult<Self> {
         if config.has_credentials() {
-            Self::authenticated(&config.api_key, &config.secret_key)
+            Self::authenticated(
+                config.api_key.expose_secret(),
+                config.secret_key.expose_secret(),
+            )
         } else {
             Self::public()
         }
@@ -289,7 +300,10 @@ impl BybitAdapter {
     /// 거래소 설정으로부터 생성합니다 (테스트넷).
     pub fn from_config_testnet(config: &ExchangeConfig) -> ExchangeResult<Self> {
         if config.has_credentials() {
-            Self::authenticated_testnet(&config.api_key, &config.secret_key)
+            Self::authenticated_testnet(
+                config.api_key.expose_secret(),
+                config.secret_key.expose_secret(),
+            )
         } else {
             Self::public_testnet()
         }
@@ -303,7 +317,7 @@ impl ExchangeAdapter for BybitAdapter {
     }
 
     fn is_authenticated(&self) -> bool {
-        self.client.credentials.is_some()
+        self.client.has_credentials()
     }
 
     fn native_quote_currency(&self) -> &str {
@@ -374,6 +388,531 @@ impl ExchangeAdapter for BybitAdapter {
     }
 }
 
+/// Binance 거래소 어댑터.
+#[derive(Debug)]
+pub struct BinanceAdapter {
+    client: BinanceClient,
+}
+
+impl BinanceAdapter {
+    /// 클라이언트로부터 새 Binance 어댑터를 생성합니다.
+    pub fn new(client: BinanceClient) -> Self {
+        Self { client }
+    }
+
+    /// 인증되지 않은 새 Binance 어댑터를 생성합니다 (메인넷).
+    pub fn public() -> ExchangeResult<Self> {
+        Ok(Self {
+            client: BinanceClient::new()?,
+        })
+    }
+
+    /// 인증되지 않은 새 Binance 어댑터를 생성합니다 (테스트넷).
+    pub fn public_testnet() -> ExchangeResult<Self> {
+        Ok(Self {
+            client: BinanceClient::new_testnet()?,
+        })
+    }
+
+    /// 인증된 새 Binance 어댑터를 생성합니다 (메인넷).
+    pub fn authenticated(api_key: &str, secret_key: &str) -> ExchangeResult<Self> {
+        Ok(Self {
+            client: BinanceClient::with_credentials(api_key, secret_key)?,
+        })
+    }
+
+    /// 인증된 새 Binance 어댑터를 생성합니다 (테스트넷).
+    pub fn authenticated_testnet(api_key: &str, secret_key: &str) -> ExchangeResult<Self> {
+        Ok(Self {
+            client: BinanceClient::with_credentials_testnet(api_key, secret_key)?,
+        })
+    }
+
+    /// 거래소 설정으로부터 생성합니다.
+    pub fn from_config(config: &ExchangeConfig) -> ExchangeResult<Self> {
+        if config.has_credentials() {
+            Self::authenticated(
+                config.api_key.expose_secret(),
+                config.secret_key.expose_secret(),
+            )
+        } else {
+            Self::public()
+        }
+    }
+
+    /// 거래소 설정으로부터 생성합니다 (테스트넷).
+    pub fn from_config_testnet(config: &ExchangeConfig) -> ExchangeResult<Self> {
+        if config.has_credentials() {
+            Self::authenticated_testnet(
+                config.api_key.expose_secret(),
+                config.secret_key.expose_secret(),
+            )
+        } else {
+            Self::public_testnet()
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ExchangeAdapter for BinanceAdapter {
+    fn name(&self) -> &str {
+        MarketData::name(&self.client)
+    }
+
+    fn is_authenticated(&self) -> bool {
+        self.client.has_credentials()
+    }
+
+    fn native_quote_currency(&self) -> &str {
+        "USDT"
+    }
+
+    async fn get_ticker(&self, markets: &[&str]) -> ExchangeResult<Vec<Ticker>> {
+        MarketData::get_ticker(&self.client, markets).await
+    }
+
+    async fn get_all_tickers(&self) -> ExchangeResult<Vec<Ticker>> {
+        MarketData::get_all_tickers(&self.client).await
+    }
+
+    async fn get_orderbook(&self, market: &str, depth: Option<u32>) -> ExchangeResult<OrderBook> {
+        MarketData::get_orderbook(&self.client, market, depth).await
+    }
+
+    async fn get_candles(
+        &self,
+        market: &str,
+        interval: CandleInterval,
+        count: u32,
+    ) -> ExchangeResult<Vec<Candle>> {
+        MarketData::get_candles(&self.client, market, interval, count).await
+    }
+
+    async fn get_candles_before(
+        &self,
+        market: &str,
+        interval: CandleInterval,
+        count: u32,
+        before: DateTime<Utc>,
+    ) -> ExchangeResult<Vec<Candle>> {
+        MarketData::get_candles_before(&self.client, market, interval, count, before).await
+    }
+
+    async fn subscribe_markets(&self, markets: &[&str]) -> ExchangeResult<()> {
+        MarketStream::subscribe_markets(&self.client, markets).await
+    }
+
+    async fn unsubscribe_markets(&self, markets: &[&str]) -> ExchangeResult<()> {
+        MarketStream::unsubscribe_markets(&self.client, markets).await
+    }
+
+    async fn place_order(&self, request: &OrderRequest) -> ExchangeResult<Order> {
+        OrderManagement::place_order(&self.client, request).await
+    }
+
+    async fn cancel_order(&self, order_id: &str) -> ExchangeResult<Order> {
+        OrderManagement::cancel_order(&self.client, order_id).await
+    }
+
+    async fn get_order(&self, order_id: &str) -> ExchangeResult<Order> {
+        OrderManagement::get_order(&self.client, order_id).await
+    }
+
+    async fn get_open_orders(&self, market: Option<&str>) -> ExchangeResult<Vec<Order>> {
+        OrderManagement::get_open_orders(&self.client, market).await
+    }
+
+    async fn get_balances(&self) -> ExchangeResult<Vec<Balance>> {
+        OrderManagement::get_balances(&self.client).await
+    }
+
+    async fn get_balance(&self, currency: &str) -> ExchangeResult<Balance> {
+        OrderManagement::get_balance(&self.client, currency).await
+    }
+}
+
+/// OKX 거래소 어댑터.
+#[derive(Debug)]
+pub struct OkxAdapter {
+    client: OkxClient,
+}
+
+impl OkxAdapter {
+    /// 클라이언트로부터 새 OKX 어댑터를 생성합니다.
+    pub fn new(client: OkxClient) -> Self {
+        Self { client }
+    }
+
+    /// 인증되지 않은 새 OKX 어댑터를 생성합니다 (실거래).
+    pub fn public() -> ExchangeResult<Self> {
+        Ok(Self {
+            client: OkxClient::new()?,
+        })
+    }
+
+    /// 인증되지 않은 새 OKX 어댑터를 생성합니다 (데모 트레이딩).
+    pub fn public_demo() -> ExchangeResult<Self> {
+        Ok(Self {
+            client: OkxClient::new_demo()?,
+        })
+    }
+
+    /// 인증된 새 OKX 어댑터를 생성합니다 (실거래).
+    pub fn authenticated(
+        api_key: &str,
+        secret_key: &str,
+        passphrase: &str,
+    ) -> ExchangeResult<Self> {
+        Ok(Self {
+            client: OkxClient::with_credentials(api_key, secret_key, passphrase)?,
+        })
+    }
+
+    /// 인증된 새 OKX 어댑터를 생성합니다 (데모 트레이딩).
+    pub fn authenticated_demo(
+        api_key: &str,
+        secret_key: &str,
+        passphrase: &str,
+    ) -> ExchangeResult<Self> {
+        Ok(Self {
+            client: OkxClient::with_credentials_demo(api_key, secret_key, passphrase)?,
+        })
+    }
+
+    /// 거래소 설정으로부터 생성합니다.
+    ///
+    /// # 에러
+    ///
+    /// API 키는 있는데 패스프레이즈가 없으면 `ConfigError`를 반환합니다.
+    pub fn from_config(config: &ExchangeConfig) -> ExchangeResult<Self> {
+        if !config.has_credentials() {
+            return Self::public();
+        }
+        if config.passphrase.is_empty() {
+            return Err(ExchangeError::ConfigError(
+                "OKX API key requires a passphrase".to_string(),
+            ));
+        }
+        Self::authenticated(
+            config.api_key.expose_secret(),
+            config.secret_key.expose_secret(),
+            config.passphrase.expose_secret(),
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl ExchangeAdapter for OkxAdapter {
+    fn name(&self) -> &str {
+        MarketData::name(&self.client)
+    }
+
+    fn is_authenticated(&self) -> bool {
+        self.client.has_credentials()
+    }
+
+    fn native_quote_currency(&self) -> &str {
+        "USDT"
+    }
+
+    async fn get_ticker(&self, markets: &[&str]) -> ExchangeResult<Vec<Ticker>> {
+        MarketData::get_ticker(&self.client, markets).await
+    }
+
+    async fn get_all_tickers(&self) -> ExchangeResult<Vec<Ticker>> {
+        MarketData::get_all_tickers(&self.client).await
+    }
+
+    async fn get_orderbook(&self, market: &str, depth: Option<u32>) -> ExchangeResult<OrderBook> {
+        MarketData::get_orderbook(&self.client, market, depth).await
+    }
+
+    async fn get_candles(
+        &self,
+        market: &str,
+        interval: CandleInterval,
+        count: u32,
+    ) -> ExchangeResult<Vec<Candle>> {
+        MarketData::get_candles(&self.client, market, interval, count).await
+    }
+
+    async fn get_candles_before(
+        &self,
+        market: &str,
+        interval: CandleInterval,
+        count: u32,
+        before: DateTime<Utc>,
+    ) -> ExchangeResult<Vec<Candle>> {
+        MarketData::get_candles_before(&self.client, market, interval, count, before).await
+    }
+
+    async fn subscribe_markets(&self, markets: &[&str]) -> ExchangeResult<()> {
+        MarketStream::subscribe_markets(&self.client, markets).await
+    }
+
+    async fn unsubscribe_markets(&self, markets: &[&str]) -> ExchangeResult<()> {
+        MarketStream::unsubscribe_markets(&self.client, markets).await
+    }
+
+    async fn place_order(&self, request: &OrderRequest) -> ExchangeResult<Order> {
+        OrderManagement::place_order(&self.client, request).await
+    }
+
+    async fn cancel_order(&self, order_id: &str) -> ExchangeResult<Order> {
+        OrderManagement::cancel_order(&self.client, order_id).await
+    }
+
+    async fn get_order(&self, order_id: &str) -> ExchangeResult<Order> {
+        OrderManagement::get_order(&self.client, order_id).await
+    }
+
+    async fn get_open_orders(&self, market: Option<&str>) -> ExchangeResult<Vec<Order>> {
+        OrderManagement::get_open_orders(&self.client, market).await
+    }
+
+    async fn get_balances(&self) -> ExchangeResult<Vec<Balance>> {
+        OrderManagement::get_balances(&self.client).await
+    }
+
+    async fn get_balance(&self, currency: &str) -> ExchangeResult<Balance> {
+        OrderManagement::get_balance(&self.client, currency).await
+    }
+}
+
+/// Coinone 거래소 어댑터.
+#[derive(Debug)]
+pub struct CoinoneAdapter {
+    client: CoinoneClient,
+}
+
+impl CoinoneAdapter {
+    /// 클라이언트로부터 새 Coinone 어댑터를 생성합니다.
+    pub fn new(client: CoinoneClient) -> Self {
+        Self { client }
+    }
+
+    /// 인증되지 않은 새 Coinone 어댑터를 생성합니다.
+    pub fn public() -> ExchangeResult<Self> {
+        Ok(Self {
+            client: CoinoneClient::new()?,
+        })
+    }
+
+    /// 인증된 새 Coinone 어댑터를 생성합니다.
+    pub fn authenticated(api_key: &str, secret_key: &str) -> ExchangeResult<Self> {
+        Ok(Self {
+            client: CoinoneClient::with_credentials(api_key, secret_key)?,
+        })
+    }
+
+    /// 거래소 설정으로부터 생성합니다.
+    pub fn from_config(config: &ExchangeConfig) -> ExchangeResult<Self> {
+        if config.has_credentials() {
+            Self::authenticated(
+                config.api_key.expose_secret(),
+                config.secret_key.expose_secret(),
+            )
+        } else {
+            Self::public()
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ExchangeAdapter for CoinoneAdapter {
+    fn name(&self) -> &str {
+        MarketData::name(&self.client)
+    }
+
+    fn is_authenticated(&self) -> bool {
+        self.client.has_credentials()
+    }
+
+    fn native_quote_currency(&self) -> &str {
+        "KRW"
+    }
+
+    async fn get_ticker(&self, markets: &[&str]) -> ExchangeResult<Vec<Ticker>> {
+        MarketData::get_ticker(&self.client, markets).await
+    }
+
+    async fn get_all_tickers(&self) -> ExchangeResult<Vec<Ticker>> {
+        MarketData::get_all_tickers(&self.client).await
+    }
+
+    async fn get_orderbook(&self, market: &str, depth: Option<u32>) -> ExchangeResult<OrderBook> {
+        MarketData::get_orderbook(&self.client, market, depth).await
+    }
+
+    async fn get_candles(
+        &self,
+        market: &str,
+        interval: CandleInterval,
+        count: u32,
+    ) -> ExchangeResult<Vec<Candle>> {
+        MarketData::get_candles(&self.client, market, interval, count).await
+    }
+
+    async fn get_candles_before(
+        &self,
+        market: &str,
+        interval: CandleInterval,
+        count: u32,
+        before: DateTime<Utc>,
+    ) -> ExchangeResult<Vec<Candle>> {
+        MarketData::get_candles_before(&self.client, market, interval, count, before).await
+    }
+
+    async fn subscribe_markets(&self, markets: &[&str]) -> ExchangeResult<()> {
+        MarketStream::subscribe_markets(&self.client, markets).await
+    }
+
+    async fn unsubscribe_markets(&self, markets: &[&str]) -> ExchangeResult<()> {
+        MarketStream::unsubscribe_markets(&self.client, markets).await
+    }
+
+    async fn place_order(&self, request: &OrderRequest) -> ExchangeResult<Order> {
+        OrderManagement::place_order(&self.client, request).await
+    }
+
+    async fn cancel_order(&self, order_id: &str) -> ExchangeResult<Order> {
+        OrderManagement::cancel_order(&self.client, order_id).await
+    }
+
+    async fn get_order(&self, order_id: &str) -> ExchangeResult<Order> {
+        OrderManagement::get_order(&self.client, order_id).await
+    }
+
+    async fn get_open_orders(&self, market: Option<&str>) -> ExchangeResult<Vec<Order>> {
+        OrderManagement::get_open_orders(&self.client, market).await
+    }
+
+    async fn get_balances(&self) -> ExchangeResult<Vec<Balance>> {
+        OrderManagement::get_balances(&self.client).await
+    }
+
+    async fn get_balance(&self, currency: &str) -> ExchangeResult<Balance> {
+        OrderManagement::get_balance(&self.client, currency).await
+    }
+}
+
+/// Korbit 거래소 어댑터.
+#[derive(Debug)]
+pub struct KorbitAdapter {
+    client: KorbitClient,
+}
+
+impl KorbitAdapter {
+    /// 클라이언트로부터 새 Korbit 어댑터를 생성합니다.
+    pub fn new(client: KorbitClient) -> Self {
+        Self { client }
+    }
+
+    /// 인증되지 않은 새 Korbit 어댑터를 생성합니다.
+    pub fn public() -> ExchangeResult<Self> {
+        Ok(Self {
+            client: KorbitClient::new()?,
+        })
+    }
+
+    /// 인증된 새 Korbit 어댑터를 생성합니다.
+    pub fn authenticated(api_key: &str, secret_key: &str) -> ExchangeResult<Self> {
+        Ok(Self {
+            client: KorbitClient::with_credentials(api_key, secret_key)?,
+        })
+    }
+
+    /// 거래소 설정으로부터 생성합니다.
+    pub fn from_config(config: &ExchangeConfig) -> ExchangeResult<Self> {
+        if config.has_credentials() {
+            Self::authenticated(
+                config.api_key.expose_secret(),
+                config.secret_key.expose_secret(),
+            )
+        } else {
+            Self::public()
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ExchangeAdapter for KorbitAdapter {
+    fn name(&self) -> &str {
+        MarketData::name(&self.client)
+    }
+
+    fn is_authenticated(&self) -> bool {
+        self.client.has_credentials()
+    }
+
+    fn native_quote_currency(&self) -> &str {
+        "KRW"
+    }
+
+    async fn get_ticker(&self, markets: &[&str]) -> ExchangeResult<Vec<Ticker>> {
+        MarketData::get_ticker(&self.client, markets).await
+    }
+
+    async fn get_all_tickers(&self) -> ExchangeResult<Vec<Ticker>> {
+        MarketData::get_all_tickers(&self.client).await
+    }
+
+    async fn get_orderbook(&self, market: &str, depth: Option<u32>) -> ExchangeResult<OrderBook> {
+        MarketData::get_orderbook(&self.client, market, depth).await
+    }
+
+    async fn get_candles(
+        &self,
+        market: &str,
+        interval: CandleInterval,
+        count: u32,
+    ) -> ExchangeResult<Vec<Candle>> {
+        MarketData::get_candles(&self.client, market, interval, count).await
+    }
+
+    async fn get_candles_before(
+        &self,
+        market: &str,
+        interval: CandleInterval,
+        count: u32,
+        before: DateTime<Utc>,
+    ) -> ExchangeResult<Vec<Candle>> {
+        MarketData::get_candles_before(&self.client, market, interval, count, before).await
+    }
+
+    async fn subscribe_markets(&self, markets: &[&str]) -> ExchangeResult<()> {
+        MarketStream::subscribe_markets(&self.client, markets).await
+    }
+
+    async fn unsubscribe_markets(&self, markets: &[&str]) -> ExchangeResult<()> {
+        MarketStream::unsubscribe_markets(&self.client, markets).await
+    }
+
+    async fn place_order(&self, request: &OrderRequest) -> ExchangeResult<Order> {
+        OrderManagement::place_order(&self.client, request).await
+    }
+
+    async fn cancel_order(&self, order_id: &str) -> ExchangeResult<Order> {
+        OrderManagement::cancel_order(&self.client, order_id).await
+    }
+
+    async fn get_order(&self, order_id: &str) -> ExchangeResult<Order> {
+        OrderManagement::get_order(&self.client, order_id).await
+    }
+
+    async fn get_open_orders(&self, market: Option<&str>) -> ExchangeResult<Vec<Order>> {
+        OrderManagement::get_open_orders(&self.client, market).await
+    }
+
+    async fn get_balances(&self) -> ExchangeResult<Vec<Balance>> {
+        OrderManagement::get_balances(&self.client).await
+    }
+
+    async fn get_balance(&self, currency: &str) -> ExchangeResult<Balance> {
+        OrderManagement::get_balance(&self.client, currency).await
+    }
+}
+
 // ==================== 팩토리 함수 ====================
 
 /// 이름으로 거래소 어댑터를 생성합니다.
@@ -431,6 +970,34 @@ pub fn create_exchange(
                 Arc::new(BybitAdapter::public()?)
             }
         }
+        ExchangeName::Binance => {
+            if let Some(cfg) = config {
+                Arc::new(BinanceAdapter::from_config(cfg)?)
+            } else {
+                Arc::new(BinanceAdapter::public()?)
+            }
+        }
+        ExchangeName::Okx => {
+            if let Some(cfg) = config {
+                Arc::new(OkxAdapter::from_config(cfg)?)
+            } else {
+                Arc::new(OkxAdapter::public()?)
+            }
+        }
+        ExchangeName::Coinone => {
+            if let Some(cfg) = config {
+                Arc::new(CoinoneAdapter::from_config(cfg)?)
+            } else {
+                Arc::new(CoinoneAdapter::public()?)
+            }
+        }
+        ExchangeName::Korbit => {
+            if let Some(cfg) = config {
+                Arc::new(KorbitAdapter::from_config(cfg)?)
+            } else {
+                Arc::new(KorbitAdapter::public()?)
+            }
+        }
     };
 
     info!(
@@ -475,6 +1042,34 @@ pub fn create_exchange_boxed(
                 Box::new(BybitAdapter::public()?)
             }
         }
+        ExchangeName::Binance => {
+            if let Some(cfg) = config {
+                Box::new(BinanceAdapter::from_config(cfg)?)
+            } else {
+                Box::new(BinanceAdapter::public()?)
+            }
+        }
+        ExchangeName::Okx => {
+            if let Some(cfg) = config {
+                Box::new(OkxAdapter::from_config(cfg)?)
+            } else {
+                Box::new(OkxAdapter::public()?)
+            }
+        }
+        ExchangeName::Coinone => {
+            if let Some(cfg) = config {
+                Box::new(CoinoneAdapter::from_config(cfg)?)
+            } else {
+                Box::new(CoinoneAdapter::public()?)
+            }
+        }
+        ExchangeName::Korbit => {
+            if let Some(cfg) = config {
+                Box::new(KorbitAdapter::from_config(cfg)?)
+            } else {
+                Box::new(KorbitAdapter::public()?)
+            }
+        }
     };
 
     Ok(adapter)
@@ -524,6 +1119,18 @@ impl ExchangeManagerExt for ExchangeManager {
         // Bybit 등록
         self.register_from_config("bybit", Some(&config.bybit))?;
 
+        // Binance 등록
+        self.register_from_config("binance", Some(&config.binance))?;
+
+        // OKX 등록
+        self.register_from_config("okx", Some(&config.okx))?;
+
+        // Coinone 등록
+        self.register_from_config("coinone", Some(&config.coinone))?;
+
+        // Korbit 등록
+        self.register_from_config("korbit", Some(&config.korbit))?;
+
         info!(count = self.len(), "모든 거래소 등록 완료");
 
         Ok(())
@@ -552,6 +1159,19 @@ mod tests {
             "bybit".parse::<ExchangeName>().ok(),
             Some(ExchangeName::Bybit)
         );
+        assert_eq!(
+            "binance".parse::<ExchangeName>().ok(),
+            Some(ExchangeName::Binance)
+        );
+        assert_eq!("okx".parse::<ExchangeName>().ok(), Some(ExchangeName::Okx));
+        assert_eq!(
+            "coinone".parse::<ExchangeName>().ok(),
+            Some(ExchangeName::Coinone)
+        );
+        assert_eq!(
+            "korbit".parse::<ExchangeName>().ok(),
+            Some(ExchangeName::Korbit)
+        );
         assert!("unknown".parse::<ExchangeName>().is_err());
     }
 
@@ -560,6 +1180,10 @@ mod tests {
         assert_eq!(ExchangeName::Upbit.as_str(), "upbit");
         assert_eq!(ExchangeName::Bithumb.as_str(), "bithumb");
         assert_eq!(ExchangeName::Bybit.as_str(), "bybit");
+        assert_eq!(ExchangeName::Binance.as_str(), "binance");
+        assert_eq!(ExchangeName::Okx.as_str(), "okx");
+        assert_eq!(ExchangeName::Coinone.as_str(), "coinone");
+        assert_eq!(ExchangeName::Korbit.as_str(), "korbit");
     }
 
     #[test]
@@ -593,11 +1217,58 @@ mod tests {
         assert_eq!(adapter.name(), "Bybit");
     }
 
+    #[test]
+    fn test_create_exchange_binance() {
+        let adapter = create_exchange("binance", None).unwrap();
+        assert_eq!(adapter.name(), "Binance");
+        assert_eq!(adapter.native_quote_currency(), "USDT");
+    }
+
+    #[test]
+    fn test_create_exchange_okx() {
+        let adapter = create_exchange("okx", None).unwrap();
+        assert_eq!(adapter.name(), "OKX");
+        assert_eq!(adapter.native_quote_currency(), "USDT");
+
+        // 패스프레이즈 없는 API 키는 설정 오류
+        let config = ExchangeConfig {
+            api_key: "test_key".into(),
+            secret_key: "test_secret".into(),
+            ..Default::default()
+        };
+        assert!(matches!(
+            create_exchange("okx", Some(&config)),
+            Err(ExchangeError::ConfigError(_))
+        ));
+
+        let config = ExchangeConfig {
+            passphrase: "test_pass".into(),
+            ..config
+        };
+        assert!(
+            create_exchange("okx", Some(&config))
+                .unwrap()
+                .is_authenticated()
+        );
+    }
+
+    #[test]
+    fn test_create_exchange_korean_venues() {
+        let adapter = create_exchange("coinone", None).unwrap();
+        assert_eq!(adapter.name(), "Coinone");
+        assert_eq!(adapter.native_quote_currency(), "KRW");
+
+        let adapter = create_exchange_boxed("korbit", None).unwrap();
+        assert_eq!(adapter.name(), "Korbit");
+        assert_eq!(adapter.native_quote_currency(), "KRW");
+    }
+
     #[test]
     fn test_create_exchange_with_credentials() {
         let config = ExchangeConfig {
-            api_key: "test_key".to_string(),
-            secret_key: "test_secret".to_string(),
+            api_key: "test_key".into(),
+            secret_key: "test_secret".into(),
+            ..Default::default()
         };
 
         let result = create_exchange("upbit", Some(&config));
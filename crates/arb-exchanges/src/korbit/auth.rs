@@ -0,0 +1,128 @@
+//! Korbit HMAC-SHA256 인증 모듈.
+//!
+//! Korbit API v2는 요청 파라미터에 `timestamp`(밀리초)를 더한 쿼리 문자열을
+//! HMAC-SHA256으로 서명하고, 결과를 `signature` 파라미터로 덧붙입니다.
+//! GET/DELETE는 쿼리 문자열로, POST는 같은 형식의 form 본문으로 전송하며,
+//! API 키는 `X-KAPI-KEY` 헤더로 전달합니다.
+
+use arb_config::SecretString;
+use arb_exchange::ExchangeError;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// API 키 헤더 이름.
+pub const HEADER_API_KEY: &str = "X-KAPI-KEY";
+
+/// Korbit API 인증을 위한 자격 증명.
+#[derive(Clone)]
+pub struct KorbitCredentials {
+    api_key: String,
+    secret_key: SecretString,
+}
+
+impl std::fmt::Debug for KorbitCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let masked_api_key = if self.api_key.len() >= 4 {
+            format!("{}****", &self.api_key[..4])
+        } else {
+            "****".to_string()
+        };
+        f.debug_struct("KorbitCredentials")
+            .field("api_key", &masked_api_key)
+            .field("secret_key", &"****")
+            .finish()
+    }
+}
+
+impl KorbitCredentials {
+    /// 새 자격 증명을 생성합니다.
+    ///
+    /// # 인자
+    ///
+    /// * `api_key` - Korbit API 키
+    /// * `secret_key` - Korbit HMAC 시크릿 키
+    pub fn new(api_key: impl Into<String>, secret_key: impl Into<String>) -> Self {
+        Self {
+            api_key: api_key.into(),
+            secret_key: SecretString::new(secret_key),
+        }
+    }
+
+    /// API 키를 반환합니다.
+    #[inline]
+    pub fn api_key(&self) -> &str {
+        &self.api_key
+    }
+
+    /// 쿼리 문자열을 서명합니다.
+    ///
+    /// # 반환값
+    ///
+    /// 소문자 16진수 HMAC-SHA256 서명
+    pub fn sign(&self, query_string: &str) -> Result<String, ExchangeError> {
+        let mut mac = HmacSha256::new_from_slice(self.secret_key.expose_secret().as_bytes())
+            .map_err(|e| ExchangeError::AuthError(format!("Invalid secret key: {e}")))?;
+        mac.update(query_string.as_bytes());
+        Ok(hex::encode(mac.finalize().into_bytes()))
+    }
+
+    /// 요청 파라미터에 `timestamp`, `signature`를 붙인 쿼리 문자열을 만듭니다.
+    ///
+    /// 반환값은 GET/DELETE 쿼리 문자열이나 POST form 본문으로 그대로 사용합니다.
+    ///
+    /// # 인자
+    ///
+    /// * `params` - 요청 파라미터 (전송 순서 유지)
+    /// * `timestamp` - 서버 시각 기준 타임스탬프 (밀리초)
+    pub fn signed_query(
+        &self,
+        params: &[(&str, &str)],
+        timestamp: u64,
+    ) -> Result<String, ExchangeError> {
+        let timestamp = timestamp.to_string();
+        let query = build_query_string(
+            params
+                .iter()
+                .copied()
+                .chain([("timestamp", timestamp.as_str())]),
+        );
+        let signature = self.sign(&query)?;
+        Ok(format!("{query}&signature={signature}"))
+    }
+}
+
+/// 파라미터로 URL 인코딩된 쿼리 문자열을 만듭니다.
+pub fn build_query_string<'a>(params: impl IntoIterator<Item = (&'a str, &'a str)>) -> String {
+    url::form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(params)
+        .finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signed_query_appends_timestamp_and_signature() {
+        let creds = KorbitCredentials::new("key", "secret");
+        let query = creds
+            .signed_query(&[("symbol", "btc_krw"), ("side", "buy")], 1707177600000)
+            .unwrap();
+
+        let (payload, signature) = query.split_once("&signature=").unwrap();
+        assert_eq!(payload, "symbol=btc_krw&side=buy&timestamp=1707177600000");
+        assert_eq!(signature, creds.sign(payload).unwrap());
+        // SHA-256 다이제스트(32바이트)의 16진수 길이
+        assert_eq!(signature.len(), 64);
+    }
+
+    #[test]
+    fn test_credentials_debug_masks_secrets() {
+        let creds = KorbitCredentials::new("abcdefgh", "super-secret");
+        let debug = format!("{creds:?}");
+        assert!(debug.contains("abcd****"));
+        assert!(!debug.contains("super-secret"));
+    }
+}
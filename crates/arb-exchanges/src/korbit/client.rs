@@ -0,0 +1,933 @@
+//! Korbit REST API 클라이언트 구현.
+//!
+//! 이 모듈은 Korbit API v2와 상호작용하기 위한 메인 클라이언트를 제공합니다.
+//!
+//! Korbit 심볼은 소문자 `{base}_{quote}`("btc_krw") 형식이며, 주문 조회·취소와
+//! 미체결 조회 모두 심볼이 필수이므로 발주 시 주문 ID별 심볼을 기억해 둡니다.
+
+use crate::clock::{ClockSample, ServerClock, ServerTimeSync, local_now_ms};
+use crate::credentials::CredentialSlot;
+use crate::korbit::auth::{HEADER_API_KEY, KorbitCredentials};
+use crate::korbit::stream::KorbitStreamInner;
+use crate::korbit::types::{
+    KorbitBalance, KorbitCandle, KorbitOrder, KorbitOrderAck, KorbitOrderbook, KorbitResponse,
+    KorbitTicker, KorbitTime,
+};
+use crate::order_cache::OrderKeyCache;
+use crate::rate_limit::RateLimiter;
+use arb_exchange::{
+    Balance, Candle, CandleInterval, ExchangeError, ExchangeName, ExchangeResult, MarketData,
+    Order, OrderBook, OrderBookLevel, OrderManagement, OrderRequest, OrderSide, OrderStatus,
+    OrderType, PriceChange, StreamConfig, Ticker, TimeInForce, to_exchange_format,
+    to_internal_format,
+};
+use chrono::{DateTime, TimeZone, Utc};
+use reqwest::{Client, Method};
+use rust_decimal::Decimal;
+use std::sync::Arc;
+use tracing::{debug, info, warn};
+
+/// Korbit REST API 기본 URL.
+const BASE_URL: &str = "https://api.korbit.co.kr";
+
+/// Korbit API 레이트 리밋 (초당 요청 수).
+/// 주문 API 한도(초당 30건 내외)보다 보수적으로 설정.
+const KORBIT_RATE_LIMIT: u32 = 10;
+/// Korbit API 최대 버스트 용량.
+const KORBIT_BURST: u32 = 3;
+
+/// 주문 ID → 심볼 캐시 최대 항목 수.
+const ORDER_SYMBOL_CACHE_CAPACITY: usize = 4096;
+
+/// `/v2/candles` 1회 최대 개수.
+const MAX_CANDLES: u32 = 200;
+
+/// Korbit API 클라이언트.
+///
+/// 이 클라이언트는 Public API와 Private API 모두 지원합니다.
+/// Private API를 사용하려면 API 키와 HMAC 시크릿 키가 필요합니다.
+pub struct KorbitClient {
+    client: Client,
+    /// 인증 정보. 복제본 간에 공유되어 [`Self::set_credentials`]로 교체됩니다.
+    credentials: CredentialSlot<KorbitCredentials>,
+    base_url: String,
+    /// WebSocket 스트림 내부 상태.
+    pub(crate) stream: Arc<KorbitStreamInner>,
+    /// 서버 시계 추정기 (서명 타임스탬프 보정, 피드 지연 측정).
+    pub(crate) clock: Arc<ServerClock>,
+    /// API 레이트 리밋터.
+    limiter: Arc<RateLimiter>,
+    /// 주문 ID / client order ID → Korbit 심볼.
+    order_symbols: OrderKeyCache,
+}
+
+impl std::fmt::Debug for KorbitClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KorbitClient")
+            .field("base_url", &self.base_url)
+            .field("credentials", &self.credentials.is_set())
+            .finish()
+    }
+}
+
+impl Clone for KorbitClient {
+    /// 클라이언트를 복제합니다.
+    ///
+    /// 커넥션 풀, rate limiter, 서버 시계, 주문 심볼 캐시를 복제본과 공유합니다.
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            credentials: self.credentials.clone(),
+            base_url: self.base_url.clone(),
+            stream: Arc::clone(&self.stream),
+            clock: Arc::clone(&self.clock),
+            limiter: Arc::clone(&self.limiter),
+            order_symbols: self.order_symbols.clone(),
+        }
+    }
+}
+
+impl KorbitClient {
+    /// 인증되지 않은 새 Korbit 클라이언트를 생성합니다.
+    ///
+    /// 이 클라이언트는 Public API만 접근할 수 있습니다.
+    ///
+    /// # 오류
+    ///
+    /// HTTP 클라이언트를 생성할 수 없는 경우 오류를 반환합니다.
+    pub fn new() -> ExchangeResult<Self> {
+        Self::new_internal(None, BASE_URL)
+    }
+
+    /// 인증된 새 Korbit 클라이언트를 생성합니다.
+    ///
+    /// # 인자
+    ///
+    /// * `api_key` - Korbit API 키
+    /// * `secret_key` - Korbit HMAC 시크릿 키
+    ///
+    /// # 오류
+    ///
+    /// HTTP 클라이언트를 생성할 수 없는 경우 오류를 반환합니다.
+    pub fn with_credentials(
+        api_key: impl Into<String>,
+        secret_key: impl Into<String>,
+    ) -> ExchangeResult<Self> {
+        let creds = KorbitCredentials::new(api_key, secret_key);
+        Self::new_internal(Some(creds), BASE_URL)
+    }
+
+    /// 내부 생성자.
+    fn new_internal(
+        credentials: Option<KorbitCredentials>,
+        base_url: &str,
+    ) -> ExchangeResult<Self> {
+        let client = Client::builder()
+            .timeout(std::time::Duration::from_secs(30))
+            .build()
+            .map_err(ExchangeError::HttpError)?;
+
+        Ok(Self {
+            client,
+            credentials: CredentialSlot::new(credentials),
+            base_url: base_url.to_string(),
+            stream: Arc::new(KorbitStreamInner::new(StreamConfig::default())),
+            clock: Arc::new(ServerClock::new("korbit")),
+            limiter: Arc::new(RateLimiter::new("korbit", KORBIT_RATE_LIMIT, KORBIT_BURST)),
+            order_symbols: OrderKeyCache::new(ORDER_SYMBOL_CACHE_CAPACITY),
+        })
+    }
+
+    /// WebSocket 스트림 내부 상태에 접근합니다.
+    pub(crate) fn stream_inner(&self) -> &KorbitStreamInner {
+        &self.stream
+    }
+
+    /// 서버 시각 오프셋을 보정한 서명 타임스탬프 (밀리초).
+    fn auth_timestamp(&self) -> u64 {
+        u64::try_from(self.clock.now_ms()).unwrap_or_default()
+    }
+
+    /// 실행 중 API 키를 교체합니다 (키 로테이션).
+    ///
+    /// 이미 서명된 요청은 이전 키로 완료됩니다.
+    ///
+    /// # 인자
+    ///
+    /// * `api_key` - Korbit API 키
+    /// * `secret_key` - Korbit HMAC 시크릿 키
+    pub fn set_credentials(&self, api_key: impl Into<String>, secret_key: impl Into<String>) {
+        self.credentials
+            .set(KorbitCredentials::new(api_key, secret_key));
+    }
+
+    /// 인증 정보가 설정되어 있으면 true를 반환합니다.
+    pub fn has_credentials(&self) -> bool {
+        self.credentials.is_set()
+    }
+
+    /// 내부 마켓 코드를 Korbit 심볼로 변환합니다.
+    ///
+    /// "KRW-BTC" -> "btc_krw"
+    pub(super) fn to_korbit_symbol(market: &str) -> String {
+        to_exchange_format(ExchangeName::Korbit, market)
+    }
+
+    /// Korbit 심볼을 내부 마켓 코드로 변환합니다.
+    ///
+    /// "btc_krw" -> "KRW-BTC"
+    pub(super) fn to_market_code(symbol: &str) -> String {
+        to_internal_format(ExchangeName::Korbit, symbol)
+    }
+
+    /// 주문 응답의 ID와 client order ID를 심볼 캐시에 반영합니다.
+    fn remember_order(&self, order: &KorbitOrder) {
+        self.order_symbols.insert(&order.order_id, &order.symbol);
+        if let Some(ref client_order_id) = order.client_order_id
+            && !client_order_id.is_empty()
+        {
+            self.order_symbols.insert(client_order_id, &order.symbol);
+        }
+    }
+
+    /// 주문 키(주문 ID 또는 client order ID)의 심볼을 찾습니다.
+    ///
+    /// Korbit은 전체 미체결 조회가 없으므로, 캐시에 없으면 이전에 거래한 심볼들의
+    /// 미체결 주문에서 찾습니다. 그래도 없으면 주문 부재를 확정할 수 없으므로
+    /// `OrderNotFound`가 아닌 `InvalidParameter`를 반환합니다.
+    async fn resolve_symbol(&self, key: &str) -> ExchangeResult<String> {
+        if let Some(symbol) = self.order_symbols.get(key) {
+            return Ok(symbol);
+        }
+
+        debug!(key, "Korbit 주문 심볼 캐시 미스 — 미체결 주문에서 검색");
+        for symbol in self.order_symbols.distinct_values() {
+            let open: Vec<KorbitOrder> = self
+                .send_signed(Method::GET, "/v2/openOrders", &[("symbol", &symbol)])
+                .await?;
+            for order in &open {
+                self.remember_order(order);
+            }
+        }
+
+        self.order_symbols.get(key).ok_or_else(|| {
+            ExchangeError::InvalidParameter(format!(
+                "Korbit order lookup requires a known symbol: {key}"
+            ))
+        })
+    }
+
+    /// Public 엔드포인트에 GET 요청을 보냅니다.
+    async fn get_public<T: serde::de::DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        params: &[(&str, &str)],
+    ) -> ExchangeResult<T> {
+        self.limiter.acquire().await;
+        let url = format!("{}{}", self.base_url, endpoint);
+        debug!(endpoint, ?params, "Korbit public GET 요청");
+        let response = self
+            .client
+            .get(&url)
+            .query(params)
+            .send()
+            .await
+            .map_err(ExchangeError::HttpError)?;
+
+        self.handle_response(response).await
+    }
+
+    /// 서명이 필요한 엔드포인트에 요청을 보냅니다.
+    ///
+    /// GET/DELETE는 서명된 쿼리 문자열을 URL에 붙이고, POST는 같은 문자열을
+    /// form 본문으로 보냅니다.
+    async fn send_signed<T: serde::de::DeserializeOwned>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &[(&str, &str)],
+    ) -> ExchangeResult<T> {
+        self.limiter.acquire().await;
+        let creds = self.credentials.get()?;
+        let query = creds.signed_query(params, self.auth_timestamp())?;
+        debug!(endpoint, %method, ?params, "Korbit signed 요청");
+
+        let request = if method == Method::POST {
+            self.client
+                .post(format!("{}{}", self.base_url, endpoint))
+                .header("Content-Type", "application/x-www-form-urlencoded")
+                .body(query)
+        } else {
+            self.client
+                .request(method, format!("{}{}?{}", self.base_url, endpoint, query))
+        };
+
+        let response = request
+            .header(HEADER_API_KEY, creds.api_key())
+            .send()
+            .await
+            .map_err(ExchangeError::HttpError)?;
+
+        self.handle_response(response).await
+    }
+
+    /// API 응답을 처리하고 `data`를 꺼냅니다.
+    ///
+    /// `data`가 없는 성공 응답(취소 등)은 `null`로 역직렬화하므로 `()`나 `Option`으로 받습니다.
+    async fn handle_response<T: serde::de::DeserializeOwned>(
+        &self,
+        response: reqwest::Response,
+    ) -> ExchangeResult<T> {
+        let status = response.status();
+        let body = response.text().await.map_err(ExchangeError::HttpError)?;
+
+        if !status.is_success() {
+            warn!(status = status.as_u16(), body = %body, "Korbit API HTTP 에러");
+            return Err(parse_error(&body, status.as_u16()));
+        }
+
+        let response: KorbitResponse<serde_json::Value> =
+            serde_json::from_str(&body).map_err(ExchangeError::JsonError)?;
+        if !response.success {
+            warn!(body = %body, "Korbit API 에러 응답");
+            return Err(parse_error(&body, status.as_u16()));
+        }
+
+        serde_json::from_value(response.data.unwrap_or_default()).map_err(ExchangeError::JsonError)
+    }
+
+    /// 주문을 조회합니다.
+    ///
+    /// * `key` - `("orderId", ..)` 또는 `("clientOrderId", ..)`
+    async fn fetch_order(&self, symbol: &str, key: (&str, &str)) -> ExchangeResult<Order> {
+        let order: KorbitOrder = self
+            .send_signed(Method::GET, "/v2/orders", &[("symbol", symbol), key])
+            .await?;
+        self.remember_order(&order);
+        Ok(convert_order(order))
+    }
+}
+
+impl ServerTimeSync for KorbitClient {
+    fn server_clock(&self) -> &ServerClock {
+        &self.clock
+    }
+
+    async fn sync_server_clock(&self) -> ExchangeResult<ClockSample> {
+        // rate limiter 대기가 RTT에 섞이지 않도록 토큰 확보 후 송신 시각을 측정합니다.
+        self.limiter.acquire().await;
+        let url = format!("{}/v2/time", self.base_url);
+        let sent_ms = local_now_ms();
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(ExchangeError::HttpError)?;
+        let received_ms = local_now_ms();
+
+        let result: KorbitTime = self.handle_response(response).await?;
+        Ok(self.clock.record_probe(sent_ms, result.time, received_ms))
+    }
+}
+
+impl MarketData for KorbitClient {
+    fn name(&self) -> &str {
+        "Korbit"
+    }
+
+    async fn get_ticker(&self, markets: &[&str]) -> ExchangeResult<Vec<Ticker>> {
+        let symbols = markets
+            .iter()
+            .map(|m| Self::to_korbit_symbol(m))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let tickers: Vec<KorbitTicker> = self
+            .get_public("/v2/tickers", &[("symbol", &symbols)])
+            .await?;
+        Ok(tickers.into_iter().map(convert_ticker).collect())
+    }
+
+    async fn get_orderbook(&self, market: &str, depth: Option<u32>) -> ExchangeResult<OrderBook> {
+        let symbol = Self::to_korbit_symbol(market);
+        let orderbook: KorbitOrderbook = self
+            .get_public("/v2/orderbook", &[("symbol", &symbol)])
+            .await?;
+
+        let depth = depth.map(|d| d as usize).unwrap_or(usize::MAX);
+        Ok(convert_orderbook(orderbook, market, depth))
+    }
+
+    async fn get_candles(
+        &self,
+        market: &str,
+        interval: CandleInterval,
+        count: u32,
+    ) -> ExchangeResult<Vec<Candle>> {
+        let symbol = Self::to_korbit_symbol(market);
+        let limit = count.min(MAX_CANDLES).to_string();
+        let params = [
+            ("symbol", symbol.as_str()),
+            ("interval", interval_to_korbit(interval)),
+            ("limit", &limit),
+        ];
+
+        let candles: Vec<KorbitCandle> = self.get_public("/v2/candles", &params).await?;
+        Ok(convert_candles(candles, market))
+    }
+
+    async fn get_candles_before(
+        &self,
+        market: &str,
+        interval: CandleInterval,
+        count: u32,
+        before: DateTime<Utc>,
+    ) -> ExchangeResult<Vec<Candle>> {
+        let symbol = Self::to_korbit_symbol(market);
+        let limit = count.min(MAX_CANDLES).to_string();
+        let before_ms = before.timestamp_millis();
+        // end는 inclusive이므로 1ms 앞까지 조회
+        let end = (before_ms - 1).to_string();
+        let params = [
+            ("symbol", symbol.as_str()),
+            ("interval", interval_to_korbit(interval)),
+            ("limit", &limit),
+            ("end", &end),
+        ];
+
+        let candles: Vec<KorbitCandle> = self.get_public("/v2/candles", &params).await?;
+        Ok(convert_candles(
+            candles
+                .into_iter()
+                .filter(|c| c.timestamp < before_ms)
+                .collect(),
+            market,
+        ))
+    }
+
+    async fn get_all_tickers(&self) -> ExchangeResult<Vec<Ticker>> {
+        let tickers: Vec<KorbitTicker> = self.get_public("/v2/tickers", &[]).await?;
+        Ok(tickers.into_iter().map(convert_ticker).collect())
+    }
+
+    fn market_code(base: &str, quote: &str) -> String {
+        // Korbit 형식: "{base}_{quote}" (예: "btc_krw")
+        format!("{}_{}", base.to_lowercase(), quote.to_lowercase())
+    }
+}
+
+impl OrderManagement for KorbitClient {
+    async fn place_order(&self, request: &OrderRequest) -> ExchangeResult<Order> {
+        let symbol = Self::to_korbit_symbol(&request.market);
+        let side = match request.side {
+            OrderSide::Buy => "buy",
+            OrderSide::Sell => "sell",
+        };
+
+        let price;
+        let qty;
+        let amt;
+        let mut params: Vec<(&str, &str)> = vec![("symbol", &symbol), ("side", side)];
+
+        match request.order_type {
+            OrderType::Limit => {
+                price = request
+                    .price
+                    .ok_or_else(|| {
+                        ExchangeError::InvalidParameter("Limit order requires a price".to_string())
+                    })?
+                    .to_string();
+                qty = request
+                    .volume
+                    .ok_or_else(|| {
+                        ExchangeError::InvalidParameter("Limit order requires a volume".to_string())
+                    })?
+                    .to_string();
+                let time_in_force = match request.time_in_force {
+                    None | Some(TimeInForce::Gtc) => "gtc",
+                    Some(TimeInForce::Ioc) => "ioc",
+                    Some(TimeInForce::Fok) => "fok",
+                    Some(TimeInForce::PostOnly) => "po",
+                };
+                params.extend([
+                    ("orderType", "limit"),
+                    ("price", price.as_str()),
+                    ("qty", qty.as_str()),
+                    ("timeInForce", time_in_force),
+                ]);
+            }
+            OrderType::Market => {
+                // 시장가 매수는 총액 기준(OrderType::Price)만 가능
+                if request.side == OrderSide::Buy {
+                    return Err(ExchangeError::InvalidParameter(
+                        "Korbit market buy requires OrderType::Price with a total amount"
+                            .to_string(),
+                    ));
+                }
+                qty = request
+                    .volume
+                    .ok_or_else(|| {
+                        ExchangeError::InvalidParameter("Market sell requires a volume".to_string())
+                    })?
+                    .to_string();
+                params.extend([("orderType", "market"), ("qty", qty.as_str())]);
+            }
+            OrderType::Price => {
+                if request.side == OrderSide::Sell {
+                    return Err(ExchangeError::InvalidParameter(
+                        "OrderType::Price is only valid for buy orders".to_string(),
+                    ));
+                }
+                amt = request
+                    .price
+                    .ok_or_else(|| {
+                        ExchangeError::InvalidParameter(
+                            "Market buy requires a total amount".to_string(),
+                        )
+                    })?
+                    .to_string();
+                params.extend([("orderType", "market"), ("amt", amt.as_str())]);
+            }
+            OrderType::Best => {
+                return Err(ExchangeError::Unsupported(
+                    "Korbit best-price orders are not supported".to_string(),
+                ));
+            }
+        }
+        if let Some(ref identifier) = request.identifier {
+            params.push(("clientOrderId", identifier));
+        }
+
+        debug!(
+            market = %request.market,
+            side,
+            order_type = ?request.order_type,
+            volume = ?request.volume,
+            price = ?request.price,
+            time_in_force = ?request.time_in_force,
+            identifier = ?request.identifier,
+            "Korbit 주문 생성 요청"
+        );
+
+        let ack: KorbitOrderAck = self
+            .send_signed(Method::POST, "/v2/orders", &params)
+            .await?;
+        self.order_symbols.insert(&ack.order_id, &symbol);
+        if let Some(ref identifier) = request.identifier {
+            self.order_symbols.insert(identifier, &symbol);
+        }
+
+        info!(
+            order_id = %ack.order_id,
+            market = %request.market,
+            side,
+            "Korbit 주문 생성 완료"
+        );
+
+        // 접수 응답에는 주문 ID만 있으므로 조회로 전체 정보를 얻음
+        self.fetch_order(&symbol, ("orderId", &ack.order_id)).await
+    }
+
+    async fn cancel_order(&self, order_id: &str) -> ExchangeResult<Order> {
+        let symbol = self.resolve_symbol(order_id).await?;
+        debug!(order_id, symbol = %symbol, "Korbit 주문 취소 요청");
+
+        let _: serde_json::Value = self
+            .send_signed(
+                Method::DELETE,
+                "/v2/orders",
+                &[("symbol", &symbol), ("orderId", order_id)],
+            )
+            .await?;
+        info!(order_id, "Korbit 주문 취소 접수 완료");
+
+        self.fetch_order(&symbol, ("orderId", order_id)).await
+    }
+
+    async fn get_order(&self, order_id: &str) -> ExchangeResult<Order> {
+        let symbol = self.resolve_symbol(order_id).await?;
+        self.fetch_order(&symbol, ("orderId", order_id)).await
+    }
+
+    async fn get_order_by_client_id(&self, client_order_id: &str) -> ExchangeResult<Order> {
+        let symbol = self.resolve_symbol(client_order_id).await?;
+        self.fetch_order(&symbol, ("clientOrderId", client_order_id))
+            .await
+    }
+
+    /// 미체결 주문을 조회합니다.
+    ///
+    /// Korbit은 심볼 지정이 필수이므로, `market`이 없으면 이 클라이언트로 거래한
+    /// 심볼들만 조회합니다.
+    async fn get_open_orders(&self, market: Option<&str>) -> ExchangeResult<Vec<Order>> {
+        let symbols = match market {
+            Some(m) => vec![Self::to_korbit_symbol(m)],
+            None => self.order_symbols.distinct_values(),
+        };
+
+        let mut orders = Vec::new();
+        for symbol in symbols {
+            let open: Vec<KorbitOrder> = self
+                .send_signed(Method::GET, "/v2/openOrders", &[("symbol", &symbol)])
+                .await?;
+            for order in open {
+                self.remember_order(&order);
+                orders.push(convert_order(order));
+            }
+        }
+        Ok(orders)
+    }
+
+    async fn get_balances(&self) -> ExchangeResult<Vec<Balance>> {
+        let balances: Vec<KorbitBalance> =
+            self.send_signed(Method::GET, "/v2/balance", &[]).await?;
+        Ok(balances.into_iter().map(convert_balance).collect())
+    }
+
+    async fn get_balance(&self, currency: &str) -> ExchangeResult<Balance> {
+        let balances = self.get_balances().await?;
+        balances
+            .into_iter()
+            .find(|b| b.currency == currency)
+            .ok_or_else(|| {
+                ExchangeError::InvalidParameter(format!("Currency not found: {currency}"))
+            })
+    }
+}
+
+/// 에러 응답 본문을 ExchangeError로 변환합니다.
+fn parse_error(body: &str, status: u16) -> ExchangeError {
+    if let Ok(response) = serde_json::from_str::<KorbitResponse<serde_json::Value>>(body)
+        && let Some(error) = response.error
+    {
+        return convert_korbit_error(&error.code, &error.message);
+    }
+
+    match status {
+        429 => ExchangeError::RateLimitExceeded(body.to_string()),
+        _ => ExchangeError::UnknownError {
+            code: status.to_string(),
+            message: body.to_string(),
+        },
+    }
+}
+
+/// Korbit 에러 코드를 ExchangeError로 변환합니다.
+fn convert_korbit_error(code: &str, message: &str) -> ExchangeError {
+    match code {
+        // 인증 에러 (키 무효, 서명 오류, 권한 없음, 타임스탬프 범위 초과)
+        "INVALID_API_KEY" | "INVALID_SIGNATURE" | "UNAUTHORIZED" | "PERMISSION_DENIED"
+        | "INVALID_TIMESTAMP" | "EXPIRED_TIMESTAMP" => {
+            ExchangeError::AuthError(message.to_string())
+        }
+        // 잔고 부족
+        "NOT_ENOUGH_BALANCE" => ExchangeError::InsufficientFunds(message.to_string()),
+        // 주문을 찾을 수 없음
+        "ORDER_NOT_FOUND" => ExchangeError::OrderNotFound(message.to_string()),
+        // 존재하지 않는 심볼
+        "INVALID_SYMBOL" | "SYMBOL_NOT_FOUND" => ExchangeError::MarketNotFound(message.to_string()),
+        // 요청 제한 초과
+        "TOO_MANY_REQUESTS" => ExchangeError::RateLimitExceeded(message.to_string()),
+        // 잘못된 파라미터 (가격/수량 단위, 최소 주문 금액 등)
+        "BAD_REQUEST" | "INVALID_PARAMETER" | "INVALID_PRICE" | "INVALID_QTY" | "INVALID_AMT" => {
+            ExchangeError::InvalidParameter(message.to_string())
+        }
+        // 점검 중
+        "MAINTENANCE" => ExchangeError::ExchangeOffline(message.to_string()),
+        _ => ExchangeError::UnknownError {
+            code: code.to_string(),
+            message: message.to_string(),
+        },
+    }
+}
+
+/// CandleInterval을 Korbit `interval` 파라미터로 변환합니다.
+fn interval_to_korbit(interval: CandleInterval) -> &'static str {
+    match interval {
+        CandleInterval::Minute1 => "1",
+        CandleInterval::Minute3 => "5", // Korbit에는 3분 간격이 없어 5분 사용
+        CandleInterval::Minute5 => "5",
+        CandleInterval::Minute10 => "15", // Korbit에는 10분 간격이 없어 15분 사용
+        CandleInterval::Minute15 => "15",
+        CandleInterval::Minute30 => "30",
+        CandleInterval::Minute60 => "60",
+        CandleInterval::Minute240 => "240",
+        CandleInterval::Day => "1D",
+        CandleInterval::Week => "1W",
+        CandleInterval::Month => "1W", // 월봉이 없어 주봉 사용
+    }
+}
+
+/// 밀리초 타임스탬프를 DateTime으로 변환합니다 (범위 밖이면 현재 시각).
+fn millis_to_datetime(millis: i64) -> DateTime<Utc> {
+    Utc.timestamp_millis_opt(millis)
+        .single()
+        .unwrap_or_else(Utc::now)
+}
+
+// 변환 함수들
+
+fn convert_ticker(t: KorbitTicker) -> Ticker {
+    let change = if t.price_change > Decimal::ZERO {
+        PriceChange::Rise
+    } else if t.price_change < Decimal::ZERO {
+        PriceChange::Fall
+    } else {
+        PriceChange::Even
+    };
+
+    Ticker {
+        market: KorbitClient::to_market_code(&t.symbol),
+        trade_price: t.close,
+        opening_price: t.open,
+        high_price: t.high,
+        low_price: t.low,
+        prev_closing_price: t.prev_close,
+        change,
+        change_rate: (t.price_change_percent / Decimal::ONE_HUNDRED).abs(),
+        change_price: t.price_change.abs(),
+        acc_trade_volume_24h: t.volume,
+        acc_trade_price_24h: t.quote_volume,
+        timestamp: t
+            .last_traded_at
+            .map(millis_to_datetime)
+            .unwrap_or_else(Utc::now),
+    }
+}
+
+fn convert_orderbook(ob: KorbitOrderbook, market: &str, depth: usize) -> OrderBook {
+    let to_levels = |levels: Vec<crate::korbit::types::KorbitPriceLevel>| -> Vec<OrderBookLevel> {
+        levels
+            .into_iter()
+            .take(depth)
+            .map(|l| OrderBookLevel {
+                price: l.price,
+                size: l.qty,
+            })
+            .collect()
+    };
+    let asks = to_levels(ob.asks);
+    let bids = to_levels(ob.bids);
+
+    OrderBook {
+        market: market.to_string(),
+        total_ask_size: asks.iter().map(|l| l.size).sum(),
+        total_bid_size: bids.iter().map(|l| l.size).sum(),
+        asks,
+        bids,
+        timestamp: millis_to_datetime(ob.timestamp),
+    }
+}
+
+/// 캔들을 시간 오름차순으로 정렬해 변환합니다.
+fn convert_candles(mut candles: Vec<KorbitCandle>, market: &str) -> Vec<Candle> {
+    candles.sort_by_key(|c| c.timestamp);
+    candles
+        .into_iter()
+        .map(|c| Candle {
+            market: market.to_string(),
+            timestamp: millis_to_datetime(c.timestamp),
+            open: c.open,
+            high: c.high,
+            low: c.low,
+            close: c.close,
+            volume: c.volume,
+        })
+        .collect()
+}
+
+fn convert_order(o: KorbitOrder) -> Order {
+    let side = match o.side.as_str() {
+        "buy" => OrderSide::Buy,
+        _ => OrderSide::Sell,
+    };
+
+    let order_type = match (o.order_type.as_str(), side) {
+        ("market", OrderSide::Buy) => OrderType::Price,
+        ("market", OrderSide::Sell) => OrderType::Market,
+        ("best", _) => OrderType::Best,
+        _ => OrderType::Limit,
+    };
+
+    let status = match o.status.as_str() {
+        "partiallyFilled" => OrderStatus::PartiallyFilled,
+        "filled" => OrderStatus::Filled,
+        "canceled" | "partiallyFilledCanceled" | "expired" | "partiallyFilledExpired" => {
+            OrderStatus::Cancelled
+        }
+        "rejected" => OrderStatus::Rejected,
+        // "pending", "open"
+        _ => OrderStatus::Wait,
+    };
+
+    let executed_volume = o.filled_qty.unwrap_or_default();
+    // 시장가 매수는 수량 없이 총액으로 주문하므로 체결 수량을 전체 수량으로 간주
+    let volume = o.qty.unwrap_or(executed_volume);
+    let remaining_volume = match status {
+        OrderStatus::Wait | OrderStatus::PartiallyFilled => {
+            (volume - executed_volume).max(Decimal::ZERO)
+        }
+        _ => Decimal::ZERO,
+    };
+
+    Order {
+        id: o.order_id,
+        market: KorbitClient::to_market_code(&o.symbol),
+        side,
+        order_type,
+        status,
+        volume,
+        remaining_volume,
+        executed_volume,
+        price: o.price.or(o.amt),
+        avg_price: o.avg_price.filter(|p| !p.is_zero()),
+        paid_fee: o.fee.unwrap_or_default(),
+        created_at: millis_to_datetime(o.created_at),
+        identifier: o.client_order_id.filter(|id| !id.is_empty()),
+    }
+}
+
+fn convert_balance(b: KorbitBalance) -> Balance {
+    Balance {
+        currency: b.currency.to_uppercase(),
+        balance: b.available,
+        locked: b.trade_in_use.unwrap_or_default() + b.withdrawal_in_use.unwrap_or_default(),
+        avg_buy_price: b.avg_price.unwrap_or_default(),
+        unit_currency: "KRW".to_string(),
+        equity: None,
+        unrealised_pnl: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{MockClient, VenueSymbol, mock_client};
+    use mockito::Matcher;
+
+    impl MockClient for KorbitClient {
+        fn public() -> Self {
+            Self::new().unwrap()
+        }
+
+        fn with_mock_url(base_url: &str) -> Self {
+            let creds = KorbitCredentials::new("test-key", "test-secret");
+            Self::new_internal(Some(creds), base_url).unwrap()
+        }
+
+        fn has_credentials(&self) -> bool {
+            KorbitClient::has_credentials(self)
+        }
+
+        fn parse_error(body: &str, status: u16) -> ExchangeError {
+            parse_error(body, status)
+        }
+    }
+
+    impl VenueSymbol for KorbitClient {
+        fn to_venue_symbol(market: &str) -> String {
+            Self::to_korbit_symbol(market)
+        }
+
+        fn from_venue_symbol(symbol: &str) -> String {
+            Self::to_market_code(symbol)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_place_limit_ioc_order() {
+        let mut server = mockito::Server::new_async().await;
+        let place = server
+            .mock("POST", "/v2/orders")
+            .match_header(HEADER_API_KEY, "test-key")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::Regex("symbol=btc_krw&side=buy&orderType=limit".into()),
+                Matcher::Regex("timeInForce=ioc&clientOrderId=arb-1&timestamp=".into()),
+                Matcher::Regex("&signature=[0-9a-f]{64}$".into()),
+            ]))
+            .with_body(r#"{"success": true, "data": {"orderId": 1234567}}"#)
+            .create_async()
+            .await;
+        let detail = server
+            .mock("GET", "/v2/orders")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("symbol".into(), "btc_krw".into()),
+                Matcher::UrlEncoded("orderId".into(), "1234567".into()),
+            ]))
+            .with_body(
+                r#"{"success": true, "data": {
+                    "orderId": 1234567, "clientOrderId": "arb-1", "symbol": "btc_krw",
+                    "orderType": "limit", "side": "buy", "timeInForce": "ioc",
+                    "price": "71500000", "qty": "0.01", "filledQty": "0.004",
+                    "avgPrice": "71500000", "status": "partiallyFilledCanceled",
+                    "createdAt": 1707177600000}}"#,
+            )
+            .create_async()
+            .await;
+
+        let client: KorbitClient = mock_client(&server);
+        let request = OrderRequest {
+            time_in_force: Some(TimeInForce::Ioc),
+            identifier: Some("arb-1".to_string()),
+            ..OrderRequest::limit_buy("KRW-BTC", Decimal::from(71_500_000), Decimal::new(1, 2))
+        };
+        let order = client.place_order(&request).await.unwrap();
+
+        place.assert_async().await;
+        detail.assert_async().await;
+        assert_eq!(order.id, "1234567");
+        assert_eq!(order.market, "KRW-BTC");
+        assert_eq!(order.status, OrderStatus::Cancelled);
+        assert_eq!(order.executed_volume, Decimal::new(4, 3));
+        assert_eq!(order.remaining_volume, Decimal::ZERO);
+        assert_eq!(order.identifier.as_deref(), Some("arb-1"));
+        assert_eq!(client.resolve_symbol("arb-1").await.unwrap(), "btc_krw");
+    }
+
+    #[tokio::test]
+    async fn test_get_open_orders_without_market_uses_traded_symbols() {
+        let mut server = mockito::Server::new_async().await;
+        let open = server
+            .mock("GET", "/v2/openOrders")
+            .match_query(Matcher::UrlEncoded("symbol".into(), "btc_krw".into()))
+            .with_body(
+                r#"{"success": true, "data": [{
+                    "orderId": 1234567, "symbol": "btc_krw", "orderType": "limit",
+                    "side": "buy", "price": "71500000", "qty": "0.01", "filledQty": "0",
+                    "status": "open", "createdAt": 1707177600000}]}"#,
+            )
+            .create_async()
+            .await;
+
+        let client: KorbitClient = mock_client(&server);
+        // 거래한 심볼이 없으면 조회하지 않음
+        assert!(client.get_open_orders(None).await.unwrap().is_empty());
+
+        client.order_symbols.insert("1234567", "btc_krw");
+        let orders = client.get_open_orders(None).await.unwrap();
+        open.assert_async().await;
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0].status, OrderStatus::Wait);
+        assert_eq!(orders[0].remaining_volume, Decimal::new(1, 2));
+    }
+
+    #[tokio::test]
+    async fn test_error_envelope() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/v2/balance")
+            .match_query(Matcher::Any)
+            .with_status(400)
+            .with_body(
+                r#"{"success": false,
+                    "error": {"code": "INVALID_SIGNATURE", "message": "Invalid signature"}}"#,
+            )
+            .create_async()
+            .await;
+
+        let client: KorbitClient = mock_client(&server);
+        let result = client.get_balances().await;
+        assert!(matches!(result, Err(ExchangeError::AuthError(_))));
+    }
+}
@@ -0,0 +1,37 @@
+//! Korbit 거래소 SDK 구현.
+//!
+//! 이 모듈은 Korbit KRW 현물 마켓 클라이언트를 제공합니다.
+//!
+//! # 기능
+//!
+//! - 시장 데이터 API: 시세(Ticker), 호가창, 캔들
+//! - 주문 API: 주문 생성(IOC/FOK/post-only 포함), 취소, 조회, 잔고 (인증 필요)
+//! - HMAC-SHA256 쿼리 서명 인증 (`X-KAPI-KEY`)
+//! - trade 채널 WebSocket 실시간 체결
+//!
+//! # 예제
+//!
+//! ```no_run
+//! use arb_exchanges::KorbitClient;
+//! use arb_exchange::MarketData;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = KorbitClient::new()?;
+//!
+//!     // 시세 조회 (공통 마켓 형식 QUOTE-BASE 사용)
+//!     let tickers = client.get_ticker(&["KRW-BTC"]).await?;
+//!     println!("BTC Price: {}", tickers[0].trade_price);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod auth;
+mod client;
+mod stream;
+mod types;
+
+pub use auth::KorbitCredentials;
+pub use client::KorbitClient;
+pub use types::*;
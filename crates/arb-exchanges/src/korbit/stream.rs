@@ -0,0 +1,477 @@
+//! Korbit WebSocket 실시간 마켓 데이터 스트림 구현.
+//!
+//! `MarketStream` trait을 구현하여 Korbit의 체결(trade) 데이터를
+//! WebSocket으로 실시간 수신합니다.
+
+use arb_exchange::error::{ExchangeError, ExchangeResult};
+use arb_exchange::stream::{MarketEvent, MarketStream, StreamCommand, StreamConfig};
+use async_trait::async_trait;
+use chrono::{TimeZone, Utc};
+use futures_util::{SinkExt, StreamExt};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::sync::{Mutex, mpsc};
+use tokio_tungstenite::connect_async;
+use tokio_tungstenite::tungstenite::Message;
+use tracing::{debug, error, info, trace, warn};
+
+use crate::clock::ServerClock;
+use crate::korbit::client::KorbitClient;
+
+/// Korbit 공개 WebSocket URL.
+const KORBIT_WS_PUBLIC_URL: &str = "wss://ws-api.korbit.co.kr/v2/public";
+
+/// 구독 채널 이름.
+const TRADE_CHANNEL: &str = "trade";
+
+/// Korbit 채널 푸시 메시지.
+#[derive(Debug, Deserialize)]
+struct KorbitPush {
+    /// 채널 이름 ("trade" 등).
+    #[serde(rename = "type")]
+    channel: String,
+    /// 심볼 (예: "btc_krw").
+    symbol: String,
+    /// 체결 목록.
+    #[serde(default)]
+    data: Vec<KorbitWsTrade>,
+}
+
+/// Korbit 체결 데이터.
+#[derive(Debug, Deserialize)]
+struct KorbitWsTrade {
+    /// 체결 시각 (밀리초).
+    timestamp: i64,
+    /// 체결 가격.
+    price: String,
+    /// 체결 수량.
+    qty: String,
+}
+
+/// WebSocket task의 내부 상태.
+struct StreamState {
+    /// 구독 해제 시그널을 보내는 sender.
+    shutdown_tx: Option<tokio::sync::oneshot::Sender<()>>,
+    /// WebSocket task join handle.
+    task_handle: Option<tokio::task::JoinHandle<()>>,
+    /// 동적 구독 변경 명령을 보내는 sender.
+    command_tx: Option<mpsc::Sender<StreamCommand>>,
+}
+
+/// Korbit MarketStream 구현을 위한 내부 상태.
+pub(crate) struct KorbitStreamInner {
+    state: Mutex<Option<StreamState>>,
+    config: StreamConfig,
+}
+
+impl KorbitStreamInner {
+    pub(crate) fn new(config: StreamConfig) -> Self {
+        Self {
+            state: Mutex::new(None),
+            config,
+        }
+    }
+}
+
+#[async_trait]
+impl MarketStream for KorbitClient {
+    fn stream_name(&self) -> &str {
+        "Korbit"
+    }
+
+    async fn subscribe(&self, markets: &[&str]) -> ExchangeResult<mpsc::Receiver<MarketEvent>> {
+        let inner = self.stream_inner();
+
+        // 기존 구독이 있으면 먼저 해제
+        {
+            let mut state_guard = inner.state.lock().await;
+            if let Some(old_state) = state_guard.take() {
+                if let Some(tx) = old_state.shutdown_tx {
+                    let _ = tx.send(());
+                }
+                if let Some(handle) = old_state.task_handle {
+                    handle.abort();
+                }
+                debug!("기존 Korbit WebSocket 구독 해제");
+            }
+        }
+
+        let buffer_size = inner.config.channel_buffer_size;
+        let (event_tx, event_rx) = mpsc::channel(buffer_size);
+        let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel();
+        let (command_tx, command_rx) = mpsc::channel::<StreamCommand>(64);
+
+        let symbols: Vec<String> = markets
+            .iter()
+            .map(|m| KorbitClient::to_korbit_symbol(m))
+            .collect();
+        let config = inner.config.clone();
+        let clock = Arc::clone(&self.clock);
+
+        info!(symbols = ?symbols, "Korbit WebSocket 구독 시작");
+
+        let task_handle = tokio::spawn(async move {
+            korbit_ws_loop(symbols, event_tx, shutdown_rx, command_rx, config, clock).await;
+        });
+
+        let mut state_guard = inner.state.lock().await;
+        *state_guard = Some(StreamState {
+            shutdown_tx: Some(shutdown_tx),
+            task_handle: Some(task_handle),
+            command_tx: Some(command_tx),
+        });
+
+        Ok(event_rx)
+    }
+
+    async fn unsubscribe(&self) -> ExchangeResult<()> {
+        let inner = self.stream_inner();
+        let mut state_guard = inner.state.lock().await;
+
+        if let Some(state) = state_guard.take() {
+            info!("Korbit WebSocket 구독 해제");
+            if let Some(tx) = state.shutdown_tx {
+                let _ = tx.send(());
+            }
+            if let Some(handle) = state.task_handle {
+                handle.abort();
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn subscribe_markets(&self, markets: &[&str]) -> ExchangeResult<()> {
+        self.send_stream_command(StreamCommand::Subscribe(
+            markets.iter().map(|m| m.to_string()).collect(),
+        ))
+        .await
+    }
+
+    async fn unsubscribe_markets(&self, markets: &[&str]) -> ExchangeResult<()> {
+        self.send_stream_command(StreamCommand::Unsubscribe(
+            markets.iter().map(|m| m.to_string()).collect(),
+        ))
+        .await
+    }
+}
+
+impl KorbitClient {
+    /// 실행 중인 WebSocket 루프에 동적 구독 명령을 전달합니다.
+    async fn send_stream_command(&self, command: StreamCommand) -> ExchangeResult<()> {
+        let state_guard = self.stream_inner().state.lock().await;
+        let tx = state_guard
+            .as_ref()
+            .and_then(|state| state.command_tx.as_ref())
+            .ok_or_else(|| ExchangeError::WebSocketError("not subscribed".into()))?;
+        tx.send(command)
+            .await
+            .map_err(|_| ExchangeError::WebSocketError("command channel closed".into()))
+    }
+}
+
+/// 신규 구독 대상 심볼만 추출하고 현재 목록에 반영합니다.
+fn build_subscribe_symbols(markets: &[String], current: &mut Vec<String>) -> Vec<String> {
+    let mut subscribe = Vec::new();
+    for market in markets {
+        let symbol = KorbitClient::to_korbit_symbol(market);
+        if !current.contains(&symbol) {
+            current.push(symbol.clone());
+            subscribe.push(symbol);
+        }
+    }
+    subscribe
+}
+
+/// 현재 구독 중인 심볼만 해제 대상으로 추출하고 현재 목록에서 제거합니다.
+fn build_unsubscribe_symbols(markets: &[String], current: &mut Vec<String>) -> Vec<String> {
+    let remove: Vec<String> = markets
+        .iter()
+        .map(|m| KorbitClient::to_korbit_symbol(m))
+        .filter(|symbol| current.contains(symbol))
+        .collect();
+
+    if !remove.is_empty() {
+        current.retain(|symbol| !remove.contains(symbol));
+    }
+
+    remove
+}
+
+/// subscribe/unsubscribe 요청 메시지를 만듭니다.
+fn build_request(method: &str, symbols: &[String]) -> String {
+    serde_json::json!([{ "method": method, "type": TRADE_CHANNEL, "symbols": symbols }]).to_string()
+}
+
+/// Korbit WebSocket 이벤트 루프 (재연결 + 동적 구독 포함).
+async fn korbit_ws_loop(
+    initial_symbols: Vec<String>,
+    event_tx: mpsc::Sender<MarketEvent>,
+    mut shutdown_rx: tokio::sync::oneshot::Receiver<()>,
+    mut command_rx: mpsc::Receiver<StreamCommand>,
+    config: StreamConfig,
+    clock: Arc<ServerClock>,
+) {
+    let mut retry_count: u32 = 0;
+    let mut backoff = config.initial_backoff;
+    // 현재 구독 중인 심볼 목록 (재연결 시 사용)
+    let mut current_symbols = initial_symbols;
+
+    loop {
+        // 종료 확인
+        if shutdown_rx.try_recv().is_ok() {
+            info!("Korbit WebSocket 종료 요청");
+            break;
+        }
+
+        match connect_and_subscribe(&current_symbols).await {
+            Ok(ws_stream) => {
+                info!("Korbit WebSocket 연결 성공");
+                retry_count = 0;
+                backoff = config.initial_backoff;
+
+                let (mut write, mut read) = ws_stream.split();
+
+                loop {
+                    tokio::select! {
+                        _ = &mut shutdown_rx => {
+                            info!("Korbit WebSocket 종료 요청 (루프 내)");
+                            let _ = write.close().await;
+                            return;
+                        }
+                        cmd = command_rx.recv() => {
+                            let (method, symbols) = match cmd {
+                                Some(StreamCommand::Subscribe(markets)) => {
+                                    ("subscribe", build_subscribe_symbols(&markets, &mut current_symbols))
+                                }
+                                Some(StreamCommand::Unsubscribe(markets)) => {
+                                    ("unsubscribe", build_unsubscribe_symbols(&markets, &mut current_symbols))
+                                }
+                                None => {
+                                    debug!("Korbit command 채널 닫힘");
+                                    continue;
+                                }
+                            };
+
+                            if symbols.is_empty() {
+                                debug!(method, "Korbit 동적 구독 변경 스킵: 변경 대상 없음");
+                                continue;
+                            }
+
+                            info!(method, symbols = ?symbols, "Korbit 동적 구독 변경");
+                            let msg = build_request(method, &symbols);
+                            if let Err(e) = write.send(Message::Text(msg.into())).await {
+                                error!(error = %e, method, "Korbit 구독 메시지 전송 실패");
+                                break;
+                            }
+                        }
+                        msg = read.next() => {
+                            match msg {
+                                Some(Ok(Message::Text(text))) => {
+                                    for event in parse_korbit_trades(&text) {
+                                        clock.observe_feed_latency(event.timestamp().timestamp_millis());
+                                        match event_tx.try_send(event) {
+                                            Ok(()) => {
+                                                trace!("Korbit 이벤트 전송 성공");
+                                            }
+                                            Err(mpsc::error::TrySendError::Full(_)) => {
+                                                warn!("Korbit 이벤트 채널 가득 참 — 이벤트 드롭");
+                                            }
+                                            Err(mpsc::error::TrySendError::Closed(_)) => {
+                                                debug!("Korbit 이벤트 채널 닫힘 — 종료");
+                                                return;
+                                            }
+                                        }
+                                    }
+                                }
+                                Some(Ok(Message::Ping(data))) => {
+                                    let _ = write.send(Message::Pong(data)).await;
+                                }
+                                Some(Ok(Message::Close(_))) => {
+                                    warn!("Korbit WebSocket 서버에서 Close 수신");
+                                    break;
+                                }
+                                Some(Err(e)) => {
+                                    error!(error = %e, "Korbit WebSocket 수신 에러");
+                                    break;
+                                }
+                                None => {
+                                    warn!("Korbit WebSocket 스트림 종료");
+                                    break;
+                                }
+                                _ => {}
+                            }
+                        }
+                    }
+                }
+            }
+            Err(e) => {
+                error!(error = %e, "Korbit WebSocket 연결 실패");
+            }
+        }
+
+        // 재연결 로직
+        retry_count += 1;
+        arb_metrics::global()
+            .counter(
+                "arb_ws_reconnects_total",
+                "WebSocket 재연결 시도 횟수",
+                &[("exchange", "korbit")],
+            )
+            .inc();
+        if config.max_retries > 0 && retry_count > config.max_retries {
+            error!(
+                retries = retry_count,
+                "Korbit WebSocket 최대 재시도 초과 — 스트림 종료"
+            );
+            break;
+        }
+
+        warn!(
+            retry = retry_count,
+            backoff_ms = backoff.as_millis(),
+            "Korbit WebSocket 재연결 대기"
+        );
+
+        tokio::select! {
+            _ = &mut shutdown_rx => {
+                info!("Korbit WebSocket 재연결 대기 중 종료 요청");
+                return;
+            }
+            _ = tokio::time::sleep(backoff) => {}
+        }
+
+        // exponential backoff (최대값 제한)
+        backoff = std::cmp::min(backoff * 2, config.max_backoff);
+    }
+}
+
+/// Korbit WebSocket에 연결하고 구독 메시지를 보냅니다.
+async fn connect_and_subscribe(
+    symbols: &[String],
+) -> Result<
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>,
+    Box<dyn std::error::Error + Send + Sync>,
+> {
+    let (mut ws_stream, response) = connect_async(KORBIT_WS_PUBLIC_URL).await?;
+
+    debug!(status = ?response.status(), "Korbit WebSocket 핸드셰이크 완료");
+
+    if !symbols.is_empty() {
+        // 구독 메시지: [{"method": "subscribe", "type": "trade", "symbols": ["btc_krw"]}]
+        let subscribe_msg = build_request("subscribe", symbols);
+        debug!(msg = %subscribe_msg, "Korbit 구독 메시지 전송");
+        ws_stream.send(Message::Text(subscribe_msg.into())).await?;
+    }
+
+    Ok(ws_stream)
+}
+
+/// Korbit WebSocket 메시지를 MarketEvent::Trade 목록으로 파싱합니다.
+///
+/// 구독 응답 등 체결 외 메시지는 빈 목록을 반환합니다.
+/// 이벤트의 market은 내부 형식("KRW-BTC")입니다.
+fn parse_korbit_trades(text: &str) -> Vec<MarketEvent> {
+    let Ok(push) = serde_json::from_str::<KorbitPush>(text) else {
+        return Vec::new();
+    };
+    if push.channel != TRADE_CHANNEL {
+        return Vec::new();
+    }
+
+    let market = KorbitClient::to_market_code(&push.symbol);
+    push.data
+        .iter()
+        .filter_map(|trade| {
+            let price = Decimal::from_str(&trade.price).ok()?;
+            let volume = Decimal::from_str(&trade.qty).ok()?;
+            let timestamp = Utc
+                .timestamp_millis_opt(trade.timestamp)
+                .single()
+                .unwrap_or_else(Utc::now);
+            Some(MarketEvent::Trade {
+                market: market.clone(),
+                price,
+                volume,
+                timestamp,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_korbit_trades() {
+        let json = r#"{
+            "type": "trade",
+            "timestamp": 1707177600100,
+            "symbol": "btc_krw",
+            "snapshot": false,
+            "data": [
+                {"timestamp": 1707177600000, "price": "71500000", "qty": "0.0012",
+                 "isBuyerTaker": true, "tradeId": 1},
+                {"timestamp": 1707177600050, "price": "71510000", "qty": "0.5",
+                 "isBuyerTaker": false, "tradeId": 2}
+            ]
+        }"#;
+
+        let events = parse_korbit_trades(json);
+        assert_eq!(events.len(), 2);
+        if let MarketEvent::Trade {
+            market,
+            price,
+            volume,
+            timestamp,
+        } = &events[0]
+        {
+            assert_eq!(market, "KRW-BTC");
+            assert_eq!(*price, Decimal::from(71_500_000));
+            assert_eq!(*volume, Decimal::new(12, 4));
+            assert_eq!(timestamp.timestamp_millis(), 1707177600000);
+        } else {
+            panic!("Expected Trade event");
+        }
+    }
+
+    #[test]
+    fn test_parse_korbit_non_trade_ignored() {
+        let ticker = r#"{"type": "ticker", "symbol": "btc_krw", "data": []}"#;
+        assert!(parse_korbit_trades(ticker).is_empty());
+        let ack = r#"[{"method": "subscribe", "type": "trade", "symbols": ["btc_krw"]}]"#;
+        assert!(parse_korbit_trades(ack).is_empty());
+    }
+
+    #[test]
+    fn test_build_subscribe_symbols_dedup() {
+        let mut current = vec!["btc_krw".to_string()];
+        let markets = vec!["KRW-BTC".to_string(), "KRW-ETH".to_string()];
+
+        let subscribe = build_subscribe_symbols(&markets, &mut current);
+        assert_eq!(subscribe, vec!["eth_krw".to_string()]);
+        assert_eq!(current.len(), 2);
+    }
+
+    #[test]
+    fn test_build_unsubscribe_symbols_only_existing() {
+        let mut current = vec!["btc_krw".to_string(), "eth_krw".to_string()];
+        let markets = vec!["KRW-BTC".to_string(), "KRW-XRP".to_string()];
+
+        let remove = build_unsubscribe_symbols(&markets, &mut current);
+        assert_eq!(remove, vec!["btc_krw".to_string()]);
+        assert_eq!(current, vec!["eth_krw".to_string()]);
+    }
+
+    #[test]
+    fn test_build_request() {
+        let msg = build_request("subscribe", &["btc_krw".to_string()]);
+        let value: serde_json::Value = serde_json::from_str(&msg).unwrap();
+        assert_eq!(value[0]["method"], "subscribe");
+        assert_eq!(value[0]["type"], "trade");
+        assert_eq!(value[0]["symbols"][0], "btc_krw");
+    }
+}
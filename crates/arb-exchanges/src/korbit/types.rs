@@ -0,0 +1,284 @@
+//! Korbit 전용 타입 및 API 응답 구조체.
+//!
+//! Korbit API v2는 모든 응답을 `{"success": bool, "data": ..., "error": {...}}`로 감싸고,
+//! 가격/수량은 문자열, 시각은 밀리초 숫자로 반환합니다.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer};
+
+/// Korbit API 응답 래퍼.
+///
+/// ```json
+/// {"success": false, "error": {"code": "NOT_ENOUGH_BALANCE", "message": "..."}}
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct KorbitResponse<T> {
+    /// 성공 여부.
+    pub success: bool,
+    /// 응답 데이터 (실패 시 없음).
+    pub data: Option<T>,
+    /// 에러 정보 (성공 시 없음).
+    #[serde(default)]
+    pub error: Option<KorbitErrorBody>,
+}
+
+/// Korbit 에러 정보.
+#[derive(Debug, Deserialize)]
+pub struct KorbitErrorBody {
+    /// 에러 코드 (예: "NOT_ENOUGH_BALANCE").
+    #[serde(default)]
+    pub code: String,
+    /// 에러 메시지.
+    #[serde(default)]
+    pub message: String,
+}
+
+/// 서버 시각 (`/v2/time`).
+#[derive(Debug, Deserialize)]
+pub struct KorbitTime {
+    /// 서버 시각 (밀리초).
+    pub time: i64,
+}
+
+/// Korbit 티커 (`/v2/tickers`).
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KorbitTicker {
+    /// 심볼 (예: "btc_krw").
+    pub symbol: String,
+    /// 24시간 시가.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub open: Decimal,
+    /// 24시간 고가.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub high: Decimal,
+    /// 24시간 저가.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub low: Decimal,
+    /// 최근 체결가.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub close: Decimal,
+    /// 전일 종가.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub prev_close: Decimal,
+    /// 전일 대비 변동가 (부호 포함).
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub price_change: Decimal,
+    /// 전일 대비 변동률 (%, 부호 포함).
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub price_change_percent: Decimal,
+    /// 24시간 거래량.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub volume: Decimal,
+    /// 24시간 거래대금.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub quote_volume: Decimal,
+    /// 마지막 체결 시각 (밀리초).
+    #[serde(default)]
+    pub last_traded_at: Option<i64>,
+}
+
+/// 호가 레벨 (`{"price": "...", "qty": "..."}`).
+#[derive(Debug, Deserialize)]
+pub struct KorbitPriceLevel {
+    /// 가격.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub price: Decimal,
+    /// 수량.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub qty: Decimal,
+}
+
+/// 오더북 (`/v2/orderbook`).
+#[derive(Debug, Deserialize)]
+pub struct KorbitOrderbook {
+    /// 오더북 시각 (밀리초).
+    pub timestamp: i64,
+    /// 매도 호가 (가격 오름차순).
+    #[serde(default)]
+    pub asks: Vec<KorbitPriceLevel>,
+    /// 매수 호가 (가격 내림차순).
+    #[serde(default)]
+    pub bids: Vec<KorbitPriceLevel>,
+}
+
+/// 캔들 (`/v2/candles`).
+#[derive(Debug, Deserialize)]
+pub struct KorbitCandle {
+    /// 캔들 시작 시각 (밀리초).
+    pub timestamp: i64,
+    /// 시가.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub open: Decimal,
+    /// 고가.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub high: Decimal,
+    /// 저가.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub low: Decimal,
+    /// 종가.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub close: Decimal,
+    /// 거래량.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub volume: Decimal,
+}
+
+/// 주문 접수 응답 (`POST /v2/orders`).
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KorbitOrderAck {
+    /// 주문 ID.
+    #[serde(deserialize_with = "deserialize_id")]
+    pub order_id: String,
+}
+
+/// Korbit 주문 (`GET /v2/orders`, `GET /v2/openOrders`).
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KorbitOrder {
+    /// 주문 ID.
+    #[serde(deserialize_with = "deserialize_id")]
+    pub order_id: String,
+    /// 사용자 지정 주문 ID.
+    #[serde(default)]
+    pub client_order_id: Option<String>,
+    /// 심볼 (예: "btc_krw").
+    pub symbol: String,
+    /// 주문 유형 ("limit", "market", "best").
+    pub order_type: String,
+    /// 주문 방향 ("buy", "sell").
+    pub side: String,
+    /// 주문 상태 ("open", "partiallyFilled", "filled", "canceled" 등).
+    pub status: String,
+    /// 주문 가격 (시장가 주문은 없음).
+    #[serde(default, deserialize_with = "deserialize_optional_decimal_string")]
+    pub price: Option<Decimal>,
+    /// 주문 수량 (시장가 매수는 없음).
+    #[serde(default, deserialize_with = "deserialize_optional_decimal_string")]
+    pub qty: Option<Decimal>,
+    /// 주문 총액 (시장가 매수).
+    #[serde(default, deserialize_with = "deserialize_optional_decimal_string")]
+    pub amt: Option<Decimal>,
+    /// 체결 수량.
+    #[serde(default, deserialize_with = "deserialize_optional_decimal_string")]
+    pub filled_qty: Option<Decimal>,
+    /// 평균 체결가.
+    #[serde(default, deserialize_with = "deserialize_optional_decimal_string")]
+    pub avg_price: Option<Decimal>,
+    /// 수수료.
+    #[serde(default, deserialize_with = "deserialize_optional_decimal_string")]
+    pub fee: Option<Decimal>,
+    /// 주문 시각 (밀리초).
+    pub created_at: i64,
+}
+
+/// Korbit 통화별 잔고 (`/v2/balance`).
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KorbitBalance {
+    /// 통화 코드 (소문자, 예: "krw", "btc").
+    pub currency: String,
+    /// 주문 가능 수량.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub available: Decimal,
+    /// 주문에 묶인 수량.
+    #[serde(default, deserialize_with = "deserialize_optional_decimal_string")]
+    pub trade_in_use: Option<Decimal>,
+    /// 출금에 묶인 수량.
+    #[serde(default, deserialize_with = "deserialize_optional_decimal_string")]
+    pub withdrawal_in_use: Option<Decimal>,
+    /// 평균 매수가.
+    #[serde(default, deserialize_with = "deserialize_optional_decimal_string")]
+    pub avg_price: Option<Decimal>,
+}
+
+/// 문자열에서 Decimal로 역직렬화 (빈 문자열은 0).
+fn deserialize_decimal_string<'de, D>(deserializer: D) -> Result<Decimal, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    if s.is_empty() {
+        return Ok(Decimal::ZERO);
+    }
+    s.parse::<Decimal>().map_err(serde::de::Error::custom)
+}
+
+/// 문자열에서 Option<Decimal>로 역직렬화 (null과 빈 문자열은 None).
+fn deserialize_optional_decimal_string<'de, D>(deserializer: D) -> Result<Option<Decimal>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let opt: Option<String> = Option::deserialize(deserializer)?;
+    match opt {
+        Some(s) if !s.is_empty() => s
+            .parse::<Decimal>()
+            .map(Some)
+            .map_err(serde::de::Error::custom),
+        _ => Ok(None),
+    }
+}
+
+/// 숫자 또는 문자열 주문 ID를 문자열로 역직렬화.
+fn deserialize_id<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::String(s) => Ok(s),
+        serde_json::Value::Number(n) => Ok(n.to_string()),
+        other => Err(serde::de::Error::custom(format!(
+            "invalid order id: {other}"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_korbit_ticker() {
+        let json = r#"{"success": true, "data": [{
+            "symbol": "btc_krw", "open": "70500000", "high": "72000000",
+            "low": "70000000", "close": "71500000", "prevClose": "70400000",
+            "priceChange": "1100000", "priceChangePercent": "1.56",
+            "volume": "17.5", "quoteVolume": "1234567890",
+            "bestBidPrice": "71490000", "bestAskPrice": "71510000",
+            "lastTradedAt": 1707177600000}]}"#;
+
+        let response: KorbitResponse<Vec<KorbitTicker>> = serde_json::from_str(json).unwrap();
+        assert!(response.success);
+        let ticker = &response.data.unwrap()[0];
+        assert_eq!(ticker.symbol, "btc_krw");
+        assert_eq!(ticker.close, Decimal::from(71_500_000));
+        assert_eq!(ticker.price_change_percent, Decimal::new(156, 2));
+    }
+
+    #[test]
+    fn test_deserialize_korbit_order_numeric_id() {
+        let json = r#"{"success": true, "data": {
+            "orderId": 1234567, "clientOrderId": "arb-1", "symbol": "btc_krw",
+            "orderType": "limit", "side": "buy", "timeInForce": "ioc",
+            "price": "71500000", "qty": "0.01", "filledQty": "0.004",
+            "filledAmt": "286000", "avgPrice": "71500000",
+            "status": "partiallyFilledCanceled", "createdAt": 1707177600000}}"#;
+
+        let response: KorbitResponse<KorbitOrder> = serde_json::from_str(json).unwrap();
+        let order = response.data.unwrap();
+        assert_eq!(order.order_id, "1234567");
+        assert_eq!(order.filled_qty, Some(Decimal::new(4, 3)));
+        assert!(order.amt.is_none());
+    }
+
+    #[test]
+    fn test_deserialize_korbit_error() {
+        let json = r#"{"success": false,
+            "error": {"code": "NOT_ENOUGH_BALANCE", "message": "Not enough balance"}}"#;
+        let response: KorbitResponse<serde_json::Value> = serde_json::from_str(json).unwrap();
+        assert!(!response.success);
+        assert!(response.data.is_none());
+        assert_eq!(response.error.unwrap().code, "NOT_ENOUGH_BALANCE");
+    }
+}
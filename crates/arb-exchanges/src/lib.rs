@@ -7,6 +7,10 @@
 //! - [upbit] - Upbit (한국 거래소)
 //! - [bithumb] - Bithumb (한국 거래소)
 //! - [bybit] - Bybit V5 (글로벌 거래소)
+//! - [binance] - Binance USDⓈ-M 선물 (글로벌 거래소, 대체 헤지)
+//! - [okx] - OKX 무기한 스왑 (글로벌 거래소, 대체 헤지)
+//! - [coinone] - Coinone (한국 거래소)
+//! - [korbit] - Korbit (한국 거래소)
 //!
 //! # 예제
 //!
@@ -35,17 +39,31 @@
 //! }
 //! ```
 
+pub mod binance;
 pub mod bithumb;
 pub mod bybit;
+pub mod clock;
+pub mod coinone;
+mod credentials;
 pub mod factory;
+pub mod korbit;
+pub mod okx;
+mod order_cache;
 pub mod rate_limit;
+#[cfg(test)]
+mod test_util;
 pub mod upbit;
 
 pub use arb_exchange::ExchangeName;
+pub use binance::BinanceClient;
 pub use bithumb::BithumbClient;
 pub use bybit::BybitClient;
+pub use clock::{ClockSample, ServerClock, ServerTimeSync};
+pub use coinone::CoinoneClient;
 pub use factory::{
-    BithumbAdapter, BybitAdapter, ExchangeManagerExt, UpbitAdapter, create_exchange,
-    create_exchange_boxed,
+    BinanceAdapter, BithumbAdapter, BybitAdapter, CoinoneAdapter, ExchangeManagerExt,
+    KorbitAdapter, OkxAdapter, UpbitAdapter, create_exchange, create_exchange_boxed,
 };
+pub use korbit::KorbitClient;
+pub use okx::OkxClient;
 pub use upbit::UpbitClient;
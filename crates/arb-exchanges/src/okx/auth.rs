@@ -0,0 +1,176 @@
+//! OKX HMAC-SHA256 인증 모듈.
+//!
+//! OKX V5 API는 `timestamp + METHOD + requestPath(+쿼리) + body` 문자열을
+//! HMAC-SHA256으로 서명한 뒤 Base64로 인코딩하여 `OK-ACCESS-SIGN` 헤더로 전달합니다.
+//! 타임스탬프는 밀리초 정밀도의 ISO 8601 UTC 문자열입니다.
+
+use arb_config::SecretString;
+use arb_exchange::ExchangeError;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use chrono::{TimeZone, Utc};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// API 키 헤더 이름.
+pub const HEADER_ACCESS_KEY: &str = "OK-ACCESS-KEY";
+/// 서명 헤더 이름.
+pub const HEADER_ACCESS_SIGN: &str = "OK-ACCESS-SIGN";
+/// 타임스탬프 헤더 이름.
+pub const HEADER_ACCESS_TIMESTAMP: &str = "OK-ACCESS-TIMESTAMP";
+/// 패스프레이즈 헤더 이름.
+pub const HEADER_ACCESS_PASSPHRASE: &str = "OK-ACCESS-PASSPHRASE";
+/// 데모 트레이딩 헤더 이름 (값 "1").
+pub const HEADER_SIMULATED_TRADING: &str = "x-simulated-trading";
+
+/// OKX API 인증을 위한 자격 증명.
+#[derive(Clone)]
+pub struct OkxCredentials {
+    api_key: String,
+    secret_key: SecretString,
+    passphrase: SecretString,
+}
+
+impl std::fmt::Debug for OkxCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let masked_api_key = if self.api_key.len() >= 4 {
+            format!("{}****", &self.api_key[..4])
+        } else {
+            "****".to_string()
+        };
+        f.debug_struct("OkxCredentials")
+            .field("api_key", &masked_api_key)
+            .field("secret_key", &"****")
+            .field("passphrase", &"****")
+            .finish()
+    }
+}
+
+impl OkxCredentials {
+    /// 새 자격 증명을 생성합니다.
+    ///
+    /// # 인자
+    ///
+    /// * `api_key` - OKX API 키
+    /// * `secret_key` - OKX API 시크릿 키
+    /// * `passphrase` - API 키 생성 시 지정한 패스프레이즈
+    pub fn new(
+        api_key: impl Into<String>,
+        secret_key: impl Into<String>,
+        passphrase: impl Into<String>,
+    ) -> Self {
+        Self {
+            api_key: api_key.into(),
+            secret_key: SecretString::new(secret_key),
+            passphrase: SecretString::new(passphrase),
+        }
+    }
+
+    /// API 키를 반환합니다.
+    #[inline]
+    pub fn api_key(&self) -> &str {
+        &self.api_key
+    }
+
+    /// 패스프레이즈를 반환합니다.
+    #[inline]
+    pub fn passphrase(&self) -> &str {
+        self.passphrase.expose_secret()
+    }
+
+    /// 요청을 서명합니다.
+    ///
+    /// # 인자
+    ///
+    /// * `timestamp` - [`format_timestamp`]로 만든 ISO 8601 타임스탬프
+    /// * `method` - HTTP 메서드 (대문자)
+    /// * `request_path` - 쿼리 문자열을 포함한 요청 경로 (예: "/api/v5/trade/order?instId=...")
+    /// * `body` - 요청 본문 (GET은 빈 문자열)
+    ///
+    /// # 반환값
+    ///
+    /// Base64 인코딩된 HMAC-SHA256 서명
+    pub fn sign(
+        &self,
+        timestamp: &str,
+        method: &str,
+        request_path: &str,
+        body: &str,
+    ) -> Result<String, ExchangeError> {
+        let mut mac = HmacSha256::new_from_slice(self.secret_key.expose_secret().as_bytes())
+            .map_err(|e| ExchangeError::AuthError(format!("Invalid secret key: {e}")))?;
+        mac.update(timestamp.as_bytes());
+        mac.update(method.as_bytes());
+        mac.update(request_path.as_bytes());
+        mac.update(body.as_bytes());
+        Ok(BASE64.encode(mac.finalize().into_bytes()))
+    }
+}
+
+/// 밀리초 타임스탬프를 OKX 서명용 ISO 8601 문자열로 변환합니다.
+///
+/// 예: 1597026383085 → "2020-08-10T02:26:23.085Z"
+pub fn format_timestamp(timestamp_ms: i64) -> String {
+    Utc.timestamp_millis_opt(timestamp_ms)
+        .single()
+        .unwrap_or_else(Utc::now)
+        .format("%Y-%m-%dT%H:%M:%S%.3fZ")
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_timestamp_millis() {
+        assert_eq!(format_timestamp(1597026383085), "2020-08-10T02:26:23.085Z");
+        assert_eq!(format_timestamp(1597026383000), "2020-08-10T02:26:23.000Z");
+    }
+
+    #[test]
+    fn test_sign_is_base64_hmac_of_prehash() {
+        let creds = OkxCredentials::new("key", "secret", "pass");
+        let signature = creds
+            .sign(
+                "2020-12-08T09:08:57.715Z",
+                "GET",
+                "/api/v5/account/balance?ccy=BTC",
+                "",
+            )
+            .unwrap();
+
+        let mut mac = HmacSha256::new_from_slice(b"secret").unwrap();
+        mac.update(b"2020-12-08T09:08:57.715ZGET/api/v5/account/balance?ccy=BTC");
+        assert_eq!(signature, BASE64.encode(mac.finalize().into_bytes()));
+        // SHA-256 다이제스트(32바이트)의 Base64 길이
+        assert_eq!(signature.len(), 44);
+    }
+
+    #[test]
+    fn test_sign_includes_body() {
+        let creds = OkxCredentials::new("key", "secret", "pass");
+        let ts = "2020-12-08T09:08:57.715Z";
+        let without_body = creds.sign(ts, "POST", "/api/v5/trade/order", "").unwrap();
+        let with_body = creds
+            .sign(
+                ts,
+                "POST",
+                "/api/v5/trade/order",
+                r#"{"instId":"BTC-USDT-SWAP"}"#,
+            )
+            .unwrap();
+        assert_ne!(without_body, with_body);
+    }
+
+    #[test]
+    fn test_credentials_debug_masks_secrets() {
+        let creds = OkxCredentials::new("abcdefgh", "super-secret", "my-passphrase");
+        let debug = format!("{creds:?}");
+        assert!(debug.contains("abcd****"));
+        assert!(!debug.contains("super-secret"));
+        assert!(!debug.contains("my-passphrase"));
+    }
+}
@@ -0,0 +1,1485 @@
+//! OKX V5 무기한 스왑 REST API 클라이언트 구현.
+//!
+//! 이 모듈은 OKX `/api/v5` API와 상호작용하기 위한 메인 클라이언트를 제공합니다.
+//!
+//! OKX 스왑은 계약 수 단위로 주문하지만, 공통 trait(`OrderRequest.volume`, `Order`,
+//! `PositionInfo`, 호가 수량)은 다른 거래소와 같이 코인 단위로 주고받습니다.
+//! 변환에는 상품별 계약 승수(`ctVal`)를 사용하며, 상품 정보는 처음 사용할 때 조회해 캐시합니다.
+
+use crate::bybit::LinearTickerInfo;
+use crate::clock::{ClockSample, ServerClock, ServerTimeSync, local_now_ms};
+use crate::credentials::CredentialSlot;
+use crate::okx::auth::{
+    HEADER_ACCESS_KEY, HEADER_ACCESS_PASSPHRASE, HEADER_ACCESS_SIGN, HEADER_ACCESS_TIMESTAMP,
+    HEADER_SIMULATED_TRADING, OkxCredentials, format_timestamp,
+};
+use crate::okx::stream::OkxStreamInner;
+use crate::okx::types::{
+    OkxAccountBalance, OkxBalanceDetail, OkxBooks, OkxCandle, OkxFundingRate, OkxInstrument,
+    OkxItemStatus, OkxOrder, OkxOrderAck, OkxPosition, OkxResponse, OkxServerTime, OkxTicker,
+};
+use crate::order_cache::OrderKeyCache;
+use crate::rate_limit::RateLimiter;
+use arb_exchange::{
+    Balance, Candle, CandleInterval, ExchangeError, ExchangeName, ExchangeResult,
+    InstrumentDataProvider, InstrumentInfoResponse, LinearOrderManagement, MarketData, Order,
+    OrderBook, OrderBookLevel, OrderManagement, OrderRequest, OrderSide, OrderStatus, OrderType,
+    PositionInfo, PriceChange, StreamConfig, Ticker, TimeInForce, to_exchange_format,
+    to_internal_format,
+};
+use chrono::{DateTime, TimeZone, Utc};
+use reqwest::{Client, Method};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+use tracing::{debug, warn};
+
+/// OKX REST API 기본 URL (실거래와 데모 트레이딩 공통).
+const BASE_URL: &str = "https://www.okx.com";
+
+/// OKX 공개 API 레이트 리밋 (초당 요청 수).
+/// 시세/호가 엔드포인트 한도(2초당 20~40건)보다 보수적으로 설정.
+const OKX_PUBLIC_RATE_LIMIT: u32 = 10;
+/// 공개 API 최대 버스트 용량.
+const OKX_PUBLIC_BURST: u32 = 5;
+
+/// OKX 비공개 API 레이트 리밋 (초당 요청 수).
+/// 주문 한도(상품별 2초당 60건)보다 보수적으로 설정.
+const OKX_PRIVATE_RATE_LIMIT: u32 = 10;
+/// 비공개 API 최대 버스트 용량.
+const OKX_PRIVATE_BURST: u32 = 5;
+
+/// 주문 키 캐시 최대 항목 수.
+const ORDER_CACHE_CAPACITY: usize = 4096;
+
+/// `clOrdId` 최대 길이 (영숫자만 허용).
+const MAX_CLIENT_ORDER_ID_LEN: usize = 32;
+
+/// `/market/candles` 1회 최대 개수.
+const MAX_CANDLES: u32 = 300;
+/// `/market/history-candles` 1회 최대 개수.
+const MAX_HISTORY_CANDLES: u32 = 100;
+/// `/market/books` 최대 depth.
+const MAX_BOOK_DEPTH: u32 = 400;
+
+/// OKX 무기한 스왑(USDT 증거금) API 클라이언트.
+///
+/// Bybit linear의 대체 헤지 거래소로 사용할 수 있도록 공개(시장 데이터) API와
+/// 스왑 주문/포지션 API를 지원합니다. 계정은 net 포지션 모드, cross 마진을 가정합니다.
+///
+/// 심볼은 Bybit 형식("BTCUSDT"), 공통 마켓 형식("USDT-BTC"), OKX 상품 ID("BTC-USDT-SWAP")를
+/// 모두 받으며, 포지션과 스트림 이벤트는 Bybit 형식 심볼로 반환합니다.
+pub struct OkxClient {
+    client: Client,
+    /// 인증 정보. 복제본 간에 공유되어 [`Self::set_credentials`]로 교체됩니다.
+    credentials: CredentialSlot<OkxCredentials>,
+    base_url: String,
+    /// 데모 트레이딩 여부 (`x-simulated-trading: 1` 헤더).
+    simulated: bool,
+    /// WebSocket 스트림 내부 상태.
+    pub(crate) stream: Arc<OkxStreamInner>,
+    /// 서버 시계 추정기 (서명 타임스탬프 보정, 피드 지연 측정).
+    pub(crate) clock: Arc<ServerClock>,
+    /// 공개 API (시세, 오더북) 레이트 리밋터.
+    public_limiter: Arc<RateLimiter>,
+    /// 비공개 API (주문, 잔고, 포지션) 레이트 리밋터.
+    private_limiter: Arc<RateLimiter>,
+    /// 상품 ID → 상품 정보 (계약 승수 변환용).
+    instruments: Arc<Mutex<HashMap<String, OkxInstrument>>>,
+    /// 주문 ID / `clOrdId` → 상품 ID (주문 조회/취소에 필요).
+    order_symbols: OrderKeyCache,
+    /// `clOrdId` → 호출자가 지정한 원래 client order ID.
+    client_order_ids: OrderKeyCache,
+}
+
+impl std::fmt::Debug for OkxClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OkxClient")
+            .field("base_url", &self.base_url)
+            .field("simulated", &self.simulated)
+            .field("credentials", &self.credentials.is_set())
+            .finish()
+    }
+}
+
+impl Clone for OkxClient {
+    /// 클라이언트를 복제합니다.
+    ///
+    /// 커넥션 풀, rate limiter 상태, 상품/주문 캐시를 복제본과 공유합니다.
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            credentials: self.credentials.clone(),
+            base_url: self.base_url.clone(),
+            simulated: self.simulated,
+            stream: Arc::clone(&self.stream),
+            clock: Arc::clone(&self.clock),
+            public_limiter: Arc::clone(&self.public_limiter),
+            private_limiter: Arc::clone(&self.private_limiter),
+            instruments: Arc::clone(&self.instruments),
+            order_symbols: self.order_symbols.clone(),
+            client_order_ids: self.client_order_ids.clone(),
+        }
+    }
+}
+
+impl OkxClient {
+    /// 실거래용 인증되지 않은 새 OKX 클라이언트를 생성합니다.
+    ///
+    /// # 에러
+    ///
+    /// HTTP 클라이언트를 생성할 수 없는 경우 에러를 반환합니다.
+    pub fn new() -> ExchangeResult<Self> {
+        Self::new_internal(None, BASE_URL, false)
+    }
+
+    /// 데모 트레이딩용 인증되지 않은 새 OKX 클라이언트를 생성합니다.
+    ///
+    /// # 에러
+    ///
+    /// HTTP 클라이언트를 생성할 수 없는 경우 에러를 반환합니다.
+    pub fn new_demo() -> ExchangeResult<Self> {
+        Self::new_internal(None, BASE_URL, true)
+    }
+
+    /// 실거래용 인증된 새 OKX 클라이언트를 생성합니다.
+    ///
+    /// # 인자
+    ///
+    /// * `api_key` - OKX API 키
+    /// * `secret_key` - OKX API 시크릿 키
+    /// * `passphrase` - API 키 패스프레이즈
+    ///
+    /// # 에러
+    ///
+    /// HTTP 클라이언트를 생성할 수 없는 경우 에러를 반환합니다.
+    pub fn with_credentials(
+        api_key: impl Into<String>,
+        secret_key: impl Into<String>,
+        passphrase: impl Into<String>,
+    ) -> ExchangeResult<Self> {
+        let creds = OkxCredentials::new(api_key, secret_key, passphrase);
+        Self::new_internal(Some(creds), BASE_URL, false)
+    }
+
+    /// 데모 트레이딩용 인증된 새 OKX 클라이언트를 생성합니다.
+    ///
+    /// # 인자
+    ///
+    /// * `api_key` - OKX 데모 API 키
+    /// * `secret_key` - OKX 데모 API 시크릿 키
+    /// * `passphrase` - API 키 패스프레이즈
+    ///
+    /// # 에러
+    ///
+    /// HTTP 클라이언트를 생성할 수 없는 경우 에러를 반환합니다.
+    pub fn with_credentials_demo(
+        api_key: impl Into<String>,
+        secret_key: impl Into<String>,
+        passphrase: impl Into<String>,
+    ) -> ExchangeResult<Self> {
+        let creds = OkxCredentials::new(api_key, secret_key, passphrase);
+        Self::new_internal(Some(creds), BASE_URL, true)
+    }
+
+    /// 내부 생성자.
+    fn new_internal(
+        credentials: Option<OkxCredentials>,
+        base_url: &str,
+        simulated: bool,
+    ) -> ExchangeResult<Self> {
+        let client = Client::builder()
+            .timeout(std::time::Duration::from_secs(30))
+            .build()
+            .map_err(ExchangeError::HttpError)?;
+
+        Ok(Self {
+            client,
+            credentials: CredentialSlot::new(credentials),
+            base_url: base_url.to_string(),
+            simulated,
+            stream: Arc::new(OkxStreamInner::new(StreamConfig::default())),
+            clock: Arc::new(ServerClock::new("okx")),
+            public_limiter: Arc::new(RateLimiter::new(
+                "okx-public",
+                OKX_PUBLIC_RATE_LIMIT,
+                OKX_PUBLIC_BURST,
+            )),
+            private_limiter: Arc::new(RateLimiter::new(
+                "okx-private",
+                OKX_PRIVATE_RATE_LIMIT,
+                OKX_PRIVATE_BURST,
+            )),
+            instruments: Arc::new(Mutex::new(HashMap::new())),
+            order_symbols: OrderKeyCache::new(ORDER_CACHE_CAPACITY),
+            client_order_ids: OrderKeyCache::new(ORDER_CACHE_CAPACITY),
+        })
+    }
+
+    /// WebSocket 스트림 내부 상태에 접근합니다.
+    pub(crate) fn stream_inner(&self) -> &OkxStreamInner {
+        &self.stream
+    }
+
+    /// 실행 중 API 키를 교체합니다 (키 로테이션).
+    ///
+    /// 이 클라이언트의 모든 복제본에 즉시 반영되며, 이미 서명된 요청은 이전 키로 완료됩니다.
+    ///
+    /// # 인자
+    ///
+    /// * `api_key` - OKX API 키
+    /// * `secret_key` - OKX API 시크릿 키
+    /// * `passphrase` - API 키 패스프레이즈
+    pub fn set_credentials(
+        &self,
+        api_key: impl Into<String>,
+        secret_key: impl Into<String>,
+        passphrase: impl Into<String>,
+    ) {
+        self.credentials
+            .set(OkxCredentials::new(api_key, secret_key, passphrase));
+    }
+
+    /// 인증 정보가 설정되어 있으면 true를 반환합니다.
+    pub fn has_credentials(&self) -> bool {
+        self.credentials.is_set()
+    }
+
+    /// 상품 정보를 반환합니다. 캐시에 없으면 조회합니다.
+    async fn instrument(&self, inst_id: &str) -> ExchangeResult<OkxInstrument> {
+        if let Some(instrument) = self
+            .instruments
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(inst_id)
+        {
+            return Ok(instrument.clone());
+        }
+        self.fetch_instrument(inst_id).await
+    }
+
+    /// 상품 정보를 조회하고 캐시를 갱신합니다.
+    async fn fetch_instrument(&self, inst_id: &str) -> ExchangeResult<OkxInstrument> {
+        let instruments: Vec<OkxInstrument> = self
+            .get_public(
+                "/api/v5/public/instruments",
+                &[("instType", "SWAP"), ("instId", inst_id)],
+            )
+            .await?;
+        let instrument = instruments
+            .into_iter()
+            .next()
+            .ok_or_else(|| ExchangeError::MarketNotFound(inst_id.to_string()))?;
+        if instrument.ct_val <= Decimal::ZERO {
+            return Err(ExchangeError::ParseError(format!(
+                "OKX instrument {inst_id} has invalid ctVal {}",
+                instrument.ct_val
+            )));
+        }
+
+        debug!(inst_id, ct_val = %instrument.ct_val, "OKX 상품 정보 캐시 갱신");
+        self.instruments
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(inst_id.to_string(), instrument.clone());
+        Ok(instrument)
+    }
+
+    /// 상품의 계약 승수(계약 1개당 코인 수량)를 반환합니다.
+    async fn contract_value(&self, inst_id: &str) -> ExchangeResult<Decimal> {
+        Ok(self.instrument(inst_id).await?.ct_val)
+    }
+
+    /// 주문 응답의 ID와 `clOrdId`를 상품 ID 캐시에 반영합니다.
+    fn remember_order(&self, order: &OkxOrder) {
+        self.order_symbols.insert(&order.ord_id, &order.inst_id);
+        if !order.cl_ord_id.is_empty() {
+            self.order_symbols.insert(&order.cl_ord_id, &order.inst_id);
+        }
+    }
+
+    /// 주문 키(주문 ID 또는 `clOrdId`)의 상품 ID를 찾습니다.
+    ///
+    /// 캐시에 없으면(재시작 등) 전체 미체결 주문에서 찾습니다. 그래도 없으면
+    /// 주문 부재를 확정할 수 없으므로 `OrderNotFound`가 아닌 `InvalidParameter`를 반환합니다.
+    async fn resolve_inst_id(&self, key: &str) -> ExchangeResult<String> {
+        if let Some(inst_id) = self.order_symbols.get(key) {
+            return Ok(inst_id);
+        }
+
+        debug!(key, "OKX 주문 상품 캐시 미스 — 미체결 주문에서 검색");
+        let open: Vec<OkxOrder> = self
+            .send_signed(
+                Method::GET,
+                "/api/v5/trade/orders-pending",
+                &[("instType", "SWAP")],
+                None,
+            )
+            .await?;
+        for order in &open {
+            self.remember_order(order);
+        }
+
+        self.order_symbols.get(key).ok_or_else(|| {
+            ExchangeError::InvalidParameter(format!(
+                "OKX order lookup requires a known instrument: {key}"
+            ))
+        })
+    }
+
+    /// 주문을 조회합니다.
+    ///
+    /// * `key` - `("ordId", ..)` 또는 `("clOrdId", ..)`
+    async fn fetch_order(&self, inst_id: &str, key: (&str, &str)) -> ExchangeResult<Order> {
+        let orders: Vec<OkxOrder> = self
+            .send_signed(
+                Method::GET,
+                "/api/v5/trade/order",
+                &[("instId", inst_id), key],
+                None,
+            )
+            .await?;
+        let order = orders
+            .into_iter()
+            .next()
+            .ok_or_else(|| ExchangeError::OrderNotFound(key.1.to_string()))?;
+        self.convert_order(order).await
+    }
+
+    /// OKX 주문을 코인 단위 공통 주문으로 변환합니다.
+    async fn convert_order(&self, order: OkxOrder) -> ExchangeResult<Order> {
+        self.remember_order(&order);
+        let ct_val = self.contract_value(&order.inst_id).await?;
+        let identifier = (!order.cl_ord_id.is_empty()).then(|| {
+            self.client_order_ids
+                .get(&order.cl_ord_id)
+                .unwrap_or_else(|| order.cl_ord_id.clone())
+        });
+        Ok(convert_order(order, ct_val, identifier))
+    }
+
+    /// 공개 엔드포인트에 GET 요청을 보내고 `data` 배열을 반환합니다.
+    async fn get_public<T: serde::de::DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        params: &[(&str, &str)],
+    ) -> ExchangeResult<Vec<T>> {
+        self.public_limiter.acquire().await;
+        let url = format!("{}{}", self.base_url, endpoint);
+        debug!(endpoint, ?params, "OKX public GET 요청");
+        let response = self
+            .client
+            .get(&url)
+            .query(params)
+            .send()
+            .await
+            .map_err(ExchangeError::HttpError)?;
+
+        self.handle_response(response).await
+    }
+
+    /// 서명이 필요한 엔드포인트에 요청을 보내고 `data` 배열을 반환합니다.
+    ///
+    /// 서명 대상 경로와 전송 URL이 일치하도록 쿼리 문자열을 직접 만들어 붙입니다.
+    async fn send_signed<T: serde::de::DeserializeOwned>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &[(&str, &str)],
+        body: Option<&serde_json::Value>,
+    ) -> ExchangeResult<Vec<T>> {
+        self.private_limiter.acquire().await;
+        let creds = self.credentials.get()?;
+
+        let request_path = if params.is_empty() {
+            endpoint.to_string()
+        } else {
+            format!("{}?{}", endpoint, build_query_string(params))
+        };
+        let body = body.map(|b| b.to_string()).unwrap_or_default();
+        let timestamp = format_timestamp(self.clock.now_ms());
+        let signature = creds.sign(&timestamp, method.as_str(), &request_path, &body)?;
+        debug!(endpoint, %method, ?params, "OKX signed 요청");
+
+        let mut request = self
+            .client
+            .request(method, format!("{}{}", self.base_url, request_path))
+            .header(HEADER_ACCESS_KEY, creds.api_key())
+            .header(HEADER_ACCESS_SIGN, signature)
+            .header(HEADER_ACCESS_TIMESTAMP, timestamp)
+            .header(HEADER_ACCESS_PASSPHRASE, creds.passphrase())
+            .header("Content-Type", "application/json");
+        if self.simulated {
+            request = request.header(HEADER_SIMULATED_TRADING, "1");
+        }
+        if !body.is_empty() {
+            request = request.body(body);
+        }
+
+        let response = request.send().await.map_err(ExchangeError::HttpError)?;
+        self.handle_response(response).await
+    }
+
+    /// API 응답을 처리하고 에러를 변환합니다.
+    ///
+    /// OKX는 HTTP 200에도 `code != "0"`으로 실패를 알리므로 본문 코드까지 확인합니다.
+    async fn handle_response<T: serde::de::DeserializeOwned>(
+        &self,
+        response: reqwest::Response,
+    ) -> ExchangeResult<Vec<T>> {
+        let status = response.status();
+        let body = response.text().await.map_err(ExchangeError::HttpError)?;
+
+        debug!(
+            status = status.as_u16(),
+            body_len = body.len(),
+            body_preview = %if body.len() > 200 { &body[..200] } else { &body },
+            "OKX API 응답 수신"
+        );
+
+        if !status.is_success() {
+            warn!(status = status.as_u16(), body = %body, "OKX API HTTP 에러");
+            return Err(parse_error(&body, status.as_u16()));
+        }
+
+        let envelope: OkxResponse<serde_json::Value> =
+            serde_json::from_str(&body).map_err(ExchangeError::JsonError)?;
+        if envelope.code != "0" {
+            warn!(code = %envelope.code, msg = %envelope.msg, "OKX API 에러 응답");
+            return Err(parse_error(&body, status.as_u16()));
+        }
+
+        envelope
+            .data
+            .into_iter()
+            .map(serde_json::from_value)
+            .collect::<Result<Vec<T>, _>>()
+            .map_err(ExchangeError::JsonError)
+    }
+
+    /// 심볼을 OKX 스왑 상품 ID로 변환합니다.
+    ///
+    /// "BTCUSDT" / "USDT-BTC" / "BTC-USDT-SWAP" -> "BTC-USDT-SWAP"
+    pub(super) fn to_inst_id(market: &str) -> String {
+        if market.ends_with("-SWAP") {
+            return market.to_string();
+        }
+        if market.contains('-') {
+            return to_exchange_format(ExchangeName::Okx, market);
+        }
+        for quote in ["USDT", "USDC"] {
+            if let Some(base) = market.strip_suffix(quote)
+                && !base.is_empty()
+            {
+                return format!("{}-{}-SWAP", base, quote);
+            }
+        }
+
+        market.to_string()
+    }
+
+    /// OKX 상품 ID를 Bybit 형식 심볼로 변환합니다.
+    ///
+    /// "BTC-USDT-SWAP" -> "BTCUSDT"
+    pub(super) fn to_symbol(inst_id: &str) -> String {
+        inst_id
+            .strip_suffix("-SWAP")
+            .unwrap_or(inst_id)
+            .replace('-', "")
+    }
+
+    /// OKX 상품 ID를 공통 마켓 형식으로 변환합니다.
+    ///
+    /// "BTC-USDT-SWAP" -> "USDT-BTC"
+    fn to_market_code(inst_id: &str) -> String {
+        to_internal_format(ExchangeName::Okx, inst_id)
+    }
+}
+
+impl ServerTimeSync for OkxClient {
+    fn server_clock(&self) -> &ServerClock {
+        &self.clock
+    }
+
+    async fn sync_server_clock(&self) -> ExchangeResult<ClockSample> {
+        // rate limiter 대기가 RTT에 섞이지 않도록 토큰 확보 후 송신 시각을 측정합니다.
+        self.public_limiter.acquire().await;
+        let url = format!("{}/api/v5/public/time", self.base_url);
+        let sent_ms = local_now_ms();
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(ExchangeError::HttpError)?;
+        let received_ms = local_now_ms();
+
+        let result: Vec<OkxServerTime> = self.handle_response(response).await?;
+        let server_ms = result
+            .first()
+            .map(|t| t.ts)
+            .ok_or_else(|| ExchangeError::ParseError("empty OKX server time".to_string()))?;
+        Ok(self.clock.record_probe(sent_ms, server_ms, received_ms))
+    }
+}
+
+impl MarketData for OkxClient {
+    fn name(&self) -> &str {
+        "OKX"
+    }
+
+    async fn get_ticker(&self, markets: &[&str]) -> ExchangeResult<Vec<Ticker>> {
+        let mut tickers = Vec::with_capacity(markets.len());
+
+        for market in markets {
+            let inst_id = Self::to_inst_id(market);
+            let result: Vec<OkxTicker> = self
+                .get_public("/api/v5/market/ticker", &[("instId", &inst_id)])
+                .await?;
+            let ticker = result
+                .into_iter()
+                .next()
+                .ok_or_else(|| ExchangeError::MarketNotFound(market.to_string()))?;
+            tickers.push(convert_ticker(ticker, market));
+        }
+
+        Ok(tickers)
+    }
+
+    async fn get_orderbook(&self, market: &str, depth: Option<u32>) -> ExchangeResult<OrderBook> {
+        let inst_id = Self::to_inst_id(market);
+        let sz = depth.unwrap_or(20).min(MAX_BOOK_DEPTH).to_string();
+
+        let result: Vec<OkxBooks> = self
+            .get_public("/api/v5/market/books", &[("instId", &inst_id), ("sz", &sz)])
+            .await?;
+        let books = result
+            .into_iter()
+            .next()
+            .ok_or_else(|| ExchangeError::MarketNotFound(market.to_string()))?;
+        let ct_val = self.contract_value(&inst_id).await?;
+
+        Ok(convert_orderbook(books, market, ct_val))
+    }
+
+    async fn get_candles(
+        &self,
+        market: &str,
+        interval: CandleInterval,
+        count: u32,
+    ) -> ExchangeResult<Vec<Candle>> {
+        let inst_id = Self::to_inst_id(market);
+        let limit = count.min(MAX_CANDLES).to_string();
+        let params = [
+            ("instId", inst_id.as_str()),
+            ("bar", interval_to_okx(interval)),
+            ("limit", &limit),
+        ];
+
+        // OKX는 최신순으로 반환하므로 오름차순으로 뒤집음
+        let candles: Vec<OkxCandle> = self.get_public("/api/v5/market/candles", &params).await?;
+        Ok(candles
+            .into_iter()
+            .rev()
+            .map(|c| convert_candle(c, market))
+            .collect())
+    }
+
+    async fn get_candles_before(
+        &self,
+        market: &str,
+        interval: CandleInterval,
+        count: u32,
+        before: DateTime<Utc>,
+    ) -> ExchangeResult<Vec<Candle>> {
+        let inst_id = Self::to_inst_id(market);
+        let limit = count.min(MAX_HISTORY_CANDLES).to_string();
+        // OKX의 `after`는 해당 시각보다 이전(exclusive) 데이터를 반환
+        let after = before.timestamp_millis().to_string();
+        let params = [
+            ("instId", inst_id.as_str()),
+            ("bar", interval_to_okx(interval)),
+            ("limit", &limit),
+            ("after", &after),
+        ];
+
+        let candles: Vec<OkxCandle> = self
+            .get_public("/api/v5/market/history-candles", &params)
+            .await?;
+        Ok(candles
+            .into_iter()
+            .rev()
+            .map(|c| convert_candle(c, market))
+            .collect())
+    }
+
+    async fn get_all_tickers(&self) -> ExchangeResult<Vec<Ticker>> {
+        let result: Vec<OkxTicker> = self
+            .get_public("/api/v5/market/tickers", &[("instType", "SWAP")])
+            .await?;
+
+        // USDT 증거금 스왑만 필터하여 변환
+        Ok(result
+            .into_iter()
+            .filter(|t| t.inst_id.ends_with("-USDT-SWAP"))
+            .map(|t| {
+                let market = Self::to_market_code(&t.inst_id);
+                convert_ticker(t, &market)
+            })
+            .collect())
+    }
+
+    fn market_code(base: &str, quote: &str) -> String {
+        // 헤지 심볼과 맞추기 위해 Bybit 형식 사용: "{BASE}{QUOTE}" (예: "BTCUSDT")
+        format!("{}{}", base.to_uppercase(), quote.to_uppercase())
+    }
+}
+
+/// 스왑 전용 거래소이므로 일반 주문 API는 linear 주문 API에 위임합니다.
+impl OrderManagement for OkxClient {
+    async fn place_order(&self, request: &OrderRequest) -> ExchangeResult<Order> {
+        self.place_order_linear(request, false).await
+    }
+
+    async fn cancel_order(&self, order_id: &str) -> ExchangeResult<Order> {
+        self.cancel_order_linear(order_id, None).await
+    }
+
+    async fn get_order(&self, order_id: &str) -> ExchangeResult<Order> {
+        self.get_order_linear(order_id).await
+    }
+
+    async fn get_order_by_client_id(&self, client_order_id: &str) -> ExchangeResult<Order> {
+        self.get_order_linear_by_client_id(client_order_id).await
+    }
+
+    async fn get_open_orders(&self, market: Option<&str>) -> ExchangeResult<Vec<Order>> {
+        let inst_id = market.map(Self::to_inst_id);
+        let mut params = vec![("instType", "SWAP")];
+        if let Some(ref inst_id) = inst_id {
+            params.push(("instId", inst_id));
+        }
+
+        let orders: Vec<OkxOrder> = self
+            .send_signed(Method::GET, "/api/v5/trade/orders-pending", &params, None)
+            .await?;
+
+        let mut result = Vec::with_capacity(orders.len());
+        for order in orders {
+            result.push(self.convert_order(order).await?);
+        }
+        Ok(result)
+    }
+
+    async fn get_balances(&self) -> ExchangeResult<Vec<Balance>> {
+        let accounts: Vec<OkxAccountBalance> = self
+            .send_signed(Method::GET, "/api/v5/account/balance", &[], None)
+            .await?;
+        Ok(accounts
+            .into_iter()
+            .flat_map(|a| a.details)
+            .map(convert_balance)
+            .collect())
+    }
+
+    async fn get_balance(&self, currency: &str) -> ExchangeResult<Balance> {
+        let balances = self.get_balances().await?;
+        balances
+            .into_iter()
+            .find(|b| b.currency == currency)
+            .ok_or_else(|| {
+                ExchangeError::InvalidParameter(format!("Currency not found: {}", currency))
+            })
+    }
+}
+
+impl InstrumentDataProvider for OkxClient {
+    /// 상품 규격을 계약 수 단위로 반환합니다 (`contract_multiplier` = `ctVal`).
+    async fn get_instrument_info(&self, symbol: &str) -> ExchangeResult<InstrumentInfoResponse> {
+        // 규격 변경을 반영하도록 캐시를 거치지 않고 조회합니다.
+        let instrument = self.fetch_instrument(&Self::to_inst_id(symbol)).await?;
+
+        Ok(InstrumentInfoResponse {
+            tick_size: instrument.tick_sz,
+            qty_step: instrument.lot_sz,
+            min_order_qty: instrument.min_sz,
+            max_order_qty: instrument.max_lmt_sz,
+            // OKX는 최소 주문 금액 대신 최소 계약 수(minSz)만 제한합니다.
+            min_notional: Decimal::ZERO,
+            contract_multiplier: instrument.ct_val,
+        })
+    }
+}
+
+impl LinearOrderManagement for OkxClient {
+    async fn place_order_linear(
+        &self,
+        request: &OrderRequest,
+        reduce_only: bool,
+    ) -> ExchangeResult<Order> {
+        let inst_id = Self::to_inst_id(&request.market);
+
+        let side = match request.side {
+            OrderSide::Buy => "buy",
+            OrderSide::Sell => "sell",
+        };
+
+        let ord_type = match request.order_type {
+            OrderType::Market => "market",
+            OrderType::Limit | OrderType::Best => match request.time_in_force {
+                None | Some(TimeInForce::Gtc) => "limit",
+                Some(TimeInForce::Ioc) => "ioc",
+                Some(TimeInForce::Fok) => "fok",
+                Some(TimeInForce::PostOnly) => "post_only",
+            },
+            OrderType::Price => {
+                return Err(ExchangeError::InvalidParameter(
+                    "OKX swap does not support quote-amount market orders".to_string(),
+                ));
+            }
+        };
+
+        let volume = request
+            .volume
+            .ok_or_else(|| ExchangeError::InvalidParameter("volume is required".to_string()))?;
+        let instrument = self.instrument(&inst_id).await?;
+        let contracts = to_contracts(volume, &instrument)?;
+
+        let mut body = serde_json::json!({
+            "instId": inst_id,
+            "tdMode": "cross",
+            "side": side,
+            "ordType": ord_type,
+            "sz": contracts.to_string(),
+        });
+        if ord_type != "market" {
+            let price = request.price.ok_or_else(|| {
+                ExchangeError::InvalidParameter("price is required for limit orders".to_string())
+            })?;
+            body["px"] = price.normalize().to_string().into();
+        }
+        if reduce_only {
+            body["reduceOnly"] = true.into();
+        }
+        if let Some(ref client_order_id) = request.identifier {
+            let cl_ord_id = to_okx_client_order_id(client_order_id);
+            // 응답 유실 시 clOrdId 조회에 상품 ID가 필요하므로 전송 전에 기억
+            self.order_symbols.insert(&cl_ord_id, &inst_id);
+            self.client_order_ids.insert(&cl_ord_id, client_order_id);
+            body["clOrdId"] = cl_ord_id.into();
+        }
+
+        debug!(
+            inst_id = %inst_id,
+            side,
+            volume = %volume,
+            contracts = %contracts,
+            price = ?request.price,
+            ord_type,
+            reduce_only,
+            "OKX 스왑 주문 생성 요청"
+        );
+
+        let acks: Vec<OkxOrderAck> = self
+            .send_signed(Method::POST, "/api/v5/trade/order", &[], Some(&body))
+            .await?;
+        let ack = acks
+            .into_iter()
+            .next()
+            .ok_or_else(|| ExchangeError::ApiError("empty OKX order response".to_string()))?;
+        self.order_symbols.insert(&ack.ord_id, &inst_id);
+
+        debug!(
+            ord_id = %ack.ord_id,
+            cl_ord_id = %ack.cl_ord_id,
+            "OKX 스왑 주문 생성 완료"
+        );
+
+        self.fetch_order(&inst_id, ("ordId", &ack.ord_id)).await
+    }
+
+    async fn get_order_linear(&self, order_id: &str) -> ExchangeResult<Order> {
+        let inst_id = self.resolve_inst_id(order_id).await?;
+        self.fetch_order(&inst_id, ("ordId", order_id)).await
+    }
+
+    async fn get_order_linear_by_client_id(&self, client_order_id: &str) -> ExchangeResult<Order> {
+        let cl_ord_id = to_okx_client_order_id(client_order_id);
+        self.client_order_ids.insert(&cl_ord_id, client_order_id);
+        let inst_id = self.resolve_inst_id(&cl_ord_id).await?;
+        self.fetch_order(&inst_id, ("clOrdId", &cl_ord_id)).await
+    }
+
+    async fn cancel_order_linear(
+        &self,
+        order_id: &str,
+        symbol: Option<&str>,
+    ) -> ExchangeResult<Order> {
+        let inst_id = match symbol {
+            Some(s) => Self::to_inst_id(s),
+            None => self.resolve_inst_id(order_id).await?,
+        };
+
+        debug!(order_id, inst_id = %inst_id, "OKX 스왑 주문 취소 요청");
+
+        let body = serde_json::json!({ "instId": inst_id, "ordId": order_id });
+        let _acks: Vec<OkxOrderAck> = self
+            .send_signed(Method::POST, "/api/v5/trade/cancel-order", &[], Some(&body))
+            .await?;
+
+        // 취소 응답에는 주문 상세가 없으므로 재조회
+        self.fetch_order(&inst_id, ("ordId", order_id)).await
+    }
+
+    async fn get_positions_linear(&self, symbol: &str) -> ExchangeResult<Vec<PositionInfo>> {
+        let inst_id = (!symbol.is_empty()).then(|| Self::to_inst_id(symbol));
+        let mut params = vec![("instType", "SWAP")];
+        if let Some(ref inst_id) = inst_id {
+            params.push(("instId", inst_id));
+        }
+
+        let positions: Vec<OkxPosition> = self
+            .send_signed(Method::GET, "/api/v5/account/positions", &params, None)
+            .await?;
+
+        let mut result = Vec::with_capacity(positions.len());
+        for position in positions {
+            // 전체 조회 시에는 보유 포지션만 남김
+            if symbol.is_empty() && position.pos.is_zero() {
+                continue;
+            }
+            let ct_val = self.contract_value(&position.inst_id).await?;
+            result.push(convert_position(position, ct_val));
+        }
+        Ok(result)
+    }
+}
+
+/// OKX 스왑 전용 API 메서드.
+impl OkxClient {
+    /// 스왑 상품의 레버리지를 설정합니다 (cross 마진).
+    ///
+    /// # 인자
+    ///
+    /// * `symbol` - 심볼 (예: "BTCUSDT" 또는 "BTC-USDT-SWAP")
+    /// * `leverage` - 설정할 레버리지 배수
+    pub async fn set_leverage(&self, symbol: &str, leverage: u32) -> ExchangeResult<()> {
+        let inst_id = Self::to_inst_id(symbol);
+        debug!(inst_id = %inst_id, leverage, "OKX 레버리지 설정 요청");
+
+        let body = serde_json::json!({
+            "instId": inst_id,
+            "lever": leverage.to_string(),
+            "mgnMode": "cross",
+        });
+        let _result: Vec<serde_json::Value> = self
+            .send_signed(
+                Method::POST,
+                "/api/v5/account/set-leverage",
+                &[],
+                Some(&body),
+            )
+            .await?;
+
+        debug!(inst_id = %inst_id, leverage, "OKX 레버리지 설정 완료");
+        Ok(())
+    }
+
+    /// 스왑 펀딩 정보를 조회합니다.
+    ///
+    /// Bybit의 [`crate::BybitClient::get_tickers_linear`]와 같은 형식으로 반환하여
+    /// 펀딩 필터를 그대로 사용할 수 있게 합니다. OKX 펀딩레이트 API는 상품별 조회만
+    /// 지원하므로 `symbol`이 없으면 `Unsupported`를 반환합니다.
+    ///
+    /// # 인자
+    ///
+    /// * `symbol` - 조회할 심볼
+    pub async fn get_tickers_linear(
+        &self,
+        symbol: Option<&str>,
+    ) -> ExchangeResult<Vec<LinearTickerInfo>> {
+        let symbol = symbol.ok_or_else(|| {
+            ExchangeError::Unsupported("OKX funding rate lookup requires an instrument".to_string())
+        })?;
+        let inst_id = Self::to_inst_id(symbol);
+
+        let result: Vec<OkxFundingRate> = self
+            .get_public("/api/v5/public/funding-rate", &[("instId", &inst_id)])
+            .await?;
+
+        Ok(result
+            .into_iter()
+            .map(|f| LinearTickerInfo {
+                symbol: Self::to_symbol(&f.inst_id),
+                funding_rate: f.funding_rate.to_string().parse::<f64>().unwrap_or(0.0),
+                next_funding_time: f.funding_time,
+            })
+            .collect())
+    }
+}
+
+// 변환 함수들
+
+/// 파라미터로 URL 인코딩된 쿼리 문자열을 만듭니다.
+fn build_query_string(params: &[(&str, &str)]) -> String {
+    url::form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(params)
+        .finish()
+}
+
+/// client order ID를 OKX `clOrdId` 규칙(영숫자, 최대 32자)에 맞춥니다.
+///
+/// 영숫자만 남기고 길면 뒤쪽 32자를 사용합니다. 전략 ID(`arb-<인스턴스>-<세션>-<난수>`)는
+/// 맨 앞 글자만 잘려 인스턴스/세션 태그와 난수가 그대로 남으므로 고유성이 유지되고,
+/// 같은 입력은 항상 같은 값이 됩니다.
+fn to_okx_client_order_id(client_order_id: &str) -> String {
+    let alphanumeric: String = client_order_id
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .collect();
+    let skip = alphanumeric.len().saturating_sub(MAX_CLIENT_ORDER_ID_LEN);
+    alphanumeric[skip..].to_string()
+}
+
+/// 코인 수량을 계약 수로 변환합니다.
+///
+/// 계약 수가 `lotSz`의 정수 배가 아니면 OKX가 거부하므로 미리 에러를 반환합니다.
+fn to_contracts(coin_qty: Decimal, instrument: &OkxInstrument) -> ExchangeResult<Decimal> {
+    let contracts = (coin_qty / instrument.ct_val).normalize();
+    if !instrument.lot_sz.is_zero() && !(contracts % instrument.lot_sz).is_zero() {
+        return Err(ExchangeError::InvalidParameter(format!(
+            "OKX order size {} is not a multiple of {} contracts ({} per contract)",
+            coin_qty, instrument.lot_sz, instrument.ct_val
+        )));
+    }
+    Ok(contracts)
+}
+
+/// 응답 본문에서 에러를 파싱합니다.
+///
+/// 주문 계열 API는 `code`가 "1"이고 실제 원인이 `data[].sCode`에 있으므로 이를 우선합니다.
+fn parse_error(body: &str, status: u16) -> ExchangeError {
+    if let Ok(resp) = serde_json::from_str::<OkxResponse<OkxItemStatus>>(body) {
+        if let Some(item) = resp
+            .data
+            .iter()
+            .find(|item| !item.s_code.is_empty() && item.s_code != "0")
+        {
+            return convert_okx_error(&item.s_code, &item.s_msg);
+        }
+        if resp.code != "0" {
+            return convert_okx_error(&resp.code, &resp.msg);
+        }
+    }
+
+    match status {
+        429 => ExchangeError::RateLimitExceeded(body.to_string()),
+        _ => ExchangeError::UnknownError {
+            code: status.to_string(),
+            message: body.to_string(),
+        },
+    }
+}
+
+/// OKX 에러 코드를 ExchangeError로 변환합니다.
+fn convert_okx_error(code: &str, message: &str) -> ExchangeError {
+    match code.parse::<i64>().unwrap_or_default() {
+        // 인증 에러 (API 키, 서명, 패스프레이즈, 타임스탬프, 권한)
+        50100..=50119 => ExchangeError::AuthError(message.to_string()),
+        // 요청 제한 초과
+        50011 | 50061 => ExchangeError::RateLimitExceeded(message.to_string()),
+        // 잔고(증거금) 부족
+        51008 => ExchangeError::InsufficientFunds(message.to_string()),
+        // 주문을 찾을 수 없음
+        51603 => ExchangeError::OrderNotFound(message.to_string()),
+        // 존재하지 않는 상품
+        51001 => ExchangeError::MarketNotFound(message.to_string()),
+        // 잘못된 파라미터, lotSz 배수 아님
+        51000 | 51121 => ExchangeError::InvalidParameter(message.to_string()),
+        // 시스템 에러 (일시 불가, 타임아웃, 과부하)
+        50001 | 50004 | 50013 => ExchangeError::InternalError(message.to_string()),
+        _ => ExchangeError::UnknownError {
+            code: code.to_string(),
+            message: message.to_string(),
+        },
+    }
+}
+
+fn convert_ticker(t: OkxTicker, market: &str) -> Ticker {
+    let change_price = t.last - t.open_24h;
+    let change = if change_price > Decimal::ZERO {
+        PriceChange::Rise
+    } else if change_price < Decimal::ZERO {
+        PriceChange::Fall
+    } else {
+        PriceChange::Even
+    };
+    let change_rate = if t.open_24h.is_zero() {
+        Decimal::ZERO
+    } else {
+        change_price / t.open_24h
+    };
+
+    let timestamp = Utc
+        .timestamp_millis_opt(t.ts)
+        .single()
+        .unwrap_or_else(Utc::now);
+
+    Ticker {
+        market: market.to_string(),
+        trade_price: t.last,
+        opening_price: t.open_24h,
+        high_price: t.high_24h,
+        low_price: t.low_24h,
+        prev_closing_price: t.open_24h, // 24시간 롤링 시가를 전일 종가로 사용
+        change,
+        change_rate,
+        change_price,
+        acc_trade_volume_24h: t.vol_ccy_24h,
+        acc_trade_price_24h: t.vol_ccy_24h * t.last, // 거래대금 필드가 없어 최근가로 근사
+        timestamp,
+    }
+}
+
+fn convert_orderbook(books: OkxBooks, market: &str, ct_val: Decimal) -> OrderBook {
+    let to_levels = |levels: Vec<crate::okx::types::OkxBookLevel>| -> Vec<OrderBookLevel> {
+        levels
+            .into_iter()
+            .map(|level| OrderBookLevel {
+                price: level.price,
+                size: level.size * ct_val,
+            })
+            .collect()
+    };
+    let asks = to_levels(books.asks);
+    let bids = to_levels(books.bids);
+
+    let total_ask_size = asks.iter().fold(Decimal::ZERO, |acc, l| acc + l.size);
+    let total_bid_size = bids.iter().fold(Decimal::ZERO, |acc, l| acc + l.size);
+
+    let timestamp = Utc
+        .timestamp_millis_opt(books.ts)
+        .single()
+        .filter(|_| books.ts > 0)
+        .unwrap_or_else(Utc::now);
+
+    OrderBook {
+        market: market.to_string(),
+        asks,
+        bids,
+        total_ask_size,
+        total_bid_size,
+        timestamp,
+    }
+}
+
+fn convert_candle(c: OkxCandle, market: &str) -> Candle {
+    let timestamp = Utc
+        .timestamp_millis_opt(c.ts)
+        .single()
+        .unwrap_or_else(Utc::now);
+
+    Candle {
+        market: market.to_string(),
+        timestamp,
+        open: c.open,
+        high: c.high,
+        low: c.low,
+        close: c.close,
+        volume: c.volume,
+    }
+}
+
+/// OKX 주문을 변환합니다. 수량은 계약 수 × `ct_val`로 코인 단위가 됩니다.
+fn convert_order(o: OkxOrder, ct_val: Decimal, identifier: Option<String>) -> Order {
+    let market = OkxClient::to_market_code(&o.inst_id);
+
+    let side = match o.side.as_str() {
+        "buy" => OrderSide::Buy,
+        _ => OrderSide::Sell,
+    };
+
+    let order_type = match o.ord_type.as_str() {
+        "market" => OrderType::Market,
+        _ => OrderType::Limit,
+    };
+
+    let status = match o.state.as_str() {
+        "live" => OrderStatus::Wait,
+        "partially_filled" => OrderStatus::PartiallyFilled,
+        "filled" => OrderStatus::Filled,
+        // 일부 체결 후 취소된 주문은 체결분이 남아 있으므로 부분 체결로 분류
+        "canceled" | "mmp_canceled" if o.acc_fill_sz > Decimal::ZERO => {
+            OrderStatus::PartiallyFilled
+        }
+        "canceled" | "mmp_canceled" => OrderStatus::Cancelled,
+        _ => OrderStatus::Wait,
+    };
+
+    let volume = o.sz * ct_val;
+    let executed_volume = o.acc_fill_sz * ct_val;
+
+    let created_at = Utc
+        .timestamp_millis_opt(o.c_time)
+        .single()
+        .unwrap_or_else(Utc::now);
+
+    Order {
+        id: o.ord_id,
+        market,
+        side,
+        order_type,
+        status,
+        volume,
+        remaining_volume: (volume - executed_volume).max(Decimal::ZERO),
+        executed_volume,
+        price: (!o.px.is_zero()).then_some(o.px),
+        avg_price: o.avg_px,
+        paid_fee: -o.fee, // OKX 수수료는 지불 시 음수
+        created_at,
+        identifier,
+    }
+}
+
+fn convert_balance(b: OkxBalanceDetail) -> Balance {
+    Balance {
+        currency: b.ccy,
+        balance: b.avail_bal,
+        locked: b.frozen_bal,
+        avg_buy_price: Decimal::ZERO,
+        unit_currency: "USDT".to_string(),
+        equity: Some(b.eq),
+        unrealised_pnl: Some(b.upl),
+    }
+}
+
+/// OKX 포지션을 변환합니다. 수량은 계약 수 × `ct_val`로 코인 단위가 됩니다.
+fn convert_position(p: OkxPosition, ct_val: Decimal) -> PositionInfo {
+    // net 모드: 수량 부호가 방향, long/short 모드: posSide가 방향
+    let side = match p.pos_side.as_str() {
+        "long" if !p.pos.is_zero() => "Buy",
+        "short" if !p.pos.is_zero() => "Sell",
+        _ if p.pos > Decimal::ZERO => "Buy",
+        _ if p.pos < Decimal::ZERO => "Sell",
+        _ => "",
+    };
+
+    PositionInfo {
+        symbol: OkxClient::to_symbol(&p.inst_id),
+        side: side.to_string(),
+        size: p.pos.abs() * ct_val,
+        entry_price: p.avg_px,
+        leverage: p.lever,
+        unrealised_pnl: p.upl,
+        liq_price: p.liq_px,
+    }
+}
+
+/// CandleInterval을 OKX bar 문자열로 변환합니다.
+fn interval_to_okx(interval: CandleInterval) -> &'static str {
+    match interval {
+        CandleInterval::Minute1 => "1m",
+        CandleInterval::Minute3 => "3m",
+        CandleInterval::Minute5 => "5m",
+        CandleInterval::Minute10 => "15m", // OKX에는 10분 간격이 없어 15분 사용
+        CandleInterval::Minute15 => "15m",
+        CandleInterval::Minute30 => "30m",
+        CandleInterval::Minute60 => "1H",
+        CandleInterval::Minute240 => "4H",
+        CandleInterval::Day => "1Dutc",
+        CandleInterval::Week => "1Wutc",
+        CandleInterval::Month => "1Mutc",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{MockClient, VenueSymbol, mock_client};
+    use mockito::Matcher;
+
+    const INSTRUMENT_JSON: &str = r#"{"code": "0", "msg": "", "data": [{
+        "instId": "BTC-USDT-SWAP", "instType": "SWAP", "ctVal": "0.01", "ctValCcy": "BTC",
+        "tickSz": "0.1", "lotSz": "0.01", "minSz": "0.01", "maxLmtSz": "100000",
+        "state": "live"}]}"#;
+
+    impl MockClient for OkxClient {
+        fn public() -> Self {
+            Self::new().unwrap()
+        }
+
+        fn with_mock_url(base_url: &str) -> Self {
+            let creds = OkxCredentials::new("test-key", "test-secret", "test-pass");
+            Self::new_internal(Some(creds), base_url, false).unwrap()
+        }
+
+        fn has_credentials(&self) -> bool {
+            OkxClient::has_credentials(self)
+        }
+
+        fn parse_error(body: &str, status: u16) -> ExchangeError {
+            parse_error(body, status)
+        }
+    }
+
+    impl VenueSymbol for OkxClient {
+        fn to_venue_symbol(market: &str) -> String {
+            Self::to_inst_id(market)
+        }
+
+        fn from_venue_symbol(inst_id: &str) -> String {
+            Self::to_market_code(inst_id)
+        }
+    }
+
+    async fn mock_instrument(server: &mut mockito::ServerGuard) -> mockito::Mock {
+        server
+            .mock("GET", "/api/v5/public/instruments")
+            .match_query(Matcher::UrlEncoded("instId".into(), "BTC-USDT-SWAP".into()))
+            .with_body(INSTRUMENT_JSON)
+            .create_async()
+            .await
+    }
+
+    fn order_json(state: &str, acc_fill_sz: &str) -> String {
+        format!(
+            r#"{{"code": "0", "msg": "", "data": [{{"ordId": "312269865356374016",
+                "clOrdId": "0190a1b2c3d4e5f60718293a4b5c6d7e", "instId": "BTC-USDT-SWAP",
+                "px": "42000", "sz": "5", "ordType": "ioc", "side": "sell",
+                "state": "{state}", "accFillSz": "{acc_fill_sz}", "avgPx": "",
+                "fee": "-0.021", "cTime": "1707177600000"}}]}}"#
+        )
+    }
+
+    #[test]
+    fn test_to_inst_id_accepts_linear_symbols() {
+        // 마켓 코드 외에 Bybit/Binance 형식 심볼과 instId도 그대로 받음
+        assert_eq!(OkxClient::to_inst_id("BTCUSDT"), "BTC-USDT-SWAP");
+        assert_eq!(OkxClient::to_inst_id("BTC-USDT-SWAP"), "BTC-USDT-SWAP");
+        assert_eq!(OkxClient::to_symbol("1000PEPE-USDT-SWAP"), "1000PEPEUSDT");
+    }
+
+    #[test]
+    fn test_to_okx_client_order_id() {
+        // 전략 ID: 영숫자 33자 → 맨 앞 'a'만 잘림
+        assert_eq!(
+            to_okx_client_order_id("arb-90e208-0007-0190a1b2c3d4e5f60718"),
+            "rb90e20800070190a1b2c3d4e5f60718"
+        );
+        assert_eq!(to_okx_client_order_id("arb-abc"), "arbabc");
+    }
+
+    #[test]
+    fn test_to_contracts_requires_lot_multiple() {
+        let instrument: OkxResponse<OkxInstrument> = serde_json::from_str(INSTRUMENT_JSON).unwrap();
+        let instrument = &instrument.data[0];
+
+        // 0.0123 BTC / 0.01 = 1.23 계약 (lotSz 0.01의 배수)
+        assert_eq!(
+            to_contracts(Decimal::new(123, 4), instrument).unwrap(),
+            Decimal::new(123, 2)
+        );
+        // 0.00123 BTC = 0.123 계약 → lotSz 배수 아님
+        assert!(matches!(
+            to_contracts(Decimal::new(123, 5), instrument),
+            Err(ExchangeError::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn test_interval_to_okx() {
+        assert_eq!(interval_to_okx(CandleInterval::Minute1), "1m");
+        assert_eq!(interval_to_okx(CandleInterval::Minute60), "1H");
+        assert_eq!(interval_to_okx(CandleInterval::Day), "1Dutc");
+    }
+
+    #[test]
+    fn test_convert_order_scales_contracts() {
+        let resp: OkxResponse<OkxOrder> =
+            serde_json::from_str(&order_json("canceled", "2")).unwrap();
+        let order = convert_order(
+            resp.data.into_iter().next().unwrap(),
+            Decimal::new(1, 2),
+            Some("arb-x".to_string()),
+        );
+        // 5 계약 × 0.01 = 0.05 BTC, 2 계약 체결 후 취소 → 부분 체결
+        assert_eq!(order.volume, Decimal::new(5, 2));
+        assert_eq!(order.executed_volume, Decimal::new(2, 2));
+        assert_eq!(order.remaining_volume, Decimal::new(3, 2));
+        assert_eq!(order.status, OrderStatus::PartiallyFilled);
+        assert_eq!(order.paid_fee, Decimal::new(21, 3));
+        assert_eq!(order.market, "USDT-BTC");
+    }
+
+    #[tokio::test]
+    async fn test_get_instrument_info_in_contract_units() {
+        let mut server = mockito::Server::new_async().await;
+        mock_instrument(&mut server).await;
+
+        let client: OkxClient = mock_client(&server);
+        let info = client.get_instrument_info("BTCUSDT").await.unwrap();
+        assert_eq!(info.tick_size, Decimal::new(1, 1));
+        assert_eq!(info.qty_step, Decimal::new(1, 2));
+        assert_eq!(info.min_order_qty, Decimal::new(1, 2));
+        assert_eq!(info.contract_multiplier, Decimal::new(1, 2));
+    }
+
+    #[tokio::test]
+    async fn test_get_orderbook_converts_sizes_to_coin() {
+        let mut server = mockito::Server::new_async().await;
+        mock_instrument(&mut server).await;
+        server
+            .mock("GET", "/api/v5/market/books")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("instId".into(), "BTC-USDT-SWAP".into()),
+                Matcher::UrlEncoded("sz".into(), "5".into()),
+            ]))
+            .with_body(
+                r#"{"code": "0", "msg": "", "data": [{
+                    "asks": [["42001.0", "120", "0", "3"]],
+                    "bids": [["42000.9", "80", "0", "2"]],
+                    "ts": "1707177600000"}]}"#,
+            )
+            .create_async()
+            .await;
+
+        let client: OkxClient = mock_client(&server);
+        let book = client.get_orderbook("USDT-BTC", Some(5)).await.unwrap();
+        assert_eq!(book.asks[0].size, Decimal::new(12, 1)); // 120 계약 × 0.01
+        assert_eq!(book.total_bid_size, Decimal::new(8, 1));
+    }
+
+    #[tokio::test]
+    async fn test_place_order_linear_signed_request() {
+        let mut server = mockito::Server::new_async().await;
+        mock_instrument(&mut server).await;
+        let place = server
+            .mock("POST", "/api/v5/trade/order")
+            .match_header(HEADER_ACCESS_KEY, "test-key")
+            .match_header(HEADER_ACCESS_PASSPHRASE, "test-pass")
+            .match_header(
+                HEADER_ACCESS_SIGN,
+                Matcher::Regex("^[A-Za-z0-9+/]{43}=$".into()),
+            )
+            .match_header(
+                HEADER_ACCESS_TIMESTAMP,
+                Matcher::Regex(r"^\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}\.\d{3}Z$".into()),
+            )
+            .match_body(Matcher::PartialJsonString(
+                r#"{"instId": "BTC-USDT-SWAP", "tdMode": "cross", "side": "sell",
+                    "ordType": "ioc", "sz": "5", "px": "42000", "reduceOnly": true,
+                    "clOrdId": "0190a1b2c3d4e5f60718293a4b5c6d7e"}"#
+                    .into(),
+            ))
+            .with_body(
+                r#"{"code": "0", "msg": "", "data": [{"ordId": "312269865356374016",
+                    "clOrdId": "0190a1b2c3d4e5f60718293a4b5c6d7e", "sCode": "0", "sMsg": ""}]}"#,
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/api/v5/trade/order")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("instId".into(), "BTC-USDT-SWAP".into()),
+                Matcher::UrlEncoded("ordId".into(), "312269865356374016".into()),
+            ]))
+            .with_body(order_json("live", "0"))
+            .create_async()
+            .await;
+
+        let client: OkxClient = mock_client(&server);
+        // 0.05 BTC = 5 계약
+        let mut request =
+            OrderRequest::limit_sell("BTCUSDT", Decimal::new(42000, 0), Decimal::new(5, 2));
+        request.time_in_force = Some(TimeInForce::Ioc);
+        request.identifier = Some("arb-0190a1b2c3d4e5f60718293a4b5c6d7e".to_string());
+
+        let order = client.place_order_linear(&request, true).await.unwrap();
+        place.assert_async().await;
+        assert_eq!(order.id, "312269865356374016");
+        assert_eq!(order.status, OrderStatus::Wait);
+        assert_eq!(order.volume, Decimal::new(5, 2));
+        // 원래 client order ID로 복원
+        assert_eq!(
+            order.identifier.as_deref(),
+            Some("arb-0190a1b2c3d4e5f60718293a4b5c6d7e")
+        );
+        assert_eq!(
+            client.order_symbols.get("312269865356374016").as_deref(),
+            Some("BTC-USDT-SWAP")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_place_order_linear_maps_item_error() {
+        let mut server = mockito::Server::new_async().await;
+        mock_instrument(&mut server).await;
+        server
+            .mock("POST", "/api/v5/trade/order")
+            .with_body(
+                r#"{"code": "1", "msg": "Operation failed.", "data": [{"ordId": "",
+                    "clOrdId": "", "sCode": "51008", "sMsg": "Insufficient USDT margin"}]}"#,
+            )
+            .create_async()
+            .await;
+
+        let client: OkxClient = mock_client(&server);
+        let request = OrderRequest::market_sell("USDT-BTC", Decimal::new(1, 2));
+        let err = client
+            .place_order_linear(&request, false)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ExchangeError::InsufficientFunds(_)));
+    }
+
+    #[tokio::test]
+    async fn test_get_positions_linear_converts_contracts() {
+        let mut server = mockito::Server::new_async().await;
+        mock_instrument(&mut server).await;
+        server
+            .mock("GET", "/api/v5/account/positions")
+            .match_query(Matcher::UrlEncoded("instType".into(), "SWAP".into()))
+            .with_body(
+                r#"{"code": "0", "msg": "", "data": [
+                    {"instId": "BTC-USDT-SWAP", "pos": "-5", "posSide": "net",
+                     "avgPx": "42000", "lever": "2", "upl": "1.5", "liqPx": "60000"},
+                    {"instId": "ETH-USDT-SWAP", "pos": "0", "posSide": "net",
+                     "avgPx": "", "lever": "3", "upl": "0", "liqPx": ""}
+                ]}"#,
+            )
+            .create_async()
+            .await;
+
+        let client: OkxClient = mock_client(&server);
+        let positions = client.get_positions_linear("").await.unwrap();
+        assert_eq!(positions.len(), 1);
+        assert_eq!(positions[0].symbol, "BTCUSDT");
+        assert_eq!(positions[0].side, "Sell");
+        assert_eq!(positions[0].size, Decimal::new(5, 2));
+    }
+
+    #[tokio::test]
+    async fn test_get_tickers_linear_funding() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/v5/public/funding-rate")
+            .match_query(Matcher::UrlEncoded("instId".into(), "BTC-USDT-SWAP".into()))
+            .with_body(
+                r#"{"code": "0", "msg": "", "data": [{"instId": "BTC-USDT-SWAP",
+                    "fundingRate": "0.0001", "fundingTime": "1707206400000",
+                    "nextFundingTime": "1707235200000"}]}"#,
+            )
+            .create_async()
+            .await;
+
+        let client: OkxClient = mock_client(&server);
+        let funding = client.get_tickers_linear(Some("BTCUSDT")).await.unwrap();
+        assert_eq!(funding[0].symbol, "BTCUSDT");
+        assert!((funding[0].funding_rate - 0.0001).abs() < 1e-12);
+        assert_eq!(funding[0].next_funding_time, 1707206400000);
+        assert!(matches!(
+            client.get_tickers_linear(None).await,
+            Err(ExchangeError::Unsupported(_))
+        ));
+    }
+}
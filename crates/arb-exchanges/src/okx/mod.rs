@@ -0,0 +1,40 @@
+//! OKX 무기한 스왑 거래소 SDK 구현.
+//!
+//! 이 모듈은 Bybit linear의 대체 헤지 거래소로 사용할 수 있는
+//! OKX USDT 증거금 무기한 스왑(SWAP) 클라이언트를 제공합니다.
+//!
+//! # 기능
+//!
+//! - 시장 데이터 API: 시세(Ticker), 호가창, 캔들
+//! - 스왑 주문 API: 주문 생성(reduce-only 포함), 취소, 조회, 포지션 (인증 필요)
+//! - 계정 API: 거래 계정 잔고 (인증 필요)
+//! - HMAC-SHA256 + Base64 헤더 인증 (API 키, 시크릿, 패스프레이즈)
+//! - `bbo-tbt` WebSocket 실시간 best bid/ask
+//! - 계약 수 ↔ 코인 수량 변환 (상품별 `ctVal`)
+//!
+//! # 예제
+//!
+//! ```no_run
+//! use arb_exchanges::OkxClient;
+//! use arb_exchange::MarketData;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = OkxClient::new()?;
+//!
+//!     // 시세 조회 (공통 마켓 형식 QUOTE-BASE 사용)
+//!     let tickers = client.get_ticker(&["USDT-BTC"]).await?;
+//!     println!("BTC Price: {}", tickers[0].trade_price);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod auth;
+mod client;
+mod stream;
+mod types;
+
+pub use auth::OkxCredentials;
+pub use client::OkxClient;
+pub use types::*;
@@ -0,0 +1,518 @@
+//! OKX 무기한 스왑 WebSocket 실시간 마켓 데이터 스트림 구현.
+//!
+//! `MarketStream` trait을 구현하여 OKX의 `bbo-tbt` (tick-by-tick best bid/ask)
+//! 데이터를 WebSocket으로 실시간 수신합니다.
+
+use arb_exchange::error::{ExchangeError, ExchangeResult};
+use arb_exchange::stream::{MarketEvent, MarketStream, StreamCommand, StreamConfig};
+use async_trait::async_trait;
+use chrono::{TimeZone, Utc};
+use futures_util::{SinkExt, StreamExt};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Mutex, mpsc};
+use tokio_tungstenite::connect_async;
+use tokio_tungstenite::tungstenite::Message;
+use tracing::{debug, error, info, trace, warn};
+
+use crate::clock::ServerClock;
+use crate::okx::client::OkxClient;
+
+/// OKX 공개 WebSocket URL.
+const OKX_WS_PUBLIC_URL: &str = "wss://ws.okx.com:8443/ws/v5/public";
+
+/// 구독 채널 이름.
+const BBO_CHANNEL: &str = "bbo-tbt";
+
+/// heartbeat 주기. OKX는 30초간 메시지가 없으면 연결을 끊습니다.
+const PING_INTERVAL: Duration = Duration::from_secs(20);
+
+/// OKX 채널 푸시 메시지.
+#[derive(Debug, Deserialize)]
+struct OkxPush {
+    arg: OkxPushArg,
+    data: Vec<OkxBbo>,
+}
+
+/// 푸시 메시지의 채널 정보.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OkxPushArg {
+    channel: String,
+    inst_id: String,
+}
+
+/// `bbo-tbt` 데이터 (`[가격, 수량, 0, 주문 수]` 레벨 1개씩).
+#[derive(Debug, Deserialize)]
+struct OkxBbo {
+    asks: Vec<Vec<String>>,
+    bids: Vec<Vec<String>>,
+    ts: String,
+}
+
+/// WebSocket task의 내부 상태.
+struct StreamState {
+    /// 구독 해제 시그널을 보내는 sender.
+    shutdown_tx: Option<tokio::sync::oneshot::Sender<()>>,
+    /// WebSocket task join handle.
+    task_handle: Option<tokio::task::JoinHandle<()>>,
+    /// 동적 구독 변경 명령을 보내는 sender.
+    command_tx: Option<mpsc::Sender<StreamCommand>>,
+}
+
+/// OKX MarketStream 구현을 위한 내부 상태.
+pub(crate) struct OkxStreamInner {
+    state: Mutex<Option<StreamState>>,
+    config: StreamConfig,
+}
+
+impl OkxStreamInner {
+    pub(crate) fn new(config: StreamConfig) -> Self {
+        Self {
+            state: Mutex::new(None),
+            config,
+        }
+    }
+}
+
+#[async_trait]
+impl MarketStream for OkxClient {
+    fn stream_name(&self) -> &str {
+        "OKX"
+    }
+
+    async fn subscribe(&self, markets: &[&str]) -> ExchangeResult<mpsc::Receiver<MarketEvent>> {
+        let inner = self.stream_inner();
+
+        // 기존 구독이 있으면 먼저 해제
+        {
+            let mut state_guard = inner.state.lock().await;
+            if let Some(old_state) = state_guard.take() {
+                if let Some(tx) = old_state.shutdown_tx {
+                    let _ = tx.send(());
+                }
+                if let Some(handle) = old_state.task_handle {
+                    handle.abort();
+                }
+                debug!("기존 OKX WebSocket 구독 해제");
+            }
+        }
+
+        let buffer_size = inner.config.channel_buffer_size;
+        let (event_tx, event_rx) = mpsc::channel(buffer_size);
+        let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel();
+        let (command_tx, command_rx) = mpsc::channel::<StreamCommand>(64);
+
+        let inst_ids: Vec<String> = markets.iter().map(|m| OkxClient::to_inst_id(m)).collect();
+        let config = inner.config.clone();
+        let clock = Arc::clone(&self.clock);
+
+        info!(inst_ids = ?inst_ids, "OKX WebSocket 구독 시작");
+
+        let task_handle = tokio::spawn(async move {
+            okx_ws_loop(inst_ids, event_tx, shutdown_rx, command_rx, config, clock).await;
+        });
+
+        let mut state_guard = inner.state.lock().await;
+        *state_guard = Some(StreamState {
+            shutdown_tx: Some(shutdown_tx),
+            task_handle: Some(task_handle),
+            command_tx: Some(command_tx),
+        });
+
+        Ok(event_rx)
+    }
+
+    async fn unsubscribe(&self) -> ExchangeResult<()> {
+        let inner = self.stream_inner();
+        let mut state_guard = inner.state.lock().await;
+
+        if let Some(state) = state_guard.take() {
+            info!("OKX WebSocket 구독 해제");
+            if let Some(tx) = state.shutdown_tx {
+                let _ = tx.send(());
+            }
+            if let Some(handle) = state.task_handle {
+                handle.abort();
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn subscribe_markets(&self, markets: &[&str]) -> ExchangeResult<()> {
+        self.send_stream_command(StreamCommand::Subscribe(
+            markets.iter().map(|m| m.to_string()).collect(),
+        ))
+        .await
+    }
+
+    async fn unsubscribe_markets(&self, markets: &[&str]) -> ExchangeResult<()> {
+        self.send_stream_command(StreamCommand::Unsubscribe(
+            markets.iter().map(|m| m.to_string()).collect(),
+        ))
+        .await
+    }
+}
+
+impl OkxClient {
+    /// 실행 중인 WebSocket 루프에 동적 구독 명령을 전달합니다.
+    async fn send_stream_command(&self, command: StreamCommand) -> ExchangeResult<()> {
+        let state_guard = self.stream_inner().state.lock().await;
+        let tx = state_guard
+            .as_ref()
+            .and_then(|state| state.command_tx.as_ref())
+            .ok_or_else(|| ExchangeError::WebSocketError("not subscribed".into()))?;
+        tx.send(command)
+            .await
+            .map_err(|_| ExchangeError::WebSocketError("command channel closed".into()))
+    }
+}
+
+/// 신규 구독 대상 상품 ID만 추출하고 현재 목록에 반영합니다.
+fn build_subscribe_inst_ids(symbols: &[String], current: &mut Vec<String>) -> Vec<String> {
+    let mut subscribe = Vec::new();
+    for symbol in symbols {
+        let inst_id = OkxClient::to_inst_id(symbol);
+        if !current.contains(&inst_id) {
+            current.push(inst_id.clone());
+            subscribe.push(inst_id);
+        }
+    }
+    subscribe
+}
+
+/// 현재 구독 중인 상품 ID만 해제 대상으로 추출하고 현재 목록에서 제거합니다.
+fn build_unsubscribe_inst_ids(symbols: &[String], current: &mut Vec<String>) -> Vec<String> {
+    let remove: Vec<String> = symbols
+        .iter()
+        .map(|s| OkxClient::to_inst_id(s))
+        .filter(|inst_id| current.contains(inst_id))
+        .collect();
+
+    if !remove.is_empty() {
+        current.retain(|inst_id| !remove.contains(inst_id));
+    }
+
+    remove
+}
+
+/// subscribe/unsubscribe 요청 메시지를 만듭니다.
+fn build_request(op: &str, inst_ids: &[String]) -> String {
+    let args: Vec<serde_json::Value> = inst_ids
+        .iter()
+        .map(|inst_id| serde_json::json!({ "channel": BBO_CHANNEL, "instId": inst_id }))
+        .collect();
+    serde_json::json!({ "op": op, "args": args }).to_string()
+}
+
+/// OKX WebSocket 이벤트 루프 (재연결 + 동적 구독 + heartbeat 포함).
+///
+/// OKX는 애플리케이션 레벨 heartbeat를 요구하므로 주기적으로 텍스트 "ping"을 보냅니다.
+async fn okx_ws_loop(
+    initial_inst_ids: Vec<String>,
+    event_tx: mpsc::Sender<MarketEvent>,
+    mut shutdown_rx: tokio::sync::oneshot::Receiver<()>,
+    mut command_rx: mpsc::Receiver<StreamCommand>,
+    config: StreamConfig,
+    clock: Arc<ServerClock>,
+) {
+    let mut retry_count: u32 = 0;
+    let mut backoff = config.initial_backoff;
+    // 현재 구독 중인 상품 ID 목록 (재연결 시 사용)
+    let mut current_inst_ids = initial_inst_ids;
+
+    loop {
+        // 종료 확인
+        if shutdown_rx.try_recv().is_ok() {
+            info!("OKX WebSocket 종료 요청");
+            break;
+        }
+
+        match connect_and_subscribe(&current_inst_ids).await {
+            Ok(ws_stream) => {
+                info!("OKX WebSocket 연결 성공");
+                retry_count = 0;
+                backoff = config.initial_backoff;
+
+                let (mut write, mut read) = ws_stream.split();
+                let mut ping_interval = tokio::time::interval(PING_INTERVAL);
+                // 첫 tick은 즉시 발생하므로 소비
+                ping_interval.tick().await;
+
+                loop {
+                    tokio::select! {
+                        _ = &mut shutdown_rx => {
+                            info!("OKX WebSocket 종료 요청 (루프 내)");
+                            let _ = write.close().await;
+                            return;
+                        }
+                        _ = ping_interval.tick() => {
+                            if let Err(e) = write.send(Message::Text("ping".into())).await {
+                                error!(error = %e, "OKX heartbeat 전송 실패");
+                                break;
+                            }
+                        }
+                        cmd = command_rx.recv() => {
+                            let (op, inst_ids) = match cmd {
+                                Some(StreamCommand::Subscribe(symbols)) => {
+                                    ("subscribe", build_subscribe_inst_ids(&symbols, &mut current_inst_ids))
+                                }
+                                Some(StreamCommand::Unsubscribe(symbols)) => {
+                                    ("unsubscribe", build_unsubscribe_inst_ids(&symbols, &mut current_inst_ids))
+                                }
+                                None => {
+                                    debug!("OKX command 채널 닫힘");
+                                    continue;
+                                }
+                            };
+
+                            if inst_ids.is_empty() {
+                                debug!(op, "OKX 동적 구독 변경 스킵: 변경 대상 없음");
+                                continue;
+                            }
+
+                            info!(op, inst_ids = ?inst_ids, "OKX 동적 구독 변경");
+                            let msg = build_request(op, &inst_ids);
+                            if let Err(e) = write.send(Message::Text(msg.into())).await {
+                                error!(error = %e, op, "OKX 구독 메시지 전송 실패");
+                                break;
+                            }
+                        }
+                        msg = read.next() => {
+                            match msg {
+                                Some(Ok(Message::Text(text))) => {
+                                    for event in parse_okx_bbo(&text) {
+                                        clock.observe_feed_latency(event.timestamp().timestamp_millis());
+                                        match event_tx.try_send(event) {
+                                            Ok(()) => {
+                                                trace!("OKX 이벤트 전송 성공");
+                                            }
+                                            Err(mpsc::error::TrySendError::Full(_)) => {
+                                                warn!("OKX 이벤트 채널 가득 참 — 이벤트 드롭");
+                                            }
+                                            Err(mpsc::error::TrySendError::Closed(_)) => {
+                                                debug!("OKX 이벤트 채널 닫힘 — 종료");
+                                                return;
+                                            }
+                                        }
+                                    }
+                                }
+                                Some(Ok(Message::Ping(data))) => {
+                                    let _ = write.send(Message::Pong(data)).await;
+                                }
+                                Some(Ok(Message::Close(_))) => {
+                                    warn!("OKX WebSocket 서버에서 Close 수신");
+                                    break;
+                                }
+                                Some(Err(e)) => {
+                                    error!(error = %e, "OKX WebSocket 수신 에러");
+                                    break;
+                                }
+                                None => {
+                                    warn!("OKX WebSocket 스트림 종료");
+                                    break;
+                                }
+                                _ => {}
+                            }
+                        }
+                    }
+                }
+            }
+            Err(e) => {
+                error!(error = %e, "OKX WebSocket 연결 실패");
+            }
+        }
+
+        // 재연결 로직
+        retry_count += 1;
+        arb_metrics::global()
+            .counter(
+                "arb_ws_reconnects_total",
+                "WebSocket 재연결 시도 횟수",
+                &[("exchange", "okx")],
+            )
+            .inc();
+        if config.max_retries > 0 && retry_count > config.max_retries {
+            error!(
+                retries = retry_count,
+                "OKX WebSocket 최대 재시도 초과 — 스트림 종료"
+            );
+            break;
+        }
+
+        warn!(
+            retry = retry_count,
+            backoff_ms = backoff.as_millis(),
+            "OKX WebSocket 재연결 대기"
+        );
+
+        tokio::select! {
+            _ = &mut shutdown_rx => {
+                info!("OKX WebSocket 재연결 대기 중 종료 요청");
+                return;
+            }
+            _ = tokio::time::sleep(backoff) => {}
+        }
+
+        // exponential backoff (최대값 제한)
+        backoff = std::cmp::min(backoff * 2, config.max_backoff);
+    }
+}
+
+/// OKX WebSocket에 연결하고 구독 메시지를 보냅니다.
+async fn connect_and_subscribe(
+    inst_ids: &[String],
+) -> Result<
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>,
+    Box<dyn std::error::Error + Send + Sync>,
+> {
+    let (mut ws_stream, response) = connect_async(OKX_WS_PUBLIC_URL).await?;
+
+    debug!(status = ?response.status(), "OKX WebSocket 핸드셰이크 완료");
+
+    if !inst_ids.is_empty() {
+        // 구독 메시지: {"op": "subscribe", "args": [{"channel": "bbo-tbt", "instId": "BTC-USDT-SWAP"}]}
+        let subscribe_msg = build_request("subscribe", inst_ids);
+        debug!(msg = %subscribe_msg, "OKX 구독 메시지 전송");
+        ws_stream.send(Message::Text(subscribe_msg.into())).await?;
+    }
+
+    Ok(ws_stream)
+}
+
+/// OKX WebSocket 메시지를 MarketEvent::BestQuote 목록으로 파싱합니다.
+///
+/// heartbeat 응답("pong")과 구독/에러 이벤트(`{"event": ...}`)는 빈 목록을 반환합니다.
+/// 이벤트의 market은 Bybit 형식 심볼("BTCUSDT")입니다.
+fn parse_okx_bbo(text: &str) -> Vec<MarketEvent> {
+    let Ok(value) = serde_json::from_str::<serde_json::Value>(text) else {
+        return Vec::new();
+    };
+
+    if let Some(event) = value.get("event").and_then(|e| e.as_str()) {
+        if event == "error" {
+            warn!(msg = %value, "OKX WebSocket 요청 실패");
+        }
+        return Vec::new();
+    }
+
+    let Ok(push) = serde_json::from_value::<OkxPush>(value) else {
+        return Vec::new();
+    };
+    if push.arg.channel != BBO_CHANNEL {
+        return Vec::new();
+    }
+
+    let market = OkxClient::to_symbol(&push.arg.inst_id);
+    let best_price = |levels: &[Vec<String>]| -> Option<Decimal> {
+        levels
+            .first()
+            .and_then(|level| level.first())
+            .and_then(|px| Decimal::from_str(px).ok())
+    };
+
+    push.data
+        .iter()
+        .filter_map(|bbo| {
+            let bid = best_price(&bbo.bids)?;
+            let ask = best_price(&bbo.asks)?;
+            let timestamp = bbo
+                .ts
+                .parse::<i64>()
+                .ok()
+                .and_then(|ts| Utc.timestamp_millis_opt(ts).single())
+                .unwrap_or_else(Utc::now);
+            Some(MarketEvent::BestQuote {
+                market: market.clone(),
+                bid,
+                ask,
+                timestamp,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_okx_bbo() {
+        let json = r#"{
+            "arg": {"channel": "bbo-tbt", "instId": "BTC-USDT-SWAP"},
+            "data": [{
+                "asks": [["99501.0", "415", "0", "13"]],
+                "bids": [["99500.5", "92", "0", "5"]],
+                "ts": "1707177600000",
+                "seqId": 123
+            }]
+        }"#;
+
+        let events = parse_okx_bbo(json);
+        assert_eq!(events.len(), 1);
+        if let MarketEvent::BestQuote {
+            market,
+            bid,
+            ask,
+            timestamp,
+        } = &events[0]
+        {
+            assert_eq!(market, "BTCUSDT");
+            assert_eq!(*bid, Decimal::from_str("99500.5").unwrap());
+            assert_eq!(*ask, Decimal::from_str("99501.0").unwrap());
+            assert_eq!(timestamp.timestamp_millis(), 1707177600000);
+        } else {
+            panic!("Expected BestQuote event");
+        }
+    }
+
+    #[test]
+    fn test_parse_okx_pong_and_events_ignored() {
+        assert!(parse_okx_bbo("pong").is_empty());
+        let subscribed = r#"{"event": "subscribe",
+            "arg": {"channel": "bbo-tbt", "instId": "BTC-USDT-SWAP"}, "connId": "a4d3ae55"}"#;
+        assert!(parse_okx_bbo(subscribed).is_empty());
+        let error = r#"{"event": "error", "code": "60012", "msg": "Invalid request"}"#;
+        assert!(parse_okx_bbo(error).is_empty());
+    }
+
+    #[test]
+    fn test_parse_okx_empty_book_side_skipped() {
+        let json = r#"{"arg": {"channel": "bbo-tbt", "instId": "BTC-USDT-SWAP"},
+            "data": [{"asks": [], "bids": [["1", "1", "0", "1"]], "ts": "1707177600000"}]}"#;
+        assert!(parse_okx_bbo(json).is_empty());
+    }
+
+    #[test]
+    fn test_build_subscribe_inst_ids_dedup() {
+        let mut current = vec!["BTC-USDT-SWAP".to_string()];
+        let symbols = vec!["BTCUSDT".to_string(), "ETHUSDT".to_string()];
+
+        let subscribe = build_subscribe_inst_ids(&symbols, &mut current);
+        assert_eq!(subscribe, vec!["ETH-USDT-SWAP".to_string()]);
+        assert_eq!(current.len(), 2);
+    }
+
+    #[test]
+    fn test_build_unsubscribe_inst_ids_only_existing() {
+        let mut current = vec!["BTC-USDT-SWAP".to_string(), "ETH-USDT-SWAP".to_string()];
+        let symbols = vec!["BTCUSDT".to_string(), "XRPUSDT".to_string()];
+
+        let remove = build_unsubscribe_inst_ids(&symbols, &mut current);
+        assert_eq!(remove, vec!["BTC-USDT-SWAP".to_string()]);
+        assert_eq!(current, vec!["ETH-USDT-SWAP".to_string()]);
+    }
+
+    #[test]
+    fn test_build_request() {
+        let msg = build_request("subscribe", &["BTC-USDT-SWAP".to_string()]);
+        let value: serde_json::Value = serde_json::from_str(&msg).unwrap();
+        assert_eq!(value["op"], "subscribe");
+        assert_eq!(value["args"][0]["channel"], "bbo-tbt");
+        assert_eq!(value["args"][0]["instId"], "BTC-USDT-SWAP");
+    }
+}
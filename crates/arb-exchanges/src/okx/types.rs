@@ -0,0 +1,377 @@
+//! OKX V5 무기한 스왑 전용 타입 및 API 응답 구조체.
+//!
+//! OKX는 모든 숫자를 문자열로 반환하며, 응답은 `{"code", "msg", "data": [...]}`
+//! 형식으로 감싸져 있습니다. 수량(`sz`, `pos`, `lotSz` 등)은 계약 수 단위입니다.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer};
+
+/// OKX API 공통 응답 래퍼.
+///
+/// ```json
+/// {"code": "0", "msg": "", "data": [...]}
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct OkxResponse<T> {
+    /// 결과 코드 ("0" = 성공, "1"/"2" = 일괄 요청의 일부/전체 실패).
+    pub code: String,
+    /// 에러 메시지.
+    #[serde(default)]
+    pub msg: String,
+    /// 결과 데이터.
+    #[serde(default = "Vec::new")]
+    pub data: Vec<T>,
+}
+
+/// 주문 계열 응답의 항목별 결과 코드.
+///
+/// `code`가 "1"이면 실제 원인은 항목의 `sCode`/`sMsg`에 있습니다.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxItemStatus {
+    /// 항목 결과 코드 ("0" = 성공).
+    #[serde(default)]
+    pub s_code: String,
+    /// 항목 에러 메시지.
+    #[serde(default)]
+    pub s_msg: String,
+}
+
+/// OKX 서버 시각 (`/api/v5/public/time`).
+#[derive(Debug, Deserialize)]
+pub struct OkxServerTime {
+    /// 서버 시각 (밀리초).
+    #[serde(deserialize_with = "deserialize_i64_string")]
+    pub ts: i64,
+}
+
+/// OKX 티커 (`/api/v5/market/ticker`, `/api/v5/market/tickers`).
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxTicker {
+    /// 상품 ID (예: "BTC-USDT-SWAP").
+    pub inst_id: String,
+    /// 최근 체결가.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub last: Decimal,
+    /// 24시간 시가.
+    #[serde(rename = "open24h", deserialize_with = "deserialize_decimal_string")]
+    pub open_24h: Decimal,
+    /// 24시간 최고가.
+    #[serde(rename = "high24h", deserialize_with = "deserialize_decimal_string")]
+    pub high_24h: Decimal,
+    /// 24시간 최저가.
+    #[serde(rename = "low24h", deserialize_with = "deserialize_decimal_string")]
+    pub low_24h: Decimal,
+    /// 24시간 거래량 (코인 단위).
+    #[serde(rename = "volCcy24h", deserialize_with = "deserialize_decimal_string")]
+    pub vol_ccy_24h: Decimal,
+    /// 데이터 생성 시각 (밀리초).
+    #[serde(deserialize_with = "deserialize_i64_string")]
+    pub ts: i64,
+}
+
+/// OKX 호가창 (`/api/v5/market/books`).
+#[derive(Debug, Deserialize)]
+pub struct OkxBooks {
+    /// 매도 호가 [가격, 수량(계약), 0, 주문 수].
+    pub asks: Vec<OkxBookLevel>,
+    /// 매수 호가 [가격, 수량(계약), 0, 주문 수].
+    pub bids: Vec<OkxBookLevel>,
+    /// 호가창 생성 시각 (밀리초).
+    #[serde(deserialize_with = "deserialize_i64_string")]
+    pub ts: i64,
+}
+
+/// OKX 호가 레벨 `[가격, 수량, 폐지 필드, 주문 수]`.
+#[derive(Debug)]
+pub struct OkxBookLevel {
+    /// 가격.
+    pub price: Decimal,
+    /// 수량 (계약 수).
+    pub size: Decimal,
+}
+
+impl<'de> Deserialize<'de> for OkxBookLevel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let arr: Vec<String> = Vec::deserialize(deserializer)?;
+        if arr.len() < 2 {
+            return Err(serde::de::Error::custom("book level array too short"));
+        }
+        Ok(Self {
+            price: arr[0].parse().map_err(serde::de::Error::custom)?,
+            size: arr[1].parse().map_err(serde::de::Error::custom)?,
+        })
+    }
+}
+
+/// OKX 캔들 (`/api/v5/market/candles`, `/api/v5/market/history-candles`).
+///
+/// API는 `[ts, o, h, l, c, vol, volCcy, volCcyQuote, confirm]` 배열을 최신순으로 반환합니다.
+#[derive(Debug)]
+pub struct OkxCandle {
+    /// 시작 시각 (밀리초).
+    pub ts: i64,
+    /// 시가.
+    pub open: Decimal,
+    /// 고가.
+    pub high: Decimal,
+    /// 저가.
+    pub low: Decimal,
+    /// 종가.
+    pub close: Decimal,
+    /// 거래량 (코인 단위, `volCcy`).
+    pub volume: Decimal,
+}
+
+impl<'de> Deserialize<'de> for OkxCandle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let arr: Vec<String> = Vec::deserialize(deserializer)?;
+        if arr.len() < 7 {
+            return Err(serde::de::Error::custom("candle array too short"));
+        }
+        let parse = |s: &str| s.parse::<Decimal>().map_err(serde::de::Error::custom);
+
+        Ok(Self {
+            ts: arr[0].parse().map_err(serde::de::Error::custom)?,
+            open: parse(&arr[1])?,
+            high: parse(&arr[2])?,
+            low: parse(&arr[3])?,
+            close: parse(&arr[4])?,
+            volume: parse(&arr[6])?,
+        })
+    }
+}
+
+/// OKX 상품 정보 (`/api/v5/public/instruments`).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxInstrument {
+    /// 상품 ID (예: "BTC-USDT-SWAP").
+    pub inst_id: String,
+    /// 계약 1개당 코인 수량 (예: BTC-USDT-SWAP = 0.01).
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub ct_val: Decimal,
+    /// 호가 단위.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub tick_sz: Decimal,
+    /// 주문 수량 단위 (계약 수).
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub lot_sz: Decimal,
+    /// 최소 주문 수량 (계약 수).
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub min_sz: Decimal,
+    /// 지정가 최대 주문 수량 (계약 수).
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub max_lmt_sz: Decimal,
+    /// 상품 상태 ("live", "suspend", "preopen" 등).
+    #[serde(default)]
+    pub state: String,
+}
+
+/// 주문 생성/취소 응답 항목.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxOrderAck {
+    /// 주문 ID.
+    pub ord_id: String,
+    /// client order ID.
+    #[serde(default)]
+    pub cl_ord_id: String,
+}
+
+/// OKX 주문 (`/api/v5/trade/order`, `/api/v5/trade/orders-pending`).
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxOrder {
+    /// 주문 ID.
+    pub ord_id: String,
+    /// client order ID (빈 문자열 = 없음).
+    #[serde(default)]
+    pub cl_ord_id: String,
+    /// 상품 ID.
+    pub inst_id: String,
+    /// 주문 가격 (시장가는 빈 문자열).
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub px: Decimal,
+    /// 주문 수량 (계약 수).
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub sz: Decimal,
+    /// 주문 유형 ("limit", "market", "post_only", "ioc", "fok").
+    pub ord_type: String,
+    /// 주문 방향 ("buy" / "sell").
+    pub side: String,
+    /// 주문 상태 ("live", "partially_filled", "filled", "canceled", "mmp_canceled").
+    pub state: String,
+    /// 누적 체결 수량 (계약 수).
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub acc_fill_sz: Decimal,
+    /// 평균 체결가 (미체결 시 None).
+    #[serde(default, deserialize_with = "deserialize_optional_decimal_string")]
+    pub avg_px: Option<Decimal>,
+    /// 수수료 (음수 = 지불, 양수 = 리베이트).
+    #[serde(default, deserialize_with = "deserialize_decimal_string")]
+    pub fee: Decimal,
+    /// 생성 시각 (밀리초).
+    #[serde(deserialize_with = "deserialize_i64_string")]
+    pub c_time: i64,
+}
+
+/// OKX 계정 잔고 (`/api/v5/account/balance`).
+#[derive(Debug, Deserialize)]
+pub struct OkxAccountBalance {
+    /// 통화별 상세.
+    #[serde(default)]
+    pub details: Vec<OkxBalanceDetail>,
+}
+
+/// OKX 통화별 잔고.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxBalanceDetail {
+    /// 통화 (예: "USDT").
+    pub ccy: String,
+    /// 자산 평가액 (미실현 손익 포함).
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub eq: Decimal,
+    /// 사용 가능 잔고.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub avail_bal: Decimal,
+    /// 동결 잔고 (주문/증거금).
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub frozen_bal: Decimal,
+    /// 미실현 손익.
+    #[serde(default, deserialize_with = "deserialize_decimal_string")]
+    pub upl: Decimal,
+}
+
+/// OKX 포지션 (`/api/v5/account/positions`).
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxPosition {
+    /// 상품 ID.
+    pub inst_id: String,
+    /// 포지션 수량 (계약 수, net 모드에서는 부호가 방향).
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub pos: Decimal,
+    /// 포지션 방향 ("net", "long", "short").
+    #[serde(default)]
+    pub pos_side: String,
+    /// 평균 진입가.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub avg_px: Decimal,
+    /// 레버리지.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub lever: Decimal,
+    /// 미실현 손익.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub upl: Decimal,
+    /// 예상 청산 가격.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub liq_px: Decimal,
+}
+
+/// OKX 펀딩레이트 (`/api/v5/public/funding-rate`).
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxFundingRate {
+    /// 상품 ID.
+    pub inst_id: String,
+    /// 현재 펀딩레이트.
+    #[serde(deserialize_with = "deserialize_decimal_string")]
+    pub funding_rate: Decimal,
+    /// 이번 펀딩 정산 시각 (밀리초).
+    #[serde(deserialize_with = "deserialize_i64_string")]
+    pub funding_time: i64,
+}
+
+/// 문자열에서 Decimal로 역직렬화.
+fn deserialize_decimal_string<'de, D>(deserializer: D) -> Result<Decimal, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    if s.is_empty() {
+        return Ok(Decimal::ZERO);
+    }
+    s.parse::<Decimal>().map_err(serde::de::Error::custom)
+}
+
+/// 문자열에서 Optional Decimal로 역직렬화 (빈 문자열과 0은 None).
+fn deserialize_optional_decimal_string<'de, D>(deserializer: D) -> Result<Option<Decimal>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let opt: Option<String> = Option::deserialize(deserializer)?;
+    match opt {
+        Some(s) if !s.is_empty() => {
+            let value = s.parse::<Decimal>().map_err(serde::de::Error::custom)?;
+            Ok((!value.is_zero()).then_some(value))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// 문자열에서 i64로 역직렬화 (빈 문자열은 0).
+fn deserialize_i64_string<'de, D>(deserializer: D) -> Result<i64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    if s.is_empty() {
+        return Ok(0);
+    }
+    s.parse::<i64>().map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_okx_candle() {
+        let json = r#"["1597026383085", "3.721", "3.743", "3.677", "3.708",
+            "8422410", "22698348.04828491", "12698348.04828491", "1"]"#;
+        let candle: OkxCandle = serde_json::from_str(json).unwrap();
+        assert_eq!(candle.ts, 1597026383085);
+        assert_eq!(candle.close, Decimal::new(3708, 3));
+        // 계약 수(vol)가 아닌 코인 수량(volCcy)을 사용
+        assert_eq!(candle.volume, Decimal::new(2269834804828491, 8));
+    }
+
+    #[test]
+    fn test_deserialize_okx_books() {
+        let json = r#"{"asks": [["41006.8", "0.60038921", "0", "1"]],
+            "bids": [["41006.3", "0.30178218", "0", "2"]], "ts": "1629966436396"}"#;
+        let books: OkxBooks = serde_json::from_str(json).unwrap();
+        assert_eq!(books.asks[0].price, Decimal::new(410068, 1));
+        assert_eq!(books.bids[0].size, Decimal::new(30178218, 8));
+        assert_eq!(books.ts, 1629966436396);
+    }
+
+    #[test]
+    fn test_deserialize_okx_order_empty_fields() {
+        let json = r#"{"ordId": "312269865356374016", "clOrdId": "", "instId": "BTC-USDT-SWAP",
+            "px": "", "sz": "3", "ordType": "market", "side": "sell", "state": "live",
+            "accFillSz": "0", "avgPx": "", "fee": "0", "cTime": "1597026383085"}"#;
+        let order: OkxOrder = serde_json::from_str(json).unwrap();
+        assert_eq!(order.px, Decimal::ZERO);
+        assert_eq!(order.avg_px, None);
+        assert!(order.cl_ord_id.is_empty());
+    }
+
+    #[test]
+    fn test_deserialize_okx_error_response() {
+        let json = r#"{"code": "1", "msg": "Operation failed.",
+            "data": [{"clOrdId": "", "ordId": "", "sCode": "51008", "sMsg": "Insufficient balance"}]}"#;
+        let resp: OkxResponse<OkxItemStatus> = serde_json::from_str(json).unwrap();
+        assert_eq!(resp.code, "1");
+        assert_eq!(resp.data[0].s_code, "51008");
+    }
+}
@@ -0,0 +1,102 @@
+//! 주문 키 캐시.
+//!
+//! Binance/OKX/Coinone/Korbit 주문 조회·취소는 심볼(마켓)이 필수이므로 발주 시 주문 ID와
+//! client order ID별 심볼을 기억해 둡니다. 클라이언트 복제본들이 같은 캐시를 공유합니다.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// 주문 키 → 값 캐시 (용량 초과 시 FIFO 축출).
+#[derive(Clone)]
+pub(crate) struct OrderKeyCache {
+    inner: Arc<Mutex<Entries>>,
+    capacity: usize,
+}
+
+#[derive(Default)]
+struct Entries {
+    values: HashMap<String, String>,
+    insertion_order: VecDeque<String>,
+}
+
+impl OrderKeyCache {
+    /// 최대 `capacity`개 항목을 보관하는 캐시를 생성합니다.
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(Entries::default())),
+            capacity,
+        }
+    }
+
+    /// 키의 값을 기록합니다. 가장 오래된 키부터 축출합니다.
+    pub(crate) fn insert(&self, key: &str, value: &str) {
+        let mut entries = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        if entries
+            .values
+            .insert(key.to_string(), value.to_string())
+            .is_none()
+        {
+            entries.insertion_order.push_back(key.to_string());
+        }
+        while entries.insertion_order.len() > self.capacity {
+            if let Some(oldest) = entries.insertion_order.pop_front() {
+                entries.values.remove(&oldest);
+            }
+        }
+    }
+
+    /// 키의 값을 반환합니다.
+    pub(crate) fn get(&self, key: &str) -> Option<String> {
+        self.inner
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .values
+            .get(key)
+            .cloned()
+    }
+
+    /// 캐시에 기록된 서로 다른 값 목록을 정렬해 반환합니다.
+    pub(crate) fn distinct_values(&self) -> Vec<String> {
+        let entries = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut values: Vec<String> = entries.values.values().cloned().collect();
+        values.sort();
+        values.dedup();
+        values
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order_key_cache_evicts_oldest() {
+        let cache = OrderKeyCache::new(2);
+        cache.insert("1", "BTCUSDT");
+        cache.insert("2", "ETHUSDT");
+        cache.insert("1", "BTCUSDT");
+        cache.insert("3", "XRPUSDT");
+
+        assert_eq!(cache.get("1"), None);
+        assert_eq!(cache.get("2").as_deref(), Some("ETHUSDT"));
+        assert_eq!(cache.get("3").as_deref(), Some("XRPUSDT"));
+    }
+
+    #[test]
+    fn test_order_key_cache_shared_between_clones() {
+        let cache = OrderKeyCache::new(4);
+        let clone = cache.clone();
+        clone.insert("arb-1", "BTCUSDT");
+        assert_eq!(cache.get("arb-1").as_deref(), Some("BTCUSDT"));
+    }
+
+    #[test]
+    fn test_order_key_cache_distinct_values() {
+        let cache = OrderKeyCache::new(4);
+        cache.insert("1", "xrp_krw");
+        cache.insert("arb-1", "xrp_krw");
+        cache.insert("2", "btc_krw");
+
+        assert_eq!(cache.distinct_values(), vec!["btc_krw", "xrp_krw"]);
+    }
+}
@@ -0,0 +1,231 @@
+//! 거래소 어댑터 공통 테스트 헬퍼.
+//!
+//! mock 서버용 클라이언트 생성과, 어댑터마다 같은 형태로 반복되는 검증
+//! (클라이언트 생성, 심볼 변환, 에러 코드 매핑, 서버 시각 동기화, 심볼 미확정 주문 조회)을
+//! 한곳에 모읍니다. 거래소 고유 동작(OKX 계약 단위 변환, Coinone IOC 에뮬레이션,
+//! Korbit 심볼 fallback 등)은 각 `client.rs`의 테스트에 둡니다.
+
+use arb_exchange::{ExchangeError, MarketData};
+
+/// mock 서버 테스트를 지원하는 어댑터 클라이언트.
+///
+/// 비공개 생성자/에러 파서를 노출하지 않도록 각 `client.rs`의 테스트 모듈에서 구현합니다.
+pub(crate) trait MockClient: MarketData + Sized {
+    /// 자격증명 없는 기본 클라이언트를 생성합니다.
+    fn public() -> Self;
+
+    /// 테스트 자격증명으로 `base_url`에 접속하는 클라이언트를 생성합니다.
+    fn with_mock_url(base_url: &str) -> Self;
+
+    /// 자격증명 보유 여부.
+    fn has_credentials(&self) -> bool;
+
+    /// 에러 응답 본문과 HTTP 상태를 ExchangeError로 변환합니다.
+    fn parse_error(body: &str, status: u16) -> ExchangeError;
+}
+
+/// 내부 마켓 코드(`QUOTE-BASE`)와 거래소 심볼 간 변환.
+pub(crate) trait VenueSymbol {
+    /// 마켓 코드 → 거래소 심볼.
+    fn to_venue_symbol(market: &str) -> String;
+
+    /// 거래소 심볼 → 마켓 코드.
+    fn from_venue_symbol(symbol: &str) -> String;
+}
+
+/// mock 서버에 접속하는 테스트 클라이언트를 생성합니다.
+pub(crate) fn mock_client<C: MockClient>(server: &mockito::ServerGuard) -> C {
+    C::with_mock_url(&server.url())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::ServerTimeSync;
+    use crate::{BinanceClient, CoinoneClient, KorbitClient, OkxClient};
+    use arb_exchange::LinearOrderManagement;
+    use mockito::Matcher;
+    use std::mem::discriminant;
+
+    /// 기대하는 에러 종류 (메시지는 비교하지 않음).
+    type Kind = fn(String) -> ExchangeError;
+
+    fn unknown(message: String) -> ExchangeError {
+        ExchangeError::UnknownError {
+            code: String::new(),
+            message,
+        }
+    }
+
+    fn assert_client_new<C: MockClient>(name: &str) {
+        let client = C::public();
+        assert!(!client.has_credentials());
+        assert_eq!(client.name(), name);
+        assert!(C::with_mock_url("http://127.0.0.1:1").has_credentials());
+    }
+
+    fn assert_symbol_round_trip<C: VenueSymbol>(market: &str, symbol: &str) {
+        assert_eq!(C::to_venue_symbol(market), symbol);
+        assert_eq!(C::from_venue_symbol(symbol), market);
+    }
+
+    fn assert_error_kinds<C: MockClient>(cases: &[(String, u16, Kind)]) {
+        for (body, status, kind) in cases {
+            let err = C::parse_error(body, *status);
+            assert_eq!(
+                discriminant(&err),
+                discriminant(&kind(String::new())),
+                "{body} ({status}) → {err:?}"
+            );
+        }
+    }
+
+    async fn assert_sync_server_clock<C: MockClient + ServerTimeSync>(path: &str, body: &str) {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", path)
+            .with_body(body)
+            .create_async()
+            .await;
+
+        let client = mock_client::<C>(&server);
+        let sample = client.sync_server_clock().await.unwrap();
+        assert!(client.server_clock().is_synced());
+        assert!(sample.offset_ms < 0);
+        mock.assert_async().await;
+    }
+
+    async fn assert_unknown_symbol_order<C: MockClient + LinearOrderManagement>(
+        open_orders_path: &str,
+        empty_body: &str,
+    ) {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", open_orders_path)
+            .match_query(Matcher::Any)
+            .with_body(empty_body)
+            .create_async()
+            .await;
+
+        let client = mock_client::<C>(&server);
+        let err = client.get_order_linear("123").await.unwrap_err();
+        // 부재를 확정할 수 없으므로 재발주를 유발하는 OrderNotFound가 아니어야 함
+        assert!(matches!(err, ExchangeError::InvalidParameter(_)));
+        mock.assert_async().await;
+    }
+
+    #[test]
+    fn test_client_new() {
+        assert_client_new::<BinanceClient>("Binance");
+        assert_client_new::<OkxClient>("OKX");
+        assert_client_new::<CoinoneClient>("Coinone");
+        assert_client_new::<KorbitClient>("Korbit");
+    }
+
+    #[test]
+    fn test_market_code() {
+        assert_eq!(BinanceClient::market_code("eth", "usdt"), "ETHUSDT");
+        assert_eq!(OkxClient::market_code("eth", "usdt"), "ETHUSDT");
+        assert_eq!(CoinoneClient::market_code("btc", "krw"), "KRW-BTC");
+        assert_eq!(KorbitClient::market_code("BTC", "KRW"), "btc_krw");
+    }
+
+    #[test]
+    fn test_symbol_conversion() {
+        assert_symbol_round_trip::<BinanceClient>("USDT-BTC", "BTCUSDT");
+        assert_symbol_round_trip::<BinanceClient>("USDT-1000PEPE", "1000PEPEUSDT");
+        assert_symbol_round_trip::<OkxClient>("USDT-ETH", "ETH-USDT-SWAP");
+        assert_symbol_round_trip::<KorbitClient>("KRW-BTC", "btc_krw");
+    }
+
+    #[test]
+    fn test_parse_error_maps_codes() {
+        let binance = |code: i64| format!(r#"{{"code": {code}, "msg": "error"}}"#);
+        assert_error_kinds::<BinanceClient>(&[
+            (binance(-2015), 401, ExchangeError::AuthError),
+            (binance(-2019), 400, ExchangeError::InsufficientFunds),
+            (binance(-2013), 400, ExchangeError::OrderNotFound),
+            (binance(-1121), 400, ExchangeError::MarketNotFound),
+            (binance(-1111), 400, ExchangeError::InvalidParameter),
+            // 418: 제한 위반 반복으로 IP 차단 (JSON 본문 없음)
+            (
+                "<html>banned</html>".into(),
+                418,
+                ExchangeError::RateLimitExceeded,
+            ),
+        ]);
+
+        let okx = |code: &str| format!(r#"{{"code": "{code}", "msg": "error", "data": []}}"#);
+        assert_error_kinds::<OkxClient>(&[
+            (okx("50113"), 401, ExchangeError::AuthError),
+            (okx("51603"), 200, ExchangeError::OrderNotFound),
+            (okx("51001"), 200, ExchangeError::MarketNotFound),
+            // 항목별 sCode가 상위 code보다 우선
+            (
+                r#"{"code": "1", "msg": "Operation failed.", "data": [
+                    {"ordId": "", "clOrdId": "", "sCode": "51008", "sMsg": "Insufficient"}]}"#
+                    .into(),
+                200,
+                ExchangeError::InsufficientFunds,
+            ),
+            (
+                "Too Many Requests".into(),
+                429,
+                ExchangeError::RateLimitExceeded,
+            ),
+        ]);
+
+        let coinone = |code: &str| {
+            format!(r#"{{"result": "error", "error_code": "{code}", "error_msg": "error"}}"#)
+        };
+        assert_error_kinds::<CoinoneClient>(&[
+            (coinone("12"), 200, ExchangeError::AuthError),
+            (coinone("103"), 200, ExchangeError::InsufficientFunds),
+            (coinone("104"), 200, ExchangeError::OrderNotFound),
+            (coinone("999"), 200, unknown),
+        ]);
+
+        let korbit = |code: &str| {
+            format!(r#"{{"success": false, "error": {{"code": "{code}", "message": "error"}}}}"#)
+        };
+        assert_error_kinds::<KorbitClient>(&[
+            (korbit("INVALID_SIGNATURE"), 401, ExchangeError::AuthError),
+            (
+                korbit("NOT_ENOUGH_BALANCE"),
+                400,
+                ExchangeError::InsufficientFunds,
+            ),
+            (korbit("ORDER_NOT_FOUND"), 404, ExchangeError::OrderNotFound),
+            (korbit("SOMETHING_ELSE"), 400, unknown),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn test_sync_server_clock() {
+        assert_sync_server_clock::<BinanceClient>(
+            "/fapi/v1/time",
+            r#"{"serverTime": 1707177600000}"#,
+        )
+        .await;
+        assert_sync_server_clock::<OkxClient>(
+            "/api/v5/public/time",
+            r#"{"code": "0", "msg": "", "data": [{"ts": "1707177600000"}]}"#,
+        )
+        .await;
+        assert_sync_server_clock::<KorbitClient>(
+            "/v2/time",
+            r#"{"success": true, "data": {"time": 1707177600000}}"#,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_get_order_linear_unknown_symbol_is_not_order_not_found() {
+        assert_unknown_symbol_order::<BinanceClient>("/fapi/v1/openOrders", "[]").await;
+        assert_unknown_symbol_order::<OkxClient>(
+            "/api/v5/trade/orders-pending",
+            r#"{"code": "0", "msg": "", "data": []}"#,
+        )
+        .await;
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! 이 모듈은 Upbit API 인증을 위한 JWT 토큰 생성을 처리합니다.
 
+use arb_config::SecretString;
 use arb_exchange::ExchangeError;
 use jsonwebtoken::{Algorithm, EncodingKey, Header, encode};
 use serde::Serialize;
@@ -23,7 +24,7 @@ struct JwtPayload {
 #[derive(Clone)]
 pub struct UpbitCredentials {
     access_key: String,
-    secret_key: String,
+    secret_key: SecretString,
 }
 
 impl std::fmt::Debug for UpbitCredentials {
@@ -50,7 +51,7 @@ impl UpbitCredentials {
     pub fn new(access_key: impl Into<String>, secret_key: impl Into<String>) -> Self {
         Self {
             access_key: access_key.into(),
-            secret_key: secret_key.into(),
+            secret_key: SecretString::new(secret_key),
         }
     }
 
@@ -100,7 +101,7 @@ impl UpbitCredentials {
     fn encode_token(&self, payload: &JwtPayload) -> Result<String, ExchangeError> {
         // Upbit 인증 가이드 기준 HS512를 사용합니다.
         let header = Header::new(Algorithm::HS512);
-        let key = EncodingKey::from_secret(self.secret_key.as_bytes());
+        let key = EncodingKey::from_secret(self.secret_key.expose_secret().as_bytes());
 
         encode(&header, payload, &key).map_err(|e| ExchangeError::AuthError(e.to_string()))
     }
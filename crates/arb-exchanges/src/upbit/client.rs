@@ -8,6 +8,8 @@ use arb_exchange::{
     StreamConfig, Ticker, TimeInForce,
 };
 
+use crate::clock::{ClockSample, ServerClock, ServerTimeSync, local_now_ms};
+use crate::credentials::CredentialSlot;
 use crate::rate_limit::RateLimiter;
 use crate::upbit::auth::{UpbitCredentials, build_query_string};
 use crate::upbit::stream::UpbitStreamInner;
@@ -47,9 +49,12 @@ const UPBIT_EMERGENCY_BURST: u32 = 5;
 
 pub struct UpbitClient {
     client: Client,
-    pub(crate) credentials: Option<UpbitCredentials>,
+    /// 인증 정보. 복제본 간에 공유되어 [`Self::set_credentials`]로 교체됩니다.
+    credentials: CredentialSlot<UpbitCredentials>,
     /// WebSocket 스트림 내부 상태.
     pub(crate) stream: Arc<UpbitStreamInner>,
+    /// 서버 시계 추정기 (시계 오차 감시, 피드 지연 측정).
+    pub(crate) clock: Arc<ServerClock>,
     /// Quotation API (시세 조회) 레이트 리밋터.
     quotation_limiter: Arc<RateLimiter>,
     /// Exchange API (주문/계좌) 레이트 리밋터.
@@ -63,7 +68,7 @@ pub struct UpbitClient {
 impl std::fmt::Debug for UpbitClient {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("UpbitClient")
-            .field("credentials", &self.credentials.is_some())
+            .field("credentials", &self.credentials.is_set())
             .finish()
     }
 }
@@ -78,6 +83,7 @@ impl Clone for UpbitClient {
             client: self.client.clone(),
             credentials: self.credentials.clone(),
             stream: Arc::clone(&self.stream),
+            clock: Arc::clone(&self.clock),
             quotation_limiter: Arc::clone(&self.quotation_limiter),
             exchange_limiter: Arc::clone(&self.exchange_limiter),
             emergency_limiter: Arc::clone(&self.emergency_limiter),
@@ -101,8 +107,9 @@ impl UpbitClient {
 
         Ok(Self {
             client,
-            credentials: None,
+            credentials: CredentialSlot::new(None),
             stream: Arc::new(UpbitStreamInner::new(StreamConfig::default())),
+            clock: Arc::new(ServerClock::new("upbit")),
             quotation_limiter: Arc::new(RateLimiter::new(
                 "upbit-quotation",
                 UPBIT_QUOTATION_RATE_LIMIT,
@@ -144,8 +151,9 @@ impl UpbitClient {
 
         Ok(Self {
             client,
-            credentials: Some(UpbitCredentials::new(access_key, secret_key)),
+            credentials: CredentialSlot::new(Some(UpbitCredentials::new(access_key, secret_key))),
             stream: Arc::new(UpbitStreamInner::new(StreamConfig::default())),
+            clock: Arc::new(ServerClock::new("upbit")),
             quotation_limiter: Arc::new(RateLimiter::new(
                 "upbit-quotation",
                 UPBIT_QUOTATION_RATE_LIMIT,
@@ -170,10 +178,26 @@ impl UpbitClient {
     }
 
     /// 인증 정보가 있으면 반환합니다.
-    fn credentials(&self) -> ExchangeResult<&UpbitCredentials> {
+    fn credentials(&self) -> ExchangeResult<Arc<UpbitCredentials>> {
+        self.credentials.get()
+    }
+
+    /// 실행 중 API 키를 교체합니다 (키 로테이션).
+    ///
+    /// 이 클라이언트의 모든 복제본에 즉시 반영되며, 이미 서명된 요청은 이전 키로 완료됩니다.
+    ///
+    /// # 인자
+    ///
+    /// * `access_key` - Upbit API 액세스 키
+    /// * `secret_key` - Upbit API 시크릿 키
+    pub fn set_credentials(&self, access_key: impl Into<String>, secret_key: impl Into<String>) {
         self.credentials
-            .as_ref()
-            .ok_or_else(|| ExchangeError::AuthError("Credentials not provided".to_string()))
+            .set(UpbitCredentials::new(access_key, secret_key));
+    }
+
+    /// 인증 정보가 설정되어 있으면 true를 반환합니다.
+    pub fn has_credentials(&self) -> bool {
+        self.credentials.is_set()
     }
 
     /// 공개 엔드포인트에 GET 요청을 보냅니다.
@@ -330,6 +354,7 @@ impl UpbitClient {
                 ExchangeError::InsufficientFunds(message.clone())
             }
             (_, "notfoundmarket") => ExchangeError::MarketNotFound(message.clone()),
+            (_, "order_not_found") => ExchangeError::OrderNotFound(message.clone()),
             (403, "market_offline") => ExchangeError::ExchangeOffline(message.clone()),
             (429, _) | (418, _) => ExchangeError::RateLimitExceeded(message.clone()),
             _ => ExchangeError::UnknownError {
@@ -340,6 +365,47 @@ impl UpbitClient {
     }
 }
 
+impl ServerTimeSync for UpbitClient {
+    fn server_clock(&self) -> &ServerClock {
+        &self.clock
+    }
+
+    /// Upbit은 서버 시각 API가 없어 시세 응답의 HTTP `Date` 헤더로 probe합니다.
+    ///
+    /// `Date` 헤더는 초 단위이므로 해당 초의 중간(+500ms)을 서버 시각으로 간주하며,
+    /// 추정 오차는 최대 ±500ms입니다.
+    async fn sync_server_clock(&self) -> ExchangeResult<ClockSample> {
+        self.quotation_limiter.acquire().await;
+        let url = format!("{BASE_URL}/ticker");
+        let sent_ms = local_now_ms();
+        let response = self
+            .client
+            .get(&url)
+            .query(&[("markets", "KRW-BTC")])
+            .send()
+            .await
+            .map_err(ExchangeError::HttpError)?;
+        let received_ms = local_now_ms();
+
+        let date = response
+            .headers()
+            .get(reqwest::header::DATE)
+            .and_then(|v| v.to_str().ok())
+            .ok_or_else(|| ExchangeError::ParseError("missing Upbit Date header".to_string()))?;
+        let server_ms = parse_http_date_ms(date).ok_or_else(|| {
+            ExchangeError::ParseError(format!("invalid Upbit Date header: {date}"))
+        })?;
+
+        Ok(self.clock.record_probe(sent_ms, server_ms, received_ms))
+    }
+}
+
+/// HTTP `Date` 헤더(RFC 7231 IMF-fixdate)를 초 중간 시각(밀리초)으로 변환합니다.
+fn parse_http_date_ms(value: &str) -> Option<i64> {
+    let parsed = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(parsed.timestamp() * 1000 + 500)
+}
+
 impl MarketData for UpbitClient {
     fn name(&self) -> &str {
         "Upbit"
@@ -550,6 +616,23 @@ impl OrderManagement for UpbitClient {
         Ok(order)
     }
 
+    async fn get_order_by_client_id(&self, client_order_id: &str) -> ExchangeResult<Order> {
+        debug!(client_order_id, "Upbit client order id 주문 조회 요청");
+
+        let params = [("identifier", client_order_id)];
+        let upbit_order: UpbitOrder = self.get_private("/order", Some(&params)).await?;
+        let order = convert_order(upbit_order);
+
+        debug!(
+            client_order_id,
+            order_id = %order.id,
+            status = ?order.status,
+            executed = %order.executed_volume,
+            "Upbit client order id 주문 조회 결과"
+        );
+        Ok(order)
+    }
+
     async fn get_open_orders(&self, market: Option<&str>) -> ExchangeResult<Vec<Order>> {
         debug!(market = ?market, "Upbit 미체결 주문 조회");
 
@@ -653,8 +736,8 @@ fn convert_orderbook(ob: UpbitOrderbook) -> OrderBook {
         .collect();
 
     // 매도호가는 오름차순, 매수호가는 내림차순으로 정렬
-    asks.sort_by(|a, b| a.price.cmp(&b.price));
-    bids.sort_by(|a, b| b.price.cmp(&a.price));
+    asks.sort_by_key(|a| a.price);
+    bids.sort_by_key(|b| std::cmp::Reverse(b.price));
 
     OrderBook {
         market: ob.market,
@@ -742,12 +825,22 @@ mod tests {
     use super::*;
     use rust_decimal::Decimal;
 
+    #[test]
+    fn test_parse_http_date_ms() {
+        // 2023-01-01T00:00:00Z
+        assert_eq!(
+            parse_http_date_ms("Sun, 01 Jan 2023 00:00:00 GMT"),
+            Some(1_672_531_200_500)
+        );
+        assert_eq!(parse_http_date_ms("not a date"), None);
+    }
+
     #[test]
     fn test_upbit_client_new() {
         let client = UpbitClient::new();
         assert!(client.is_ok());
         let client = client.unwrap();
-        assert!(client.credentials.is_none());
+        assert!(!client.has_credentials());
     }
 
     #[test]
@@ -755,7 +848,20 @@ mod tests {
         let client = UpbitClient::with_credentials("access_key", "secret_key");
         assert!(client.is_ok());
         let client = client.unwrap();
-        assert!(client.credentials.is_some());
+        assert!(client.has_credentials());
+    }
+
+    #[test]
+    fn test_upbit_client_set_credentials_shared_with_clones() {
+        let client = UpbitClient::new().unwrap();
+        let cloned = client.clone();
+        assert!(!cloned.has_credentials());
+
+        client.set_credentials("rotated_access", "rotated_secret");
+        assert!(cloned.has_credentials());
+        let debug = format!("{:?}", cloned.credentials().unwrap());
+        assert!(debug.contains("rota****"));
+        assert!(!debug.contains("rotated_secret"));
     }
 
     #[test]
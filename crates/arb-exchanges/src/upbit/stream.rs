@@ -11,11 +11,13 @@ use futures_util::{SinkExt, StreamExt};
 use rust_decimal::Decimal;
 use serde::Deserialize;
 use std::str::FromStr;
+use std::sync::Arc;
 use tokio::sync::{Mutex, mpsc};
 use tokio_tungstenite::connect_async;
 use tokio_tungstenite::tungstenite::Message;
 use tracing::{debug, error, info, trace, warn};
 
+use crate::clock::ServerClock;
 use crate::upbit::client::UpbitClient;
 
 /// Upbit WebSocket URL.
@@ -93,6 +95,7 @@ impl MarketStream for UpbitClient {
 
         let market_codes: Vec<String> = markets.iter().map(|m| m.to_string()).collect();
         let config = inner.config.clone();
+        let clock = Arc::clone(&self.clock);
 
         info!(
             markets = ?market_codes,
@@ -100,7 +103,15 @@ impl MarketStream for UpbitClient {
         );
 
         let task_handle = tokio::spawn(async move {
-            upbit_ws_loop(market_codes, event_tx, shutdown_rx, command_rx, config).await;
+            upbit_ws_loop(
+                market_codes,
+                event_tx,
+                shutdown_rx,
+                command_rx,
+                config,
+                clock,
+            )
+            .await;
         });
 
         let mut state_guard = inner.state.lock().await;
@@ -176,6 +187,7 @@ async fn upbit_ws_loop(
     mut shutdown_rx: tokio::sync::oneshot::Receiver<()>,
     mut command_rx: mpsc::Receiver<StreamCommand>,
     config: StreamConfig,
+    clock: Arc<ServerClock>,
 ) {
     let mut retry_count: u32 = 0;
     let mut backoff = config.initial_backoff;
@@ -251,6 +263,7 @@ async fn upbit_ws_loop(
                             match msg {
                                 Some(Ok(Message::Text(text))) => {
                                     if let Some(event) = parse_upbit_trade(&text) {
+                                        clock.observe_feed_latency(event.timestamp().timestamp_millis());
                                         // backpressure: try_send로 버퍼 가득 찬 경우 드롭
                                         match event_tx.try_send(event) {
                                             Ok(()) => {
@@ -271,6 +284,7 @@ async fn upbit_ws_loop(
                                     if let Ok(text) = String::from_utf8(data.to_vec())
                                         && let Some(event) = parse_upbit_trade(&text)
                                     {
+                                        clock.observe_feed_latency(event.timestamp().timestamp_millis());
                                         match event_tx.try_send(event) {
                                             Ok(()) => {}
                                             Err(mpsc::error::TrySendError::Full(_)) => {
@@ -310,6 +324,13 @@ async fn upbit_ws_loop(
 
         // 재연결 로직
         retry_count += 1;
+        arb_metrics::global()
+            .counter(
+                "arb_ws_reconnects_total",
+                "WebSocket 재연결 시도 횟수",
+                &[("exchange", "upbit")],
+            )
+            .inc();
         if config.max_retries > 0 && retry_count > config.max_retries {
             error!(
                 retries = retry_count,
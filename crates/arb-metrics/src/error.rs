@@ -0,0 +1,19 @@
+//! 메트릭 exporter 에러 타입.
+
+use thiserror::Error;
+
+/// 메트릭 exporter 에러.
+#[derive(Debug, Error)]
+pub enum MetricsError {
+    /// 리스닝 주소 파싱/바인딩 실패.
+    #[error("Failed to bind metrics listener on {addr}: {reason}")]
+    Bind {
+        /// 요청한 리스닝 주소.
+        addr: String,
+        /// 실패 사유.
+        reason: String,
+    },
+    /// 소켓 I/O 에러.
+    #[error("Metrics I/O error: {0}")]
+    Io(#[from] std::io::Error),
+}
@@ -0,0 +1,46 @@
+//! # Metrics Module
+//!
+//! 라이브 트레이딩 내부 상태를 Prometheus 형식으로 노출하는 모듈입니다.
+//!
+//! - [`Registry`]: 카운터/게이지/히스토그램 및 scrape 시점 수집기 등록
+//! - [`HttpServer`]: 임베디드 HTTP 서버 (`GET /metrics` 및 JSON 상태 라우트)
+//! - [`global`]: 프로세스 전역 레지스트리 (각 크레이트가 직접 계측할 때 사용)
+//!
+//! ## 사용 예시
+//!
+//! ```rust,no_run
+//! use arb_metrics::{HttpServer, LATENCY_BUCKETS};
+//!
+//! # async fn run() -> Result<(), arb_metrics::MetricsError> {
+//! arb_metrics::global()
+//!     .counter("arb_ws_reconnects_total", "WebSocket 재연결 횟수", &[("exchange", "upbit")])
+//!     .inc();
+//! arb_metrics::global()
+//!     .histogram("arb_demo_seconds", "데모 지연", &[], LATENCY_BUCKETS)
+//!     .observe(0.012);
+//!
+//! let server = HttpServer::bind("127.0.0.1:9100")
+//!     .await?
+//!     .metrics(arb_metrics::global());
+//! server.run(std::future::pending()).await;
+//! # Ok(())
+//! # }
+//! ```
+
+mod error;
+mod registry;
+mod server;
+
+pub use error::MetricsError;
+pub use registry::{Counter, Gauge, Histogram, LATENCY_BUCKETS, Registry, Sampler};
+pub use server::{HttpResponse, HttpServer};
+
+use std::sync::OnceLock;
+
+/// 프로세스 전역 레지스트리.
+static GLOBAL: OnceLock<Registry> = OnceLock::new();
+
+/// 프로세스 전역 레지스트리를 반환합니다.
+pub fn global() -> &'static Registry {
+    GLOBAL.get_or_init(Registry::new)
+}
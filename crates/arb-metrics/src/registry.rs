@@ -0,0 +1,554 @@
+//! 메트릭 타입, 레지스트리, Prometheus text exposition 인코딩.
+//!
+//! 핫패스에서 호출되는 `inc`/`set`/`observe`는 모두 lock-free atomic 연산입니다.
+//! 레지스트리 lock은 메트릭 최초 등록과 scrape 시점에만 잡습니다.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use tracing::warn;
+
+/// 지연 시간(초) 히스토그램 기본 버킷 (1ms ~ 10s).
+pub const LATENCY_BUCKETS: &[f64] = &[
+    0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// 단조 증가 카운터.
+#[derive(Debug, Default)]
+pub struct Counter(AtomicU64);
+
+impl Counter {
+    /// 1 증가.
+    pub fn inc(&self) {
+        self.inc_by(1);
+    }
+
+    /// `n`만큼 증가.
+    pub fn inc_by(&self, n: u64) {
+        self.0.fetch_add(n, Ordering::Relaxed);
+    }
+
+    /// 현재 값.
+    pub fn get(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// 임의 값으로 설정 가능한 게이지 (f64 비트를 atomic에 저장).
+#[derive(Debug, Default)]
+pub struct Gauge(AtomicU64);
+
+impl Gauge {
+    /// 값 설정.
+    pub fn set(&self, value: f64) {
+        self.0.store(value.to_bits(), Ordering::Relaxed);
+    }
+
+    /// 현재 값에 `delta`를 더합니다 (음수 가능).
+    pub fn add(&self, delta: f64) {
+        atomic_f64_add(&self.0, delta);
+    }
+
+    /// 현재 값.
+    pub fn get(&self) -> f64 {
+        f64::from_bits(self.0.load(Ordering::Relaxed))
+    }
+}
+
+/// 누적 버킷 히스토그램.
+#[derive(Debug)]
+pub struct Histogram {
+    /// 버킷 상한 (오름차순, `+Inf` 제외).
+    bounds: Vec<f64>,
+    /// 버킷별 관측 수 (비누적). 인코딩 시 누적합으로 변환합니다.
+    buckets: Vec<AtomicU64>,
+    /// 전체 관측 수.
+    count: AtomicU64,
+    /// 관측값 합계 (f64 비트).
+    sum: AtomicU64,
+}
+
+impl Histogram {
+    /// 버킷 상한 목록으로 히스토그램을 생성합니다.
+    ///
+    /// 유한하지 않은 값과 중복은 제거하고 오름차순으로 정렬합니다.
+    pub fn new(bounds: &[f64]) -> Self {
+        let mut bounds: Vec<f64> = bounds.iter().copied().filter(|b| b.is_finite()).collect();
+        bounds.sort_by(f64::total_cmp);
+        bounds.dedup();
+        let buckets = bounds.iter().map(|_| AtomicU64::new(0)).collect();
+        Self {
+            bounds,
+            buckets,
+            count: AtomicU64::new(0),
+            sum: AtomicU64::new(0f64.to_bits()),
+        }
+    }
+
+    /// 값 하나를 관측합니다.
+    pub fn observe(&self, value: f64) {
+        let idx = self.bounds.partition_point(|b| *b < value);
+        if let Some(bucket) = self.buckets.get(idx) {
+            bucket.fetch_add(1, Ordering::Relaxed);
+        }
+        self.count.fetch_add(1, Ordering::Relaxed);
+        atomic_f64_add(&self.sum, value);
+    }
+
+    /// 경과 시간을 초 단위로 관측합니다.
+    pub fn observe_duration(&self, elapsed: Duration) {
+        self.observe(elapsed.as_secs_f64());
+    }
+
+    /// 전체 관측 수.
+    pub fn count(&self) -> u64 {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    /// 관측값 합계.
+    pub fn sum(&self) -> f64 {
+        f64::from_bits(self.sum.load(Ordering::Relaxed))
+    }
+
+    /// `(상한, 누적 관측 수)` 목록 (`+Inf` 제외).
+    pub fn cumulative_buckets(&self) -> Vec<(f64, u64)> {
+        let mut acc = 0;
+        self.bounds
+            .iter()
+            .zip(&self.buckets)
+            .map(|(bound, bucket)| {
+                acc += bucket.load(Ordering::Relaxed);
+                (*bound, acc)
+            })
+            .collect()
+    }
+}
+
+/// f64 비트를 담은 atomic에 CAS 루프로 값을 더합니다.
+fn atomic_f64_add(cell: &AtomicU64, delta: f64) {
+    let _ = cell.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |bits| {
+        Some((f64::from_bits(bits) + delta).to_bits())
+    });
+}
+
+/// 메트릭 종류 (`# TYPE` 라인).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MetricKind {
+    Counter,
+    Gauge,
+    Histogram,
+}
+
+impl MetricKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Counter => "counter",
+            Self::Gauge => "gauge",
+            Self::Histogram => "histogram",
+        }
+    }
+}
+
+/// 레이블 조합 하나에 대응하는 시계열.
+#[derive(Debug)]
+enum Series {
+    Counter(Arc<Counter>),
+    Gauge(Arc<Gauge>),
+    Histogram(Arc<Histogram>),
+}
+
+/// 같은 이름을 공유하는 시계열 묶음.
+#[derive(Debug)]
+struct Family {
+    help: String,
+    kind: MetricKind,
+    /// 렌더링된 레이블 문자열(`a="b",c="d"`) → 시계열.
+    series: BTreeMap<String, Series>,
+}
+
+/// scrape 시점에 값을 읽어 오는 콜백.
+type CollectorFn = Box<dyn Fn(&mut Sampler) + Send + Sync>;
+
+/// 메트릭 레지스트리.
+///
+/// 두 가지 방식으로 값을 노출합니다.
+/// - 직접 계측: [`Registry::counter`]/[`Registry::gauge`]/[`Registry::histogram`]이
+///   돌려준 핸들을 보관하고 이벤트마다 갱신.
+/// - 수집기: 이미 다른 구조체(`MonitoringCounters`, `RiskManager` 등)가 들고 있는
+///   값을 [`Registry::register_collector`] 콜백에서 scrape 시점에 읽어 옴.
+#[derive(Default)]
+pub struct Registry {
+    families: RwLock<BTreeMap<String, Family>>,
+    collectors: RwLock<BTreeMap<String, CollectorFn>>,
+}
+
+impl std::fmt::Debug for Registry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let collectors: Vec<String> = self
+            .collectors
+            .read()
+            .map(|c| c.keys().cloned().collect())
+            .unwrap_or_default();
+        f.debug_struct("Registry")
+            .field("families", &self.families)
+            .field("collectors", &collectors)
+            .finish()
+    }
+}
+
+impl Registry {
+    /// 빈 레지스트리 생성.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 카운터 핸들을 가져오거나 새로 등록합니다.
+    pub fn counter(&self, name: &str, help: &str, labels: &[(&str, &str)]) -> Arc<Counter> {
+        let series = self.get_or_insert(name, help, MetricKind::Counter, labels, || {
+            Series::Counter(Arc::default())
+        });
+        match series {
+            Some(Series::Counter(c)) => c,
+            _ => Arc::default(),
+        }
+    }
+
+    /// 게이지 핸들을 가져오거나 새로 등록합니다.
+    pub fn gauge(&self, name: &str, help: &str, labels: &[(&str, &str)]) -> Arc<Gauge> {
+        let series = self.get_or_insert(name, help, MetricKind::Gauge, labels, || {
+            Series::Gauge(Arc::default())
+        });
+        match series {
+            Some(Series::Gauge(g)) => g,
+            _ => Arc::default(),
+        }
+    }
+
+    /// 히스토그램 핸들을 가져오거나 새로 등록합니다.
+    ///
+    /// 이미 등록된 시계열이면 `buckets`는 무시하고 기존 핸들을 돌려줍니다.
+    pub fn histogram(
+        &self,
+        name: &str,
+        help: &str,
+        labels: &[(&str, &str)],
+        buckets: &[f64],
+    ) -> Arc<Histogram> {
+        let series = self.get_or_insert(name, help, MetricKind::Histogram, labels, || {
+            Series::Histogram(Arc::new(Histogram::new(buckets)))
+        });
+        match series {
+            Some(Series::Histogram(h)) => h,
+            _ => Arc::new(Histogram::new(buckets)),
+        }
+    }
+
+    /// scrape 시점 수집기를 등록합니다. 같은 `key`로 다시 등록하면 교체됩니다.
+    pub fn register_collector<F>(&self, key: &str, collector: F)
+    where
+        F: Fn(&mut Sampler) + Send + Sync + 'static,
+    {
+        let mut collectors = self.collectors.write().unwrap_or_else(|e| e.into_inner());
+        collectors.insert(key.to_string(), Box::new(collector));
+    }
+
+    /// 수집기를 제거합니다. 존재했으면 true.
+    pub fn unregister_collector(&self, key: &str) -> bool {
+        let mut collectors = self.collectors.write().unwrap_or_else(|e| e.into_inner());
+        collectors.remove(key).is_some()
+    }
+
+    /// 전체 메트릭을 Prometheus text exposition format(0.0.4)으로 인코딩합니다.
+    pub fn encode(&self) -> String {
+        let mut out = String::new();
+
+        {
+            let families = self.families.read().unwrap_or_else(|e| e.into_inner());
+            for (name, family) in families.iter() {
+                write_header(&mut out, name, &family.help, family.kind);
+                for (labels, series) in &family.series {
+                    match series {
+                        Series::Counter(c) => write_sample(&mut out, name, labels, c.get() as f64),
+                        Series::Gauge(g) => write_sample(&mut out, name, labels, g.get()),
+                        Series::Histogram(h) => write_histogram(&mut out, name, labels, h),
+                    }
+                }
+            }
+        }
+
+        let mut sampler = Sampler::default();
+        {
+            let collectors = self.collectors.read().unwrap_or_else(|e| e.into_inner());
+            for collector in collectors.values() {
+                collector(&mut sampler);
+            }
+        }
+        for (name, family) in &sampler.families {
+            write_header(&mut out, name, &family.help, family.kind);
+            for (labels, value) in &family.samples {
+                write_sample(&mut out, name, labels, *value);
+            }
+        }
+
+        out
+    }
+
+    /// 이름+레이블로 시계열을 조회하고 없으면 생성합니다.
+    ///
+    /// 같은 이름이 다른 종류로 이미 등록되어 있으면 None (호출자는 노출되지 않는
+    /// 분리된 핸들을 돌려줍니다).
+    fn get_or_insert(
+        &self,
+        name: &str,
+        help: &str,
+        kind: MetricKind,
+        labels: &[(&str, &str)],
+        make: impl FnOnce() -> Series,
+    ) -> Option<Series> {
+        let key = render_labels(labels);
+        let mut families = self.families.write().unwrap_or_else(|e| e.into_inner());
+        let family = families.entry(name.to_string()).or_insert_with(|| Family {
+            help: help.to_string(),
+            kind,
+            series: BTreeMap::new(),
+        });
+        if family.kind != kind {
+            warn!(
+                metric = name,
+                registered = family.kind.as_str(),
+                requested = kind.as_str(),
+                "메트릭 종류 불일치 — 노출되지 않는 핸들 반환"
+            );
+            return None;
+        }
+        let series = family.series.entry(key).or_insert_with(make);
+        Some(match series {
+            Series::Counter(c) => Series::Counter(Arc::clone(c)),
+            Series::Gauge(g) => Series::Gauge(Arc::clone(g)),
+            Series::Histogram(h) => Series::Histogram(Arc::clone(h)),
+        })
+    }
+}
+
+/// 수집기 콜백이 scrape 시점 값을 기록하는 버퍼.
+#[derive(Debug, Default)]
+pub struct Sampler {
+    families: BTreeMap<String, SampledFamily>,
+}
+
+#[derive(Debug)]
+struct SampledFamily {
+    help: String,
+    kind: MetricKind,
+    samples: Vec<(String, f64)>,
+}
+
+impl Sampler {
+    /// 게이지 샘플 기록.
+    pub fn gauge(&mut self, name: &str, help: &str, labels: &[(&str, &str)], value: f64) {
+        self.push(name, help, MetricKind::Gauge, labels, value);
+    }
+
+    /// 카운터 샘플 기록 (외부 구조체가 누적한 단조 증가 값).
+    pub fn counter(&mut self, name: &str, help: &str, labels: &[(&str, &str)], value: u64) {
+        self.push(name, help, MetricKind::Counter, labels, value as f64);
+    }
+
+    fn push(
+        &mut self,
+        name: &str,
+        help: &str,
+        kind: MetricKind,
+        labels: &[(&str, &str)],
+        value: f64,
+    ) {
+        let family = self
+            .families
+            .entry(name.to_string())
+            .or_insert_with(|| SampledFamily {
+                help: help.to_string(),
+                kind,
+                samples: Vec::new(),
+            });
+        if family.kind != kind {
+            warn!(metric = name, "수집기 메트릭 종류 불일치 — 샘플 무시");
+            return;
+        }
+        family.samples.push((render_labels(labels), value));
+    }
+}
+
+/// 레이블 목록을 `a="b",c="d"` 형태로 렌더링합니다 (중괄호 제외).
+fn render_labels(labels: &[(&str, &str)]) -> String {
+    let mut out = String::new();
+    for (i, (key, value)) in labels.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        out.push_str(key);
+        out.push_str("=\"");
+        for ch in value.chars() {
+            match ch {
+                '\\' => out.push_str("\\\\"),
+                '"' => out.push_str("\\\""),
+                '\n' => out.push_str("\\n"),
+                _ => out.push(ch),
+            }
+        }
+        out.push('"');
+    }
+    out
+}
+
+/// Prometheus 숫자 표기 (`+Inf`, `-Inf`, `NaN` 포함).
+fn format_value(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 { "+Inf" } else { "-Inf" }.to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+fn write_header(out: &mut String, name: &str, help: &str, kind: MetricKind) {
+    let help = help.replace('\\', "\\\\").replace('\n', "\\n");
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} {}", kind.as_str());
+}
+
+fn write_sample(out: &mut String, name: &str, labels: &str, value: f64) {
+    if labels.is_empty() {
+        let _ = writeln!(out, "{name} {}", format_value(value));
+    } else {
+        let _ = writeln!(out, "{name}{{{labels}}} {}", format_value(value));
+    }
+}
+
+fn write_histogram(out: &mut String, name: &str, labels: &str, histogram: &Histogram) {
+    let sep = if labels.is_empty() { "" } else { "," };
+    let count = histogram.count();
+    for (bound, cumulative) in histogram.cumulative_buckets() {
+        let _ = writeln!(
+            out,
+            "{name}_bucket{{{labels}{sep}le=\"{}\"}} {cumulative}",
+            format_value(bound)
+        );
+    }
+    let _ = writeln!(out, "{name}_bucket{{{labels}{sep}le=\"+Inf\"}} {count}");
+    write_sample(out, &format!("{name}_sum"), labels, histogram.sum());
+    write_sample(out, &format!("{name}_count"), labels, count as f64);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counter_and_gauge_basic() {
+        let registry = Registry::new();
+        let counter = registry.counter("arb_test_total", "test counter", &[]);
+        counter.inc();
+        counter.inc_by(4);
+        assert_eq!(counter.get(), 5);
+
+        // 같은 이름+레이블은 같은 핸들
+        registry
+            .counter("arb_test_total", "test counter", &[])
+            .inc();
+        assert_eq!(counter.get(), 6);
+
+        let gauge = registry.gauge("arb_test_gauge", "test gauge", &[("coin", "BTC")]);
+        gauge.set(1.5);
+        gauge.add(-0.25);
+        assert!((gauge.get() - 1.25).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_histogram_cumulative_buckets() {
+        let h = Histogram::new(&[0.1, 0.01, 1.0, f64::INFINITY, 0.1]);
+        h.observe(0.005);
+        h.observe(0.01); // le 포함
+        h.observe(0.5);
+        h.observe(3.0); // +Inf만
+
+        assert_eq!(h.cumulative_buckets(), vec![(0.01, 2), (0.1, 2), (1.0, 3)]);
+        assert_eq!(h.count(), 4);
+        assert!((h.sum() - 3.515).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_encode_text_format() {
+        let registry = Registry::new();
+        registry
+            .counter(
+                "arb_ws_reconnects_total",
+                "reconnects",
+                &[("exchange", "upbit")],
+            )
+            .inc_by(3);
+        registry
+            .histogram(
+                "arb_latency_seconds",
+                "latency",
+                &[("leg", "bybit")],
+                &[0.1, 1.0],
+            )
+            .observe(0.2);
+
+        let text = registry.encode();
+        assert!(text.contains("# TYPE arb_ws_reconnects_total counter\n"));
+        assert!(text.contains("arb_ws_reconnects_total{exchange=\"upbit\"} 3\n"));
+        assert!(text.contains("# TYPE arb_latency_seconds histogram\n"));
+        assert!(text.contains("arb_latency_seconds_bucket{leg=\"bybit\",le=\"0.1\"} 0\n"));
+        assert!(text.contains("arb_latency_seconds_bucket{leg=\"bybit\",le=\"1\"} 1\n"));
+        assert!(text.contains("arb_latency_seconds_bucket{leg=\"bybit\",le=\"+Inf\"} 1\n"));
+        assert!(text.contains("arb_latency_seconds_sum{leg=\"bybit\"} 0.2\n"));
+        assert!(text.contains("arb_latency_seconds_count{leg=\"bybit\"} 1\n"));
+    }
+
+    #[test]
+    fn test_encode_label_escaping() {
+        let registry = Registry::new();
+        registry
+            .gauge("arb_escape", "escape", &[("v", "a\"b\\c\nd")])
+            .set(f64::NAN);
+        let text = registry.encode();
+        assert!(text.contains("arb_escape{v=\"a\\\"b\\\\c\\nd\"} NaN\n"));
+    }
+
+    #[test]
+    fn test_collector_replaced_by_key() {
+        let registry = Registry::new();
+        registry.register_collector("risk", |s| {
+            s.gauge("arb_risk_daily_pnl_usdt", "daily pnl", &[], -1.0);
+        });
+        registry.register_collector("risk", |s| {
+            s.gauge("arb_risk_daily_pnl_usdt", "daily pnl", &[], 2.5);
+            s.counter("arb_risk_trades_total", "trades", &[], 7);
+        });
+
+        let text = registry.encode();
+        assert!(text.contains("arb_risk_daily_pnl_usdt 2.5\n"));
+        assert!(!text.contains("arb_risk_daily_pnl_usdt -1\n"));
+        assert!(text.contains("# TYPE arb_risk_trades_total counter\narb_risk_trades_total 7\n"));
+
+        assert!(registry.unregister_collector("risk"));
+        assert!(!registry.encode().contains("arb_risk_daily_pnl_usdt"));
+    }
+
+    #[test]
+    fn test_kind_mismatch_returns_detached_handle() {
+        let registry = Registry::new();
+        registry.counter("arb_dup", "dup", &[]).inc();
+        let gauge = registry.gauge("arb_dup", "dup", &[]);
+        gauge.set(42.0);
+
+        let text = registry.encode();
+        assert!(text.contains("arb_dup 1\n"));
+        assert!(!text.contains("42"));
+    }
+}
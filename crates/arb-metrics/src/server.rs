@@ -0,0 +1,277 @@
+//! 임베디드 HTTP 서버.
+//!
+//! Prometheus scrape와 로컬 상태 조회용 최소 HTTP/1.1 서버입니다.
+//! 라우트는 경로 단위 GET 핸들러이며, `/metrics`는 [`HttpServer::metrics`]로 붙입니다.
+
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::future::Future;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use http_body_util::Full;
+use hyper::body::{Bytes, Incoming};
+use hyper::header::{ALLOW, CONTENT_TYPE, HeaderValue};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use tokio::net::TcpListener;
+use tracing::{debug, info, warn};
+
+use crate::error::MetricsError;
+use crate::registry::Registry;
+
+/// Prometheus text exposition format content type.
+const CONTENT_TYPE_PROMETHEUS: &str = "text/plain; version=0.0.4; charset=utf-8";
+/// JSON content type.
+const CONTENT_TYPE_JSON: &str = "application/json";
+
+/// 라우트 핸들러 응답.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    /// HTTP 상태 코드.
+    pub status: u16,
+    /// Content-Type 헤더 값.
+    pub content_type: &'static str,
+    /// 응답 본문.
+    pub body: String,
+}
+
+impl HttpResponse {
+    /// 200 JSON 응답.
+    pub fn json(body: String) -> Self {
+        Self {
+            status: 200,
+            content_type: CONTENT_TYPE_JSON,
+            body,
+        }
+    }
+
+    /// 상태 코드 변경.
+    pub fn with_status(mut self, status: u16) -> Self {
+        self.status = status;
+        self
+    }
+
+    fn into_response(self) -> Response<Full<Bytes>> {
+        let mut resp = Response::new(Full::new(Bytes::from(self.body)));
+        *resp.status_mut() =
+            StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        resp.headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static(self.content_type));
+        resp
+    }
+}
+
+/// 경로별 GET 핸들러.
+type Handler = Arc<dyn Fn() -> Pin<Box<dyn Future<Output = HttpResponse> + Send>> + Send + Sync>;
+
+/// 임베디드 HTTP 서버.
+pub struct HttpServer {
+    listener: TcpListener,
+    routes: HashMap<String, Handler>,
+}
+
+impl std::fmt::Debug for HttpServer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut routes: Vec<&String> = self.routes.keys().collect();
+        routes.sort();
+        f.debug_struct("HttpServer")
+            .field("listener", &self.listener)
+            .field("routes", &routes)
+            .finish()
+    }
+}
+
+impl HttpServer {
+    /// 리스닝 소켓을 바인딩합니다.
+    ///
+    /// # 인자
+    ///
+    /// * `addr` - 리스닝 주소 (e.g., "127.0.0.1:9100", 포트 0이면 임의 포트)
+    pub async fn bind(addr: &str) -> Result<Self, MetricsError> {
+        let listener = TcpListener::bind(addr)
+            .await
+            .map_err(|e| MetricsError::Bind {
+                addr: addr.to_string(),
+                reason: e.to_string(),
+            })?;
+        Ok(Self {
+            listener,
+            routes: HashMap::new(),
+        })
+    }
+
+    /// 실제 바인딩된 주소.
+    pub fn local_addr(&self) -> Result<SocketAddr, MetricsError> {
+        Ok(self.listener.local_addr()?)
+    }
+
+    /// GET 라우트를 추가합니다. 같은 경로는 덮어씁니다.
+    pub fn route<F, Fut>(mut self, path: &str, handler: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = HttpResponse> + Send + 'static,
+    {
+        self.routes
+            .insert(path.to_string(), Arc::new(move || Box::pin(handler())));
+        self
+    }
+
+    /// `GET /metrics` 라우트를 추가합니다 (Prometheus text format).
+    pub fn metrics(self, registry: &'static Registry) -> Self {
+        self.route("/metrics", move || async move {
+            HttpResponse {
+                status: 200,
+                content_type: CONTENT_TYPE_PROMETHEUS,
+                body: registry.encode(),
+            }
+        })
+    }
+
+    /// `shutdown`이 완료될 때까지 연결을 수락합니다.
+    pub async fn run<F>(self, shutdown: F)
+    where
+        F: Future<Output = ()>,
+    {
+        let mut paths: Vec<&String> = self.routes.keys().collect();
+        paths.sort();
+        info!(
+            addr = ?self.listener.local_addr().ok(),
+            routes = ?paths,
+            "HTTP 서버 시작"
+        );
+        let routes = Arc::new(self.routes);
+        tokio::pin!(shutdown);
+
+        loop {
+            tokio::select! {
+                _ = &mut shutdown => {
+                    info!("HTTP 서버 종료");
+                    break;
+                }
+                accepted = self.listener.accept() => {
+                    let (stream, peer) = match accepted {
+                        Ok(v) => v,
+                        Err(e) => {
+                            warn!(error = %e, "HTTP 연결 수락 실패");
+                            tokio::time::sleep(Duration::from_millis(100)).await;
+                            continue;
+                        }
+                    };
+                    let routes = Arc::clone(&routes);
+                    tokio::spawn(async move {
+                        let service = service_fn(move |req: Request<Incoming>| {
+                            let routes = Arc::clone(&routes);
+                            async move { Ok::<_, Infallible>(dispatch(&routes, &req).await) }
+                        });
+                        if let Err(e) = http1::Builder::new()
+                            .serve_connection(TokioIo::new(stream), service)
+                            .await
+                        {
+                            debug!(peer = %peer, error = %e, "HTTP 연결 처리 에러");
+                        }
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// 요청 라우팅.
+async fn dispatch<B>(routes: &HashMap<String, Handler>, req: &Request<B>) -> Response<Full<Bytes>> {
+    let Some(handler) = routes.get(req.uri().path()) else {
+        return plain(StatusCode::NOT_FOUND, "not found\n");
+    };
+    match *req.method() {
+        Method::GET | Method::HEAD => handler().await.into_response(),
+        _ => {
+            let mut resp = plain(StatusCode::METHOD_NOT_ALLOWED, "method not allowed\n");
+            resp.headers_mut()
+                .insert(ALLOW, HeaderValue::from_static("GET, HEAD"));
+            resp
+        }
+    }
+}
+
+fn plain(status: StatusCode, body: &'static str) -> Response<Full<Bytes>> {
+    let mut resp = Response::new(Full::new(Bytes::from_static(body.as_bytes())));
+    *resp.status_mut() = status;
+    resp
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpStream;
+
+    async fn request(addr: SocketAddr, method: &str, path: &str) -> String {
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        let req =
+            format!("{method} {path} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n");
+        stream.write_all(req.as_bytes()).await.unwrap();
+        let mut buf = Vec::new();
+        stream.read_to_end(&mut buf).await.unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_http_server_routes() {
+        let registry: &'static Registry = Box::leak(Box::new(Registry::new()));
+        registry
+            .counter("arb_server_test_total", "server test", &[])
+            .inc_by(2);
+
+        let server = HttpServer::bind("127.0.0.1:0")
+            .await
+            .unwrap()
+            .metrics(registry)
+            .route("/status/ping", || async {
+                HttpResponse::json(r#"{"ok":true}"#.to_string())
+            })
+            .route("/status/missing", || async {
+                HttpResponse::json(r#"{"error":"not configured"}"#.to_string()).with_status(503)
+            });
+        let addr = server.local_addr().unwrap();
+        let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+        let handle = tokio::spawn(server.run(async {
+            let _ = rx.await;
+        }));
+
+        let ok = request(addr, "GET", "/metrics").await;
+        assert!(ok.starts_with("HTTP/1.1 200"));
+        assert!(ok.contains("text/plain; version=0.0.4"));
+        assert!(ok.contains("arb_server_test_total 2\n"));
+
+        let json = request(addr, "GET", "/status/ping").await;
+        assert!(json.starts_with("HTTP/1.1 200"));
+        assert!(json.contains("content-type: application/json"));
+        assert!(json.ends_with(r#"{"ok":true}"#));
+
+        assert!(
+            request(addr, "GET", "/status/missing")
+                .await
+                .starts_with("HTTP/1.1 503")
+        );
+        assert!(request(addr, "GET", "/").await.starts_with("HTTP/1.1 404"));
+        assert!(
+            request(addr, "POST", "/metrics")
+                .await
+                .starts_with("HTTP/1.1 405")
+        );
+
+        tx.send(()).unwrap();
+        handle.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_http_server_bind_error() {
+        let err = HttpServer::bind("not-an-addr").await.unwrap_err();
+        assert!(matches!(err, MetricsError::Bind { .. }));
+    }
+}
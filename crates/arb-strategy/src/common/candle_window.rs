@@ -64,6 +64,17 @@ impl CandleWindow {
     pub fn last(&self) -> Option<f64> {
         self.data.back().copied()
     }
+
+    /// 윈도우 크기를 변경합니다.
+    ///
+    /// 축소 시 가장 오래된 값부터 제거하여 최근 `new_size`개만 유지합니다.
+    /// 확대 시 기존 데이터는 유지되며, 새 크기만큼 찰 때까지 `is_ready()`는 false입니다.
+    pub fn resize(&mut self, new_size: usize) {
+        while self.data.len() > new_size {
+            self.data.pop_front();
+        }
+        self.window_size = new_size;
+    }
 }
 
 #[cfg(test)]
@@ -132,6 +143,42 @@ mod tests {
         assert_eq!(window.last(), Some(20.0));
     }
 
+    #[test]
+    fn test_resize_shrink_keeps_latest() {
+        let mut window = CandleWindow::new(5);
+        for v in 1..=5 {
+            window.push(v as f64);
+        }
+        window.resize(3);
+        assert_eq!(window.window_size(), 3);
+        assert!(window.is_ready());
+        assert_eq!(
+            window.data().iter().copied().collect::<Vec<_>>(),
+            vec![3.0, 4.0, 5.0]
+        );
+
+        // 축소 후 push 시 새 크기 기준으로 evict
+        window.push(6.0);
+        assert_eq!(window.len(), 3);
+        assert_eq!(window.data()[0], 4.0);
+    }
+
+    #[test]
+    fn test_resize_grow_not_ready() {
+        let mut window = CandleWindow::new(3);
+        for v in 1..=3 {
+            window.push(v as f64);
+        }
+        window.resize(5);
+        assert_eq!(window.len(), 3);
+        assert!(!window.is_ready());
+
+        window.push(4.0);
+        window.push(5.0);
+        assert!(window.is_ready());
+        assert_eq!(window.data()[0], 1.0);
+    }
+
     #[test]
     fn test_data_reference() {
         let mut window = CandleWindow::new(3);
@@ -1,7 +1,7 @@
 //! 데이터 변환 유틸리티.
 //!
 //! 타입 설계 원칙에 따라 금융 데이터(Decimal)와 통계 데이터(f64) 간 변환,
-//! 그리고 타임스탬프 정규화(분 단위 truncate) 기능을 집중 관리합니다.
+//! 그리고 타임스탬프 정규화(분/캔들 간격 단위 truncate) 기능을 집중 관리합니다.
 
 use chrono::{DateTime, Timelike, Utc};
 use rust_decimal::Decimal;
@@ -19,6 +19,21 @@ pub fn truncate_to_minute(ts: DateTime<Utc>) -> DateTime<Utc> {
         .unwrap_or(ts)
 }
 
+/// 캔들 간격(분) 경계를 기준으로 timestamp를 truncate합니다.
+///
+/// UNIX epoch 기준 `interval_minutes`의 배수로 내림합니다.
+/// 60분 이하의 약수 간격(5/15/60분)은 정시 경계와 일치하므로 거래소 캔들 시작 시각과 정렬됩니다.
+/// `interval_minutes`가 0 또는 1이면 `truncate_to_minute`와 동일합니다.
+pub fn truncate_to_interval(ts: DateTime<Utc>, interval_minutes: u32) -> DateTime<Utc> {
+    let minute = truncate_to_minute(ts);
+    if interval_minutes <= 1 {
+        return minute;
+    }
+    let step = i64::from(interval_minutes) * 60;
+    let secs = minute.timestamp();
+    DateTime::from_timestamp(secs - secs.rem_euclid(step), 0).unwrap_or(minute)
+}
+
 /// Decimal을 f64로 변환합니다.
 ///
 /// 변환 불가 시 `StrategyError::Statistics` 반환.
@@ -129,4 +144,34 @@ mod tests {
         assert_eq!(truncated.nanosecond(), 0);
         assert_eq!(truncated.minute(), 15);
     }
+
+    #[test]
+    fn test_truncate_to_interval_5m() {
+        use chrono::TimeZone;
+        let ts = Utc.with_ymd_and_hms(2026, 2, 6, 10, 34, 45).unwrap();
+        let truncated = truncate_to_interval(ts, 5);
+        assert_eq!(
+            truncated,
+            Utc.with_ymd_and_hms(2026, 2, 6, 10, 30, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_truncate_to_interval_1h() {
+        use chrono::TimeZone;
+        let ts = Utc.with_ymd_and_hms(2026, 2, 6, 10, 59, 59).unwrap();
+        let truncated = truncate_to_interval(ts, 60);
+        assert_eq!(
+            truncated,
+            Utc.with_ymd_and_hms(2026, 2, 6, 10, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_truncate_to_interval_1m_same_as_minute() {
+        use chrono::TimeZone;
+        let ts = Utc.with_ymd_and_hms(2026, 2, 6, 10, 34, 45).unwrap();
+        assert_eq!(truncate_to_interval(ts, 1), truncate_to_minute(ts));
+        assert_eq!(truncate_to_interval(ts, 0), truncate_to_minute(ts));
+    }
 }
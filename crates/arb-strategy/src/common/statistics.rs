@@ -1,6 +1,8 @@
 //! 통계 유틸리티.
 //!
 //! Z-Score 계산을 위한 평균, 표준편차, z-score 함수를 제공합니다.
+//! 스프레드의 평균 회귀 여부를 판단하기 위한 ADF/Engle-Granger 검정과
+//! Ornstein-Uhlenbeck half-life 추정도 포함합니다.
 //! 모든 연산은 f64 도메인에서 수행됩니다.
 
 use crate::error::StatisticsError;
@@ -49,6 +51,343 @@ pub fn z_score(
     Ok((current - mean_val) / stddev_val)
 }
 
+/// ADF 검정 임계값 (상수항 포함, 추세 없음, 대표본 MacKinnon 값).
+///
+/// (유의수준, 임계값) 쌍. 검정 통계량이 임계값보다 작으면 단위근 귀무가설을 기각합니다.
+const ADF_CRITICAL_VALUES: [(f64, f64); 3] = [(0.01, -3.43), (0.05, -2.86), (0.10, -2.57)];
+
+/// Engle-Granger 공적분 검정 임계값 (변수 2개, 상수항 포함, 대표본).
+///
+/// 잔차는 추정된 회귀식에서 나오므로 ADF보다 더 엄격한 임계값을 사용합니다.
+const ENGLE_GRANGER_CRITICAL_VALUES: [(f64, f64); 3] =
+    [(0.01, -3.90), (0.05, -3.34), (0.10, -3.04)];
+
+/// 회귀 계수 추정 시 필요한 최소 관측 수 (회귀변수 수 대비 여유분).
+const MIN_REGRESSION_DOF: usize = 10;
+
+/// 유의수준에 해당하는 임계값을 테이블에서 조회합니다.
+///
+/// 테이블에 없는 유의수준은 그보다 작거나 같은 가장 가까운 유의수준(더 엄격한 쪽)으로
+/// 내림합니다 (예: 0.03 → 0.01). 0.01 미만이면 0.01, 0.10을 초과하면 0.10 임계값을 사용합니다.
+fn lookup_critical_value(table: &[(f64, f64); 3], significance: f64) -> f64 {
+    table
+        .iter()
+        .rev()
+        .find(|(level, _)| *level <= significance + 1e-12)
+        .map(|(_, cv)| *cv)
+        .unwrap_or(table[0].1)
+}
+
+/// ADF 검정 임계값을 반환합니다 (유의수준: 0.01, 0.05, 0.10).
+pub fn adf_critical_value(significance: f64) -> f64 {
+    lookup_critical_value(&ADF_CRITICAL_VALUES, significance)
+}
+
+/// Engle-Granger 공적분 검정 임계값을 반환합니다 (유의수준: 0.01, 0.05, 0.10).
+pub fn engle_granger_critical_value(significance: f64) -> f64 {
+    lookup_critical_value(&ENGLE_GRANGER_CRITICAL_VALUES, significance)
+}
+
+/// OLS 회귀 결과.
+struct OlsFit {
+    /// 회귀 계수 (회귀변수 순서와 동일).
+    coef: Vec<f64>,
+    /// 계수별 표준오차.
+    std_err: Vec<f64>,
+}
+
+/// 정규방정식 (X'X)β = X'y 를 Gauss-Jordan 소거로 풀어 OLS 회귀를 수행합니다.
+///
+/// `rows`는 관측치별 회귀변수 벡터이며, 상수항이 필요하면 호출자가 1.0을 포함해야 합니다.
+/// 회귀변수 수가 작으므로(ADF: 2 + lags) 일반 역행렬 계산으로 충분합니다.
+fn ols(y: &[f64], rows: &[Vec<f64>]) -> Result<OlsFit, StatisticsError> {
+    let n = y.len();
+    let k = rows.first().map(|r| r.len()).unwrap_or(0);
+    if k == 0 || n < k + MIN_REGRESSION_DOF {
+        return Err(StatisticsError::InsufficientData {
+            required: k + MIN_REGRESSION_DOF,
+            actual: n,
+        });
+    }
+
+    // X'X와 X'y 누적
+    let mut xtx = vec![vec![0.0; k]; k];
+    let mut xty = vec![0.0; k];
+    for (row, &yi) in rows.iter().zip(y) {
+        for i in 0..k {
+            xty[i] += row[i] * yi;
+            for j in 0..k {
+                xtx[i][j] += row[i] * row[j];
+            }
+        }
+    }
+
+    // [X'X | I]에 Gauss-Jordan 소거 → (X'X)^-1
+    let mut inv = vec![vec![0.0; k]; k];
+    for (i, r) in inv.iter_mut().enumerate() {
+        r[i] = 1.0;
+    }
+    for col in 0..k {
+        let pivot_row = (col..k)
+            .max_by(|&a, &b| {
+                xtx[a][col]
+                    .abs()
+                    .partial_cmp(&xtx[b][col].abs())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .unwrap_or(col);
+        if xtx[pivot_row][col].abs() < 1e-12 {
+            return Err(StatisticsError::ZeroDivision);
+        }
+        xtx.swap(col, pivot_row);
+        inv.swap(col, pivot_row);
+
+        let pivot = xtx[col][col];
+        for j in 0..k {
+            xtx[col][j] /= pivot;
+            inv[col][j] /= pivot;
+        }
+        for r in 0..k {
+            if r == col {
+                continue;
+            }
+            let factor = xtx[r][col];
+            if factor == 0.0 {
+                continue;
+            }
+            for j in 0..k {
+                xtx[r][j] -= factor * xtx[col][j];
+                inv[r][j] -= factor * inv[col][j];
+            }
+        }
+    }
+
+    let coef: Vec<f64> = (0..k)
+        .map(|i| (0..k).map(|j| inv[i][j] * xty[j]).sum())
+        .collect();
+
+    // 잔차 분산 (자유도 n - k)
+    let ssr: f64 = rows
+        .iter()
+        .zip(y)
+        .map(|(row, &yi)| {
+            let fitted: f64 = row.iter().zip(&coef).map(|(x, b)| x * b).sum();
+            (yi - fitted).powi(2)
+        })
+        .sum();
+    let sigma2 = ssr / (n - k) as f64;
+
+    let std_err: Vec<f64> = (0..k)
+        .map(|i| (sigma2 * inv[i][i]).max(0.0).sqrt())
+        .collect();
+
+    if coef.iter().chain(&std_err).any(|v| v.is_nan()) {
+        return Err(StatisticsError::NanDetected("OLS regression".to_string()));
+    }
+
+    Ok(OlsFit { coef, std_err })
+}
+
+/// ADF (Augmented Dickey-Fuller) 검정 결과.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdfResult {
+    /// 검정 통계량 (y_{t-1} 계수의 t-stat). 음수일수록 강한 평균 회귀.
+    pub statistic: f64,
+    /// y_{t-1} 계수 (γ). 음수이면 평균 회귀 방향.
+    pub gamma: f64,
+    /// 사용한 차분 시차 수.
+    pub lags: usize,
+    /// 회귀에 사용된 관측 수.
+    pub n_obs: usize,
+}
+
+impl AdfResult {
+    /// 주어진 유의수준에서 단위근 귀무가설이 기각되는지(정상성) 확인합니다.
+    pub fn is_stationary(&self, significance: f64) -> bool {
+        self.statistic < adf_critical_value(significance)
+    }
+}
+
+/// ADF 검정을 수행합니다 (상수항 포함, 추세 없음).
+///
+/// 회귀식: Δy_t = α + γ·y_{t-1} + Σ_{i=1..lags} φ_i·Δy_{t-i} + ε_t
+///
+/// # 인자
+///
+/// * `data` - 시계열 (시간 오름차순)
+/// * `lags` - 차분 시차 수 (자기상관 보정용, 보통 1)
+///
+/// # 에러
+///
+/// * `StatisticsError::InsufficientData` - 관측 수가 회귀에 부족한 경우
+/// * `StatisticsError::ZeroDivision` - 상수 시계열 등으로 회귀가 특이(singular)한 경우
+pub fn adf_test(data: &VecDeque<f64>, lags: usize) -> Result<AdfResult, StatisticsError> {
+    let series: Vec<f64> = data.iter().copied().collect();
+    adf_test_slice(&series, lags)
+}
+
+fn adf_test_slice(series: &[f64], lags: usize) -> Result<AdfResult, StatisticsError> {
+    let required = lags + 2 + MIN_REGRESSION_DOF + 2;
+    if series.len() < required {
+        return Err(StatisticsError::InsufficientData {
+            required,
+            actual: series.len(),
+        });
+    }
+
+    let diffs: Vec<f64> = series.windows(2).map(|w| w[1] - w[0]).collect();
+
+    // t는 diffs 인덱스 (Δy_t = diffs[t], y_{t-1} = series[t])
+    let mut y = Vec::with_capacity(diffs.len() - lags);
+    let mut rows = Vec::with_capacity(diffs.len() - lags);
+    for t in lags..diffs.len() {
+        let mut row = Vec::with_capacity(2 + lags);
+        row.push(1.0);
+        row.push(series[t]);
+        for i in 1..=lags {
+            row.push(diffs[t - i]);
+        }
+        y.push(diffs[t]);
+        rows.push(row);
+    }
+
+    let fit = ols(&y, &rows)?;
+    let gamma = fit.coef[1];
+    let se = fit.std_err[1];
+    if se == 0.0 {
+        return Err(StatisticsError::ZeroDivision);
+    }
+
+    Ok(AdfResult {
+        statistic: gamma / se,
+        gamma,
+        lags,
+        n_obs: y.len(),
+    })
+}
+
+/// Engle-Granger 2단계 공적분 검정 결과.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CointegrationResult {
+    /// 1단계 회귀 y = intercept + hedge_ratio·x 의 기울기.
+    pub hedge_ratio: f64,
+    /// 1단계 회귀 절편.
+    pub intercept: f64,
+    /// 잔차에 대한 ADF 검정 결과.
+    pub adf: AdfResult,
+}
+
+impl CointegrationResult {
+    /// 주어진 유의수준에서 공적분 관계가 성립하는지 확인합니다.
+    ///
+    /// 잔차 ADF 통계량을 Engle-Granger 임계값과 비교합니다.
+    pub fn is_cointegrated(&self, significance: f64) -> bool {
+        self.adf.statistic < engle_granger_critical_value(significance)
+    }
+}
+
+/// Engle-Granger 2단계 공적분 검정을 수행합니다.
+///
+/// 1. y = α + β·x 를 OLS로 추정
+/// 2. 잔차 e_t = y_t - α - β·x_t 에 ADF 검정 적용
+///
+/// # 인자
+///
+/// * `y` - 종속 시계열 (예: Bybit 가격)
+/// * `x` - 독립 시계열 (예: Upbit USD 환산 가격)
+/// * `lags` - 잔차 ADF 검정의 차분 시차 수
+///
+/// # 에러
+///
+/// * `StatisticsError::LengthMismatch` - 두 시계열 길이가 다른 경우
+/// * `StatisticsError::InsufficientData` - 관측 수가 회귀에 부족한 경우
+pub fn engle_granger(
+    y: &VecDeque<f64>,
+    x: &VecDeque<f64>,
+    lags: usize,
+) -> Result<CointegrationResult, StatisticsError> {
+    if y.len() != x.len() {
+        return Err(StatisticsError::LengthMismatch {
+            y_len: y.len(),
+            x_len: x.len(),
+        });
+    }
+
+    let ys: Vec<f64> = y.iter().copied().collect();
+    let rows: Vec<Vec<f64>> = x.iter().map(|&xi| vec![1.0, xi]).collect();
+    let fit = ols(&ys, &rows)?;
+    let intercept = fit.coef[0];
+    let hedge_ratio = fit.coef[1];
+
+    let residuals: Vec<f64> = ys
+        .iter()
+        .zip(x.iter())
+        .map(|(yi, xi)| yi - intercept - hedge_ratio * xi)
+        .collect();
+    let adf = adf_test_slice(&residuals, lags)?;
+
+    Ok(CointegrationResult {
+        hedge_ratio,
+        intercept,
+        adf,
+    })
+}
+
+/// Ornstein-Uhlenbeck 과정의 half-life를 추정합니다 (캔들 개수 단위).
+///
+/// Δy_t = a + b·y_{t-1} 를 OLS로 추정한 뒤, 이산 AR(1) 관계
+/// y_t = a + (1 + b)·y_{t-1} 에서 half-life = -ln(2) / ln(1 + b) 를 계산합니다.
+///
+/// # 반환값
+///
+/// * `Ok(Some(half_life))` - 평균 회귀하는 경우 (-1 < b < 0)
+/// * `Ok(None)` - 평균 회귀하지 않는 경우 (b >= 0 또는 진동 발산)
+pub fn half_life(data: &VecDeque<f64>) -> Result<Option<f64>, StatisticsError> {
+    let series: Vec<f64> = data.iter().copied().collect();
+    if series.len() < 2 + MIN_REGRESSION_DOF + 2 {
+        return Err(StatisticsError::InsufficientData {
+            required: 2 + MIN_REGRESSION_DOF + 2,
+            actual: series.len(),
+        });
+    }
+
+    let y: Vec<f64> = series.windows(2).map(|w| w[1] - w[0]).collect();
+    let rows: Vec<Vec<f64>> = series[..series.len() - 1]
+        .iter()
+        .map(|&prev| vec![1.0, prev])
+        .collect();
+    let b = ols(&y, &rows)?.coef[1];
+
+    if b >= 0.0 || b <= -1.0 {
+        return Ok(None);
+    }
+    Ok(Some(-std::f64::consts::LN_2 / (1.0 + b).ln()))
+}
+
+/// 시계열의 선형 추세 기울기 t-통계량을 계산합니다.
+///
+/// 회귀식: y_t = α + β·t + ε_t 에서 β / se(β)를 반환합니다.
+/// 양수면 상승 추세, 음수면 하락 추세이며, 절댓값이 클수록 추세가 통계적으로 뚜렷합니다.
+/// 잔차가 0인 완전한 직선이면 기울기 부호의 무한대를 반환합니다.
+///
+/// # 에러
+///
+/// * `StatisticsError::InsufficientData` - 관측 수가 회귀에 부족한 경우
+pub fn trend_t_stat(data: &VecDeque<f64>) -> Result<f64, StatisticsError> {
+    let y: Vec<f64> = data.iter().copied().collect();
+    let rows: Vec<Vec<f64>> = (0..y.len()).map(|t| vec![1.0, t as f64]).collect();
+    let fit = ols(&y, &rows)?;
+    let (slope, se) = (fit.coef[1], fit.std_err[1]);
+    if se == 0.0 {
+        return Ok(if slope == 0.0 {
+            0.0
+        } else {
+            f64::INFINITY.copysign(slope)
+        });
+    }
+    Ok(slope / se)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -129,4 +468,183 @@ mod tests {
         let result = z_score(5.0, 3.0, 0.01, 0.01).unwrap();
         assert!((result - 200.0).abs() < 1e-10);
     }
+
+    /// 테스트용 결정적 난수 생성기 (LCG + Box-Muller 정규분포).
+    struct TestRng(u64);
+
+    impl TestRng {
+        fn uniform(&mut self) -> f64 {
+            self.0 = self
+                .0
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((self.0 >> 11) as f64 + 0.5) / (1u64 << 53) as f64
+        }
+
+        fn normal(&mut self) -> f64 {
+            let u1 = self.uniform();
+            let u2 = self.uniform();
+            (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+        }
+    }
+
+    /// y_t = phi·y_{t-1} + ε_t 형태의 AR(1) 시계열을 생성합니다.
+    fn ar1_series(phi: f64, n: usize, seed: u64) -> VecDeque<f64> {
+        let mut rng = TestRng(seed);
+        let mut y = 0.0;
+        (0..n)
+            .map(|_| {
+                y = phi * y + rng.normal();
+                y
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_critical_value_lookup() {
+        assert_eq!(adf_critical_value(0.01), -3.43);
+        assert_eq!(adf_critical_value(0.05), -2.86);
+        assert_eq!(adf_critical_value(0.10), -2.57);
+        // 테이블 사이 값은 더 엄격한(작은 유의수준) 쪽으로 내림
+        assert_eq!(adf_critical_value(0.03), -3.43);
+        assert_eq!(adf_critical_value(0.07), -2.86);
+        assert_eq!(adf_critical_value(0.001), -3.43);
+        assert_eq!(engle_granger_critical_value(0.05), -3.34);
+        assert_eq!(engle_granger_critical_value(0.5), -3.04);
+    }
+
+    #[test]
+    fn test_adf_stationary_ar1() {
+        let data = ar1_series(0.9, 1440, 7);
+        let result = adf_test(&data, 1).unwrap();
+        assert!(result.gamma < 0.0);
+        assert!(
+            result.is_stationary(0.01),
+            "AR(1) phi=0.9는 정상 시계열이어야 합니다: {}",
+            result.statistic
+        );
+        assert_eq!(result.lags, 1);
+        assert_eq!(result.n_obs, 1440 - 2);
+    }
+
+    #[test]
+    fn test_adf_random_walk_not_stationary() {
+        let data = ar1_series(1.0, 1440, 11);
+        let result = adf_test(&data, 1).unwrap();
+        assert!(
+            !result.is_stationary(0.05),
+            "random walk는 정상 시계열이 아니어야 합니다: {}",
+            result.statistic
+        );
+    }
+
+    #[test]
+    fn test_adf_insufficient_data() {
+        let data: VecDeque<f64> = vec![1.0, 2.0, 1.5].into();
+        assert!(matches!(
+            adf_test(&data, 1),
+            Err(StatisticsError::InsufficientData { .. })
+        ));
+    }
+
+    #[test]
+    fn test_adf_constant_series_singular() {
+        let data: VecDeque<f64> = vec![5.0; 100].into();
+        assert!(matches!(
+            adf_test(&data, 1),
+            Err(StatisticsError::ZeroDivision)
+        ));
+    }
+
+    #[test]
+    fn test_half_life_ar1() {
+        // 이론값: -ln2 / ln(0.9) ≈ 6.58
+        let data = ar1_series(0.9, 5000, 3);
+        let hl = half_life(&data).unwrap().expect("평균 회귀 시계열");
+        assert!((hl - 6.58).abs() < 1.5, "half-life 추정 오차 과대: {hl}");
+    }
+
+    #[test]
+    fn test_half_life_trending_none() {
+        // 지수적으로 발산하는 시계열은 평균 회귀하지 않음
+        let data: VecDeque<f64> = (0..100).map(|i| 1.05f64.powi(i)).collect();
+        assert_eq!(half_life(&data).unwrap(), None);
+    }
+
+    #[test]
+    fn test_half_life_insufficient_data() {
+        let data: VecDeque<f64> = vec![1.0, 2.0].into();
+        assert!(half_life(&data).is_err());
+    }
+
+    #[test]
+    fn test_engle_granger_cointegrated() {
+        let x = ar1_series(1.0, 1440, 21);
+        let noise = ar1_series(0.5, 1440, 42);
+        let y: VecDeque<f64> = x
+            .iter()
+            .zip(noise.iter())
+            .map(|(xi, ei)| 2.0 + 1.5 * xi + 0.1 * ei)
+            .collect();
+
+        let result = engle_granger(&y, &x, 1).unwrap();
+        assert!((result.hedge_ratio - 1.5).abs() < 0.01);
+        assert!((result.intercept - 2.0).abs() < 0.5);
+        assert!(result.is_cointegrated(0.01));
+    }
+
+    #[test]
+    fn test_engle_granger_independent_walks() {
+        let x = ar1_series(1.0, 1440, 5);
+        let y = ar1_series(1.0, 1440, 9);
+        let result = engle_granger(&y, &x, 1).unwrap();
+        assert!(!result.is_cointegrated(0.05));
+    }
+
+    #[test]
+    fn test_engle_granger_length_mismatch() {
+        let x: VecDeque<f64> = vec![1.0; 50].into();
+        let y: VecDeque<f64> = vec![1.0; 40].into();
+        assert!(matches!(
+            engle_granger(&y, &x, 1),
+            Err(StatisticsError::LengthMismatch {
+                y_len: 40,
+                x_len: 50
+            })
+        ));
+    }
+
+    #[test]
+    fn test_trend_t_stat_uptrend() {
+        // 노이즈가 섞인 상승 추세
+        let data: VecDeque<f64> = (0..30)
+            .map(|i| 0.01 * i as f64 + 0.005 * (i as f64 * 1.7).sin())
+            .collect();
+        let t = trend_t_stat(&data).unwrap();
+        assert!(t > 10.0, "t={t}");
+    }
+
+    #[test]
+    fn test_trend_t_stat_mean_reverting() {
+        // 추세 없이 평균 주변을 진동
+        let data: VecDeque<f64> = (0..30).map(|i| (i as f64 * 2.1).sin()).collect();
+        let t = trend_t_stat(&data).unwrap();
+        assert!(t.abs() < 2.0, "t={t}");
+    }
+
+    #[test]
+    fn test_trend_t_stat_perfect_line() {
+        let data: VecDeque<f64> = (0..20).map(|i| -0.5 * i as f64).collect();
+        let t = trend_t_stat(&data).unwrap();
+        assert!(t < -1e6, "t={t}");
+    }
+
+    #[test]
+    fn test_trend_t_stat_insufficient_data() {
+        let data: VecDeque<f64> = vec![1.0, 2.0, 3.0].into();
+        assert!(matches!(
+            trend_t_stat(&data),
+            Err(StatisticsError::InsufficientData { .. })
+        ));
+    }
 }
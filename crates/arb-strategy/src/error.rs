@@ -32,6 +32,15 @@ pub enum StatisticsError {
         /// 최소 임계값
         threshold: f64,
     },
+
+    /// 짝지어야 하는 두 시계열의 길이가 다릅니다.
+    #[error("Series length mismatch: y has {y_len}, x has {x_len}")]
+    LengthMismatch {
+        /// 종속 시계열 길이
+        y_len: usize,
+        /// 독립 시계열 길이
+        x_len: usize,
+    },
 }
 
 /// 포지션 에러.
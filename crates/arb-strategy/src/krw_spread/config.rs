@@ -0,0 +1,246 @@
+//! KRW 현물 간 스프레드 전략 설정.
+//!
+//! `krw_spread.toml`의 `[krw_spread]` 섹션에서 로드합니다.
+//! 모든 금액은 KRW 단위이며, 환율/펀딩 관련 설정은 없습니다.
+
+use std::path::Path;
+
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use tracing::info;
+
+use crate::error::StrategyError;
+use crate::output::writer::OutputConfig;
+use crate::zscore::orderbook::Exchange;
+use crate::zscore::risk::RiskConfig;
+
+/// Upbit-Bithumb KRW 스프레드 전략 설정.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct KrwSpreadConfig {
+    /// 대상 코인 목록 (양쪽 거래소 KRW 마켓에 모두 상장된 코인).
+    pub coins: Vec<String>,
+    /// 수수료 차감 후 최소 순 스프레드 (%).
+    pub min_net_spread_pct: f64,
+    /// Upbit taker 수수료율 (예: 0.0005).
+    pub upbit_taker_fee: f64,
+    /// Bithumb taker 수수료율 (예: 0.0004).
+    pub bithumb_taker_fee: f64,
+    /// 1회 주문 최대 금액 (KRW, 매수 레그 기준).
+    pub max_order_krw: Decimal,
+    /// 1회 주문 최소 금액 (KRW, 양쪽 거래소 최소 주문 금액 이상).
+    pub min_order_krw: Decimal,
+    /// 오더북 안전 볼륨 중 실제 주문에 사용할 비율 (0, 1].
+    pub safe_volume_ratio: f64,
+    /// 오더북 조회 깊이.
+    pub orderbook_depth: u32,
+    /// 오더북 캐시 최대 허용 나이 (초).
+    pub orderbook_max_age_sec: u64,
+    /// 오더북 폴링 주기 (밀리초).
+    pub poll_interval_ms: u64,
+    /// 코인별 체결 후 재진입 대기 시간 (초).
+    pub cooldown_sec: u64,
+    /// 한 거래소에 코인 재고가 몰린 비율 경고 임계값 (0.5, 1.0].
+    pub max_inventory_skew: f64,
+    /// 주문 응답 타임아웃 (초).
+    pub order_timeout_sec: u64,
+    /// 총 운용 자본 (KRW, 양쪽 거래소 KRW + 코인 평가액 합).
+    pub total_capital_krw: Decimal,
+    /// 일일 최대 손실 (KRW).
+    pub max_daily_loss_krw: Decimal,
+    /// 단건 최대 손실 (KRW).
+    pub max_single_loss_krw: Decimal,
+    /// Kill switch 활성화 여부.
+    pub kill_switch_enabled: bool,
+    /// 세션 파일 출력 설정.
+    pub output: OutputConfig,
+}
+
+impl Default for KrwSpreadConfig {
+    fn default() -> Self {
+        Self {
+            coins: vec!["BTC".to_string(), "ETH".to_string(), "XRP".to_string()],
+            min_net_spread_pct: 0.15,
+            upbit_taker_fee: 0.0005,
+            bithumb_taker_fee: 0.0004,
+            max_order_krw: Decimal::from(1_000_000),
+            min_order_krw: Decimal::from(5_000),
+            safe_volume_ratio: 0.7,
+            orderbook_depth: 15,
+            orderbook_max_age_sec: 2,
+            poll_interval_ms: 500,
+            cooldown_sec: 3,
+            max_inventory_skew: 0.8,
+            order_timeout_sec: 5,
+            total_capital_krw: Decimal::from(10_000_000),
+            max_daily_loss_krw: Decimal::from(200_000),
+            max_single_loss_krw: Decimal::from(50_000),
+            kill_switch_enabled: true,
+            output: OutputConfig::default(),
+        }
+    }
+}
+
+/// `krw_spread.toml` 최상위 래퍼.
+#[derive(Deserialize)]
+struct TomlWrapper {
+    #[serde(default)]
+    krw_spread: KrwSpreadConfig,
+}
+
+impl KrwSpreadConfig {
+    /// 파일에서 설정을 로드합니다.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, StrategyError> {
+        let content = std::fs::read_to_string(path.as_ref())?;
+        let config = Self::from_toml_str(&content)?;
+        info!(path = %path.as_ref().display(), "KRW 스프레드 설정 파일 로드 완료");
+        Ok(config)
+    }
+
+    /// TOML 문자열에서 설정을 파싱하고 검증합니다.
+    ///
+    /// 코인 심볼은 대문자로 정규화합니다.
+    pub fn from_toml_str(s: &str) -> Result<Self, StrategyError> {
+        let wrapper: TomlWrapper = toml::from_str(s)
+            .map_err(|e| StrategyError::Config(format!("TOML parse error: {e}")))?;
+        let mut config = wrapper.krw_spread;
+        for coin in &mut config.coins {
+            *coin = coin.trim().to_uppercase();
+        }
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// 설정값 유효성 검증.
+    pub fn validate(&self) -> Result<(), StrategyError> {
+        if self.coins.is_empty() {
+            return Err(StrategyError::Config("coins must not be empty".to_string()));
+        }
+        if self.min_net_spread_pct <= 0.0 {
+            return Err(StrategyError::Config(
+                "min_net_spread_pct must be positive".to_string(),
+            ));
+        }
+        for (key, fee) in [
+            ("upbit_taker_fee", self.upbit_taker_fee),
+            ("bithumb_taker_fee", self.bithumb_taker_fee),
+        ] {
+            if !(0.0..0.01).contains(&fee) {
+                return Err(StrategyError::Config(format!(
+                    "{key} must be in [0, 0.01), got: {fee}"
+                )));
+            }
+        }
+        if self.min_order_krw <= Decimal::ZERO || self.max_order_krw < self.min_order_krw {
+            return Err(StrategyError::Config(
+                "max_order_krw must be >= min_order_krw > 0".to_string(),
+            ));
+        }
+        if self.safe_volume_ratio <= 0.0 || self.safe_volume_ratio > 1.0 {
+            return Err(StrategyError::Config(format!(
+                "safe_volume_ratio must be in (0, 1.0], got: {}",
+                self.safe_volume_ratio
+            )));
+        }
+        if self.max_inventory_skew <= 0.5 || self.max_inventory_skew > 1.0 {
+            return Err(StrategyError::Config(format!(
+                "max_inventory_skew must be in (0.5, 1.0], got: {}",
+                self.max_inventory_skew
+            )));
+        }
+        if self.poll_interval_ms == 0 || self.orderbook_max_age_sec == 0 {
+            return Err(StrategyError::Config(
+                "poll_interval_ms and orderbook_max_age_sec must be greater than 0".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// `RiskManager`용 한도를 구성합니다.
+    ///
+    /// `RiskConfig`의 금액 필드는 USDT로 명명되어 있지만 통화를 해석하지 않으므로
+    /// 이 전략에서는 KRW 금액을 그대로 담습니다. 드로다운/rolling 24h 한도는
+    /// 일일 손실 한도를 공유하고, 동시 포지션 개념이 없으므로 코인 수를 상한으로 둡니다.
+    pub fn risk_config(&self) -> RiskConfig {
+        RiskConfig {
+            max_daily_loss_usdt: self.max_daily_loss_krw,
+            max_drawdown_usdt: self.max_daily_loss_krw,
+            max_rolling_24h_loss_usdt: self.max_daily_loss_krw,
+            max_single_loss_usdt: self.max_single_loss_krw,
+            max_order_size_usdt: self.max_order_krw,
+            max_concurrent_positions: self.coins.len(),
+            total_capital_usdt: self.total_capital_krw,
+            kill_switch_enabled: self.kill_switch_enabled,
+            ..RiskConfig::default()
+        }
+    }
+
+    /// 거래소의 taker 수수료율을 반환합니다 (이 전략이 다루지 않는 Bybit는 0).
+    pub fn taker_fee(&self, exchange: Exchange) -> f64 {
+        match exchange {
+            Exchange::Upbit => self.upbit_taker_fee,
+            Exchange::Bithumb => self.bithumb_taker_fee,
+            Exchange::Bybit => 0.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_is_valid() {
+        assert!(KrwSpreadConfig::default().validate().is_ok());
+    }
+
+    #[test]
+    fn test_from_toml_str_partial_section() {
+        let config = KrwSpreadConfig::from_toml_str(
+            r#"
+[krw_spread]
+coins = ["btc", " sol "]
+min_net_spread_pct = 0.2
+max_order_krw = 500000
+"#,
+        )
+        .unwrap();
+        assert_eq!(config.coins, vec!["BTC", "SOL"]);
+        assert_eq!(config.min_net_spread_pct, 0.2);
+        assert_eq!(config.max_order_krw, Decimal::from(500_000));
+        // 나머지는 기본값
+        assert_eq!(config.bithumb_taker_fee, 0.0004);
+        assert_eq!(config.taker_fee(Exchange::Upbit), 0.0005);
+    }
+
+    #[test]
+    fn test_validate_rejects_invalid_values() {
+        let config = KrwSpreadConfig {
+            coins: vec![],
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+
+        let config = KrwSpreadConfig {
+            max_order_krw: Decimal::from(1_000),
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+
+        let config = KrwSpreadConfig {
+            max_inventory_skew: 0.5,
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_risk_config_uses_krw_limits() {
+        let config = KrwSpreadConfig::default();
+        let risk = config.risk_config();
+        assert_eq!(risk.max_daily_loss_usdt, Decimal::from(200_000));
+        assert_eq!(risk.max_order_size_usdt, Decimal::from(1_000_000));
+        assert_eq!(risk.total_capital_usdt, Decimal::from(10_000_000));
+        assert_eq!(risk.max_concurrent_positions, 3);
+    }
+}
@@ -0,0 +1,303 @@
+//! 양쪽 거래소 사전 배치 재고 관리.
+//!
+//! 스프레드 거래는 매수 거래소의 KRW와 매도 거래소의 코인을 동시에 소모합니다.
+//! 주문 전 `reserve`로 필요한 수량을 선점하고, 체결 후 `settle`로 실제 체결량을
+//! 반영합니다. 거래가 한 방향으로 반복되면 코인이 한쪽으로 쏠리므로
+//! `skew`로 리밸런싱 필요 여부를 판단합니다.
+
+use std::collections::HashMap;
+
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive as _;
+use tracing::debug;
+
+use crate::error::PositionError;
+use crate::krw_spread::signal::SpreadDirection;
+use crate::zscore::orderbook::Exchange;
+
+/// KRW 통화 코드.
+pub const KRW: &str = "KRW";
+
+/// 한 레그의 실제 체결 결과.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LegFill {
+    /// 체결 수량 (코인).
+    pub qty: Decimal,
+    /// 평균 체결가 (KRW).
+    pub avg_price: Decimal,
+    /// 지불 수수료 (KRW).
+    pub fee_krw: Decimal,
+}
+
+impl LegFill {
+    /// 체결 금액 (KRW, 수수료 제외).
+    pub fn notional(&self) -> Decimal {
+        self.qty * self.avg_price
+    }
+}
+
+/// 주문 전 선점한 재고.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InventoryReservation {
+    /// 코인 심볼.
+    pub coin: String,
+    /// 거래 방향.
+    pub direction: SpreadDirection,
+    /// 매도 거래소에서 선점한 코인 수량.
+    pub coin_qty: Decimal,
+    /// 매수 거래소에서 선점한 KRW (수수료 포함).
+    pub krw_amount: Decimal,
+}
+
+/// 거래소별 잔고 장부.
+///
+/// `(거래소, 통화)` 단위로 잔고와 선점 수량을 관리합니다.
+/// 가용 잔고 = 잔고 - 선점 수량.
+#[derive(Debug, Default)]
+pub struct InventoryLedger {
+    /// 잔고.
+    balances: HashMap<(Exchange, String), Decimal>,
+    /// 주문 중 선점 수량.
+    reserved: HashMap<(Exchange, String), Decimal>,
+}
+
+impl InventoryLedger {
+    /// 빈 장부를 생성합니다.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 잔고를 설정합니다 (시작 시 또는 거래소 잔고 동기화).
+    pub fn set_balance(&mut self, exchange: Exchange, currency: &str, amount: Decimal) {
+        self.balances
+            .insert((exchange, currency.to_uppercase()), amount);
+    }
+
+    /// 잔고를 조회합니다 (선점 포함).
+    pub fn balance(&self, exchange: Exchange, currency: &str) -> Decimal {
+        self.balances
+            .get(&(exchange, currency.to_uppercase()))
+            .copied()
+            .unwrap_or(Decimal::ZERO)
+    }
+
+    /// 가용 잔고를 조회합니다 (잔고 - 선점).
+    pub fn available(&self, exchange: Exchange, currency: &str) -> Decimal {
+        let reserved = self
+            .reserved
+            .get(&(exchange, currency.to_uppercase()))
+            .copied()
+            .unwrap_or(Decimal::ZERO);
+        self.balance(exchange, currency) - reserved
+    }
+
+    /// 스프레드 거래에 필요한 재고를 선점합니다.
+    ///
+    /// 매수 거래소 KRW(`krw_amount`)와 매도 거래소 코인(`coin_qty`)이 모두
+    /// 가용해야 하며, 하나라도 부족하면 아무것도 선점하지 않습니다.
+    pub fn reserve(
+        &mut self,
+        coin: &str,
+        direction: SpreadDirection,
+        coin_qty: Decimal,
+        krw_amount: Decimal,
+    ) -> Result<InventoryReservation, PositionError> {
+        let buy = direction.buy_exchange();
+        let sell = direction.sell_exchange();
+
+        let available_krw = self.available(buy, KRW);
+        if available_krw < krw_amount {
+            return Err(PositionError::InsufficientCapital {
+                required: krw_amount,
+                available: available_krw,
+            });
+        }
+        let available_coin = self.available(sell, coin);
+        if available_coin < coin_qty {
+            return Err(PositionError::InsufficientCapital {
+                required: coin_qty,
+                available: available_coin,
+            });
+        }
+
+        *self.reserved.entry((buy, KRW.to_string())).or_default() += krw_amount;
+        *self
+            .reserved
+            .entry((sell, coin.to_uppercase()))
+            .or_default() += coin_qty;
+
+        debug!(
+            coin = coin,
+            direction = %direction,
+            coin_qty = %coin_qty,
+            krw_amount = %krw_amount,
+            "재고 선점"
+        );
+
+        Ok(InventoryReservation {
+            coin: coin.to_uppercase(),
+            direction,
+            coin_qty,
+            krw_amount,
+        })
+    }
+
+    /// 선점을 해제합니다 (주문 실패 또는 체결 반영 전 단계).
+    pub fn release(&mut self, reservation: &InventoryReservation) {
+        let buy = reservation.direction.buy_exchange();
+        let sell = reservation.direction.sell_exchange();
+        if let Some(r) = self.reserved.get_mut(&(buy, KRW.to_string())) {
+            *r = (*r - reservation.krw_amount).max(Decimal::ZERO);
+        }
+        if let Some(r) = self.reserved.get_mut(&(sell, reservation.coin.clone())) {
+            *r = (*r - reservation.coin_qty).max(Decimal::ZERO);
+        }
+    }
+
+    /// 선점을 해제하고 실제 체결량을 잔고에 반영합니다.
+    ///
+    /// 두 레그 체결량이 다르면 차이만큼 코인 총량이 변하며,
+    /// 이는 다음 리밸런싱에서 흡수됩니다.
+    pub fn settle(&mut self, reservation: &InventoryReservation, buy: &LegFill, sell: &LegFill) {
+        self.release(reservation);
+
+        let coin = reservation.coin.as_str();
+        let buy_ex = reservation.direction.buy_exchange();
+        let sell_ex = reservation.direction.sell_exchange();
+
+        let buy_krw = self.balance(buy_ex, KRW) - buy.notional() - buy.fee_krw;
+        let buy_coin = self.balance(buy_ex, coin) + buy.qty;
+        let sell_krw = self.balance(sell_ex, KRW) + sell.notional() - sell.fee_krw;
+        let sell_coin = self.balance(sell_ex, coin) - sell.qty;
+
+        self.set_balance(buy_ex, KRW, buy_krw);
+        self.set_balance(buy_ex, coin, buy_coin);
+        self.set_balance(sell_ex, KRW, sell_krw);
+        self.set_balance(sell_ex, coin, sell_coin);
+    }
+
+    /// 코인 총량 중 Upbit 보유 비율 (0.0 ~ 1.0). 총량이 0이면 `None`.
+    pub fn skew(&self, coin: &str) -> Option<f64> {
+        let upbit = self.balance(Exchange::Upbit, coin);
+        let total = upbit + self.balance(Exchange::Bithumb, coin);
+        if total <= Decimal::ZERO {
+            return None;
+        }
+        (upbit / total).to_f64()
+    }
+
+    /// 한 거래소의 코인 보유 비율이 `max_skew`를 넘었는지 확인합니다.
+    pub fn needs_rebalance(&self, coin: &str, max_skew: f64) -> bool {
+        self.skew(coin)
+            .is_some_and(|upbit_share| upbit_share > max_skew || 1.0 - upbit_share > max_skew)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seeded_ledger() -> InventoryLedger {
+        let mut ledger = InventoryLedger::new();
+        for ex in [Exchange::Upbit, Exchange::Bithumb] {
+            ledger.set_balance(ex, KRW, Decimal::from(1_000_000));
+            ledger.set_balance(ex, "XRP", Decimal::from(1_000));
+        }
+        ledger
+    }
+
+    #[test]
+    fn test_reserve_checks_both_legs() {
+        let mut ledger = seeded_ledger();
+        let dir = SpreadDirection::BuyUpbitSellBithumb;
+
+        // KRW 부족
+        assert!(
+            ledger
+                .reserve("XRP", dir, Decimal::from(10), Decimal::from(2_000_000))
+                .is_err()
+        );
+        // 코인 부족
+        assert!(
+            ledger
+                .reserve("XRP", dir, Decimal::from(2_000), Decimal::from(10_000))
+                .is_err()
+        );
+        // 실패 시 선점 없음
+        assert_eq!(
+            ledger.available(Exchange::Upbit, KRW),
+            Decimal::from(1_000_000)
+        );
+
+        let r = ledger
+            .reserve("xrp", dir, Decimal::from(100), Decimal::from(100_000))
+            .unwrap();
+        assert_eq!(
+            ledger.available(Exchange::Upbit, KRW),
+            Decimal::from(900_000)
+        );
+        assert_eq!(
+            ledger.available(Exchange::Bithumb, "XRP"),
+            Decimal::from(900)
+        );
+
+        ledger.release(&r);
+        assert_eq!(
+            ledger.available(Exchange::Upbit, KRW),
+            Decimal::from(1_000_000)
+        );
+        assert_eq!(
+            ledger.available(Exchange::Bithumb, "XRP"),
+            Decimal::from(1_000)
+        );
+    }
+
+    #[test]
+    fn test_settle_moves_inventory() {
+        let mut ledger = seeded_ledger();
+        let r = ledger
+            .reserve(
+                "XRP",
+                SpreadDirection::BuyUpbitSellBithumb,
+                Decimal::from(100),
+                Decimal::from(100_100),
+            )
+            .unwrap();
+        let buy = LegFill {
+            qty: Decimal::from(100),
+            avg_price: Decimal::from(1_000),
+            fee_krw: Decimal::from(50),
+        };
+        let sell = LegFill {
+            qty: Decimal::from(100),
+            avg_price: Decimal::from(1_010),
+            fee_krw: Decimal::from(40),
+        };
+        ledger.settle(&r, &buy, &sell);
+
+        assert_eq!(ledger.balance(Exchange::Upbit, KRW), Decimal::from(899_950));
+        assert_eq!(ledger.balance(Exchange::Upbit, "XRP"), Decimal::from(1_100));
+        assert_eq!(
+            ledger.balance(Exchange::Bithumb, KRW),
+            Decimal::from(1_100_960)
+        );
+        assert_eq!(ledger.balance(Exchange::Bithumb, "XRP"), Decimal::from(900));
+        // 선점 해제 확인
+        assert_eq!(
+            ledger.available(Exchange::Upbit, KRW),
+            ledger.balance(Exchange::Upbit, KRW)
+        );
+    }
+
+    #[test]
+    fn test_needs_rebalance() {
+        let mut ledger = seeded_ledger();
+        assert_eq!(ledger.skew("XRP"), Some(0.5));
+        assert!(!ledger.needs_rebalance("XRP", 0.8));
+
+        ledger.set_balance(Exchange::Bithumb, "XRP", Decimal::from(100));
+        assert!(ledger.needs_rebalance("XRP", 0.8));
+        assert_eq!(ledger.skew("BTC"), None);
+        assert!(!ledger.needs_rebalance("BTC", 0.8));
+    }
+}
@@ -0,0 +1,15 @@
+//! Upbit-Bithumb KRW 현물 간 스프레드 차익거래 전략 모듈.
+//!
+//! 같은 코인을 싼 거래소에서 사고 비싼 거래소에서 동시에 팝니다.
+//! 양쪽 거래소에 KRW와 코인을 미리 배치해 두고 거래하므로 송금 대기가 없고,
+//! 환율 변환이나 펀딩비도 없습니다.
+//!
+//! Z-Score 전략의 `SharedObCache`, `ExecutionPolicy`, `RiskManager`,
+//! `SessionWriter`를 재사용하며, 시그널 규칙과 재고 관리만 별도로 둡니다.
+
+pub mod config;
+pub mod inventory;
+pub mod monitor;
+pub mod policy;
+pub mod signal;
+pub mod trade;
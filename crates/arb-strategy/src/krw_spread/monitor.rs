@@ -0,0 +1,320 @@
+//! KRW 스프레드 모니터.
+//!
+//! 주기적으로 양쪽 거래소 오더북을 조회해 `SharedObCache`에 저장하고,
+//! 시그널이 발생하면 실행 정책에 전달합니다. 워밍업/분봉 집계가 필요 없으므로
+//! Z-Score 모니터와 달리 폴링 루프 하나로 동작합니다.
+
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use arb_exchange::MarketData;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+
+use crate::error::StrategyError;
+use crate::krw_spread::config::KrwSpreadConfig;
+use crate::krw_spread::policy::SpreadEntryContext;
+use crate::krw_spread::signal::evaluate_signal;
+use crate::zscore::execution_policy::ExecutionPolicy;
+use crate::zscore::orderbook::{Exchange, SharedObCache};
+use crate::zscore::risk::RiskManager;
+
+/// Upbit-Bithumb KRW 스프레드 모니터.
+pub struct KrwSpreadMonitor<U, H, P> {
+    upbit: Arc<U>,
+    bithumb: Arc<H>,
+    policy: Arc<P>,
+    config: Arc<KrwSpreadConfig>,
+    risk: Arc<RiskManager>,
+    ob_cache: SharedObCache,
+    /// 코인별 마지막 진입 시각 (cooldown 판단).
+    last_entry: HashMap<String, Instant>,
+}
+
+impl<U, H, P> KrwSpreadMonitor<U, H, P>
+where
+    U: MarketData + 'static,
+    H: MarketData + 'static,
+    P: ExecutionPolicy<SpreadEntryContext, Infallible, Infallible>,
+{
+    /// 새 모니터를 생성합니다.
+    ///
+    /// `risk`는 정책과 같은 `RiskManager`(`SpreadResources::risk`)를 전달해
+    /// 거래소 연결 상태가 진입 허용 판단에 반영되도록 합니다.
+    pub fn new(
+        upbit: Arc<U>,
+        bithumb: Arc<H>,
+        policy: Arc<P>,
+        config: Arc<KrwSpreadConfig>,
+        risk: Arc<RiskManager>,
+    ) -> Self {
+        Self {
+            upbit,
+            bithumb,
+            policy,
+            config,
+            risk,
+            ob_cache: SharedObCache::new(),
+            last_entry: HashMap::new(),
+        }
+    }
+
+    /// 오더북 캐시를 반환합니다 (상태 조회용).
+    pub fn ob_cache(&self) -> &SharedObCache {
+        &self.ob_cache
+    }
+
+    /// 취소될 때까지 폴링 루프를 실행합니다.
+    pub async fn run(&mut self, cancel: CancellationToken) -> Result<(), StrategyError> {
+        info!(
+            coins = ?self.config.coins,
+            min_net_spread_pct = self.config.min_net_spread_pct,
+            poll_interval_ms = self.config.poll_interval_ms,
+            "KRW 스프레드 모니터 시작"
+        );
+
+        let mut interval =
+            tokio::time::interval(Duration::from_millis(self.config.poll_interval_ms));
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+        loop {
+            tokio::select! {
+                _ = cancel.cancelled() => {
+                    info!("KRW 스프레드 모니터 종료");
+                    self.policy.on_shutdown().await;
+                    return Ok(());
+                }
+                _ = interval.tick() => {
+                    let coins = self.config.coins.clone();
+                    for coin in &coins {
+                        self.poll_coin(coin).await;
+                    }
+                }
+            }
+        }
+    }
+
+    /// 한 코인의 오더북을 갱신하고 시그널을 평가합니다.
+    ///
+    /// 진입 정책을 호출했으면 `true`를 반환합니다.
+    pub async fn poll_coin(&mut self, coin: &str) -> bool {
+        let market = format!("KRW-{coin}");
+        let depth = Some(self.config.orderbook_depth);
+        let (upbit_result, bithumb_result) = tokio::join!(
+            self.upbit.get_orderbook(&market, depth),
+            self.bithumb.get_orderbook(&market, depth)
+        );
+        // RiskManager의 두 번째 연결 슬롯(bybit)은 이 전략에서 Bithumb을 뜻함
+        self.risk
+            .check_connection_health(upbit_result.is_ok(), bithumb_result.is_ok());
+
+        {
+            let mut data = self.ob_cache.data.write().await;
+            for (exchange, result) in [
+                (Exchange::Upbit, upbit_result),
+                (Exchange::Bithumb, bithumb_result),
+            ] {
+                match result {
+                    Ok(ob) => data.update(exchange, coin, ob),
+                    Err(e) => {
+                        warn!(coin = coin, exchange = ?exchange, error = %e, "오더북 조회 실패");
+                    }
+                }
+            }
+        }
+
+        if !self.policy.is_entry_allowed() {
+            debug!(coin = coin, "진입 차단 상태, 시그널 평가 스킵");
+            return false;
+        }
+        let cooldown = Duration::from_secs(self.config.cooldown_sec);
+        if self
+            .last_entry
+            .get(coin)
+            .is_some_and(|at| at.elapsed() < cooldown)
+        {
+            return false;
+        }
+
+        let signal = {
+            let data = self.ob_cache.data.read().await;
+            let max_age = self.config.orderbook_max_age_sec;
+            if !data.is_fresh(Exchange::Upbit, coin, max_age)
+                || !data.is_fresh(Exchange::Bithumb, coin, max_age)
+            {
+                debug!(coin = coin, "오더북 캐시 만료, 시그널 평가 스킵");
+                return false;
+            }
+            let (Some(upbit), Some(bithumb)) = (
+                data.get(Exchange::Upbit, coin),
+                data.get(Exchange::Bithumb, coin),
+            ) else {
+                return false;
+            };
+            evaluate_signal(coin, &upbit.orderbook, &bithumb.orderbook, &self.config)
+        };
+        let Some(signal) = signal else {
+            return false;
+        };
+
+        info!(
+            coin = coin,
+            direction = %signal.direction,
+            qty = %signal.qty,
+            buy_price = %signal.buy_price,
+            sell_price = %signal.sell_price,
+            gross_spread_pct = signal.gross_spread_pct,
+            net_spread_pct = signal.volume.net_spread_pct,
+            "KRW 스프레드 진입 시그널"
+        );
+
+        self.last_entry.insert(coin.to_string(), Instant::now());
+        if let Err(e) = self.policy.on_entry_signal(signal.into()).await {
+            warn!(coin = coin, error = %e, "스프레드 진입 처리 실패");
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::krw_spread::inventory::{InventoryLedger, KRW};
+    use crate::krw_spread::policy::{KrwSpreadSimPolicy, SpreadResources};
+    use arb_exchange::{Candle, CandleInterval, ExchangeResult, OrderBook, OrderBookLevel, Ticker};
+    use chrono::{DateTime, Utc};
+    use rust_decimal::Decimal;
+
+    /// 고정 호가를 반환하는 테스트용 거래소.
+    struct FixedBook {
+        ask: i64,
+        bid: i64,
+    }
+
+    impl MarketData for FixedBook {
+        fn name(&self) -> &str {
+            "fixed"
+        }
+
+        async fn get_ticker(&self, _markets: &[&str]) -> ExchangeResult<Vec<Ticker>> {
+            Ok(vec![])
+        }
+
+        async fn get_orderbook(
+            &self,
+            market: &str,
+            _depth: Option<u32>,
+        ) -> ExchangeResult<OrderBook> {
+            let level = |price: i64| OrderBookLevel {
+                price: Decimal::from(price),
+                size: Decimal::from(1_000),
+            };
+            Ok(OrderBook {
+                market: market.to_string(),
+                asks: vec![level(self.ask)],
+                bids: vec![level(self.bid)],
+                total_ask_size: Decimal::from(1_000),
+                total_bid_size: Decimal::from(1_000),
+                timestamp: Utc::now(),
+            })
+        }
+
+        async fn get_candles(
+            &self,
+            _market: &str,
+            _interval: CandleInterval,
+            _count: u32,
+        ) -> ExchangeResult<Vec<Candle>> {
+            Ok(vec![])
+        }
+
+        async fn get_candles_before(
+            &self,
+            _market: &str,
+            _interval: CandleInterval,
+            _count: u32,
+            _before: DateTime<Utc>,
+        ) -> ExchangeResult<Vec<Candle>> {
+            Ok(vec![])
+        }
+
+        async fn get_all_tickers(&self) -> ExchangeResult<Vec<Ticker>> {
+            Ok(vec![])
+        }
+
+        fn market_code(base: &str, quote: &str) -> String {
+            format!("{quote}-{base}")
+        }
+    }
+
+    fn make_monitor(
+        upbit: FixedBook,
+        bithumb: FixedBook,
+    ) -> (
+        KrwSpreadMonitor<FixedBook, FixedBook, KrwSpreadSimPolicy>,
+        SpreadResources,
+    ) {
+        let config = Arc::new(KrwSpreadConfig {
+            coins: vec!["XRP".to_string()],
+            ..Default::default()
+        });
+        let mut inventory = InventoryLedger::new();
+        for ex in [Exchange::Upbit, Exchange::Bithumb] {
+            inventory.set_balance(ex, KRW, Decimal::from(10_000_000));
+            inventory.set_balance(ex, "XRP", Decimal::from(10_000));
+        }
+        let resources = SpreadResources::new(config.clone(), inventory, None);
+        let policy = Arc::new(KrwSpreadSimPolicy::new(resources.clone()));
+        let monitor = KrwSpreadMonitor::new(
+            Arc::new(upbit),
+            Arc::new(bithumb),
+            policy,
+            config,
+            resources.risk.clone(),
+        );
+        (monitor, resources)
+    }
+
+    #[tokio::test]
+    async fn test_poll_coin_executes_signal_and_respects_cooldown() {
+        let (mut monitor, resources) = make_monitor(
+            FixedBook {
+                ask: 1_000,
+                bid: 998,
+            },
+            FixedBook {
+                ask: 1_012,
+                bid: 1_010,
+            },
+        );
+
+        assert!(monitor.poll_coin("XRP").await);
+        assert_eq!(resources.trades.lock().await.len(), 1);
+        assert!(
+            monitor
+                .ob_cache()
+                .data
+                .read()
+                .await
+                .is_fresh(Exchange::Bithumb, "XRP", 5)
+        );
+
+        // cooldown 중에는 재진입하지 않음
+        assert!(!monitor.poll_coin("XRP").await);
+        assert_eq!(resources.trades.lock().await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_poll_coin_no_signal_within_fees() {
+        let book = || FixedBook {
+            ask: 1_001,
+            bid: 1_000,
+        };
+        let (mut monitor, resources) = make_monitor(book(), book());
+
+        assert!(!monitor.poll_coin("XRP").await);
+        assert!(resources.trades.lock().await.is_empty());
+    }
+}
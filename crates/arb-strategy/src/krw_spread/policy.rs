@@ -0,0 +1,425 @@
+//! KRW 스프레드 실행 정책.
+//!
+//! `ExecutionPolicy<SpreadEntryContext, Infallible, Infallible>` 구현체입니다.
+//! 스프레드 거래는 매수/매도 레그를 동시에 체결하고 끝나므로 청산/TTL 경로가 없습니다.
+//!
+//! - `KrwSpreadSimPolicy`: 시그널 VWAP으로 즉시 가상 체결
+//! - `KrwSpreadLivePolicy`: 양쪽 거래소에 IOC 지정가 주문을 동시에 발주
+//!
+//! 두 정책 모두 `SpreadResources`를 통해 재고 선점/정산, `RiskManager` 기록,
+//! 세션 CSV 기록을 공유합니다.
+
+use std::convert::Infallible;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use arb_exchange::{
+    ExchangeError, ExchangeResult, Order, OrderManagement, OrderRequest, TimeInForce,
+};
+use rust_decimal::Decimal;
+use rust_decimal::prelude::FromPrimitive as _;
+use tracing::{error, info, warn};
+
+use crate::error::StrategyError;
+use crate::krw_spread::config::KrwSpreadConfig;
+use crate::krw_spread::inventory::{InventoryLedger, InventoryReservation, LegFill};
+use crate::krw_spread::signal::{SpreadDirection, SpreadSignal};
+use crate::krw_spread::trade::SpreadTrade;
+use crate::output::writer::SessionWriter;
+use crate::zscore::execution_policy::ExecutionPolicy;
+use crate::zscore::orderbook::Exchange;
+use crate::zscore::risk::RiskManager;
+
+/// 스프레드 진입 컨텍스트 (owned 스냅샷, Send + 'static).
+#[derive(Debug, Clone)]
+pub struct SpreadEntryContext {
+    /// 코인 심볼.
+    pub coin: String,
+    /// 거래 방향.
+    pub direction: SpreadDirection,
+    /// 주문 수량 (코인).
+    pub qty: Decimal,
+    /// 매수 IOC 지정가 (KRW).
+    pub buy_price: Decimal,
+    /// 매도 IOC 지정가 (KRW).
+    pub sell_price: Decimal,
+    /// 매수 VWAP (KRW, 시뮬레이션 체결가).
+    pub buy_vwap: f64,
+    /// 매도 VWAP (KRW, 시뮬레이션 체결가).
+    pub sell_vwap: f64,
+    /// 기대 순 스프레드 (%).
+    pub net_spread_pct: f64,
+}
+
+impl From<SpreadSignal> for SpreadEntryContext {
+    fn from(signal: SpreadSignal) -> Self {
+        Self {
+            coin: signal.coin,
+            direction: signal.direction,
+            qty: signal.qty,
+            buy_price: signal.buy_price,
+            sell_price: signal.sell_price,
+            buy_vwap: signal.volume.buy_vwap,
+            sell_vwap: signal.volume.sell_vwap,
+            net_spread_pct: signal.volume.net_spread_pct,
+        }
+    }
+}
+
+/// 스프레드 정책 공유 리소스.
+///
+/// 모니터와 정책이 같은 재고 장부/거래 목록/세션 writer를 참조하도록
+/// `Arc`로 묶어 전달합니다.
+#[derive(Clone)]
+pub struct SpreadResources {
+    /// 전략 설정.
+    pub config: Arc<KrwSpreadConfig>,
+    /// 양쪽 거래소 재고 장부.
+    pub inventory: Arc<parking_lot::Mutex<InventoryLedger>>,
+    /// 리스크 관리자 (금액 단위 KRW).
+    pub risk: Arc<RiskManager>,
+    /// 체결 완료된 스프레드 거래 목록.
+    pub trades: Arc<tokio::sync::Mutex<Vec<SpreadTrade>>>,
+    /// 세션 CSV 기록기.
+    pub session_writer: Arc<tokio::sync::Mutex<Option<SessionWriter>>>,
+    /// 다음 거래 ID.
+    next_trade_id: Arc<AtomicU64>,
+}
+
+impl SpreadResources {
+    /// 설정과 초기 재고로 공유 리소스를 생성합니다.
+    pub fn new(
+        config: Arc<KrwSpreadConfig>,
+        inventory: InventoryLedger,
+        session_writer: Option<SessionWriter>,
+    ) -> Self {
+        let risk = Arc::new(RiskManager::new(config.risk_config()));
+        Self {
+            config,
+            inventory: Arc::new(parking_lot::Mutex::new(inventory)),
+            risk,
+            trades: Arc::new(tokio::sync::Mutex::new(Vec::new())),
+            session_writer: Arc::new(tokio::sync::Mutex::new(session_writer)),
+            next_trade_id: Arc::new(AtomicU64::new(1)),
+        }
+    }
+
+    /// 매수 KRW(수수료 포함) + 매도 코인을 선점합니다.
+    ///
+    /// 재고 부족이면 `None`을 반환하고 진입을 건너뜁니다.
+    fn reserve(&self, ctx: &SpreadEntryContext) -> Option<InventoryReservation> {
+        let buy_fee = Decimal::from_f64(self.config.taker_fee(ctx.direction.buy_exchange()))
+            .unwrap_or(Decimal::ZERO);
+        let krw_amount = ctx.qty * ctx.buy_price * (Decimal::ONE + buy_fee);
+        match self
+            .inventory
+            .lock()
+            .reserve(&ctx.coin, ctx.direction, ctx.qty, krw_amount)
+        {
+            Ok(reservation) => Some(reservation),
+            Err(e) => {
+                info!(
+                    coin = ctx.coin.as_str(),
+                    direction = %ctx.direction,
+                    error = %e,
+                    "재고 부족으로 스프레드 진입 스킵"
+                );
+                None
+            }
+        }
+    }
+
+    /// 체결 결과를 재고/리스크/세션 파일에 반영합니다.
+    ///
+    /// 양쪽 레그 모두 미체결이면 선점만 해제하고 거래를 기록하지 않습니다.
+    async fn settle(
+        &self,
+        ctx: &SpreadEntryContext,
+        reservation: &InventoryReservation,
+        buy: LegFill,
+        sell: LegFill,
+    ) {
+        let needs_rebalance = {
+            let mut inventory = self.inventory.lock();
+            inventory.settle(reservation, &buy, &sell);
+            inventory.needs_rebalance(&ctx.coin, self.config.max_inventory_skew)
+        };
+        if buy.qty.is_zero() && sell.qty.is_zero() {
+            return;
+        }
+
+        let id = self.next_trade_id.fetch_add(1, Ordering::Relaxed);
+        let trade = SpreadTrade::from_fills(
+            id,
+            &ctx.coin,
+            ctx.direction,
+            &buy,
+            &sell,
+            ctx.net_spread_pct,
+        );
+
+        info!(
+            coin = ctx.coin.as_str(),
+            direction = %ctx.direction,
+            buy_qty = %trade.buy_qty,
+            sell_qty = %trade.sell_qty,
+            net_pnl = %trade.net_pnl,
+            expected_spread_pct = trade.expected_spread_pct,
+            realized_spread_pct = trade.realized_spread_pct,
+            "스프레드 거래 체결"
+        );
+        if !trade.unmatched_qty.is_zero() {
+            warn!(
+                coin = ctx.coin.as_str(),
+                unmatched_qty = %trade.unmatched_qty,
+                "레그 체결 수량 불일치, 차이는 재고로 흡수"
+            );
+        }
+        if needs_rebalance {
+            warn!(
+                coin = ctx.coin.as_str(),
+                max_inventory_skew = self.config.max_inventory_skew,
+                "코인 재고가 한쪽 거래소로 편중, 리밸런싱 필요"
+            );
+        }
+
+        if let Some(reason) = self.risk.record_trade(trade.net_pnl) {
+            error!(reason = ?reason, "스프레드 전략 kill switch 발동");
+        }
+        if let Some(writer) = self.session_writer.lock().await.as_mut()
+            && let Err(e) = writer.append_spread_trade(&trade)
+        {
+            warn!(error = %e, "스프레드 거래 CSV 기록 실패");
+        }
+        self.trades.lock().await.push(trade);
+    }
+}
+
+/// 시뮬레이션 스프레드 정책.
+///
+/// 시그널의 VWAP으로 요청 수량 전량이 체결된 것으로 간주합니다.
+pub struct KrwSpreadSimPolicy {
+    resources: SpreadResources,
+}
+
+impl KrwSpreadSimPolicy {
+    /// 공유 리소스로 시뮬레이션 정책을 생성합니다.
+    pub fn new(resources: SpreadResources) -> Self {
+        Self { resources }
+    }
+
+    /// 가상 체결 결과를 만듭니다 (VWAP 체결가, 설정 수수료율).
+    fn simulated_fill(&self, exchange: Exchange, qty: Decimal, vwap: f64) -> LegFill {
+        let avg_price = Decimal::from_f64(vwap).unwrap_or(Decimal::ZERO);
+        let fee_rate =
+            Decimal::from_f64(self.resources.config.taker_fee(exchange)).unwrap_or(Decimal::ZERO);
+        LegFill {
+            qty,
+            avg_price,
+            fee_krw: qty * avg_price * fee_rate,
+        }
+    }
+}
+
+impl ExecutionPolicy<SpreadEntryContext, Infallible, Infallible> for KrwSpreadSimPolicy {
+    async fn on_entry_signal(&self, ctx: SpreadEntryContext) -> Result<(), StrategyError> {
+        let Some(reservation) = self.resources.reserve(&ctx) else {
+            return Ok(());
+        };
+        let buy = self.simulated_fill(ctx.direction.buy_exchange(), ctx.qty, ctx.buy_vwap);
+        let sell = self.simulated_fill(ctx.direction.sell_exchange(), ctx.qty, ctx.sell_vwap);
+        self.resources.settle(&ctx, &reservation, buy, sell).await;
+        Ok(())
+    }
+
+    async fn on_exit_signal(&self, ctx: Infallible) -> Result<(), StrategyError> {
+        match ctx {}
+    }
+
+    async fn on_ttl_expiry(&self, ctx: Infallible) -> Result<(), StrategyError> {
+        match ctx {}
+    }
+
+    fn is_entry_allowed(&self) -> bool {
+        !self.resources.risk.is_killed()
+    }
+}
+
+/// 라이브 스프레드 정책.
+///
+/// 매수 거래소에 IOC 지정가 매수, 매도 거래소에 IOC 지정가 매도를 동시에 발주합니다.
+/// 한쪽만 체결되어도 비상 청산하지 않고 재고 차이로 남겨 두며,
+/// 편중이 임계값을 넘으면 경고합니다.
+pub struct KrwSpreadLivePolicy<U, H> {
+    upbit: Arc<U>,
+    bithumb: Arc<H>,
+    resources: SpreadResources,
+}
+
+impl<U, H> KrwSpreadLivePolicy<U, H>
+where
+    U: OrderManagement + 'static,
+    H: OrderManagement + 'static,
+{
+    /// 양쪽 거래소 클라이언트와 공유 리소스로 라이브 정책을 생성합니다.
+    pub fn new(upbit: Arc<U>, bithumb: Arc<H>, resources: SpreadResources) -> Self {
+        Self {
+            upbit,
+            bithumb,
+            resources,
+        }
+    }
+}
+
+/// 주문을 발주합니다. 타임아웃은 에러로 변환합니다.
+async fn place_with_timeout<C: OrderManagement>(
+    client: &C,
+    request: &OrderRequest,
+    timeout: Duration,
+) -> ExchangeResult<Order> {
+    tokio::time::timeout(timeout, client.place_order(request))
+        .await
+        .unwrap_or_else(|_| {
+            Err(ExchangeError::InternalError(format!(
+                "order timed out after {}s",
+                timeout.as_secs()
+            )))
+        })
+}
+
+/// 주문 응답을 레그 체결 결과로 변환합니다. 실패한 레그는 미체결로 취급합니다.
+fn leg_fill(exchange: Exchange, result: ExchangeResult<Order>, limit_price: Decimal) -> LegFill {
+    match result {
+        Ok(order) => LegFill {
+            qty: order.executed_volume,
+            avg_price: order.avg_price.unwrap_or(limit_price),
+            fee_krw: order.paid_fee,
+        },
+        Err(e) => {
+            error!(exchange = ?exchange, error = %e, "스프레드 레그 주문 실패");
+            LegFill::default()
+        }
+    }
+}
+
+impl<U, H> ExecutionPolicy<SpreadEntryContext, Infallible, Infallible> for KrwSpreadLivePolicy<U, H>
+where
+    U: OrderManagement + 'static,
+    H: OrderManagement + 'static,
+{
+    async fn on_entry_signal(&self, ctx: SpreadEntryContext) -> Result<(), StrategyError> {
+        let Some(reservation) = self.resources.reserve(&ctx) else {
+            return Ok(());
+        };
+
+        let market = format!("KRW-{}", ctx.coin);
+        let client_id = uuid::Uuid::now_v7().simple().to_string();
+        let buy_request = OrderRequest::limit_buy(&market, ctx.buy_price, ctx.qty)
+            .with_time_in_force(TimeInForce::Ioc)
+            .with_identifier(format!("sb{client_id}"));
+        let sell_request = OrderRequest::limit_sell(&market, ctx.sell_price, ctx.qty)
+            .with_time_in_force(TimeInForce::Ioc)
+            .with_identifier(format!("ss{client_id}"));
+
+        let timeout = Duration::from_secs(self.resources.config.order_timeout_sec);
+        let upbit = self.upbit.as_ref();
+        let bithumb = self.bithumb.as_ref();
+        let (buy_result, sell_result) = match ctx.direction {
+            SpreadDirection::BuyUpbitSellBithumb => tokio::join!(
+                place_with_timeout(upbit, &buy_request, timeout),
+                place_with_timeout(bithumb, &sell_request, timeout)
+            ),
+            SpreadDirection::BuyBithumbSellUpbit => tokio::join!(
+                place_with_timeout(bithumb, &buy_request, timeout),
+                place_with_timeout(upbit, &sell_request, timeout)
+            ),
+        };
+
+        let buy = leg_fill(ctx.direction.buy_exchange(), buy_result, ctx.buy_price);
+        let sell = leg_fill(ctx.direction.sell_exchange(), sell_result, ctx.sell_price);
+        self.resources.settle(&ctx, &reservation, buy, sell).await;
+        Ok(())
+    }
+
+    async fn on_exit_signal(&self, ctx: Infallible) -> Result<(), StrategyError> {
+        match ctx {}
+    }
+
+    async fn on_ttl_expiry(&self, ctx: Infallible) -> Result<(), StrategyError> {
+        match ctx {}
+    }
+
+    fn is_entry_allowed(&self) -> bool {
+        self.resources.risk.is_entry_allowed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::krw_spread::inventory::KRW;
+
+    fn make_resources() -> SpreadResources {
+        let mut inventory = InventoryLedger::new();
+        for ex in [Exchange::Upbit, Exchange::Bithumb] {
+            inventory.set_balance(ex, KRW, Decimal::from(1_000_000));
+            inventory.set_balance(ex, "XRP", Decimal::from(1_000));
+        }
+        SpreadResources::new(Arc::new(KrwSpreadConfig::default()), inventory, None)
+    }
+
+    fn make_ctx(qty: i64) -> SpreadEntryContext {
+        SpreadEntryContext {
+            coin: "XRP".to_string(),
+            direction: SpreadDirection::BuyUpbitSellBithumb,
+            qty: Decimal::from(qty),
+            buy_price: Decimal::from(1_000),
+            sell_price: Decimal::from(1_010),
+            buy_vwap: 1_000.0,
+            sell_vwap: 1_010.0,
+            net_spread_pct: 0.9,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sim_policy_settles_inventory_and_records_trade() {
+        let resources = make_resources();
+        let policy = KrwSpreadSimPolicy::new(resources.clone());
+        assert!(policy.is_entry_allowed());
+
+        policy.on_entry_signal(make_ctx(100)).await.unwrap();
+
+        let trades = resources.trades.lock().await;
+        assert_eq!(trades.len(), 1);
+        assert_eq!(trades[0].id, 1);
+        // 101,000 - 40.4 - (100,000 + 50) = 909.6
+        assert_eq!(trades[0].net_pnl, Decimal::new(9096, 1));
+
+        let inventory = resources.inventory.lock();
+        assert_eq!(
+            inventory.balance(Exchange::Upbit, "XRP"),
+            Decimal::from(1_100)
+        );
+        assert_eq!(
+            inventory.balance(Exchange::Bithumb, "XRP"),
+            Decimal::from(900)
+        );
+        assert_eq!(resources.risk.total_trade_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_sim_policy_skips_when_inventory_short() {
+        let resources = make_resources();
+        let policy = KrwSpreadSimPolicy::new(resources.clone());
+
+        // Bithumb XRP 1,000개 초과
+        policy.on_entry_signal(make_ctx(2_000)).await.unwrap();
+
+        assert!(resources.trades.lock().await.is_empty());
+        assert_eq!(
+            resources.inventory.lock().available(Exchange::Upbit, KRW),
+            Decimal::from(1_000_000)
+        );
+    }
+}
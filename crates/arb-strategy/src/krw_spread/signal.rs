@@ -0,0 +1,340 @@
+//! KRW 현물 간 스프레드 시그널.
+//!
+//! 같은 코인의 Upbit/Bithumb 오더북을 비교하여 싼 쪽 매수 + 비싼 쪽 매도의
+//! 수수료 차감 후 순 스프레드를 계산합니다. 환율 변환이나 rolling 통계 없이
+//! 오더북 스냅샷만으로 판단하며, 양방향 중 순 스프레드가 큰 쪽을 선택합니다.
+
+use std::fmt;
+
+use arb_exchange::OrderBook;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use tracing::trace;
+
+use crate::krw_spread::config::KrwSpreadConfig;
+use crate::zscore::orderbook::{Exchange, levels_to_f64};
+
+/// KRW 주문 수량 소수 자릿수 (Upbit/Bithumb 공통 상한).
+const QTY_DECIMALS: u32 = 8;
+
+/// 스프레드 거래 방향.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpreadDirection {
+    /// Upbit 매수 + Bithumb 매도 (Bithumb 가격이 높을 때).
+    BuyUpbitSellBithumb,
+    /// Bithumb 매수 + Upbit 매도 (Upbit 가격이 높을 때).
+    BuyBithumbSellUpbit,
+}
+
+impl SpreadDirection {
+    /// 매수 레그 거래소.
+    pub fn buy_exchange(self) -> Exchange {
+        match self {
+            Self::BuyUpbitSellBithumb => Exchange::Upbit,
+            Self::BuyBithumbSellUpbit => Exchange::Bithumb,
+        }
+    }
+
+    /// 매도 레그 거래소.
+    pub fn sell_exchange(self) -> Exchange {
+        match self {
+            Self::BuyUpbitSellBithumb => Exchange::Bithumb,
+            Self::BuyBithumbSellUpbit => Exchange::Upbit,
+        }
+    }
+
+    /// 출력용 문자열 ("upbit_to_bithumb" / "bithumb_to_upbit").
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::BuyUpbitSellBithumb => "upbit_to_bithumb",
+            Self::BuyBithumbSellUpbit => "bithumb_to_upbit",
+        }
+    }
+}
+
+impl fmt::Display for SpreadDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 오더북 two-pointer 소비 결과.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpreadVolume {
+    /// 순 스프레드가 임계값 이상인 최대 수량 (코인).
+    pub qty: f64,
+    /// 매수 VWAP (KRW).
+    pub buy_vwap: f64,
+    /// 매도 VWAP (KRW).
+    pub sell_vwap: f64,
+    /// 매수 레그가 소비한 가장 높은 매도호가 (KRW, IOC 지정가로 사용).
+    pub buy_limit_price: f64,
+    /// 매도 레그가 소비한 가장 낮은 매수호가 (KRW, IOC 지정가로 사용).
+    pub sell_limit_price: f64,
+    /// VWAP 기준 수수료 차감 후 순 스프레드 (%).
+    pub net_spread_pct: f64,
+}
+
+/// 진입 시그널.
+#[derive(Debug, Clone)]
+pub struct SpreadSignal {
+    /// 코인 심볼.
+    pub coin: String,
+    /// 거래 방향.
+    pub direction: SpreadDirection,
+    /// 주문 수량 (코인, 소수 8자리 내림).
+    pub qty: Decimal,
+    /// 매수 IOC 지정가 (KRW).
+    pub buy_price: Decimal,
+    /// 매도 IOC 지정가 (KRW).
+    pub sell_price: Decimal,
+    /// 최우선 호가 기준 총 스프레드 (%, 수수료 차감 전).
+    pub gross_spread_pct: f64,
+    /// 안전 볼륨 전체 기준 순 스프레드 (%).
+    pub volume: SpreadVolume,
+}
+
+/// 매수 VWAP/매도 VWAP에서 수수료 차감 후 순 스프레드(%)를 계산합니다.
+pub fn net_spread_pct(buy_price: f64, sell_price: f64, buy_fee: f64, sell_fee: f64) -> f64 {
+    let cost = buy_price * (1.0 + buy_fee);
+    if cost <= 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    let proceeds = sell_price * (1.0 - sell_fee);
+    (proceeds - cost) / cost * 100.0
+}
+
+/// 매수 레그 asks와 매도 레그 bids를 two-pointer로 동시 소비하여
+/// VWAP 기준 순 스프레드가 `min_net_spread_pct` 이상인 최대 수량을 계산합니다.
+///
+/// # 인자
+///
+/// * `buy_asks` - 매수 거래소 매도호가 (price_krw, size_coins), 가격 오름차순
+/// * `sell_bids` - 매도 거래소 매수호가 (price_krw, size_coins), 가격 내림차순
+/// * `buy_fee` / `sell_fee` - 각 레그 taker 수수료율
+/// * `min_net_spread_pct` - 최소 순 스프레드 (%)
+///
+/// # 반환값
+///
+/// 첫 단계부터 임계값 미달이거나 오더북이 비어있으면 `None`.
+pub fn evaluate_spread_volume(
+    buy_asks: &[(f64, f64)],
+    sell_bids: &[(f64, f64)],
+    buy_fee: f64,
+    sell_fee: f64,
+    min_net_spread_pct: f64,
+) -> Option<SpreadVolume> {
+    if buy_asks.is_empty() || sell_bids.is_empty() {
+        return None;
+    }
+
+    let mut buy_ptr: usize = 0;
+    let mut sell_ptr: usize = 0;
+    let mut buy_remaining = buy_asks[0].1;
+    let mut sell_remaining = sell_bids[0].1;
+
+    let mut total_coins: f64 = 0.0;
+    let mut buy_cost: f64 = 0.0;
+    let mut sell_revenue: f64 = 0.0;
+    let mut last_valid: Option<SpreadVolume> = None;
+
+    loop {
+        let consume = buy_remaining.min(sell_remaining);
+        if consume <= 0.0 {
+            break;
+        }
+
+        buy_cost += consume * buy_asks[buy_ptr].0;
+        sell_revenue += consume * sell_bids[sell_ptr].0;
+        total_coins += consume;
+        buy_remaining -= consume;
+        sell_remaining -= consume;
+
+        let buy_vwap = buy_cost / total_coins;
+        let sell_vwap = sell_revenue / total_coins;
+        let net = net_spread_pct(buy_vwap, sell_vwap, buy_fee, sell_fee);
+
+        trace!(
+            total_coins = total_coins,
+            buy_vwap = buy_vwap,
+            sell_vwap = sell_vwap,
+            net_spread_pct = net,
+            "KRW 스프레드 two-pointer 단계"
+        );
+
+        if net < min_net_spread_pct {
+            return last_valid;
+        }
+        last_valid = Some(SpreadVolume {
+            qty: total_coins,
+            buy_vwap,
+            sell_vwap,
+            buy_limit_price: buy_asks[buy_ptr].0,
+            sell_limit_price: sell_bids[sell_ptr].0,
+            net_spread_pct: net,
+        });
+
+        if buy_remaining <= 0.0 {
+            buy_ptr += 1;
+            if buy_ptr >= buy_asks.len() {
+                break;
+            }
+            buy_remaining = buy_asks[buy_ptr].1;
+        }
+        if sell_remaining <= 0.0 {
+            sell_ptr += 1;
+            if sell_ptr >= sell_bids.len() {
+                break;
+            }
+            sell_remaining = sell_bids[sell_ptr].1;
+        }
+    }
+
+    last_valid
+}
+
+/// 양쪽 오더북에서 진입 시그널을 평가합니다.
+///
+/// 두 방향을 모두 계산해 순 스프레드가 큰 쪽을 택한 뒤, 안전 볼륨에
+/// `safe_volume_ratio`를 곱하고 `max_order_krw`로 상한을 둡니다.
+/// 주문 금액이 `min_order_krw` 미만이면 `None`입니다.
+pub fn evaluate_signal(
+    coin: &str,
+    upbit_ob: &OrderBook,
+    bithumb_ob: &OrderBook,
+    config: &KrwSpreadConfig,
+) -> Option<SpreadSignal> {
+    let candidates = [
+        (SpreadDirection::BuyUpbitSellBithumb, upbit_ob, bithumb_ob),
+        (SpreadDirection::BuyBithumbSellUpbit, bithumb_ob, upbit_ob),
+    ];
+
+    let (direction, buy_ob, sell_ob, volume) = candidates
+        .into_iter()
+        .filter_map(|(direction, buy_ob, sell_ob)| {
+            let volume = evaluate_spread_volume(
+                &levels_to_f64(buy_ob, true),
+                &levels_to_f64(sell_ob, false),
+                config.taker_fee(direction.buy_exchange()),
+                config.taker_fee(direction.sell_exchange()),
+                config.min_net_spread_pct,
+            )?;
+            Some((direction, buy_ob, sell_ob, volume))
+        })
+        .max_by(|a, b| a.3.net_spread_pct.total_cmp(&b.3.net_spread_pct))?;
+
+    let max_qty = config.max_order_krw.to_f64()? / volume.buy_vwap;
+    let qty_f64 = (volume.qty * config.safe_volume_ratio).min(max_qty);
+    let qty = Decimal::from_f64(qty_f64)?
+        .round_dp_with_strategy(QTY_DECIMALS, rust_decimal::RoundingStrategy::ToZero);
+    let buy_price = Decimal::from_f64(volume.buy_limit_price)?.normalize();
+    let sell_price = Decimal::from_f64(volume.sell_limit_price)?.normalize();
+
+    if qty <= Decimal::ZERO || qty * buy_price < config.min_order_krw {
+        trace!(coin = coin, qty = %qty, "KRW 스프레드 주문 금액 최소 미달");
+        return None;
+    }
+
+    let best_ask = buy_ob.best_ask()?.price.to_f64()?;
+    let best_bid = sell_ob.bids.first()?.price.to_f64()?;
+    let gross_spread_pct = (best_bid - best_ask) / best_ask * 100.0;
+
+    Some(SpreadSignal {
+        coin: coin.to_string(),
+        direction,
+        qty,
+        buy_price,
+        sell_price,
+        gross_spread_pct,
+        volume,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arb_exchange::OrderBookLevel;
+    use chrono::Utc;
+
+    fn make_orderbook(asks: &[(i64, i64)], bids: &[(i64, i64)]) -> OrderBook {
+        let level = |&(price, size): &(i64, i64)| OrderBookLevel {
+            price: Decimal::from(price),
+            size: Decimal::from(size),
+        };
+        OrderBook {
+            market: "KRW-XRP".to_string(),
+            asks: asks.iter().map(level).collect(),
+            bids: bids.iter().map(level).collect(),
+            total_ask_size: Decimal::ZERO,
+            total_bid_size: Decimal::ZERO,
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_net_spread_pct_subtracts_fees() {
+        // 1000 매수, 1010 매도, 수수료 0 → 1%
+        assert!((net_spread_pct(1000.0, 1010.0, 0.0, 0.0) - 1.0).abs() < 1e-9);
+        // 수수료 0.05% + 0.04% → 약 0.9%
+        let net = net_spread_pct(1000.0, 1010.0, 0.0005, 0.0004);
+        assert!(net < 1.0 && net > 0.89);
+    }
+
+    #[test]
+    fn test_evaluate_spread_volume_stops_at_threshold() {
+        // 1레벨: 1000 → 1010 (1%), 2레벨까지 소비하면 VWAP 스프레드 하락
+        let asks = [(1000.0, 10.0), (1008.0, 10.0)];
+        let bids = [(1010.0, 10.0), (1002.0, 10.0)];
+        let result = evaluate_spread_volume(&asks, &bids, 0.0, 0.0, 0.5).unwrap();
+        assert!((result.qty - 10.0).abs() < 1e-9);
+        assert_eq!(result.buy_limit_price, 1000.0);
+        assert_eq!(result.sell_limit_price, 1010.0);
+    }
+
+    #[test]
+    fn test_evaluate_spread_volume_none_when_unprofitable() {
+        let asks = [(1000.0, 10.0)];
+        let bids = [(1001.0, 10.0)];
+        assert!(evaluate_spread_volume(&asks, &bids, 0.0005, 0.0004, 0.15).is_none());
+        assert!(evaluate_spread_volume(&[], &bids, 0.0, 0.0, 0.15).is_none());
+    }
+
+    #[test]
+    fn test_evaluate_signal_picks_profitable_direction() {
+        let config = KrwSpreadConfig::default();
+        // Upbit가 비쌈 → Bithumb 매수 + Upbit 매도
+        let upbit = make_orderbook(&[(1012, 1_000)], &[(1010, 1_000)]);
+        let bithumb = make_orderbook(&[(1000, 1_000)], &[(998, 1_000)]);
+
+        let signal = evaluate_signal("XRP", &upbit, &bithumb, &config).unwrap();
+        assert_eq!(signal.direction, SpreadDirection::BuyBithumbSellUpbit);
+        assert_eq!(signal.direction.buy_exchange(), Exchange::Bithumb);
+        assert_eq!(signal.buy_price, Decimal::from(1000));
+        assert_eq!(signal.sell_price, Decimal::from(1010));
+        // 안전 볼륨 1000 * 0.7 = 700개 (700,000 KRW < max_order_krw)
+        assert_eq!(signal.qty, Decimal::from(700));
+        assert!((signal.gross_spread_pct - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_evaluate_signal_caps_by_max_order_krw() {
+        let config = KrwSpreadConfig {
+            max_order_krw: Decimal::from(100_000),
+            ..Default::default()
+        };
+        let upbit = make_orderbook(&[(1000, 1_000)], &[(998, 1_000)]);
+        let bithumb = make_orderbook(&[(1012, 1_000)], &[(1010, 1_000)]);
+
+        let signal = evaluate_signal("XRP", &upbit, &bithumb, &config).unwrap();
+        assert_eq!(signal.direction, SpreadDirection::BuyUpbitSellBithumb);
+        assert_eq!(signal.qty, Decimal::from(100));
+    }
+
+    #[test]
+    fn test_evaluate_signal_none_within_fees() {
+        let config = KrwSpreadConfig::default();
+        let upbit = make_orderbook(&[(1001, 1_000)], &[(1000, 1_000)]);
+        let bithumb = make_orderbook(&[(1001, 1_000)], &[(1000, 1_000)]);
+        assert!(evaluate_signal("XRP", &upbit, &bithumb, &config).is_none());
+    }
+}
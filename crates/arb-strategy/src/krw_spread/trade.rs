@@ -0,0 +1,175 @@
+//! KRW 스프레드 거래 기록.
+//!
+//! 진입/청산 쌍으로 구성되는 `ClosedPosition`과 달리, 스프레드 거래는
+//! 매수/매도 레그가 동시에 체결되는 단일 이벤트입니다.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive as _;
+use serde::Serialize;
+
+use crate::krw_spread::inventory::LegFill;
+use crate::krw_spread::signal::SpreadDirection;
+
+/// 체결 완료된 스프레드 거래 1건.
+#[derive(Debug, Clone, Serialize)]
+pub struct SpreadTrade {
+    /// 거래 고유 ID (세션 내 순번).
+    pub id: u64,
+    /// 코인 심볼.
+    pub coin: String,
+    /// 거래 방향 ("upbit_to_bithumb" / "bithumb_to_upbit").
+    pub direction: String,
+    /// 체결 시각.
+    pub executed_at: DateTime<Utc>,
+    /// 매수 체결 수량.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub buy_qty: Decimal,
+    /// 매수 평균가 (KRW).
+    #[serde(with = "rust_decimal::serde::str")]
+    pub buy_price: Decimal,
+    /// 매도 체결 수량.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub sell_qty: Decimal,
+    /// 매도 평균가 (KRW).
+    #[serde(with = "rust_decimal::serde::str")]
+    pub sell_price: Decimal,
+    /// 매수 레그 수수료 (KRW).
+    #[serde(with = "rust_decimal::serde::str")]
+    pub buy_fee: Decimal,
+    /// 매도 레그 수수료 (KRW).
+    #[serde(with = "rust_decimal::serde::str")]
+    pub sell_fee: Decimal,
+    /// 시그널 시점 기대 순 스프레드 (%).
+    pub expected_spread_pct: f64,
+    /// 체결가 기준 실현 순 스프레드 (%, 매칭 수량이 없으면 0).
+    pub realized_spread_pct: f64,
+    /// 순손익 (KRW, 양쪽 매칭 수량 기준, 수수료 차감).
+    #[serde(with = "rust_decimal::serde::str")]
+    pub net_pnl: Decimal,
+    /// 매수/매도 체결 수량 차이 (매수 - 매도, 재고로 흡수).
+    #[serde(with = "rust_decimal::serde::str")]
+    pub unmatched_qty: Decimal,
+}
+
+impl SpreadTrade {
+    /// 양쪽 레그 체결 결과에서 거래 기록을 생성합니다.
+    ///
+    /// 손익은 두 레그가 모두 체결된 수량(min)에 대해서만 계산하며,
+    /// 수수료는 매칭 비율만큼 안분합니다. 남는 수량은 재고 변동으로만 남습니다.
+    pub fn from_fills(
+        id: u64,
+        coin: &str,
+        direction: SpreadDirection,
+        buy: &LegFill,
+        sell: &LegFill,
+        expected_spread_pct: f64,
+    ) -> Self {
+        let matched = buy.qty.min(sell.qty);
+        let prorate = |fill: &LegFill| {
+            if fill.qty.is_zero() {
+                Decimal::ZERO
+            } else {
+                fill.fee_krw * matched / fill.qty
+            }
+        };
+        let cost = matched * buy.avg_price + prorate(buy);
+        let proceeds = matched * sell.avg_price - prorate(sell);
+        let net_pnl = proceeds - cost;
+        let realized_spread_pct = if cost.is_zero() {
+            0.0
+        } else {
+            (net_pnl / cost * Decimal::ONE_HUNDRED)
+                .to_f64()
+                .unwrap_or(0.0)
+        };
+
+        Self {
+            id,
+            coin: coin.to_string(),
+            direction: direction.as_str().to_string(),
+            executed_at: Utc::now(),
+            buy_qty: buy.qty,
+            buy_price: buy.avg_price,
+            sell_qty: sell.qty,
+            sell_price: sell.avg_price,
+            buy_fee: buy.fee_krw,
+            sell_fee: sell.fee_krw,
+            expected_spread_pct,
+            realized_spread_pct,
+            net_pnl,
+            unmatched_qty: buy.qty - sell.qty,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_fills_matched_pnl() {
+        let buy = LegFill {
+            qty: Decimal::from(100),
+            avg_price: Decimal::from(1_000),
+            fee_krw: Decimal::from(50),
+        };
+        let sell = LegFill {
+            qty: Decimal::from(100),
+            avg_price: Decimal::from(1_010),
+            fee_krw: Decimal::from(40),
+        };
+        let trade = SpreadTrade::from_fills(
+            1,
+            "XRP",
+            SpreadDirection::BuyUpbitSellBithumb,
+            &buy,
+            &sell,
+            0.9,
+        );
+        // 101,000 - 40 - (100,000 + 50) = 910
+        assert_eq!(trade.net_pnl, Decimal::from(910));
+        assert_eq!(trade.unmatched_qty, Decimal::ZERO);
+        assert_eq!(trade.direction, "upbit_to_bithumb");
+        assert!(trade.realized_spread_pct > 0.9 && trade.realized_spread_pct < 0.91);
+    }
+
+    #[test]
+    fn test_from_fills_partial_sell_leg() {
+        let buy = LegFill {
+            qty: Decimal::from(100),
+            avg_price: Decimal::from(1_000),
+            fee_krw: Decimal::from(50),
+        };
+        let sell = LegFill {
+            qty: Decimal::from(40),
+            avg_price: Decimal::from(1_010),
+            fee_krw: Decimal::from(16),
+        };
+        let trade = SpreadTrade::from_fills(
+            2,
+            "XRP",
+            SpreadDirection::BuyBithumbSellUpbit,
+            &buy,
+            &sell,
+            0.9,
+        );
+        // 매칭 40개: 40,400 - 16 - (40,000 + 20) = 364
+        assert_eq!(trade.net_pnl, Decimal::from(364));
+        assert_eq!(trade.unmatched_qty, Decimal::from(60));
+    }
+
+    #[test]
+    fn test_from_fills_no_fill() {
+        let trade = SpreadTrade::from_fills(
+            3,
+            "XRP",
+            SpreadDirection::BuyUpbitSellBithumb,
+            &LegFill::default(),
+            &LegFill::default(),
+            0.5,
+        );
+        assert_eq!(trade.net_pnl, Decimal::ZERO);
+        assert_eq!(trade.realized_spread_pct, 0.0);
+    }
+}
@@ -5,6 +5,7 @@
 
 pub mod common;
 pub mod error;
+pub mod krw_spread;
 pub mod output;
 pub mod zscore;
 
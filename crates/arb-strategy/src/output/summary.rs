@@ -10,6 +10,7 @@ use rust_decimal::Decimal;
 use serde::Serialize;
 
 use crate::zscore::pnl::{ClosedPosition, calculate_max_drawdown, daily_pnl};
+use crate::zscore::tca::ExecutionQuality;
 
 /// 모니터링 카운터.
 ///
@@ -40,6 +41,12 @@ pub struct MonitoringCounters {
     pub safe_volume_exceeded_close_count: u64,
     /// stddev 필터로 실제 분류 변경된 코인 수 (초기 선택 + 재선택 + 런타임).
     pub coin_rejected_spread_stddev_count: u64,
+    /// 평균 회귀 필터(half-life/ADF)로 제외된 코인 수 (초기 선택 + 재선택).
+    pub coin_rejected_mean_reversion_count: u64,
+    /// adaptive 모드로 코인 윈도우/임계값이 변경된 횟수.
+    pub adaptive_param_change_count: u64,
+    /// 포지션 보유 중이라 adaptive 조정이 보류된 횟수.
+    pub adaptive_param_deferred_count: u64,
     /// regime change 감지 횟수 (cooldown으로 무시된 것 제외).
     pub regime_change_detected_count: u64,
     /// cooldown에 의해 억제된 regime change 횟수.
@@ -48,6 +55,12 @@ pub struct MonitoringCounters {
     pub entry_rejected_min_position_count: u64,
     /// 최소 기대 수익률 미달로 진입 거부된 횟수.
     pub entry_rejected_min_roi_count: u64,
+    /// 상위 타임프레임 스프레드 추세로 진입 거부된 횟수.
+    pub entry_rejected_trend_count: u64,
+    /// 자본 배분기 한도 소진으로 진입 거부된 횟수.
+    pub entry_rejected_allocator_count: u64,
+    /// 손절/트레일링 스탑 규칙으로 청산 요청된 포지션 수.
+    pub stop_loss_exit_count: u64,
     /// 잔고 스냅샷 try_send 실패 (드롭) 수.
     pub balance_snapshot_dropped: u64,
 }
@@ -76,6 +89,50 @@ pub struct CoinPnl {
     pub win_rate: f64,
 }
 
+/// 분할 진입 단계별 PnL 집계.
+#[derive(Debug, Clone, Serialize)]
+pub struct TierPnl {
+    /// 분할 진입 단계 (0부터).
+    pub tier: u8,
+    /// 거래 수.
+    pub trades: usize,
+    /// 순 PnL 합계 (USDT).
+    #[serde(with = "rust_decimal::serde::str")]
+    pub net_pnl: Decimal,
+    /// 승률 (%).
+    pub win_rate: f64,
+    /// 평균 진입 Z-Score.
+    pub avg_entry_z_score: f64,
+}
+
+/// 코인 × 주문 유형별 체결 품질(TCA) 집계.
+///
+/// 슬리피지/shortfall은 불리한 방향이 양수입니다.
+#[derive(Debug, Clone, Serialize)]
+pub struct TcaSummary {
+    /// 코인 심볼.
+    pub coin: String,
+    /// 주문 유형 ("limit_ioc" / "market").
+    pub order_type: String,
+    /// 체결 건수 (진입/청산 각각 1건).
+    pub executions: usize,
+    /// 평균 Upbit 슬리피지 (bps).
+    pub avg_upbit_slippage_bps: f64,
+    /// 평균 Bybit 슬리피지 (bps).
+    pub avg_bybit_slippage_bps: f64,
+    /// 평균 implementation shortfall (bps).
+    pub avg_shortfall_bps: f64,
+    /// Implementation shortfall 합계 (USDT).
+    #[serde(with = "rust_decimal::serde::str")]
+    pub total_shortfall_usdt: Decimal,
+    /// 평균 체결 지연 (ms).
+    pub avg_fill_latency_ms: f64,
+    /// 최대 체결 지연 (ms).
+    pub max_fill_latency_ms: u64,
+    /// 평균 레그 간 체결 시차 (ms).
+    pub avg_leg_gap_ms: f64,
+}
+
 /// 세션 요약 지표.
 ///
 /// 세션 종료 시 `ClosedPosition` 배열로부터 계산되며,
@@ -118,6 +175,10 @@ pub struct SessionSummary {
     pub daily_pnl: Vec<DailyPnl>,
     /// 코인별 PnL 집계.
     pub coin_pnl: Vec<CoinPnl>,
+    /// 분할 진입 단계별 PnL 집계 (분할 진입 비활성화 시 단계 0 하나).
+    pub tier_pnl: Vec<TierPnl>,
+    /// 코인 × 주문 유형별 체결 품질 집계 (라이브 전용, 시뮬레이션은 빈 배열).
+    pub tca: Vec<TcaSummary>,
     /// 일별 PnL 기반 Sharpe Ratio (무위험이자율 0 가정).
     pub sharpe_ratio: f64,
     /// 총 수수료 합계 (USDT).
@@ -149,6 +210,12 @@ pub struct SessionSummary {
     pub safe_volume_exceeded_close_count: u64,
     /// stddev 필터로 분류 변경된 코인 수.
     pub coin_rejected_spread_stddev_count: u64,
+    /// 평균 회귀 필터로 제외된 코인 수.
+    pub coin_rejected_mean_reversion_count: u64,
+    /// adaptive 파라미터 변경 횟수.
+    pub adaptive_param_change_count: u64,
+    /// adaptive 파라미터 변경 보류 횟수.
+    pub adaptive_param_deferred_count: u64,
     /// regime change 감지 횟수.
     pub regime_change_detected_count: u64,
     /// cooldown으로 억제된 regime change 횟수.
@@ -157,6 +224,12 @@ pub struct SessionSummary {
     pub entry_rejected_min_position_count: u64,
     /// 최소 기대 수익률 미달 진입 거부 횟수.
     pub entry_rejected_min_roi_count: u64,
+    /// 상위 타임프레임 추세 진입 거부 횟수.
+    pub entry_rejected_trend_count: u64,
+    /// 자본 배분기 한도 소진 진입 거부 횟수.
+    pub entry_rejected_allocator_count: u64,
+    /// 손절/트레일링 스탑 청산 요청 포지션 수.
+    pub stop_loss_exit_count: u64,
     /// 잔고 스냅샷 드롭 횟수.
     pub balance_snapshot_dropped: u64,
 }
@@ -254,6 +327,8 @@ impl SessionSummary {
 
         // 코인별 PnL 집계
         let coin_pnl_vec = calculate_coin_pnl(trades);
+        let tier_pnl_vec = calculate_tier_pnl(trades);
+        let tca_vec = calculate_tca_summary(trades);
 
         // 총 수수료
         let total_fees: Decimal = trades.iter().map(|t| t.total_fees).sum();
@@ -279,6 +354,8 @@ impl SessionSummary {
             avg_holding_minutes,
             daily_pnl: daily_pnl_vec,
             coin_pnl: coin_pnl_vec,
+            tier_pnl: tier_pnl_vec,
+            tca: tca_vec,
             sharpe_ratio,
             total_fees,
             liquidation_count,
@@ -294,11 +371,17 @@ impl SessionSummary {
             fallback_no_rounding_count: counters.fallback_no_rounding_count,
             safe_volume_exceeded_close_count: counters.safe_volume_exceeded_close_count,
             coin_rejected_spread_stddev_count: counters.coin_rejected_spread_stddev_count,
+            coin_rejected_mean_reversion_count: counters.coin_rejected_mean_reversion_count,
+            adaptive_param_change_count: counters.adaptive_param_change_count,
+            adaptive_param_deferred_count: counters.adaptive_param_deferred_count,
             regime_change_detected_count: counters.regime_change_detected_count,
             regime_change_suppressed_by_cooldown_count: counters
                 .regime_change_suppressed_by_cooldown_count,
             entry_rejected_min_position_count: counters.entry_rejected_min_position_count,
             entry_rejected_min_roi_count: counters.entry_rejected_min_roi_count,
+            entry_rejected_trend_count: counters.entry_rejected_trend_count,
+            entry_rejected_allocator_count: counters.entry_rejected_allocator_count,
+            stop_loss_exit_count: counters.stop_loss_exit_count,
             balance_snapshot_dropped: counters.balance_snapshot_dropped,
         }
     }
@@ -348,6 +431,42 @@ impl SessionSummary {
             }
         }
 
+        // 분할 진입 단계별 집계 (단계 0만 있으면 생략)
+        if self.tier_pnl.iter().any(|tp| tp.tier > 0) {
+            s.push_str("\n단계별:\n");
+            for tp in &self.tier_pnl {
+                s.push_str(&format!(
+                    "  단계 {}: {}건, {} USDT (승률 {:.1}%, 평균 진입 z {:.2})\n",
+                    tp.tier,
+                    tp.trades,
+                    format_decimal_signed(tp.net_pnl),
+                    tp.win_rate,
+                    tp.avg_entry_z_score
+                ));
+            }
+        }
+
+        // 체결 품질 (TCA)
+        if !self.tca.is_empty() {
+            s.push_str("\n체결 품질 (TCA, 불리한 방향 +):\n");
+            for t in &self.tca {
+                s.push_str(&format!(
+                    "  {} [{}]: {}건, 슬리피지 Upbit {:.1} / Bybit {:.1} bps, \
+                     IS {:.1} bps ({} USDT), 체결 {:.0}ms (최대 {}ms), 레그 시차 {:.0}ms\n",
+                    t.coin,
+                    t.order_type,
+                    t.executions,
+                    t.avg_upbit_slippage_bps,
+                    t.avg_bybit_slippage_bps,
+                    t.avg_shortfall_bps,
+                    format_decimal_signed(t.total_shortfall_usdt),
+                    t.avg_fill_latency_ms,
+                    t.max_fill_latency_ms,
+                    t.avg_leg_gap_ms
+                ));
+            }
+        }
+
         // 일별 PnL
         if !self.daily_pnl.is_empty() {
             s.push_str("\n일별 PnL:\n");
@@ -417,6 +536,15 @@ impl SessionSummary {
             "stddev 필터 제외: {}건\n",
             format_number(self.coin_rejected_spread_stddev_count)
         ));
+        s.push_str(&format!(
+            "평균 회귀 필터 제외: {}건\n",
+            format_number(self.coin_rejected_mean_reversion_count)
+        ));
+        s.push_str(&format!(
+            "adaptive 파라미터 변경: {}건 (포지션 보유로 보류: {}건)\n",
+            format_number(self.adaptive_param_change_count),
+            format_number(self.adaptive_param_deferred_count)
+        ));
         s.push_str(&format!(
             "regime change 감지: {}건\n",
             format_number(self.regime_change_detected_count)
@@ -433,6 +561,18 @@ impl SessionSummary {
             "최소 ROI 미달 진입 거부: {}건\n",
             format_number(self.entry_rejected_min_roi_count)
         ));
+        s.push_str(&format!(
+            "추세 필터 진입 거부: {}건\n",
+            format_number(self.entry_rejected_trend_count)
+        ));
+        s.push_str(&format!(
+            "자본 배분 한도 진입 거부: {}건\n",
+            format_number(self.entry_rejected_allocator_count)
+        ));
+        s.push_str(&format!(
+            "손절/트레일링 스탑 청산: {}건\n",
+            format_number(self.stop_loss_exit_count)
+        ));
         s.push_str(&format!(
             "잔고 스냅샷 드롭: {}건\n",
             format_number(self.balance_snapshot_dropped)
@@ -474,18 +614,7 @@ fn calculate_coin_pnl(trades: &[ClosedPosition]) -> Vec<CoinPnl> {
     grouped
         .into_iter()
         .map(|(coin, coin_trades)| {
-            let trades_count = coin_trades.len();
-            let net_pnl: Decimal = coin_trades.iter().map(|t| t.net_pnl).sum();
-            let wins = coin_trades
-                .iter()
-                .filter(|t| t.net_pnl > Decimal::ZERO)
-                .count();
-            let win_rate = if trades_count > 0 {
-                (wins as f64 / trades_count as f64) * 100.0
-            } else {
-                0.0
-            };
-
+            let (trades_count, net_pnl, win_rate) = group_stats(&coin_trades);
             CoinPnl {
                 coin,
                 trades: trades_count,
@@ -496,6 +625,83 @@ fn calculate_coin_pnl(trades: &[ClosedPosition]) -> Vec<CoinPnl> {
         .collect()
 }
 
+/// 분할 진입 단계별 PnL 집계.
+fn calculate_tier_pnl(trades: &[ClosedPosition]) -> Vec<TierPnl> {
+    let mut grouped: BTreeMap<u8, Vec<&ClosedPosition>> = BTreeMap::new();
+    for trade in trades {
+        grouped.entry(trade.tier).or_default().push(trade);
+    }
+
+    grouped
+        .into_iter()
+        .map(|(tier, tier_trades)| {
+            let (trades_count, net_pnl, win_rate) = group_stats(&tier_trades);
+            let avg_entry_z_score =
+                tier_trades.iter().map(|t| t.entry_z_score).sum::<f64>() / trades_count as f64;
+            TierPnl {
+                tier,
+                trades: trades_count,
+                net_pnl,
+                win_rate,
+                avg_entry_z_score,
+            }
+        })
+        .collect()
+}
+
+/// 청산 기록에 부착된 진입/청산 체결 품질을 코인 × 주문 유형별로 집계합니다.
+fn calculate_tca_summary(trades: &[ClosedPosition]) -> Vec<TcaSummary> {
+    let mut grouped: BTreeMap<(String, String), Vec<&ExecutionQuality>> = BTreeMap::new();
+    for trade in trades {
+        for quality in [&trade.entry_quality, &trade.exit_quality]
+            .into_iter()
+            .flatten()
+        {
+            grouped
+                .entry((trade.coin.clone(), quality.order_type.clone()))
+                .or_default()
+                .push(quality);
+        }
+    }
+
+    grouped
+        .into_iter()
+        .map(|((coin, order_type), records)| {
+            let n = records.len() as f64;
+            let avg =
+                |f: fn(&ExecutionQuality) -> f64| records.iter().map(|q| f(q)).sum::<f64>() / n;
+            TcaSummary {
+                coin,
+                order_type,
+                executions: records.len(),
+                avg_upbit_slippage_bps: avg(|q| q.upbit_slippage_bps),
+                avg_bybit_slippage_bps: avg(|q| q.bybit_slippage_bps),
+                avg_shortfall_bps: avg(|q| q.implementation_shortfall_bps),
+                total_shortfall_usdt: records
+                    .iter()
+                    .map(|q| q.implementation_shortfall_usdt)
+                    .sum(),
+                avg_fill_latency_ms: avg(|q| q.fill_latency_ms as f64),
+                max_fill_latency_ms: records.iter().map(|q| q.fill_latency_ms).max().unwrap_or(0),
+                avg_leg_gap_ms: avg(|q| q.leg_gap_ms as f64),
+            }
+        })
+        .collect()
+}
+
+/// 거래 묶음의 (거래 수, 순 PnL 합계, 승률 %)를 계산합니다.
+fn group_stats(trades: &[&ClosedPosition]) -> (usize, Decimal, f64) {
+    let trades_count = trades.len();
+    let net_pnl: Decimal = trades.iter().map(|t| t.net_pnl).sum();
+    let wins = trades.iter().filter(|t| t.net_pnl > Decimal::ZERO).count();
+    let win_rate = if trades_count > 0 {
+        (wins as f64 / trades_count as f64) * 100.0
+    } else {
+        0.0
+    };
+    (trades_count, net_pnl, win_rate)
+}
+
 /// `Decimal`을 `f64`로 변환합니다.
 fn decimal_to_f64(d: Decimal) -> f64 {
     use rust_decimal::prelude::ToPrimitive;
@@ -564,10 +770,13 @@ mod tests {
             entry_usd_krw: 1380.0,
             exit_usd_krw: 1381.0,
             is_liquidated,
+            tier: 0,
             actual_upbit_fee: None,
             actual_bybit_fee: None,
             funding_fee: None,
             adjustment_cost: None,
+            entry_quality: None,
+            exit_quality: None,
         }
     }
 
@@ -821,6 +1030,54 @@ mod tests {
         assert_eq!(xrp.trades, 1);
         assert_eq!(xrp.net_pnl, Decimal::new(3, 0));
         assert!((xrp.win_rate - 100.0).abs() < 0.01); // 1/1 = 100%
+
+        // 분할 진입 미사용: 단계 0 하나, 텍스트에는 단계별 섹션 없음
+        assert_eq!(summary.tier_pnl.len(), 1);
+        assert_eq!(summary.tier_pnl[0].trades, 3);
+        assert!(!summary.to_text().contains("단계별:"));
+    }
+
+    #[test]
+    fn test_tier_pnl_grouping() {
+        let start = Utc.with_ymd_and_hms(2026, 2, 9, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2026, 2, 10, 0, 0, 0).unwrap();
+        let coins = vec!["BTC".to_string()];
+        let exit = Utc.with_ymd_and_hms(2026, 2, 9, 12, 0, 0).unwrap();
+
+        let mut trades = vec![
+            make_trade("BTC", Decimal::new(4, 0), Decimal::ZERO, 30, exit, false),
+            make_trade("BTC", Decimal::new(6, 0), Decimal::ZERO, 30, exit, false),
+            make_trade("BTC", Decimal::new(-2, 0), Decimal::ZERO, 30, exit, false),
+        ];
+        trades[1].tier = 1;
+        trades[1].entry_z_score = 2.5;
+        trades[2].tier = 1;
+        trades[2].entry_z_score = 2.7;
+
+        let summary = SessionSummary::calculate(
+            &trades,
+            start,
+            end,
+            &coins,
+            1400.0,
+            1410.0,
+            0,
+            &MonitoringCounters::default(),
+        );
+
+        assert_eq!(summary.tier_pnl.len(), 2);
+        let t0 = &summary.tier_pnl[0];
+        assert_eq!((t0.tier, t0.trades), (0, 1));
+        assert_eq!(t0.net_pnl, Decimal::new(4, 0));
+        let t1 = &summary.tier_pnl[1];
+        assert_eq!((t1.tier, t1.trades), (1, 2));
+        assert_eq!(t1.net_pnl, Decimal::new(4, 0));
+        assert!((t1.win_rate - 50.0).abs() < 0.01);
+        assert!((t1.avg_entry_z_score - 2.6).abs() < 1e-9);
+
+        let text = summary.to_text();
+        assert!(text.contains("단계별:"));
+        assert!(text.contains("단계 1: 2건"));
     }
 
     #[test]
@@ -900,4 +1157,69 @@ mod tests {
 
         assert_eq!(summary.liquidation_count, 2);
     }
+
+    #[test]
+    fn test_tca_summary_grouped_by_coin_and_order_type() {
+        use crate::zscore::live_executor::ExecutedExit;
+        use crate::zscore::tca::ExecutionQuality;
+        use std::time::Duration;
+
+        // 기대가 대비 Upbit 매도 `upbit_krw`, Bybit 매수 70,000 (슬리피지 0) 체결
+        let exit_quality = |upbit_krw: i64, latency_ms: u64| {
+            ExecutionQuality::from_exit(
+                Decimal::new(70_000, 0),
+                Decimal::new(70_000, 0),
+                1400.0,
+                &ExecutedExit {
+                    upbit_order_id: "u".to_string(),
+                    bybit_order_id: "b".to_string(),
+                    upbit_filled_qty: Decimal::new(1, 2),
+                    bybit_filled_qty: Decimal::new(1, 2),
+                    upbit_avg_price_krw: Decimal::new(upbit_krw, 0),
+                    bybit_avg_price: Decimal::new(70_000, 0),
+                    upbit_fee: Decimal::ZERO,
+                    bybit_fee: Decimal::ZERO,
+                    upbit_latency: Duration::from_millis(latency_ms),
+                    bybit_latency: Duration::from_millis(10),
+                },
+            )
+        };
+        let exit = Utc.with_ymd_and_hms(2026, 2, 9, 12, 0, 0).unwrap();
+
+        let mut first = make_trade("BTC", Decimal::ONE, Decimal::ZERO, 30, exit, false);
+        let mut entry = exit_quality(97_902_000, 30);
+        entry.side = "entry".to_string();
+        entry.order_type = "limit_ioc".to_string();
+        first.entry_quality = Some(entry);
+        first.exit_quality = Some(exit_quality(97_902_000, 110)); // 10bps 불리
+        let mut second = make_trade("BTC", Decimal::ONE, Decimal::ZERO, 30, exit, false);
+        second.exit_quality = Some(exit_quality(97_804_000, 210)); // 20bps 불리
+        let sim_only = make_trade("XRP", Decimal::ONE, Decimal::ZERO, 30, exit, false);
+
+        let summary = SessionSummary::calculate(
+            &[first, second, sim_only],
+            exit,
+            exit,
+            &["BTC".to_string(), "XRP".to_string()],
+            1400.0,
+            1400.0,
+            0,
+            &MonitoringCounters::default(),
+        );
+
+        assert_eq!(summary.tca.len(), 2);
+        let market = summary
+            .tca
+            .iter()
+            .find(|t| t.order_type == "market")
+            .unwrap();
+        assert_eq!(market.coin, "BTC");
+        assert_eq!(market.executions, 2);
+        assert!((market.avg_upbit_slippage_bps - 15.0).abs() < 1e-9);
+        assert_eq!(market.avg_bybit_slippage_bps, 0.0);
+        assert_eq!(market.max_fill_latency_ms, 210);
+        assert!((market.avg_leg_gap_ms - 150.0).abs() < 1e-9);
+        assert!(market.total_shortfall_usdt > Decimal::ZERO);
+        assert!(summary.to_text().contains("BTC [limit_ioc]: 1건"));
+    }
 }
@@ -2,6 +2,7 @@
 //!
 //! `SessionWriter`는 세션 디렉토리를 관리하며,
 //! 거래 내역과 분봉 통계를 CSV 실시간 append + JSON 종료 시 일괄 저장합니다.
+//! KRW 스프레드 전략의 거래는 `spread_trades.csv`에 별도로 기록합니다.
 
 use std::fs::{self, File, OpenOptions};
 use std::io::{self, BufWriter, Write};
@@ -12,10 +13,11 @@ use serde::{Deserialize, Serialize};
 use tracing::{debug, info};
 
 use super::summary::SessionSummary;
+use crate::krw_spread::trade::SpreadTrade;
 use crate::zscore::pnl::ClosedPosition;
 
 /// 출력 설정.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct OutputConfig {
     /// 파일 출력 활성화 여부 (기본값: true).
     pub enabled: bool,
@@ -59,6 +61,12 @@ pub struct MinuteRecord {
     pub position: String,
     /// 데이터 출처 ("warmup" / "live").
     pub source: String,
+    /// 해당 분에 적용된 코인별 윈도우 크기.
+    pub window_size: usize,
+    /// 해당 분에 적용된 코인별 진입 임계값.
+    pub entry_z_threshold: f64,
+    /// 해당 분에 적용된 코인별 청산 임계값.
+    pub exit_z_threshold: f64,
 }
 
 /// 세션 파일 writer.
@@ -76,6 +84,8 @@ pub struct SessionWriter {
     trades_header_written: bool,
     /// minutes.csv 헤더 기록 여부.
     minutes_header_written: bool,
+    /// spread_trades.csv BufWriter (첫 append 시 생성).
+    spread_trades_writer: Option<BufWriter<File>>,
 }
 
 impl SessionWriter {
@@ -116,6 +126,7 @@ impl SessionWriter {
             minutes_writer: BufWriter::new(minutes_file),
             trades_header_written: false,
             minutes_header_written: false,
+            spread_trades_writer: None,
         }))
     }
 
@@ -136,6 +147,7 @@ impl SessionWriter {
             minutes_writer: BufWriter::new(minutes_file),
             trades_header_written: false,
             minutes_header_written: false,
+            spread_trades_writer: None,
         })
     }
 
@@ -156,14 +168,14 @@ impl SessionWriter {
                  upbit_entry_price,bybit_entry_price,upbit_exit_price,bybit_exit_price,\
                  entry_spread_pct,exit_spread_pct,entry_z_score,exit_z_score,\
                  entry_usd_krw,exit_usd_krw,upbit_pnl,bybit_pnl,\
-                 upbit_fees,bybit_fees,total_fees,net_pnl,is_liquidated"
+                 upbit_fees,bybit_fees,total_fees,net_pnl,is_liquidated,tier"
             )?;
             self.trades_header_written = true;
         }
 
         writeln!(
             self.trades_writer,
-            "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+            "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
             trade.id,
             trade.coin,
             trade.entry_time.to_rfc3339(),
@@ -187,7 +199,8 @@ impl SessionWriter {
             trade.bybit_fees,
             trade.total_fees,
             trade.net_pnl,
-            trade.is_liquidated
+            trade.is_liquidated,
+            trade.tier
         )?;
 
         self.trades_writer.flush()?;
@@ -195,6 +208,57 @@ impl SessionWriter {
         Ok(())
     }
 
+    /// KRW 스프레드 거래 1건을 `spread_trades.csv`에 append합니다.
+    ///
+    /// 파일은 첫 호출 시 생성하며 헤더를 함께 기록합니다. 매 append 후 flush합니다.
+    pub fn append_spread_trade(&mut self, trade: &SpreadTrade) -> io::Result<()> {
+        let writer = match &mut self.spread_trades_writer {
+            Some(writer) => writer,
+            None => {
+                let mut writer =
+                    BufWriter::new(open_csv_file(&self.session_dir, "spread_trades.csv")?);
+                writeln!(
+                    writer,
+                    "id,coin,direction,executed_at,buy_qty,buy_price,sell_qty,sell_price,\
+                     buy_fee,sell_fee,expected_spread_pct,realized_spread_pct,net_pnl,unmatched_qty"
+                )?;
+                self.spread_trades_writer.insert(writer)
+            }
+        };
+
+        writeln!(
+            writer,
+            "{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+            trade.id,
+            trade.coin,
+            trade.direction,
+            trade.executed_at.to_rfc3339(),
+            trade.buy_qty,
+            trade.buy_price,
+            trade.sell_qty,
+            trade.sell_price,
+            trade.buy_fee,
+            trade.sell_fee,
+            trade.expected_spread_pct,
+            trade.realized_spread_pct,
+            trade.net_pnl,
+            trade.unmatched_qty
+        )?;
+        writer.flush()?;
+
+        debug!(coin = %trade.coin, net_pnl = %trade.net_pnl, "스프레드 거래 CSV append");
+        Ok(())
+    }
+
+    /// KRW 스프레드 거래 목록을 `spread_trades.json`으로 저장합니다 (세션 종료 시).
+    pub fn finalize_spread_trades(&self, trades: &[SpreadTrade]) -> io::Result<()> {
+        let path = self.session_dir.join("spread_trades.json");
+        let json = serde_json::to_string_pretty(trades).map_err(io::Error::other)?;
+        fs::write(&path, json)?;
+        info!(path = %path.display(), count = trades.len(), "spread_trades.json 저장");
+        Ok(())
+    }
+
     /// 분봉 통계 1건을 `minutes.csv`에 append합니다.
     ///
     /// 첫 호출 시 CSV 헤더를 기록합니다. 매 append 후 flush합니다.
@@ -268,7 +332,8 @@ impl SessionWriter {
             writeln!(
                 self.minutes_writer,
                 "timestamp,coin,upbit_close,bybit_close,usd_krw,\
-                 spread_pct,mean,stddev,z_score,position,source"
+                 spread_pct,mean,stddev,z_score,position,source,\
+                 window_size,entry_z_threshold,exit_z_threshold"
             )?;
             self.minutes_header_written = true;
         }
@@ -280,7 +345,7 @@ impl SessionWriter {
 fn write_minute_row<W: Write>(writer: &mut W, record: &MinuteRecord) -> io::Result<()> {
     writeln!(
         writer,
-        "{},{},{},{},{},{},{},{},{},{},{}",
+        "{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
         record.timestamp,
         record.coin,
         record.upbit_close,
@@ -291,7 +356,10 @@ fn write_minute_row<W: Write>(writer: &mut W, record: &MinuteRecord) -> io::Resu
         record.stddev,
         record.z_score,
         record.position,
-        record.source
+        record.source,
+        record.window_size,
+        record.entry_z_threshold,
+        record.exit_z_threshold
     )
 }
 
@@ -364,10 +432,13 @@ mod tests {
             entry_usd_krw: 1380.0,
             exit_usd_krw: 1381.0,
             is_liquidated: false,
+            tier: 0,
             actual_upbit_fee: None,
             actual_bybit_fee: None,
             funding_fee: None,
             adjustment_cost: None,
+            entry_quality: None,
+            exit_quality: None,
         }
     }
 
@@ -385,6 +456,9 @@ mod tests {
             z_score,
             position: "NONE".to_string(),
             source: source.to_string(),
+            window_size: 1440,
+            entry_z_threshold: 2.0,
+            exit_z_threshold: 0.5,
         }
     }
 
@@ -435,6 +509,43 @@ mod tests {
         assert!(lines[2].starts_with("0,ETH,"));
     }
 
+    #[test]
+    fn test_append_spread_trade_csv_lazy() {
+        use crate::krw_spread::inventory::LegFill;
+        use crate::krw_spread::signal::SpreadDirection;
+
+        let tmp = tempfile::tempdir().unwrap();
+        let session_dir = tmp.path().join("test_spread");
+        let mut writer = SessionWriter::with_dir(session_dir.clone()).unwrap();
+
+        // 스프레드 거래가 없으면 파일을 만들지 않음
+        assert!(!session_dir.join("spread_trades.csv").exists());
+
+        let fill = LegFill {
+            qty: Decimal::from(10),
+            avg_price: Decimal::from(1_000),
+            fee_krw: Decimal::from(5),
+        };
+        for id in 1..=2 {
+            let trade = SpreadTrade::from_fills(
+                id,
+                "XRP",
+                SpreadDirection::BuyUpbitSellBithumb,
+                &fill,
+                &fill,
+                0.2,
+            );
+            writer.append_spread_trade(&trade).unwrap();
+        }
+
+        let content = fs::read_to_string(session_dir.join("spread_trades.csv")).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("id,coin,direction,"));
+        assert!(lines[1].starts_with("1,XRP,upbit_to_bithumb,"));
+        assert!(lines[2].starts_with("2,XRP,"));
+    }
+
     #[test]
     fn test_append_minute_csv() {
         let tmp = tempfile::tempdir().unwrap();
@@ -455,7 +566,9 @@ mod tests {
         // 헤더 + 데이터 2행 = 3행
         assert_eq!(lines.len(), 3);
         assert!(lines[0].starts_with("timestamp,"));
+        assert!(lines[0].ends_with(",window_size,entry_z_threshold,exit_z_threshold"));
         assert!(lines[1].contains("BTC"));
+        assert!(lines[1].ends_with(",1440,2,0.5"));
         assert!(lines[2].contains("XRP"));
     }
 
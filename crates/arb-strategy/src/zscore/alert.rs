@@ -1,15 +1,58 @@
-//! 텔레그램 알림 서비스.
+//! 알림 서비스.
 //!
-//! AlertService는 라이브 트레이딩에서 중요한 이벤트를 텔레그램으로 전송합니다.
+//! AlertService는 라이브 트레이딩에서 중요한 이벤트를 [`AlertRouter`]에 등록된 sink
+//! (Telegram, Slack, Discord, 이메일, webhook)로 전송합니다.
 //! 일반 알림은 mpsc 비동기 채널로 처리하며, DB alerts 테이블에는 항상 감사로그를 기록합니다.
-//! 텔레그램 전송은 best-effort 채널입니다.
+//! 외부 sink 전송은 best-effort 채널입니다.
 
+use arb_alert::{AlertLevel, AlertNotification, AlertRouter, FnSink, TELEGRAM_SINK};
+use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
+use std::collections::VecDeque;
 use std::fmt;
 use std::future::Future;
 use std::pin::Pin;
+use std::sync::Arc;
 use std::{fs::OpenOptions, io::Write};
 
+/// 최근 알림 보관 개수 (상태 API 조회용).
+const RECENT_ALERT_CAPACITY: usize = 100;
+
+/// 최근 처리된 알림 기록.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RecentAlert {
+    /// consumer 처리 시각 (UTC).
+    pub at: DateTime<Utc>,
+    /// 심각도 레벨.
+    pub level: String,
+    /// 이벤트 타입.
+    pub event_type: String,
+    /// 사람이 읽는 메시지.
+    pub message: String,
+}
+
+/// 최근 알림 링 버퍼 핸들.
+///
+/// 알림 채널 sender를 들고 있지 않으므로, 이 핸들이 살아 있어도
+/// consumer 종료(모든 `AlertService` drop)를 막지 않습니다.
+#[derive(Debug, Clone, Default)]
+pub struct RecentAlerts(Arc<parking_lot::Mutex<VecDeque<RecentAlert>>>);
+
+impl RecentAlerts {
+    fn push(&self, alert: RecentAlert) {
+        let mut recent = self.0.lock();
+        if recent.len() == RECENT_ALERT_CAPACITY {
+            recent.pop_front();
+        }
+        recent.push_back(alert);
+    }
+
+    /// 최근 알림을 최신순으로 반환합니다.
+    pub fn snapshot(&self) -> Vec<RecentAlert> {
+        self.0.lock().iter().rev().cloned().collect()
+    }
+}
+
 /// 알림 이벤트 타입 (13종).
 #[derive(Debug, Clone)]
 pub enum AlertEvent {
@@ -66,6 +109,12 @@ pub enum AlertEvent {
     },
     /// DB 연결 끊김.
     DbConnectionLost { retry_count: u32 },
+    /// 거래소 서버 시각 오차 임계값 초과.
+    ClockSkew {
+        exchange: String,
+        offset_ms: i64,
+        threshold_ms: u64,
+    },
     /// 펀딩비 진입 차단.
     FundingBlockEntry {
         coin: String,
@@ -83,25 +132,31 @@ pub enum AlertEvent {
 }
 
 impl AlertEvent {
-    /// 알림 심각도 레벨 ("info", "warn", "critical").
-    pub fn level(&self) -> &str {
+    /// 알림 심각도 레벨 (라우팅용).
+    pub fn alert_level(&self) -> AlertLevel {
         match self {
             Self::EntryExecuted { .. } | Self::ExitExecuted { .. } | Self::DailySummary { .. } => {
-                "info"
+                AlertLevel::Info
             }
             Self::ConnectionLost { .. }
             | Self::BalanceInsufficient { .. }
             | Self::DbConnectionLost { .. }
+            | Self::ClockSkew { .. }
             | Self::FundingBlockEntry { .. }
             | Self::ReconciliationMismatch { .. }
-            | Self::Error { .. } => "warn",
+            | Self::Error { .. } => AlertLevel::Warn,
             Self::KillSwitchTriggered { .. }
             | Self::KillSwitchComplete { .. }
             | Self::LegFailure { .. }
-            | Self::EmergencyCloseFailure { .. } => "critical",
+            | Self::EmergencyCloseFailure { .. } => AlertLevel::Critical,
         }
     }
 
+    /// 알림 심각도 레벨 ("info", "warn", "critical").
+    pub fn level(&self) -> &str {
+        self.alert_level().as_str()
+    }
+
     /// 알림이 치명적(critical)인지 확인합니다.
     pub fn is_critical(&self) -> bool {
         self.level() == "critical"
@@ -120,6 +175,7 @@ impl AlertEvent {
             Self::ConnectionLost { .. } => "connection_lost",
             Self::BalanceInsufficient { .. } => "balance_insufficient",
             Self::DbConnectionLost { .. } => "db_connection_lost",
+            Self::ClockSkew { .. } => "clock_skew",
             Self::FundingBlockEntry { .. } => "funding_block_entry",
             Self::Error { .. } => "error",
             Self::DailySummary { .. } => "daily_summary",
@@ -217,6 +273,16 @@ impl fmt::Display for AlertEvent {
                     "\u{1f5c4}\u{fe0f} DB CONNECTION LOST: retries={retry_count}"
                 )
             }
+            Self::ClockSkew {
+                exchange,
+                offset_ms,
+                threshold_ms,
+            } => {
+                write!(
+                    f,
+                    "\u{23f0} CLOCK SKEW: {exchange} offset={offset_ms}ms threshold={threshold_ms}ms"
+                )
+            }
             Self::FundingBlockEntry {
                 coin,
                 rate,
@@ -261,12 +327,12 @@ pub type DbAlertFn = Box<
 
 /// Triple failure 대응 함수 타입.
 ///
-/// DB + Telegram이 모두 실패했을 때 호출됩니다.
+/// DB와 라우팅된 외부 sink가 모두 실패했을 때 호출됩니다.
 pub type TripleFailureFn = Box<
     dyn Fn(i64, String, String, String) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync,
 >;
 
-/// AlertService: 텔레그램 알림 + DB 감사로그.
+/// AlertService: 외부 sink 알림 + DB 감사로그.
 ///
 /// 일반 알림은 mpsc 비동기 채널(64 bounded)로 전송하여 이벤트 루프를 블로킹하지 않습니다.
 /// 치명적 알림(kill switch, 비상 청산 실패)은 청산 완료 후 동기적으로 전송합니다.
@@ -276,6 +342,8 @@ pub struct AlertService {
     tx: tokio::sync::mpsc::Sender<AlertEvent>,
     /// 현재 세션 ID.
     session_id: i64,
+    /// 최근 처리된 알림 (최대 `RECENT_ALERT_CAPACITY`개).
+    recent: RecentAlerts,
 }
 
 /// AlertService consumer handle (background task).
@@ -327,6 +395,7 @@ impl AlertService {
 
     /// AlertService를 생성하고 triple failure 대응 함수를 등록합니다.
     ///
+    /// `telegram_send_fn`은 모든 알림을 받는 단일 sink로 등록됩니다.
     /// DB + Telegram 모두 실패 시:
     /// 1) stderr + /tmp/arb_emergency.log 기록
     /// 2) `triple_failure_fn` 호출
@@ -355,8 +424,44 @@ impl AlertService {
         + Send
         + Sync
         + 'static,
+    ) -> (Self, AlertConsumer) {
+        let router = AlertRouter::new().sink(TELEGRAM_SINK, FnSink::new(telegram_send_fn));
+        Self::with_router(session_id, router, db_alert_fn, triple_failure_fn)
+    }
+
+    /// 라우터로 AlertService를 생성합니다.
+    ///
+    /// 각 알림은 `router`의 라우팅 규칙에 맞는 sink로만 전송됩니다.
+    /// 대상 sink가 없는 알림은 DB에만 기록합니다.
+    /// DB와 대상 sink가 모두 실패하면 (대상 sink 중 하나라도 성공하면 실패 아님):
+    /// 1) stderr + /tmp/arb_emergency.log 기록
+    /// 2) `triple_failure_fn` 호출
+    pub fn with_router(
+        session_id: i64,
+        router: AlertRouter,
+        db_alert_fn: impl Fn(
+            i64,
+            &str,
+            &str,
+            &str,
+            Option<String>,
+        ) -> Pin<Box<dyn Future<Output = Result<(), String>> + Send>>
+        + Send
+        + Sync
+        + 'static,
+        triple_failure_fn: impl Fn(
+            i64,
+            String,
+            String,
+            String,
+        ) -> Pin<Box<dyn Future<Output = ()> + Send>>
+        + Send
+        + Sync
+        + 'static,
     ) -> (Self, AlertConsumer) {
         let (tx, mut rx) = tokio::sync::mpsc::channel::<AlertEvent>(64);
+        let recent = RecentAlerts::default();
+        let recent_consumer = recent.clone();
 
         let handle = tokio::spawn(async move {
             while let Some(event) = rx.recv().await {
@@ -365,13 +470,25 @@ impl AlertService {
                 let event_type = event.event_type().to_string();
                 let payload = format!("{:?}", event);
 
+                recent_consumer.push(RecentAlert {
+                    at: Utc::now(),
+                    level: level.clone(),
+                    event_type: event_type.clone(),
+                    message: message.clone(),
+                });
+
                 let mut db_ok = false;
-                let mut tg_ok = false;
 
                 // DB 감사로그는 항상 기록
                 let mut last_db_err = None;
-                let mut last_tg_err = None;
-                match (db_alert_fn)(session_id, &level, &event_type, &message, Some(payload)).await
+                match (db_alert_fn)(
+                    session_id,
+                    &level,
+                    &event_type,
+                    &message,
+                    Some(payload.clone()),
+                )
+                .await
                 {
                     Ok(()) => {
                         db_ok = true;
@@ -386,36 +503,44 @@ impl AlertService {
                     }
                 }
 
-                // 텔레그램은 best-effort 전송
-                match (telegram_send_fn)(message.clone()).await {
-                    Ok(()) => {
-                        tg_ok = true;
-                        tracing::debug!(
+                // 외부 sink는 best-effort 전송
+                let mut notification = AlertNotification::new(
+                    session_id,
+                    event.alert_level(),
+                    event_type.as_str(),
+                    message.as_str(),
+                );
+                notification.payload = Some(payload);
+                let deliveries = router.dispatch(&notification).await;
+                let sink_ok = deliveries.is_empty() || deliveries.iter().any(|d| d.result.is_ok());
+                let mut sink_errors = Vec::new();
+                for delivery in &deliveries {
+                    match &delivery.result {
+                        Ok(()) => tracing::debug!(
+                            sink = delivery.sink.as_str(),
                             event_type = event_type.as_str(),
-                            "텔레그램 알림 전송 완료"
-                        );
-                    }
-                    Err(tg_err) => {
-                        last_tg_err = Some(tg_err.clone());
-                        tracing::warn!(
-                            error = tg_err.as_str(),
-                            event_type = event_type.as_str(),
-                            "텔레그램 전송 실패"
-                        );
+                            "알림 전송 완료"
+                        ),
+                        Err(e) => {
+                            tracing::warn!(
+                                sink = delivery.sink.as_str(),
+                                error = %e,
+                                event_type = event_type.as_str(),
+                                "알림 sink 전송 실패"
+                            );
+                            sink_errors.push(format!("{}: {e}", delivery.sink));
+                        }
                     }
                 }
 
-                if !db_ok && !tg_ok {
+                if !db_ok && !sink_ok {
                     let emergency_message = format!(
-                        "level={level} event_type={event_type} message={message} db_error={:?} telegram_error={:?}",
-                        last_db_err, last_tg_err
+                        "level={level} event_type={event_type} message={message} db_error={:?} sink_errors={:?}",
+                        last_db_err, sink_errors
                     );
                     AlertService::write_emergency_fallback(emergency_message.as_str());
 
-                    tracing::error!(
-                        event_type = event_type.as_str(),
-                        "알림 DB + 텔레그램 모두 실패"
-                    );
+                    tracing::error!(event_type = event_type.as_str(), "알림 DB + sink 모두 실패");
 
                     (triple_failure_fn)(
                         session_id,
@@ -429,7 +554,14 @@ impl AlertService {
             tracing::info!("AlertService consumer 종료");
         });
 
-        (Self { tx, session_id }, AlertConsumer { handle })
+        (
+            Self {
+                tx,
+                session_id,
+                recent,
+            },
+            AlertConsumer { handle },
+        )
     }
 
     /// 비동기 알림을 전송합니다 (try_send, 이벤트 루프 비블로킹).
@@ -451,6 +583,11 @@ impl AlertService {
         self.session_id
     }
 
+    /// consumer가 처리한 최근 알림 핸들을 반환합니다.
+    pub fn recent_alerts(&self) -> RecentAlerts {
+        self.recent.clone()
+    }
+
     fn write_emergency_fallback(message: &str) {
         let now = chrono::Utc::now().to_rfc3339();
         let line = format!("{now} {message}");
@@ -558,6 +695,15 @@ mod tests {
             AlertEvent::DbConnectionLost { retry_count: 3 }.level(),
             "warn"
         );
+        assert_eq!(
+            AlertEvent::ClockSkew {
+                exchange: "bybit".into(),
+                offset_ms: -1500,
+                threshold_ms: 1000,
+            }
+            .level(),
+            "warn"
+        );
         assert_eq!(
             AlertEvent::FundingBlockEntry {
                 coin: "BTC".into(),
@@ -743,6 +889,11 @@ mod tests {
                 available: Decimal::from(50u64),
             },
             AlertEvent::DbConnectionLost { retry_count: 5 },
+            AlertEvent::ClockSkew {
+                exchange: "upbit".into(),
+                offset_ms: 2000,
+                threshold_ms: 1000,
+            },
             AlertEvent::FundingBlockEntry {
                 coin: "BTC".into(),
                 rate: 0.0123,
@@ -851,6 +1002,15 @@ mod tests {
             AlertEvent::DbConnectionLost { retry_count: 0 }.event_type(),
             "db_connection_lost"
         );
+        assert_eq!(
+            AlertEvent::ClockSkew {
+                exchange: "".into(),
+                offset_ms: 0,
+                threshold_ms: 0,
+            }
+            .event_type(),
+            "clock_skew"
+        );
         assert_eq!(
             AlertEvent::FundingBlockEntry {
                 coin: "BTC".into(),
@@ -906,6 +1066,33 @@ mod tests {
         assert_eq!(counter.load(Ordering::SeqCst), 1);
     }
 
+    #[tokio::test]
+    async fn test_alert_service_recent_alerts_bounded() {
+        let (service, consumer) = AlertService::new(1, noop_telegram(), noop_db_alert());
+        let recent = service.recent_alerts();
+
+        for i in 0..(RECENT_ALERT_CAPACITY + 5) {
+            service
+                .send_critical(AlertEvent::Error {
+                    message: format!("err-{i}"),
+                })
+                .await;
+        }
+        // 핸들은 sender를 들고 있지 않으므로 consumer 종료를 막지 않음
+        drop(service);
+        consumer.shutdown().await;
+
+        let recent = recent.snapshot();
+        assert_eq!(recent.len(), RECENT_ALERT_CAPACITY);
+        assert!(
+            recent[0]
+                .message
+                .contains(&format!("err-{}", RECENT_ALERT_CAPACITY + 4))
+        );
+        assert_eq!(recent[0].event_type, "error");
+        assert_eq!(recent[0].level, "warn");
+    }
+
     #[tokio::test]
     async fn test_alert_service_db_always_written() {
         let db_counter = Arc::new(AtomicU32::new(0));
@@ -1105,4 +1292,96 @@ mod tests {
 
         assert_eq!(counter.load(Ordering::SeqCst), 1);
     }
+
+    fn counting_sink(counter: &Arc<AtomicU32>) -> FnSink {
+        let c = Arc::clone(counter);
+        FnSink::new(move |_msg: String| {
+            c.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async { Ok(()) })
+        })
+    }
+
+    #[tokio::test]
+    async fn test_alert_service_routes_by_event_and_level() {
+        use arb_alert::AlertRoute;
+
+        let oncall = Arc::new(AtomicU32::new(0));
+        let quiet = Arc::new(AtomicU32::new(0));
+        let router = AlertRouter::new()
+            .sink("oncall", counting_sink(&oncall))
+            .sink("quiet", counting_sink(&quiet))
+            .route(AlertRoute::new(AlertLevel::Critical, &["oncall"]).events(&["leg_failure"]))
+            .route(AlertRoute::new(AlertLevel::Info, &["quiet"]).events(&["entry_executed"]));
+
+        let (service, consumer) =
+            AlertService::with_router(1, router, noop_db_alert(), |_, _, _, _| Box::pin(async {}));
+
+        service.send(AlertEvent::LegFailure {
+            coin: "BTC".into(),
+            succeeded_leg: "upbit".into(),
+            failed_leg: "bybit".into(),
+            action_taken: "reverse".into(),
+        });
+        service.send(AlertEvent::EntryExecuted {
+            coin: "BTC".into(),
+            qty: Decimal::ONE,
+            upbit_price: Decimal::ONE,
+            bybit_price: Decimal::ONE,
+            expected_pnl: Decimal::ONE,
+        });
+        service.send(AlertEvent::Error {
+            message: "unrouted".into(),
+        });
+
+        drop(service);
+        consumer.shutdown().await;
+
+        assert_eq!(oncall.load(Ordering::SeqCst), 1);
+        assert_eq!(quiet.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_alert_service_triple_failure_needs_all_routed_sinks_down() {
+        let hook_counter = Arc::new(AtomicU32::new(0));
+        let hook_counter_clone = Arc::clone(&hook_counter);
+        let delivered = Arc::new(AtomicU32::new(0));
+
+        let fail_db = |_sid: i64,
+                       _level: &str,
+                       _evt: &str,
+                       _msg: &str,
+                       _payload: Option<String>|
+         -> Pin<Box<dyn Future<Output = Result<(), String>> + Send>> {
+            Box::pin(async { Err("db offline".to_string()) })
+        };
+        let triple_hook = move |_sid: i64,
+                                _level: String,
+                                _event_type: String,
+                                _message: String|
+              -> Pin<Box<dyn Future<Output = ()> + Send>> {
+            let c = Arc::clone(&hook_counter_clone);
+            Box::pin(async move {
+                c.fetch_add(1, Ordering::SeqCst);
+            })
+        };
+
+        // 하나라도 성공하면 triple failure 아님
+        let router = AlertRouter::new()
+            .sink(
+                "down",
+                FnSink::new(|_msg: String| Box::pin(async { Err("offline".to_string()) })),
+            )
+            .sink("up", counting_sink(&delivered));
+        let (service, consumer) = AlertService::with_router(1, router, fail_db, triple_hook);
+
+        service.send(AlertEvent::Error {
+            message: "partial".into(),
+        });
+
+        drop(service);
+        consumer.shutdown().await;
+
+        assert_eq!(delivered.load(Ordering::SeqCst), 1);
+        assert_eq!(hook_counter.load(Ordering::SeqCst), 0);
+    }
 }
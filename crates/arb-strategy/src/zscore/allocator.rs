@@ -0,0 +1,522 @@
+//! 포트폴리오 자본 배분기 (CapitalAllocator).
+//!
+//! 틱 시그널 task는 코인별로 동시에 실행되므로, 배분기 없이는 먼저 도착한 진입 후보가
+//! 남은 자본을 먼저 가져갑니다. 배분기는 `batch_window_ms` 동안 들어온 후보를 모아
+//! 기대 수익률 × 신뢰도 순으로 정렬한 뒤 다음 한도 안에서 순서대로 크기를 배정합니다.
+//!
+//! - 코인별: `total_capital_usdt × max_position_ratio` − 보유 중인 포지션
+//! - 클러스터별 (`[allocator.clusters]`): `total_capital_usdt × max_cluster_ratio` − 보유분
+//! - 전체: `total_capital_usdt` − 모든 보유분
+//!
+//! 보유분은 배치마다 `PositionManager`에서 다시 계산하므로 포지션이 청산되어 자본이
+//! 풀리면 다음 배치의 배정 크기가 자동으로 커집니다. 배정된 자본은 진입 처리가 끝날 때까지
+//! [`AllocationGrant`]로 잡아 두어, 아직 포지션으로 등록되지 않은 배정분이 다음 배치에서
+//! 중복 배정되지 않게 합니다. 진입 주문 체결 중에는 포지션과 배정분이 함께 잡혀
+//! 보수적으로 계산됩니다.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use tokio::sync::oneshot;
+use tracing::{debug, info};
+
+use crate::zscore::config::ZScoreConfig;
+use crate::zscore::position::PositionManager;
+
+/// 진입 후보 하나.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AllocationCandidate {
+    /// 코인 심볼.
+    pub coin: String,
+    /// 기대 수익률 (%).
+    pub expected_roi_pct: f64,
+    /// 신뢰도 (0~2, z-score가 진입 임계값을 얼마나 넘었는지).
+    pub confidence: f64,
+    /// 오더북 안전 볼륨과 코인 한도로 계산한 요청 크기 (USDT).
+    pub requested_usdt: f64,
+}
+
+impl AllocationCandidate {
+    /// z-score와 진입 임계값으로 신뢰도를 계산해 후보를 생성합니다.
+    pub fn new(
+        coin: impl Into<String>,
+        z_score: f64,
+        entry_z_threshold: f64,
+        expected_roi_pct: f64,
+        requested_usdt: f64,
+    ) -> Self {
+        let confidence = if entry_z_threshold > 0.0 {
+            (z_score.abs() / entry_z_threshold).clamp(0.0, 2.0)
+        } else {
+            1.0
+        };
+        Self {
+            coin: coin.into(),
+            expected_roi_pct,
+            confidence,
+            requested_usdt,
+        }
+    }
+
+    /// 정렬 점수 (기대 수익률 × 신뢰도).
+    pub fn score(&self) -> f64 {
+        self.expected_roi_pct * self.confidence
+    }
+}
+
+/// 후보 목록을 점수 순으로 정렬해 한도 안에서 크기를 배정합니다.
+///
+/// `used_by_coin`은 보유 포지션과 처리 중인 배정분을 합친 코인별 사용 자본입니다.
+/// 반환값은 후보와 같은 순서의 배정 크기(USDT)이며, 0이면 배정 실패입니다.
+/// 같은 코인 후보가 여럿이면 점수가 가장 높은 하나만 배정받습니다.
+pub fn allocate(
+    candidates: &[AllocationCandidate],
+    used_by_coin: &HashMap<String, f64>,
+    config: &ZScoreConfig,
+) -> Vec<f64> {
+    let to_f64 = |d: Decimal| d.to_f64().unwrap_or(0.0);
+    let total_capital = to_f64(config.total_capital_usdt);
+    let coin_cap = to_f64(config.total_capital_usdt * config.max_position_ratio);
+    let cluster_cap = to_f64(config.total_capital_usdt * config.allocator.max_cluster_ratio);
+    let min_position = to_f64(config.min_position_usdt);
+
+    let mut free = total_capital - used_by_coin.values().sum::<f64>();
+    let mut cluster_used: HashMap<&str, f64> = HashMap::new();
+    for (coin, used) in used_by_coin {
+        if let Some(cluster) = config.allocator.cluster_of(coin) {
+            *cluster_used.entry(cluster).or_default() += used;
+        }
+    }
+
+    let mut order: Vec<usize> = (0..candidates.len()).collect();
+    order.sort_by(|&a, &b| {
+        candidates[b]
+            .score()
+            .partial_cmp(&candidates[a].score())
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    let mut granted = vec![0.0; candidates.len()];
+    let mut granted_coins: Vec<&str> = Vec::new();
+    for idx in order {
+        let cand = &candidates[idx];
+        if granted_coins.contains(&cand.coin.as_str()) || cand.requested_usdt <= 0.0 {
+            continue;
+        }
+        let coin_headroom = coin_cap - used_by_coin.get(&cand.coin).copied().unwrap_or(0.0);
+        let cluster = config.allocator.cluster_of(&cand.coin);
+        let cluster_headroom = cluster
+            .map(|c| cluster_cap - cluster_used.get(c).copied().unwrap_or(0.0))
+            .unwrap_or(f64::MAX);
+
+        let size = cand
+            .requested_usdt
+            .min(coin_headroom)
+            .min(cluster_headroom)
+            .min(free);
+        if size <= 0.0 || size < min_position {
+            continue;
+        }
+
+        granted[idx] = size;
+        granted_coins.push(&cand.coin);
+        free -= size;
+        if let Some(c) = cluster {
+            *cluster_used.entry(c).or_default() += size;
+        }
+    }
+    granted
+}
+
+/// 배치 대기 중인 후보와 결과 전달 채널.
+struct PendingRequest {
+    candidate: AllocationCandidate,
+    tx: oneshot::Sender<f64>,
+}
+
+#[derive(Default)]
+struct AllocatorState {
+    pending: Vec<PendingRequest>,
+    /// 배정됐지만 아직 진입 처리가 끝나지 않은 코인별 자본.
+    in_flight: HashMap<String, f64>,
+}
+
+/// 진입 후보를 배치로 모아 자본을 배분합니다.
+///
+/// 모니터 하나당 하나를 `Arc`로 공유합니다.
+#[derive(Default)]
+pub struct CapitalAllocator {
+    state: parking_lot::Mutex<AllocatorState>,
+}
+
+impl CapitalAllocator {
+    /// 새 CapitalAllocator를 생성합니다.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 후보를 배치에 넣고 배정 결과를 기다립니다.
+    ///
+    /// 배치의 첫 후보가 배치 task를 띄우고, task가 `batch_window_ms`만큼 기다린 뒤 모인
+    /// 후보를 한꺼번에 배분합니다. 배치는 요청 future와 분리된 task에서 돌므로 첫 후보의
+    /// 요청이 취소돼도 나머지 후보는 결과를 받습니다. 배정 크기가 0이면 `None`을
+    /// 반환합니다. 배분기가 비활성이면 요청 크기를 그대로 배정합니다.
+    pub async fn request(
+        self: &Arc<Self>,
+        candidate: AllocationCandidate,
+        config: &Arc<ZScoreConfig>,
+        position_mgr: &Arc<tokio::sync::Mutex<PositionManager>>,
+    ) -> Option<AllocationGrant> {
+        let coin = candidate.coin.clone();
+        if !config.allocator.enabled {
+            return (candidate.requested_usdt > 0.0)
+                .then(|| self.grant(coin, candidate.requested_usdt));
+        }
+
+        let (tx, rx) = oneshot::channel();
+        let is_leader = {
+            let mut state = self.state.lock();
+            state.pending.push(PendingRequest { candidate, tx });
+            state.pending.len() == 1
+        };
+
+        if is_leader {
+            let (allocator, config, position_mgr) = (
+                Arc::clone(self),
+                Arc::clone(config),
+                Arc::clone(position_mgr),
+            );
+            tokio::spawn(async move {
+                tokio::time::sleep(Duration::from_millis(config.allocator.batch_window_ms)).await;
+                allocator.run_batch(&config, &position_mgr).await;
+            });
+        }
+
+        let size = rx.await.unwrap_or(0.0);
+        (size > 0.0).then(|| AllocationGrant {
+            allocator: Arc::clone(self),
+            coin,
+            size_usdt: size,
+        })
+    }
+
+    /// 모인 후보를 배분하고 결과를 전달합니다.
+    async fn run_batch(
+        &self,
+        config: &ZScoreConfig,
+        position_mgr: &tokio::sync::Mutex<PositionManager>,
+    ) {
+        let mut used: HashMap<String, f64> = {
+            let pm = position_mgr.lock().await;
+            pm.open_positions
+                .keys()
+                .map(|coin| {
+                    let used = pm.coin_used_capital(coin).to_f64().unwrap_or(0.0);
+                    (coin.clone(), used)
+                })
+                .collect()
+        };
+
+        let mut state = self.state.lock();
+        let batch = std::mem::take(&mut state.pending);
+        for (coin, amount) in &state.in_flight {
+            *used.entry(coin.clone()).or_default() += amount;
+        }
+
+        let candidates: Vec<AllocationCandidate> =
+            batch.iter().map(|p| p.candidate.clone()).collect();
+        let sizes = allocate(&candidates, &used, config);
+
+        for (req, size) in batch.into_iter().zip(sizes) {
+            if size > 0.0 {
+                *state
+                    .in_flight
+                    .entry(req.candidate.coin.clone())
+                    .or_default() += size;
+                if size < req.candidate.requested_usdt {
+                    debug!(
+                        coin = req.candidate.coin.as_str(),
+                        requested_usdt = req.candidate.requested_usdt,
+                        granted_usdt = size,
+                        "자본 배분: 요청 크기 축소"
+                    );
+                }
+            } else {
+                info!(
+                    coin = req.candidate.coin.as_str(),
+                    score = req.candidate.score(),
+                    requested_usdt = req.candidate.requested_usdt,
+                    batch_size = candidates.len(),
+                    "자본 배분: 한도 소진으로 배정 없음"
+                );
+            }
+            // 수신 측이 이미 종료됐으면 배정분을 되돌림
+            if req.tx.send(size).is_err() && size > 0.0 {
+                Self::release_locked(&mut state, &req.candidate.coin, size);
+            }
+        }
+    }
+
+    /// 배분기를 거치지 않은 배정 (비활성 모드).
+    fn grant(self: &Arc<Self>, coin: String, size_usdt: f64) -> AllocationGrant {
+        *self.state.lock().in_flight.entry(coin.clone()).or_default() += size_usdt;
+        AllocationGrant {
+            allocator: Arc::clone(self),
+            coin,
+            size_usdt,
+        }
+    }
+
+    fn release_locked(state: &mut AllocatorState, coin: &str, size_usdt: f64) {
+        if let Some(amount) = state.in_flight.get_mut(coin) {
+            *amount -= size_usdt;
+            if *amount <= 1e-9 {
+                state.in_flight.remove(coin);
+            }
+        }
+    }
+
+    /// 처리 중인 배정 총액 (USDT).
+    pub fn in_flight_usdt(&self) -> f64 {
+        self.state.lock().in_flight.values().sum()
+    }
+}
+
+/// 배정된 자본. drop 시 배분기의 처리 중 금액에서 빠집니다.
+///
+/// 진입 처리(`on_entry_signal`)가 끝날 때까지 유지해야 합니다. 그 시점에는 포지션이
+/// `PositionManager`에 등록됐거나 진입이 거부된 상태입니다.
+pub struct AllocationGrant {
+    allocator: Arc<CapitalAllocator>,
+    coin: String,
+    size_usdt: f64,
+}
+
+impl AllocationGrant {
+    /// 배정 크기 (USDT).
+    pub fn size_usdt(&self) -> f64 {
+        self.size_usdt
+    }
+}
+
+impl Drop for AllocationGrant {
+    fn drop(&mut self) {
+        let mut state = self.allocator.state.lock();
+        CapitalAllocator::release_locked(&mut state, &self.coin, self.size_usdt);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_config() -> ZScoreConfig {
+        let mut config = ZScoreConfig {
+            total_capital_usdt: Decimal::from(1000),
+            max_position_ratio: Decimal::new(4, 1), // 코인당 400
+            min_position_usdt: Decimal::from(50),
+            ..Default::default()
+        };
+        config.allocator.enabled = true;
+        config.allocator.batch_window_ms = 20;
+        config.allocator.max_cluster_ratio = Decimal::new(5, 1); // 클러스터당 500
+        config.allocator.clusters.insert(
+            "meme".to_string(),
+            vec!["DOGE".to_string(), "SHIB".to_string(), "PEPE".to_string()],
+        );
+        config
+    }
+
+    fn cand(coin: &str, roi: f64, requested: f64) -> AllocationCandidate {
+        AllocationCandidate {
+            coin: coin.to_string(),
+            expected_roi_pct: roi,
+            confidence: 1.0,
+            requested_usdt: requested,
+        }
+    }
+
+    #[test]
+    fn test_confidence_from_z_score() {
+        let c = AllocationCandidate::new("BTC", -3.0, 2.0, 0.5, 100.0);
+        assert!((c.confidence - 1.5).abs() < 1e-9);
+        assert!((c.score() - 0.75).abs() < 1e-9);
+        let capped = AllocationCandidate::new("BTC", 10.0, 2.0, 0.5, 100.0);
+        assert!((capped.confidence - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_allocate_ranks_by_score_and_caps_total() {
+        let config = make_config();
+        let candidates = vec![
+            cand("BTC", 0.2, 400.0),
+            cand("ETH", 0.5, 400.0),
+            cand("XRP", 0.3, 400.0),
+        ];
+        let sizes = allocate(&candidates, &HashMap::new(), &config);
+        // ETH(400) → XRP(400) → BTC(남은 200)
+        assert_eq!(sizes, vec![200.0, 400.0, 400.0]);
+    }
+
+    #[test]
+    fn test_allocate_coin_cap_includes_existing_position() {
+        let config = make_config();
+        let used = HashMap::from([("BTC".to_string(), 350.0)]);
+        let sizes = allocate(&[cand("BTC", 0.5, 300.0)], &used, &config);
+        // 코인 한도 400 - 보유 350 = 50 (min_position 50 충족)
+        assert_eq!(sizes, vec![50.0]);
+
+        let used = HashMap::from([("BTC".to_string(), 380.0)]);
+        let sizes = allocate(&[cand("BTC", 0.5, 300.0)], &used, &config);
+        assert_eq!(sizes, vec![0.0]);
+    }
+
+    #[test]
+    fn test_allocate_cluster_cap() {
+        let config = make_config();
+        let used = HashMap::from([("DOGE".to_string(), 300.0)]);
+        let candidates = vec![cand("SHIB", 0.9, 400.0), cand("PEPE", 0.8, 400.0)];
+        let sizes = allocate(&candidates, &used, &config);
+        // meme 클러스터 한도 500 - DOGE 300 = 200 → SHIB가 모두 사용
+        assert_eq!(sizes, vec![200.0, 0.0]);
+    }
+
+    #[test]
+    fn test_allocate_duplicate_coin_keeps_best() {
+        let config = make_config();
+        let candidates = vec![cand("BTC", 0.1, 100.0), cand("BTC", 0.4, 100.0)];
+        let sizes = allocate(&candidates, &HashMap::new(), &config);
+        assert_eq!(sizes, vec![0.0, 100.0]);
+    }
+
+    #[test]
+    fn test_allocate_grows_when_capital_freed() {
+        let config = make_config();
+        let used = HashMap::from([("ETH".to_string(), 400.0), ("XRP".to_string(), 400.0)]);
+        let before = allocate(&[cand("BTC", 0.5, 400.0)], &used, &config);
+        assert_eq!(before, vec![200.0]);
+
+        let used = HashMap::from([("ETH".to_string(), 400.0)]);
+        let after = allocate(&[cand("BTC", 0.5, 400.0)], &used, &config);
+        assert_eq!(after, vec![400.0]);
+    }
+
+    #[test]
+    fn test_cluster_of_case_insensitive() {
+        let config = make_config();
+        assert_eq!(config.allocator.cluster_of("doge"), Some("meme"));
+        assert_eq!(config.allocator.cluster_of("BTC"), None);
+    }
+
+    #[tokio::test]
+    async fn test_request_batches_concurrent_candidates() {
+        let config = Arc::new(make_config());
+        let allocator = Arc::new(CapitalAllocator::new());
+        let pm = Arc::new(tokio::sync::Mutex::new(PositionManager::new()));
+
+        let spawn = |c: AllocationCandidate| {
+            let (allocator, config, pm) =
+                (Arc::clone(&allocator), Arc::clone(&config), Arc::clone(&pm));
+            tokio::spawn(async move {
+                allocator
+                    .request(c, &config, &pm)
+                    .await
+                    .map(|g| g.size_usdt())
+            })
+        };
+        // 낮은 점수 후보가 먼저 도착해도 같은 배치면 높은 점수가 우선
+        let low = spawn(cand("BTC", 0.1, 400.0));
+        tokio::task::yield_now().await;
+        let high_a = spawn(cand("ETH", 0.9, 400.0));
+        let high_b = spawn(cand("XRP", 0.8, 400.0));
+
+        assert_eq!(high_a.await.unwrap(), Some(400.0));
+        assert_eq!(high_b.await.unwrap(), Some(400.0));
+        assert_eq!(low.await.unwrap(), Some(200.0));
+        // grant가 모두 drop되어 처리 중 금액 없음
+        assert_eq!(allocator.in_flight_usdt(), 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_leader_cancelled_mid_window_still_resolves_followers() {
+        let config = Arc::new(make_config());
+        let allocator = Arc::new(CapitalAllocator::new());
+        let pm = Arc::new(tokio::sync::Mutex::new(PositionManager::new()));
+
+        let spawn = |c: AllocationCandidate| {
+            let (allocator, config, pm) =
+                (Arc::clone(&allocator), Arc::clone(&config), Arc::clone(&pm));
+            tokio::spawn(async move {
+                allocator
+                    .request(c, &config, &pm)
+                    .await
+                    .map(|g| g.size_usdt())
+            })
+        };
+        let leader = spawn(cand("BTC", 0.9, 400.0));
+        tokio::task::yield_now().await;
+        let follower = spawn(cand("ETH", 0.5, 400.0));
+        tokio::task::yield_now().await;
+        // 배치 대기 중 첫 후보 취소
+        leader.abort();
+        assert!(leader.await.unwrap_err().is_cancelled());
+
+        let size = tokio::time::timeout(Duration::from_secs(1), follower)
+            .await
+            .expect("follower must resolve")
+            .unwrap();
+        assert_eq!(size, Some(400.0));
+        // 취소된 후보의 배정분은 되돌려짐
+        assert_eq!(allocator.in_flight_usdt(), 0.0);
+
+        // 이후 요청도 새 배치로 처리됨
+        let next = allocator
+            .request(cand("XRP", 0.5, 100.0), &config, &pm)
+            .await;
+        assert_eq!(next.map(|g| g.size_usdt()), Some(100.0));
+    }
+
+    #[tokio::test]
+    async fn test_grant_holds_capital_until_dropped() {
+        let config = Arc::new(make_config());
+        let allocator = Arc::new(CapitalAllocator::new());
+        let pm = Arc::new(tokio::sync::Mutex::new(PositionManager::new()));
+
+        let first = allocator
+            .request(cand("BTC", 0.5, 400.0), &config, &pm)
+            .await
+            .unwrap();
+        let second = allocator
+            .request(cand("BTC", 0.5, 400.0), &config, &pm)
+            .await;
+        // 코인 한도 400을 첫 배정이 점유
+        assert!(second.is_none());
+
+        drop(first);
+        let third = allocator
+            .request(cand("BTC", 0.5, 400.0), &config, &pm)
+            .await;
+        assert_eq!(third.map(|g| g.size_usdt()), Some(400.0));
+    }
+
+    #[tokio::test]
+    async fn test_request_disabled_passes_through() {
+        let mut config = make_config();
+        config.allocator.enabled = false;
+        let config = Arc::new(config);
+        let allocator = Arc::new(CapitalAllocator::new());
+        let pm = Arc::new(tokio::sync::Mutex::new(PositionManager::new()));
+
+        let grant = allocator
+            .request(cand("BTC", 0.0, 5000.0), &config, &pm)
+            .await
+            .unwrap();
+        assert_eq!(grant.size_usdt(), 5000.0);
+        assert_eq!(allocator.in_flight_usdt(), 5000.0);
+        drop(grant);
+        assert_eq!(allocator.in_flight_usdt(), 0.0);
+    }
+}
@@ -3,6 +3,9 @@
 //! 거래소별 가용 잔고 추적 + 동시 진입 시 자본 예약 패턴.
 //! 단일 `parking_lot::Mutex`로 양 거래소 잔고를 보호하며,
 //! `ReservationToken` RAII 패턴으로 예약 누수를 방지합니다.
+//!
+//! 여러 전략 인스턴스가 한 계정을 나눠 쓰면 각 인스턴스는 계정 잔고의
+//! 자기 몫(`account_share`)만 추적합니다.
 
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -46,6 +49,8 @@ pub struct BalanceTracker {
     inner: Arc<Mutex<BalanceState>>,
     /// ReservationToken TTL (기본 6분).
     reservation_ttl: Duration,
+    /// 계정 잔고 중 이 추적기 몫 (Upbit, Bybit). 각각 0 < share <= 1, 전용 계정이면 1.
+    account_share: (Decimal, Decimal),
 }
 
 /// 잔고 예약 토큰 (RAII).
@@ -117,9 +122,35 @@ impl BalanceTracker {
                 next_reservation_id: 0,
             })),
             reservation_ttl: Duration::from_secs(360), // 6분
+            account_share: (Decimal::ONE, Decimal::ONE),
         }
     }
 
+    /// 다른 전략 인스턴스와 계정을 공유하는 BalanceTracker를 생성합니다.
+    ///
+    /// 거래소별 계정 전체 잔고에 `share`의 해당 몫을 곱한 금액을 초기 가용 잔고로
+    /// 사용합니다. 한쪽 거래소만 공유하면 다른 쪽 몫은 1입니다.
+    /// 각 몫은 [0, 1] 범위로 제한됩니다.
+    pub fn new_shared(
+        account_upbit_krw: Decimal,
+        account_bybit_usdt: Decimal,
+        share: (Decimal, Decimal),
+    ) -> Self {
+        let upbit_share = share.0.clamp(Decimal::ZERO, Decimal::ONE);
+        let bybit_share = share.1.clamp(Decimal::ZERO, Decimal::ONE);
+        let mut tracker = Self::new(
+            account_upbit_krw * upbit_share,
+            account_bybit_usdt * bybit_share,
+        );
+        tracker.account_share = (upbit_share, bybit_share);
+        info!(
+            upbit_share = %upbit_share,
+            bybit_share = %bybit_share,
+            "BalanceTracker 공유 계정 모드"
+        );
+        tracker
+    }
+
     /// 커스텀 TTL로 BalanceTracker를 생성합니다 (테스트용).
     #[cfg(test)]
     pub fn with_ttl(upbit_krw: Decimal, bybit_usdt: Decimal, ttl: Duration) -> Self {
@@ -131,6 +162,7 @@ impl BalanceTracker {
                 next_reservation_id: 0,
             })),
             reservation_ttl: ttl,
+            account_share: (Decimal::ONE, Decimal::ONE),
         }
     }
 
@@ -399,6 +431,16 @@ impl BalanceTracker {
         released_count
     }
 
+    /// 계정 잔고 중 이 추적기 몫을 반환합니다 (전용 계정이면 1).
+    pub fn account_share(&self) -> (Decimal, Decimal) {
+        self.account_share
+    }
+
+    /// 다른 인스턴스와 계정을 공유하면 true를 반환합니다.
+    pub fn is_shared_account(&self) -> bool {
+        self.account_share.0 < Decimal::ONE || self.account_share.1 < Decimal::ONE
+    }
+
     /// 현재 가용 잔고를 반환합니다.
     pub fn available(&self) -> (Decimal, Decimal) {
         let state = self.inner.lock();
@@ -739,4 +781,26 @@ mod tests {
         assert_eq!(reserved_upbit, Decimal::from(500_000));
         assert_eq!(reserved_bybit, Decimal::from(250));
     }
+
+    #[test]
+    fn test_new_shared_scales_balance() {
+        let bt = BalanceTracker::new_shared(
+            Decimal::from(1_000_000),
+            Decimal::from(500),
+            (Decimal::new(25, 2), Decimal::ONE),
+        );
+        assert_eq!(bt.available(), (Decimal::from(250_000), Decimal::from(500)));
+        assert_eq!(bt.account_share(), (Decimal::new(25, 2), Decimal::ONE));
+        assert!(bt.is_shared_account());
+
+        let dedicated = BalanceTracker::new(Decimal::from(1_000_000), Decimal::from(500));
+        assert!(!dedicated.is_shared_account());
+        let clamped = BalanceTracker::new_shared(
+            Decimal::from(100),
+            Decimal::from(10),
+            (Decimal::from(2), Decimal::ONE),
+        );
+        assert_eq!(clamped.account_share(), (Decimal::ONE, Decimal::ONE));
+        assert!(!clamped.is_shared_account());
+    }
 }
@@ -0,0 +1,379 @@
+//! 코인별 전략 파라미터 (윈도우 크기, 진입/청산 임계값).
+//!
+//! `strategy.toml`의 `[zscore.coin_overrides.<COIN>]` 정적 오버라이드와
+//! adaptive 모드의 주기적 재추정 결과를 하나의 테이블로 관리합니다.
+//!
+//! 우선순위: adaptive 조정값 > 코인 오버라이드 > 전역 설정.
+//!
+//! adaptive 모드는 스프레드 half-life로 윈도우 크기를, 단기/장기 stddev 비율로
+//! 임계값 배율을 정합니다. 단기 변동성이 장기 대비 커지면 장기 stddev 기준
+//! Z-Score가 과대평가되므로 임계값을 함께 올려 과잉 진입을 막습니다.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::zscore::config::ZScoreConfig;
+
+/// 윈도우 크기 변경 최소 비율 (현재 대비 20% 미만 변화는 무시).
+const ADAPTIVE_WINDOW_MIN_CHANGE_RATIO: f64 = 0.2;
+
+/// 진입 임계값 변경 최소 폭 (Z-Score 단위).
+const ADAPTIVE_THRESHOLD_MIN_CHANGE: f64 = 0.05;
+
+/// 코인 하나에 적용되는 전략 파라미터.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CoinParams {
+    /// 캔들 윈도우 크기.
+    pub window_size: usize,
+    /// Z-Score 진입 임계값.
+    pub entry_z_threshold: f64,
+    /// Z-Score 청산 임계값.
+    pub exit_z_threshold: f64,
+}
+
+impl CoinParams {
+    /// 전역 설정값으로 파라미터를 생성합니다.
+    pub fn from_config(config: &ZScoreConfig) -> Self {
+        Self {
+            window_size: config.window_size,
+            entry_z_threshold: config.entry_z_threshold,
+            exit_z_threshold: config.exit_z_threshold,
+        }
+    }
+}
+
+/// `[zscore.coin_overrides.<COIN>]` 섹션의 코인별 오버라이드.
+///
+/// 지정하지 않은 필드는 전역 설정값을 따릅니다.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CoinOverride {
+    /// 캔들 윈도우 크기.
+    pub window_size: Option<usize>,
+    /// Z-Score 진입 임계값.
+    pub entry_z_threshold: Option<f64>,
+    /// Z-Score 청산 임계값.
+    pub exit_z_threshold: Option<f64>,
+}
+
+impl CoinOverride {
+    /// 기본 파라미터에 오버라이드를 적용합니다.
+    pub fn apply(&self, base: CoinParams) -> CoinParams {
+        CoinParams {
+            window_size: self.window_size.unwrap_or(base.window_size),
+            entry_z_threshold: self.entry_z_threshold.unwrap_or(base.entry_z_threshold),
+            exit_z_threshold: self.exit_z_threshold.unwrap_or(base.exit_z_threshold),
+        }
+    }
+}
+
+/// adaptive 재추정 입력값.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdaptiveEstimate {
+    /// 스프레드 half-life (캔들 수). 평균 회귀하지 않으면 `None`.
+    pub half_life: Option<f64>,
+    /// 단기(60분) stddev / 장기 stddev 비율. 단기 윈도우 미준비 시 `None`.
+    pub vol_ratio: Option<f64>,
+}
+
+/// 추정값으로 새 파라미터를 계산합니다.
+///
+/// - 윈도우: `half_life × adaptive_half_life_multiplier`를
+///   `[adaptive_min_window, adaptive_max_window]`로 clamp. half-life가 없으면 기준값 유지.
+/// - 임계값: 기준 진입/청산 임계값에 `vol_ratio`를
+///   `[adaptive_threshold_scale_min, adaptive_threshold_scale_max]`로 clamp한 배율을 곱합니다.
+///   진입/청산에 같은 배율을 곱하므로 `entry > exit` 관계가 유지됩니다.
+///
+/// # 인자
+///
+/// * `configured` - 오버라이드까지 반영한 기준 파라미터
+/// * `estimate` - half-life / 변동성 비율 추정값
+/// * `config` - adaptive 설정을 담은 전략 설정
+pub fn adapt_params(
+    configured: CoinParams,
+    estimate: &AdaptiveEstimate,
+    config: &ZScoreConfig,
+) -> CoinParams {
+    let window_size = match estimate.half_life {
+        Some(hl) if hl.is_finite() && hl > 0.0 => {
+            let target = (hl * config.adaptive_half_life_multiplier).round();
+            let min = config.adaptive_min_window as f64;
+            let max = config.adaptive_max_window as f64;
+            target.clamp(min, max) as usize
+        }
+        _ => configured.window_size,
+    };
+
+    let scale = estimate
+        .vol_ratio
+        .filter(|r| r.is_finite() && *r > 0.0)
+        .map(|r| {
+            r.clamp(
+                config.adaptive_threshold_scale_min,
+                config.adaptive_threshold_scale_max,
+            )
+        })
+        .unwrap_or(1.0);
+
+    CoinParams {
+        window_size,
+        entry_z_threshold: configured.entry_z_threshold * scale,
+        exit_z_threshold: configured.exit_z_threshold * scale,
+    }
+}
+
+/// 현재 파라미터 대비 의미 있는 변화인지 판단합니다.
+///
+/// 매 주기 미세 조정으로 윈도우가 흔들리지 않도록 윈도우는 20% 이상,
+/// 진입 임계값은 0.05 이상 변할 때만 변경으로 간주합니다.
+pub fn is_significant_change(current: &CoinParams, proposed: &CoinParams) -> bool {
+    let window_delta = (proposed.window_size as f64 - current.window_size as f64).abs();
+    let window_changed =
+        window_delta >= current.window_size as f64 * ADAPTIVE_WINDOW_MIN_CHANGE_RATIO;
+    let threshold_changed = (proposed.entry_z_threshold - current.entry_z_threshold).abs()
+        >= ADAPTIVE_THRESHOLD_MIN_CHANGE;
+    window_changed || threshold_changed
+}
+
+/// 코인별 파라미터 테이블.
+///
+/// `Arc<parking_lot::RwLock<CoinParamsTable>>`로 공유되며,
+/// 틱 경로에서는 read lock으로 `get`만 호출합니다.
+#[derive(Debug, Clone)]
+pub struct CoinParamsTable {
+    /// 전역 설정값.
+    base: CoinParams,
+    /// 코인별 정적 오버라이드 (base에 적용 완료된 값).
+    overrides: HashMap<String, CoinParams>,
+    /// adaptive 모드가 조정한 값.
+    adapted: HashMap<String, CoinParams>,
+}
+
+impl CoinParamsTable {
+    /// 설정에서 테이블을 생성합니다.
+    pub fn new(config: &ZScoreConfig) -> Self {
+        let base = CoinParams::from_config(config);
+        let overrides = config
+            .coin_overrides
+            .iter()
+            .map(|(coin, ov)| (coin.clone(), ov.apply(base)))
+            .collect();
+        Self {
+            base,
+            overrides,
+            adapted: HashMap::new(),
+        }
+    }
+
+    /// 전역값과 코인 오버라이드를 새 설정으로 교체합니다 (설정 핫 리로드).
+    ///
+    /// adaptive 조정값은 다음 재추정 주기까지 그대로 유지됩니다.
+    pub fn rebase(&mut self, config: &ZScoreConfig) {
+        let adapted = std::mem::take(&mut self.adapted);
+        *self = Self::new(config);
+        self.adapted = adapted;
+    }
+
+    /// 코인에 현재 적용 중인 파라미터를 반환합니다.
+    pub fn get(&self, coin: &str) -> CoinParams {
+        self.adapted
+            .get(coin)
+            .copied()
+            .unwrap_or_else(|| self.configured(coin))
+    }
+
+    /// adaptive 조정 이전의 설정 파라미터 (오버라이드 > 전역)를 반환합니다.
+    pub fn configured(&self, coin: &str) -> CoinParams {
+        self.overrides.get(coin).copied().unwrap_or(self.base)
+    }
+
+    /// adaptive 조정값을 기록합니다.
+    pub fn set_adapted(&mut self, coin: &str, params: CoinParams) {
+        self.adapted.insert(coin.to_string(), params);
+    }
+
+    /// 코인의 adaptive 조정값을 제거합니다 (코인 제거 시 호출).
+    pub fn reset_coin(&mut self, coin: &str) {
+        self.adapted.remove(coin);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn adaptive_config() -> ZScoreConfig {
+        ZScoreConfig {
+            window_size: 1440,
+            entry_z_threshold: 2.0,
+            exit_z_threshold: 0.5,
+            adaptive_params: true,
+            adaptive_half_life_multiplier: 4.0,
+            adaptive_min_window: 120,
+            adaptive_max_window: 2880,
+            adaptive_threshold_scale_min: 0.8,
+            adaptive_threshold_scale_max: 1.5,
+            ..ZScoreConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_coin_override_apply_partial() {
+        let base = CoinParams {
+            window_size: 1440,
+            entry_z_threshold: 2.0,
+            exit_z_threshold: 0.5,
+        };
+        let ov = CoinOverride {
+            window_size: Some(720),
+            entry_z_threshold: None,
+            exit_z_threshold: Some(0.3),
+        };
+        let applied = ov.apply(base);
+        assert_eq!(applied.window_size, 720);
+        assert_eq!(applied.entry_z_threshold, 2.0);
+        assert_eq!(applied.exit_z_threshold, 0.3);
+    }
+
+    #[test]
+    fn test_table_priority() {
+        let mut config = adaptive_config();
+        config.coin_overrides.insert(
+            "ETH".to_string(),
+            CoinOverride {
+                entry_z_threshold: Some(2.5),
+                ..CoinOverride::default()
+            },
+        );
+        let mut table = CoinParamsTable::new(&config);
+
+        // 오버라이드 없는 코인은 전역값
+        assert_eq!(table.get("BTC").entry_z_threshold, 2.0);
+        // 오버라이드 적용
+        assert_eq!(table.get("ETH").entry_z_threshold, 2.5);
+        assert_eq!(table.get("ETH").window_size, 1440);
+
+        // adaptive 값이 최우선
+        let adapted = CoinParams {
+            window_size: 600,
+            entry_z_threshold: 3.0,
+            exit_z_threshold: 0.75,
+        };
+        table.set_adapted("ETH", adapted);
+        assert_eq!(table.get("ETH"), adapted);
+        assert_eq!(table.configured("ETH").entry_z_threshold, 2.5);
+
+        // 리셋 시 오버라이드로 복귀
+        table.reset_coin("ETH");
+        assert_eq!(table.get("ETH").entry_z_threshold, 2.5);
+
+        // rebase는 전역값만 교체하고 adaptive 값은 유지
+        table.set_adapted("XRP", adapted);
+        config.entry_z_threshold = 2.2;
+        table.rebase(&config);
+        assert_eq!(table.get("BTC").entry_z_threshold, 2.2);
+        assert_eq!(table.get("ETH").entry_z_threshold, 2.5);
+        assert_eq!(table.get("XRP"), adapted);
+    }
+
+    #[test]
+    fn test_adapt_params_window_from_half_life() {
+        let config = adaptive_config();
+        let configured = CoinParams::from_config(&config);
+        let est = AdaptiveEstimate {
+            half_life: Some(100.0),
+            vol_ratio: None,
+        };
+        let adapted = adapt_params(configured, &est, &config);
+        assert_eq!(adapted.window_size, 400);
+        // vol_ratio 없으면 임계값 유지
+        assert_eq!(adapted.entry_z_threshold, 2.0);
+        assert_eq!(adapted.exit_z_threshold, 0.5);
+    }
+
+    #[test]
+    fn test_adapt_params_window_clamped() {
+        let config = adaptive_config();
+        let configured = CoinParams::from_config(&config);
+
+        let short = AdaptiveEstimate {
+            half_life: Some(5.0),
+            vol_ratio: None,
+        };
+        assert_eq!(adapt_params(configured, &short, &config).window_size, 120);
+
+        let long = AdaptiveEstimate {
+            half_life: Some(5000.0),
+            vol_ratio: None,
+        };
+        assert_eq!(adapt_params(configured, &long, &config).window_size, 2880);
+    }
+
+    #[test]
+    fn test_adapt_params_no_half_life_keeps_window() {
+        let config = adaptive_config();
+        let configured = CoinParams {
+            window_size: 720,
+            ..CoinParams::from_config(&config)
+        };
+        let est = AdaptiveEstimate {
+            half_life: None,
+            vol_ratio: Some(1.0),
+        };
+        assert_eq!(adapt_params(configured, &est, &config).window_size, 720);
+    }
+
+    #[test]
+    fn test_adapt_params_threshold_scale_clamped() {
+        let config = adaptive_config();
+        let configured = CoinParams::from_config(&config);
+
+        let high_vol = AdaptiveEstimate {
+            half_life: None,
+            vol_ratio: Some(3.0),
+        };
+        let adapted = adapt_params(configured, &high_vol, &config);
+        assert!((adapted.entry_z_threshold - 3.0).abs() < 1e-9);
+        assert!((adapted.exit_z_threshold - 0.75).abs() < 1e-9);
+
+        let low_vol = AdaptiveEstimate {
+            half_life: None,
+            vol_ratio: Some(0.1),
+        };
+        let adapted = adapt_params(configured, &low_vol, &config);
+        assert!((adapted.entry_z_threshold - 1.6).abs() < 1e-9);
+        assert!((adapted.exit_z_threshold - 0.4).abs() < 1e-9);
+        assert!(adapted.entry_z_threshold > adapted.exit_z_threshold);
+    }
+
+    #[test]
+    fn test_is_significant_change() {
+        let current = CoinParams {
+            window_size: 1000,
+            entry_z_threshold: 2.0,
+            exit_z_threshold: 0.5,
+        };
+
+        // 10% 윈도우 변화, 임계값 동일 → 무시
+        let small = CoinParams {
+            window_size: 1100,
+            ..current
+        };
+        assert!(!is_significant_change(&current, &small));
+
+        // 20% 윈도우 변화 → 변경
+        let window = CoinParams {
+            window_size: 800,
+            ..current
+        };
+        assert!(is_significant_change(&current, &window));
+
+        // 임계값 0.1 변화 → 변경
+        let threshold = CoinParams {
+            entry_z_threshold: 2.1,
+            ..current
+        };
+        assert!(is_significant_change(&current, &threshold));
+    }
+}
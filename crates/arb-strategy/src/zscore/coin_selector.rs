@@ -2,12 +2,16 @@
 //!
 //! 양쪽 거래소의 전종목 티커를 조회하여 교집합을 구하고,
 //! 거래량과 변동성 기준으로 최적의 코인을 자동 선택합니다.
+//! 워밍업 이후에는 스프레드의 평균 회귀 점수(ADF, Engle-Granger, half-life)를
+//! 후보에 부착하여 평균 회귀 성향이 강한 코인을 우선 선택할 수 있게 합니다.
 
+use crate::common::statistics;
 use crate::error::StrategyError;
+use crate::zscore::spread::SpreadCalculator;
 use arb_exchange::{CandleInterval, MarketData, Ticker};
 use rust_decimal::Decimal;
 use rust_decimal::prelude::ToPrimitive;
-use std::collections::HashSet;
+use std::collections::{HashSet, VecDeque};
 use tracing::{debug, info, warn};
 
 /// 스테이블코인 목록 (자동 선택에서 제외).
@@ -24,6 +28,132 @@ pub struct CoinCandidate {
     pub volume_1h_usdt: f64,
     /// 24시간 변동성 ((high - low) / low × 100).
     pub volatility_24h_pct: f64,
+    /// 스프레드 평균 회귀 점수 (워밍업 후 `attach_mean_reversion_scores`로 채워짐).
+    pub mean_reversion: Option<MeanReversionScore>,
+}
+
+/// 스프레드 평균 회귀 점수.
+///
+/// 워밍업 캔들로 계산하며, 스프레드(%)가 실제로 평균으로 회귀하는지를 나타냅니다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MeanReversionScore {
+    /// 스프레드(%) 시계열의 ADF 검정 통계량 (음수일수록 강한 평균 회귀).
+    pub adf_stat: f64,
+    /// 유의수준에서 스프레드가 정상(stationary) 시계열인지 여부.
+    pub stationary: bool,
+    /// Bybit ~ Upbit(USD) 가격 Engle-Granger 잔차 ADF 통계량.
+    pub coint_stat: Option<f64>,
+    /// 유의수준에서 두 가격이 공적분 관계인지 여부.
+    pub cointegrated: bool,
+    /// Engle-Granger 1단계 hedge ratio (Bybit = α + β·Upbit).
+    pub hedge_ratio: Option<f64>,
+    /// Ornstein-Uhlenbeck half-life (캔들 수). None이면 평균 회귀하지 않음.
+    pub half_life: Option<f64>,
+}
+
+impl MeanReversionScore {
+    /// 워밍업 윈도우에서 평균 회귀 점수를 계산합니다.
+    ///
+    /// 스프레드 ADF 검정이나 half-life 추정이 실패하면 (데이터 부족, 상수 시계열 등)
+    /// `None`을 반환합니다. 공적분 검정 실패는 해당 필드만 비웁니다.
+    ///
+    /// # 인자
+    ///
+    /// * `spread` - 스프레드(%) 윈도우
+    /// * `upbit_usd` - Upbit USD 환산 가격 윈도우
+    /// * `bybit` - Bybit 가격 윈도우
+    /// * `adf_lags` - ADF 검정 차분 시차 수
+    /// * `significance` - 유의수준 (0.01, 0.05, 0.10)
+    pub fn from_windows(
+        spread: &VecDeque<f64>,
+        upbit_usd: &VecDeque<f64>,
+        bybit: &VecDeque<f64>,
+        adf_lags: usize,
+        significance: f64,
+    ) -> Option<Self> {
+        let adf = statistics::adf_test(spread, adf_lags).ok()?;
+        let half_life = statistics::half_life(spread).ok()?;
+        let coint = statistics::engle_granger(bybit, upbit_usd, adf_lags).ok();
+
+        Some(Self {
+            adf_stat: adf.statistic,
+            stationary: adf.is_stationary(significance),
+            coint_stat: coint.map(|c| c.adf.statistic),
+            cointegrated: coint
+                .map(|c| c.is_cointegrated(significance))
+                .unwrap_or(false),
+            hedge_ratio: coint.map(|c| c.hedge_ratio),
+            half_life,
+        })
+    }
+
+    /// 선택 기준 통과 여부.
+    ///
+    /// * `max_half_life` - half-life 상한 (캔들 수, 0.0이면 비활성화)
+    /// * `require_stationary` - true이면 ADF 정상성 검정 통과 필수
+    pub fn passes(&self, max_half_life: f64, require_stationary: bool) -> bool {
+        if require_stationary && !self.stationary {
+            return false;
+        }
+        if max_half_life > 0.0 {
+            return matches!(self.half_life, Some(hl) if hl <= max_half_life);
+        }
+        true
+    }
+
+    /// 정렬용 강도 키: half-life가 짧을수록 (평균 회귀가 빠를수록) 작은 값.
+    ///
+    /// 평균 회귀하지 않으면 `f64::INFINITY`.
+    pub fn strength_key(&self) -> f64 {
+        self.half_life.unwrap_or(f64::INFINITY)
+    }
+}
+
+/// 워밍업된 SpreadCalculator에서 후보별 평균 회귀 점수를 계산하여 부착합니다.
+///
+/// SpreadCalculator에 없거나 윈도우가 준비되지 않은 코인은 `None`으로 남습니다.
+pub fn attach_mean_reversion_scores(
+    candidates: &mut [CoinCandidate],
+    spread_calc: &SpreadCalculator,
+    adf_lags: usize,
+    significance: f64,
+) {
+    for candidate in candidates.iter_mut() {
+        let coin = candidate.coin.as_str();
+        if !spread_calc.is_ready(coin) {
+            candidate.mean_reversion = None;
+            continue;
+        }
+        let windows = (
+            spread_calc.spread_window(coin),
+            spread_calc.upbit_window(coin),
+            spread_calc.bybit_window(coin),
+        );
+        candidate.mean_reversion = match windows {
+            (Some(spread), Some(upbit), Some(bybit)) => MeanReversionScore::from_windows(
+                spread.data(),
+                upbit.data(),
+                bybit.data(),
+                adf_lags,
+                significance,
+            ),
+            _ => None,
+        };
+
+        match candidate.mean_reversion {
+            Some(score) => debug!(
+                coin,
+                adf_stat = score.adf_stat,
+                stationary = score.stationary,
+                coint_stat = ?score.coint_stat,
+                cointegrated = score.cointegrated,
+                hedge_ratio = ?score.hedge_ratio,
+                half_life = ?score.half_life,
+                "평균 회귀 점수 계산 완료"
+            ),
+            None => debug!(coin, "평균 회귀 점수 계산 불가 (데이터 부족)"),
+        }
+    }
 }
 
 /// 볼륨/변동성 기반 자동 코인 선택기.
@@ -137,7 +267,7 @@ impl<'a, U: MarketData, B: MarketData> CoinSelector<'a, U, B> {
             .collect();
 
         // 거래대금 오름차순 정렬 후 상위 50%만 유지
-        volume_pairs.sort_by(|a, b| a.1.cmp(&b.1));
+        volume_pairs.sort_by_key(|a| a.1);
         let cutoff = volume_pairs.len() / 2;
         let top_half: Vec<&str> = volume_pairs[cutoff..]
             .iter()
@@ -239,6 +369,7 @@ impl<'a, U: MarketData, B: MarketData> CoinSelector<'a, U, B> {
                 coin: coin.to_string(),
                 volume_1h_usdt,
                 volatility_24h_pct,
+                mean_reversion: None,
             });
         }
 
@@ -766,4 +897,60 @@ mod tests {
             );
         }
     }
+
+    // --- MeanReversionScore 테스트 ---
+
+    fn make_score(half_life: Option<f64>, stationary: bool) -> MeanReversionScore {
+        MeanReversionScore {
+            adf_stat: -3.0,
+            stationary,
+            coint_stat: None,
+            cointegrated: false,
+            hedge_ratio: None,
+            half_life,
+        }
+    }
+
+    #[test]
+    fn test_mean_reversion_score_passes() {
+        let score = make_score(Some(12.0), true);
+        assert!(score.passes(0.0, false));
+        assert!(score.passes(20.0, true));
+        assert!(!score.passes(10.0, false));
+
+        // 비정상 스프레드는 require_stationary일 때만 탈락
+        let non_stationary = make_score(Some(12.0), false);
+        assert!(non_stationary.passes(20.0, false));
+        assert!(!non_stationary.passes(20.0, true));
+
+        // half-life 미추정 시 상한이 설정되면 탈락
+        let no_hl = make_score(None, true);
+        assert!(no_hl.passes(0.0, true));
+        assert!(!no_hl.passes(20.0, false));
+        assert_eq!(no_hl.strength_key(), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_mean_reversion_score_from_windows_ar1() {
+        // 결정적 AR(1) 스프레드 (phi=0.8) + 공적분 가격 쌍
+        let mut state = 0.3_f64;
+        let mut spread = VecDeque::new();
+        let mut upbit = VecDeque::new();
+        let mut bybit = VecDeque::new();
+        for i in 0..300 {
+            let shock = ((i * 7919) % 97) as f64 / 97.0 - 0.5;
+            state = 0.8 * state + shock * 0.1;
+            spread.push_back(state);
+            let base = 100.0 + (i as f64) * 0.05;
+            upbit.push_back(base);
+            bybit.push_back(base + state);
+        }
+
+        let score = MeanReversionScore::from_windows(&spread, &upbit, &bybit, 1, 0.05)
+            .expect("점수 계산 실패");
+        assert!(score.stationary, "adf_stat={}", score.adf_stat);
+        assert!(score.cointegrated);
+        let hl = score.half_life.expect("half-life 추정 실패");
+        assert!(hl > 0.0 && hl < 20.0, "half_life={hl}");
+    }
 }
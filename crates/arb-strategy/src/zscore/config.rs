@@ -3,30 +3,43 @@
 //! TOML 파일에서 전략 설정을 로드합니다.
 //! `arb-config`의 간이 파서는 중첩 섹션을 지원하지 않으므로,
 //! 전략 설정은 별도 파일(`strategy.toml`)로 분리하여 자체 로딩합니다.
+//!
+//! 직렬화는 `strategy.toml`과 같은 섹션 구조(`zscore`/`output`/`balance_snapshot`/`allocator`/`scale_in`/`stop_loss`)를 따르므로
+//! 직렬화 결과를 다시 `from_toml_str`로 읽으면 같은 설정이 복원됩니다.
 
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 
 use arb_exchange::CandleInterval;
 use rust_decimal::Decimal;
-use serde::Deserialize;
+use rust_decimal::prelude::ToPrimitive as _;
+use serde::{Deserialize, Serialize, Serializer};
+use sha2::{Digest, Sha256};
 use tracing::{debug, info, warn};
 
 use crate::error::StrategyError;
+use crate::zscore::coin_params::{CoinOverride, CoinParams};
+use crate::zscore::kalman::KalmanParams;
+use crate::zscore::spread::StatsMode;
+use crate::zscore::trend::TrendParams;
 
 /// Z-Score 기반 차익거래 전략 설정.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct ZScoreConfig {
     /// 대상 코인 목록 (e.g., ["BTC", "ETH", "XRP"]).
     pub coins: Vec<String>,
-    /// 캔들 윈도우 크기 (기본값: 1440 = 1일치 1분봉).
+    /// 캔들 윈도우 크기 (캔들 수, 기본값: 1440 = 1일치 1분봉).
     /// 가이드라인: 최적 윈도우 = 추정 half-life의 3~5배.
     pub window_size: usize,
-    /// 캔들 간격 (기본값: 1분).
+    /// 캔들 간격 (1분/5분/15분/1시간, 기본값: 1분).
+    /// 틱 스트림을 이 간격으로 집계하며, 워밍업도 같은 간격의 캔들을 조회합니다.
     pub candle_interval: CandleInterval,
     /// Z-Score 진입 임계값 (기본값: 2.0).
     pub entry_z_threshold: f64,
     /// Z-Score 청산 임계값 (기본값: 0.5).
     pub exit_z_threshold: f64,
+    /// 코인별 윈도우/임계값 오버라이드 (`[zscore.coin_overrides.<COIN>]`).
+    pub coin_overrides: HashMap<String, CoinOverride>,
     /// 총 자본금 (USDT 기준, 양 거래소 합산).
     pub total_capital_usdt: Decimal,
     /// 코인 페어당 최대 포지션 크기 비율 = total_capital_usdt × max_position_ratio.
@@ -56,6 +69,60 @@ pub struct ZScoreConfig {
     /// 워밍업 후 계산된 stddev가 이 값을 초과하는 코인은 자동 선택에서 제외.
     /// 0.0이면 필터 비활성화.
     pub max_spread_stddev: f64,
+    /// 코인 선택 시 최대 스프레드 half-life (캔들 수, 기본값: 0.0).
+    /// 워밍업 캔들로 추정한 Ornstein-Uhlenbeck half-life가 이 값을 초과하거나
+    /// 평균 회귀하지 않는 코인은 자동 선택에서 제외. 0.0이면 필터 비활성화.
+    pub max_half_life_candles: f64,
+    /// 코인 선택 시 스프레드 ADF 정상성 검정 통과 필수 여부 (기본값: false).
+    pub require_stationary_spread: bool,
+    /// ADF/Engle-Granger 검정 유의수준 (0.01, 0.05, 0.10 중 하나, 기본값: 0.05).
+    pub stationarity_significance: f64,
+    /// ADF 검정 차분 시차 수 (기본값: 1).
+    pub adf_lags: usize,
+    /// 코인별 윈도우/임계값 adaptive 조정 활성화 (기본값: false).
+    /// 주기적으로 half-life와 변동성을 재추정하여 윈도우 크기와 임계값을 조정합니다.
+    /// 포지션 보유 중인 코인은 청산될 때까지 조정을 보류합니다.
+    pub adaptive_params: bool,
+    /// adaptive 재추정 주기 (분, 기본값: 60).
+    pub adaptive_interval_min: u64,
+    /// 윈도우 크기 = half-life × 배수 (기본값: 4.0, 가이드라인 3~5배).
+    pub adaptive_half_life_multiplier: f64,
+    /// adaptive 윈도우 최솟값 (기본값: 120).
+    pub adaptive_min_window: usize,
+    /// adaptive 윈도우 최댓값 (기본값: 2880 = 2일치 1분봉).
+    pub adaptive_max_window: usize,
+    /// 임계값 배율 하한 (기본값: 0.8).
+    pub adaptive_threshold_scale_min: f64,
+    /// 임계값 배율 상한 (기본값: 1.5).
+    pub adaptive_threshold_scale_max: f64,
+    /// 스프레드 통계 추정 방식 ("rolling" 또는 "ewma", 기본값: "rolling").
+    /// "ewma"이면 윈도우가 가득 차기 전에도 `ewma_min_samples` 이후 z-score를 계산합니다.
+    pub stats_mode: String,
+    /// EWMA half-life (캔들 수, 기본값: 240.0). `stats_mode = "ewma"`일 때만 사용.
+    pub ewma_half_life: f64,
+    /// EWMA 통계를 유효로 간주하기 위한 최소 캔들 수 (기본값: 120).
+    pub ewma_min_samples: usize,
+    /// 헤지 모델 ("fixed" 또는 "kalman", 기본값: "fixed").
+    /// "kalman"이면 Kalman filter로 시변 헤지 비율을 추정하여 잔차 z-score로 시그널을 평가하고,
+    /// Bybit short 수량을 헤지 비율만큼 조정합니다.
+    pub hedge_model: String,
+    /// Kalman 상태 변화 속도 (0 < delta < 1, 기본값: 1e-5).
+    pub kalman_delta: f64,
+    /// Kalman 관측 노이즈 분산 (정규화 가격 기준, 기본값: 1e-6).
+    pub kalman_obs_var: f64,
+    /// 헤지 비율 하한 (기본값: 0.8). 추정값이 범위를 벗어나면 clamp.
+    pub kalman_hedge_ratio_min: f64,
+    /// 헤지 비율 상한 (기본값: 1.2).
+    pub kalman_hedge_ratio_max: f64,
+    /// 상위 타임프레임 추세 필터 활성화 (기본값: false).
+    /// 활성화 시 상위 간격 스프레드가 상승 추세이면 진입을 거부합니다.
+    pub trend_filter_enabled: bool,
+    /// 추세 필터 캔들 간격 (기본값: 1시간). `candle_interval`보다 길어야 합니다.
+    pub trend_filter_interval: CandleInterval,
+    /// 추세 판단에 사용할 상위 타임프레임 캔들 수 (기본값: 24).
+    pub trend_filter_window: usize,
+    /// 진입 거부 기준 추세 t-통계량 (기본값: 3.0).
+    pub trend_filter_max_t: f64,
     /// 자동 선택에서 제외할 코인 블랙리스트.
     pub blacklist: Vec<String>,
     /// 포지션 TTL (시간 단위, 기본값: 24).
@@ -77,6 +144,12 @@ pub struct ZScoreConfig {
     pub min_position_usdt: Decimal,
     /// 잔고 스냅샷 설정.
     pub balance_snapshot: BalanceSnapshotConfig,
+    /// 포트폴리오 자본 배분기 설정.
+    pub allocator: AllocatorConfig,
+    /// 분할 진입(피라미딩) 설정.
+    pub scale_in: ScaleInConfig,
+    /// 포지션별 손절/트레일링 스탑 설정.
+    pub stop_loss: StopLossConfig,
     /// 세션 출력 설정.
     pub output: crate::output::writer::OutputConfig,
 
@@ -85,6 +158,16 @@ pub struct ZScoreConfig {
     pub bybit_category: String,
     /// 주문 체결 대기 타임아웃 (초).
     pub order_timeout_sec: u64,
+    /// 거래소 서버 시각 probe 주기 (초).
+    pub clock_sync_interval_sec: u64,
+    /// 서버 시각 오차 알림 임계값 (밀리초, 절대값).
+    pub clock_skew_alert_ms: u64,
+    /// Upbit 미체결 주문 watchdog 점검 주기 (초).
+    pub order_watchdog_interval_sec: u64,
+    /// 전략 heartbeat 유실 판정 시간 (초). 초과 시 전략 태그 미체결 주문 전량 취소.
+    pub order_watchdog_heartbeat_timeout_sec: u64,
+    /// Bybit Disconnect Cancel All 시간창 (초, 0이면 비활성화).
+    pub bybit_dcp_window_sec: u32,
     /// 주문 재시도 횟수.
     pub max_retry_count: u32,
     /// 주문 타입: "limit_ioc", "limit_gtc_cancel", "market".
@@ -163,12 +246,21 @@ pub struct ZScoreConfig {
     pub shutdown_policy: String,
 
     // === 텔레그램 ===
-    /// 텔레그램 알림 활성화.
+    /// 텔레그램 알림 활성화 (false면 telegram 종류 알림 sink 제외).
     pub telegram_enabled: bool,
 }
 
+/// 세션에 기록하는 설정 스냅샷.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigSnapshot {
+    /// 전체 유효 설정 JSON (`strategy.toml`과 같은 섹션 구조).
+    pub json: String,
+    /// `json`의 SHA-256 hex.
+    pub hash: String,
+}
+
 /// 잔고 스냅샷 설정.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct BalanceSnapshotConfig {
     /// 정기 기록 주기 (초). 기본값: 600 (10분).
     pub interval_sec: u64,
@@ -180,6 +272,177 @@ impl Default for BalanceSnapshotConfig {
     }
 }
 
+/// 포트폴리오 자본 배분기 설정 (`[allocator]` 섹션).
+///
+/// 동작은 [`crate::zscore::allocator`] 참고.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AllocatorConfig {
+    /// 배분기 사용 여부. false면 후보별 요청 크기를 그대로 사용합니다 (선착순).
+    pub enabled: bool,
+    /// 후보를 모으는 배치 구간 (ms, 기본값: 200).
+    pub batch_window_ms: u64,
+    /// 클러스터당 최대 자본 비율 (기본값: 0.4).
+    pub max_cluster_ratio: Decimal,
+    /// 상관관계가 높은 코인 묶음 (클러스터 이름 → 코인 목록).
+    pub clusters: BTreeMap<String, Vec<String>>,
+}
+
+impl Default for AllocatorConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            batch_window_ms: 200,
+            max_cluster_ratio: Decimal::new(4, 1), // 0.4
+            clusters: BTreeMap::new(),
+        }
+    }
+}
+
+impl AllocatorConfig {
+    /// 코인이 속한 클러스터 이름을 반환합니다.
+    pub fn cluster_of(&self, coin: &str) -> Option<&str> {
+        self.clusters
+            .iter()
+            .find(|(_, coins)| coins.iter().any(|c| c.eq_ignore_ascii_case(coin)))
+            .map(|(name, _)| name.as_str())
+    }
+}
+
+/// 분할 진입 단계 하나.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntryTier {
+    /// 기본 진입 임계값에 더하는 Z-Score 오프셋 (첫 단계는 0.0).
+    pub z_offset: f64,
+    /// 코인당 최대 자본(`total_capital_usdt × max_position_ratio`) 대비 이 단계의 크기 비율.
+    pub size_ratio: Decimal,
+}
+
+/// 분할 진입(피라미딩) 설정 (`[scale_in]` 섹션).
+///
+/// Z-Score가 벌어질수록 단계별로 추가 진입합니다. 단계 k의 진입 임계값은
+/// `entry_z_threshold + tiers[k].z_offset`이고, 단계마다 별도 포지션으로 추적됩니다.
+/// 비활성화 시 기존처럼 `entry_z_threshold` 한 번에 코인 자본 한도까지 진입합니다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScaleInConfig {
+    /// 분할 진입 사용 여부.
+    pub enabled: bool,
+    /// 청산 방식 (기본값: "blended").
+    /// - "blended": 모든 단계를 `exit_z_threshold`에서 함께 청산
+    /// - "per_tier": 단계 k는 `exit_z_threshold + tiers[k].z_offset` 이하에서 청산
+    pub exit_mode: String,
+    /// 진입 단계 목록 (z_offset 오름차순).
+    pub tiers: Vec<EntryTier>,
+}
+
+impl Default for ScaleInConfig {
+    fn default() -> Self {
+        let third = Decimal::new(3333, 4); // 0.3333
+        Self {
+            enabled: false,
+            exit_mode: "blended".to_string(),
+            tiers: [0.0, 0.5, 1.0]
+                .into_iter()
+                .map(|z_offset| EntryTier {
+                    z_offset,
+                    size_ratio: third,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl ScaleInConfig {
+    /// 단계별 청산 모드 여부.
+    pub fn is_per_tier_exit(&self) -> bool {
+        self.exit_mode == "per_tier"
+    }
+
+    /// 보유 중인 단계 목록에서 다음에 진입할 단계를 반환합니다 (비어 있는 가장 낮은 단계).
+    ///
+    /// 모든 단계를 보유 중이면 `None`을 반환합니다.
+    pub fn next_tier(&self, held: &[u8]) -> Option<u8> {
+        (0..self.tiers.len())
+            .map(|t| t as u8)
+            .find(|t| !held.contains(t))
+    }
+
+    /// 단계 `tier`의 진입 임계값.
+    pub fn entry_z_threshold(&self, base: f64, tier: u8) -> f64 {
+        base + self.z_offset(tier)
+    }
+
+    /// 단계 `tier`의 청산 임계값 (blended 모드는 모든 단계가 `base`).
+    pub fn exit_z_threshold(&self, base: f64, tier: u8) -> f64 {
+        if self.is_per_tier_exit() {
+            base + self.z_offset(tier)
+        } else {
+            base
+        }
+    }
+
+    /// 단계 `tier`에 배정되는 최대 자본 (USDT).
+    pub fn tier_capital(&self, max_coin_capital: Decimal, tier: u8) -> Decimal {
+        self.tiers
+            .get(tier as usize)
+            .map(|t| max_coin_capital * t.size_ratio)
+            .unwrap_or(Decimal::ZERO)
+    }
+
+    /// 보유 단계 중 현재 Z-Score에서 청산 조건을 만족하는 단계 목록.
+    pub fn tiers_to_exit(&self, base_exit_z: f64, z_score: f64, held: &[u8]) -> Vec<u8> {
+        let mut tiers: Vec<u8> = held
+            .iter()
+            .copied()
+            .filter(|&t| z_score <= self.exit_z_threshold(base_exit_z, t))
+            .collect();
+        tiers.sort_unstable();
+        tiers.dedup();
+        tiers
+    }
+
+    /// 단계 오프셋 (범위를 벗어난 단계는 마지막 단계 오프셋 사용).
+    fn z_offset(&self, tier: u8) -> f64 {
+        self.tiers
+            .get(tier as usize)
+            .or(self.tiers.last())
+            .map(|t| t.z_offset)
+            .unwrap_or(0.0)
+    }
+}
+
+/// 포지션별 손절/트레일링 스탑 설정 (`[stop_loss]` 섹션).
+///
+/// 매 틱 Z-Score 청산과 별도로 평가되며, 규칙별로 0.0이면 해당 규칙을 끕니다.
+/// 동작은 [`crate::zscore::stops`] 참고.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StopLossConfig {
+    /// 손절 규칙 사용 여부.
+    pub enabled: bool,
+    /// 스프레드 손절: 진입 대비 스프레드 확대폭 (%p).
+    pub spread_stop_pct: f64,
+    /// Z-Score 손절: 현재 Z-Score가 이 값 이상이면 청산.
+    pub z_stop: f64,
+    /// 미실현 손실 손절: 포지션 크기 대비 손실률 (%, 왕복 수수료 포함).
+    pub max_loss_pct: f64,
+    /// 트레일링 스탑 활성화 기준 미실현 수익률 (%).
+    pub trailing_activation_pct: f64,
+    /// 트레일링 스탑 폭: 최고 미실현 수익률 대비 하락폭 (%p).
+    pub trailing_distance_pct: f64,
+}
+
+impl Default for StopLossConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            spread_stop_pct: 0.0,
+            z_stop: 0.0,
+            max_loss_pct: 0.0,
+            trailing_activation_pct: 0.0,
+            trailing_distance_pct: 0.0,
+        }
+    }
+}
+
 impl Default for ZScoreConfig {
     fn default() -> Self {
         Self {
@@ -188,6 +451,7 @@ impl Default for ZScoreConfig {
             candle_interval: CandleInterval::Minute1,
             entry_z_threshold: 2.0,
             exit_z_threshold: 0.5,
+            coin_overrides: HashMap::new(),
             total_capital_usdt: Decimal::new(10000, 0),
             max_position_ratio: Decimal::new(2, 1), // 0.2
             upbit_taker_fee: Decimal::new(5, 4),    // 0.0005
@@ -201,6 +465,29 @@ impl Default for ZScoreConfig {
             reselect_interval_min: 10,
             min_volume_1h_usdt: Decimal::new(50_000, 0),
             max_spread_stddev: 0.5,
+            max_half_life_candles: 0.0,
+            require_stationary_spread: false,
+            stationarity_significance: 0.05,
+            adf_lags: 1,
+            adaptive_params: false,
+            adaptive_interval_min: 60,
+            adaptive_half_life_multiplier: 4.0,
+            adaptive_min_window: 120,
+            adaptive_max_window: 2880,
+            adaptive_threshold_scale_min: 0.8,
+            adaptive_threshold_scale_max: 1.5,
+            stats_mode: "rolling".to_string(),
+            ewma_half_life: 240.0,
+            ewma_min_samples: 120,
+            hedge_model: "fixed".to_string(),
+            kalman_delta: 1e-5,
+            kalman_obs_var: 1e-6,
+            kalman_hedge_ratio_min: 0.8,
+            kalman_hedge_ratio_max: 1.2,
+            trend_filter_enabled: false,
+            trend_filter_interval: CandleInterval::Minute60,
+            trend_filter_window: 24,
+            trend_filter_max_t: 3.0,
             blacklist: vec![],
             position_ttl_hours: 24,
             grace_period_hours: 4,
@@ -209,10 +496,18 @@ impl Default for ZScoreConfig {
             min_expected_roi: 0.10,
             min_position_usdt: Decimal::new(100, 0),
             balance_snapshot: BalanceSnapshotConfig::default(),
+            allocator: AllocatorConfig::default(),
+            scale_in: ScaleInConfig::default(),
+            stop_loss: StopLossConfig::default(),
             output: crate::output::writer::OutputConfig::default(),
             // 주문 실행
             bybit_category: "linear".to_string(),
             order_timeout_sec: 5,
+            clock_sync_interval_sec: 60,
+            clock_skew_alert_ms: 1000,
+            order_watchdog_interval_sec: 30,
+            order_watchdog_heartbeat_timeout_sec: 180,
+            bybit_dcp_window_sec: 10,
             max_retry_count: 2,
             order_type: "limit_ioc".to_string(),
             upbit_ioc_reject_block_count: 3,
@@ -290,6 +585,151 @@ impl ZScoreConfig {
                 "entry_z_threshold must be greater than exit_z_threshold".to_string(),
             ));
         }
+        if self.allocator.max_cluster_ratio <= Decimal::ZERO
+            || self.allocator.max_cluster_ratio > Decimal::ONE
+        {
+            return Err(StrategyError::Config(
+                "allocator.max_cluster_ratio must be in (0, 1.0]".to_string(),
+            ));
+        }
+        if self.allocator.batch_window_ms > 5_000 {
+            return Err(StrategyError::Config(format!(
+                "allocator.batch_window_ms must be <= 5000, got: {}",
+                self.allocator.batch_window_ms
+            )));
+        }
+        let mut clustered: HashMap<&str, &str> = HashMap::new();
+        for (cluster, coins) in &self.allocator.clusters {
+            for coin in coins {
+                if let Some(other) = clustered.insert(coin.as_str(), cluster.as_str())
+                    && other != cluster
+                {
+                    return Err(StrategyError::Config(format!(
+                        "allocator.clusters: {coin} belongs to both {other} and {cluster}"
+                    )));
+                }
+            }
+        }
+        if self.scale_in.exit_mode != "blended" && self.scale_in.exit_mode != "per_tier" {
+            return Err(StrategyError::Config(format!(
+                "scale_in.exit_mode must be \"blended\" or \"per_tier\", got: {}",
+                self.scale_in.exit_mode
+            )));
+        }
+        if self.scale_in.enabled {
+            let tiers = &self.scale_in.tiers;
+            if tiers.is_empty() || tiers.len() > 10 {
+                return Err(StrategyError::Config(format!(
+                    "scale_in.tiers must have 1 to 10 entries, got: {}",
+                    tiers.len()
+                )));
+            }
+            if tiers[0].z_offset != 0.0 {
+                return Err(StrategyError::Config(
+                    "scale_in.tiers[0].z_offset must be 0.0".to_string(),
+                ));
+            }
+            if tiers.windows(2).any(|w| w[1].z_offset <= w[0].z_offset) {
+                return Err(StrategyError::Config(
+                    "scale_in.tiers z_offset must be strictly increasing".to_string(),
+                ));
+            }
+            if tiers
+                .iter()
+                .any(|t| t.size_ratio <= Decimal::ZERO || t.size_ratio > Decimal::ONE)
+            {
+                return Err(StrategyError::Config(
+                    "scale_in.tiers size_ratio must be in (0, 1.0]".to_string(),
+                ));
+            }
+            let total: Decimal = tiers.iter().map(|t| t.size_ratio).sum();
+            if total > Decimal::ONE {
+                return Err(StrategyError::Config(format!(
+                    "scale_in.tiers size_ratio sum must be <= 1.0, got: {total}"
+                )));
+            }
+        }
+        let stop = &self.stop_loss;
+        for (name, value) in [
+            ("spread_stop_pct", stop.spread_stop_pct),
+            ("z_stop", stop.z_stop),
+            ("max_loss_pct", stop.max_loss_pct),
+            ("trailing_activation_pct", stop.trailing_activation_pct),
+            ("trailing_distance_pct", stop.trailing_distance_pct),
+        ] {
+            if !value.is_finite() || value < 0.0 {
+                return Err(StrategyError::Config(format!(
+                    "stop_loss.{name} must be non-negative, got: {value}"
+                )));
+            }
+        }
+        if (stop.trailing_activation_pct > 0.0) != (stop.trailing_distance_pct > 0.0) {
+            return Err(StrategyError::Config(
+                "stop_loss.trailing_activation_pct and trailing_distance_pct must be set together"
+                    .to_string(),
+            ));
+        }
+        if stop.z_stop > 0.0 {
+            // 분할 진입 시 마지막 단계 진입 임계값보다 커야 모든 단계가 진입 가능
+            let highest_entry_z = if self.scale_in.enabled {
+                self.scale_in.entry_z_threshold(
+                    self.entry_z_threshold,
+                    self.scale_in.tiers.len().saturating_sub(1) as u8,
+                )
+            } else {
+                self.entry_z_threshold
+            };
+            if stop.z_stop <= highest_entry_z {
+                return Err(StrategyError::Config(format!(
+                    "stop_loss.z_stop must be greater than the highest entry z threshold ({highest_entry_z})"
+                )));
+            }
+        }
+        for (coin, ov) in &self.coin_overrides {
+            let params = ov.apply(CoinParams::from_config(self));
+            if params.window_size == 0 {
+                return Err(StrategyError::Config(format!(
+                    "coin_overrides.{coin}.window_size must be greater than 0"
+                )));
+            }
+            if params.exit_z_threshold < 0.0 {
+                return Err(StrategyError::Config(format!(
+                    "coin_overrides.{coin}.exit_z_threshold must be non-negative"
+                )));
+            }
+            if params.entry_z_threshold <= params.exit_z_threshold {
+                return Err(StrategyError::Config(format!(
+                    "coin_overrides.{coin}.entry_z_threshold must be greater than exit_z_threshold"
+                )));
+            }
+        }
+        if self.adaptive_params {
+            if self.adaptive_interval_min == 0 {
+                return Err(StrategyError::Config(
+                    "adaptive_interval_min must be greater than 0".to_string(),
+                ));
+            }
+            if self.adaptive_half_life_multiplier <= 0.0 {
+                return Err(StrategyError::Config(
+                    "adaptive_half_life_multiplier must be positive".to_string(),
+                ));
+            }
+            if self.adaptive_min_window == 0 || self.adaptive_min_window > self.adaptive_max_window
+            {
+                return Err(StrategyError::Config(
+                    "adaptive_min_window must be in [1, adaptive_max_window]".to_string(),
+                ));
+            }
+            if self.adaptive_threshold_scale_min <= 0.0
+                || self.adaptive_threshold_scale_min > 1.0
+                || self.adaptive_threshold_scale_max < 1.0
+            {
+                return Err(StrategyError::Config(
+                    "adaptive_threshold_scale_min must be in (0, 1.0] and adaptive_threshold_scale_max must be >= 1.0"
+                        .to_string(),
+                ));
+            }
+        }
         if self.max_position_ratio <= Decimal::ZERO || self.max_position_ratio > Decimal::ONE {
             return Err(StrategyError::Config(
                 "max_position_ratio must be in (0, 1.0]".to_string(),
@@ -300,6 +740,82 @@ impl ZScoreConfig {
                 "min_stddev_threshold must be positive".to_string(),
             ));
         }
+        let valid_stats_modes = ["rolling", "ewma"];
+        if !valid_stats_modes.contains(&self.stats_mode.as_str()) {
+            return Err(StrategyError::Config(format!(
+                "stats_mode must be one of {:?}, got: {}",
+                valid_stats_modes, self.stats_mode
+            )));
+        }
+        if self.stats_mode == "ewma" {
+            if !(self.ewma_half_life > 0.0 && self.ewma_half_life.is_finite()) {
+                return Err(StrategyError::Config(
+                    "ewma_half_life must be positive".to_string(),
+                ));
+            }
+            if self.ewma_min_samples == 0 {
+                return Err(StrategyError::Config(
+                    "ewma_min_samples must be greater than 0".to_string(),
+                ));
+            }
+        }
+        let valid_hedge_models = ["fixed", "kalman"];
+        if !valid_hedge_models.contains(&self.hedge_model.as_str()) {
+            return Err(StrategyError::Config(format!(
+                "hedge_model must be one of {:?}, got: {}",
+                valid_hedge_models, self.hedge_model
+            )));
+        }
+        if self.hedge_model == "kalman" {
+            if !(self.kalman_delta > 0.0 && self.kalman_delta < 1.0) {
+                return Err(StrategyError::Config(
+                    "kalman_delta must be in (0, 1)".to_string(),
+                ));
+            }
+            if !(self.kalman_obs_var > 0.0 && self.kalman_obs_var.is_finite()) {
+                return Err(StrategyError::Config(
+                    "kalman_obs_var must be positive".to_string(),
+                ));
+            }
+            if !(self.kalman_hedge_ratio_min > 0.0
+                && self.kalman_hedge_ratio_min <= 1.0
+                && self.kalman_hedge_ratio_max >= 1.0)
+            {
+                return Err(StrategyError::Config(
+                    "kalman_hedge_ratio_min must be in (0, 1.0] and kalman_hedge_ratio_max must be >= 1.0"
+                        .to_string(),
+                ));
+            }
+        }
+        if candle_interval_label(self.candle_interval).is_none() {
+            return Err(StrategyError::Config(format!(
+                "candle_interval must be one of {:?}, got: {:?}",
+                SUPPORTED_CANDLE_INTERVALS, self.candle_interval
+            )));
+        }
+        if self.trend_filter_enabled {
+            let base = self.candle_interval.as_minutes();
+            let higher = self.trend_filter_interval.as_minutes();
+            if candle_interval_label(self.trend_filter_interval).is_none()
+                || higher <= base
+                || !higher.is_multiple_of(base)
+            {
+                return Err(StrategyError::Config(format!(
+                    "trend_filter_interval must be one of {:?} and a multiple of candle_interval greater than it, got: {:?}",
+                    SUPPORTED_CANDLE_INTERVALS, self.trend_filter_interval
+                )));
+            }
+            if self.trend_filter_window < MIN_TREND_FILTER_WINDOW {
+                return Err(StrategyError::Config(format!(
+                    "trend_filter_window must be at least {MIN_TREND_FILTER_WINDOW}"
+                )));
+            }
+            if self.trend_filter_max_t.is_nan() || self.trend_filter_max_t <= 0.0 {
+                return Err(StrategyError::Config(
+                    "trend_filter_max_t must be positive".to_string(),
+                ));
+            }
+        }
         if self.max_spread_stddev < 0.0 {
             return Err(StrategyError::Config(
                 "max_spread_stddev must be non-negative".to_string(),
@@ -308,6 +824,27 @@ impl ZScoreConfig {
         if !self.auto_select && self.max_spread_stddev > 0.0 {
             warn!("max_spread_stddev는 auto_select=true일 때만 적용됩니다");
         }
+        if self.max_half_life_candles < 0.0 {
+            return Err(StrategyError::Config(
+                "max_half_life_candles must be non-negative".to_string(),
+            ));
+        }
+        let valid_significance = [0.01, 0.05, 0.10];
+        if !valid_significance
+            .iter()
+            .any(|v| (v - self.stationarity_significance).abs() < 1e-9)
+        {
+            return Err(StrategyError::Config(format!(
+                "stationarity_significance must be one of {:?}, got: {}",
+                valid_significance, self.stationarity_significance
+            )));
+        }
+        if !self.auto_select && (self.max_half_life_candles > 0.0 || self.require_stationary_spread)
+        {
+            warn!(
+                "max_half_life_candles/require_stationary_spread는 auto_select=true일 때만 적용됩니다"
+            );
+        }
         if self.min_expected_roi < 0.0 {
             return Err(StrategyError::Config(
                 "min_expected_roi must be non-negative".to_string(),
@@ -332,6 +869,34 @@ impl ZScoreConfig {
                 "order_timeout_sec must be greater than 0".to_string(),
             ));
         }
+        if self.clock_sync_interval_sec == 0 {
+            return Err(StrategyError::Config(
+                "clock_sync_interval_sec must be greater than 0".to_string(),
+            ));
+        }
+        if self.clock_skew_alert_ms == 0 {
+            return Err(StrategyError::Config(
+                "clock_skew_alert_ms must be greater than 0".to_string(),
+            ));
+        }
+        if self.order_watchdog_interval_sec == 0 {
+            return Err(StrategyError::Config(
+                "order_watchdog_interval_sec must be greater than 0".to_string(),
+            ));
+        }
+        // heartbeat는 분봉 마감마다 갱신되므로 1분 이하는 정상 상태에서도 유실로 오판
+        if self.order_watchdog_heartbeat_timeout_sec <= 60 {
+            return Err(StrategyError::Config(format!(
+                "order_watchdog_heartbeat_timeout_sec must be greater than 60, got: {}",
+                self.order_watchdog_heartbeat_timeout_sec
+            )));
+        }
+        if self.bybit_dcp_window_sec != 0 && !(3..=300).contains(&self.bybit_dcp_window_sec) {
+            return Err(StrategyError::Config(format!(
+                "bybit_dcp_window_sec must be 0 (disabled) or in [3, 300], got: {}",
+                self.bybit_dcp_window_sec
+            )));
+        }
         let valid_order_types = ["limit_ioc", "limit_gtc_cancel", "market"];
         if !valid_order_types.contains(&self.order_type.as_str()) {
             return Err(StrategyError::Config(format!(
@@ -482,6 +1047,64 @@ impl ZScoreConfig {
         Ok(())
     }
 
+    /// 코인에 설정된 파라미터를 반환합니다 (코인 오버라이드 > 전역 설정).
+    ///
+    /// adaptive 조정값은 포함하지 않습니다. 런타임 값은 `CoinParamsTable`을 사용합니다.
+    pub fn coin_params(&self, coin: &str) -> CoinParams {
+        let base = CoinParams::from_config(self);
+        self.coin_overrides
+            .get(coin)
+            .map(|ov| ov.apply(base))
+            .unwrap_or(base)
+    }
+
+    /// 워밍업 시 조회할 캔들 수를 반환합니다.
+    ///
+    /// Rolling 모드는 윈도우 전체가 필요합니다. EWMA 모드는 `ewma_half_life`의
+    /// [`EWMA_WARMUP_HALF_LIVES`]배(그 이전 캔들의 가중치는 약 3% 미만)만 조회하되
+    /// `ewma_min_samples` 이상, `window_size` 이하로 제한합니다.
+    pub fn warmup_candle_count(&self, window_size: usize) -> usize {
+        match self.spread_stats_mode() {
+            StatsMode::Ewma {
+                half_life,
+                min_samples,
+            } => {
+                let needed = (half_life * EWMA_WARMUP_HALF_LIVES).ceil() as usize;
+                needed.max(min_samples).min(window_size)
+            }
+            StatsMode::Rolling => window_size,
+        }
+    }
+
+    /// `SpreadCalculator`에 적용할 통계 추정 방식을 반환합니다.
+    pub fn spread_stats_mode(&self) -> StatsMode {
+        if self.stats_mode == "ewma" {
+            StatsMode::Ewma {
+                half_life: self.ewma_half_life,
+                min_samples: self.ewma_min_samples,
+            }
+        } else {
+            StatsMode::Rolling
+        }
+    }
+
+    /// Kalman 헤지 모델 파라미터를 반환합니다 (`hedge_model = "kalman"`이 아니면 `None`).
+    pub fn kalman_params(&self) -> Option<KalmanParams> {
+        (self.hedge_model == "kalman").then_some(KalmanParams {
+            delta: self.kalman_delta,
+            obs_var: self.kalman_obs_var,
+        })
+    }
+
+    /// 상위 타임프레임 추세 필터 파라미터를 반환합니다 (비활성 시 `None`).
+    pub fn trend_params(&self) -> Option<TrendParams> {
+        self.trend_filter_enabled.then_some(TrendParams {
+            interval: self.trend_filter_interval,
+            window: self.trend_filter_window,
+            max_t_stat: self.trend_filter_max_t,
+        })
+    }
+
     /// TOML 파일에서 설정을 로드합니다.
     ///
     /// 파일 형식은 `[zscore]` 섹션 아래에 설정값을 기술합니다.
@@ -497,6 +1120,22 @@ impl ZScoreConfig {
     pub fn from_toml_str(s: &str) -> Result<Self, StrategyError> {
         let wrapper: TomlWrapper = toml::from_str(s)
             .map_err(|e| StrategyError::Config(format!("TOML parse error: {e}")))?;
+        // 캔들 간격 문자열은 From 변환 전에 검증 (From은 실패할 수 없음)
+        for (key, value) in [
+            ("candle_interval", &wrapper.zscore.candle_interval),
+            (
+                "trend_filter_interval",
+                &wrapper.zscore.trend_filter_interval,
+            ),
+        ] {
+            if let Some(v) = value
+                && parse_candle_interval(v).is_none()
+            {
+                return Err(StrategyError::Config(format!(
+                    "{key} must be one of {SUPPORTED_CANDLE_INTERVALS:?}, got: {v}"
+                )));
+            }
+        }
         let mut config: ZScoreConfig = wrapper.zscore.into();
 
         // [output] 섹션이 있으면 OutputConfig로 변환
@@ -514,8 +1153,116 @@ impl ZScoreConfig {
             };
         }
 
+        // [allocator] 섹션이 있으면 AllocatorConfig로 변환 (클러스터 코인은 대문자 정규화)
+        if let Some(raw_alloc) = wrapper.allocator {
+            let defaults = AllocatorConfig::default();
+            config.allocator = AllocatorConfig {
+                enabled: raw_alloc.enabled.unwrap_or(defaults.enabled),
+                batch_window_ms: raw_alloc
+                    .batch_window_ms
+                    .unwrap_or(defaults.batch_window_ms),
+                max_cluster_ratio: raw_alloc
+                    .max_cluster_ratio
+                    .and_then(|v| Decimal::try_from(v).ok())
+                    .unwrap_or(defaults.max_cluster_ratio),
+                clusters: raw_alloc
+                    .clusters
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|(name, coins)| {
+                        (name, coins.into_iter().map(|c| c.to_uppercase()).collect())
+                    })
+                    .collect(),
+            };
+        }
+
+        // [stop_loss] 섹션이 있으면 StopLossConfig로 변환
+        if let Some(raw_stop) = wrapper.stop_loss {
+            let defaults = StopLossConfig::default();
+            config.stop_loss = StopLossConfig {
+                enabled: raw_stop.enabled.unwrap_or(defaults.enabled),
+                spread_stop_pct: raw_stop.spread_stop_pct.unwrap_or(defaults.spread_stop_pct),
+                z_stop: raw_stop.z_stop.unwrap_or(defaults.z_stop),
+                max_loss_pct: raw_stop.max_loss_pct.unwrap_or(defaults.max_loss_pct),
+                trailing_activation_pct: raw_stop
+                    .trailing_activation_pct
+                    .unwrap_or(defaults.trailing_activation_pct),
+                trailing_distance_pct: raw_stop
+                    .trailing_distance_pct
+                    .unwrap_or(defaults.trailing_distance_pct),
+            };
+        }
+
+        // [scale_in] 섹션이 있으면 ScaleInConfig로 변환 (tiers 생략 시 기본 3단계)
+        if let Some(raw_scale_in) = wrapper.scale_in {
+            let defaults = ScaleInConfig::default();
+            config.scale_in = ScaleInConfig {
+                enabled: raw_scale_in.enabled.unwrap_or(defaults.enabled),
+                exit_mode: raw_scale_in.exit_mode.unwrap_or(defaults.exit_mode),
+                tiers: match raw_scale_in.tiers {
+                    Some(tiers) => tiers
+                        .into_iter()
+                        .map(|t| EntryTier {
+                            z_offset: t.z_offset,
+                            size_ratio: Decimal::try_from(t.size_ratio).unwrap_or(Decimal::ZERO),
+                        })
+                        .collect(),
+                    None => defaults.tiers,
+                },
+            };
+        }
+
         Ok(config)
     }
+
+    /// `strategy.toml` 형식으로 직렬화합니다 (`from_toml_str`로 다시 읽을 수 있음).
+    pub fn to_toml_string(&self) -> Result<String, StrategyError> {
+        toml::to_string(self)
+            .map_err(|e| StrategyError::Config(format!("TOML serialize error: {e}")))
+    }
+
+    /// 세션 기록용 전체 설정 스냅샷(JSON + SHA-256 해시)을 생성합니다.
+    pub fn snapshot(&self) -> Result<ConfigSnapshot, StrategyError> {
+        let json = serde_json::to_string(self)
+            .map_err(|e| StrategyError::Config(format!("JSON serialize error: {e}")))?;
+        let hash = hex::encode(Sha256::digest(json.as_bytes()));
+        Ok(ConfigSnapshot { json, hash })
+    }
+}
+
+// === 캔들 간격 ===
+
+/// 설정 파일에서 지원하는 캔들 간격 표기.
+///
+/// Upbit/Bybit 양쪽 캔들 API가 모두 지원하는 간격만 허용합니다.
+const SUPPORTED_CANDLE_INTERVALS: [&str; 4] = ["1m", "5m", "15m", "1h"];
+
+/// 추세 필터 최소 캔들 수 (OLS 회귀 자유도 확보).
+const MIN_TREND_FILTER_WINDOW: usize = 12;
+
+/// EWMA 모드 워밍업 조회 길이 (half-life 배수). 5배 이전 캔들의 가중치는 2^-5 ≈ 3%.
+const EWMA_WARMUP_HALF_LIVES: f64 = 5.0;
+
+/// 캔들 간격 표기("1m", "5m", "15m", "1h")를 `CandleInterval`로 변환합니다.
+fn parse_candle_interval(s: &str) -> Option<CandleInterval> {
+    match s {
+        "1m" => Some(CandleInterval::Minute1),
+        "5m" => Some(CandleInterval::Minute5),
+        "15m" => Some(CandleInterval::Minute15),
+        "1h" => Some(CandleInterval::Minute60),
+        _ => None,
+    }
+}
+
+/// `CandleInterval`을 설정 파일 표기로 변환합니다 (지원하지 않는 간격이면 `None`).
+fn candle_interval_label(interval: CandleInterval) -> Option<&'static str> {
+    match interval {
+        CandleInterval::Minute1 => Some("1m"),
+        CandleInterval::Minute5 => Some("5m"),
+        CandleInterval::Minute15 => Some("15m"),
+        CandleInterval::Minute60 => Some("1h"),
+        _ => None,
+    }
 }
 
 // === serde default 함수 ===
@@ -526,6 +1273,21 @@ fn default_bybit_category() -> String {
 fn default_order_timeout_sec() -> u64 {
     5
 }
+fn default_clock_sync_interval_sec() -> u64 {
+    60
+}
+fn default_clock_skew_alert_ms() -> u64 {
+    1000
+}
+fn default_order_watchdog_interval_sec() -> u64 {
+    30
+}
+fn default_order_watchdog_heartbeat_timeout_sec() -> u64 {
+    180
+}
+fn default_bybit_dcp_window_sec() -> u32 {
+    10
+}
 fn default_max_retry_count() -> u32 {
     2
 }
@@ -623,8 +1385,9 @@ fn default_shutdown_policy() -> String {
     "keep".to_string()
 }
 
-/// TOML 최상위 래퍼 (`[zscore]`, `[output]`, `[balance_snapshot]` 섹션).
-#[derive(Deserialize)]
+/// TOML 최상위 래퍼 (`[zscore]`, `[output]`, `[balance_snapshot]`, `[allocator]`, `[scale_in]`,
+/// `[stop_loss]` 섹션).
+#[derive(Serialize, Deserialize)]
 struct TomlWrapper {
     #[serde(default)]
     zscore: RawZScoreConfig,
@@ -632,30 +1395,73 @@ struct TomlWrapper {
     output: Option<RawOutputConfig>,
     #[serde(default)]
     balance_snapshot: Option<RawBalanceSnapshotConfig>,
+    #[serde(default)]
+    allocator: Option<RawAllocatorConfig>,
+    #[serde(default)]
+    scale_in: Option<RawScaleInConfig>,
+    #[serde(default)]
+    stop_loss: Option<RawStopLossConfig>,
 }
 
 /// TOML 출력 설정 역직렬화용 중간 구조체.
-#[derive(Deserialize, Default)]
+#[derive(Serialize, Deserialize, Default)]
 struct RawOutputConfig {
     enabled: Option<bool>,
     dir: Option<String>,
 }
 
 /// TOML 잔고 스냅샷 설정 역직렬화용 중간 구조체.
-#[derive(Deserialize, Default)]
+#[derive(Serialize, Deserialize, Default)]
 struct RawBalanceSnapshotConfig {
     interval_sec: Option<u64>,
 }
 
-/// TOML 역직렬화 전용 중간 구조체.
+/// TOML 자본 배분기 설정 역직렬화용 중간 구조체.
+#[derive(Serialize, Deserialize, Default)]
+struct RawAllocatorConfig {
+    enabled: Option<bool>,
+    batch_window_ms: Option<u64>,
+    max_cluster_ratio: Option<f64>,
+    clusters: Option<BTreeMap<String, Vec<String>>>,
+}
+
+/// TOML 분할 진입 설정 역직렬화용 중간 구조체.
+#[derive(Serialize, Deserialize, Default)]
+struct RawScaleInConfig {
+    enabled: Option<bool>,
+    exit_mode: Option<String>,
+    tiers: Option<Vec<RawEntryTier>>,
+}
+
+/// TOML 손절 설정 역직렬화용 중간 구조체.
+#[derive(Serialize, Deserialize, Default)]
+struct RawStopLossConfig {
+    enabled: Option<bool>,
+    spread_stop_pct: Option<f64>,
+    z_stop: Option<f64>,
+    max_loss_pct: Option<f64>,
+    trailing_activation_pct: Option<f64>,
+    trailing_distance_pct: Option<f64>,
+}
+
+/// TOML 분할 진입 단계 역직렬화용 중간 구조체.
+#[derive(Serialize, Deserialize)]
+struct RawEntryTier {
+    z_offset: f64,
+    size_ratio: f64,
+}
+
+/// TOML 직렬화/역직렬화용 중간 구조체.
 ///
 /// `Decimal`은 TOML float에서 직접 역직렬화가 어려우므로
 /// `f64`로 먼저 받은 뒤 `Decimal::try_from()`으로 변환합니다.
-#[derive(Deserialize)]
+/// `coin_overrides`는 직렬화 결과가 항상 같도록 `BTreeMap`으로 받습니다.
+#[derive(Serialize, Deserialize)]
 #[serde(default)]
 struct RawZScoreConfig {
     coins: Vec<String>,
     window_size: usize,
+    candle_interval: Option<String>,
     entry_z_threshold: f64,
     exit_z_threshold: f64,
     total_capital_usdt: f64,
@@ -671,6 +1477,30 @@ struct RawZScoreConfig {
     reselect_interval_min: Option<u64>,
     min_volume_1h_usdt: Option<f64>,
     max_spread_stddev: Option<f64>,
+    max_half_life_candles: Option<f64>,
+    require_stationary_spread: Option<bool>,
+    stationarity_significance: Option<f64>,
+    adf_lags: Option<usize>,
+    coin_overrides: Option<BTreeMap<String, CoinOverride>>,
+    adaptive_params: Option<bool>,
+    adaptive_interval_min: Option<u64>,
+    adaptive_half_life_multiplier: Option<f64>,
+    adaptive_min_window: Option<usize>,
+    adaptive_max_window: Option<usize>,
+    adaptive_threshold_scale_min: Option<f64>,
+    adaptive_threshold_scale_max: Option<f64>,
+    stats_mode: Option<String>,
+    ewma_half_life: Option<f64>,
+    ewma_min_samples: Option<usize>,
+    hedge_model: Option<String>,
+    kalman_delta: Option<f64>,
+    kalman_obs_var: Option<f64>,
+    kalman_hedge_ratio_min: Option<f64>,
+    kalman_hedge_ratio_max: Option<f64>,
+    trend_filter_enabled: Option<bool>,
+    trend_filter_interval: Option<String>,
+    trend_filter_window: Option<usize>,
+    trend_filter_max_t: Option<f64>,
     blacklist: Option<Vec<String>>,
     position_ttl_hours: Option<u64>,
     grace_period_hours: Option<u64>,
@@ -683,6 +1513,16 @@ struct RawZScoreConfig {
     bybit_category: String,
     #[serde(default = "default_order_timeout_sec")]
     order_timeout_sec: u64,
+    #[serde(default = "default_clock_sync_interval_sec")]
+    clock_sync_interval_sec: u64,
+    #[serde(default = "default_clock_skew_alert_ms")]
+    clock_skew_alert_ms: u64,
+    #[serde(default = "default_order_watchdog_interval_sec")]
+    order_watchdog_interval_sec: u64,
+    #[serde(default = "default_order_watchdog_heartbeat_timeout_sec")]
+    order_watchdog_heartbeat_timeout_sec: u64,
+    #[serde(default = "default_bybit_dcp_window_sec")]
+    bybit_dcp_window_sec: u32,
     #[serde(default = "default_max_retry_count")]
     max_retry_count: u32,
     #[serde(default = "default_order_type")]
@@ -765,6 +1605,7 @@ impl Default for RawZScoreConfig {
         Self {
             coins: defaults.coins,
             window_size: defaults.window_size,
+            candle_interval: None,
             entry_z_threshold: defaults.entry_z_threshold,
             exit_z_threshold: defaults.exit_z_threshold,
             total_capital_usdt: 10000.0,
@@ -780,6 +1621,30 @@ impl Default for RawZScoreConfig {
             reselect_interval_min: None,
             min_volume_1h_usdt: None,
             max_spread_stddev: None,
+            max_half_life_candles: None,
+            require_stationary_spread: None,
+            stationarity_significance: None,
+            adf_lags: None,
+            coin_overrides: None,
+            adaptive_params: None,
+            adaptive_interval_min: None,
+            adaptive_half_life_multiplier: None,
+            adaptive_min_window: None,
+            adaptive_max_window: None,
+            adaptive_threshold_scale_min: None,
+            adaptive_threshold_scale_max: None,
+            stats_mode: None,
+            ewma_half_life: None,
+            ewma_min_samples: None,
+            hedge_model: None,
+            kalman_delta: None,
+            kalman_obs_var: None,
+            kalman_hedge_ratio_min: None,
+            kalman_hedge_ratio_max: None,
+            trend_filter_enabled: None,
+            trend_filter_interval: None,
+            trend_filter_window: None,
+            trend_filter_max_t: None,
             blacklist: None,
             position_ttl_hours: None,
             grace_period_hours: None,
@@ -790,6 +1655,11 @@ impl Default for RawZScoreConfig {
             // 주문 실행
             bybit_category: default_bybit_category(),
             order_timeout_sec: default_order_timeout_sec(),
+            clock_sync_interval_sec: default_clock_sync_interval_sec(),
+            clock_skew_alert_ms: default_clock_skew_alert_ms(),
+            order_watchdog_interval_sec: default_order_watchdog_interval_sec(),
+            order_watchdog_heartbeat_timeout_sec: default_order_watchdog_heartbeat_timeout_sec(),
+            bybit_dcp_window_sec: default_bybit_dcp_window_sec(),
             max_retry_count: default_max_retry_count(),
             order_type: default_order_type(),
             upbit_ioc_reject_block_count: default_upbit_ioc_reject_block_count(),
@@ -839,9 +1709,19 @@ impl From<RawZScoreConfig> for ZScoreConfig {
         Self {
             coins: raw.coins,
             window_size: raw.window_size,
-            candle_interval: CandleInterval::Minute1,
+            candle_interval: raw
+                .candle_interval
+                .as_deref()
+                .and_then(parse_candle_interval)
+                .unwrap_or(CandleInterval::Minute1),
             entry_z_threshold: raw.entry_z_threshold,
             exit_z_threshold: raw.exit_z_threshold,
+            coin_overrides: raw
+                .coin_overrides
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(coin, ov)| (coin.to_uppercase(), ov))
+                .collect(),
             total_capital_usdt: Decimal::try_from(raw.total_capital_usdt)
                 .unwrap_or(Decimal::new(10000, 0)),
             max_position_ratio: raw
@@ -862,6 +1742,33 @@ impl From<RawZScoreConfig> for ZScoreConfig {
                 .and_then(|v| Decimal::try_from(v).ok())
                 .unwrap_or(Decimal::new(50_000, 0)),
             max_spread_stddev: raw.max_spread_stddev.unwrap_or(0.5),
+            max_half_life_candles: raw.max_half_life_candles.unwrap_or(0.0),
+            require_stationary_spread: raw.require_stationary_spread.unwrap_or(false),
+            stationarity_significance: raw.stationarity_significance.unwrap_or(0.05),
+            adf_lags: raw.adf_lags.unwrap_or(1),
+            adaptive_params: raw.adaptive_params.unwrap_or(false),
+            adaptive_interval_min: raw.adaptive_interval_min.unwrap_or(60),
+            adaptive_half_life_multiplier: raw.adaptive_half_life_multiplier.unwrap_or(4.0),
+            adaptive_min_window: raw.adaptive_min_window.unwrap_or(120),
+            adaptive_max_window: raw.adaptive_max_window.unwrap_or(2880),
+            adaptive_threshold_scale_min: raw.adaptive_threshold_scale_min.unwrap_or(0.8),
+            adaptive_threshold_scale_max: raw.adaptive_threshold_scale_max.unwrap_or(1.5),
+            stats_mode: raw.stats_mode.unwrap_or_else(|| "rolling".to_string()),
+            ewma_half_life: raw.ewma_half_life.unwrap_or(240.0),
+            ewma_min_samples: raw.ewma_min_samples.unwrap_or(120),
+            hedge_model: raw.hedge_model.unwrap_or_else(|| "fixed".to_string()),
+            kalman_delta: raw.kalman_delta.unwrap_or(1e-5),
+            kalman_obs_var: raw.kalman_obs_var.unwrap_or(1e-6),
+            kalman_hedge_ratio_min: raw.kalman_hedge_ratio_min.unwrap_or(0.8),
+            kalman_hedge_ratio_max: raw.kalman_hedge_ratio_max.unwrap_or(1.2),
+            trend_filter_enabled: raw.trend_filter_enabled.unwrap_or(false),
+            trend_filter_interval: raw
+                .trend_filter_interval
+                .as_deref()
+                .and_then(parse_candle_interval)
+                .unwrap_or(CandleInterval::Minute60),
+            trend_filter_window: raw.trend_filter_window.unwrap_or(24),
+            trend_filter_max_t: raw.trend_filter_max_t.unwrap_or(3.0),
             blacklist: raw.blacklist.unwrap_or_default(),
             position_ttl_hours: raw.position_ttl_hours.unwrap_or(24),
             grace_period_hours: raw.grace_period_hours.unwrap_or(4),
@@ -873,10 +1780,18 @@ impl From<RawZScoreConfig> for ZScoreConfig {
                 .and_then(|v| Decimal::try_from(v).ok())
                 .unwrap_or(Decimal::new(100, 0)),
             balance_snapshot: BalanceSnapshotConfig::default(),
+            allocator: AllocatorConfig::default(),
+            scale_in: ScaleInConfig::default(),
+            stop_loss: StopLossConfig::default(),
             output: crate::output::writer::OutputConfig::default(),
             // 주문 실행
             bybit_category: raw.bybit_category,
             order_timeout_sec: raw.order_timeout_sec,
+            clock_sync_interval_sec: raw.clock_sync_interval_sec,
+            clock_skew_alert_ms: raw.clock_skew_alert_ms,
+            order_watchdog_interval_sec: raw.order_watchdog_interval_sec,
+            order_watchdog_heartbeat_timeout_sec: raw.order_watchdog_heartbeat_timeout_sec,
+            bybit_dcp_window_sec: raw.bybit_dcp_window_sec,
             max_retry_count: raw.max_retry_count,
             order_type: raw.order_type,
             upbit_ioc_reject_block_count: raw.upbit_ioc_reject_block_count,
@@ -921,6 +1836,162 @@ impl From<RawZScoreConfig> for ZScoreConfig {
     }
 }
 
+impl From<&ZScoreConfig> for TomlWrapper {
+    fn from(c: &ZScoreConfig) -> Self {
+        let to_f64 = |d: Decimal| d.to_f64().unwrap_or_default();
+        let zscore = RawZScoreConfig {
+            coins: c.coins.clone(),
+            window_size: c.window_size,
+            candle_interval: candle_interval_label(c.candle_interval).map(str::to_string),
+            entry_z_threshold: c.entry_z_threshold,
+            exit_z_threshold: c.exit_z_threshold,
+            total_capital_usdt: to_f64(c.total_capital_usdt),
+            max_position_ratio: Some(to_f64(c.max_position_ratio)),
+            upbit_taker_fee: to_f64(c.upbit_taker_fee),
+            bybit_taker_fee: to_f64(c.bybit_taker_fee),
+            leverage: c.leverage,
+            bybit_mmr: to_f64(c.bybit_mmr),
+            min_stddev_threshold: c.min_stddev_threshold,
+            max_concurrent_positions: c.max_concurrent_positions,
+            auto_select: Some(c.auto_select),
+            max_coins: Some(c.max_coins),
+            reselect_interval_min: Some(c.reselect_interval_min),
+            min_volume_1h_usdt: Some(to_f64(c.min_volume_1h_usdt)),
+            max_spread_stddev: Some(c.max_spread_stddev),
+            max_half_life_candles: Some(c.max_half_life_candles),
+            require_stationary_spread: Some(c.require_stationary_spread),
+            stationarity_significance: Some(c.stationarity_significance),
+            adf_lags: Some(c.adf_lags),
+            coin_overrides: Some(
+                c.coin_overrides
+                    .iter()
+                    .map(|(coin, ov)| (coin.clone(), ov.clone()))
+                    .collect(),
+            ),
+            adaptive_params: Some(c.adaptive_params),
+            adaptive_interval_min: Some(c.adaptive_interval_min),
+            adaptive_half_life_multiplier: Some(c.adaptive_half_life_multiplier),
+            adaptive_min_window: Some(c.adaptive_min_window),
+            adaptive_max_window: Some(c.adaptive_max_window),
+            adaptive_threshold_scale_min: Some(c.adaptive_threshold_scale_min),
+            adaptive_threshold_scale_max: Some(c.adaptive_threshold_scale_max),
+            stats_mode: Some(c.stats_mode.clone()),
+            ewma_half_life: Some(c.ewma_half_life),
+            ewma_min_samples: Some(c.ewma_min_samples),
+            hedge_model: Some(c.hedge_model.clone()),
+            kalman_delta: Some(c.kalman_delta),
+            kalman_obs_var: Some(c.kalman_obs_var),
+            kalman_hedge_ratio_min: Some(c.kalman_hedge_ratio_min),
+            kalman_hedge_ratio_max: Some(c.kalman_hedge_ratio_max),
+            trend_filter_enabled: Some(c.trend_filter_enabled),
+            trend_filter_interval: candle_interval_label(c.trend_filter_interval)
+                .map(str::to_string),
+            trend_filter_window: Some(c.trend_filter_window),
+            trend_filter_max_t: Some(c.trend_filter_max_t),
+            blacklist: Some(c.blacklist.clone()),
+            position_ttl_hours: Some(c.position_ttl_hours),
+            grace_period_hours: Some(c.grace_period_hours),
+            entry_cooldown_sec: Some(c.entry_cooldown_sec),
+            max_cache_age_sec: Some(c.max_cache_age_sec),
+            min_expected_roi: Some(c.min_expected_roi),
+            min_position_usdt: Some(to_f64(c.min_position_usdt)),
+            // 주문 실행
+            bybit_category: c.bybit_category.clone(),
+            order_timeout_sec: c.order_timeout_sec,
+            clock_sync_interval_sec: c.clock_sync_interval_sec,
+            clock_skew_alert_ms: c.clock_skew_alert_ms,
+            order_watchdog_interval_sec: c.order_watchdog_interval_sec,
+            order_watchdog_heartbeat_timeout_sec: c.order_watchdog_heartbeat_timeout_sec,
+            bybit_dcp_window_sec: c.bybit_dcp_window_sec,
+            max_retry_count: c.max_retry_count,
+            order_type: c.order_type.clone(),
+            upbit_ioc_reject_block_count: c.upbit_ioc_reject_block_count,
+            upbit_ioc_reject_cooldown_minutes: c.upbit_ioc_reject_cooldown_minutes,
+            max_slippage_pct: c.max_slippage_pct,
+            post_exec_pnl_gate_ratio: c.post_exec_pnl_gate_ratio,
+            emergency_wide_ioc_slippage_pct: c.emergency_wide_ioc_slippage_pct.clone(),
+            emergency_price_fallback_margin_pct: c.emergency_price_fallback_margin_pct,
+            max_dust_usdt: c.max_dust_usdt,
+            // 리스크 관리
+            kill_switch_enabled: c.kill_switch_enabled,
+            max_daily_loss_pct: c.max_daily_loss_pct,
+            max_drawdown_pct: c.max_drawdown_pct,
+            max_single_loss_pct: c.max_single_loss_pct,
+            max_daily_loss_usdt: c.max_daily_loss_usdt,
+            max_drawdown_usdt: c.max_drawdown_usdt,
+            max_single_loss_usdt: c.max_single_loss_usdt,
+            max_order_size_usdt: c.max_order_size_usdt,
+            max_rolling_24h_loss_usdt: c.max_rolling_24h_loss_usdt,
+            hwm_window_days: c.hwm_window_days,
+            max_unrealized_loss_pct: c.max_unrealized_loss_pct,
+            // 환율
+            max_forex_age_min: c.max_forex_age_min,
+            forex_change_alert_pct: c.forex_change_alert_pct,
+            forex_stabilization_minutes: c.forex_stabilization_minutes,
+            // 펀딩비
+            funding_block_before_min: c.funding_block_before_min,
+            funding_block_after_min: c.funding_block_after_min,
+            funding_force_close_enabled: c.funding_force_close_enabled,
+            funding_force_close_minutes_major: c.funding_force_close_minutes_major,
+            funding_force_close_minutes_alt: c.funding_force_close_minutes_alt,
+            funding_major_coins: c.funding_major_coins.clone(),
+            funding_alert_ratio: c.funding_alert_ratio,
+            funding_exclude_ratio: c.funding_exclude_ratio,
+            // PendingExchangeRecovery
+            pending_recovery_timeout_hours: c.pending_recovery_timeout_hours,
+            // Graceful shutdown
+            shutdown_policy: c.shutdown_policy.clone(),
+            // 텔레그램
+            telegram_enabled: c.telegram_enabled,
+        };
+        Self {
+            zscore,
+            output: Some(RawOutputConfig {
+                enabled: Some(c.output.enabled),
+                dir: Some(c.output.dir.clone()),
+            }),
+            balance_snapshot: Some(RawBalanceSnapshotConfig {
+                interval_sec: Some(c.balance_snapshot.interval_sec),
+            }),
+            allocator: Some(RawAllocatorConfig {
+                enabled: Some(c.allocator.enabled),
+                batch_window_ms: Some(c.allocator.batch_window_ms),
+                max_cluster_ratio: Some(to_f64(c.allocator.max_cluster_ratio)),
+                clusters: Some(c.allocator.clusters.clone()),
+            }),
+            scale_in: Some(RawScaleInConfig {
+                enabled: Some(c.scale_in.enabled),
+                exit_mode: Some(c.scale_in.exit_mode.clone()),
+                tiers: Some(
+                    c.scale_in
+                        .tiers
+                        .iter()
+                        .map(|t| RawEntryTier {
+                            z_offset: t.z_offset,
+                            size_ratio: to_f64(t.size_ratio),
+                        })
+                        .collect(),
+                ),
+            }),
+            stop_loss: Some(RawStopLossConfig {
+                enabled: Some(c.stop_loss.enabled),
+                spread_stop_pct: Some(c.stop_loss.spread_stop_pct),
+                z_stop: Some(c.stop_loss.z_stop),
+                max_loss_pct: Some(c.stop_loss.max_loss_pct),
+                trailing_activation_pct: Some(c.stop_loss.trailing_activation_pct),
+                trailing_distance_pct: Some(c.stop_loss.trailing_distance_pct),
+            }),
+        }
+    }
+}
+
+/// `strategy.toml`과 같은 섹션 구조로 직렬화합니다.
+impl Serialize for ZScoreConfig {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        TomlWrapper::from(self).serialize(serializer)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1149,6 +2220,287 @@ auto_select = true
         assert_eq!(config.max_spread_stddev, 0.5);
     }
 
+    // --- 평균 회귀 필터 테스트 ---
+
+    #[test]
+    fn test_mean_reversion_defaults() {
+        let config = ZScoreConfig::default();
+        assert_eq!(config.max_half_life_candles, 0.0);
+        assert!(!config.require_stationary_spread);
+        assert_eq!(config.stationarity_significance, 0.05);
+        assert_eq!(config.adf_lags, 1);
+    }
+
+    #[test]
+    fn test_mean_reversion_from_toml() {
+        let toml = r#"
+[zscore]
+coins = ["BTC"]
+auto_select = true
+max_half_life_candles = 300.0
+require_stationary_spread = true
+stationarity_significance = 0.01
+adf_lags = 2
+"#;
+        let config = ZScoreConfig::from_toml_str(toml).unwrap();
+        assert_eq!(config.max_half_life_candles, 300.0);
+        assert!(config.require_stationary_spread);
+        assert_eq!(config.stationarity_significance, 0.01);
+        assert_eq!(config.adf_lags, 2);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_stationarity_significance_invalid() {
+        let config = ZScoreConfig {
+            stationarity_significance: 0.2,
+            ..ZScoreConfig::default()
+        };
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("stationarity_significance"));
+    }
+
+    #[test]
+    fn test_max_half_life_negative_invalid() {
+        let config = ZScoreConfig {
+            max_half_life_candles: -1.0,
+            ..ZScoreConfig::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    // --- 코인별 오버라이드 / adaptive 테스트 ---
+
+    #[test]
+    fn test_coin_overrides_from_toml() {
+        let toml_str = r#"
+[zscore]
+coins = ["BTC", "ETH"]
+window_size = 1440
+entry_z_threshold = 2.0
+exit_z_threshold = 0.5
+
+[zscore.coin_overrides.ETH]
+window_size = 720
+entry_z_threshold = 2.5
+
+[zscore.coin_overrides.xrp]
+exit_z_threshold = 0.3
+"#;
+        let config = ZScoreConfig::from_toml_str(toml_str).unwrap();
+        assert!(config.validate().is_ok());
+
+        let eth = config.coin_params("ETH");
+        assert_eq!(eth.window_size, 720);
+        assert_eq!(eth.entry_z_threshold, 2.5);
+        assert_eq!(eth.exit_z_threshold, 0.5);
+
+        // 키는 대문자로 정규화
+        let xrp = config.coin_params("XRP");
+        assert_eq!(xrp.window_size, 1440);
+        assert_eq!(xrp.exit_z_threshold, 0.3);
+
+        // 오버라이드 없는 코인은 전역값
+        let btc = config.coin_params("BTC");
+        assert_eq!(btc.window_size, 1440);
+        assert_eq!(btc.entry_z_threshold, 2.0);
+    }
+
+    #[test]
+    fn test_coin_overrides_unknown_field_rejected() {
+        let toml_str = r#"
+[zscore]
+coins = ["BTC"]
+
+[zscore.coin_overrides.BTC]
+entry_z = 2.5
+"#;
+        assert!(ZScoreConfig::from_toml_str(toml_str).is_err());
+    }
+
+    #[test]
+    fn test_coin_overrides_entry_le_exit_invalid() {
+        let mut config = ZScoreConfig::default();
+        config.coin_overrides.insert(
+            "BTC".to_string(),
+            CoinOverride {
+                entry_z_threshold: Some(0.4),
+                ..CoinOverride::default()
+            },
+        );
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("coin_overrides.BTC"));
+    }
+
+    #[test]
+    fn test_adaptive_defaults() {
+        let config = ZScoreConfig::from_toml_str("[zscore]\ncoins = [\"BTC\"]\n").unwrap();
+        assert!(!config.adaptive_params);
+        assert_eq!(config.adaptive_interval_min, 60);
+        assert_eq!(config.adaptive_half_life_multiplier, 4.0);
+        assert_eq!(config.adaptive_min_window, 120);
+        assert_eq!(config.adaptive_max_window, 2880);
+        assert_eq!(config.adaptive_threshold_scale_min, 0.8);
+        assert_eq!(config.adaptive_threshold_scale_max, 1.5);
+        assert!(config.coin_overrides.is_empty());
+    }
+
+    #[test]
+    fn test_adaptive_window_range_invalid() {
+        let config = ZScoreConfig {
+            adaptive_params: true,
+            adaptive_min_window: 3000,
+            adaptive_max_window: 2880,
+            ..ZScoreConfig::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_adaptive_scale_invalid() {
+        let config = ZScoreConfig {
+            adaptive_params: true,
+            adaptive_threshold_scale_max: 0.9,
+            ..ZScoreConfig::default()
+        };
+        assert!(config.validate().is_err());
+
+        // adaptive 비활성화 시 검증 생략
+        let config = ZScoreConfig {
+            adaptive_params: false,
+            adaptive_threshold_scale_max: 0.9,
+            ..ZScoreConfig::default()
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_stats_mode_default_rolling() {
+        let config = ZScoreConfig::from_toml_str("[zscore]\ncoins = [\"BTC\"]\n").unwrap();
+        assert_eq!(config.stats_mode, "rolling");
+        assert_eq!(config.spread_stats_mode(), StatsMode::Rolling);
+    }
+
+    #[test]
+    fn test_stats_mode_ewma_from_toml() {
+        let toml_str = r#"
+[zscore]
+coins = ["BTC"]
+stats_mode = "ewma"
+ewma_half_life = 60.0
+ewma_min_samples = 30
+"#;
+        let config = ZScoreConfig::from_toml_str(toml_str).unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(
+            config.spread_stats_mode(),
+            StatsMode::Ewma {
+                half_life: 60.0,
+                min_samples: 30,
+            }
+        );
+    }
+
+    #[test]
+    fn test_warmup_candle_count() {
+        // rolling: 윈도우 전체
+        let config = ZScoreConfig::default();
+        assert_eq!(config.warmup_candle_count(1440), 1440);
+
+        // ewma: half-life × 5, min_samples 이상, window_size 이하
+        let config = ZScoreConfig {
+            stats_mode: "ewma".to_string(),
+            ewma_half_life: 60.0,
+            ewma_min_samples: 30,
+            ..ZScoreConfig::default()
+        };
+        assert_eq!(config.warmup_candle_count(1440), 300);
+        assert_eq!(config.warmup_candle_count(200), 200);
+
+        let config = ZScoreConfig {
+            ewma_min_samples: 500,
+            ..config
+        };
+        assert_eq!(config.warmup_candle_count(1440), 500);
+    }
+
+    #[test]
+    fn test_stats_mode_invalid() {
+        let config = ZScoreConfig {
+            stats_mode: "median".to_string(),
+            ..ZScoreConfig::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_ewma_half_life_invalid() {
+        let config = ZScoreConfig {
+            stats_mode: "ewma".to_string(),
+            ewma_half_life: 0.0,
+            ..ZScoreConfig::default()
+        };
+        assert!(config.validate().is_err());
+
+        // rolling 모드에서는 EWMA 파라미터 검증 생략
+        let config = ZScoreConfig {
+            ewma_half_life: 0.0,
+            ..ZScoreConfig::default()
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_hedge_model_default_fixed() {
+        let config = ZScoreConfig::from_toml_str("[zscore]\ncoins = [\"BTC\"]\n").unwrap();
+        assert_eq!(config.hedge_model, "fixed");
+        assert!(config.kalman_params().is_none());
+    }
+
+    #[test]
+    fn test_hedge_model_kalman_from_toml() {
+        let toml_str = r#"
+[zscore]
+coins = ["BTC"]
+hedge_model = "kalman"
+kalman_delta = 0.0001
+kalman_obs_var = 0.000004
+"#;
+        let config = ZScoreConfig::from_toml_str(toml_str).unwrap();
+        assert!(config.validate().is_ok());
+        let params = config.kalman_params().unwrap();
+        assert_eq!(params.delta, 0.0001);
+        assert_eq!(params.obs_var, 0.000004);
+        assert_eq!(config.kalman_hedge_ratio_min, 0.8);
+        assert_eq!(config.kalman_hedge_ratio_max, 1.2);
+    }
+
+    #[test]
+    fn test_hedge_model_invalid() {
+        let config = ZScoreConfig {
+            hedge_model: "ols".to_string(),
+            ..ZScoreConfig::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_kalman_delta_invalid() {
+        let config = ZScoreConfig {
+            hedge_model: "kalman".to_string(),
+            kalman_delta: 1.0,
+            ..ZScoreConfig::default()
+        };
+        assert!(config.validate().is_err());
+
+        let config = ZScoreConfig {
+            hedge_model: "kalman".to_string(),
+            kalman_hedge_ratio_max: 0.9,
+            ..ZScoreConfig::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
     // --- OutputConfig 테스트 ---
 
     #[test]
@@ -1319,6 +2671,11 @@ coins = ["BTC"]
 coins = ["BTC"]
 bybit_category = "inverse"
 order_timeout_sec = 10
+clock_sync_interval_sec = 30
+clock_skew_alert_ms = 500
+order_watchdog_interval_sec = 15
+order_watchdog_heartbeat_timeout_sec = 300
+bybit_dcp_window_sec = 0
 max_retry_count = 3
 order_type = "market"
 max_slippage_pct = 0.2
@@ -1355,6 +2712,11 @@ telegram_enabled = false
         let config = ZScoreConfig::from_toml_str(toml).unwrap();
         assert_eq!(config.bybit_category, "inverse");
         assert_eq!(config.order_timeout_sec, 10);
+        assert_eq!(config.clock_sync_interval_sec, 30);
+        assert_eq!(config.clock_skew_alert_ms, 500);
+        assert_eq!(config.order_watchdog_interval_sec, 15);
+        assert_eq!(config.order_watchdog_heartbeat_timeout_sec, 300);
+        assert_eq!(config.bybit_dcp_window_sec, 0);
         assert_eq!(config.max_retry_count, 3);
         assert_eq!(config.order_type, "market");
         assert_eq!(config.max_slippage_pct, 0.2);
@@ -1399,6 +2761,11 @@ coins = ["BTC"]
         let config = ZScoreConfig::from_toml_str(toml).unwrap();
         assert_eq!(config.bybit_category, "linear");
         assert_eq!(config.order_timeout_sec, 5);
+        assert_eq!(config.clock_sync_interval_sec, 60);
+        assert_eq!(config.clock_skew_alert_ms, 1000);
+        assert_eq!(config.order_watchdog_interval_sec, 30);
+        assert_eq!(config.order_watchdog_heartbeat_timeout_sec, 180);
+        assert_eq!(config.bybit_dcp_window_sec, 10);
         assert_eq!(config.max_retry_count, 2);
         assert_eq!(config.order_type, "limit_ioc");
         assert!(config.kill_switch_enabled);
@@ -1417,6 +2784,64 @@ coins = ["BTC"]
         assert!(err.to_string().contains("order_type"));
     }
 
+    #[test]
+    fn test_validate_invalid_clock_sync_fields() {
+        let config = ZScoreConfig {
+            clock_sync_interval_sec: 0,
+            ..ZScoreConfig::default()
+        };
+        let err = config.validate().unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("clock_sync_interval_sec must be greater than 0")
+        );
+
+        let config = ZScoreConfig {
+            clock_skew_alert_ms: 0,
+            ..ZScoreConfig::default()
+        };
+        let err = config.validate().unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("clock_skew_alert_ms must be greater than 0")
+        );
+    }
+
+    #[test]
+    fn test_validate_invalid_order_watchdog_fields() {
+        let config = ZScoreConfig {
+            order_watchdog_interval_sec: 0,
+            ..ZScoreConfig::default()
+        };
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("order_watchdog_interval_sec"));
+
+        let config = ZScoreConfig {
+            order_watchdog_heartbeat_timeout_sec: 60,
+            ..ZScoreConfig::default()
+        };
+        let err = config.validate().unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("order_watchdog_heartbeat_timeout_sec must be greater than 60")
+        );
+
+        for window in [1, 2, 301] {
+            let config = ZScoreConfig {
+                bybit_dcp_window_sec: window,
+                ..ZScoreConfig::default()
+            };
+            let err = config.validate().unwrap_err();
+            assert!(err.to_string().contains("bybit_dcp_window_sec"));
+        }
+
+        let config = ZScoreConfig {
+            bybit_dcp_window_sec: 0,
+            ..ZScoreConfig::default()
+        };
+        assert!(config.validate().is_ok());
+    }
+
     #[test]
     fn test_validate_invalid_upbit_ioc_reject_block_count() {
         let config = ZScoreConfig {
@@ -1604,6 +3029,199 @@ coins = ["BTC"]
         assert!(config.validate().is_err());
     }
 
+    // --- AllocatorConfig 테스트 ---
+
+    #[test]
+    fn test_allocator_config_from_toml() {
+        let toml = r#"
+[zscore]
+coins = ["BTC", "DOGE"]
+
+[allocator]
+enabled = true
+batch_window_ms = 300
+max_cluster_ratio = 0.3
+
+[allocator.clusters]
+meme = ["doge", "SHIB"]
+"#;
+        let config = ZScoreConfig::from_toml_str(toml).unwrap();
+        assert!(config.allocator.enabled);
+        assert_eq!(config.allocator.batch_window_ms, 300);
+        assert_eq!(config.allocator.max_cluster_ratio, Decimal::new(3, 1));
+        assert_eq!(config.allocator.cluster_of("DOGE"), Some("meme"));
+        assert!(config.validate().is_ok());
+
+        // 직렬화 후 다시 읽어도 같은 설정
+        let restored = ZScoreConfig::from_toml_str(&config.to_toml_string().unwrap()).unwrap();
+        assert_eq!(restored.allocator, config.allocator);
+    }
+
+    // --- ScaleInConfig 테스트 ---
+
+    #[test]
+    fn test_scale_in_config_from_toml() {
+        let toml = r#"
+[zscore]
+entry_z_threshold = 2.0
+exit_z_threshold = 0.5
+
+[scale_in]
+enabled = true
+exit_mode = "per_tier"
+
+[[scale_in.tiers]]
+z_offset = 0.0
+size_ratio = 0.5
+
+[[scale_in.tiers]]
+z_offset = 0.75
+size_ratio = 0.5
+"#;
+        let config = ZScoreConfig::from_toml_str(toml).unwrap();
+        assert!(config.scale_in.enabled);
+        assert!(config.scale_in.is_per_tier_exit());
+        assert_eq!(config.scale_in.tiers.len(), 2);
+        assert_eq!(config.scale_in.tiers[1].z_offset, 0.75);
+        assert_eq!(config.scale_in.tiers[1].size_ratio, Decimal::new(5, 1));
+
+        // 직렬화 → 역직렬화 라운드트립
+        let restored = ZScoreConfig::from_toml_str(&config.to_toml_string().unwrap()).unwrap();
+        assert_eq!(restored.scale_in, config.scale_in);
+
+        // tiers 생략 시 기본 3단계
+        let config = ZScoreConfig::from_toml_str("[scale_in]\nenabled = true\n").unwrap();
+        assert_eq!(config.scale_in.tiers.len(), 3);
+        assert!(!config.scale_in.is_per_tier_exit());
+    }
+
+    #[test]
+    fn test_scale_in_tier_thresholds() {
+        let mut scale_in = ScaleInConfig {
+            enabled: true,
+            ..ScaleInConfig::default()
+        };
+
+        // 비어 있는 가장 낮은 단계부터 채움
+        assert_eq!(scale_in.next_tier(&[]), Some(0));
+        assert_eq!(scale_in.next_tier(&[0, 2]), Some(1));
+        assert_eq!(scale_in.next_tier(&[0, 1, 2]), None);
+
+        assert!((scale_in.entry_z_threshold(2.0, 2) - 3.0).abs() < 1e-9);
+        assert_eq!(
+            scale_in.tier_capital(Decimal::new(3000, 0), 1),
+            Decimal::new(9999, 1)
+        );
+
+        // blended: 모든 단계가 기본 청산 임계값
+        assert!(scale_in.tiers_to_exit(0.5, 0.8, &[0, 1, 2]).is_empty());
+        assert_eq!(scale_in.tiers_to_exit(0.5, 0.4, &[0, 1, 2]), vec![0, 1, 2]);
+
+        // per_tier: 높은 단계가 먼저 청산
+        scale_in.exit_mode = "per_tier".to_string();
+        assert_eq!(scale_in.tiers_to_exit(0.5, 0.8, &[0, 1, 2]), vec![1, 2]);
+        assert_eq!(scale_in.tiers_to_exit(0.5, 1.2, &[0, 1, 2]), vec![2]);
+    }
+
+    #[test]
+    fn test_scale_in_config_validation() {
+        let mut config = ZScoreConfig::default();
+        config.scale_in.enabled = true;
+        assert!(config.validate().is_ok());
+
+        config.scale_in.exit_mode = "fifo".to_string();
+        assert!(config.validate().is_err());
+        config.scale_in.exit_mode = "blended".to_string();
+
+        config.scale_in.tiers[0].z_offset = 0.2;
+        assert!(config.validate().is_err());
+        config.scale_in.tiers[0].z_offset = 0.0;
+
+        config.scale_in.tiers[2].z_offset = 0.5;
+        assert!(config.validate().is_err());
+        config.scale_in.tiers[2].z_offset = 1.0;
+
+        config.scale_in.tiers[0].size_ratio = Decimal::new(5, 1);
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("size_ratio sum"), "{err}");
+
+        config.scale_in.tiers.clear();
+        assert!(config.validate().is_err());
+
+        // 비활성화 시 단계 검증 생략
+        config.scale_in.enabled = false;
+        assert!(config.validate().is_ok());
+    }
+
+    // --- StopLossConfig 테스트 ---
+
+    #[test]
+    fn test_stop_loss_config_from_toml() {
+        let toml = r#"
+[stop_loss]
+enabled = true
+spread_stop_pct = 1.5
+z_stop = 4.0
+max_loss_pct = 2.0
+trailing_activation_pct = 0.4
+trailing_distance_pct = 0.15
+"#;
+        let config = ZScoreConfig::from_toml_str(toml).unwrap();
+        assert!(config.stop_loss.enabled);
+        assert_eq!(config.stop_loss.spread_stop_pct, 1.5);
+        assert_eq!(config.stop_loss.z_stop, 4.0);
+        assert_eq!(config.stop_loss.trailing_distance_pct, 0.15);
+        assert!(config.validate().is_ok());
+
+        let restored = ZScoreConfig::from_toml_str(&config.to_toml_string().unwrap()).unwrap();
+        assert_eq!(restored.stop_loss, config.stop_loss);
+    }
+
+    #[test]
+    fn test_stop_loss_config_validation() {
+        let mut config = ZScoreConfig::default();
+        config.stop_loss.enabled = true;
+        assert!(config.validate().is_ok());
+
+        config.stop_loss.max_loss_pct = -1.0;
+        assert!(config.validate().is_err());
+        config.stop_loss.max_loss_pct = 0.0;
+
+        // 트레일링 스탑은 활성화 기준과 폭을 함께 설정
+        config.stop_loss.trailing_activation_pct = 0.3;
+        assert!(config.validate().is_err());
+        config.stop_loss.trailing_distance_pct = 0.1;
+        assert!(config.validate().is_ok());
+
+        // z_stop은 진입 임계값(분할 진입 시 마지막 단계)보다 커야 함
+        config.stop_loss.z_stop = 2.0;
+        assert!(config.validate().is_err());
+        config.stop_loss.z_stop = 2.8;
+        assert!(config.validate().is_ok());
+        config.scale_in.enabled = true;
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("z_stop"), "{err}");
+    }
+
+    #[test]
+    fn test_allocator_config_validation() {
+        let mut config = ZScoreConfig::default();
+        assert!(!config.allocator.enabled);
+        config.allocator.max_cluster_ratio = Decimal::ZERO;
+        assert!(config.validate().is_err());
+
+        let mut config = ZScoreConfig::default();
+        config
+            .allocator
+            .clusters
+            .insert("a".to_string(), vec!["DOGE".to_string()]);
+        config
+            .allocator
+            .clusters
+            .insert("b".to_string(), vec!["DOGE".to_string()]);
+        assert!(config.validate().is_err());
+    }
+
     // --- BalanceSnapshotConfig 테스트 ---
 
     #[test]
@@ -1656,4 +3274,125 @@ coins = ["BTC"]
         let config = ZScoreConfig::default();
         assert_eq!(config.balance_snapshot.interval_sec, 600);
     }
+
+    #[test]
+    fn test_candle_interval_default_1m() {
+        let config = ZScoreConfig::from_toml_str("[zscore]\ncoins = [\"BTC\"]\n").unwrap();
+        assert_eq!(config.candle_interval, CandleInterval::Minute1);
+        assert!(!config.trend_filter_enabled);
+        assert!(config.trend_params().is_none());
+    }
+
+    #[test]
+    fn test_candle_interval_from_toml() {
+        let toml = r#"
+[zscore]
+coins = ["BTC"]
+candle_interval = "5m"
+trend_filter_enabled = true
+trend_filter_interval = "1h"
+trend_filter_window = 48
+"#;
+        let config = ZScoreConfig::from_toml_str(toml).unwrap();
+        assert_eq!(config.candle_interval, CandleInterval::Minute5);
+        assert!(config.validate().is_ok());
+        let params = config.trend_params().unwrap();
+        assert_eq!(params.interval, CandleInterval::Minute60);
+        assert_eq!(params.window, 48);
+        assert_eq!(params.max_t_stat, 3.0);
+    }
+
+    #[test]
+    fn test_candle_interval_invalid_string() {
+        let toml = r#"
+[zscore]
+coins = ["BTC"]
+candle_interval = "10m"
+"#;
+        let err = ZScoreConfig::from_toml_str(toml).unwrap_err();
+        assert!(err.to_string().contains("candle_interval"));
+    }
+
+    #[test]
+    fn test_candle_interval_unsupported_rejected() {
+        let config = ZScoreConfig {
+            candle_interval: CandleInterval::Minute3,
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_trend_filter_interval_must_exceed_candle_interval() {
+        let config = ZScoreConfig {
+            candle_interval: CandleInterval::Minute15,
+            trend_filter_enabled: true,
+            trend_filter_interval: CandleInterval::Minute15,
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+
+        let config = ZScoreConfig {
+            trend_filter_enabled: true,
+            trend_filter_window: 5,
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_toml_round_trip() {
+        let mut coin_overrides = HashMap::new();
+        coin_overrides.insert(
+            "ETH".to_string(),
+            CoinOverride {
+                window_size: Some(720),
+                entry_z_threshold: Some(2.5),
+                ..Default::default()
+            },
+        );
+        let configs = [
+            ZScoreConfig::default(),
+            ZScoreConfig {
+                coins: vec!["BTC".to_string(), "ETH".to_string()],
+                candle_interval: CandleInterval::Minute5,
+                total_capital_usdt: Decimal::new(12_345, 1),
+                upbit_taker_fee: Decimal::new(5, 4),
+                max_daily_loss_usdt: 150.0,
+                trend_filter_enabled: true,
+                trend_filter_interval: CandleInterval::Minute60,
+                coin_overrides,
+                output: crate::output::writer::OutputConfig {
+                    enabled: false,
+                    dir: "out".to_string(),
+                },
+                ..Default::default()
+            },
+        ];
+        for config in configs {
+            let toml_str = config.to_toml_string().unwrap();
+            let parsed = ZScoreConfig::from_toml_str(&toml_str).unwrap();
+            assert_eq!(parsed, config, "round trip mismatch:\n{toml_str}");
+        }
+    }
+
+    #[test]
+    fn test_snapshot_hash_is_stable() {
+        let config = ZScoreConfig::default();
+        let a = config.snapshot().unwrap();
+        let b = config.clone().snapshot().unwrap();
+        assert_eq!(a, b);
+        assert_eq!(a.hash.len(), 64);
+
+        let json: serde_json::Value = serde_json::from_str(&a.json).unwrap();
+        assert_eq!(json["zscore"]["window_size"], 1440);
+        assert!(json["output"].is_object());
+        assert!(json["balance_snapshot"].is_object());
+
+        let changed = ZScoreConfig {
+            entry_z_threshold: config.entry_z_threshold + 0.5,
+            ..config
+        };
+        assert_ne!(changed.snapshot().unwrap().hash, a.hash);
+    }
 }
@@ -0,0 +1,137 @@
+//! 설정 스냅샷 비교.
+//!
+//! `ZScoreConfig::snapshot()`이 만든 JSON(또는 그 `serde_json::Value`)을 필드 단위로 비교합니다.
+//! 핫 리로드의 재시작 필요 필드 판정과 세션 간 설정 비교 도구(`examples/config_diff.rs`)에서 사용합니다.
+
+use std::fmt;
+
+use serde_json::Value;
+
+use crate::error::StrategyError;
+use crate::zscore::config::ZScoreConfig;
+
+/// 달라진 필드 한 건.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigDiff {
+    /// 점(`.`)으로 연결한 필드 경로 (e.g., "zscore.coin_overrides.ETH.window_size").
+    pub path: String,
+    /// 왼쪽 값 (없으면 `None`).
+    pub left: Option<Value>,
+    /// 오른쪽 값 (없으면 `None`).
+    pub right: Option<Value>,
+}
+
+impl fmt::Display for ConfigDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let show = |v: &Option<Value>| {
+            v.as_ref()
+                .map_or_else(|| "<none>".to_string(), Value::to_string)
+        };
+        write!(
+            f,
+            "{}: {} -> {}",
+            self.path,
+            show(&self.left),
+            show(&self.right)
+        )
+    }
+}
+
+/// 두 JSON 값을 재귀적으로 비교해 달라진 leaf 필드를 경로 순으로 반환합니다.
+///
+/// 객체는 키 단위로 내려가고, 배열과 스칼라는 값 전체를 한 필드로 비교합니다.
+pub fn diff_config_json(left: &Value, right: &Value) -> Vec<ConfigDiff> {
+    let mut diffs = Vec::new();
+    diff_into(&mut diffs, String::new(), Some(left), Some(right));
+    diffs.sort_by(|a, b| a.path.cmp(&b.path));
+    diffs
+}
+
+/// 두 설정을 직렬화해 비교합니다.
+pub fn diff_configs(
+    left: &ZScoreConfig,
+    right: &ZScoreConfig,
+) -> Result<Vec<ConfigDiff>, StrategyError> {
+    let to_value = |c: &ZScoreConfig| {
+        serde_json::to_value(c)
+            .map_err(|e| StrategyError::Config(format!("JSON serialize error: {e}")))
+    };
+    Ok(diff_config_json(&to_value(left)?, &to_value(right)?))
+}
+
+fn diff_into(out: &mut Vec<ConfigDiff>, path: String, left: Option<&Value>, right: Option<&Value>) {
+    match (left, right) {
+        (Some(Value::Object(l)), Some(Value::Object(r))) => {
+            for (key, lv) in l {
+                diff_into(out, join(&path, key), Some(lv), r.get(key));
+            }
+            for (key, rv) in r {
+                if !l.contains_key(key) {
+                    diff_into(out, join(&path, key), None, Some(rv));
+                }
+            }
+        }
+        (l, r) if l == r => {}
+        (l, r) => out.push(ConfigDiff {
+            path,
+            left: l.cloned(),
+            right: r.cloned(),
+        }),
+    }
+}
+
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+
+    #[test]
+    fn test_diff_config_json_nested() {
+        let left = json!({
+            "zscore": {"window_size": 1440, "coins": ["BTC"], "coin_overrides": {}},
+            "output": {"enabled": true},
+        });
+        let right = json!({
+            "zscore": {
+                "window_size": 720,
+                "coins": ["BTC", "ETH"],
+                "coin_overrides": {"ETH": {"window_size": 360}},
+            },
+            "output": {"enabled": true},
+        });
+        let diffs = diff_config_json(&left, &right);
+        let paths: Vec<&str> = diffs.iter().map(|d| d.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "zscore.coin_overrides.ETH",
+                "zscore.coins",
+                "zscore.window_size"
+            ]
+        );
+        assert_eq!(diffs[0].left, None);
+        assert_eq!(diffs[2].to_string(), "zscore.window_size: 1440 -> 720");
+        assert!(diff_config_json(&left, &left).is_empty());
+    }
+
+    #[test]
+    fn test_diff_configs() {
+        let left = ZScoreConfig::default();
+        let right = ZScoreConfig {
+            leverage: left.leverage + 1,
+            ..left.clone()
+        };
+        let diffs = diff_configs(&left, &right).unwrap();
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].path, "zscore.leverage");
+    }
+}
@@ -17,7 +17,7 @@ use crate::output::writer::{MinuteRecord, SessionWriter};
 use crate::zscore::config::ZScoreConfig;
 use crate::zscore::instrument::InstrumentInfo;
 use crate::zscore::pnl::ClosedPosition;
-use crate::zscore::position::PositionManager;
+use crate::zscore::position::{PositionManager, VirtualPosition};
 
 /// 진입 시그널 컨텍스트 (owned 스냅샷, Send + 'static).
 ///
@@ -41,8 +41,13 @@ pub struct EntryContext {
     pub upbit_entry_usd: Decimal,
     /// Bybit 진입가 USDT (라운딩 후, floor).
     pub bybit_entry: Decimal,
-    /// 진입 수량 (코인 단위, qty_step 라운딩 완료).
+    /// 진입 수량 (코인 단위, qty_step 라운딩 완료, Upbit 레그 기준).
     pub qty: Decimal,
+    /// Bybit short 수량 (헤지 비율 적용, qty_step 라운딩 완료).
+    /// 고정 헤지 모델에서는 `qty`와 동일합니다.
+    pub bybit_qty: Decimal,
+    /// 진입 시 헤지 비율 (고정 모델은 1.0).
+    pub hedge_ratio: f64,
     /// USD/KRW 환율.
     pub usd_krw: f64,
     /// Rolling mean.
@@ -55,6 +60,8 @@ pub struct EntryContext {
     pub safe_volume_usdt: f64,
     /// 오더북 볼륨 비율.
     pub volume_ratio: f64,
+    /// 분할 진입 단계 (분할 진입 비활성화 시 0).
+    pub tier: u8,
 }
 
 /// 청산 시그널 컨텍스트 (owned 스냅샷, Send + 'static).
@@ -73,6 +80,8 @@ pub struct ExitContext {
     /// USD/KRW 환율.
     pub usd_krw: f64,
     /// 청산 안전 볼륨 (USDT, None이면 오더북 미확보).
+    ///
+    /// 시그널 청산은 수익성이 양수인 구간, 손절 청산은 수익성과 무관한 호가 깊이입니다.
     pub exit_safe_volume_usdt: Option<f64>,
     /// Rolling mean.
     pub mean: f64,
@@ -80,6 +89,21 @@ pub struct ExitContext {
     pub instrument_info: Option<InstrumentInfo>,
     /// Bybit 현재가 (USDT, 부분 청산 qty 변환용).
     pub bybit_price: Decimal,
+    /// 청산 대상 분할 진입 단계 (None이면 코인의 모든 포지션).
+    pub tiers: Option<Vec<u8>>,
+    /// 청산 대상 포지션 ID (손절 규칙용, None이면 ID로 거르지 않음).
+    pub position_ids: Option<Vec<u64>>,
+}
+
+impl ExitContext {
+    /// 포지션이 이번 청산 대상인지 확인합니다 (단계, ID 조건 모두 만족).
+    pub fn targets(&self, pos: &VirtualPosition) -> bool {
+        self.tiers.as_ref().is_none_or(|t| t.contains(&pos.tier))
+            && self
+                .position_ids
+                .as_ref()
+                .is_none_or(|ids| ids.contains(&pos.id))
+    }
 }
 
 /// TTL 만료 청산 컨텍스트 (owned 스냅샷, Send + 'static).
@@ -112,8 +136,10 @@ pub struct TtlPosition {
     pub id: u64,
     /// 포지션 크기 (USDT).
     pub size_usdt: Decimal,
-    /// 포지션 수량 (코인 단위).
+    /// 포지션 수량 (코인 단위, Upbit 레그 기준).
     pub qty: Decimal,
+    /// Bybit 레그 수량 (헤지 비율 적용).
+    pub bybit_qty: Decimal,
 }
 
 /// run() 내부에서 생성되는 공유 리소스.
@@ -150,36 +176,34 @@ pub struct SharedResources {
 /// - `on_exit_signal`: pm.transition_to_closing → LiveExecutor.execute_exit
 /// - `on_ttl_expiry`: 실제 TTL 청산 (LiveExecutor 사용)
 /// - `is_entry_allowed`: RiskManager.is_entry_allowed() + 잔고 + reconciliation 상태
-pub trait ExecutionPolicy: Send + Sync + 'static {
+///
+/// ## 컨텍스트 타입 파라미터
+///
+/// `E`/`X`/`T`는 진입/청산/TTL 컨텍스트 타입이며 기본값은 Z-Score 컨텍스트입니다.
+/// 청산 레그가 없는 전략(`krw_spread`)은 `X`/`T`에 `Infallible`을 지정합니다.
+pub trait ExecutionPolicy<E = EntryContext, X = ExitContext, T = TtlExpiryContext>:
+    Send + Sync + 'static
+{
     /// 진입 시그널 처리.
     ///
     /// 9단계 검증 통과 후 호출됩니다.
     /// 시뮬: VirtualPosition 즉시 생성.
     /// 라이브: BalanceTracker.reserve → LiveExecutor.execute_entry → commit/release.
-    fn on_entry_signal(
-        &self,
-        ctx: EntryContext,
-    ) -> impl Future<Output = Result<(), StrategyError>> + Send;
+    fn on_entry_signal(&self, ctx: E) -> impl Future<Output = Result<(), StrategyError>> + Send;
 
     /// 청산 시그널 처리.
     ///
     /// Z-Score 청산 조건 충족 시 호출됩니다.
     /// 시뮬: PositionManager.close_position() 가상 체결.
     /// 라이브: LiveExecutor.execute_exit() 실주문.
-    fn on_exit_signal(
-        &self,
-        ctx: ExitContext,
-    ) -> impl Future<Output = Result<(), StrategyError>> + Send;
+    fn on_exit_signal(&self, ctx: X) -> impl Future<Output = Result<(), StrategyError>> + Send;
 
     /// TTL 만료 포지션 청산.
     ///
     /// minute_timer에서 만료된 포지션 감지 시 호출됩니다.
     /// 시뮬: 가상 TTL 청산.
     /// 라이브: LiveExecutor.execute_exit() + tokio::spawn으로 분리.
-    fn on_ttl_expiry(
-        &self,
-        ctx: TtlExpiryContext,
-    ) -> impl Future<Output = Result<(), StrategyError>> + Send;
+    fn on_ttl_expiry(&self, ctx: T) -> impl Future<Output = Result<(), StrategyError>> + Send;
 
     /// 진입 가능 여부 확인 (lock-free, 빠른 체크).
     ///
@@ -260,6 +284,8 @@ mod tests {
             upbit_entry_usd: Decimal::new(100_000, 0),
             bybit_entry: Decimal::new(100_050, 0),
             qty: Decimal::new(10, 3),
+            bybit_qty: Decimal::new(10, 3),
+            hedge_ratio: 1.0,
             usd_krw: 1380.0,
             mean: 0.1,
             stddev: 0.05,
@@ -269,9 +295,11 @@ mod tests {
                 min_order_qty: Decimal::new(1, 3),
                 min_notional: Decimal::new(5, 0),
                 max_order_qty: Decimal::new(100, 0),
+                contract_multiplier: Decimal::ONE,
             },
             safe_volume_usdt: 1000.0,
             volume_ratio: 0.7,
+            tier: 0,
         };
         let cloned = ctx.clone();
         assert_eq!(cloned.coin, "BTC");
@@ -291,6 +319,8 @@ mod tests {
             mean: 0.1,
             instrument_info: None,
             bybit_price: Decimal::new(3005, 0),
+            tiers: None,
+            position_ids: None,
         };
         let cloned = ctx.clone();
         assert_eq!(cloned.coin, "ETH");
@@ -304,6 +334,7 @@ mod tests {
                 id: 1,
                 size_usdt: Decimal::new(100, 0),
                 qty: Decimal::new(500, 0),
+                bybit_qty: Decimal::new(500, 0),
             }],
             usd_krw: 1380.0,
             current_spread_pct: 0.15,
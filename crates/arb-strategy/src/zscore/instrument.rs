@@ -182,6 +182,8 @@ pub fn round_price_conservative(price: Decimal, tick_size: Decimal, is_buy: bool
 ///
 /// `floor_to_step`의 의미적 별칭(semantic alias)입니다.
 /// 진입/청산 수량 라운딩 시 가독성을 위해 사용합니다.
+/// 계약 단위 거래소는 [`InstrumentInfo::qty_step`]이 이미 계약 승수를 반영한
+/// 코인 단위이므로, 결과는 정수 계약 수에 해당하는 코인 수량입니다.
 ///
 /// # 인자
 ///
@@ -203,7 +205,11 @@ pub fn round_qty_floor(qty: Decimal, qty_step: Decimal) -> Decimal {
 ///
 /// Bybit REST API에서 조회한 tick_size, qty_step 등을 보관합니다.
 /// Upbit은 하드코딩 호가 테이블을 사용하므로 이 구조체에 포함하지 않습니다.
-#[derive(Debug, Clone, Default)]
+///
+/// 수량 필드는 모두 코인 단위입니다. 계약 수로 주문하는 거래소(OKX 스왑)는
+/// [`From<InstrumentInfoResponse>`] 변환 시 계약 승수를 곱해 코인 단위로 맞추므로,
+/// `qty_step`으로 내림한 수량은 항상 정수 계약 수가 됩니다.
+#[derive(Debug, Clone)]
 pub struct InstrumentInfo {
     /// 가격 최소 단위 (Bybit priceFilter.tickSize).
     pub tick_size: Decimal,
@@ -215,6 +221,21 @@ pub struct InstrumentInfo {
     pub min_notional: Decimal,
     /// 최대 주문 수량 (Bybit lotSizeFilter.maxOrderQty).
     pub max_order_qty: Decimal,
+    /// 계약 1개당 코인 수량 (OKX ctVal). 코인 단위로 주문하는 거래소는 1.
+    pub contract_multiplier: Decimal,
+}
+
+impl Default for InstrumentInfo {
+    fn default() -> Self {
+        Self {
+            tick_size: Decimal::ZERO,
+            qty_step: Decimal::ZERO,
+            min_order_qty: Decimal::ZERO,
+            min_notional: Decimal::ZERO,
+            max_order_qty: Decimal::ZERO,
+            contract_multiplier: Decimal::ONE,
+        }
+    }
 }
 
 /// 코인별 InstrumentInfo 캐시.
@@ -262,13 +283,20 @@ impl Default for InstrumentCache {
 // ---------------------------------------------------------------------------
 
 impl From<InstrumentInfoResponse> for InstrumentInfo {
+    /// 거래소 주문 단위의 수량 규격을 코인 단위로 변환합니다.
     fn from(resp: InstrumentInfoResponse) -> Self {
+        let multiplier = if resp.contract_multiplier > Decimal::ZERO {
+            resp.contract_multiplier
+        } else {
+            Decimal::ONE
+        };
         Self {
             tick_size: resp.tick_size,
-            qty_step: resp.qty_step,
-            min_order_qty: resp.min_order_qty,
+            qty_step: resp.qty_step * multiplier,
+            min_order_qty: resp.min_order_qty * multiplier,
             min_notional: resp.min_notional,
-            max_order_qty: resp.max_order_qty,
+            max_order_qty: resp.max_order_qty * multiplier,
+            contract_multiplier: multiplier,
         }
     }
 }
@@ -304,6 +332,7 @@ pub async fn fetch_instruments(
                     qty_step = %inst.qty_step,
                     min_order_qty = %inst.min_order_qty,
                     min_notional = %inst.min_notional,
+                    contract_multiplier = %inst.contract_multiplier,
                     "instrument info 로드 완료"
                 );
                 infos.push((coin.clone(), inst));
@@ -714,6 +743,7 @@ mod tests {
             min_order_qty: Decimal::new(1, 3), // 0.001
             min_notional: Decimal::new(5, 0),  // 5
             max_order_qty: Decimal::new(1000, 0),
+            contract_multiplier: Decimal::ONE,
         };
 
         cache.insert("BTC".to_string(), info.clone());
@@ -742,6 +772,7 @@ mod tests {
             min_order_qty: Decimal::new(1, 3),
             min_notional: Decimal::new(5, 0),
             max_order_qty: Decimal::new(1000, 0),
+            contract_multiplier: Decimal::ONE,
         };
         let info2 = InstrumentInfo {
             tick_size: Decimal::new(1, 1), // 변경: 0.1
@@ -749,6 +780,7 @@ mod tests {
             min_order_qty: Decimal::new(1, 2),
             min_notional: Decimal::new(10, 0),
             max_order_qty: Decimal::new(500, 0),
+            contract_multiplier: Decimal::ONE,
         };
 
         cache.insert("BTC".to_string(), info1);
@@ -768,6 +800,7 @@ mod tests {
             min_order_qty: Decimal::new(1, 5),
             min_notional: Decimal::new(5, 0),
             max_order_qty: Decimal::new(100, 0),
+            contract_multiplier: Decimal::ONE,
         };
         let eth_info = InstrumentInfo {
             tick_size: Decimal::new(1, 1),
@@ -775,6 +808,7 @@ mod tests {
             min_order_qty: Decimal::new(1, 3),
             min_notional: Decimal::new(1, 0),
             max_order_qty: Decimal::new(10000, 0),
+            contract_multiplier: Decimal::ONE,
         };
 
         cache.insert("BTC".to_string(), btc_info);
@@ -807,6 +841,7 @@ mod tests {
             min_order_qty: Decimal::new(1, 3), // 0.001
             max_order_qty: Decimal::new(1000, 0),
             min_notional: Decimal::new(5, 0),
+            contract_multiplier: Decimal::ONE,
         };
 
         let info: InstrumentInfo = resp.into();
@@ -826,10 +861,41 @@ mod tests {
             min_order_qty: Decimal::new(1, 5), // 0.00001
             max_order_qty: Decimal::new(50000, 0),
             min_notional: Decimal::new(1, 0),
+            contract_multiplier: Decimal::ONE,
         };
 
         let info = InstrumentInfo::from(resp);
         assert_eq!(info.tick_size, Decimal::new(5, 1));
         assert_eq!(info.qty_step, Decimal::new(1, 5));
     }
+
+    #[test]
+    fn test_from_instrument_info_response_contract_multiplier() {
+        // OKX BTC-USDT-SWAP: ctVal 0.01 BTC, lotSz 0.01 계약, minSz 0.01 계약
+        let resp = InstrumentInfoResponse {
+            tick_size: Decimal::new(1, 1),
+            qty_step: Decimal::new(1, 2),
+            min_order_qty: Decimal::new(1, 2),
+            max_order_qty: Decimal::new(10000, 0),
+            min_notional: Decimal::ZERO,
+            contract_multiplier: Decimal::new(1, 2),
+        };
+
+        let info = InstrumentInfo::from(resp);
+        assert_eq!(info.qty_step, Decimal::new(1, 4)); // 0.0001 BTC
+        assert_eq!(info.min_order_qty, Decimal::new(1, 4));
+        assert_eq!(info.max_order_qty, Decimal::new(100, 0));
+        assert_eq!(info.contract_multiplier, Decimal::new(1, 2));
+
+        // 코인 단위 라운딩 결과는 정수 배의 lotSz 계약
+        let qty = round_qty_floor(Decimal::new(123456, 6), info.qty_step); // 0.123456 BTC
+        assert_eq!(qty, Decimal::new(1234, 4));
+        assert_eq!(qty / info.contract_multiplier, Decimal::new(1234, 2)); // 12.34 계약
+    }
+
+    #[test]
+    fn test_instrument_info_default_multiplier_is_one() {
+        let info = InstrumentInfo::default();
+        assert_eq!(info.contract_multiplier, Decimal::ONE);
+    }
 }
@@ -0,0 +1,312 @@
+//! Kalman filter 기반 동적 헤지 비율 추정.
+//!
+//! 고정 1:1 스프레드(`spread_pct`) 대신 양 레그 가격 관계를 시변 회귀로 추정합니다.
+//!
+//! 관측 모델: `upbit_usd_t = alpha_t + beta_t × bybit_t + e_t`
+//!
+//! 상태 `[beta, alpha]`는 random walk로 가정하며, `beta`가 Upbit 1개당
+//! Bybit short 수량(헤지 비율)이 됩니다. 가격은 첫 관측의 Bybit 가격으로
+//! 정규화하여 `delta`/`obs_var`가 코인 가격 수준과 무관하게 동작하도록 합니다.
+
+use rust_decimal::Decimal;
+
+use crate::common::candle_window::CandleWindow;
+use crate::zscore::instrument::{InstrumentInfo, floor_to_step};
+
+/// 초기 상태 공분산 (정규화 가격 기준).
+const INITIAL_STATE_VAR: f64 = 1e-2;
+
+/// Kalman filter 파라미터.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KalmanParams {
+    /// 상태 변화 속도 (0 < delta < 1). 클수록 헤지 비율이 빠르게 변합니다.
+    pub delta: f64,
+    /// 관측 노이즈 분산 (정규화 가격 기준).
+    pub obs_var: f64,
+}
+
+/// 코인별 동적 헤지 비율 추정기.
+#[derive(Debug, Clone)]
+pub struct KalmanHedge {
+    params: KalmanParams,
+    /// 가격 정규화 기준 (첫 Bybit 관측가, 0이면 미초기화).
+    scale: f64,
+    /// 상태 벡터 `[beta, alpha]`.
+    state: [f64; 2],
+    /// 상태 공분산.
+    cov: [[f64; 2]; 2],
+    /// 반영된 관측 수.
+    count: usize,
+}
+
+impl KalmanHedge {
+    /// 초기 상태(beta=1, alpha=0)로 추정기를 생성합니다.
+    pub fn new(params: KalmanParams) -> Self {
+        Self {
+            params,
+            scale: 0.0,
+            state: [1.0, 0.0],
+            cov: [[INITIAL_STATE_VAR, 0.0], [0.0, INITIAL_STATE_VAR]],
+            count: 0,
+        }
+    }
+
+    /// Upbit(USD 환산)/Bybit 가격 윈도우를 순서대로 반영한 추정기를 생성합니다.
+    ///
+    /// 두 윈도우는 같은 분봉에서 함께 push되므로 인덱스가 정렬되어 있습니다.
+    pub fn from_windows(params: KalmanParams, upbit: &CandleWindow, bybit: &CandleWindow) -> Self {
+        let mut kf = Self::new(params);
+        for (&u, &b) in upbit.data().iter().zip(bybit.data().iter()) {
+            kf.update(u, b);
+        }
+        kf
+    }
+
+    /// 상태 예측 공분산 `P + Q`.
+    fn predicted_cov(&self) -> [[f64; 2]; 2] {
+        let q = self.params.delta / (1.0 - self.params.delta);
+        let p = self.cov;
+        [[p[0][0] + q, p[0][1]], [p[1][0], p[1][1] + q]]
+    }
+
+    /// 예측 공분산 `r`에서 관측 벡터 `[x, 1]`의 innovation 분산을 계산합니다.
+    fn innovation_var(&self, r: &[[f64; 2]; 2], x: f64) -> f64 {
+        x * x * r[0][0] + x * (r[0][1] + r[1][0]) + r[1][1] + self.params.obs_var
+    }
+
+    /// 분봉 관측값 1개를 반영합니다 (O(1)).
+    ///
+    /// 0 이하 또는 비유한 가격은 무시합니다.
+    pub fn update(&mut self, upbit_usd: f64, bybit: f64) {
+        if !(upbit_usd > 0.0 && bybit > 0.0 && upbit_usd.is_finite() && bybit.is_finite()) {
+            return;
+        }
+        if self.scale == 0.0 {
+            self.scale = bybit;
+        }
+        let x = bybit / self.scale;
+        let y = upbit_usd / self.scale;
+
+        let r = self.predicted_cov();
+        let s = self.innovation_var(&r, x);
+        let e = y - (self.state[0] * x + self.state[1]);
+
+        // K = R·h / S, h = [x, 1]
+        let rh = [r[0][0] * x + r[0][1], r[1][0] * x + r[1][1]];
+        let k = [rh[0] / s, rh[1] / s];
+
+        self.state[0] += k[0] * e;
+        self.state[1] += k[1] * e;
+
+        // P = R - K·(R·h)ᵀ
+        self.cov = [
+            [r[0][0] - k[0] * rh[0], r[0][1] - k[0] * rh[1]],
+            [r[1][0] - k[1] * rh[0], r[1][1] - k[1] * rh[1]],
+        ];
+        self.count += 1;
+    }
+
+    /// 현재 헤지 비율 (Upbit 1개당 Bybit 수량).
+    pub fn hedge_ratio(&self) -> f64 {
+        self.state[0]
+    }
+
+    /// 현재 절편 (USD).
+    pub fn intercept(&self) -> f64 {
+        self.state[1] * self.scale
+    }
+
+    /// 반영된 관측 수.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// 현재 가격에서 잔차 기반 (fair spread, stddev)를 스프레드(%) 단위로 반환합니다.
+    ///
+    /// `fair spread`는 추정된 회귀식이 예측하는 Upbit 가격 기준 스프레드이고,
+    /// `stddev`는 innovation 표준편차를 Upbit 가격 대비 %로 환산한 값입니다.
+    /// 따라서 `(current_spread - fair) / stddev`가 잔차 z-score가 되어
+    /// 기존 `signal::evaluate_*_signal`에 (mean, stddev) 대신 그대로 전달할 수 있습니다.
+    ///
+    /// 관측이 없거나 가격이 유효하지 않으면 `None`.
+    pub fn tick_stats(&self, upbit_usd: f64, bybit: f64) -> Option<(f64, f64)> {
+        if self.count == 0 || upbit_usd <= 0.0 || bybit <= 0.0 {
+            return None;
+        }
+        let x = bybit / self.scale;
+        let fitted_upbit = (self.state[0] * x + self.state[1]) * self.scale;
+        if fitted_upbit <= 0.0 {
+            return None;
+        }
+        let r = self.predicted_cov();
+        let s = self.innovation_var(&r, x);
+        let fair_spread = (bybit - fitted_upbit) / fitted_upbit * 100.0;
+        let stddev = s.max(0.0).sqrt() * self.scale / upbit_usd * 100.0;
+        Some((fair_spread, stddev))
+    }
+
+    /// 현재 가격의 잔차 z-score (양수 = Bybit 고평가).
+    pub fn residual_z(&self, upbit_usd: f64, bybit: f64) -> Option<f64> {
+        let (fair, stddev) = self.tick_stats(upbit_usd, bybit)?;
+        if stddev <= 0.0 {
+            return None;
+        }
+        let spread = (bybit - upbit_usd) / upbit_usd * 100.0;
+        Some((spread - fair) / stddev)
+    }
+}
+
+/// 헤지 비율을 적용한 Bybit short 수량을 계산합니다.
+///
+/// 헤지 비율은 `[min_ratio, max_ratio]`로 제한하고 `qty_step`으로 내림합니다.
+/// 결과가 `min_order_qty` 미만이면 1:1 수량(`upbit_qty`)을 반환합니다.
+pub fn hedge_qty(
+    upbit_qty: Decimal,
+    hedge_ratio: f64,
+    min_ratio: f64,
+    max_ratio: f64,
+    info: &InstrumentInfo,
+) -> Decimal {
+    let ratio = hedge_ratio.clamp(min_ratio, max_ratio);
+    let Ok(ratio_dec) = Decimal::try_from(ratio) else {
+        return upbit_qty;
+    };
+    let raw = upbit_qty * ratio_dec;
+    let qty = floor_to_step(raw, info.qty_step);
+    if qty < info.min_order_qty {
+        return upbit_qty;
+    }
+    qty
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params() -> KalmanParams {
+        KalmanParams {
+            delta: 1e-5,
+            obs_var: 1e-6,
+        }
+    }
+
+    fn info() -> InstrumentInfo {
+        InstrumentInfo {
+            tick_size: Decimal::new(1, 2),
+            qty_step: Decimal::new(1, 3),
+            min_order_qty: Decimal::new(1, 3),
+            min_notional: Decimal::new(5, 0),
+            max_order_qty: Decimal::new(100, 0),
+            contract_multiplier: Decimal::ONE,
+        }
+    }
+
+    /// 결정적 의사 노이즈 (-1.0 ~ 1.0).
+    fn noise(i: usize) -> f64 {
+        ((i as f64 * 12.9898).sin() * 43758.5453).fract()
+    }
+
+    #[test]
+    fn test_kalman_converges_to_true_hedge_ratio() {
+        // upbit = 2.0 + 0.95 × bybit + noise
+        let mut kf = KalmanHedge::new(params());
+        for i in 0..3000 {
+            let bybit = 100.0 + 10.0 * (i as f64 / 80.0).sin();
+            let upbit = 2.0 + 0.95 * bybit + 0.05 * noise(i);
+            kf.update(upbit, bybit);
+        }
+        assert!(
+            (kf.hedge_ratio() - 0.95).abs() < 0.02,
+            "beta={}",
+            kf.hedge_ratio()
+        );
+        assert!(
+            (kf.intercept() - 2.0).abs() < 2.0,
+            "alpha={}",
+            kf.intercept()
+        );
+        assert_eq!(kf.count(), 3000);
+    }
+
+    #[test]
+    fn test_kalman_ignores_invalid_prices() {
+        let mut kf = KalmanHedge::new(params());
+        kf.update(0.0, 100.0);
+        kf.update(100.0, f64::NAN);
+        assert_eq!(kf.count(), 0);
+        assert!(kf.tick_stats(100.0, 100.0).is_none());
+    }
+
+    #[test]
+    fn test_residual_z_sign() {
+        let mut kf = KalmanHedge::new(params());
+        for i in 0..500 {
+            let bybit = 100.0 + (i as f64 / 30.0).sin();
+            kf.update(bybit * 0.99, bybit);
+        }
+        // 관계 유지 시 잔차 ≈ 0
+        let z_fair = kf.residual_z(99.0, 100.0).unwrap();
+        // Bybit 고평가 → 양수
+        let z_rich = kf.residual_z(99.0, 100.5).unwrap();
+        assert!(z_rich > z_fair + 1.0, "z_rich={z_rich}, z_fair={z_fair}");
+        // Bybit 저평가 → 음수
+        let z_cheap = kf.residual_z(99.0, 99.5).unwrap();
+        assert!(z_cheap < z_fair - 1.0, "z_cheap={z_cheap}, z_fair={z_fair}");
+    }
+
+    #[test]
+    fn test_tick_stats_matches_fixed_spread_when_one_to_one() {
+        // beta≈1, alpha≈0 관계에서는 fair spread가 상수 프리미엄에 수렴
+        let mut kf = KalmanHedge::new(params());
+        for i in 0..2000 {
+            let upbit = 100.0 + 5.0 * (i as f64 / 50.0).sin();
+            kf.update(upbit, upbit * 1.003);
+        }
+        let (fair, stddev) = kf.tick_stats(100.0, 100.3).unwrap();
+        assert!((fair - 0.3).abs() < 0.05, "fair={fair}");
+        assert!(stddev > 0.0);
+    }
+
+    #[test]
+    fn test_from_windows_matches_sequential_update() {
+        let mut upbit = CandleWindow::new(100);
+        let mut bybit = CandleWindow::new(100);
+        let mut kf = KalmanHedge::new(params());
+        for i in 0..50 {
+            let b = 50.0 + noise(i);
+            let u = b * 0.98;
+            upbit.push(u);
+            bybit.push(b);
+            kf.update(u, b);
+        }
+        let rebuilt = KalmanHedge::from_windows(params(), &upbit, &bybit);
+        assert_eq!(rebuilt.count(), kf.count());
+        assert!((rebuilt.hedge_ratio() - kf.hedge_ratio()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_hedge_qty_applies_ratio_and_step() {
+        // 1.000 × 0.9567 = 0.9567 → 0.956
+        let qty = hedge_qty(Decimal::ONE, 0.9567, 0.8, 1.2, &info());
+        assert_eq!(qty, Decimal::new(956, 3));
+    }
+
+    #[test]
+    fn test_hedge_qty_clamped() {
+        let qty = hedge_qty(Decimal::ONE, 1.8, 0.8, 1.2, &info());
+        assert_eq!(qty, Decimal::new(12, 1));
+    }
+
+    #[test]
+    fn test_hedge_qty_below_min_falls_back() {
+        // 0.001 × 0.9 = 0.0009 → 0 (< min_order_qty) → 1:1
+        let qty = hedge_qty(Decimal::new(1, 3), 0.9, 0.8, 1.2, &info());
+        assert_eq!(qty, Decimal::new(1, 3));
+    }
+
+    #[test]
+    fn test_hedge_qty_one_to_one_unchanged() {
+        let qty = hedge_qty(Decimal::new(1234, 3), 1.0, 0.8, 1.2, &info());
+        assert_eq!(qty, Decimal::new(1234, 3));
+    }
+}
@@ -3,7 +3,11 @@
 //! Upbit 현물 + Bybit 선물 양 레그 동시 주문을 실행합니다.
 //! IOC 지정가 주문을 기본으로 하며, 비상 청산 3단계 escalation을 지원합니다.
 //! trait/dyn 없이 구체 제네릭 타입으로 hot path 성능을 최적화합니다.
+//!
+//! 발주 응답이 타임아웃되거나 접수 여부가 불확실한 에러면 client order id로 주문을 조회해
+//! 실제 접수 여부를 확인한 뒤에만 재발주합니다 (중복 레그 방지).
 
+use std::future::Future;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -11,26 +15,35 @@ use rust_decimal::Decimal;
 use tracing::{debug, error, info, warn};
 
 use arb_exchange::{
-    ExchangeError, InstrumentDataProvider, LinearOrderManagement, MarketData, OrderManagement,
-    OrderRequest, OrderSide, OrderType, TimeInForce,
+    ExchangeError, ExchangeResult, InstrumentDataProvider, LinearOrderManagement, MarketData,
+    Order, OrderManagement, OrderRequest, OrderSide, OrderType, TimeInForce,
 };
 
 use crate::zscore::config::ZScoreConfig;
 use crate::zscore::instrument::{
     InstrumentInfo, ceil_to_step, round_price_conservative, upbit_tick_size,
 };
+use crate::zscore::metrics;
 
 // ---------------------------------------------------------------------------
 // 요청/응답 타입
 // ---------------------------------------------------------------------------
 
+/// 진입 주문 유형 (양 레그 IOC 지정가, 설정 `order_type` 표기와 동일).
+pub const ENTRY_ORDER_TYPE: &str = "limit_ioc";
+
+/// 청산 주문 유형 (양 레그 시장가).
+pub const EXIT_ORDER_TYPE: &str = "market";
+
 /// 진입 주문 요청.
 #[derive(Debug, Clone)]
 pub struct EntryRequest {
     /// 코인 심볼 (예: "BTC").
     pub coin: String,
-    /// 주문 수량 (코인 단위, 양 레그 동일).
+    /// Upbit 매수 수량 (코인 단위).
     pub qty: Decimal,
+    /// Bybit short 수량 (헤지 비율 적용, 고정 헤지는 `qty`와 동일).
+    pub bybit_qty: Decimal,
     /// Upbit KRW 지정가.
     pub upbit_krw_price: Decimal,
     /// Bybit USDT 지정가.
@@ -48,8 +61,10 @@ pub struct EntryRequest {
 pub struct ExitRequest {
     /// 코인 심볼.
     pub coin: String,
-    /// 청산 수량.
+    /// Upbit 매도 수량.
     pub qty: Decimal,
+    /// Bybit close 수량 (진입 시 헤지 비율 적용 수량).
+    pub bybit_qty: Decimal,
     /// 거래 규격 정보.
     pub instrument_info: InstrumentInfo,
     /// Exit Client Order ID.
@@ -75,10 +90,16 @@ pub struct ExecutedEntry {
     pub upbit_fee: Decimal,
     /// Bybit 수수료.
     pub bybit_fee: Decimal,
-    /// 유효 수량 (양 레그 min, Upbit 코인 수수료 차감 후).
+    /// 유효 수량 (Upbit 레그 기준, 헤지 비율 환산 후 양 레그 min, Upbit 코인 수수료 차감 후).
     pub effective_qty: Decimal,
+    /// 유효 Bybit 수량 (`effective_qty` × 헤지 비율, Bybit 체결 수량 이하).
+    pub effective_bybit_qty: Decimal,
     /// 초과분 청산 비용 (USDT).
     pub adjustment_cost: Decimal,
+    /// Upbit 레그 발주~체결 응답 소요 시간.
+    pub upbit_latency: Duration,
+    /// Bybit 레그 발주~체결 응답 소요 시간.
+    pub bybit_latency: Duration,
 }
 
 /// 청산 체결 결과.
@@ -100,6 +121,10 @@ pub struct ExecutedExit {
     pub upbit_fee: Decimal,
     /// Bybit 수수료.
     pub bybit_fee: Decimal,
+    /// Upbit 레그 발주~체결 응답 소요 시간.
+    pub upbit_latency: Duration,
+    /// Bybit 레그 발주~체결 응답 소요 시간.
+    pub bybit_latency: Duration,
 }
 
 /// 레그 방향.
@@ -203,6 +228,32 @@ impl From<ExchangeError> for OrderExecutionError {
 // 내부 주문 결과 (간소화)
 // ---------------------------------------------------------------------------
 
+/// 비상 청산 주문 태그 (포지션 간 공유되므로 client order id 조회 대상에서 제외).
+const EMERGENCY_ORDER_TAG: &str = "emergency";
+/// 넓은 IOC 비상 청산 주문 태그.
+const EMERGENCY_WIDE_ORDER_TAG: &str = "emergency-wide";
+
+/// 불확실한 발주 실패 후 재발주를 포함한 최대 발주 횟수.
+const MAX_PLACE_ATTEMPTS: u32 = 2;
+
+/// 레그 발주 실패 원인.
+#[derive(Debug)]
+enum PlaceError {
+    /// 응답 타임아웃 (접수 여부 확인 불가).
+    TimedOut,
+    /// 거래소 에러.
+    Exchange(ExchangeError),
+}
+
+impl std::fmt::Display for PlaceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TimedOut => write!(f, "order request timed out"),
+            Self::Exchange(e) => write!(f, "{e}"),
+        }
+    }
+}
+
 /// 내부 주문 결과.
 #[derive(Debug)]
 struct OrderResult {
@@ -216,6 +267,13 @@ struct OrderResult {
     paid_fee: Decimal,
 }
 
+/// future 결과와 완료까지 걸린 시간을 함께 반환합니다 (레그별 체결 지연 측정).
+async fn timed<T>(fut: impl Future<Output = T>) -> (T, Duration) {
+    let started_at = std::time::Instant::now();
+    let output = fut.await;
+    (output, started_at.elapsed())
+}
+
 // ---------------------------------------------------------------------------
 // LiveExecutor
 // ---------------------------------------------------------------------------
@@ -281,6 +339,7 @@ where
     ) -> Result<ExecutedEntry, OrderExecutionError> {
         let coin = &request.coin;
         let qty = request.qty;
+        let bybit_qty = request.bybit_qty;
 
         // Upbit KRW 가격 (슬리피지 마진 적용 후, 호가 단위로 올림 정규화)
         let slippage_mul = Decimal::ONE
@@ -305,6 +364,7 @@ where
         info!(
             coin = coin.as_str(),
             qty = %qty,
+            bybit_qty = %bybit_qty,
             upbit_limit_krw = %upbit_limit_price_krw,
             bybit_limit_usdt = %bybit_limit_price,
             client_order_id = request.client_order_id.as_str(),
@@ -319,35 +379,28 @@ where
             "진입 가격 정규화 완료"
         );
 
-        let order_timeout = Duration::from_secs(self.config.order_timeout_sec);
         let mut upbit_error: Option<String> = None;
         let mut bybit_error: Option<String> = None;
 
-        // 양 레그 동시 발주 (IOC 지정가)
-        let (upbit_result, bybit_result) = tokio::join!(
-            tokio::time::timeout(
-                order_timeout,
-                self.place_upbit_buy(
-                    &upbit_market,
-                    qty,
-                    upbit_limit_price_krw,
-                    &request.client_order_id,
-                ),
-            ),
-            tokio::time::timeout(
-                order_timeout,
-                self.place_bybit_short(
-                    &bybit_symbol,
-                    qty,
-                    bybit_limit_price,
-                    &request.client_order_id,
-                ),
-            ),
+        // 양 레그 동시 발주 (IOC 지정가, 레그별 타임아웃/접수 확인은 submit_reconciled에서 처리)
+        let ((upbit_result, upbit_latency), (bybit_result, bybit_latency)) = tokio::join!(
+            timed(self.place_upbit_buy(
+                &upbit_market,
+                qty,
+                upbit_limit_price_krw,
+                &request.client_order_id,
+            )),
+            timed(self.place_bybit_short(
+                &bybit_symbol,
+                bybit_qty,
+                bybit_limit_price,
+                &request.client_order_id,
+            )),
         );
 
         // 결과 파싱
         let upbit_order = match upbit_result {
-            Ok(Ok(order)) => {
+            Ok(order) => {
                 info!(
                     order_id = order.id.as_str(),
                     filled_qty = %order.filled_qty,
@@ -356,19 +409,19 @@ where
                 );
                 Some(order)
             }
-            Ok(Err(e)) => {
-                warn!(error = %e, "Upbit 매수 실패");
-                upbit_error = Some(e.to_string());
+            Err(PlaceError::TimedOut) => {
+                warn!("Upbit 매수 타임아웃");
                 None
             }
-            Err(_) => {
-                warn!("Upbit 매수 타임아웃");
+            Err(PlaceError::Exchange(e)) => {
+                warn!(error = %e, "Upbit 매수 실패");
+                upbit_error = Some(e.to_string());
                 None
             }
         };
 
         let bybit_order = match bybit_result {
-            Ok(Ok(order)) => {
+            Ok(order) => {
                 info!(
                     order_id = order.id.as_str(),
                     filled_qty = %order.filled_qty,
@@ -377,20 +430,25 @@ where
                 );
                 Some(order)
             }
-            Ok(Err(e)) => {
-                warn!(error = %e, "Bybit short 실패");
-                bybit_error = Some(e.to_string());
+            Err(PlaceError::TimedOut) => {
+                warn!("Bybit short 타임아웃");
                 None
             }
-            Err(_) => {
-                warn!("Bybit short 타임아웃");
+            Err(PlaceError::Exchange(e)) => {
+                warn!(error = %e, "Bybit short 실패");
+                bybit_error = Some(e.to_string());
                 None
             }
         };
 
         match (upbit_order, bybit_order) {
             // 양쪽 체결 성공
-            (Some(upbit), Some(bybit)) => self.handle_both_filled_entry(request, upbit, bybit),
+            (Some(upbit), Some(bybit)) => {
+                let mut executed = self.handle_both_filled_entry(request, upbit, bybit)?;
+                executed.upbit_latency = upbit_latency;
+                executed.bybit_latency = bybit_latency;
+                Ok(executed)
+            }
             // 한쪽만 체결 → 비상 청산
             (Some(upbit), None) => {
                 warn!(
@@ -452,19 +510,35 @@ where
         // Upbit은 코인 수수료를 수량에서 차감 (taker fee rate 기반 추산)
         let upbit_fee_rate = self.config.upbit_taker_fee;
         let upbit_net_qty = upbit.filled_qty * (Decimal::ONE - upbit_fee_rate);
-        let effective_qty = upbit_net_qty.min(bybit.filled_qty);
+
+        // 헤지 비율 (Bybit / Upbit 요청 수량). 고정 헤지는 1.
+        let hedge_ratio = if request.qty.is_zero() || request.bybit_qty == request.qty {
+            Decimal::ONE
+        } else {
+            request.bybit_qty / request.qty
+        };
+        let (effective_qty, effective_bybit_qty) = if hedge_ratio == Decimal::ONE {
+            let q = upbit_net_qty.min(bybit.filled_qty);
+            (q, q)
+        } else {
+            // Bybit 체결 수량을 Upbit 기준으로 환산하여 min
+            let q = upbit_net_qty.min(bybit.filled_qty / hedge_ratio);
+            (q, (q * hedge_ratio).min(bybit.filled_qty))
+        };
 
         debug!(
             upbit_filled = %upbit.filled_qty,
             upbit_net = %upbit_net_qty,
             bybit_filled = %bybit.filled_qty,
+            hedge_ratio = %hedge_ratio,
             effective = %effective_qty,
+            effective_bybit = %effective_bybit_qty,
             "유효 수량 산출"
         );
 
         // 초과분 계산
         let upbit_excess = upbit_net_qty - effective_qty;
-        let bybit_excess = bybit.filled_qty - effective_qty;
+        let bybit_excess = bybit.filled_qty - effective_bybit_qty;
         let mut adjustment_cost = Decimal::ZERO;
 
         if upbit_excess > Decimal::ZERO || bybit_excess > Decimal::ZERO {
@@ -502,7 +576,10 @@ where
             upbit_fee,
             bybit_fee,
             effective_qty,
+            effective_bybit_qty,
             adjustment_cost,
+            upbit_latency: Duration::ZERO,
+            bybit_latency: Duration::ZERO,
         })
     }
 
@@ -515,6 +592,7 @@ where
     ) -> Result<ExecutedExit, OrderExecutionError> {
         let coin = &request.coin;
         let qty = request.qty;
+        let bybit_qty = request.bybit_qty;
 
         let upbit_market = format!("KRW-{}", coin);
         let bybit_symbol = format!("{}USDT", coin);
@@ -526,22 +604,14 @@ where
             "청산 주문 발주 시작"
         );
 
-        let order_timeout = Duration::from_secs(self.config.order_timeout_sec);
-
         // 양 레그 동시 발주: Upbit 시장가 매도 + Bybit 시장가 close(매수)
-        let (upbit_result, bybit_result) = tokio::join!(
-            tokio::time::timeout(
-                order_timeout,
-                self.place_upbit_sell(&upbit_market, qty, &request.exit_client_order_id),
-            ),
-            tokio::time::timeout(
-                order_timeout,
-                self.place_bybit_close(&bybit_symbol, qty, &request.exit_client_order_id),
-            ),
+        let ((upbit_result, upbit_latency), (bybit_result, bybit_latency)) = tokio::join!(
+            timed(self.place_upbit_sell(&upbit_market, qty, &request.exit_client_order_id)),
+            timed(self.place_bybit_close(&bybit_symbol, bybit_qty, &request.exit_client_order_id)),
         );
 
         let upbit_order = match upbit_result {
-            Ok(Ok(order)) => {
+            Ok(order) => {
                 info!(
                     order_id = order.id.as_str(),
                     filled_qty = %order.filled_qty,
@@ -549,18 +619,18 @@ where
                 );
                 Some(order)
             }
-            Ok(Err(e)) => {
-                warn!(error = %e, "Upbit 매도 실패");
+            Err(PlaceError::TimedOut) => {
+                warn!("Upbit 매도 타임아웃");
                 None
             }
-            Err(_) => {
-                warn!("Upbit 매도 타임아웃");
+            Err(PlaceError::Exchange(e)) => {
+                warn!(error = %e, "Upbit 매도 실패");
                 None
             }
         };
 
         let bybit_order = match bybit_result {
-            Ok(Ok(order)) => {
+            Ok(order) => {
                 info!(
                     order_id = order.id.as_str(),
                     filled_qty = %order.filled_qty,
@@ -568,12 +638,12 @@ where
                 );
                 Some(order)
             }
-            Ok(Err(e)) => {
-                warn!(error = %e, "Bybit close 실패");
+            Err(PlaceError::TimedOut) => {
+                warn!("Bybit close 타임아웃");
                 None
             }
-            Err(_) => {
-                warn!("Bybit close 타임아웃");
+            Err(PlaceError::Exchange(e)) => {
+                warn!(error = %e, "Bybit close 실패");
                 None
             }
         };
@@ -588,6 +658,8 @@ where
                 bybit_avg_price: bybit.avg_price,
                 upbit_fee: upbit.paid_fee,
                 bybit_fee: bybit.paid_fee,
+                upbit_latency,
+                bybit_latency,
             }),
             (Some(_upbit), None) => {
                 warn!("Bybit close 실패, 비상 처리 필요");
@@ -646,8 +718,14 @@ where
             );
 
             let result = match leg {
-                Leg::Upbit => self.place_upbit_sell(symbol, qty, "emergency").await,
-                Leg::Bybit => self.place_bybit_close(symbol, qty, "emergency").await,
+                Leg::Upbit => {
+                    self.place_upbit_sell(symbol, qty, EMERGENCY_ORDER_TAG)
+                        .await
+                }
+                Leg::Bybit => {
+                    self.place_bybit_close(symbol, qty, EMERGENCY_ORDER_TAG)
+                        .await
+                }
             };
 
             match result {
@@ -698,8 +776,14 @@ where
 
             // 넓은 슬리피지로 시장가 재시도
             let result = match leg {
-                Leg::Upbit => self.place_upbit_sell(symbol, qty, "emergency-wide").await,
-                Leg::Bybit => self.place_bybit_close(symbol, qty, "emergency-wide").await,
+                Leg::Upbit => {
+                    self.place_upbit_sell(symbol, qty, EMERGENCY_WIDE_ORDER_TAG)
+                        .await
+                }
+                Leg::Bybit => {
+                    self.place_bybit_close(symbol, qty, EMERGENCY_WIDE_ORDER_TAG)
+                        .await
+                }
             };
 
             match result {
@@ -738,6 +822,98 @@ where
     // Private helpers: 주문 발주
     // -----------------------------------------------------------------------
 
+    /// 타임아웃/불확실한 에러 시 접수 여부를 확인하며 주문을 발주합니다.
+    ///
+    /// 발주 응답이 타임아웃되거나 `is_ambiguous()` 에러면 `lookup`으로 client order id를
+    /// 조회합니다. 접수가 확인되면 조회된 주문을 반환하고, `OrderNotFound`일 때만
+    /// 재발주합니다. 조회마저 실패하면 중복 레그를 피하기 위해 원래 에러를 반환합니다.
+    /// 비상 청산 태그처럼 주문마다 고유하지 않은 id는 조회하지 않습니다.
+    async fn submit_reconciled<P, PF, L, LF>(
+        &self,
+        leg: Leg,
+        side: &'static str,
+        client_order_id: &str,
+        place: P,
+        lookup: L,
+    ) -> Result<Order, PlaceError>
+    where
+        P: Fn() -> PF,
+        PF: Future<Output = ExchangeResult<Order>>,
+        L: Fn() -> LF,
+        LF: Future<Output = ExchangeResult<Order>>,
+    {
+        let order_timeout = Duration::from_secs(self.config.order_timeout_sec);
+        let reconcilable = !client_order_id.is_empty()
+            && client_order_id != EMERGENCY_ORDER_TAG
+            && client_order_id != EMERGENCY_WIDE_ORDER_TAG;
+
+        let mut attempt = 0u32;
+        loop {
+            attempt += 1;
+
+            let started_at = std::time::Instant::now();
+            let result = tokio::time::timeout(order_timeout, place()).await;
+            let ok = matches!(result, Ok(Ok(_)));
+            metrics::observe_order_roundtrip(leg, side, ok, started_at.elapsed());
+
+            let place_err = match result {
+                Ok(Ok(order)) => return Ok(order),
+                Ok(Err(e)) if !e.is_ambiguous() => return Err(PlaceError::Exchange(e)),
+                Ok(Err(e)) => PlaceError::Exchange(e),
+                Err(_) => PlaceError::TimedOut,
+            };
+            if !reconcilable {
+                return Err(place_err);
+            }
+
+            warn!(
+                leg = %leg,
+                client_order_id = client_order_id,
+                attempt = attempt,
+                error = %place_err,
+                "발주 결과 불확실, client order id로 접수 여부 조회"
+            );
+
+            match tokio::time::timeout(order_timeout, lookup()).await {
+                Ok(Ok(order)) => {
+                    info!(
+                        leg = %leg,
+                        client_order_id = client_order_id,
+                        order_id = order.id.as_str(),
+                        executed_volume = %order.executed_volume,
+                        "거래소 접수 확인, 재발주 생략"
+                    );
+                    return Ok(order);
+                }
+                Ok(Err(ExchangeError::OrderNotFound(_))) if attempt < MAX_PLACE_ATTEMPTS => {
+                    warn!(
+                        leg = %leg,
+                        client_order_id = client_order_id,
+                        "거래소 미접수 확인, 재발주"
+                    );
+                }
+                Ok(Err(ExchangeError::OrderNotFound(_))) => return Err(place_err),
+                Ok(Err(e)) => {
+                    error!(
+                        leg = %leg,
+                        client_order_id = client_order_id,
+                        lookup_error = %e,
+                        "접수 여부 조회 실패, 중복 방지를 위해 재발주 생략"
+                    );
+                    return Err(place_err);
+                }
+                Err(_) => {
+                    error!(
+                        leg = %leg,
+                        client_order_id = client_order_id,
+                        "접수 여부 조회 타임아웃, 중복 방지를 위해 재발주 생략"
+                    );
+                    return Err(place_err);
+                }
+            }
+        }
+    }
+
     /// Upbit IOC 지정가 매수 주문.
     async fn place_upbit_buy(
         &self,
@@ -745,7 +921,7 @@ where
         qty: Decimal,
         price_krw: Decimal,
         client_order_id: &str,
-    ) -> Result<OrderResult, ExchangeError> {
+    ) -> Result<OrderResult, PlaceError> {
         debug!(
             market = market,
             qty = %qty,
@@ -758,10 +934,20 @@ where
             .with_time_in_force(TimeInForce::Ioc)
             .with_identifier(client_order_id.to_string());
 
-        let order = self.upbit.place_order(&request).await.map_err(|e| {
-            error!(error = %e, market = market, "Upbit 매수 주문 실패");
-            e
-        })?;
+        let upbit = &self.upbit;
+        let order = self
+            .submit_reconciled(
+                Leg::Upbit,
+                "buy",
+                client_order_id,
+                || upbit.place_order(&request),
+                || upbit.get_order_by_client_id(client_order_id),
+            )
+            .await
+            .map_err(|e| {
+                error!(error = %e, market = market, "Upbit 매수 주문 실패");
+                e
+            })?;
 
         debug!(
             order_id = order.id.as_str(),
@@ -784,7 +970,7 @@ where
         market: &str,
         qty: Decimal,
         client_order_id: &str,
-    ) -> Result<OrderResult, ExchangeError> {
+    ) -> Result<OrderResult, PlaceError> {
         debug!(
             market = market,
             qty = %qty,
@@ -795,10 +981,20 @@ where
         let request =
             OrderRequest::market_sell(market, qty).with_identifier(client_order_id.to_string());
 
-        let order = self.upbit.place_order(&request).await.map_err(|e| {
-            error!(error = %e, market = market, "Upbit 매도 주문 실패");
-            e
-        })?;
+        let upbit = &self.upbit;
+        let order = self
+            .submit_reconciled(
+                Leg::Upbit,
+                "sell",
+                client_order_id,
+                || upbit.place_order(&request),
+                || upbit.get_order_by_client_id(client_order_id),
+            )
+            .await
+            .map_err(|e| {
+                error!(error = %e, market = market, "Upbit 매도 주문 실패");
+                e
+            })?;
 
         Ok(OrderResult {
             id: order.id,
@@ -815,7 +1011,7 @@ where
         qty: Decimal,
         price: Decimal,
         client_order_id: &str,
-    ) -> Result<OrderResult, ExchangeError> {
+    ) -> Result<OrderResult, PlaceError> {
         debug!(
             symbol = symbol,
             qty = %qty,
@@ -837,9 +1033,15 @@ where
         };
 
         // linear 선물 API 사용 (reduce_only=false: 신규 포지션)
+        let bybit = &self.bybit;
         let order = self
-            .bybit
-            .place_order_linear(&request, false)
+            .submit_reconciled(
+                Leg::Bybit,
+                "sell",
+                client_order_id,
+                || bybit.place_order_linear(&request, false),
+                || bybit.get_order_linear_by_client_id(client_order_id),
+            )
             .await
             .map_err(|e| {
                 error!(error = %e, symbol = symbol, "Bybit linear short 주문 실패");
@@ -867,7 +1069,7 @@ where
         symbol: &str,
         qty: Decimal,
         client_order_id: &str,
-    ) -> Result<OrderResult, ExchangeError> {
+    ) -> Result<OrderResult, PlaceError> {
         debug!(
             symbol = symbol,
             qty = %qty,
@@ -888,9 +1090,15 @@ where
         };
 
         // linear 선물 API 사용 (reduce_only=true: 포지션 청산)
+        let bybit = &self.bybit;
         let order = self
-            .bybit
-            .place_order_linear(&request, true)
+            .submit_reconciled(
+                Leg::Bybit,
+                "buy",
+                client_order_id,
+                || bybit.place_order_linear(&request, true),
+                || bybit.get_order_linear_by_client_id(client_order_id),
+            )
             .await
             .map_err(|e| {
                 error!(error = %e, symbol = symbol, "Bybit linear close 주문 실패");
@@ -941,6 +1149,10 @@ mod tests {
         paid_fee: Decimal,
         should_fail: bool,
         fail_error: Option<String>,
+        /// 앞선 N회 발주를 접수 여부가 불확실한 에러로 실패시킴.
+        ambiguous_failures: u32,
+        /// client order id 조회 시 주문이 접수된 것으로 응답할지 여부.
+        landed: bool,
     }
 
     impl Default for MockOrderResponse {
@@ -952,16 +1164,42 @@ mod tests {
                 paid_fee: Decimal::ZERO,
                 should_fail: false,
                 fail_error: None,
+                ambiguous_failures: 0,
+                landed: false,
             }
         }
     }
 
+    /// client order id 조회 mock 응답 (`landed`면 접수된 주문, 아니면 OrderNotFound).
+    fn mock_lookup(resp: &MockOrderResponse, client_order_id: &str) -> ExchangeResult<Order> {
+        if !resp.landed {
+            return Err(ExchangeError::OrderNotFound(client_order_id.to_string()));
+        }
+        Ok(Order {
+            id: resp.id.clone(),
+            market: "mock".to_string(),
+            side: OrderSide::Buy,
+            order_type: OrderType::Limit,
+            status: OrderStatus::Filled,
+            volume: resp.executed_volume,
+            remaining_volume: Decimal::ZERO,
+            executed_volume: resp.executed_volume,
+            price: resp.avg_price,
+            avg_price: resp.avg_price,
+            paid_fee: resp.paid_fee,
+            created_at: Utc::now(),
+            identifier: Some(client_order_id.to_string()),
+        })
+    }
+
     /// Mock Upbit 클라이언트.
     struct MockUpbit {
         /// place_order 호출 시 반환할 응답.
         next_response: Mutex<MockOrderResponse>,
         /// place_order 호출 기록.
         order_history: Mutex<Vec<OrderRequest>>,
+        /// get_order_by_client_id 호출 기록.
+        lookup_history: Mutex<Vec<String>>,
     }
 
     impl MockUpbit {
@@ -969,6 +1207,7 @@ mod tests {
             Self {
                 next_response: Mutex::new(response),
                 order_history: Mutex::new(Vec::new()),
+                lookup_history: Mutex::new(Vec::new()),
             }
         }
     }
@@ -1015,7 +1254,14 @@ mod tests {
     impl OrderManagement for MockUpbit {
         async fn place_order(&self, request: &OrderRequest) -> ExchangeResult<Order> {
             self.order_history.lock().await.push(request.clone());
-            let resp = self.next_response.lock().await.clone();
+            let resp = {
+                let mut next = self.next_response.lock().await;
+                if next.ambiguous_failures > 0 {
+                    next.ambiguous_failures -= 1;
+                    return Err(ExchangeError::ExchangeOffline("mock gateway".to_string()));
+                }
+                next.clone()
+            };
 
             if resp.should_fail {
                 return Err(ExchangeError::ApiError(
@@ -1049,6 +1295,15 @@ mod tests {
             Err(ExchangeError::Unsupported("mock".to_string()))
         }
 
+        async fn get_order_by_client_id(&self, client_order_id: &str) -> ExchangeResult<Order> {
+            self.lookup_history
+                .lock()
+                .await
+                .push(client_order_id.to_string());
+            let resp = self.next_response.lock().await.clone();
+            mock_lookup(&resp, client_order_id)
+        }
+
         async fn get_open_orders(&self, _market: Option<&str>) -> ExchangeResult<Vec<Order>> {
             Ok(vec![])
         }
@@ -1066,6 +1321,7 @@ mod tests {
     struct MockBybit {
         next_response: Mutex<MockOrderResponse>,
         order_history: Mutex<Vec<OrderRequest>>,
+        lookup_history: Mutex<Vec<String>>,
     }
 
     impl MockBybit {
@@ -1073,6 +1329,7 @@ mod tests {
             Self {
                 next_response: Mutex::new(response),
                 order_history: Mutex::new(Vec::new()),
+                lookup_history: Mutex::new(Vec::new()),
             }
         }
     }
@@ -1119,7 +1376,14 @@ mod tests {
     impl OrderManagement for MockBybit {
         async fn place_order(&self, request: &OrderRequest) -> ExchangeResult<Order> {
             self.order_history.lock().await.push(request.clone());
-            let resp = self.next_response.lock().await.clone();
+            let resp = {
+                let mut next = self.next_response.lock().await;
+                if next.ambiguous_failures > 0 {
+                    next.ambiguous_failures -= 1;
+                    return Err(ExchangeError::ExchangeOffline("mock gateway".to_string()));
+                }
+                next.clone()
+            };
 
             if resp.should_fail {
                 return Err(ExchangeError::ApiError(
@@ -1153,6 +1417,15 @@ mod tests {
             Err(ExchangeError::Unsupported("mock".to_string()))
         }
 
+        async fn get_order_by_client_id(&self, client_order_id: &str) -> ExchangeResult<Order> {
+            self.lookup_history
+                .lock()
+                .await
+                .push(client_order_id.to_string());
+            let resp = self.next_response.lock().await.clone();
+            mock_lookup(&resp, client_order_id)
+        }
+
         async fn get_open_orders(&self, _market: Option<&str>) -> ExchangeResult<Vec<Order>> {
             Ok(vec![])
         }
@@ -1177,6 +1450,7 @@ mod tests {
                 min_order_qty: Decimal::new(1, 3),
                 max_order_qty: Decimal::new(100, 0),
                 min_notional: Decimal::new(5, 0),
+                contract_multiplier: Decimal::ONE,
             })
         }
     }
@@ -1195,6 +1469,13 @@ mod tests {
             self.get_order(order_id).await
         }
 
+        async fn get_order_linear_by_client_id(
+            &self,
+            client_order_id: &str,
+        ) -> ExchangeResult<Order> {
+            self.get_order_by_client_id(client_order_id).await
+        }
+
         async fn cancel_order_linear(
             &self,
             order_id: &str,
@@ -1231,6 +1512,7 @@ mod tests {
         EntryRequest {
             coin: "BTC".to_string(),
             qty: Decimal::new(1, 2), // 0.01 BTC
+            bybit_qty: Decimal::new(1, 2),
             upbit_krw_price: Decimal::new(60_000_000, 0),
             bybit_usdt_price: Decimal::new(42000, 0),
             usd_krw: 1350.0,
@@ -1240,6 +1522,7 @@ mod tests {
                 min_order_qty: Decimal::new(1, 5),
                 min_notional: Decimal::new(5, 0),
                 max_order_qty: Decimal::new(100, 0),
+                contract_multiplier: Decimal::ONE,
             },
             client_order_id: "test-uuid-001".to_string(),
         }
@@ -1249,12 +1532,14 @@ mod tests {
         ExitRequest {
             coin: "BTC".to_string(),
             qty: Decimal::new(1, 2), // 0.01 BTC
+            bybit_qty: Decimal::new(1, 2),
             instrument_info: InstrumentInfo {
                 tick_size: Decimal::new(1, 2),
                 qty_step: Decimal::new(1, 5),
                 min_order_qty: Decimal::new(1, 5),
                 min_notional: Decimal::new(5, 0),
                 max_order_qty: Decimal::new(100, 0),
+                contract_multiplier: Decimal::ONE,
             },
             exit_client_order_id: "test-uuid-exit-001".to_string(),
         }
@@ -1295,6 +1580,38 @@ mod tests {
         assert!(entry.effective_qty > Decimal::ZERO);
     }
 
+    #[tokio::test]
+    async fn test_execute_entry_hedged_bybit_qty() {
+        let upbit = Arc::new(MockUpbit::new(MockOrderResponse {
+            id: "upbit-001".to_string(),
+            executed_volume: Decimal::new(1, 2), // 0.01
+            avg_price: Some(Decimal::new(60_000_000, 0)),
+            paid_fee: Decimal::new(30_000, 0),
+            ..Default::default()
+        }));
+        let bybit = Arc::new(MockBybit::new(MockOrderResponse {
+            id: "bybit-001".to_string(),
+            executed_volume: Decimal::new(9, 3), // 0.009
+            avg_price: Some(Decimal::new(42000, 0)),
+            paid_fee: Decimal::new(207, 3),
+            ..Default::default()
+        }));
+
+        let executor = LiveExecutor::new(upbit, bybit.clone(), make_config());
+        let mut req = make_entry_request();
+        req.bybit_qty = Decimal::new(9, 3); // hedge_ratio = 0.9
+        let entry = executor.execute_entry(&req).await.unwrap();
+
+        // Bybit에는 헤지 수량으로 주문
+        let bybit_orders = bybit.order_history.lock().await;
+        assert_eq!(bybit_orders[0].volume, Some(Decimal::new(9, 3)));
+
+        // upbit_net = 0.01 × 0.9995 = 0.009995, bybit 환산 = 0.009 / 0.9 = 0.01
+        assert_eq!(entry.effective_qty, Decimal::new(9995, 6));
+        // 0.009995 × 0.9 = 0.0089955 (Bybit 체결량 이하)
+        assert_eq!(entry.effective_bybit_qty, Decimal::new(89955, 7));
+    }
+
     #[tokio::test]
     async fn test_execute_entry_upbit_only_filled() {
         let upbit = Arc::new(MockUpbit::new(MockOrderResponse {
@@ -1559,6 +1876,7 @@ mod tests {
         let req = EntryRequest {
             coin: "DOGE".to_string(),
             qty: Decimal::new(2256, 0),
+            bybit_qty: Decimal::new(2256, 0),
             upbit_krw_price: Decimal::new(162, 0),
             bybit_usdt_price: Decimal::new(11069919, 8), // 0.11069919
             usd_krw: 1440.64,
@@ -1568,6 +1886,7 @@ mod tests {
                 min_order_qty: Decimal::new(1, 3),
                 min_notional: Decimal::new(5, 0),
                 max_order_qty: Decimal::new(10_000_000, 0),
+                contract_multiplier: Decimal::ONE,
             },
             client_order_id: "test-uuid-tick-001".to_string(),
         };
@@ -1595,6 +1914,88 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_execute_entry_ambiguous_failure_landed_skips_resubmit() {
+        // 발주 응답은 유실됐지만 거래소에는 접수됨 → 조회 결과 사용, 재발주 없음
+        let upbit = Arc::new(MockUpbit::new(MockOrderResponse {
+            id: "upbit-010".to_string(),
+            executed_volume: Decimal::new(1, 2),
+            avg_price: Some(Decimal::new(60_000_000, 0)),
+            paid_fee: Decimal::new(30_000, 0),
+            ambiguous_failures: 1,
+            landed: true,
+            ..Default::default()
+        }));
+        let bybit = Arc::new(MockBybit::new(MockOrderResponse {
+            id: "bybit-010".to_string(),
+            executed_volume: Decimal::new(1, 2),
+            avg_price: Some(Decimal::new(42000, 0)),
+            paid_fee: Decimal::new(231, 3),
+            ..Default::default()
+        }));
+
+        let executor = LiveExecutor::new(upbit.clone(), bybit, make_config());
+        let entry = executor.execute_entry(&make_entry_request()).await.unwrap();
+
+        assert_eq!(entry.upbit_order_id, "upbit-010");
+        assert_eq!(upbit.order_history.lock().await.len(), 1);
+        assert_eq!(
+            upbit.lookup_history.lock().await.as_slice(),
+            ["test-uuid-001".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_entry_ambiguous_failure_not_found_resubmits() {
+        // 조회 결과 미접수 → 같은 client order id로 1회 재발주
+        let upbit = Arc::new(MockUpbit::new(MockOrderResponse {
+            id: "upbit-011".to_string(),
+            executed_volume: Decimal::new(1, 2),
+            avg_price: Some(Decimal::new(60_000_000, 0)),
+            paid_fee: Decimal::new(30_000, 0),
+            ..Default::default()
+        }));
+        let bybit = Arc::new(MockBybit::new(MockOrderResponse {
+            id: "bybit-011".to_string(),
+            executed_volume: Decimal::new(1, 2),
+            avg_price: Some(Decimal::new(42000, 0)),
+            paid_fee: Decimal::new(231, 3),
+            ambiguous_failures: 1,
+            ..Default::default()
+        }));
+
+        let executor = LiveExecutor::new(upbit, bybit.clone(), make_config());
+        let entry = executor.execute_entry(&make_entry_request()).await.unwrap();
+
+        assert_eq!(entry.bybit_order_id, "bybit-011");
+        let orders = bybit.order_history.lock().await;
+        assert_eq!(orders.len(), 2);
+        assert_eq!(orders[0].identifier, orders[1].identifier);
+        assert_eq!(bybit.lookup_history.lock().await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_execute_entry_rejected_order_skips_lookup() {
+        // 명확한 거부(ApiError)는 미접수가 확실하므로 조회/재발주하지 않음
+        let upbit = Arc::new(MockUpbit::new(MockOrderResponse {
+            should_fail: true,
+            ..Default::default()
+        }));
+        let bybit = Arc::new(MockBybit::new(MockOrderResponse {
+            should_fail: true,
+            ..Default::default()
+        }));
+
+        let executor = LiveExecutor::new(upbit.clone(), bybit.clone(), make_config());
+        let result = executor.execute_entry(&make_entry_request()).await;
+
+        assert!(result.is_err());
+        assert_eq!(upbit.order_history.lock().await.len(), 1);
+        assert!(upbit.lookup_history.lock().await.is_empty());
+        assert_eq!(bybit.order_history.lock().await.len(), 1);
+        assert!(bybit.lookup_history.lock().await.is_empty());
+    }
+
     // =======================================================================
     // execute_exit 테스트
     // =======================================================================
@@ -1831,7 +2232,10 @@ mod tests {
             upbit_fee: Decimal::new(30_000, 0),
             bybit_fee: Decimal::new(231, 3),
             effective_qty: Decimal::new(9995, 6),
+            effective_bybit_qty: Decimal::new(9995, 6),
             adjustment_cost: Decimal::ZERO,
+            upbit_latency: Duration::from_millis(120),
+            bybit_latency: Duration::from_millis(80),
         };
         assert_eq!(entry.upbit_order_id, "u-001");
         assert_eq!(entry.bybit_order_id, "b-001");
@@ -1848,6 +2252,8 @@ mod tests {
             bybit_avg_price: Decimal::new(41500, 0),
             upbit_fee: Decimal::new(30_500, 0),
             bybit_fee: Decimal::new(228, 3),
+            upbit_latency: Duration::from_millis(95),
+            bybit_latency: Duration::from_millis(60),
         };
         assert_eq!(exit.upbit_order_id, "u-exit");
     }
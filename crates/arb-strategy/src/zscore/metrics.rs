@@ -0,0 +1,298 @@
+//! Prometheus 메트릭 연동.
+//!
+//! 핫패스 지연(틱→시그널, 레그별 주문 왕복)은 이벤트마다 히스토그램에 직접 관측하고,
+//! `MonitoringCounters`/`BalanceTracker`/`RiskManager`처럼 이미 상태를 보유한 구조체는
+//! scrape 시점 수집기로 읽어 갑니다. 수집기는 `Weak` 참조만 잡으므로
+//! 세션이 끝나 원본이 drop되면 해당 메트릭도 더 이상 노출되지 않습니다.
+//!
+//! 수집기 메트릭에는 전략 인스턴스 이름을 `instance` 라벨로 붙입니다.
+//! 지연 히스토그램은 프로세스 전체 합산입니다.
+
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive as _;
+
+use arb_metrics::{LATENCY_BUCKETS, Sampler};
+
+use crate::output::summary::MonitoringCounters;
+use crate::zscore::balance::BalanceTracker;
+use crate::zscore::live_executor::Leg;
+use crate::zscore::risk::RiskManager;
+
+/// 틱 수신부터 진입/청산 시그널 판정까지의 지연을 관측합니다.
+///
+/// * `signal` - "entry", "exit" 또는 "stop" (손절 규칙)
+pub(crate) fn observe_tick_to_signal(signal: &'static str, elapsed: Duration) {
+    arb_metrics::global()
+        .histogram(
+            "arb_tick_to_signal_seconds",
+            "틱 수신부터 진입/청산 시그널 판정까지의 지연 (초)",
+            &[("signal", signal)],
+            LATENCY_BUCKETS,
+        )
+        .observe_duration(elapsed);
+}
+
+/// 레그별 주문 발주~응답 왕복 지연을 관측합니다.
+///
+/// * `side` - "buy" 또는 "sell"
+/// * `ok` - 거래소 응답 성공 여부
+pub(crate) fn observe_order_roundtrip(leg: Leg, side: &'static str, ok: bool, elapsed: Duration) {
+    let leg = match leg {
+        Leg::Upbit => "upbit",
+        Leg::Bybit => "bybit",
+    };
+    let outcome = if ok { "ok" } else { "error" };
+    arb_metrics::global()
+        .histogram(
+            "arb_order_roundtrip_seconds",
+            "레그별 주문 발주부터 거래소 응답까지의 왕복 지연 (초)",
+            &[("leg", leg), ("side", side), ("outcome", outcome)],
+            LATENCY_BUCKETS,
+        )
+        .observe_duration(elapsed);
+}
+
+/// 모니터링 카운터 수집기를 등록합니다 (`arb_monitor_events_total{instance=...,event=...}`).
+pub fn register_monitoring_counters(
+    instance: &str,
+    counters: &Arc<parking_lot::Mutex<MonitoringCounters>>,
+) {
+    let weak = Arc::downgrade(counters);
+    let owned = instance.to_string();
+    arb_metrics::global().register_collector(
+        &format!("monitoring_counters/{instance}"),
+        move |s| {
+            if let Some(counters) = weak.upgrade() {
+                collect_monitoring_counters(s, &owned, &counters.lock());
+            }
+        },
+    );
+}
+
+/// 잔고 수집기를 등록합니다.
+pub fn register_balance_tracker(instance: &str, tracker: &Arc<BalanceTracker>) {
+    let weak: Weak<BalanceTracker> = Arc::downgrade(tracker);
+    let owned = instance.to_string();
+    arb_metrics::global().register_collector(&format!("balance_tracker/{instance}"), move |s| {
+        if let Some(tracker) = weak.upgrade() {
+            collect_balance(s, &owned, &tracker);
+        }
+    });
+}
+
+/// 리스크 매니저 수집기를 등록합니다.
+pub fn register_risk_manager(instance: &str, risk: &Arc<RiskManager>) {
+    let weak: Weak<RiskManager> = Arc::downgrade(risk);
+    let owned = instance.to_string();
+    arb_metrics::global().register_collector(&format!("risk_manager/{instance}"), move |s| {
+        if let Some(risk) = weak.upgrade() {
+            collect_risk(s, &owned, &risk);
+        }
+    });
+}
+
+fn collect_monitoring_counters(s: &mut Sampler, instance: &str, c: &MonitoringCounters) {
+    const NAME: &str = "arb_monitor_events_total";
+    const HELP: &str = "모니터링 이벤트 누적 횟수 (세션 요약 MonitoringCounters)";
+    let events = [
+        ("dropped_tick", c.dropped_tick_count),
+        ("orderbook_fetch", c.orderbook_fetch_count),
+        ("orderbook_fetch_fail", c.orderbook_fetch_fail_count),
+        ("stale_cache_skip", c.stale_cache_skip_count),
+        ("entry_rejected_slippage", c.entry_rejected_slippage_count),
+        ("partial_close", c.partial_close_count),
+        ("forced_liquidation", c.forced_liquidation_count),
+        (
+            "entry_rejected_order_constraint",
+            c.entry_rejected_order_constraint_count,
+        ),
+        (
+            "entry_rejected_rounding_pnl",
+            c.entry_rejected_rounding_pnl_count,
+        ),
+        ("fallback_no_rounding", c.fallback_no_rounding_count),
+        (
+            "safe_volume_exceeded_close",
+            c.safe_volume_exceeded_close_count,
+        ),
+        (
+            "coin_rejected_spread_stddev",
+            c.coin_rejected_spread_stddev_count,
+        ),
+        (
+            "coin_rejected_mean_reversion",
+            c.coin_rejected_mean_reversion_count,
+        ),
+        ("adaptive_param_change", c.adaptive_param_change_count),
+        ("adaptive_param_deferred", c.adaptive_param_deferred_count),
+        ("regime_change_detected", c.regime_change_detected_count),
+        (
+            "regime_change_suppressed_by_cooldown",
+            c.regime_change_suppressed_by_cooldown_count,
+        ),
+        (
+            "entry_rejected_min_position",
+            c.entry_rejected_min_position_count,
+        ),
+        ("entry_rejected_min_roi", c.entry_rejected_min_roi_count),
+        ("entry_rejected_trend", c.entry_rejected_trend_count),
+        ("entry_rejected_allocator", c.entry_rejected_allocator_count),
+        ("stop_loss_exit", c.stop_loss_exit_count),
+        ("balance_snapshot_dropped", c.balance_snapshot_dropped),
+    ];
+    for (event, value) in events {
+        s.counter(
+            NAME,
+            HELP,
+            &[("instance", instance), ("event", event)],
+            value,
+        );
+    }
+}
+
+fn collect_balance(s: &mut Sampler, instance: &str, tracker: &BalanceTracker) {
+    let ((avail_krw, avail_usdt), (reserved_krw, reserved_usdt)) = tracker.available_and_reserved();
+    let upbit = [
+        ("instance", instance),
+        ("exchange", "upbit"),
+        ("currency", "KRW"),
+    ];
+    let bybit = [
+        ("instance", instance),
+        ("exchange", "bybit"),
+        ("currency", "USDT"),
+    ];
+    let labels = [("instance", instance)];
+
+    const AVAILABLE: &str = "arb_balance_available";
+    const AVAILABLE_HELP: &str = "예약 차감 후 가용 잔고";
+    s.gauge(AVAILABLE, AVAILABLE_HELP, &upbit, to_f64(avail_krw));
+    s.gauge(AVAILABLE, AVAILABLE_HELP, &bybit, to_f64(avail_usdt));
+
+    const RESERVED: &str = "arb_balance_reserved";
+    const RESERVED_HELP: &str = "주문 진행 중 예약된 잔고";
+    s.gauge(RESERVED, RESERVED_HELP, &upbit, to_f64(reserved_krw));
+    s.gauge(RESERVED, RESERVED_HELP, &bybit, to_f64(reserved_usdt));
+
+    s.gauge(
+        "arb_balance_active_reservations",
+        "활성 잔고 예약 수",
+        &labels,
+        tracker.active_reservation_count() as f64,
+    );
+}
+
+fn collect_risk(s: &mut Sampler, instance: &str, risk: &RiskManager) {
+    let labels = [("instance", instance)];
+    s.gauge(
+        "arb_risk_daily_pnl_usdt",
+        "당일 실현 PnL (USDT)",
+        &labels,
+        to_f64(risk.daily_pnl()),
+    );
+    s.gauge(
+        "arb_risk_equity_usdt",
+        "현재 equity (USDT)",
+        &labels,
+        to_f64(risk.current_equity()),
+    );
+    s.gauge(
+        "arb_risk_rolling_24h_loss_usdt",
+        "최근 24시간 누적 손실 (USDT)",
+        &labels,
+        to_f64(risk.rolling_24h_loss()),
+    );
+    s.gauge(
+        "arb_risk_kill_switch_active",
+        "kill switch 발동 여부 (1=발동)",
+        &labels,
+        f64::from(u8::from(risk.is_killed())),
+    );
+    s.gauge(
+        "arb_risk_entry_allowed",
+        "신규 진입 허용 여부 (1=허용)",
+        &labels,
+        f64::from(u8::from(risk.is_entry_allowed())),
+    );
+    s.counter(
+        "arb_risk_trades_total",
+        "리스크 매니저에 기록된 누적 거래 수",
+        &labels,
+        risk.total_trade_count(),
+    );
+}
+
+fn to_f64(value: Decimal) -> f64 {
+    value.to_f64().unwrap_or(f64::NAN)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arb_metrics::Registry;
+
+    use crate::zscore::risk::RiskConfig;
+
+    fn encode(collect: impl Fn(&mut Sampler) + Send + Sync + 'static) -> String {
+        let registry = Registry::new();
+        registry.register_collector("test", collect);
+        registry.encode()
+    }
+
+    #[test]
+    fn test_collect_monitoring_counters() {
+        let counters = MonitoringCounters {
+            dropped_tick_count: 3,
+            entry_rejected_trend_count: 2,
+            ..Default::default()
+        };
+        let text = encode(move |s| collect_monitoring_counters(s, "default", &counters));
+        assert!(text.contains("# TYPE arb_monitor_events_total counter\n"));
+        assert!(
+            text.contains(
+                "arb_monitor_events_total{instance=\"default\",event=\"dropped_tick\"} 3\n"
+            )
+        );
+        assert!(text.contains(
+            "arb_monitor_events_total{instance=\"default\",event=\"entry_rejected_trend\"} 2\n"
+        ));
+        assert!(text.contains(
+            "arb_monitor_events_total{instance=\"default\",event=\"partial_close\"} 0\n"
+        ));
+    }
+
+    #[test]
+    fn test_collect_balance() {
+        let tracker = Arc::new(BalanceTracker::new(
+            Decimal::new(1_000_000, 0),
+            Decimal::new(500, 0),
+        ));
+        let _token = tracker
+            .reserve(Decimal::new(100_000, 0), Decimal::new(50, 0))
+            .unwrap();
+        let t = Arc::clone(&tracker);
+        let text = encode(move |s| collect_balance(s, "btc", &t));
+        assert!(text.contains(
+            "arb_balance_available{instance=\"btc\",exchange=\"upbit\",currency=\"KRW\"} 900000\n"
+        ));
+        assert!(text.contains(
+            "arb_balance_reserved{instance=\"btc\",exchange=\"bybit\",currency=\"USDT\"} 50\n"
+        ));
+        assert!(text.contains("arb_balance_active_reservations{instance=\"btc\"} 1\n"));
+    }
+
+    #[test]
+    fn test_collect_risk() {
+        let risk = Arc::new(RiskManager::new(RiskConfig::default()));
+        let r = Arc::clone(&risk);
+        let text = encode(move |s| collect_risk(s, "default", &r));
+        assert!(text.contains("arb_risk_daily_pnl_usdt{instance=\"default\"} 0\n"));
+        assert!(text.contains("arb_risk_kill_switch_active{instance=\"default\"} 0\n"));
+        assert!(text.contains("arb_risk_entry_allowed{instance=\"default\"} 1\n"));
+        assert!(text.contains("arb_risk_trades_total{instance=\"default\"} 0\n"));
+    }
+}
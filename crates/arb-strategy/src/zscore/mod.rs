@@ -1,21 +1,32 @@
 //! Z-Score 기반 차익거래 전략 모듈.
 
 pub mod alert;
+pub mod allocator;
 pub mod balance;
 pub mod balance_recorder;
+pub mod coin_params;
 pub mod coin_selector;
 pub mod config;
+pub mod config_diff;
 pub mod execution_policy;
 pub mod instrument;
+pub mod kalman;
 pub mod live_executor;
+pub mod metrics;
 pub mod monitor;
 pub mod monitor_core;
 pub mod monitor_live;
 pub mod monitor_sim;
+pub mod order_watchdog;
 pub mod orderbook;
 pub mod pnl;
 pub mod position;
 pub mod position_store;
+pub mod reload;
 pub mod risk;
 pub mod signal;
 pub mod spread;
+pub mod status;
+pub mod stops;
+pub mod tca;
+pub mod trend;
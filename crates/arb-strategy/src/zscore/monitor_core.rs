@@ -23,24 +23,33 @@ use std::time::Duration;
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use rust_decimal::prelude::ToPrimitive as _;
+use tokio::sync::watch;
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, info, trace, warn};
 
-use arb_exchange::{InstrumentDataProvider, MarketData, MarketEvent, MarketStream};
+use arb_exchange::{CandleInterval, InstrumentDataProvider, MarketData, MarketEvent, MarketStream};
 use arb_forex::ForexCache;
 
 use crate::common::candle_fetcher::fetch_all_candles;
-use crate::common::convert::truncate_to_minute;
+use crate::common::convert::truncate_to_interval;
+use crate::common::fee::roundtrip_fee_pct;
+use crate::common::statistics;
 use crate::error::StrategyError;
 use crate::output::summary::MonitoringCounters;
 use crate::output::summary::SessionSummary;
 use crate::output::writer::{MinuteRecord, SessionWriter};
-use crate::zscore::coin_selector::{CoinCandidate, CoinSelector};
+use crate::zscore::allocator::{AllocationCandidate, CapitalAllocator};
+use crate::zscore::coin_params::{self, AdaptiveEstimate, CoinParams, CoinParamsTable};
+use crate::zscore::coin_selector::{
+    CoinCandidate, CoinSelector, MeanReversionScore, attach_mean_reversion_scores,
+};
 use crate::zscore::config::ZScoreConfig;
 use crate::zscore::execution_policy::{
     EntryContext, ExecutionPolicy, ExitContext, SharedResources, TtlExpiryContext, TtlPosition,
 };
 use crate::zscore::instrument::{self, InstrumentCache, fetch_instruments};
+use crate::zscore::kalman;
+use crate::zscore::metrics;
 use crate::zscore::orderbook;
 use crate::zscore::pnl::ClosedPosition;
 use crate::zscore::position::PositionManager;
@@ -48,6 +57,9 @@ use crate::zscore::position::PositionManager;
 use crate::zscore::position::VirtualPosition;
 use crate::zscore::signal::{self, Signal};
 use crate::zscore::spread::SpreadCalculator;
+use crate::zscore::status::StatusBoard;
+use crate::zscore::stops;
+use crate::zscore::trend::TrendParams;
 
 /// 분 완결 시 반환되는 데이터 (코인별 Upbit close, 코인별 Bybit close).
 pub(crate) type MinuteCloses = (
@@ -55,11 +67,16 @@ pub(crate) type MinuteCloses = (
     HashMap<String, Option<Decimal>>,
 );
 
-/// 코인별 현재 분의 캔들 빌더.
+/// 코인별 현재 캔들의 빌더.
+///
+/// 이름은 1분봉 기준이지만 `with_interval`로 지정한 캔들 간격(5분/15분/1시간) 단위로
+/// 캔들 경계를 판단합니다.
 #[derive(Debug)]
 pub(crate) struct MinuteCandleBuilder {
-    /// 현재 분의 시작 시간.
+    /// 현재 캔들의 시작 시간.
     pub current_minute: Option<DateTime<Utc>>,
+    /// 캔들 간격 (분).
+    pub interval_minutes: u32,
     /// 코인별 Upbit 마지막 체결가.
     pub upbit_last_trade: HashMap<String, Decimal>,
     /// 코인별 Bybit best bid.
@@ -70,14 +87,26 @@ impl MinuteCandleBuilder {
     pub fn new() -> Self {
         Self {
             current_minute: None,
+            interval_minutes: 1,
             upbit_last_trade: HashMap::new(),
             bybit_last_bid: HashMap::new(),
         }
     }
 
-    /// 이벤트의 분이 변경되었는지 확인합니다.
+    /// 캔들 간격을 지정합니다.
+    pub fn with_interval(mut self, interval: CandleInterval) -> Self {
+        self.interval_minutes = interval.as_minutes();
+        self
+    }
+
+    /// 이벤트 시각을 캔들 시작 시각으로 정규화합니다.
+    pub fn truncate(&self, ts: DateTime<Utc>) -> DateTime<Utc> {
+        truncate_to_interval(ts, self.interval_minutes)
+    }
+
+    /// 이벤트의 캔들 구간이 변경되었는지 확인합니다.
     pub fn is_new_minute(&self, ts: DateTime<Utc>) -> bool {
-        let minute = truncate_to_minute(ts);
+        let minute = self.truncate(ts);
         match self.current_minute {
             Some(current) => minute > current,
             None => true,
@@ -118,9 +147,9 @@ impl MinuteCandleBuilder {
         (upbit_closes, bybit_closes)
     }
 
-    /// 새 분으로 전환합니다.
+    /// 새 캔들 구간으로 전환합니다.
     pub fn start_new_minute(&mut self, minute: DateTime<Utc>) {
-        self.current_minute = Some(truncate_to_minute(minute));
+        self.current_minute = Some(self.truncate(minute));
         self.upbit_last_trade.clear();
         self.bybit_last_bid.clear();
     }
@@ -230,12 +259,27 @@ pub(crate) struct RegimeChangeResult {
     pub dropped_coins: Vec<String>,
 }
 
+/// 코인 선택 우선순위로 정렬합니다.
+///
+/// half-life 오름차순(평균 회귀가 빠른 순)이 1차 키, stddev 오름차순이 2차 키입니다.
+/// half-life가 없는 코인은 뒤로 가며, `half_lives`가 비어 있으면 stddev 순과 같습니다.
+fn sort_by_selection_rank(coins: &mut [(String, f64)], half_lives: &HashMap<String, f64>) {
+    let half_life = |coin: &str| half_lives.get(coin).copied().unwrap_or(f64::INFINITY);
+    coins.sort_by(|a, b| {
+        half_life(&a.0)
+            .partial_cmp(&half_life(&b.0))
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+    });
+}
+
 /// 워밍업 후 stddev 기준으로 코인을 필터링합니다.
 ///
 /// # 인자
 /// * `coin_stats` - 코인별 (mean, stddev). None이면 워밍업 실패로 제거.
-/// * `max_spread_stddev` - stddev 상한 (0.0이면 필터링 없이 stddev 오름차순 max_coins개 반환)
+/// * `max_spread_stddev` - stddev 상한 (0.0이면 필터링 없이 우선순위 max_coins개 반환)
 /// * `max_coins` - 최대 코인 수
+/// * `half_lives` - 코인별 half-life (캔들 수). 선택 우선순위의 1차 키 ([`sort_by_selection_rank`])
 ///
 /// # 반환값
 /// (유지 코인, 제거 코인)
@@ -243,6 +287,7 @@ pub(crate) fn filter_coins_by_stddev(
     coin_stats: &[(String, Option<(f64, f64)>)],
     max_spread_stddev: f64,
     max_coins: usize,
+    half_lives: &HashMap<String, f64>,
 ) -> (Vec<String>, Vec<String>) {
     // cached_stats=None인 코인 제거
     let mut valid: Vec<(String, f64)> = coin_stats
@@ -257,11 +302,11 @@ pub(crate) fn filter_coins_by_stddev(
         .map(|(coin, _)| coin.clone())
         .collect();
 
-    // stddev 오름차순 정렬
-    valid.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+    // half-life → stddev 우선순위 정렬
+    sort_by_selection_rank(&mut valid, half_lives);
 
     if max_spread_stddev == 0.0 {
-        // 필터링 없이 stddev 오름차순 max_coins개만 반환
+        // 필터링 없이 우선순위 max_coins개만 반환
         let kept: Vec<String> = valid
             .iter()
             .take(max_coins)
@@ -289,11 +334,11 @@ pub(crate) fn filter_coins_by_stddev(
     }
 
     if passed.is_empty() {
-        // Fallback: 전체 초과 시 stddev 오름차순 max_coins개 강제 선택
+        // Fallback: 전체 초과 시 우선순위 max_coins개 강제 선택
         warn!(
             max_spread_stddev = max_spread_stddev,
             total_coins = valid.len(),
-            "모든 코인 stddev 초과, fallback: stddev 오름차순 {}개 강제 선택",
+            "모든 코인 stddev 초과, fallback: 우선순위 {}개 강제 선택",
             max_coins
         );
         let kept: Vec<String> = valid
@@ -327,6 +372,95 @@ pub(crate) fn filter_coins_by_stddev(
     (kept, removed)
 }
 
+/// 워밍업 후 평균 회귀 점수 기준으로 코인을 필터링합니다.
+///
+/// 통과한 코인은 half-life 오름차순(평균 회귀가 빠른 순)으로 정렬하여 반환합니다.
+/// 전체가 기준을 통과하지 못하면 stddev 필터와 동일하게 fallback으로 전체를 유지합니다.
+///
+/// # 인자
+/// * `coin_scores` - 코인별 평균 회귀 점수. None이면 점수 계산 불가로 탈락 처리.
+/// * `max_half_life` - half-life 상한 (캔들 수, 0.0이면 비활성화)
+/// * `require_stationary` - ADF 정상성 검정 통과 필수 여부
+///
+/// # 반환값
+/// (유지 코인, 제거 코인)
+pub(crate) fn filter_coins_by_mean_reversion(
+    coin_scores: &[(String, Option<MeanReversionScore>)],
+    max_half_life: f64,
+    require_stationary: bool,
+) -> (Vec<String>, Vec<String>) {
+    let strength = |score: &Option<MeanReversionScore>| {
+        score.map(|s| s.strength_key()).unwrap_or(f64::INFINITY)
+    };
+
+    let mut sorted: Vec<&(String, Option<MeanReversionScore>)> = coin_scores.iter().collect();
+    sorted.sort_by(|a, b| {
+        strength(&a.1)
+            .partial_cmp(&strength(&b.1))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    if max_half_life == 0.0 && !require_stationary {
+        return (sorted.iter().map(|(c, _)| c.clone()).collect(), Vec::new());
+    }
+
+    let (passed, failed): (Vec<_>, Vec<_>) = sorted.into_iter().partition(|(_, score)| {
+        score
+            .map(|s| s.passes(max_half_life, require_stationary))
+            .unwrap_or(false)
+    });
+
+    if passed.is_empty() {
+        warn!(
+            max_half_life = max_half_life,
+            require_stationary = require_stationary,
+            total_coins = failed.len(),
+            "모든 코인이 평균 회귀 기준 미달, fallback: 전체 유지"
+        );
+        return (failed.iter().map(|(c, _)| c.clone()).collect(), Vec::new());
+    }
+
+    (
+        passed.iter().map(|(c, _)| c.clone()).collect(),
+        failed.iter().map(|(c, _)| c.clone()).collect(),
+    )
+}
+
+/// 추정 half-life 대비 window_size가 가이드라인(3~5배)을 벗어나면 로그를 남깁니다.
+fn log_window_half_life_guideline(coin: &str, half_life: Option<f64>, window_size: usize) {
+    let Some(hl) = half_life else {
+        return;
+    };
+    let recommended_min = hl * 3.0;
+    let recommended_max = hl * 5.0;
+    let window = window_size as f64;
+    if window < recommended_min || window > recommended_max {
+        info!(
+            coin,
+            half_life = format!("{hl:.1}"),
+            window_size,
+            recommended_min = recommended_min.ceil() as u64,
+            recommended_max = recommended_max.floor() as u64,
+            "window_size가 half-life 3~5배 가이드라인을 벗어남"
+        );
+    }
+}
+
+/// 다음 설정 갱신을 기다립니다.
+///
+/// 구독이 없거나 sender가 drop되면 영원히 대기하므로 `select!` 분기가 비활성화됩니다.
+async fn next_config_update(
+    updates: &mut Option<watch::Receiver<Arc<ZScoreConfig>>>,
+) -> Option<Arc<ZScoreConfig>> {
+    if let Some(rx) = updates.as_mut() {
+        if rx.changed().await.is_ok() {
+            return Some(Arc::clone(&rx.borrow_and_update()));
+        }
+        *updates = None;
+    }
+    std::future::pending().await
+}
+
 /// 실시간 Z-Score 모니터.
 ///
 /// `P: ExecutionPolicy`로 시뮬레이션/라이브 체결을 컴파일타임에 결정합니다.
@@ -342,6 +476,12 @@ where
     config: Arc<ZScoreConfig>,
     forex_cache: Arc<ForexCache>,
     policy: Arc<P>,
+    /// 상태 조회 API (선택). `run()` 시작 시 포지션/스프레드 상태를 바인딩합니다.
+    status: Option<StatusBoard>,
+    /// 설정 핫 리로드 구독 (선택). `ConfigReloader`가 라이브 적용 가능한 변경만 보냅니다.
+    config_updates: Option<watch::Receiver<Arc<ZScoreConfig>>>,
+    /// 전략 인스턴스 이름 (메트릭 `instance` 라벨).
+    instance_name: String,
 }
 
 impl<U, B, P> ZScoreMonitor<U, B, P>
@@ -366,9 +506,30 @@ where
             config: Arc::new(config),
             forex_cache,
             policy: Arc::new(policy),
+            status: None,
+            config_updates: None,
+            instance_name: "default".to_string(),
         }
     }
 
+    /// 전략 인스턴스 이름을 지정합니다 (기본값 "default").
+    pub fn with_instance_name(mut self, name: impl Into<String>) -> Self {
+        self.instance_name = name.into();
+        self
+    }
+
+    /// 상태 조회 API에 모니터 내부 상태를 노출합니다.
+    pub fn with_status_board(mut self, board: StatusBoard) -> Self {
+        self.status = Some(board);
+        self
+    }
+
+    /// 설정 핫 리로드를 구독합니다 (`ConfigReloader::new`가 반환한 receiver).
+    pub fn with_config_updates(mut self, updates: watch::Receiver<Arc<ZScoreConfig>>) -> Self {
+        self.config_updates = Some(updates);
+        self
+    }
+
     /// 실시간 모니터링을 시작합니다.
     ///
     /// CancellationToken이 cancel되면 graceful shutdown합니다.
@@ -417,34 +578,35 @@ where
         });
 
         // 1. 코인 목록 결정
-        let mut current_coins: Vec<String> = if self.config.auto_select {
-            info!("자동 코인 선택 활성화: 초기 코인 선택 중...");
-            let selector = CoinSelector::new(self.upbit.as_ref(), self.bybit.as_ref());
-            let usd_krw_for_select = self.forex_cache.get_cached_rate().unwrap_or(0.0);
-            // 확대 선택: stddev 필터 + pruning 여유분 확보
-            let expanded_count = self.config.max_coins * 2;
-            let candidates = selector
-                .select(
-                    expanded_count,
-                    self.config.min_volume_1h_usdt,
-                    &self.config.blacklist,
-                    usd_krw_for_select,
-                )
-                .await?;
-
-            if candidates.is_empty() {
-                warn!("자동 선택 결과 후보 코인이 없습니다. 볼륨 조건을 확인하세요.");
-            }
+        let (mut current_coins, mut selected_candidates): (Vec<String>, Vec<CoinCandidate>) =
+            if self.config.auto_select {
+                info!("자동 코인 선택 활성화: 초기 코인 선택 중...");
+                let selector = CoinSelector::new(self.upbit.as_ref(), self.bybit.as_ref());
+                let usd_krw_for_select = self.forex_cache.get_cached_rate().unwrap_or(0.0);
+                // 확대 선택: stddev 필터 + pruning 여유분 확보
+                let expanded_count = self.config.max_coins * 2;
+                let candidates = selector
+                    .select(
+                        expanded_count,
+                        self.config.min_volume_1h_usdt,
+                        &self.config.blacklist,
+                        usd_krw_for_select,
+                    )
+                    .await?;
+
+                if candidates.is_empty() {
+                    warn!("자동 선택 결과 후보 코인이 없습니다. 볼륨 조건을 확인하세요.");
+                }
 
-            let mut expanded_coins: Vec<String> =
-                candidates.iter().map(|c| c.coin.clone()).collect();
-            expanded_coins.sort();
-            expanded_coins.dedup();
-            info!(coins = ?expanded_coins, count = expanded_coins.len(), "확대 후보 코인 선택 완료");
-            expanded_coins
-        } else {
-            self.config.coins.clone()
-        };
+                let mut expanded_coins: Vec<String> =
+                    candidates.iter().map(|c| c.coin.clone()).collect();
+                expanded_coins.sort();
+                expanded_coins.dedup();
+                info!(coins = ?expanded_coins, count = expanded_coins.len(), "확대 후보 코인 선택 완료");
+                (expanded_coins, candidates)
+            } else {
+                (self.config.coins.clone(), Vec::new())
+            };
 
         // 시작 시점 환율 기록
         let usd_krw_start = self.forex_cache.get_cached_rate().unwrap_or(0.0);
@@ -454,7 +616,11 @@ where
         // 2. 워밍업: REST API로 캔들 사전 로드 (로컬 변수)
         let mut spread_calc_local = if self.config.auto_select {
             // auto_select: 빈 상태로 생성 (warmup_single_coin_standalone이 add_coin 호출)
-            let mut sc = SpreadCalculator::new(&[], self.config.window_size);
+            let mut sc = SpreadCalculator::new(&[], self.config.window_size)
+                .with_candle_interval(self.config.candle_interval.as_minutes())
+                .with_stats_mode(self.config.spread_stats_mode())
+                .with_kalman(self.config.kalman_params())
+                .with_trend(self.config.trend_params());
             for coin in &current_coins {
                 if let Err(e) = Self::warmup_single_coin_standalone(
                     self.upbit.as_ref(),
@@ -473,7 +639,12 @@ where
             sc
         } else {
             // 수동 선택: 기존 warmup 유지 (실패 시 즉시 에러)
-            let mut sc = SpreadCalculator::new(&current_coins, self.config.window_size);
+            // 코인별 윈도우 크기는 warmup에서 add_coin_with_window로 적용
+            let mut sc = SpreadCalculator::new(&[], self.config.window_size)
+                .with_candle_interval(self.config.candle_interval.as_minutes())
+                .with_stats_mode(self.config.spread_stats_mode())
+                .with_kalman(self.config.kalman_params())
+                .with_trend(self.config.trend_params());
             Self::warmup(
                 self.upbit.as_ref(),
                 self.bybit.as_ref(),
@@ -486,6 +657,49 @@ where
             sc
         };
 
+        // auto_select: 평균 회귀 점수 계산 + 필터링 (stddev 필터 이전)
+        let mut mean_reversion_rejected = 0u64;
+        if self.config.auto_select {
+            selected_candidates.retain(|c| current_coins.contains(&c.coin));
+            attach_mean_reversion_scores(
+                &mut selected_candidates,
+                &spread_calc_local,
+                self.config.adf_lags,
+                self.config.stationarity_significance,
+            );
+
+            let coin_scores: Vec<(String, Option<MeanReversionScore>)> = selected_candidates
+                .iter()
+                .map(|c| (c.coin.clone(), c.mean_reversion))
+                .collect();
+            for (coin, score) in &coin_scores {
+                log_window_half_life_guideline(
+                    coin,
+                    score.and_then(|s| s.half_life),
+                    self.config.coin_params(coin).window_size,
+                );
+            }
+
+            let (kept, removed) = filter_coins_by_mean_reversion(
+                &coin_scores,
+                self.config.max_half_life_candles,
+                self.config.require_stationary_spread,
+            );
+            for coin in &removed {
+                spread_calc_local.remove_coin(coin);
+                debug!(coin = coin.as_str(), "평균 회귀 필터: 코인 제거");
+            }
+            if !removed.is_empty() {
+                mean_reversion_rejected = removed.len() as u64;
+                info!(
+                    kept = ?kept,
+                    removed = ?removed,
+                    "평균 회귀 필터 적용"
+                );
+            }
+            current_coins = kept;
+        }
+
         // auto_select: stddev 필터링
         if self.config.auto_select && self.config.max_spread_stddev >= 0.0 {
             let coin_stats: Vec<(String, Option<(f64, f64)>)> = current_coins
@@ -493,10 +707,19 @@ where
                 .map(|c| (c.clone(), spread_calc_local.cached_stats(c)))
                 .collect();
 
+            let half_lives: HashMap<String, f64> = selected_candidates
+                .iter()
+                .filter_map(|c| {
+                    c.mean_reversion
+                        .and_then(|s| s.half_life)
+                        .map(|hl| (c.coin.clone(), hl))
+                })
+                .collect();
             let (kept, removed) = filter_coins_by_stddev(
                 &coin_stats,
                 self.config.max_spread_stddev,
                 self.config.max_coins,
+                &half_lives,
             );
 
             if kept.is_empty() {
@@ -539,6 +762,9 @@ where
         let instrument_cache = Arc::new(parking_lot::RwLock::new(InstrumentCache::default()));
         fetch_instruments(self.bybit.as_ref(), &instrument_cache, &current_coins).await;
 
+        // 코인별 파라미터 테이블 (오버라이드 + adaptive 조정값)
+        let coin_params = Arc::new(parking_lot::RwLock::new(CoinParamsTable::new(&self.config)));
+
         // 워밍업 완료 후 요약 레코드 생성 및 기록
         let mut minute_records: Vec<MinuteRecord> = Vec::new();
         {
@@ -546,6 +772,7 @@ where
                 &spread_calc_local,
                 &current_coins,
                 &self.config,
+                &coin_params.read(),
                 &self.forex_cache,
             );
             minute_records.extend(warmup_records.iter().cloned());
@@ -553,7 +780,10 @@ where
 
         // OrderBookCache 로컬 변수로 프리페치
         let mut ob_cache_local = orderbook::OrderBookCache::new();
-        let mut counters_local = MonitoringCounters::default();
+        let mut counters_local = MonitoringCounters {
+            coin_rejected_mean_reversion_count: mean_reversion_rejected,
+            ..MonitoringCounters::default()
+        };
 
         // 워밍업 완료 후 오더북 프리페치
         for coin in &current_coins {
@@ -591,6 +821,7 @@ where
 
         // 4. 공유 상태를 Arc로 래핑
         let position_mgr = Arc::new(tokio::sync::Mutex::new(PositionManager::new()));
+        let allocator = Arc::new(CapitalAllocator::new());
         let trades = Arc::new(tokio::sync::Mutex::new(Vec::<ClosedPosition>::new()));
         let ob_cache = orderbook::SharedObCache::new();
         // 프리페치 데이터를 SharedObCache에 복사
@@ -605,8 +836,12 @@ where
             }
         }
         let counters = Arc::new(parking_lot::Mutex::new(counters_local));
+        metrics::register_monitoring_counters(&self.instance_name, &counters);
         let session_writer = Arc::new(tokio::sync::Mutex::new(session_writer_local));
         let spread_calc = Arc::new(tokio::sync::RwLock::new(spread_calc_local));
+        if let Some(board) = &self.status {
+            board.bind_monitor(Arc::clone(&position_mgr), Arc::clone(&spread_calc));
+        }
         let total_event_count = Arc::new(AtomicU64::new(0));
 
         // ExecutionPolicy에 공유 상태 바인딩 (SimPolicy: OnceLock 설정, LivePolicy: no-op)
@@ -631,7 +866,8 @@ where
         }
 
         // 이벤트 루프용 로컬 변수
-        let mut candle_builder = MinuteCandleBuilder::new();
+        let mut candle_builder =
+            MinuteCandleBuilder::new().with_interval(self.config.candle_interval);
         let mut minute_timer = tokio::time::interval(Duration::from_secs(60));
 
         // heartbeat 관련 상태
@@ -645,6 +881,13 @@ where
         // 첫 tick 소모 (interval은 즉시 첫 번째 tick을 발생시킴)
         reselect_timer.tick().await;
 
+        // adaptive 파라미터 재추정 타이머 (adaptive_params=true일 때만 사용)
+        let adaptive_interval = Duration::from_secs(self.config.adaptive_interval_min.max(1) * 60);
+        let mut adaptive_timer = tokio::time::interval(adaptive_interval);
+        adaptive_timer.tick().await;
+        // adaptive 재추정 중복 실행 방지 guard (CAS 패턴)
+        let adaptive_running = Arc::new(AtomicBool::new(false));
+
         // 탈락 코인 TTL 추적: 탈락 시각 기록
         let mut dropped_at: HashMap<String, DateTime<Utc>> = HashMap::new();
 
@@ -657,12 +900,27 @@ where
         let mut consecutive_regime_changes: u32 = 0;
         const MAX_COOLDOWN_MIN: u64 = 60;
 
+        // 핫 리로드로 교체되는 설정 (루프 안에서는 self.config 대신 사용)
+        let mut config = Arc::clone(&self.config);
+        let mut config_updates = self.config_updates.clone();
+
         loop {
             tokio::select! {
                 _ = cancel_token.cancelled() => {
                     info!("종료 요청 수신. 모니터링 종료 중...");
                     break;
                 }
+                Some(updated) = next_config_update(&mut config_updates) => {
+                    // 이후 spawn되는 틱/재선택/TTL 처리부터 새 설정 사용
+                    coin_params.write().rebase(&updated);
+                    config = updated;
+                    info!(
+                        entry_z = config.entry_z_threshold,
+                        exit_z = config.exit_z_threshold,
+                        max_coins = config.max_coins,
+                        "전략 설정 핫 리로드 반영"
+                    );
+                }
                 Some(event) = upbit_rx.recv() => {
                     total_event_count.fetch_add(1, Ordering::Relaxed);
                     // 캔들 업데이트 (가벼운 동기 작업)
@@ -670,7 +928,7 @@ where
                         &event,
                         &mut candle_builder,
                         &spread_calc,
-                        &self.config,
+                        &config,
                         &current_coins,
                         &self.forex_cache,
                         &session_writer,
@@ -678,6 +936,7 @@ where
                         &position_mgr,
                         &trades,
                         &instrument_cache,
+                        &coin_params,
                         &counters,
                         &self.policy,
                     ).await;
@@ -687,7 +946,7 @@ where
                         &current_coins,
                         &candle_builder,
                         &spread_calc,
-                        &self.config,
+                        &config,
                         &self.forex_cache,
                         &position_mgr,
                         &ob_cache,
@@ -695,6 +954,8 @@ where
                         &self.upbit,
                         &self.bybit,
                         &instrument_cache,
+                        &coin_params,
+                        &allocator,
                         &self.policy,
                     ).await;
                 }
@@ -705,7 +966,7 @@ where
                         &event,
                         &mut candle_builder,
                         &spread_calc,
-                        &self.config,
+                        &config,
                         &current_coins,
                         &self.forex_cache,
                         &session_writer,
@@ -713,6 +974,7 @@ where
                         &position_mgr,
                         &trades,
                         &instrument_cache,
+                        &coin_params,
                         &counters,
                         &self.policy,
                     ).await;
@@ -722,7 +984,7 @@ where
                         &current_coins,
                         &candle_builder,
                         &spread_calc,
-                        &self.config,
+                        &config,
                         &self.forex_cache,
                         &position_mgr,
                         &ob_cache,
@@ -730,6 +992,8 @@ where
                         &self.upbit,
                         &self.bybit,
                         &instrument_cache,
+                        &coin_params,
+                        &allocator,
                         &self.policy,
                     ).await;
                 }
@@ -737,7 +1001,7 @@ where
                     let now = Utc::now();
                     if candle_builder.is_new_minute(now) {
                         match Self::finalize_and_process(
-                            &self.config,
+                            &config,
                             &mut candle_builder,
                             &spread_calc,
                             &position_mgr,
@@ -748,6 +1012,7 @@ where
                             &session_writer,
                             &mut minute_records,
                             &instrument_cache,
+                            &coin_params,
                             &counters,
                             &self.policy,
                         ).await {
@@ -770,6 +1035,7 @@ where
                                             data.remove_coin(coin);
                                         }
                                         ob_cache.computing.remove_coin(coin);
+                                        coin_params.write().reset_coin(coin);
                                         let upbit_market = format!("KRW-{coin}");
                                         let bybit_market = format!("{coin}USDT");
                                         self.upbit.unsubscribe_markets(&[&upbit_market]).await.ok();
@@ -781,13 +1047,13 @@ where
                                         dropped_at.entry(coin.clone()).or_insert(Utc::now());
                                     }
                                     counters.lock().regime_change_detected_count += 1;
-                                    if current_coins.len() < self.config.max_coins
-                                        && self.config.auto_select
+                                    if current_coins.len() < config.max_coins
+                                        && config.auto_select
                                         && !reselecting
                                     {
                                         reselecting = true;
                                         consecutive_regime_changes += 1;
-                                        let backoff_min = (self.config.reselect_interval_min)
+                                        let backoff_min = (config.reselect_interval_min)
                                             .saturating_mul(
                                                 1u64 << (consecutive_regime_changes - 1).min(6),
                                             )
@@ -802,7 +1068,7 @@ where
                                             "regime change → 즉시 재선택 (지수적 백오프 cooldown)"
                                         );
                                         Self::spawn_reselection(
-                                            Arc::clone(&self.config),
+                                            Arc::clone(&config),
                                             Arc::clone(&self.upbit),
                                             Arc::clone(&self.bybit),
                                             Arc::clone(&self.forex_cache),
@@ -836,7 +1102,7 @@ where
 
                     // TTL 만료 포지션 체크 (모든 코인)
                     if let Err(e) = Self::check_ttl_positions(
-                        &self.config,
+                        &config,
                         &position_mgr,
                         &spread_calc,
                         &counters,
@@ -848,11 +1114,11 @@ where
                         warn!(error = %e, "check_ttl_positions 실패");
                     }
                 }
-                _ = reselect_timer.tick(), if self.config.auto_select && !reselecting => {
+                _ = reselect_timer.tick(), if config.auto_select && !reselecting => {
                     reselecting = true;
                     info!("코인 재선택 시작...");
                     Self::spawn_reselection(
-                        Arc::clone(&self.config),
+                        Arc::clone(&config),
                         Arc::clone(&self.upbit),
                         Arc::clone(&self.bybit),
                         Arc::clone(&self.forex_cache),
@@ -865,6 +1131,28 @@ where
                         reselect_tx.clone(),
                     );
                 }
+                _ = adaptive_timer.tick(), if config.adaptive_params => {
+                    if adaptive_running
+                        .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+                        .is_ok()
+                    {
+                        info!("adaptive 파라미터 재추정 시작...");
+                        Self::spawn_adaptive_update(
+                            Arc::clone(&config),
+                            Arc::clone(&self.upbit),
+                            Arc::clone(&self.bybit),
+                            Arc::clone(&self.forex_cache),
+                            Arc::clone(&spread_calc),
+                            Arc::clone(&coin_params),
+                            Arc::clone(&counters),
+                            Arc::clone(&position_mgr),
+                            current_coins.clone(),
+                            Arc::clone(&adaptive_running),
+                        );
+                    } else {
+                        warn!("adaptive 재추정 이전 작업 진행 중 — 스킵");
+                    }
+                }
                 Some(result) = reselect_rx.recv() => {
                     current_coins = result.new_coins.clone();
                     for (coin, ts) in result.dropped_at_updates {
@@ -872,6 +1160,7 @@ where
                     }
                     for coin in &result.removed_coins {
                         dropped_at.remove(coin);
+                        coin_params.write().reset_coin(coin);
                     }
                     // 새 코인에 대한 InstrumentInfo 로드
                     let new_coins_to_fetch: Vec<String> = {
@@ -1008,14 +1297,19 @@ where
         spread_calc: &mut SpreadCalculator,
     ) -> Result<(), StrategyError> {
         let end_time = Utc::now();
-        let window_size = config.window_size;
 
-        // SpreadCalculator에 코인이 없으면 추가 (idempotent)
-        spread_calc.add_coin(coin);
+        // SpreadCalculator에 코인이 없으면 코인별 윈도우 크기로 추가 (idempotent)
+        spread_calc.add_coin_with_window(coin, config.coin_params(coin).window_size);
+        let window_size = spread_calc
+            .coin_window_size(coin)
+            .unwrap_or(config.window_size);
+        // EWMA 모드는 윈도우 전체가 아닌 half-life 배수만 조회
+        let fetch_count = config.warmup_candle_count(window_size);
 
         debug!(
             coin = coin,
             window_size = window_size,
+            fetch_count = fetch_count,
             "워밍업 시작: 캔들 데이터 로드"
         );
 
@@ -1026,7 +1320,7 @@ where
             upbit,
             &upbit_market,
             config.candle_interval,
-            window_size,
+            fetch_count,
             end_time,
             200,
             Duration::from_millis(100),
@@ -1037,15 +1331,16 @@ where
             bybit,
             &bybit_market,
             config.candle_interval,
-            window_size,
+            fetch_count,
             end_time,
             1000,
             Duration::from_millis(10),
         )
         .await?;
 
-        // ForexCache에서 일봉 환율 조회
-        let warmup_days = (window_size as i64 / (24 * 60)) + 2; // 여유 2일
+        // ForexCache에서 일봉 환율 조회 (윈도우 기간 = 캔들 수 × 캔들 간격)
+        let interval_min = config.candle_interval.as_minutes();
+        let warmup_days = (fetch_count as i64 * i64::from(interval_min) / (24 * 60)) + 2; // 여유 2일
         let from = end_time - chrono::Duration::days(warmup_days.max(2));
         let daily_rates = forex_cache
             .get_daily_rates(from, end_time)
@@ -1060,14 +1355,14 @@ where
             "워밍업 데이터 로드"
         );
 
-        // 분 단위로 타임스탬프 정규화 (Upbit 초정밀도 / Bybit 밀리초 정밀도 정렬)
+        // 캔들 간격 단위로 타임스탬프 정규화 (Upbit 초정밀도 / Bybit 밀리초 정밀도 정렬)
         let upbit_map: std::collections::HashMap<DateTime<Utc>, Decimal> = upbit_candles
             .iter()
-            .map(|c| (truncate_to_minute(c.timestamp), c.close))
+            .map(|c| (truncate_to_interval(c.timestamp, interval_min), c.close))
             .collect();
         let bybit_map: std::collections::HashMap<DateTime<Utc>, Decimal> = bybit_candles
             .iter()
-            .map(|c| (truncate_to_minute(c.timestamp), c.close))
+            .map(|c| (truncate_to_interval(c.timestamp, interval_min), c.close))
             .collect();
 
         // 일봉 환율을 날짜별 맵으로 변환
@@ -1132,6 +1427,15 @@ where
             )?;
         }
 
+        // 추세 필터: 상위 타임프레임 캔들로 별도 워밍업 (실패해도 필터 미준비로 진행)
+        if let Some(params) = spread_calc.trend_params() {
+            match Self::fetch_trend_closes(upbit, bybit, forex_cache, coin, params, end_time).await
+            {
+                Ok(closes) => spread_calc.seed_trend(coin, &closes),
+                Err(e) => warn!(coin = coin, error = %e, "추세 필터 워밍업 실패"),
+            }
+        }
+
         debug!(
             coin = coin,
             is_ready = spread_calc.is_ready(coin),
@@ -1153,17 +1457,20 @@ where
         spread_calc: &tokio::sync::RwLock<SpreadCalculator>,
     ) -> Result<(), StrategyError> {
         let end_time = Utc::now();
-        let window_size = config.window_size;
 
         // SpreadCalculator에 코인 추가 (write lock)
-        {
+        let window_size = {
             let mut sc = spread_calc.write().await;
-            sc.add_coin(coin);
-        }
+            sc.add_coin_with_window(coin, config.coin_params(coin).window_size);
+            sc.coin_window_size(coin).unwrap_or(config.window_size)
+        };
+        // EWMA 모드는 윈도우 전체가 아닌 half-life 배수만 조회
+        let fetch_count = config.warmup_candle_count(window_size);
 
         debug!(
             coin = coin,
             window_size = window_size,
+            fetch_count = fetch_count,
             "워밍업 시작: 캔들 데이터 로드"
         );
 
@@ -1174,7 +1481,7 @@ where
             upbit,
             &upbit_market,
             config.candle_interval,
-            window_size,
+            fetch_count,
             end_time,
             200,
             Duration::from_millis(100),
@@ -1185,7 +1492,7 @@ where
             bybit,
             &bybit_market,
             config.candle_interval,
-            window_size,
+            fetch_count,
             end_time,
             1000,
             Duration::from_millis(10),
@@ -1193,7 +1500,8 @@ where
         .await?;
 
         // ForexCache에서 일봉 환율 조회
-        let warmup_days = (window_size as i64 / (24 * 60)) + 2;
+        let interval_min = config.candle_interval.as_minutes();
+        let warmup_days = (fetch_count as i64 * i64::from(interval_min) / (24 * 60)) + 2;
         let from = end_time - chrono::Duration::days(warmup_days.max(2));
         let daily_rates = forex_cache
             .get_daily_rates(from, end_time)
@@ -1210,11 +1518,11 @@ where
 
         let upbit_map: std::collections::HashMap<DateTime<Utc>, Decimal> = upbit_candles
             .iter()
-            .map(|c| (truncate_to_minute(c.timestamp), c.close))
+            .map(|c| (truncate_to_interval(c.timestamp, interval_min), c.close))
             .collect();
         let bybit_map: std::collections::HashMap<DateTime<Utc>, Decimal> = bybit_candles
             .iter()
-            .map(|c| (truncate_to_minute(c.timestamp), c.close))
+            .map(|c| (truncate_to_interval(c.timestamp, interval_min), c.close))
             .collect();
 
         let daily_rate_map: std::collections::HashMap<chrono::NaiveDate, f64> = daily_rates
@@ -1262,11 +1570,103 @@ where
             )?;
         }
 
+        let trend_params = sc.trend_params();
         debug!(coin = coin, is_ready = sc.is_ready(coin), "워밍업 완료");
+        drop(sc);
+
+        // 추세 필터 워밍업 (조회 중에는 write lock 해제)
+        if let Some(params) = trend_params {
+            match Self::fetch_trend_closes(upbit, bybit, forex_cache, coin, params, end_time).await
+            {
+                Ok(closes) => spread_calc.write().await.seed_trend(coin, &closes),
+                Err(e) => warn!(coin = coin, error = %e, "추세 필터 워밍업 실패"),
+            }
+        }
 
         Ok(())
     }
 
+    /// 추세 필터 워밍업용 상위 타임프레임 스프레드 close를 조회합니다.
+    ///
+    /// `get_candles_before` 페이지네이션으로 `params.window`개 이상을 수집하고,
+    /// 진행 중인(미완결) 상위 캔들은 제외합니다. 환율은 일봉을 날짜별로 forward-fill합니다.
+    async fn fetch_trend_closes(
+        upbit: &U,
+        bybit: &B,
+        forex_cache: &ForexCache,
+        coin: &str,
+        params: TrendParams,
+        end_time: DateTime<Utc>,
+    ) -> Result<Vec<f64>, StrategyError> {
+        let interval_min = params.interval.as_minutes();
+        let count = params.window + 1;
+
+        let upbit_candles = fetch_all_candles(
+            upbit,
+            &format!("KRW-{coin}"),
+            params.interval,
+            count,
+            end_time,
+            200,
+            Duration::from_millis(100),
+        )
+        .await?;
+        let bybit_candles = fetch_all_candles(
+            bybit,
+            &format!("{coin}USDT"),
+            params.interval,
+            count,
+            end_time,
+            1000,
+            Duration::from_millis(10),
+        )
+        .await?;
+
+        let days = (count as i64 * i64::from(interval_min) / (24 * 60)) + 2;
+        let daily_rates = forex_cache
+            .get_daily_rates(end_time - chrono::Duration::days(days), end_time)
+            .await
+            .map_err(|e| StrategyError::DataAlignment(format!("Forex warmup failed: {e}")))?;
+        let daily_rate_map: std::collections::HashMap<chrono::NaiveDate, f64> = daily_rates
+            .iter()
+            .map(|(dt, rate)| (dt.date_naive(), *rate))
+            .collect();
+        let bybit_map: std::collections::HashMap<DateTime<Utc>, Decimal> = bybit_candles
+            .iter()
+            .map(|c| (truncate_to_interval(c.timestamp, interval_min), c.close))
+            .collect();
+
+        let current_bucket = truncate_to_interval(end_time, interval_min);
+        let mut last_usd_krw: f64 = daily_rates.last().map(|(_, r)| *r).unwrap_or(0.0);
+        let mut closes = Vec::with_capacity(upbit_candles.len());
+        for candle in &upbit_candles {
+            let ts = truncate_to_interval(candle.timestamp, interval_min);
+            if ts >= current_bucket {
+                continue;
+            }
+            if let Some(&rate) = daily_rate_map.get(&ts.date_naive()) {
+                last_usd_krw = rate;
+            }
+            let Some(bybit_close) = bybit_map.get(&ts) else {
+                continue;
+            };
+            let upbit_usd = candle.close.to_f64().unwrap_or(0.0) / last_usd_krw;
+            let bybit_f64 = bybit_close.to_f64().unwrap_or(0.0);
+            if !upbit_usd.is_finite() || upbit_usd <= 0.0 || bybit_f64 <= 0.0 {
+                continue;
+            }
+            closes.push((bybit_f64 - upbit_usd) / upbit_usd * 100.0);
+        }
+
+        debug!(
+            coin = coin,
+            interval_min = interval_min,
+            closes = closes.len(),
+            "추세 필터 상위 캔들 로드"
+        );
+        Ok(closes)
+    }
+
     /// 이벤트에서 캔들 업데이트 + 분 경계 처리를 수행합니다 (가벼운 동기 작업).
     ///
     /// `candle_builder`는 select! 루프 로컬 변수로 유지합니다.
@@ -1284,6 +1684,7 @@ where
         position_mgr: &Arc<tokio::sync::Mutex<PositionManager>>,
         trades: &Arc<tokio::sync::Mutex<Vec<ClosedPosition>>>,
         instrument_cache: &Arc<parking_lot::RwLock<InstrumentCache>>,
+        coin_params: &Arc<parking_lot::RwLock<CoinParamsTable>>,
         counters: &Arc<parking_lot::Mutex<MonitoringCounters>>,
         policy: &Arc<P>,
     ) {
@@ -1297,7 +1698,7 @@ where
             if candle_builder.current_minute.is_some() {
                 debug!(
                     prev_minute = ?candle_builder.current_minute,
-                    new_minute = %truncate_to_minute(event_ts),
+                    new_minute = %candle_builder.truncate(event_ts),
                     "분 경계 변경 감지: 이전 분 완결 처리"
                 );
                 // regime change 결과는 update_candle_and_spread 경로에서 무시
@@ -1313,6 +1714,7 @@ where
                     session_writer,
                     minute_records,
                     instrument_cache,
+                    coin_params,
                     counters,
                     policy,
                 )
@@ -1353,8 +1755,13 @@ where
         upbit_client: &Arc<U>,
         bybit_client: &Arc<B>,
         instrument_cache: &Arc<parking_lot::RwLock<InstrumentCache>>,
+        coin_params: &Arc<parking_lot::RwLock<CoinParamsTable>>,
+        allocator: &Arc<CapitalAllocator>,
         policy: &Arc<P>,
     ) {
+        // 틱→시그널 지연 측정 기준 시각
+        let tick_received_at = std::time::Instant::now();
+
         // 1. 이벤트에서 코인 및 소스 거래소 추출
         let (coin, source_exchange) = match event {
             MarketEvent::Trade { market, .. } => {
@@ -1395,13 +1802,28 @@ where
         let current_spread = (bybit_f64 - upbit_usd) / upbit_usd * 100.0;
 
         // 3. spread_calc read lock -> cached_stats Copy
-        let (mean, stddev) = {
+        // Kalman 헤지 모델이면 잔차 기반 (fair spread, stddev)로 대체
+        // 상위 타임프레임 추세가 진입 방향과 반대이면 t-통계량 보관 (진입 시에만 거부)
+        let (mean, stddev, hedge_ratio, adverse_trend_t) = {
             let sc = spread_calc.read().await;
-            match sc.cached_stats(&coin) {
-                Some(s) => s,
-                None => return,
+            let Some((mean, stddev)) = sc.cached_stats(&coin) else {
+                return;
+            };
+            let adverse_trend_t = sc
+                .trend(&coin)
+                .filter(|tf| tf.is_adverse())
+                .and_then(|tf| tf.t_stat());
+            match sc.kalman(&coin) {
+                Some(kf) => match kf.tick_stats(upbit_usd, bybit_f64) {
+                    Some((fair, resid_stddev)) => {
+                        (fair, resid_stddev, kf.hedge_ratio(), adverse_trend_t)
+                    }
+                    None => return,
+                },
+                None => (mean, stddev, 1.0, adverse_trend_t),
             }
         };
+        let params = coin_params.read().get(&coin);
 
         trace!(
             coin = coin.as_str(),
@@ -1413,13 +1835,15 @@ where
             } else {
                 0.0
             },
+            hedge_ratio = hedge_ratio,
             "틱 시그널 평가 입력"
         );
 
         // 4. computing flag check-and-set (atomic CAS)
         // 양쪽 거래소 모두 체크하여 같은 코인의 동시 spawn 방지
+        // Bithumb은 Z-Score 모니터에서 Upbit와 같은 KRW 현물 레그(U 클라이언트)로 취급
         let other_exchange = match source_exchange {
-            orderbook::Exchange::Upbit => orderbook::Exchange::Bybit,
+            orderbook::Exchange::Upbit | orderbook::Exchange::Bithumb => orderbook::Exchange::Bybit,
             orderbook::Exchange::Bybit => orderbook::Exchange::Upbit,
         };
         if ob_cache.computing.try_set_computing(source_exchange, &coin) {
@@ -1445,6 +1869,7 @@ where
         let upbit_client = Arc::clone(upbit_client);
         let bybit_client = Arc::clone(bybit_client);
         let instrument_cache = Arc::clone(instrument_cache);
+        let allocator = Arc::clone(allocator);
         let policy = Arc::clone(policy);
 
         tokio::spawn(async move {
@@ -1457,13 +1882,18 @@ where
                 current_spread,
                 mean,
                 stddev,
+                params,
+                hedge_ratio,
+                adverse_trend_t,
                 source_exchange,
+                tick_received_at,
                 position_mgr,
                 ob_cache.clone(),
                 counters,
                 upbit_client,
                 bybit_client,
                 instrument_cache,
+                allocator,
                 policy,
             )
             .await;
@@ -1502,32 +1932,40 @@ where
         current_spread: f64,
         mean: f64,
         stddev: f64,
+        params: CoinParams,
+        hedge_ratio: f64,
+        adverse_trend_t: Option<f64>,
         source_exchange: orderbook::Exchange,
+        tick_received_at: std::time::Instant,
         position_mgr: Arc<tokio::sync::Mutex<PositionManager>>,
         ob_cache: orderbook::SharedObCache,
         counters: Arc<parking_lot::Mutex<MonitoringCounters>>,
         upbit_client: Arc<U>,
         bybit_client: Arc<B>,
         instrument_cache: Arc<parking_lot::RwLock<InstrumentCache>>,
+        allocator: Arc<CapitalAllocator>,
         policy: Arc<P>,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        // Bithumb은 Z-Score 모니터에서 Upbit와 같은 KRW 현물 레그(U 클라이언트)로 취급
         let other_exchange = match source_exchange {
-            orderbook::Exchange::Upbit => orderbook::Exchange::Bybit,
+            orderbook::Exchange::Upbit | orderbook::Exchange::Bithumb => orderbook::Exchange::Bybit,
             orderbook::Exchange::Bybit => orderbook::Exchange::Upbit,
         };
 
         // 소스 거래소 오더북 REST 조회
         let market = match source_exchange {
-            orderbook::Exchange::Upbit => format!("KRW-{coin}"),
+            orderbook::Exchange::Upbit | orderbook::Exchange::Bithumb => format!("KRW-{coin}"),
             orderbook::Exchange::Bybit => format!("{coin}USDT"),
         };
         let depth = match source_exchange {
-            orderbook::Exchange::Upbit => Some(15),
+            orderbook::Exchange::Upbit | orderbook::Exchange::Bithumb => Some(15),
             orderbook::Exchange::Bybit => Some(25),
         };
 
         let ob_result = match source_exchange {
-            orderbook::Exchange::Upbit => upbit_client.get_orderbook(&market, depth).await,
+            orderbook::Exchange::Upbit | orderbook::Exchange::Bithumb => {
+                upbit_client.get_orderbook(&market, depth).await
+            }
             orderbook::Exchange::Bybit => bybit_client.get_orderbook(&market, depth).await,
         };
 
@@ -1554,23 +1992,92 @@ where
             }
         }
 
-        // USD 환산된 Upbit 가격
-        let upbit_f64 = upbit_price.to_f64().unwrap_or(0.0);
-        let upbit_usd = upbit_f64 / usd_krw;
-        let upbit_usd_dec = Decimal::try_from(upbit_usd).unwrap_or(Decimal::ZERO);
-
         // 청산/진입 공통: InstrumentInfo 조회
         let inst_info = {
             let cache = instrument_cache.read();
             cache.get(&coin).cloned()
         };
 
-        // 4. 청산 시그널 평가 (exit-first)
+        // 4. 손절 규칙 평가 (포지션별, z-score 청산보다 먼저)
+        if config.stop_loss.enabled {
+            let z =
+                statistics::z_score(current_spread, mean, stddev, config.min_stddev_threshold).ok();
+            let fee_pct = roundtrip_fee_pct(config.upbit_taker_fee, config.bybit_taker_fee)
+                .to_f64()
+                .unwrap_or(0.0);
+            let triggers = {
+                let mut pm = position_mgr.lock().await;
+                stops::check_stops(
+                    &mut pm,
+                    &coin,
+                    current_spread,
+                    z,
+                    fee_pct,
+                    &config.stop_loss,
+                )
+            };
+
+            if !triggers.is_empty() {
+                for t in &triggers {
+                    info!(
+                        coin = coin.as_str(),
+                        position_id = t.position_id,
+                        reason = t.reason.as_str(),
+                        unrealized_pnl_pct = t.unrealized_pnl_pct,
+                        z_score = ?z,
+                        spread_pct = current_spread,
+                        "[틱] 손절 시그널"
+                    );
+                }
+                counters.lock().stop_loss_exit_count += triggers.len() as u64;
+
+                if let Some(mut exit_ctx) = Self::build_exit_context(
+                    true,
+                    &coin,
+                    z.unwrap_or(0.0),
+                    current_spread,
+                    upbit_price,
+                    bybit_price,
+                    usd_krw,
+                    mean,
+                    &inst_info,
+                    &ob_cache,
+                    &counters,
+                    &config,
+                )
+                .await
+                {
+                    exit_ctx.position_ids = Some(triggers.iter().map(|t| t.position_id).collect());
+                    metrics::observe_tick_to_signal("stop", tick_received_at.elapsed());
+                    if let Err(e) = policy.on_exit_signal(exit_ctx).await {
+                        warn!(coin = coin.as_str(), error = %e, "손절 청산 정책 실행 실패");
+                    }
+                }
+            }
+        }
+
+        // 5. 청산 시그널 평가 (exit-first)
         {
             let pm = position_mgr.lock().await;
             let has_positions = pm.has_position(&coin);
+            let held_tiers = pm.held_tiers(&coin);
             drop(pm);
 
+            // 단계별 청산: 보유 단계 중 가장 높은 청산 임계값으로 시그널을 평가하고,
+            // 실제 청산 대상은 조건을 만족한 단계로 한정
+            let per_tier_exit = config.scale_in.enabled && config.scale_in.is_per_tier_exit();
+            let exit_params = if per_tier_exit {
+                CoinParams {
+                    exit_z_threshold: held_tiers
+                        .iter()
+                        .map(|&t| config.scale_in.exit_z_threshold(params.exit_z_threshold, t))
+                        .fold(params.exit_z_threshold, f64::max),
+                    ..params
+                }
+            } else {
+                params
+            };
+
             if let Some(Signal::Exit {
                 coin: c,
                 z_score,
@@ -1581,80 +2088,47 @@ where
                 mean,
                 stddev,
                 has_positions,
+                &exit_params,
                 &config,
-            )? {
-                // 청산 가격 라운딩
-                let (exit_upbit_usd, exit_bybit) = if let Some(ref inst) = inst_info {
-                    // Upbit 매도: floor (더 싸게 팔음 = 불리한 방향)
-                    let exit_upbit_krw = instrument::floor_to_step(
-                        upbit_price,
-                        instrument::upbit_tick_size(upbit_price),
-                    );
-                    let exit_upbit_usd_val =
-                        Decimal::try_from(exit_upbit_krw.to_f64().unwrap_or(0.0) / usd_krw)
-                            .unwrap_or(upbit_usd_dec);
-
-                    // Bybit close (매수): ceil (더 비싸게 삼 = 불리한 방향)
-                    let exit_bybit_val =
-                        instrument::round_price_conservative(bybit_price, inst.tick_size, true);
-                    (exit_upbit_usd_val, exit_bybit_val)
-                } else {
-                    counters.lock().fallback_no_rounding_count += 1;
-                    (upbit_usd_dec, bybit_price)
-                };
-
+            )? && let Some(mut exit_ctx) = Self::build_exit_context(
+                false,
+                &c,
+                z_score,
+                sp,
+                upbit_price,
+                bybit_price,
+                usd_krw,
+                mean,
+                &inst_info,
+                &ob_cache,
+                &counters,
+                &config,
+            )
+            .await
+            {
                 info!(
                     coin = c.as_str(),
                     z_score = z_score,
                     spread_pct = sp,
-                    exit_upbit_usd = %exit_upbit_usd,
-                    exit_bybit = %exit_bybit,
+                    exit_upbit_usd = %exit_ctx.exit_upbit_usd,
+                    exit_bybit = %exit_ctx.exit_bybit,
                     "[틱] 청산 시그널"
                 );
 
-                // 오더북 기반 청산 안전 볼륨 계산
-                let data = ob_cache.data.read().await;
-                let upbit_cached = data.get(orderbook::Exchange::Upbit, &c);
-                let bybit_cached = data.get(orderbook::Exchange::Bybit, &c);
-
-                if let (Some(upbit_ob), Some(bybit_ob)) = (upbit_cached, bybit_cached) {
-                    let upbit_bids = orderbook::levels_to_f64(&upbit_ob.orderbook, false);
-                    let bybit_asks = orderbook::levels_to_f64(&bybit_ob.orderbook, true);
-                    drop(data);
-
-                    let exit_safe = orderbook::calculate_exit_safe_volume(
-                        &upbit_bids,
-                        &bybit_asks,
-                        mean,
-                        config.upbit_taker_fee.to_f64().unwrap_or(0.0),
-                        config.bybit_taker_fee.to_f64().unwrap_or(0.0),
-                        usd_krw,
-                    );
-
-                    // ExitContext 구성 → policy 콜백
-                    let exit_ctx = ExitContext {
-                        coin: c.clone(),
-                        z_score,
-                        spread_pct: sp,
-                        exit_upbit_usd,
-                        exit_bybit,
-                        usd_krw,
-                        exit_safe_volume_usdt: exit_safe.map(|sv| sv.safe_volume_usdt),
-                        mean,
-                        instrument_info: inst_info.clone(),
-                        bybit_price,
-                    };
+                exit_ctx.tiers = per_tier_exit.then(|| {
+                    config
+                        .scale_in
+                        .tiers_to_exit(params.exit_z_threshold, z_score, &held_tiers)
+                });
 
-                    if let Err(e) = policy.on_exit_signal(exit_ctx).await {
-                        warn!(coin = c.as_str(), error = %e, "청산 정책 실행 실패");
-                    }
-                } else {
-                    drop(data);
+                metrics::observe_tick_to_signal("exit", tick_received_at.elapsed());
+                if let Err(e) = policy.on_exit_signal(exit_ctx).await {
+                    warn!(coin = c.as_str(), error = %e, "청산 정책 실행 실패");
                 }
             }
         }
 
-        // 5. 진입 시그널 평가
+        // 6. 진입 시그널 평가
         if !policy.is_entry_allowed() {
             return Ok(());
         }
@@ -1665,8 +2139,31 @@ where
             let coin_used = pm.coin_used_capital(&coin);
             let open_count = pm.open_count();
             let last_entry = pm.last_entry_at(&coin);
+            let held_tiers = pm.held_tiers(&coin);
             drop(pm);
 
+            // 분할 진입: 비어 있는 가장 낮은 단계의 임계값으로 평가.
+            // 추가 단계는 높아진 임계값이 간격 역할을 하므로 쿨다운은 첫 진입에만 적용
+            let (tier, entry_params, last_entry) = if config.scale_in.enabled {
+                let Some(tier) = config.scale_in.next_tier(&held_tiers) else {
+                    return Ok(());
+                };
+                let entry_params = CoinParams {
+                    entry_z_threshold: config
+                        .scale_in
+                        .entry_z_threshold(params.entry_z_threshold, tier),
+                    ..params
+                };
+                let last_entry = if held_tiers.is_empty() {
+                    last_entry
+                } else {
+                    None
+                };
+                (tier, entry_params, last_entry)
+            } else {
+                (0, params, last_entry)
+            };
+
             if let Some(Signal::Enter {
                 coin: c,
                 z_score,
@@ -1681,8 +2178,23 @@ where
                 max_coin_capital,
                 open_count,
                 last_entry,
+                &entry_params,
                 &config,
             )? {
+                // 상위 타임프레임 추세 필터
+                if let Some(trend_t) = adverse_trend_t {
+                    info!(
+                        coin = c.as_str(),
+                        z_score,
+                        spread_pct = sp,
+                        trend_t,
+                        filter = "htf_trend",
+                        "진입 거부: 상위 타임프레임 스프레드 상승 추세"
+                    );
+                    counters.lock().entry_rejected_trend_count += 1;
+                    return Ok(());
+                }
+
                 // InstrumentInfo 필수 체크
                 let Some(ref inst) = inst_info else {
                     info!(
@@ -1726,30 +2238,66 @@ where
                                 .to_f64()
                                 .unwrap_or(0.0);
                             // pm 락 → 자본 확인
-                            let entry_result = {
+                            let mut requested_usdt = {
                                 let pm = position_mgr.lock().await;
                                 let used = pm.coin_used_capital(&c).to_f64().unwrap_or(0.0);
-                                let remaining_cap = max_coin_cap - used;
                                 drop(pm);
+                                (sv.safe_volume_usdt * ratio).min(max_coin_cap - used)
+                            };
+                            if config.scale_in.enabled {
+                                let tier_cap = config
+                                    .scale_in
+                                    .tier_capital(
+                                        config.total_capital_usdt * config.max_position_ratio,
+                                        tier,
+                                    )
+                                    .to_f64()
+                                    .unwrap_or(0.0);
+                                requested_usdt = requested_usdt.min(tier_cap);
+                            }
 
-                                let size_usdt_f64 =
-                                    (sv.safe_volume_usdt * ratio).min(remaining_cap);
-
-                                // 9단계 검증
-                                Self::validate_entry(
-                                    &c,
-                                    size_usdt_f64,
-                                    bybit_price,
-                                    upbit_price,
-                                    usd_krw,
-                                    z_score,
-                                    sp,
-                                    expected_profit_pct,
-                                    inst,
-                                    &config,
-                                )
+                            // 자본 배분: 동시 후보와 기대 수익률 × 신뢰도로 경합
+                            // (grant는 on_entry_signal이 끝날 때까지 유지)
+                            let candidate = AllocationCandidate::new(
+                                c.clone(),
+                                z_score,
+                                entry_params.entry_z_threshold,
+                                expected_profit_pct,
+                                requested_usdt,
+                            );
+                            let grant = allocator.request(candidate, &config, &position_mgr).await;
+                            let size_usdt_f64 = match &grant {
+                                Some(grant) => grant.size_usdt(),
+                                None if !config.allocator.enabled => requested_usdt,
+                                None => {
+                                    info!(
+                                        coin = c.as_str(),
+                                        z_score,
+                                        spread_pct = sp,
+                                        expected_profit = expected_profit_pct,
+                                        requested_usdt,
+                                        filter = "allocator",
+                                        "진입 거부: z-score 통과 후 자본 배분 한도 소진"
+                                    );
+                                    counters.lock().entry_rejected_allocator_count += 1;
+                                    return Ok(());
+                                }
                             };
 
+                            // 9단계 검증
+                            let entry_result = Self::validate_entry(
+                                &c,
+                                size_usdt_f64,
+                                bybit_price,
+                                upbit_price,
+                                usd_krw,
+                                z_score,
+                                sp,
+                                expected_profit_pct,
+                                inst,
+                                &config,
+                            );
+
                             match entry_result {
                                 EntryValidation::Accepted {
                                     qty,
@@ -1757,6 +2305,19 @@ where
                                     bybit_entry,
                                     adjusted_profit,
                                 } => {
+                                    // Bybit short 수량 (Kalman 헤지 비율 적용, 고정 모델은 1:1)
+                                    let bybit_qty = if config.kalman_params().is_some() {
+                                        kalman::hedge_qty(
+                                            qty,
+                                            hedge_ratio,
+                                            config.kalman_hedge_ratio_min,
+                                            config.kalman_hedge_ratio_max,
+                                            inst,
+                                        )
+                                    } else {
+                                        qty
+                                    };
+
                                     // EntryContext 구성 → policy 콜백
                                     let entry_ctx = EntryContext {
                                         coin: c.clone(),
@@ -1768,12 +2329,15 @@ where
                                         upbit_entry_usd,
                                         bybit_entry,
                                         qty,
+                                        bybit_qty,
+                                        hedge_ratio,
                                         usd_krw,
                                         mean,
                                         stddev,
                                         instrument_info: inst.clone(),
                                         safe_volume_usdt: sv.safe_volume_usdt,
                                         volume_ratio: ratio,
+                                        tier,
                                     };
 
                                     info!(
@@ -1783,11 +2347,17 @@ where
                                         expected_profit = expected_profit_pct,
                                         adjusted_profit = adjusted_profit,
                                         qty = %qty,
+                                        bybit_qty = %bybit_qty,
                                         upbit_entry_usd = %upbit_entry_usd,
                                         bybit_entry = %bybit_entry,
+                                        tier,
                                         "[틱] 진입 시그널 (라운딩 적용)"
                                     );
 
+                                    metrics::observe_tick_to_signal(
+                                        "entry",
+                                        tick_received_at.elapsed(),
+                                    );
                                     if let Err(e) = policy.on_entry_signal(entry_ctx).await {
                                         warn!(
                                             coin = c.as_str(),
@@ -1865,6 +2435,92 @@ where
         Ok(())
     }
 
+    /// 청산 가격 라운딩 + 오더북 기반 청산 안전 볼륨으로 `ExitContext`를 구성합니다.
+    ///
+    /// `is_stop`이면 수익성 게이트 없이 호가 깊이만으로 안전 볼륨을 계산합니다
+    /// ([`orderbook::calculate_exit_liquidity_volume`]). 손절은 손실 구간에서 발생하므로
+    /// 수익성 게이트를 적용하면 정작 필요한 순간에 청산이 거부됩니다.
+    ///
+    /// 양쪽 오더북 캐시가 없으면 `None`을 반환합니다.
+    /// 단계/포지션 필터(`tiers`, `position_ids`)는 호출자가 채웁니다.
+    #[allow(clippy::too_many_arguments)]
+    async fn build_exit_context(
+        is_stop: bool,
+        coin: &str,
+        z_score: f64,
+        spread_pct: f64,
+        upbit_price: Decimal,
+        bybit_price: Decimal,
+        usd_krw: f64,
+        mean: f64,
+        inst_info: &Option<instrument::InstrumentInfo>,
+        ob_cache: &orderbook::SharedObCache,
+        counters: &parking_lot::Mutex<MonitoringCounters>,
+        config: &ZScoreConfig,
+    ) -> Option<ExitContext> {
+        // USD 환산된 Upbit 가격
+        let upbit_usd_dec = Decimal::try_from(upbit_price.to_f64().unwrap_or(0.0) / usd_krw)
+            .unwrap_or(Decimal::ZERO);
+
+        // 청산 가격 라운딩
+        let (exit_upbit_usd, exit_bybit) = if let Some(inst) = inst_info {
+            // Upbit 매도: floor (더 싸게 팔음 = 불리한 방향)
+            let exit_upbit_krw =
+                instrument::floor_to_step(upbit_price, instrument::upbit_tick_size(upbit_price));
+            let exit_upbit_usd_val =
+                Decimal::try_from(exit_upbit_krw.to_f64().unwrap_or(0.0) / usd_krw)
+                    .unwrap_or(upbit_usd_dec);
+
+            // Bybit close (매수): ceil (더 비싸게 삼 = 불리한 방향)
+            let exit_bybit_val =
+                instrument::round_price_conservative(bybit_price, inst.tick_size, true);
+            (exit_upbit_usd_val, exit_bybit_val)
+        } else {
+            counters.lock().fallback_no_rounding_count += 1;
+            (upbit_usd_dec, bybit_price)
+        };
+
+        // 오더북 기반 청산 안전 볼륨 계산
+        let data = ob_cache.data.read().await;
+        let (Some(upbit_ob), Some(bybit_ob)) = (
+            data.get(orderbook::Exchange::Upbit, coin),
+            data.get(orderbook::Exchange::Bybit, coin),
+        ) else {
+            return None;
+        };
+        let upbit_bids = orderbook::levels_to_f64(&upbit_ob.orderbook, false);
+        let bybit_asks = orderbook::levels_to_f64(&bybit_ob.orderbook, true);
+        drop(data);
+
+        let exit_safe = if is_stop {
+            orderbook::calculate_exit_liquidity_volume(&upbit_bids, &bybit_asks)
+        } else {
+            orderbook::calculate_exit_safe_volume(
+                &upbit_bids,
+                &bybit_asks,
+                mean,
+                config.upbit_taker_fee.to_f64().unwrap_or(0.0),
+                config.bybit_taker_fee.to_f64().unwrap_or(0.0),
+                usd_krw,
+            )
+        };
+
+        Some(ExitContext {
+            coin: coin.to_string(),
+            z_score,
+            spread_pct,
+            exit_upbit_usd,
+            exit_bybit,
+            usd_krw,
+            exit_safe_volume_usdt: exit_safe.map(|sv| sv.safe_volume_usdt),
+            mean,
+            instrument_info: inst_info.clone(),
+            bybit_price,
+            tiers: None,
+            position_ids: None,
+        })
+    }
+
     /// TTL 만료 포지션을 체크하고 청산합니다.
     ///
     /// 탈락 코인 중 TTL이 만료된 포지션을 grace period에 따라 분할/전량 청산합니다.
@@ -1903,11 +2559,15 @@ where
                 continue;
             }
 
-            let positions: Vec<(u64, Decimal, Decimal)> = {
+            let positions: Vec<(u64, Decimal, Decimal, Decimal)> = {
                 let pm = position_mgr.lock().await;
                 pm.open_positions
                     .get(coin.as_str())
-                    .map(|ps| ps.iter().map(|p| (p.id, p.size_usdt(), p.qty)).collect())
+                    .map(|ps| {
+                        ps.iter()
+                            .map(|p| (p.id, p.size_usdt(), p.qty, p.bybit_leg_qty()))
+                            .collect()
+                    })
                     .unwrap_or_default()
             };
 
@@ -1963,10 +2623,11 @@ where
 
             let ttl_positions: Vec<TtlPosition> = positions
                 .iter()
-                .map(|(id, size_usdt, qty)| TtlPosition {
+                .map(|(id, size_usdt, qty, bybit_qty)| TtlPosition {
                     id: *id,
                     size_usdt: *size_usdt,
                     qty: *qty,
+                    bybit_qty: *bybit_qty,
                 })
                 .collect();
 
@@ -2014,12 +2675,13 @@ where
         session_writer: &tokio::sync::Mutex<Option<SessionWriter>>,
         minute_records: &mut Vec<MinuteRecord>,
         instrument_cache: &Arc<parking_lot::RwLock<InstrumentCache>>,
+        coin_params: &parking_lot::RwLock<CoinParamsTable>,
         counters: &parking_lot::Mutex<MonitoringCounters>,
         policy: &Arc<P>,
     ) -> Result<Option<RegimeChangeResult>, StrategyError> {
         let ts = candle_builder
             .current_minute
-            .unwrap_or_else(|| truncate_to_minute(new_minute_ts));
+            .unwrap_or_else(|| candle_builder.truncate(new_minute_ts));
 
         debug!(
             timestamp = %ts,
@@ -2184,6 +2846,7 @@ where
                     "분 완결: 통계 갱신 완료 (시그널은 틱에서 처리)"
                 );
 
+                let params = coin_params.read().get(coin);
                 let record = MinuteRecord {
                     timestamp: ts.to_rfc3339(),
                     coin: coin.clone(),
@@ -2196,6 +2859,9 @@ where
                     z_score: z,
                     position: position_str.to_string(),
                     source: "live".to_string(),
+                    window_size: params.window_size,
+                    entry_z_threshold: params.entry_z_threshold,
+                    exit_z_threshold: params.exit_z_threshold,
                 };
 
                 minute_records.push(record.clone());
@@ -2456,6 +3122,49 @@ where
                             }
                         }
 
+                        // 새 코인 평균 회귀 체크
+                        if config.max_half_life_candles > 0.0 || config.require_stationary_spread {
+                            let score = {
+                                let sc = spread_calc.read().await;
+                                let mut scored: Vec<CoinCandidate> = new_candidates
+                                    .iter()
+                                    .filter(|c| c.coin == *coin)
+                                    .cloned()
+                                    .collect();
+                                attach_mean_reversion_scores(
+                                    &mut scored,
+                                    &sc,
+                                    config.adf_lags,
+                                    config.stationarity_significance,
+                                );
+                                scored.first().and_then(|c| c.mean_reversion)
+                            };
+                            let passes = score
+                                .map(|s| {
+                                    s.passes(
+                                        config.max_half_life_candles,
+                                        config.require_stationary_spread,
+                                    )
+                                })
+                                .unwrap_or(false);
+                            if !passes {
+                                warn!(
+                                    coin = coin.as_str(),
+                                    half_life = ?score.and_then(|s| s.half_life),
+                                    adf_stat = ?score.map(|s| s.adf_stat),
+                                    "재선택 코인 평균 회귀 기준 미달, 건너뜀"
+                                );
+                                spread_calc.write().await.remove_coin(coin);
+                                counters.lock().coin_rejected_mean_reversion_count += 1;
+                                continue;
+                            }
+                            log_window_half_life_guideline(
+                                coin,
+                                score.and_then(|s| s.half_life),
+                                config.coin_params(coin).window_size,
+                            );
+                        }
+
                         info!(coin = coin.as_str(), "코인 추가 완료");
                     }
                     Err(e) => {
@@ -2466,7 +3175,7 @@ where
                 }
             }
 
-            // 최종 코인 수집 전 초과 코인 pruning
+            // 최종 코인 수집 전 초과 코인 pruning (half-life → stddev 우선순위)
             let new_coins: Vec<String> = {
                 let sc = spread_calc.read().await;
                 let mut active: Vec<(String, f64)> = sc
@@ -2477,7 +3186,15 @@ where
                             .map(|(_, stddev)| (coin.to_string(), stddev))
                     })
                     .collect();
-                active.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+                let half_lives: HashMap<String, f64> = active
+                    .iter()
+                    .filter_map(|(coin, _)| {
+                        let window = sc.spread_window(coin)?;
+                        let hl = statistics::half_life(window.data()).ok().flatten()?;
+                        Some((coin.clone(), hl))
+                    })
+                    .collect();
+                sort_by_selection_rank(&mut active, &half_lives);
                 active.into_iter().map(|(coin, _)| coin).collect()
             };
 
@@ -2523,11 +3240,183 @@ where
         });
     }
 
+    /// adaptive 파라미터 재추정을 tokio::spawn으로 분리합니다.
+    ///
+    /// 완료 시 `running` guard를 해제합니다.
+    #[allow(clippy::too_many_arguments)]
+    fn spawn_adaptive_update(
+        config: Arc<ZScoreConfig>,
+        upbit: Arc<U>,
+        bybit: Arc<B>,
+        forex_cache: Arc<ForexCache>,
+        spread_calc: Arc<tokio::sync::RwLock<SpreadCalculator>>,
+        coin_params: Arc<parking_lot::RwLock<CoinParamsTable>>,
+        counters: Arc<parking_lot::Mutex<MonitoringCounters>>,
+        position_mgr: Arc<tokio::sync::Mutex<PositionManager>>,
+        coins: Vec<String>,
+        running: Arc<AtomicBool>,
+    ) {
+        tokio::spawn(async move {
+            for coin in &coins {
+                Self::adapt_single_coin(
+                    &config,
+                    upbit.as_ref(),
+                    bybit.as_ref(),
+                    &forex_cache,
+                    &spread_calc,
+                    &coin_params,
+                    &counters,
+                    &position_mgr,
+                    coin,
+                )
+                .await;
+            }
+            running.store(false, Ordering::Release);
+            debug!("adaptive 파라미터 재추정 완료");
+        });
+    }
+
+    /// 단일 코인의 half-life/변동성을 재추정하여 윈도우 크기와 임계값을 조정합니다.
+    ///
+    /// 포지션 보유 중인 코인은 진입 시점의 통계 기준을 유지하기 위해 조정을 보류합니다.
+    /// 윈도우 축소는 기존 데이터를 잘라 즉시 적용하고, 확대는 별도 SpreadCalculator에서
+    /// 워밍업을 마친 뒤 교체합니다. 확대 워밍업 실패 시 임계값만 조정합니다.
+    #[allow(clippy::too_many_arguments)]
+    async fn adapt_single_coin(
+        config: &ZScoreConfig,
+        upbit: &U,
+        bybit: &B,
+        forex_cache: &ForexCache,
+        spread_calc: &tokio::sync::RwLock<SpreadCalculator>,
+        coin_params: &parking_lot::RwLock<CoinParamsTable>,
+        counters: &parking_lot::Mutex<MonitoringCounters>,
+        position_mgr: &tokio::sync::Mutex<PositionManager>,
+        coin: &str,
+    ) {
+        if position_mgr.lock().await.has_position(coin) {
+            debug!(coin = coin, "포지션 보유 중, adaptive 조정 보류");
+            counters.lock().adaptive_param_deferred_count += 1;
+            return;
+        }
+
+        // spread_calc read → 추정값 복사 → drop
+        let estimate = {
+            let sc = spread_calc.read().await;
+            let Some(window) = sc.spread_window(coin) else {
+                return;
+            };
+            if !window.is_ready() {
+                return;
+            }
+            let half_life = statistics::half_life(window.data()).ok().flatten();
+            let vol_ratio = match (sc.cached_short_stats(coin), sc.cached_stats(coin)) {
+                (Some((_, short)), Some((_, long))) if long > 0.0 => Some(short / long),
+                _ => None,
+            };
+            AdaptiveEstimate {
+                half_life,
+                vol_ratio,
+            }
+        };
+
+        let (current, configured) = {
+            let table = coin_params.read();
+            (table.get(coin), table.configured(coin))
+        };
+        let mut proposed = coin_params::adapt_params(configured, &estimate, config);
+        if !coin_params::is_significant_change(&current, &proposed) {
+            trace!(
+                coin = coin,
+                half_life = ?estimate.half_life,
+                vol_ratio = ?estimate.vol_ratio,
+                "adaptive 파라미터 변화 미미, 유지"
+            );
+            return;
+        }
+
+        // 확대 시 staging 워밍업 (REST 호출, 락 미보유)
+        let mut staging = None;
+        if proposed.window_size > current.window_size {
+            // 추세 필터는 기존 상태를 유지하므로 staging에서는 비활성 (상위 캔들 재조회 방지)
+            let mut calc = SpreadCalculator::new(&[], proposed.window_size)
+                .with_candle_interval(config.candle_interval.as_minutes())
+                .with_stats_mode(config.spread_stats_mode())
+                .with_kalman(config.kalman_params());
+            calc.add_coin_with_window(coin, proposed.window_size);
+            let warmed = match Self::warmup_single_coin_standalone(
+                upbit,
+                bybit,
+                config,
+                forex_cache,
+                coin,
+                &mut calc,
+            )
+            .await
+            {
+                Ok(()) => calc.is_ready(coin),
+                Err(e) => {
+                    warn!(coin = coin, error = %e, "adaptive 윈도우 확대 워밍업 실패");
+                    false
+                }
+            };
+
+            if warmed {
+                staging = Some(calc);
+            } else {
+                proposed.window_size = current.window_size;
+                if !coin_params::is_significant_change(&current, &proposed) {
+                    return;
+                }
+            }
+        }
+
+        // 적용 단계: position_mgr 락을 쥔 채 재확인 → spread_calc 교체 → 파라미터 반영.
+        // 위의 await 동안 진입이 일어났을 수 있으므로, 보유 포지션의 통계 기준이 바뀌지 않도록
+        // 적용이 끝날 때까지 락을 유지합니다.
+        let pm = position_mgr.lock().await;
+        if pm.has_position(coin) {
+            debug!(coin = coin, "adaptive 추정 중 진입 발생, 적용 보류");
+            counters.lock().adaptive_param_deferred_count += 1;
+            return;
+        }
+        if proposed.window_size != current.window_size {
+            let mut sc = spread_calc.write().await;
+            // 추정 중 코인이 제거되었으면 다시 추가하지 않음
+            if sc.spread_window(coin).is_none() {
+                return;
+            }
+            let result = match staging.as_mut() {
+                Some(staging) => sc.replace_coin_from(coin, staging),
+                None => sc.resize_coin_window(coin, proposed.window_size),
+            };
+            if let Err(e) = result {
+                warn!(coin = coin, error = %e, "adaptive 윈도우 변경 실패");
+                return;
+            }
+        }
+        coin_params.write().set_adapted(coin, proposed);
+        drop(pm);
+        counters.lock().adaptive_param_change_count += 1;
+        info!(
+            coin = coin,
+            half_life = ?estimate.half_life,
+            vol_ratio = ?estimate.vol_ratio,
+            old_window = current.window_size,
+            new_window = proposed.window_size,
+            old_entry_z = current.entry_z_threshold,
+            new_entry_z = proposed.entry_z_threshold,
+            old_exit_z = current.exit_z_threshold,
+            new_exit_z = proposed.exit_z_threshold,
+            "adaptive 파라미터 변경"
+        );
+    }
+
     /// 워밍업 완료 후 각 코인의 현재 통계를 `MinuteRecord`로 생성합니다.
     fn generate_warmup_records(
         spread_calc: &SpreadCalculator,
         coins: &[String],
         config: &ZScoreConfig,
+        coin_params: &CoinParamsTable,
         forex_cache: &ForexCache,
     ) -> Vec<MinuteRecord> {
         let mut records = Vec::new();
@@ -2549,6 +3438,7 @@ where
                     .bybit_window(coin)
                     .and_then(|w| w.last())
                     .unwrap_or(0.0);
+                let params = coin_params.get(coin);
 
                 records.push(MinuteRecord {
                     timestamp: Utc::now().to_rfc3339(),
@@ -2562,6 +3452,9 @@ where
                     z_score: z,
                     position: "NONE".to_string(),
                     source: "warmup".to_string(),
+                    window_size: params.window_size,
+                    entry_z_threshold: params.entry_z_threshold,
+                    exit_z_threshold: params.exit_z_threshold,
                 });
             }
         }
@@ -2756,6 +3649,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::convert::truncate_to_minute;
     use crate::zscore::coin_selector::CoinCandidate;
     use crate::zscore::monitor_sim::SimPolicy;
 
@@ -2838,6 +3732,30 @@ mod tests {
         assert!(builder.current_minute.is_some());
     }
 
+    #[test]
+    fn test_candle_builder_5m_interval() {
+        use chrono::TimeZone;
+        let mut builder = MinuteCandleBuilder::new().with_interval(CandleInterval::Minute5);
+        builder.start_new_minute(Utc.with_ymd_and_hms(2026, 3, 1, 9, 12, 30).unwrap());
+        assert_eq!(
+            builder.current_minute,
+            Some(Utc.with_ymd_and_hms(2026, 3, 1, 9, 10, 0).unwrap())
+        );
+
+        // 같은 5분 구간 내에서는 분이 바뀌어도 새 캔들이 아님
+        assert!(!builder.is_new_minute(Utc.with_ymd_and_hms(2026, 3, 1, 9, 14, 59).unwrap()));
+        assert!(builder.is_new_minute(Utc.with_ymd_and_hms(2026, 3, 1, 9, 15, 0).unwrap()));
+    }
+
+    #[test]
+    fn test_candle_builder_1h_interval() {
+        use chrono::TimeZone;
+        let mut builder = MinuteCandleBuilder::new().with_interval(CandleInterval::Minute60);
+        builder.start_new_minute(Utc.with_ymd_and_hms(2026, 3, 1, 9, 45, 0).unwrap());
+        assert!(!builder.is_new_minute(Utc.with_ymd_and_hms(2026, 3, 1, 9, 59, 0).unwrap()));
+        assert!(builder.is_new_minute(Utc.with_ymd_and_hms(2026, 3, 1, 10, 0, 1).unwrap()));
+    }
+
     #[test]
     fn test_candle_builder_krw_usdt_ignored() {
         let mut builder = MinuteCandleBuilder::new();
@@ -2881,16 +3799,19 @@ mod tests {
                 coin: "BTC".to_string(),
                 volume_1h_usdt: 1_000_000.0,
                 volatility_24h_pct: 5.0,
+                mean_reversion: None,
             },
             CoinCandidate {
                 coin: "ETH".to_string(),
                 volume_1h_usdt: 500_000.0,
                 volatility_24h_pct: 4.0,
+                mean_reversion: None,
             },
             CoinCandidate {
                 coin: "AVAX".to_string(),
                 volume_1h_usdt: 300_000.0,
                 volatility_24h_pct: 8.0,
+                mean_reversion: None,
             },
         ];
 
@@ -2921,6 +3842,7 @@ mod tests {
             coin: "SOL".to_string(),
             volume_1h_usdt: 500_000.0,
             volatility_24h_pct: 6.0,
+            mean_reversion: None,
         }];
 
         let diff = diff_coins(&current, &new_candidates, &pm);
@@ -2944,11 +3866,13 @@ mod tests {
                 coin: "BTC".to_string(),
                 volume_1h_usdt: 1_000_000.0,
                 volatility_24h_pct: 5.0,
+                mean_reversion: None,
             },
             CoinCandidate {
                 coin: "ETH".to_string(),
                 volume_1h_usdt: 500_000.0,
                 volatility_24h_pct: 4.0,
+                mean_reversion: None,
             },
         ];
 
@@ -2985,11 +3909,13 @@ mod tests {
                 coin: "BTC".to_string(),
                 volume_1h_usdt: 1_000_000.0,
                 volatility_24h_pct: 5.0,
+                mean_reversion: None,
             },
             CoinCandidate {
                 coin: "ETH".to_string(),
                 volume_1h_usdt: 500_000.0,
                 volatility_24h_pct: 4.0,
+                mean_reversion: None,
             },
         ];
 
@@ -3069,6 +3995,8 @@ mod tests {
             &upbit,
             &bybit,
             &instrument_cache,
+            &Arc::new(parking_lot::RwLock::new(CoinParamsTable::new(&config))),
+            &Arc::new(CapitalAllocator::new()),
             &policy,
         )
         .await;
@@ -3121,6 +4049,8 @@ mod tests {
             &upbit,
             &bybit,
             &instrument_cache,
+            &Arc::new(parking_lot::RwLock::new(CoinParamsTable::new(&config))),
+            &Arc::new(CapitalAllocator::new()),
             &policy,
         )
         .await;
@@ -3175,6 +4105,8 @@ mod tests {
             &upbit,
             &bybit,
             &instrument_cache,
+            &Arc::new(parking_lot::RwLock::new(CoinParamsTable::new(&config))),
+            &Arc::new(CapitalAllocator::new()),
             &policy,
         )
         .await;
@@ -3221,6 +4153,8 @@ mod tests {
             &upbit,
             &bybit,
             &instrument_cache,
+            &Arc::new(parking_lot::RwLock::new(CoinParamsTable::new(&config))),
+            &Arc::new(CapitalAllocator::new()),
             &policy,
         )
         .await;
@@ -3293,6 +4227,8 @@ mod tests {
             &upbit,
             &bybit,
             &instrument_cache,
+            &Arc::new(parking_lot::RwLock::new(CoinParamsTable::new(&config))),
+            &Arc::new(CapitalAllocator::new()),
             &policy,
         )
         .await;
@@ -3388,7 +4324,7 @@ mod tests {
             ("ETH".to_string(), Some((0.0, 0.3))),
             ("XRP".to_string(), Some((0.0, 0.6))),
         ];
-        let (kept, removed) = filter_coins_by_stddev(&coin_stats, 0.5, 5);
+        let (kept, removed) = filter_coins_by_stddev(&coin_stats, 0.5, 5, &HashMap::new());
         assert_eq!(kept, vec!["BTC", "ETH"]);
         assert_eq!(removed, vec!["XRP"]);
     }
@@ -3401,7 +4337,7 @@ mod tests {
             ("XRP".to_string(), Some((0.0, 0.3))),
         ];
         // 전부 통과하지만 max_coins=2
-        let (kept, removed) = filter_coins_by_stddev(&coin_stats, 0.5, 2);
+        let (kept, removed) = filter_coins_by_stddev(&coin_stats, 0.5, 2, &HashMap::new());
         assert_eq!(kept, vec!["BTC", "ETH"]);
         assert_eq!(removed, vec!["XRP"]);
     }
@@ -3413,7 +4349,7 @@ mod tests {
             ("ETH".to_string(), None),
             ("XRP".to_string(), Some((0.0, 0.2))),
         ];
-        let (kept, removed) = filter_coins_by_stddev(&coin_stats, 0.5, 5);
+        let (kept, removed) = filter_coins_by_stddev(&coin_stats, 0.5, 5, &HashMap::new());
         assert_eq!(kept, vec!["BTC", "XRP"]);
         assert!(removed.contains(&"ETH".to_string()));
     }
@@ -3426,7 +4362,7 @@ mod tests {
             ("XRP".to_string(), Some((0.0, 3.0))),
         ];
         // 전부 0.5 초과 -> fallback: stddev 오름차순 2개
-        let (kept, removed) = filter_coins_by_stddev(&coin_stats, 0.5, 2);
+        let (kept, removed) = filter_coins_by_stddev(&coin_stats, 0.5, 2, &HashMap::new());
         assert_eq!(kept, vec!["BTC", "ETH"]);
         assert_eq!(removed, vec!["XRP"]);
     }
@@ -3439,7 +4375,7 @@ mod tests {
             ("XRP".to_string(), Some((0.0, 2.0))),
         ];
         // max_spread_stddev=0.0이면 필터링 없이 stddev 오름차순 max_coins개
-        let (kept, removed) = filter_coins_by_stddev(&coin_stats, 0.0, 2);
+        let (kept, removed) = filter_coins_by_stddev(&coin_stats, 0.0, 2, &HashMap::new());
         // stddev 오름차순: ETH(0.1), XRP(2.0), BTC(5.0)
         assert_eq!(kept, vec!["ETH", "XRP"]);
         assert_eq!(removed, vec!["BTC"]);
@@ -3448,7 +4384,7 @@ mod tests {
     #[test]
     fn test_filter_coins_by_stddev_all_none() {
         let coin_stats = vec![("BTC".to_string(), None), ("ETH".to_string(), None)];
-        let (kept, removed) = filter_coins_by_stddev(&coin_stats, 0.5, 5);
+        let (kept, removed) = filter_coins_by_stddev(&coin_stats, 0.5, 5, &HashMap::new());
         assert!(kept.is_empty());
         assert_eq!(removed.len(), 2);
     }
@@ -3460,11 +4396,100 @@ mod tests {
             ("A".to_string(), Some((0.0, 0.1))),
             ("B".to_string(), Some((0.0, 0.2))),
         ];
-        let (kept, _removed) = filter_coins_by_stddev(&coin_stats, 0.5, 5);
+        let (kept, _removed) = filter_coins_by_stddev(&coin_stats, 0.5, 5, &HashMap::new());
         // stddev 오름차순: A(0.1), B(0.2), C(0.4)
         assert_eq!(kept, vec!["A", "B", "C"]);
     }
 
+    #[test]
+    fn test_filter_coins_by_stddev_prefers_half_life_rank() {
+        // stddev 순(A, B, C)과 half-life 순(C, B, A)이 반대
+        let coin_stats = vec![
+            ("A".to_string(), Some((0.0, 0.1))),
+            ("B".to_string(), Some((0.0, 0.2))),
+            ("C".to_string(), Some((0.0, 0.4))),
+            ("D".to_string(), Some((0.0, 0.05))),
+        ];
+        let half_lives: HashMap<String, f64> = [
+            ("A".to_string(), 40.0),
+            ("B".to_string(), 20.0),
+            ("C".to_string(), 5.0),
+        ]
+        .into_iter()
+        .collect();
+
+        // max_coins 절단도 half-life 순으로: 빠른 C, B 유지
+        let (kept, removed) = filter_coins_by_stddev(&coin_stats, 0.5, 2, &half_lives);
+        assert_eq!(kept, vec!["C", "B"]);
+        assert_eq!(removed, vec!["A", "D"]);
+
+        // half-life 없는 코인(D)은 stddev가 가장 작아도 뒤로
+        let (kept, _) = filter_coins_by_stddev(&coin_stats, 0.0, 4, &half_lives);
+        assert_eq!(kept, vec!["C", "B", "A", "D"]);
+    }
+
+    // --- filter_coins_by_mean_reversion 테스트 ---
+
+    fn mr_score(half_life: Option<f64>, stationary: bool) -> Option<MeanReversionScore> {
+        Some(MeanReversionScore {
+            adf_stat: if stationary { -4.0 } else { -1.0 },
+            stationary,
+            coint_stat: None,
+            cointegrated: false,
+            hedge_ratio: None,
+            half_life,
+        })
+    }
+
+    #[test]
+    fn test_filter_coins_by_mean_reversion_disabled_keeps_all_sorted() {
+        let coin_scores = vec![
+            ("BTC".to_string(), mr_score(Some(50.0), false)),
+            ("ETH".to_string(), mr_score(Some(10.0), true)),
+            ("XRP".to_string(), None),
+        ];
+        let (kept, removed) = filter_coins_by_mean_reversion(&coin_scores, 0.0, false);
+        // half-life 오름차순, 점수 없는 코인은 마지막
+        assert_eq!(kept, vec!["ETH", "BTC", "XRP"]);
+        assert!(removed.is_empty());
+    }
+
+    #[test]
+    fn test_filter_coins_by_mean_reversion_max_half_life() {
+        let coin_scores = vec![
+            ("BTC".to_string(), mr_score(Some(50.0), true)),
+            ("ETH".to_string(), mr_score(Some(10.0), true)),
+            ("XRP".to_string(), mr_score(None, true)),
+        ];
+        let (kept, removed) = filter_coins_by_mean_reversion(&coin_scores, 30.0, false);
+        assert_eq!(kept, vec!["ETH"]);
+        assert_eq!(removed, vec!["BTC", "XRP"]);
+    }
+
+    #[test]
+    fn test_filter_coins_by_mean_reversion_require_stationary() {
+        let coin_scores = vec![
+            ("BTC".to_string(), mr_score(Some(20.0), false)),
+            ("ETH".to_string(), mr_score(Some(40.0), true)),
+            ("XRP".to_string(), None),
+        ];
+        let (kept, removed) = filter_coins_by_mean_reversion(&coin_scores, 0.0, true);
+        assert_eq!(kept, vec!["ETH"]);
+        assert_eq!(removed, vec!["BTC", "XRP"]);
+    }
+
+    #[test]
+    fn test_filter_coins_by_mean_reversion_fallback_all_fail() {
+        let coin_scores = vec![
+            ("BTC".to_string(), mr_score(Some(80.0), false)),
+            ("ETH".to_string(), mr_score(Some(60.0), false)),
+        ];
+        // 전부 기준 미달 -> fallback: 전체 유지 (half-life 오름차순)
+        let (kept, removed) = filter_coins_by_mean_reversion(&coin_scores, 30.0, true);
+        assert_eq!(kept, vec!["ETH", "BTC"]);
+        assert!(removed.is_empty());
+    }
+
     #[test]
     fn test_filter_coins_by_stddev_exact_threshold() {
         let coin_stats = vec![
@@ -3472,8 +4497,142 @@ mod tests {
             ("ETH".to_string(), Some((0.0, 0.50001))),
         ];
         // 0.5 이하면 통과, 0.50001은 초과
-        let (kept, removed) = filter_coins_by_stddev(&coin_stats, 0.5, 5);
+        let (kept, removed) = filter_coins_by_stddev(&coin_stats, 0.5, 5, &HashMap::new());
         assert_eq!(kept, vec!["BTC"]);
         assert_eq!(removed, vec!["ETH"]);
     }
+
+    #[tokio::test]
+    async fn test_stop_exit_not_blocked_by_profit_gate() {
+        // 스프레드가 크게 확대된 오더북: 손절은 발동하지만 수익성 게이트는 청산을 거부하는 상황
+        use crate::zscore::config::StopLossConfig;
+        use crate::zscore::execution_policy::SharedResources;
+        use arb_exchange::{OrderBook, OrderBookLevel};
+
+        let config = Arc::new(ZScoreConfig {
+            stop_loss: StopLossConfig {
+                enabled: true,
+                spread_stop_pct: 1.0,
+                ..StopLossConfig::default()
+            },
+            ..ZScoreConfig::default()
+        });
+        let position_mgr = Arc::new(tokio::sync::Mutex::new(PositionManager::new()));
+        let trades = Arc::new(tokio::sync::Mutex::new(Vec::<ClosedPosition>::new()));
+        let counters = Arc::new(parking_lot::Mutex::new(MonitoringCounters::default()));
+        let policy = SimPolicy::new();
+        policy.bind_shared_resources(SharedResources {
+            config: config.clone(),
+            position_mgr: position_mgr.clone(),
+            trades: trades.clone(),
+            counters: counters.clone(),
+            session_writer: Arc::new(tokio::sync::Mutex::new(None)),
+        });
+
+        // 진입 스프레드 0.05%, 0.01 BTC (≈ 1000 USDT)
+        position_mgr
+            .lock()
+            .await
+            .open_position(VirtualPosition {
+                id: 0,
+                coin: "BTC".to_string(),
+                entry_time: Utc::now(),
+                upbit_entry_price: Decimal::new(99_950, 0),
+                bybit_entry_price: Decimal::new(100_000, 0),
+                bybit_liquidation_price: Decimal::new(200_000, 0),
+                entry_usd_krw: 1400.0,
+                entry_spread_pct: 0.05,
+                entry_z_score: 2.5,
+                qty: Decimal::new(1, 2),
+                ..Default::default()
+            })
+            .unwrap();
+
+        // Upbit 136,000,000 KRW (≈ 97,143 USD) vs Bybit 101,000 USDT → 스프레드 ≈ 3.97%
+        let usd_krw = 1400.0;
+        let upbit_price = Decimal::new(136_000_000, 0);
+        let bybit_price = Decimal::new(101_000, 0);
+        let level = |price: Decimal| OrderBookLevel {
+            price,
+            size: Decimal::new(5, 2),
+        };
+        let book = |asks: Vec<OrderBookLevel>, bids: Vec<OrderBookLevel>| OrderBook {
+            market: "BTC".to_string(),
+            asks,
+            bids,
+            total_ask_size: Decimal::ZERO,
+            total_bid_size: Decimal::ZERO,
+            timestamp: Utc::now(),
+        };
+        let ob_cache = orderbook::SharedObCache::new();
+        {
+            let mut data = ob_cache.data.write().await;
+            data.update(
+                orderbook::Exchange::Upbit,
+                "BTC",
+                book(Vec::new(), vec![level(upbit_price)]),
+            );
+            data.update(
+                orderbook::Exchange::Bybit,
+                "BTC",
+                book(vec![level(bybit_price)], Vec::new()),
+            );
+        }
+
+        let upbit_usd = upbit_price.to_f64().unwrap() / usd_krw;
+        let spread = (bybit_price.to_f64().unwrap() - upbit_usd) / upbit_usd * 100.0;
+        let triggers = {
+            let mut pm = position_mgr.lock().await;
+            stops::check_stops(&mut pm, "BTC", spread, None, 0.21, &config.stop_loss)
+        };
+        assert_eq!(triggers.len(), 1);
+
+        type Monitor = ZScoreMonitor<MockMarket, MockMarket, SimPolicy>;
+        // 시그널 청산 경로(수익성 게이트)는 안전 볼륨 없음 → 청산 불가
+        let gated = Monitor::build_exit_context(
+            false,
+            "BTC",
+            0.0,
+            spread,
+            upbit_price,
+            bybit_price,
+            usd_krw,
+            0.05,
+            &None,
+            &ob_cache,
+            &counters,
+            &config,
+        )
+        .await
+        .unwrap();
+        assert!(gated.exit_safe_volume_usdt.is_none());
+
+        // 손절 경로는 호가 깊이(0.05 BTC × 101,000)를 안전 볼륨으로 사용
+        let mut exit_ctx = Monitor::build_exit_context(
+            true,
+            "BTC",
+            0.0,
+            spread,
+            upbit_price,
+            bybit_price,
+            usd_krw,
+            0.05,
+            &None,
+            &ob_cache,
+            &counters,
+            &config,
+        )
+        .await
+        .unwrap();
+        let safe = exit_ctx.exit_safe_volume_usdt.unwrap();
+        assert!((safe - 5050.0).abs() < 1e-6, "safe={safe}");
+
+        exit_ctx.position_ids = Some(triggers.iter().map(|t| t.position_id).collect());
+        policy.on_exit_signal(exit_ctx).await.unwrap();
+
+        assert!(!position_mgr.lock().await.has_position("BTC"));
+        let trades = trades.lock().await;
+        assert_eq!(trades.len(), 1);
+        assert!(trades[0].net_pnl < Decimal::ZERO);
+    }
 }
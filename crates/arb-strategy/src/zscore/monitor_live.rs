@@ -39,8 +39,8 @@ use chrono::Utc;
 use rust_decimal::Decimal;
 use rust_decimal::prelude::ToPrimitive as _;
 use tracing::{debug, error, info, warn};
-use uuid::Uuid;
 
+use arb_db::execution_quality::ExecutionQualityRecord;
 use arb_db::minutes::MinuteRecord as DbMinuteRecord;
 use arb_db::trades::TradeRecord;
 use arb_db::writer::{DbWriteRequest, DbWriter};
@@ -57,10 +57,12 @@ use crate::zscore::execution_policy::{
     EntryContext, ExecutionPolicy, ExitContext, SharedResources, TtlExpiryContext,
 };
 use crate::zscore::live_executor::{EntryRequest, ExitRequest, LiveExecutor, OrderExecutionError};
+use crate::zscore::order_watchdog::{ClientOrderIdScope, WatchdogHeartbeat};
 use crate::zscore::pnl::ClosedPosition;
 use crate::zscore::position::{self, PositionManager, PositionState, VirtualPosition};
 use crate::zscore::position_store::{PositionRecord, PositionStore, UpdateFields};
 use crate::zscore::risk::RiskManager;
+use crate::zscore::tca::ExecutionQuality;
 
 // ---------------------------------------------------------------------------
 // SharedResources 지연 바인딩 (SimPolicy와 동일 패턴)
@@ -126,6 +128,10 @@ where
     upbit_ioc_reject_states: parking_lot::Mutex<HashMap<String, UpbitIocRejectState>>,
     /// Reconciliation 불일치 감지 시 진입 차단 플래그.
     reconciliation_blocked: AtomicBool,
+    /// 미체결 주문 watchdog heartbeat (None이면 비활성화).
+    watchdog_heartbeat: Option<WatchdogHeartbeat>,
+    /// client order ID 발급 범위 (watchdog와 같은 인스턴스/세션 태그).
+    client_order_ids: ClientOrderIdScope,
     /// 제네릭 마커.
     _marker: PhantomData<(U, B)>,
 }
@@ -180,10 +186,27 @@ where
             balance_sender,
             upbit_ioc_reject_states: parking_lot::Mutex::new(HashMap::new()),
             reconciliation_blocked: AtomicBool::new(false),
+            watchdog_heartbeat: None,
+            client_order_ids: ClientOrderIdScope::new("default", session_id),
             _marker: PhantomData,
         }
     }
 
+    /// 분봉 마감마다 갱신할 미체결 주문 watchdog heartbeat를 설정합니다.
+    pub fn with_watchdog_heartbeat(mut self, heartbeat: WatchdogHeartbeat) -> Self {
+        self.watchdog_heartbeat = Some(heartbeat);
+        self
+    }
+
+    /// client order ID 발급 범위를 설정합니다 (기본값: `default` 인스턴스, 현재 세션).
+    ///
+    /// 같은 계정을 공유하는 인스턴스가 서로의 주문을 watchdog로 취소하지 않도록
+    /// 인스턴스마다 다른 범위를 지정하고, 같은 범위를 watchdog에도 전달해야 합니다.
+    pub fn with_client_order_ids(mut self, scope: ClientOrderIdScope) -> Self {
+        self.client_order_ids = scope;
+        self
+    }
+
     /// SharedResources에 접근합니다.
     fn shared(&self) -> &LivePolicyShared {
         self.shared
@@ -191,9 +214,11 @@ where
             .expect("LivePolicy::bind_shared_resources() must be called before use")
     }
 
-    /// UUID v7 형식의 client order ID를 생성합니다.
-    fn new_client_order_id() -> String {
-        Uuid::now_v7().to_string()
+    /// 인스턴스/세션 태그가 붙은 client order ID를 생성합니다.
+    ///
+    /// 미체결 주문 watchdog가 이 접두사로 자기 인스턴스의 주문을 식별합니다.
+    fn new_client_order_id(&self) -> String {
+        self.client_order_ids.new_client_order_id()
     }
 
     /// PositionRecord를 생성합니다 (DB INSERT용).
@@ -204,7 +229,7 @@ where
             coin: pos.coin.clone(),
             state: pos.state.to_string(),
             upbit_qty: pos.qty,
-            bybit_qty: pos.qty,
+            bybit_qty: pos.bybit_leg_qty(),
             upbit_entry_price: Some(pos.upbit_entry_price),
             bybit_entry_price: Some(pos.bybit_entry_price),
             upbit_order_id: pos.upbit_order_id.clone(),
@@ -441,6 +466,75 @@ where
 
         db_writer.send(DbWriteRequest::InsertTrade(trade));
     }
+
+    /// 진입/청산 체결 품질 1건을 DB writer로 전송합니다.
+    fn enqueue_execution_quality(
+        &self,
+        coin: &str,
+        position_db_id: Option<i64>,
+        quality: &ExecutionQuality,
+    ) {
+        let Some(db_writer) = &self.db_writer else {
+            return;
+        };
+        let Some(position_id) = position_db_id else {
+            warn!(
+                coin = coin,
+                side = quality.side.as_str(),
+                "position db_id 없음, execution_quality INSERT 스킵"
+            );
+            return;
+        };
+
+        let ms = |v: u64| i64::try_from(v).unwrap_or(i64::MAX);
+        let record = ExecutionQualityRecord {
+            id: None,
+            session_id: self.session_id,
+            position_id,
+            coin: coin.to_string(),
+            side: quality.side.clone(),
+            order_type: quality.order_type.clone(),
+            max_slippage_pct: quality.max_slippage_pct,
+            expected_upbit_krw: quality.expected_upbit_krw,
+            actual_upbit_krw: quality.actual_upbit_krw,
+            expected_bybit: quality.expected_bybit,
+            actual_bybit: quality.actual_bybit,
+            upbit_qty: quality.upbit_qty,
+            bybit_qty: quality.bybit_qty,
+            usd_krw: quality.usd_krw,
+            upbit_slippage_bps: quality.upbit_slippage_bps,
+            bybit_slippage_bps: quality.bybit_slippage_bps,
+            slippage_cost_usdt: quality.slippage_cost_usdt,
+            fees_usdt: quality.fees_usdt,
+            implementation_shortfall_usdt: quality.implementation_shortfall_usdt,
+            implementation_shortfall_bps: quality.implementation_shortfall_bps,
+            expected_profit_pct: quality.expected_profit_pct,
+            safe_volume_usdt: quality.safe_volume_usdt,
+            upbit_latency_ms: ms(quality.upbit_latency_ms),
+            bybit_latency_ms: ms(quality.bybit_latency_ms),
+            fill_latency_ms: ms(quality.fill_latency_ms),
+            leg_gap_ms: ms(quality.leg_gap_ms),
+            executed_at: quality.executed_at,
+        };
+
+        db_writer.send(DbWriteRequest::InsertExecutionQuality(record));
+    }
+
+    /// 체결 품질 로그 (슬리피지 bps는 불리한 방향이 양수).
+    fn log_execution_quality(coin: &str, quality: &ExecutionQuality) {
+        info!(
+            coin = coin,
+            side = quality.side.as_str(),
+            order_type = quality.order_type.as_str(),
+            upbit_slippage_bps = quality.upbit_slippage_bps,
+            bybit_slippage_bps = quality.bybit_slippage_bps,
+            shortfall_bps = quality.implementation_shortfall_bps,
+            shortfall_usdt = %quality.implementation_shortfall_usdt,
+            fill_latency_ms = quality.fill_latency_ms,
+            leg_gap_ms = quality.leg_gap_ms,
+            "체결 품질"
+        );
+    }
 }
 
 impl<U, B, S> ExecutionPolicy for LivePolicy<U, B, S>
@@ -529,7 +623,7 @@ where
             return Ok(());
         };
 
-        let client_order_id = Self::new_client_order_id();
+        let client_order_id = self.new_client_order_id();
 
         // ④ pm.lock() → max_concurrent 확인 + VirtualPosition 등록 (Opening)
         let (pos_id, db_id) = {
@@ -571,6 +665,20 @@ where
                 return Ok(());
             }
 
+            // 같은 분할 진입 단계가 동시 틱에서 먼저 등록되었으면 거부
+            if shared.config.scale_in.enabled && pm.held_tiers(coin).contains(&ctx.tier) {
+                info!(
+                    coin = coin.as_str(),
+                    z_score = ctx.z_score,
+                    tier = ctx.tier,
+                    filter = "scale_in_tier_held",
+                    "진입 거부: 분할 진입 단계 이미 보유"
+                );
+                drop(pm);
+                self.balance_tracker.release(&mut reservation);
+                return Ok(());
+            }
+
             // Liquidation price 계산
             let liq_price = position::calculate_liquidation_price(
                 ctx.bybit_entry,
@@ -590,9 +698,11 @@ where
                 entry_spread_pct: ctx.spread_pct,
                 entry_z_score: ctx.z_score,
                 qty: ctx.qty,
+                bybit_qty: Some(ctx.bybit_qty),
                 state: PositionState::Opening,
                 in_flight: true,
                 client_order_id: Some(client_order_id.clone()),
+                tier: ctx.tier,
                 ..Default::default()
             };
 
@@ -649,6 +759,7 @@ where
         let entry_request = EntryRequest {
             coin: coin.clone(),
             qty: ctx.qty,
+            bybit_qty: ctx.bybit_qty,
             upbit_krw_price: ctx.upbit_price_krw,
             bybit_usdt_price: ctx.bybit_entry,
             usd_krw: ctx.usd_krw,
@@ -672,6 +783,10 @@ where
                     "진입 양 레그 체결 성공"
                 );
 
+                let entry_quality =
+                    ExecutionQuality::from_entry(&ctx, &executed, shared.config.max_slippage_pct);
+                Self::log_execution_quality(coin, &entry_quality);
+
                 // pm 락 → state 전이 (Opening → Open)
                 {
                     let mut pm = shared.position_mgr.lock().await;
@@ -681,6 +796,7 @@ where
                         p.state = PositionState::Open;
                         p.in_flight = false;
                         p.qty = executed.effective_qty;
+                        p.bybit_qty = Some(executed.effective_bybit_qty);
                         p.upbit_order_id = Some(executed.upbit_order_id.clone());
                         p.bybit_order_id = Some(executed.bybit_order_id.clone());
                         // 실제 체결가 반영
@@ -689,8 +805,10 @@ where
                             p.upbit_entry_price = executed.upbit_avg_price_krw / usd_krw_dec;
                         }
                         p.bybit_entry_price = executed.bybit_avg_price;
+                        p.entry_quality = Some(entry_quality.clone());
                     }
                 }
+                self.enqueue_execution_quality(coin, (db_id >= 0).then_some(db_id), &entry_quality);
 
                 // DB 상태 전이 (Opening → Open)
                 if db_id >= 0 {
@@ -702,7 +820,8 @@ where
                             upbit_order_id: Some(executed.upbit_order_id),
                             bybit_order_id: Some(executed.bybit_order_id),
                             upbit_qty: Some(executed.upbit_filled_qty),
-                            bybit_qty: Some(executed.bybit_filled_qty),
+                            // 초과분은 executor가 이미 청산했으므로 유효 수량만 보유 헤지
+                            bybit_qty: Some(executed.effective_bybit_qty),
                             upbit_entry_price: Some(executed.upbit_avg_price_krw),
                             bybit_entry_price: Some(executed.bybit_avg_price),
                             in_flight: Some(false),
@@ -714,7 +833,7 @@ where
 
                 // 잔고 확정 (실 체결 금액 기준)
                 let actual_upbit_krw = executed.upbit_avg_price_krw * executed.upbit_filled_qty;
-                let actual_bybit_usdt = executed.bybit_avg_price * executed.bybit_filled_qty;
+                let actual_bybit_usdt = executed.bybit_avg_price * executed.effective_bybit_qty;
                 self.balance_tracker
                     .commit(&mut reservation, actual_upbit_krw, actual_bybit_usdt);
 
@@ -987,18 +1106,27 @@ where
         }
 
         // pm 락 → 청산 대상 포지션 수집 + Closing 전이
-        let positions_to_close: Vec<(u64, Option<i64>, Decimal, Decimal, f64)> = {
+        let positions_to_close: Vec<(u64, Option<i64>, Decimal, Decimal, f64, Decimal)> = {
             let mut pm = shared.position_mgr.lock().await;
-            let positions: Vec<(u64, Option<i64>, Decimal, Decimal, f64)> = pm
+            let positions: Vec<(u64, Option<i64>, Decimal, Decimal, f64, Decimal)> = pm
                 .open_positions
                 .get(coin.as_str())
                 .map(|ps| {
                     ps.iter()
-                        .filter(|p| p.state == PositionState::Open && !p.in_flight)
+                        .filter(|p| {
+                            p.state == PositionState::Open && !p.in_flight && ctx.targets(p)
+                        })
                         .map(|p| {
                             let profit_rate = (ctx.spread_pct - p.entry_spread_pct)
                                 / p.size_usdt().to_f64().unwrap_or(1.0);
-                            (p.id, p.db_id, p.qty, p.size_usdt(), profit_rate)
+                            (
+                                p.id,
+                                p.db_id,
+                                p.qty,
+                                p.size_usdt(),
+                                profit_rate,
+                                p.bybit_leg_qty(),
+                            )
                         })
                         .collect()
                 })
@@ -1012,7 +1140,7 @@ where
             let mut remaining_safe_usdt = exit_safe_volume_usdt;
             let mut targets = Vec::new();
 
-            for (pid, db_id, qty, size, _rate) in sorted {
+            for (pid, db_id, qty, size, _rate, bybit_qty) in sorted {
                 if remaining_safe_usdt <= 0.0 {
                     break;
                 }
@@ -1034,7 +1162,7 @@ where
                     p.closing_started_at = Some(Utc::now());
                 }
 
-                targets.push((pid, db_id, qty, size, 0.0));
+                targets.push((pid, db_id, qty, size, 0.0, bybit_qty));
             }
 
             targets
@@ -1047,8 +1175,8 @@ where
         }
 
         // 각 포지션에 대해 청산 수행
-        for (pid, db_id, qty, _size, _) in &positions_to_close {
-            let exit_client_order_id = Self::new_client_order_id();
+        for (pid, db_id, qty, _size, _, bybit_qty) in &positions_to_close {
+            let exit_client_order_id = self.new_client_order_id();
 
             // DB Closing 전이
             if let Some(db_id) = db_id {
@@ -1076,6 +1204,7 @@ where
             let exit_request = ExitRequest {
                 coin: coin.clone(),
                 qty: *qty,
+                bybit_qty: *bybit_qty,
                 instrument_info: ctx.instrument_info.clone().unwrap_or_default(),
                 exit_client_order_id: exit_client_order_id.clone(),
             };
@@ -1116,7 +1245,17 @@ where
                         }
                     };
 
-                    if let Some(closed) = closed_opt {
+                    if let Some(mut closed) = closed_opt {
+                        let exit_quality = ExecutionQuality::from_exit(
+                            ctx.exit_upbit_usd,
+                            ctx.exit_bybit,
+                            ctx.usd_krw,
+                            &executed,
+                        );
+                        Self::log_execution_quality(coin, &exit_quality);
+                        self.enqueue_execution_quality(coin, *db_id, &exit_quality);
+                        closed.exit_quality = Some(exit_quality);
+
                         // DB Closed 전이
                         if let Some(db_id) = db_id {
                             self.db_update_state(
@@ -1137,8 +1276,9 @@ where
                         // 잔고 복원
                         let received_upbit_krw =
                             executed.upbit_avg_price_krw * executed.upbit_filled_qty;
+                        // 포지션이 보유한 헤지 수량을 넘는 체결분은 반영하지 않음
                         let received_bybit_usdt =
-                            executed.bybit_avg_price * executed.bybit_filled_qty;
+                            executed.bybit_avg_price * executed.bybit_filled_qty.min(*bybit_qty);
                         self.balance_tracker
                             .on_exit(received_upbit_krw, received_bybit_usdt);
 
@@ -1226,7 +1366,7 @@ where
         );
 
         for ttl_pos in &ctx.positions {
-            let exit_client_order_id = Self::new_client_order_id();
+            let exit_client_order_id = self.new_client_order_id();
 
             // pm 락 → Closing 전이
             let db_id = {
@@ -1287,6 +1427,7 @@ where
             let exit_request = ExitRequest {
                 coin: coin.clone(),
                 qty: ttl_pos.qty,
+                bybit_qty: ttl_pos.bybit_qty,
                 instrument_info: ctx.instrument_info.clone().unwrap_or_default(),
                 exit_client_order_id,
             };
@@ -1319,7 +1460,17 @@ where
                         }
                     };
 
-                    if let Some(closed) = closed_opt {
+                    if let Some(mut closed) = closed_opt {
+                        let exit_quality = ExecutionQuality::from_exit(
+                            ctx.exit_upbit_usd,
+                            ctx.exit_bybit,
+                            ctx.usd_krw,
+                            &executed,
+                        );
+                        Self::log_execution_quality(coin, &exit_quality);
+                        self.enqueue_execution_quality(coin, db_id, &exit_quality);
+                        closed.exit_quality = Some(exit_quality);
+
                         // DB Closed 전이
                         if let Some(db_id) = db_id {
                             self.db_update_state(
@@ -1340,8 +1491,9 @@ where
                         // 잔고 복원
                         let received_upbit_krw =
                             executed.upbit_avg_price_krw * executed.upbit_filled_qty;
-                        let received_bybit_usdt =
-                            executed.bybit_avg_price * executed.bybit_filled_qty;
+                        // 포지션이 보유한 헤지 수량을 넘는 체결분은 반영하지 않음
+                        let received_bybit_usdt = executed.bybit_avg_price
+                            * executed.bybit_filled_qty.min(ttl_pos.bybit_qty);
                         self.balance_tracker
                             .on_exit(received_upbit_krw, received_bybit_usdt);
 
@@ -1453,6 +1605,9 @@ where
     }
 
     async fn on_minute_closed(&self, record: &MinuteRecord) {
+        if let Some(heartbeat) = &self.watchdog_heartbeat {
+            heartbeat.beat();
+        }
         self.enqueue_minute_record(record).await;
     }
 
@@ -1517,6 +1672,37 @@ where
         let expected_upbit = current_available_upbit + reserved_upbit;
         let expected_bybit = current_available_bybit + reserved_bybit;
 
+        // 공유 계정: 다른 인스턴스 주문으로 실잔고가 움직이므로 drift 보정 대신,
+        // 실잔고가 이 인스턴스 몫에도 못 미칠 때만 하향 보정한다.
+        if self.balance_tracker.is_shared_account() {
+            if upbit_actual < expected_upbit || bybit_actual < expected_bybit {
+                warn!(
+                    upbit_expected = %expected_upbit,
+                    upbit_actual = %upbit_actual,
+                    bybit_expected = %expected_bybit,
+                    bybit_actual = %bybit_actual,
+                    "공유 계정 실잔고 부족 → 가용 잔고 하향 보정"
+                );
+                self.balance_tracker.set_available(
+                    current_available_upbit
+                        .min(upbit_actual - reserved_upbit)
+                        .max(Decimal::ZERO),
+                    current_available_bybit
+                        .min(bybit_actual - reserved_bybit)
+                        .max(Decimal::ZERO),
+                );
+                self.emit_alert(AlertEvent::Error {
+                    message: format!(
+                        "shared account balance below allocation: upbit {upbit_actual} < {expected_upbit} \
+                         or bybit {bybit_actual} < {expected_bybit}"
+                    ),
+                });
+            } else {
+                debug!("잔고 동기화 정상 (공유 계정, 실잔고 >= 할당)");
+            }
+            return;
+        }
+
         // drift 계산 (%)
         let upbit_drift_pct = if expected_upbit > Decimal::ZERO {
             ((upbit_actual - expected_upbit).abs() / expected_upbit * Decimal::from(100))
@@ -1917,6 +2103,9 @@ mod tests {
         async fn get_order(&self, _: &str) -> ExchangeResult<Order> {
             Err(ExchangeError::Unsupported("mock".into()))
         }
+        async fn get_order_by_client_id(&self, _: &str) -> ExchangeResult<Order> {
+            Err(ExchangeError::Unsupported("mock".into()))
+        }
         async fn get_open_orders(&self, _: Option<&str>) -> ExchangeResult<Vec<Order>> {
             Ok(vec![])
         }
@@ -2003,6 +2192,9 @@ mod tests {
         async fn get_order(&self, _: &str) -> ExchangeResult<Order> {
             Err(ExchangeError::Unsupported("mock".into()))
         }
+        async fn get_order_by_client_id(&self, _: &str) -> ExchangeResult<Order> {
+            Err(ExchangeError::Unsupported("mock".into()))
+        }
         async fn get_open_orders(&self, _: Option<&str>) -> ExchangeResult<Vec<Order>> {
             Ok(vec![])
         }
@@ -2022,6 +2214,7 @@ mod tests {
                 min_order_qty: Decimal::new(1, 3),
                 max_order_qty: Decimal::new(100, 0),
                 min_notional: Decimal::new(5, 0),
+                contract_multiplier: Decimal::ONE,
             })
         }
     }
@@ -2039,6 +2232,13 @@ mod tests {
             self.get_order(order_id).await
         }
 
+        async fn get_order_linear_by_client_id(
+            &self,
+            client_order_id: &str,
+        ) -> ExchangeResult<Order> {
+            self.get_order_by_client_id(client_order_id).await
+        }
+
         async fn cancel_order_linear(
             &self,
             order_id: &str,
@@ -2089,7 +2289,7 @@ mod tests {
             id: i64,
             from: &str,
             to: &str,
-            _fields: UpdateFields,
+            fields: UpdateFields,
         ) -> Result<TransitionResult, String> {
             let mut records = self.records.lock().unwrap();
             if let Some(rec) = records
@@ -2097,6 +2297,12 @@ mod tests {
                 .find(|r| r.id == Some(id) && r.state == from)
             {
                 rec.state = to.to_string();
+                if let Some(qty) = fields.upbit_qty {
+                    rec.upbit_qty = qty;
+                }
+                if let Some(qty) = fields.bybit_qty {
+                    rec.bybit_qty = qty;
+                }
                 Ok(TransitionResult::Applied)
             } else {
                 Ok(TransitionResult::AlreadyTransitioned)
@@ -2165,6 +2371,8 @@ mod tests {
             upbit_entry_usd: Decimal::new(42000, 0),
             bybit_entry: Decimal::new(42000, 0),
             qty: Decimal::new(1, 2), // 0.01 BTC
+            bybit_qty: Decimal::new(1, 2),
+            hedge_ratio: 1.0,
             usd_krw: 1380.0,
             mean: 0.1,
             stddev: 0.05,
@@ -2174,9 +2382,11 @@ mod tests {
                 min_order_qty: Decimal::new(1, 3),
                 min_notional: Decimal::new(5, 0),
                 max_order_qty: Decimal::new(100, 0),
+                contract_multiplier: Decimal::ONE,
             },
             safe_volume_usdt: 1000.0,
             volume_ratio: 0.7,
+            tier: 0,
         }
     }
 
@@ -2310,6 +2520,41 @@ mod tests {
         assert!(bybit_avail < Decimal::from(10_000));
     }
 
+    #[tokio::test]
+    async fn test_entry_hedge_ratio_persists_effective_bybit_qty() {
+        // hedge_ratio 0.9: Bybit 요청 0.009, 체결 0.01 → 초과분은 executor가 정리
+        let (policy, _, pm, _trades, _, _, _, position_store) = make_live_policy(
+            MockOrderResponse {
+                id: "upbit-001".into(),
+                executed_volume: Decimal::new(1, 2),
+                avg_price: Some(Decimal::new(60_000_000, 0)),
+                ..Default::default()
+            },
+            MockOrderResponse {
+                id: "bybit-001".into(),
+                executed_volume: Decimal::new(1, 2),
+                avg_price: Some(Decimal::new(42000, 0)),
+                ..Default::default()
+            },
+        );
+
+        let ctx = EntryContext {
+            bybit_qty: Decimal::new(9, 3),
+            hedge_ratio: 0.9,
+            ..make_entry_ctx()
+        };
+        policy.on_entry_signal(ctx).await.unwrap();
+
+        let pm = pm.lock().await;
+        let position = &pm.open_positions["BTC"][0];
+        let bybit_leg_qty = position.bybit_leg_qty();
+        assert!(bybit_leg_qty < Decimal::new(1, 2));
+
+        let records = position_store.records.lock().unwrap();
+        assert_eq!(records[0].state, "Open");
+        assert_eq!(records[0].bybit_qty, bybit_leg_qty);
+    }
+
     #[tokio::test]
     async fn test_entry_both_failed() {
         let (policy, _, pm, _, _, balance_tracker, _, position_store) = make_live_policy(
@@ -2495,8 +2740,11 @@ mod tests {
                 min_order_qty: Decimal::new(1, 3),
                 min_notional: Decimal::new(5, 0),
                 max_order_qty: Decimal::new(100, 0),
+                contract_multiplier: Decimal::ONE,
             }),
             bybit_price: Decimal::new(41500, 0),
+            tiers: None,
+            position_ids: None,
         };
 
         let result = policy.on_exit_signal(exit_ctx).await;
@@ -2527,6 +2775,8 @@ mod tests {
             mean: 0.1,
             instrument_info: None,
             bybit_price: Decimal::new(41500, 0),
+            tiers: None,
+            position_ids: None,
         };
 
         let result = policy.on_exit_signal(exit_ctx).await;
@@ -2614,6 +2864,7 @@ mod tests {
                 id: pos_id,
                 size_usdt: Decimal::new(1, 2),
                 qty: Decimal::new(1, 2),
+                bybit_qty: Decimal::new(1, 2),
             }],
             usd_krw: 1380.0,
             current_spread_pct: 0.15,
@@ -2624,6 +2875,7 @@ mod tests {
                 min_order_qty: Decimal::new(1, 3),
                 min_notional: Decimal::new(5, 0),
                 max_order_qty: Decimal::new(100, 0),
+                contract_multiplier: Decimal::ONE,
             }),
             exit_upbit_usd: Decimal::new(1, 0),
             exit_bybit: Decimal::new(1, 0),
@@ -2678,6 +2930,7 @@ mod tests {
                 id: pos_id,
                 size_usdt: Decimal::new(100, 0),
                 qty: Decimal::new(1, 2),
+                bybit_qty: Decimal::new(1, 2),
             }],
             usd_krw: 1380.0,
             current_spread_pct: 0.15,
@@ -115,12 +115,15 @@ impl ExecutionPolicy for SimPolicy {
             entry_spread_pct: ctx.spread_pct,
             entry_z_score: ctx.z_score,
             qty: ctx.qty,
+            bybit_qty: Some(ctx.bybit_qty),
+            tier: ctx.tier,
             ..Default::default()
         };
 
         info!(
             coin = ctx.coin.as_str(),
             qty = %ctx.qty,
+            bybit_qty = %ctx.bybit_qty,
             upbit_entry_usd = %ctx.upbit_entry_usd,
             bybit_entry = %ctx.bybit_entry,
             z_score = ctx.z_score,
@@ -128,6 +131,15 @@ impl ExecutionPolicy for SimPolicy {
         );
 
         let mut pm = inner.position_mgr.lock().await;
+        // 같은 분할 진입 단계가 동시 틱에서 먼저 등록되었으면 건너뜀
+        if inner.config.scale_in.enabled && pm.held_tiers(&ctx.coin).contains(&ctx.tier) {
+            info!(
+                coin = ctx.coin.as_str(),
+                tier = ctx.tier,
+                "[SimPolicy] 분할 진입 단계 이미 보유"
+            );
+            return Ok(());
+        }
         if let Err(e) = pm.open_position(pos) {
             warn!(coin = ctx.coin.as_str(), error = %e, "포지션 오픈 실패");
         }
@@ -147,6 +159,7 @@ impl ExecutionPolicy for SimPolicy {
                 .get(ctx.coin.as_str())
                 .map(|ps| {
                     ps.iter()
+                        .filter(|p| ctx.targets(p))
                         .map(|p| {
                             let profit_rate = (ctx.spread_pct - p.entry_spread_pct)
                                 / p.size_usdt().to_f64().unwrap_or(1.0);
@@ -355,6 +368,8 @@ mod tests {
             upbit_entry_usd: Decimal::new(100_000, 0),
             bybit_entry: Decimal::new(100_050, 0),
             qty: Decimal::new(10, 3),
+            bybit_qty: Decimal::new(10, 3),
+            hedge_ratio: 1.0,
             usd_krw: 1380.0,
             mean: 0.1,
             stddev: 0.05,
@@ -364,9 +379,11 @@ mod tests {
                 min_order_qty: Decimal::new(1, 3),
                 min_notional: Decimal::new(5, 0),
                 max_order_qty: Decimal::new(100, 0),
+                contract_multiplier: Decimal::ONE,
             },
             safe_volume_usdt: 1000.0,
             volume_ratio: 0.7,
+            tier: 0,
         }
     }
 
@@ -428,6 +445,8 @@ mod tests {
             mean: 0.1,
             instrument_info: None,
             bybit_price: Decimal::new(100_000, 0),
+            tiers: None,
+            position_ids: None,
         };
         policy.on_exit_signal(exit_ctx).await.unwrap();
 
@@ -438,6 +457,72 @@ mod tests {
         assert_eq!(trades.len(), 1);
     }
 
+    #[tokio::test]
+    async fn test_sim_policy_exit_only_targeted_tiers() {
+        let (policy, pm, trades, _counters) = make_sim_policy();
+
+        // 단계 0, 1 진입
+        policy.on_entry_signal(make_entry_ctx()).await.unwrap();
+        let mut tier1 = make_entry_ctx();
+        tier1.tier = 1;
+        policy.on_entry_signal(tier1).await.unwrap();
+        assert_eq!(pm.lock().await.held_tiers("BTC"), vec![0, 1]);
+
+        // 단계 1만 청산
+        let exit_ctx = ExitContext {
+            coin: "BTC".to_string(),
+            z_score: 1.0,
+            spread_pct: 0.1,
+            exit_upbit_usd: Decimal::new(100_100, 0),
+            exit_bybit: Decimal::new(100_000, 0),
+            usd_krw: 1380.0,
+            exit_safe_volume_usdt: Some(10000.0),
+            mean: 0.1,
+            instrument_info: None,
+            bybit_price: Decimal::new(100_000, 0),
+            tiers: Some(vec![1]),
+            position_ids: None,
+        };
+        policy.on_exit_signal(exit_ctx).await.unwrap();
+
+        assert_eq!(pm.lock().await.held_tiers("BTC"), vec![0]);
+        let trades = trades.lock().await;
+        assert_eq!(trades.len(), 1);
+        assert_eq!(trades[0].tier, 1);
+    }
+
+    #[tokio::test]
+    async fn test_sim_policy_exit_only_targeted_positions() {
+        let (policy, pm, trades, _counters) = make_sim_policy();
+        policy.on_entry_signal(make_entry_ctx()).await.unwrap();
+        policy.on_entry_signal(make_entry_ctx()).await.unwrap();
+        let target_id = pm.lock().await.open_positions["BTC"][1].id;
+
+        // 손절 규칙으로 지정된 포지션만 청산
+        let exit_ctx = ExitContext {
+            coin: "BTC".to_string(),
+            z_score: 3.5,
+            spread_pct: 1.2,
+            exit_upbit_usd: Decimal::new(100_100, 0),
+            exit_bybit: Decimal::new(100_000, 0),
+            usd_krw: 1380.0,
+            exit_safe_volume_usdt: Some(10000.0),
+            mean: 0.1,
+            instrument_info: None,
+            bybit_price: Decimal::new(100_000, 0),
+            tiers: None,
+            position_ids: Some(vec![target_id]),
+        };
+        policy.on_exit_signal(exit_ctx).await.unwrap();
+
+        let pm = pm.lock().await;
+        assert_eq!(pm.open_count(), 1);
+        assert_ne!(pm.open_positions["BTC"][0].id, target_id);
+        let trades = trades.lock().await;
+        assert_eq!(trades.len(), 1);
+        assert_eq!(trades[0].id, target_id);
+    }
+
     #[tokio::test]
     async fn test_sim_policy_is_entry_allowed() {
         let (policy, _pm, _trades, _counters) = make_sim_policy();
@@ -464,6 +549,7 @@ mod tests {
                 id: position_id,
                 size_usdt: Decimal::new(1000, 0),
                 qty: Decimal::new(10, 3),
+                bybit_qty: Decimal::new(10, 3),
             }],
             usd_krw: 1380.0,
             current_spread_pct: 0.15,
@@ -506,6 +592,7 @@ mod tests {
                 id: position_id,
                 size_usdt: Decimal::new(1000, 0),
                 qty: Decimal::new(10, 3),
+                bybit_qty: Decimal::new(10, 3),
             }],
             usd_krw: 1380.0,
             current_spread_pct: 0.15,
@@ -540,6 +627,8 @@ mod tests {
             mean: 0.1,
             instrument_info: None,
             bybit_price: Decimal::new(100_000, 0),
+            tiers: None,
+            position_ids: None,
         };
         policy.on_exit_signal(exit_ctx).await.unwrap();
 
@@ -568,6 +657,8 @@ mod tests {
             mean: 0.1,
             instrument_info: None,
             bybit_price: Decimal::new(100_050, 0),
+            tiers: None,
+            position_ids: None,
         };
         policy.on_exit_signal(exit_ctx).await.unwrap();
 
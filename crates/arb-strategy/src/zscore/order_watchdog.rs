@@ -0,0 +1,574 @@
+//! 미체결 주문 watchdog.
+//!
+//! `limit_gtc_cancel` 주문은 발주와 취소 사이에 프로세스가 죽거나 네트워크가 끊기면
+//! 호가창에 남습니다. Bybit은 거래소 측 Disconnect Cancel All(DCP)로 보호하지만
+//! Upbit에는 해당 기능이 없으므로, 이 인스턴스가 발주한 주문(client order ID 접두사
+//! [`ClientOrderIdScope::instance_prefix`])을 주기적으로 점검해 다음 시점에 취소합니다.
+//! 같은 계정을 공유하는 다른 인스턴스의 주문은 접두사가 달라 건드리지 않습니다.
+//!
+//! - 시작 시: 이 인스턴스의 이전 세션이 남긴 주문 전량
+//! - 거래소 재연결 시 (조회 실패 후 첫 성공): 최소 경과 시간을 넘긴 주문
+//! - heartbeat 유실 시 (분봉 마감 중단): 전략 태그 주문 전량
+//! - 그 외 주기 점검: 최소 경과 시간을 넘긴 주문 (실행 엔진의 취소가 실패한 잔여분)
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+
+use arb_exchange::{ExchangeResult, Order, OrderManagement};
+use chrono::Utc;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+/// 전략이 발주하는 주문의 client order ID 공통 접두사.
+///
+/// Upbit `identifier`, Bybit `orderLinkId`로 전달됩니다.
+pub const CLIENT_ORDER_ID_PREFIX: &str = "arb-";
+
+/// client order ID 최대 길이 (Bybit `orderLinkId` 제한).
+const CLIENT_ORDER_ID_MAX_LEN: usize = 36;
+
+/// 인스턴스/세션별 client order ID 발급 범위.
+///
+/// 형식은 `arb-<인스턴스 태그 6자>-<세션 태그 4자>-<난수 20자>` (36자)입니다.
+/// 인스턴스 태그는 인스턴스 이름의 FNV-1a 해시, 세션 태그는 세션 ID 하위 16비트(16진수)입니다.
+/// watchdog는 인스턴스 접두사로 점검 대상을 한정하므로 같은 계정을 공유하는 다른 인스턴스의
+/// 주문은 취소하지 않고, 세션 태그로 이전 세션이 남긴 주문을 구분해 기록합니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientOrderIdScope {
+    /// `arb-<인스턴스 태그>-`
+    instance_prefix: String,
+    /// `arb-<인스턴스 태그>-<세션 태그>-`
+    session_prefix: String,
+}
+
+impl ClientOrderIdScope {
+    /// 인스턴스 이름과 DB 세션 ID로 범위를 생성합니다.
+    pub fn new(instance_name: &str, session_id: i64) -> Self {
+        let instance_prefix = format!(
+            "{CLIENT_ORDER_ID_PREFIX}{:06x}-",
+            fnv1a_32(instance_name.as_bytes()) & 0x00ff_ffff
+        );
+        let session_prefix = format!("{instance_prefix}{:04x}-", session_id & 0xffff);
+        Self {
+            instance_prefix,
+            session_prefix,
+        }
+    }
+
+    /// 이 인스턴스의 모든 세션에 공통인 접두사.
+    pub fn instance_prefix(&self) -> &str {
+        &self.instance_prefix
+    }
+
+    /// 현재 세션의 접두사.
+    pub fn session_prefix(&self) -> &str {
+        &self.session_prefix
+    }
+
+    /// 현재 세션 접두사가 붙은 새 client order ID를 생성합니다.
+    pub fn new_client_order_id(&self) -> String {
+        let random = Uuid::new_v4().simple().to_string();
+        let random_len = CLIENT_ORDER_ID_MAX_LEN - self.session_prefix.len();
+        format!("{}{}", self.session_prefix, &random[..random_len])
+    }
+
+    /// 이 인스턴스(현재 또는 이전 세션)가 발주한 주문인지 확인합니다.
+    pub fn is_own_order(&self, order: &Order) -> bool {
+        order
+            .identifier
+            .as_deref()
+            .is_some_and(|id| id.starts_with(&self.instance_prefix))
+    }
+
+    /// 현재 세션이 발주한 주문인지 확인합니다.
+    pub fn is_current_session(&self, order: &Order) -> bool {
+        order
+            .identifier
+            .as_deref()
+            .is_some_and(|id| id.starts_with(&self.session_prefix))
+    }
+}
+
+/// 32비트 FNV-1a 해시 (실행/빌드 간 값이 고정되어야 하는 인스턴스 태그용).
+fn fnv1a_32(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0x811c_9dc5, |hash, b| {
+        (hash ^ u32::from(*b)).wrapping_mul(0x0100_0193)
+    })
+}
+
+/// 전략 heartbeat 핸들.
+///
+/// 전략이 살아 있는 동안 [`beat`](Self::beat)을 주기적으로 호출합니다.
+/// 첫 호출 전까지는 heartbeat 유실을 판정하지 않습니다 (워밍업 구간).
+#[derive(Debug, Clone, Default)]
+pub struct WatchdogHeartbeat {
+    /// 마지막 heartbeat 시각 (Unix epoch 밀리초, 0이면 미기록).
+    last_beat_ms: Arc<AtomicI64>,
+}
+
+impl WatchdogHeartbeat {
+    /// 미기록 상태의 heartbeat를 생성합니다.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 현재 시각으로 heartbeat를 갱신합니다.
+    pub fn beat(&self) {
+        self.last_beat_ms
+            .store(Utc::now().timestamp_millis(), Ordering::Relaxed);
+    }
+
+    /// 마지막 heartbeat 이후 `timeout`이 지났는지 반환합니다. 미기록이면 false.
+    pub fn is_stale(&self, timeout: Duration) -> bool {
+        let last = self.last_beat_ms.load(Ordering::Relaxed);
+        if last == 0 {
+            return false;
+        }
+        let elapsed_ms = Utc::now().timestamp_millis() - last;
+        elapsed_ms > timeout.as_millis() as i64
+    }
+}
+
+/// 전략 태그 미체결 주문 watchdog.
+pub struct OrderWatchdog<E: OrderManagement> {
+    /// 거래소 이름 (로그/메트릭 라벨).
+    exchange_name: &'static str,
+    /// 주문 조회/취소 클라이언트.
+    client: Arc<E>,
+    /// 점검 대상 client order ID 범위 (이 인스턴스).
+    scope: ClientOrderIdScope,
+    /// 점검 주기.
+    interval: Duration,
+    /// heartbeat 유실 판정 시간.
+    heartbeat_timeout: Duration,
+    /// 재연결 시 취소 대상 최소 경과 시간 (진행 중 주문 보호).
+    min_order_age: Duration,
+    /// 전략 heartbeat.
+    heartbeat: WatchdogHeartbeat,
+}
+
+impl<E: OrderManagement> OrderWatchdog<E> {
+    /// 새 watchdog를 생성합니다.
+    ///
+    /// # 인자
+    ///
+    /// * `exchange_name` - 거래소 이름 (로그/메트릭 라벨)
+    /// * `client` - 주문 조회/취소 클라이언트
+    /// * `scope` - 점검 대상 client order ID 범위
+    /// * `interval` - 점검 주기
+    /// * `heartbeat_timeout` - heartbeat 유실 판정 시간
+    /// * `min_order_age` - 재연결 시 취소 대상 최소 경과 시간
+    pub fn new(
+        exchange_name: &'static str,
+        client: Arc<E>,
+        scope: ClientOrderIdScope,
+        interval: Duration,
+        heartbeat_timeout: Duration,
+        min_order_age: Duration,
+    ) -> Self {
+        Self {
+            exchange_name,
+            client,
+            scope,
+            interval,
+            heartbeat_timeout,
+            min_order_age,
+            heartbeat: WatchdogHeartbeat::new(),
+        }
+    }
+
+    /// 전략에 전달할 heartbeat 핸들을 반환합니다.
+    pub fn heartbeat_handle(&self) -> WatchdogHeartbeat {
+        self.heartbeat.clone()
+    }
+
+    /// `min_age` 이상 경과한 이 인스턴스의 미체결 주문을 취소하고, 취소한 주문 수를 반환합니다.
+    ///
+    /// 개별 취소 실패는 경고만 남기고 다음 주문으로 진행합니다
+    /// (그 사이 체결/취소된 주문은 다음 점검에서 사라짐).
+    pub async fn sweep(&self, min_age: Duration, reason: &'static str) -> ExchangeResult<usize> {
+        let open_orders = self.client.get_open_orders(None).await?;
+        let now = Utc::now();
+        let targets: Vec<Order> = open_orders
+            .into_iter()
+            .filter(|o| self.scope.is_own_order(o))
+            .filter(|o| {
+                (now - o.created_at)
+                    .to_std()
+                    .is_ok_and(|age| age >= min_age)
+            })
+            .collect();
+
+        let mut cancelled = 0usize;
+        for order in &targets {
+            match self.client.cancel_order(&order.id).await {
+                Ok(_) => {
+                    cancelled += 1;
+                    warn!(
+                        exchange = self.exchange_name,
+                        reason = reason,
+                        order_id = %order.id,
+                        market = %order.market,
+                        identifier = ?order.identifier,
+                        previous_session = !self.scope.is_current_session(order),
+                        remaining = %order.remaining_volume,
+                        "미체결 전략 주문 취소"
+                    );
+                }
+                Err(e) => warn!(
+                    exchange = self.exchange_name,
+                    reason = reason,
+                    order_id = %order.id,
+                    error = %e,
+                    "미체결 전략 주문 취소 실패"
+                ),
+            }
+        }
+
+        if cancelled > 0 {
+            arb_metrics::global()
+                .counter(
+                    "arb_order_watchdog_cancels_total",
+                    "watchdog가 취소한 미체결 전략 주문 수",
+                    &[("exchange", self.exchange_name), ("reason", reason)],
+                )
+                .inc_by(cancelled as u64);
+        }
+        debug!(
+            exchange = self.exchange_name,
+            reason = reason,
+            targets = targets.len(),
+            cancelled = cancelled,
+            "미체결 전략 주문 점검 완료"
+        );
+        Ok(cancelled)
+    }
+
+    /// `cancel`이 취소될 때까지 watchdog를 실행합니다.
+    ///
+    /// 시작 시 전략 태그 주문을 전량 취소한 뒤, 주기마다 거래소 연결 상태와
+    /// heartbeat를 점검합니다.
+    pub async fn run(self, cancel: CancellationToken) {
+        info!(
+            exchange = self.exchange_name,
+            interval_sec = self.interval.as_secs(),
+            heartbeat_timeout_sec = self.heartbeat_timeout.as_secs(),
+            "미체결 주문 watchdog 시작"
+        );
+
+        // 시작 시 점검 실패는 첫 주기에서 재연결 점검으로 이어짐
+        let mut disconnected = match self.sweep(Duration::ZERO, "startup").await {
+            Ok(_) => false,
+            Err(e) => {
+                warn!(exchange = self.exchange_name, error = %e, "시작 시 미체결 주문 점검 실패");
+                true
+            }
+        };
+        let mut heartbeat_lost = false;
+
+        let mut ticker = tokio::time::interval(self.interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        ticker.tick().await;
+
+        loop {
+            tokio::select! {
+                _ = cancel.cancelled() => {
+                    info!(exchange = self.exchange_name, "미체결 주문 watchdog 종료");
+                    return;
+                }
+                _ = ticker.tick() => {}
+            }
+
+            let stale = self.heartbeat.is_stale(self.heartbeat_timeout);
+            if stale && !heartbeat_lost {
+                warn!(
+                    exchange = self.exchange_name,
+                    timeout_sec = self.heartbeat_timeout.as_secs(),
+                    "전략 heartbeat 유실, 전략 태그 미체결 주문 전량 취소"
+                );
+            } else if !stale && heartbeat_lost {
+                info!(exchange = self.exchange_name, "전략 heartbeat 복구");
+            }
+
+            // 유실 첫 점검은 경과 시간과 무관하게 전량, 이후는 진행 중 주문 보호
+            let (min_age, reason) = if stale && !heartbeat_lost {
+                (Duration::ZERO, "heartbeat_lost")
+            } else if disconnected {
+                (self.min_order_age, "reconnect")
+            } else {
+                (self.min_order_age, "periodic")
+            };
+
+            match self.sweep(min_age, reason).await {
+                Ok(_) => {
+                    if disconnected {
+                        info!(exchange = self.exchange_name, "거래소 재연결 확인");
+                    }
+                    disconnected = false;
+                    heartbeat_lost = stale;
+                }
+                Err(e) => {
+                    if !disconnected {
+                        warn!(exchange = self.exchange_name, error = %e, "미체결 주문 조회 실패, 연결 끊김으로 간주");
+                    }
+                    disconnected = true;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arb_exchange::{Balance, ExchangeError, OrderRequest, OrderSide, OrderStatus, OrderType};
+    use rust_decimal::Decimal;
+    use std::sync::Mutex;
+
+    struct MockExchange {
+        open_orders: Mutex<Vec<Order>>,
+        cancelled: Mutex<Vec<String>>,
+        fail_query: Mutex<bool>,
+    }
+
+    impl MockExchange {
+        fn new(open_orders: Vec<Order>) -> Self {
+            Self {
+                open_orders: Mutex::new(open_orders),
+                cancelled: Mutex::new(Vec::new()),
+                fail_query: Mutex::new(false),
+            }
+        }
+    }
+
+    fn make_order(id: &str, identifier: Option<&str>, age_sec: i64) -> Order {
+        Order {
+            id: id.to_string(),
+            market: "KRW-BTC".to_string(),
+            side: OrderSide::Buy,
+            order_type: OrderType::Limit,
+            status: OrderStatus::Wait,
+            volume: Decimal::ONE,
+            remaining_volume: Decimal::ONE,
+            executed_volume: Decimal::ZERO,
+            price: Some(Decimal::from(100)),
+            avg_price: None,
+            paid_fee: Decimal::ZERO,
+            created_at: Utc::now() - chrono::Duration::seconds(age_sec),
+            identifier: identifier.map(str::to_string),
+        }
+    }
+
+    impl OrderManagement for MockExchange {
+        async fn place_order(&self, _request: &OrderRequest) -> ExchangeResult<Order> {
+            unimplemented!()
+        }
+
+        async fn cancel_order(&self, order_id: &str) -> ExchangeResult<Order> {
+            self.cancelled.lock().unwrap().push(order_id.to_string());
+            let mut orders = self.open_orders.lock().unwrap();
+            let idx = orders
+                .iter()
+                .position(|o| o.id == order_id)
+                .ok_or_else(|| ExchangeError::OrderNotFound(order_id.to_string()))?;
+            let mut order = orders.remove(idx);
+            order.status = OrderStatus::Cancelled;
+            Ok(order)
+        }
+
+        async fn get_order(&self, order_id: &str) -> ExchangeResult<Order> {
+            Err(ExchangeError::OrderNotFound(order_id.to_string()))
+        }
+
+        async fn get_order_by_client_id(&self, client_order_id: &str) -> ExchangeResult<Order> {
+            Err(ExchangeError::OrderNotFound(client_order_id.to_string()))
+        }
+
+        async fn get_open_orders(&self, _market: Option<&str>) -> ExchangeResult<Vec<Order>> {
+            if *self.fail_query.lock().unwrap() {
+                return Err(ExchangeError::ExchangeOffline("network".to_string()));
+            }
+            Ok(self.open_orders.lock().unwrap().clone())
+        }
+
+        async fn get_balances(&self) -> ExchangeResult<Vec<Balance>> {
+            Ok(Vec::new())
+        }
+
+        async fn get_balance(&self, currency: &str) -> ExchangeResult<Balance> {
+            Err(ExchangeError::InvalidParameter(currency.to_string()))
+        }
+    }
+
+    fn scope() -> ClientOrderIdScope {
+        ClientOrderIdScope::new("main", 7)
+    }
+
+    /// 현재 세션 접두사가 붙은 identifier.
+    fn tagged(suffix: &str) -> String {
+        format!("{}{suffix}", scope().session_prefix())
+    }
+
+    fn make_watchdog(client: Arc<MockExchange>) -> OrderWatchdog<MockExchange> {
+        OrderWatchdog::new(
+            "mock",
+            client,
+            scope(),
+            Duration::from_millis(10),
+            Duration::from_secs(180),
+            Duration::from_secs(30),
+        )
+    }
+
+    #[test]
+    fn test_new_client_order_id_format() {
+        let scope = scope();
+        let id = scope.new_client_order_id();
+        assert!(id.starts_with(CLIENT_ORDER_ID_PREFIX));
+        assert!(id.starts_with(scope.session_prefix()));
+        assert_eq!(scope.session_prefix().len(), 16);
+        assert_eq!(id.len(), CLIENT_ORDER_ID_MAX_LEN);
+        assert!(id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-'));
+        assert_ne!(id, scope.new_client_order_id());
+        // 인스턴스 태그는 실행마다 같아야 이전 세션 주문을 찾을 수 있음
+        assert_eq!(scope, ClientOrderIdScope::new("main", 7));
+        assert_eq!(scope.instance_prefix(), "arb-90e208-");
+    }
+
+    #[test]
+    fn test_scope_distinguishes_instances_and_sessions() {
+        let current = scope();
+        let previous = ClientOrderIdScope::new("main", 6);
+        let other = ClientOrderIdScope::new("alt", 7);
+        assert_eq!(current.instance_prefix(), previous.instance_prefix());
+        assert_ne!(current.instance_prefix(), other.instance_prefix());
+
+        let mine = make_order("1", Some(&current.new_client_order_id()), 0);
+        let stale = make_order("2", Some(&previous.new_client_order_id()), 0);
+        let foreign = make_order("3", Some(&other.new_client_order_id()), 0);
+        assert!(current.is_own_order(&mine) && current.is_current_session(&mine));
+        assert!(current.is_own_order(&stale) && !current.is_current_session(&stale));
+        assert!(!current.is_own_order(&foreign));
+        assert!(!current.is_own_order(&make_order("4", Some("manual-1"), 0)));
+        assert!(!current.is_own_order(&make_order("5", None, 0)));
+    }
+
+    #[test]
+    fn test_heartbeat_unarmed_until_first_beat() {
+        let hb = WatchdogHeartbeat::new();
+        assert!(!hb.is_stale(Duration::ZERO));
+
+        hb.last_beat_ms
+            .store(Utc::now().timestamp_millis() - 10_000, Ordering::Relaxed);
+        assert!(hb.is_stale(Duration::from_secs(5)));
+        assert!(!hb.is_stale(Duration::from_secs(60)));
+
+        hb.beat();
+        assert!(!hb.is_stale(Duration::from_secs(5)));
+    }
+
+    #[tokio::test]
+    async fn test_sweep_cancels_only_tagged_orders_past_min_age() {
+        let client = Arc::new(MockExchange::new(vec![
+            make_order("old-tagged", Some(&tagged("1")), 120),
+            make_order("new-tagged", Some(&tagged("2")), 1),
+            make_order("manual", Some("manual-1"), 120),
+            make_order("untagged", None, 120),
+            make_order(
+                "other-instance",
+                Some(&ClientOrderIdScope::new("alt", 7).new_client_order_id()),
+                120,
+            ),
+        ]));
+        let watchdog = make_watchdog(Arc::clone(&client));
+
+        let cancelled = watchdog
+            .sweep(Duration::from_secs(30), "periodic")
+            .await
+            .unwrap();
+        assert_eq!(cancelled, 1);
+        assert_eq!(*client.cancelled.lock().unwrap(), vec!["old-tagged"]);
+
+        let cancelled = watchdog.sweep(Duration::ZERO, "startup").await.unwrap();
+        assert_eq!(cancelled, 1);
+        assert_eq!(client.open_orders.lock().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_run_startup_sweep_cancels_all_tagged_orders() {
+        let client = Arc::new(MockExchange::new(vec![
+            make_order("a", Some(&tagged("1")), 0),
+            make_order(
+                "b",
+                Some(&ClientOrderIdScope::new("main", 6).new_client_order_id()),
+                0,
+            ),
+            make_order("manual", None, 0),
+        ]));
+        let watchdog = make_watchdog(Arc::clone(&client));
+        let cancel = CancellationToken::new();
+        let handle = tokio::spawn(watchdog.run(cancel.clone()));
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        cancel.cancel();
+        handle.await.unwrap();
+
+        let cancelled = client.cancelled.lock().unwrap().clone();
+        assert_eq!(cancelled, vec!["a", "b"]);
+    }
+
+    #[tokio::test]
+    async fn test_run_heartbeat_loss_cancels_fresh_orders() {
+        let client = Arc::new(MockExchange::new(Vec::new()));
+        let watchdog = make_watchdog(Arc::clone(&client));
+        let hb = watchdog.heartbeat_handle();
+        // heartbeat가 5분 전에 멈춤
+        hb.last_beat_ms
+            .store(Utc::now().timestamp_millis() - 300_000, Ordering::Relaxed);
+
+        let cancel = CancellationToken::new();
+        let handle = tokio::spawn(watchdog.run(cancel.clone()));
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        // 최소 경과 시간 미만이어도 heartbeat 유실 시에는 취소 대상
+        client
+            .open_orders
+            .lock()
+            .unwrap()
+            .push(make_order("fresh", Some(&tagged("1")), 0));
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        cancel.cancel();
+        handle.await.unwrap();
+
+        assert_eq!(*client.cancelled.lock().unwrap(), vec!["fresh"]);
+    }
+
+    #[tokio::test]
+    async fn test_run_reconnect_sweep_after_query_failure() {
+        let client = Arc::new(MockExchange::new(Vec::new()));
+        *client.fail_query.lock().unwrap() = true;
+        let watchdog = make_watchdog(Arc::clone(&client));
+        let hb = watchdog.heartbeat_handle();
+        hb.beat();
+
+        let cancel = CancellationToken::new();
+        let handle = tokio::spawn(watchdog.run(cancel.clone()));
+        tokio::time::sleep(Duration::from_millis(25)).await;
+
+        {
+            let mut orders = client.open_orders.lock().unwrap();
+            orders.push(make_order("orphan", Some(&tagged("1")), 120));
+            orders.push(make_order("in-flight", Some(&tagged("2")), 1));
+        }
+        *client.fail_query.lock().unwrap() = false;
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        cancel.cancel();
+        handle.await.unwrap();
+
+        // 재연결 점검은 진행 중 주문(최소 경과 시간 미만)을 건드리지 않음
+        assert_eq!(*client.cancelled.lock().unwrap(), vec!["orphan"]);
+    }
+}
@@ -27,6 +27,8 @@ pub enum Exchange {
     Upbit,
     /// Bybit (글로벌/USDT).
     Bybit,
+    /// Bithumb (한국/KRW).
+    Bithumb,
 }
 
 /// 오더북 캐시.
@@ -38,6 +40,8 @@ pub struct OrderBookCache {
     upbit: HashMap<String, CachedOrderBook>,
     /// Bybit 오더북 캐시.
     bybit: HashMap<String, CachedOrderBook>,
+    /// Bithumb 오더북 캐시.
+    bithumb: HashMap<String, CachedOrderBook>,
     /// 오더북 조회 중 플래그 (거래소, 코인).
     computing_flags: HashMap<(Exchange, String), bool>,
 }
@@ -48,6 +52,7 @@ impl OrderBookCache {
         Self {
             upbit: HashMap::new(),
             bybit: HashMap::new(),
+            bithumb: HashMap::new(),
             computing_flags: HashMap::new(),
         }
     }
@@ -61,6 +66,7 @@ impl OrderBookCache {
         let map = match exchange {
             Exchange::Upbit => &mut self.upbit,
             Exchange::Bybit => &mut self.bybit,
+            Exchange::Bithumb => &mut self.bithumb,
         };
         map.insert(coin.to_string(), cached);
         trace!(exchange = ?exchange, coin = %coin, "오더북 캐시 갱신");
@@ -71,6 +77,7 @@ impl OrderBookCache {
         let map = match exchange {
             Exchange::Upbit => &self.upbit,
             Exchange::Bybit => &self.bybit,
+            Exchange::Bithumb => &self.bithumb,
         };
         map.get(coin)
     }
@@ -114,6 +121,8 @@ pub struct ObCacheData {
     upbit: HashMap<String, CachedOrderBook>,
     /// Bybit 오더북 캐시.
     bybit: HashMap<String, CachedOrderBook>,
+    /// Bithumb 오더북 캐시.
+    bithumb: HashMap<String, CachedOrderBook>,
 }
 
 impl ObCacheData {
@@ -122,6 +131,7 @@ impl ObCacheData {
         Self {
             upbit: HashMap::new(),
             bybit: HashMap::new(),
+            bithumb: HashMap::new(),
         }
     }
 
@@ -134,6 +144,7 @@ impl ObCacheData {
         let map = match exchange {
             Exchange::Upbit => &mut self.upbit,
             Exchange::Bybit => &mut self.bybit,
+            Exchange::Bithumb => &mut self.bithumb,
         };
         map.insert(coin.to_string(), cached);
         trace!(exchange = ?exchange, coin = %coin, "오더북 캐시 갱신");
@@ -144,6 +155,7 @@ impl ObCacheData {
         let map = match exchange {
             Exchange::Upbit => &self.upbit,
             Exchange::Bybit => &self.bybit,
+            Exchange::Bithumb => &self.bithumb,
         };
         map.get(coin)
     }
@@ -155,10 +167,11 @@ impl ObCacheData {
             .unwrap_or(false)
     }
 
-    /// 코인 관련 캐시를 양쪽 거래소에서 제거합니다.
+    /// 코인 관련 캐시를 모든 거래소에서 제거합니다.
     pub fn remove_coin(&mut self, coin: &str) {
         self.upbit.remove(coin);
         self.bybit.remove(coin);
+        self.bithumb.remove(coin);
     }
 }
 
@@ -218,7 +231,7 @@ impl ComputingFlags {
         debug!(exchange = ?exchange, coin = %coin, "computing flag 해제");
     }
 
-    /// 특정 코인의 computing flag를 양쪽 거래소에서 제거합니다.
+    /// 특정 코인의 computing flag를 모든 거래소에서 제거합니다.
     pub fn remove_coin(&self, coin: &str) {
         let mut flags = self.inner.lock();
         flags.retain(|k, _| k.1 != coin);
@@ -580,6 +593,65 @@ pub fn calculate_exit_safe_volume(
     last_valid
 }
 
+/// 손절 청산용 유동성 볼륨 계산 (Upbit 매도 + Bybit 롱 커버).
+///
+/// [`calculate_exit_safe_volume`]과 같은 호가(Upbit bids + Bybit asks)를 소비하되,
+/// 수익성 검증 없이 양쪽 호가 깊이만으로 동시에 체결 가능한 수량을 계산합니다.
+/// 손절은 손실 구간에서 발생하므로 수익성 게이트를 적용하면 청산이 항상 거부됩니다.
+///
+/// # 인자
+///
+/// * `upbit_bids` - Upbit 매수호가 (price_krw, size_coins), 가격 내림차순
+/// * `bybit_asks` - Bybit 매도호가 (price_usdt, size_coins), 가격 오름차순
+///
+/// # 반환값
+///
+/// 양쪽 호가 깊이 중 작은 쪽 전량. 오더북이 비어있으면 `None`.
+pub fn calculate_exit_liquidity_volume(
+    upbit_bids: &[(f64, f64)],
+    bybit_asks: &[(f64, f64)],
+) -> Option<SafeVolumeResult> {
+    if upbit_bids.is_empty() || bybit_asks.is_empty() {
+        return None;
+    }
+
+    let upbit_depth: f64 = upbit_bids.iter().map(|(_, size)| size).sum();
+    let bybit_depth: f64 = bybit_asks.iter().map(|(_, size)| size).sum();
+    let total_coins = upbit_depth.min(bybit_depth);
+    if total_coins <= 0.0 {
+        return None;
+    }
+
+    // 가격 순서대로 total_coins만큼 소비한 VWAP
+    let vwap = |levels: &[(f64, f64)]| {
+        let mut remaining = total_coins;
+        let mut notional = 0.0;
+        for &(price, size) in levels {
+            let consume = remaining.min(size);
+            notional += consume * price;
+            remaining -= consume;
+            if remaining <= 0.0 {
+                break;
+            }
+        }
+        notional / total_coins
+    };
+    let upbit_vwap = vwap(upbit_bids);
+    let bybit_vwap = vwap(bybit_asks);
+
+    // 슬리피지는 최우선 호가 대비 비율이므로 환율 없이 계산
+    let exit_slippage_pct = (upbit_bids[0].0 - upbit_vwap) / upbit_bids[0].0 * 100.0
+        + (bybit_vwap - bybit_asks[0].0) / bybit_asks[0].0 * 100.0;
+
+    Some(SafeVolumeResult {
+        safe_volume_coins: total_coins,
+        safe_volume_usdt: total_coins * bybit_vwap,
+        upbit_vwap,
+        bybit_vwap,
+        entry_slippage_pct: exit_slippage_pct,
+    })
+}
+
 /// OrderBook의 호가를 `(f64, f64)` 튜플 슬라이스로 변환합니다.
 ///
 /// `OrderBookLevel`의 `Decimal` 필드를 `f64`로 변환하여
@@ -807,6 +879,30 @@ mod tests {
         assert!(sv.safe_volume_usdt > 0.0);
     }
 
+    #[test]
+    fn test_exit_liquidity_volume_ignores_profitability() {
+        // 스프레드 확대: Upbit bids가 Bybit asks보다 크게 낮아 수익성 게이트는 거부
+        let upbit_bids = vec![(1_300_000.0, 0.5), (1_299_000.0, 1.0)];
+        let bybit_asks = vec![(1000.0, 1.0), (1001.0, 2.0)];
+        let usd_krw = 1400.0;
+
+        assert!(
+            calculate_exit_safe_volume(&upbit_bids, &bybit_asks, 0.0, 0.0005, 0.00055, usd_krw)
+                .is_none()
+        );
+
+        // 유동성 볼륨: 양쪽 깊이 중 작은 쪽(Upbit 1.5) 전량
+        let lv = calculate_exit_liquidity_volume(&upbit_bids, &bybit_asks).unwrap();
+        assert!((lv.safe_volume_coins - 1.5).abs() < 1e-12);
+        // Bybit VWAP = (1.0 × 1000 + 0.5 × 1001) / 1.5
+        let expected_bybit_vwap = (1000.0 + 500.5) / 1.5;
+        assert!((lv.bybit_vwap - expected_bybit_vwap).abs() < 1e-9);
+        assert!((lv.safe_volume_usdt - 1.5 * expected_bybit_vwap).abs() < 1e-9);
+        assert!(lv.entry_slippage_pct > 0.0);
+
+        assert!(calculate_exit_liquidity_volume(&[], &bybit_asks).is_none());
+    }
+
     #[test]
     fn test_safe_volume_ratio() {
         // < 100,000 → 0.5
@@ -917,6 +1013,27 @@ mod tests {
         assert!(data.get(Exchange::Bybit, "BTC").is_none());
     }
 
+    #[test]
+    fn test_ob_cache_data_bithumb_remove_coin() {
+        let mut data = ObCacheData::new();
+        data.update(
+            Exchange::Upbit,
+            "BTC",
+            make_orderbook(vec![(100, 10)], vec![(99, 10)]),
+        );
+        data.update(
+            Exchange::Bithumb,
+            "BTC",
+            make_orderbook(vec![(101, 10)], vec![(100, 10)]),
+        );
+        assert!(data.is_fresh(Exchange::Bithumb, "BTC", 5));
+        assert!(data.get(Exchange::Bybit, "BTC").is_none());
+
+        data.remove_coin("BTC");
+        assert!(data.get(Exchange::Upbit, "BTC").is_none());
+        assert!(data.get(Exchange::Bithumb, "BTC").is_none());
+    }
+
     // --- ComputingFlags 테스트 ---
 
     #[test]
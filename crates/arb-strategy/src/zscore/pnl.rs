@@ -7,6 +7,8 @@ use rust_decimal::Decimal;
 use serde::Serialize;
 use tracing::debug;
 
+use crate::zscore::tca::ExecutionQuality;
+
 /// 청산된 포지션 기록.
 #[derive(Debug, Clone, Serialize)]
 pub struct ClosedPosition {
@@ -20,7 +22,7 @@ pub struct ClosedPosition {
     pub exit_time: DateTime<Utc>,
     /// 보유 시간 (분).
     pub holding_minutes: u64,
-    /// 청산 수량 (양 leg 동일).
+    /// 청산 수량 (Upbit 레그 기준).
     #[serde(with = "rust_decimal::serde::str")]
     pub qty: Decimal,
     /// 포지션 크기 (USDT, 단일 leg 기준).
@@ -70,6 +72,8 @@ pub struct ClosedPosition {
     pub exit_usd_krw: f64,
     /// 강제 청산 여부.
     pub is_liquidated: bool,
+    /// 분할 진입 단계 (0부터).
+    pub tier: u8,
     /// 실 Upbit 수수료 (라이브 전용, Order.paid_fee 기반).
     #[serde(
         with = "rust_decimal::serde::str_option",
@@ -98,6 +102,12 @@ pub struct ClosedPosition {
         default
     )]
     pub adjustment_cost: Option<Decimal>,
+    /// 진입 체결 품질 (라이브 전용, 포지션을 소진하는 청산 기록에만 부착).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub entry_quality: Option<ExecutionQuality>,
+    /// 청산 체결 품질 (라이브 전용).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub exit_quality: Option<ExecutionQuality>,
 }
 
 /// Equity curve에서 max drawdown을 계산합니다 (USDT 절대값).
@@ -181,10 +191,13 @@ mod tests {
             entry_usd_krw: 1380.0,
             exit_usd_krw: 1381.0,
             is_liquidated: false,
+            tier: 0,
             actual_upbit_fee: None,
             actual_bybit_fee: None,
             funding_fee: None,
             adjustment_cost: None,
+            entry_quality: None,
+            exit_quality: None,
         }
     }
 
@@ -270,6 +283,8 @@ mod tests {
             actual_bybit_fee: Some(Decimal::new(3, 1)), // 0.3 USDT
             funding_fee: Some(Decimal::new(-2, 1)),     // -0.2 USDT (수취)
             adjustment_cost: Some(Decimal::new(1, 2)),  // 0.01 USDT
+            entry_quality: None,
+            exit_quality: None,
             ..make_closed(10, 0)
         };
         assert_eq!(pos.actual_upbit_fee.unwrap(), Decimal::new(5, 1));
@@ -307,6 +322,8 @@ mod tests {
             actual_bybit_fee: None,
             funding_fee: Some(Decimal::ZERO),
             adjustment_cost: None,
+            entry_quality: None,
+            exit_quality: None,
             ..make_closed(10, 0)
         };
         let json = serde_json::to_string(&pos).unwrap();
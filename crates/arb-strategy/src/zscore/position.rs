@@ -13,6 +13,7 @@ use tracing::{info, warn};
 
 use crate::error::PositionError;
 use crate::zscore::pnl::ClosedPosition;
+use crate::zscore::tca::ExecutionQuality;
 
 /// 포지션 상태 머신.
 ///
@@ -75,8 +76,11 @@ pub struct VirtualPosition {
     pub entry_spread_pct: f64,
     /// 진입 시 Z-Score.
     pub entry_z_score: f64,
-    /// 포지션 수량 (코인 단위, 양 leg 동일).
+    /// 포지션 수량 (코인 단위, Upbit 레그 기준).
     pub qty: Decimal,
+    /// Bybit short 수량 (헤지 비율 적용 시). `None`이면 `qty`와 동일합니다.
+    #[serde(default)]
+    pub bybit_qty: Option<Decimal>,
     /// DB primary key 참조 (라이브 전용, 시뮬에서는 None).
     #[serde(default)]
     pub db_id: Option<i64>,
@@ -107,6 +111,15 @@ pub struct VirtualPosition {
     /// 비상 청산 시도 횟수.
     #[serde(default)]
     pub emergency_attempts: u32,
+    /// 분할 진입 단계 (0부터, 분할 진입 비활성화 시 항상 0).
+    #[serde(default)]
+    pub tier: u8,
+    /// 보유 중 최고 미실현 수익률 (%, 트레일링 스탑용).
+    #[serde(default)]
+    pub peak_pnl_pct: Option<f64>,
+    /// 진입 체결 품질 (라이브 전용, 청산 시 `ClosedPosition`으로 이관).
+    #[serde(default)]
+    pub entry_quality: Option<ExecutionQuality>,
 }
 
 impl Default for VirtualPosition {
@@ -122,6 +135,7 @@ impl Default for VirtualPosition {
             entry_spread_pct: 0.0,
             entry_z_score: 0.0,
             qty: Decimal::ZERO,
+            bybit_qty: None,
             db_id: None,
             upbit_order_id: None,
             bybit_order_id: None,
@@ -132,6 +146,9 @@ impl Default for VirtualPosition {
             exit_client_order_id: None,
             succeeded_leg: None,
             emergency_attempts: 0,
+            tier: 0,
+            peak_pnl_pct: None,
+            entry_quality: None,
         }
     }
 }
@@ -141,6 +158,20 @@ impl VirtualPosition {
     pub fn size_usdt(&self) -> Decimal {
         self.qty * self.bybit_entry_price
     }
+
+    /// Bybit 레그 수량.
+    pub fn bybit_leg_qty(&self) -> Decimal {
+        self.bybit_qty.unwrap_or(self.qty)
+    }
+
+    /// Upbit 수량 `upbit_qty`에 대응하는 Bybit 수량 (진입 시 헤지 비율 유지).
+    pub fn bybit_qty_for(&self, upbit_qty: Decimal) -> Decimal {
+        match self.bybit_qty {
+            Some(b) if upbit_qty != self.qty && !self.qty.is_zero() => upbit_qty * b / self.qty,
+            Some(b) => b,
+            None => upbit_qty,
+        }
+    }
 }
 
 /// 포지션 매니저.
@@ -383,6 +414,15 @@ impl PositionManager {
         };
 
         let remaining_qty = pos.qty.saturating_sub(close_qty);
+        // 헤지 비율로 비례 계산한 Bybit 잔량은 qty_step 단위가 아닐 수 있으므로 내림
+        // (다음 청산 주문이 거래소 수량 규격을 벗어나지 않도록)
+        let remaining_bybit_qty = pos.bybit_qty.map(|b| {
+            let remaining = b.saturating_sub(pos.bybit_qty_for(close_qty));
+            match instrument_info {
+                Some(info) => crate::zscore::instrument::round_qty_floor(remaining, info.qty_step),
+                None => remaining,
+            }
+        });
 
         let closed = Self::build_closed_position(
             pos,
@@ -412,6 +452,7 @@ impl PositionManager {
         let remaining_pos = if remaining_qty > Decimal::ZERO {
             // 잔여 포지션 축소 (ID 동일 유지)
             positions[idx].qty = remaining_qty;
+            positions[idx].bybit_qty = remaining_bybit_qty;
             Some(positions[idx].clone())
         } else {
             // 전량 소진 → 포지션 제거
@@ -430,6 +471,18 @@ impl PositionManager {
         self.last_entry_time.get(coin).copied()
     }
 
+    /// 코인이 보유 중인 분할 진입 단계 목록 (Opening/Closing 포함, 오름차순).
+    pub fn held_tiers(&self, coin: &str) -> Vec<u8> {
+        let mut tiers: Vec<u8> = self
+            .open_positions
+            .get(coin)
+            .map(|ps| ps.iter().map(|p| p.tier).collect())
+            .unwrap_or_default();
+        tiers.sort_unstable();
+        tiers.dedup();
+        tiers
+    }
+
     /// Bybit liquidation 체크: liquidation 조건에 해당하는 포지션 ID들을 반환합니다.
     pub fn check_liquidation(&self, coin: &str, current_bybit_price: Decimal) -> Vec<u64> {
         let Some(positions) = self.open_positions.get(coin) else {
@@ -454,7 +507,7 @@ impl PositionManager {
 
     /// ClosedPosition을 생성하는 내부 헬퍼.
     ///
-    /// `close_qty`는 코인 수량 기반이며, 양 leg 동일 수량으로 계산합니다.
+    /// `close_qty`는 Upbit 레그 코인 수량이며, Bybit 레그는 진입 시 헤지 비율로 환산합니다.
     #[allow(clippy::too_many_arguments)]
     fn build_closed_position(
         pos: &VirtualPosition,
@@ -471,18 +524,21 @@ impl PositionManager {
     ) -> ClosedPosition {
         let holding_minutes = (exit_time - pos.entry_time).num_minutes().unsigned_abs();
 
-        let qty = close_qty; // 양 leg 동일 수량
+        let qty = close_qty;
+        // 헤지 비율이 적용된 포지션은 Bybit 레그 수량이 다름
+        let bybit_qty = pos.bybit_qty_for(close_qty);
 
         // Upbit 현물 PnL
         let upbit_pnl = (exit_upbit_usdt_price - pos.upbit_entry_price) * qty;
 
         // Bybit short PnL
-        let bybit_pnl = (pos.bybit_entry_price - exit_bybit_price) * qty;
+        let bybit_pnl = (pos.bybit_entry_price - exit_bybit_price) * bybit_qty;
 
         // 수수료: 진입/청산 각각의 가격에 수수료를 개별 적용
         let upbit_fees =
             (pos.upbit_entry_price * qty + exit_upbit_usdt_price * qty) * upbit_taker_fee;
-        let bybit_fees = (pos.bybit_entry_price * qty + exit_bybit_price * qty) * bybit_taker_fee;
+        let bybit_fees =
+            (pos.bybit_entry_price * bybit_qty + exit_bybit_price * bybit_qty) * bybit_taker_fee;
         let total_fees = upbit_fees + bybit_fees;
 
         // 순 PnL
@@ -513,10 +569,18 @@ impl PositionManager {
             entry_usd_krw: pos.entry_usd_krw,
             exit_usd_krw,
             is_liquidated,
+            tier: pos.tier,
             actual_upbit_fee: None,
             actual_bybit_fee: None,
             funding_fee: None,
             adjustment_cost: None,
+            // 부분 청산이 반복돼도 진입 품질은 포지션을 소진하는 기록에 한 번만 집계
+            entry_quality: if close_qty >= pos.qty {
+                pos.entry_quality.clone()
+            } else {
+                None
+            },
+            exit_quality: None,
         }
     }
 
@@ -765,6 +829,63 @@ mod tests {
         assert_eq!(remaining[0].qty, Decimal::new(2, 0));
     }
 
+    #[test]
+    fn test_entry_quality_attached_only_to_exhausting_close() {
+        use crate::zscore::live_executor::ExecutedExit;
+        use crate::zscore::tca::ExecutionQuality;
+
+        let quality = ExecutionQuality::from_exit(
+            Decimal::new(100_000, 0),
+            Decimal::new(100_050, 0),
+            1380.0,
+            &ExecutedExit {
+                upbit_order_id: "u".to_string(),
+                bybit_order_id: "b".to_string(),
+                upbit_filled_qty: Decimal::new(5, 0),
+                bybit_filled_qty: Decimal::new(5, 0),
+                upbit_avg_price_krw: Decimal::new(138_000_000, 0),
+                bybit_avg_price: Decimal::new(100_050, 0),
+                upbit_fee: Decimal::ZERO,
+                bybit_fee: Decimal::ZERO,
+                upbit_latency: std::time::Duration::from_millis(50),
+                bybit_latency: std::time::Duration::from_millis(70),
+            },
+        );
+        let mut pm = PositionManager::new();
+        pm.open_position(VirtualPosition {
+            coin: "BTC".to_string(),
+            upbit_entry_price: Decimal::new(100_000, 0),
+            bybit_entry_price: Decimal::new(100_050, 0),
+            qty: Decimal::new(5, 0),
+            entry_quality: Some(quality),
+            ..Default::default()
+        })
+        .unwrap();
+
+        let close = |pm: &mut PositionManager, qty: i64| {
+            pm.close_partial(
+                "BTC",
+                0,
+                Decimal::new(qty, 0),
+                None,
+                Decimal::new(100_020, 0),
+                Decimal::new(100_020, 0),
+                1381.0,
+                0.0,
+                0.3,
+                Decimal::new(5, 4),
+                Decimal::new(55, 5),
+                false,
+            )
+            .unwrap()
+            .0
+        };
+
+        // 부분 청산에는 진입 품질을 붙이지 않고, 잔량을 소진하는 청산에만 한 번 부착
+        assert!(close(&mut pm, 2).entry_quality.is_none());
+        assert!(close(&mut pm, 3).entry_quality.is_some());
+    }
+
     #[test]
     fn test_close_partial() {
         let mut pm = PositionManager::new();
@@ -1030,6 +1151,7 @@ mod tests {
             min_order_qty: Decimal::new(1, 1), // 0.1
             min_notional: Decimal::new(5, 0),
             max_order_qty: Decimal::new(1000, 0),
+            contract_multiplier: Decimal::ONE,
         };
 
         // partial_qty = 3.15 → floor(3.15, 0.1) = 3.1
@@ -1057,6 +1179,60 @@ mod tests {
         assert_eq!(rem.qty, Decimal::new(69, 1));
     }
 
+    #[test]
+    fn test_close_partial_remaining_bybit_qty_rounded_to_step() {
+        // 헤지 비율 비례로 계산한 Bybit 잔량도 qty_step으로 내림
+        use crate::zscore::instrument::InstrumentInfo;
+
+        let mut pm = PositionManager::new();
+        pm.open_position(VirtualPosition {
+            id: 0,
+            coin: "ETH".to_string(),
+            entry_time: Utc::now(),
+            upbit_entry_price: Decimal::new(3000, 0),
+            bybit_entry_price: Decimal::new(3010, 0),
+            bybit_liquidation_price: Decimal::new(6000, 0),
+            entry_usd_krw: 1380.0,
+            entry_spread_pct: 0.05,
+            entry_z_score: 2.5,
+            qty: Decimal::new(3, 0),
+            bybit_qty: Some(Decimal::new(29, 1)), // 2.9 (헤지 비율 < 1)
+            ..Default::default()
+        })
+        .unwrap();
+
+        let info = InstrumentInfo {
+            tick_size: Decimal::new(1, 2),
+            qty_step: Decimal::new(1, 1),
+            min_order_qty: Decimal::new(1, 1),
+            min_notional: Decimal::new(5, 0),
+            max_order_qty: Decimal::new(1000, 0),
+            contract_multiplier: Decimal::ONE,
+        };
+
+        // 1 ETH 청산 → Bybit 비례 청산 0.9666.. → 잔량 1.9333.. → 1.9
+        let (_, remaining) = pm
+            .close_partial(
+                "ETH",
+                0,
+                Decimal::ONE,
+                Some(&info),
+                Decimal::new(3020, 0),
+                Decimal::new(3020, 0),
+                1381.0,
+                0.0,
+                0.3,
+                Decimal::new(5, 4),
+                Decimal::new(55, 5),
+                false,
+            )
+            .unwrap();
+
+        let rem = remaining.unwrap();
+        assert_eq!(rem.qty, Decimal::new(2, 0));
+        assert_eq!(rem.bybit_qty, Some(Decimal::new(19, 1)));
+    }
+
     #[test]
     fn test_close_partial_instrument_info_zero_rounding_becomes_full() {
         // partial_qty가 qty_step보다 작으면 라운딩 후 0 → 전량 청산 전환
@@ -1084,6 +1260,7 @@ mod tests {
             min_order_qty: Decimal::new(1, 3), // 0.001
             min_notional: Decimal::new(5, 0),
             max_order_qty: Decimal::new(100, 0),
+            contract_multiplier: Decimal::ONE,
         };
 
         // partial_qty = 0.0005 → floor(0.0005, 0.001) = 0 → 전량 청산 전환
@@ -1137,6 +1314,7 @@ mod tests {
             min_order_qty: Decimal::new(2, 1), // 0.2
             min_notional: Decimal::new(5, 0),
             max_order_qty: Decimal::new(1000, 0),
+            contract_multiplier: Decimal::ONE,
         };
 
         // partial_qty = 0.9 → floor(0.9, 0.1) = 0.9
@@ -1303,6 +1481,7 @@ mod tests {
             entry_spread_pct: 0.05,
             entry_z_score: 2.5,
             qty: Decimal::ONE,
+            bybit_qty: Some(Decimal::new(9, 1)),
             db_id: Some(123),
             upbit_order_id: Some("upbit-uuid-1".to_string()),
             bybit_order_id: Some("bybit-uuid-1".to_string()),
@@ -1313,6 +1492,9 @@ mod tests {
             exit_client_order_id: None,
             succeeded_leg: None,
             emergency_attempts: 0,
+            tier: 2,
+            peak_pnl_pct: Some(0.4),
+            entry_quality: None,
         };
 
         let json = serde_json::to_string(&pos).unwrap();
@@ -1328,6 +1510,8 @@ mod tests {
             deserialized.client_order_id.as_deref(),
             Some("client-uuid-1")
         );
+        assert_eq!(deserialized.tier, 2);
+        assert_eq!(deserialized.peak_pnl_pct, Some(0.4));
     }
 
     #[test]
@@ -1352,6 +1536,8 @@ mod tests {
         assert!(!pos.in_flight);
         assert_eq!(pos.state, PositionState::Open);
         assert_eq!(pos.emergency_attempts, 0);
+        assert_eq!(pos.tier, 0);
+        assert!(pos.peak_pnl_pct.is_none());
     }
 
     // --- register_opening / transition_state 테스트 ---
@@ -1584,4 +1770,83 @@ mod tests {
         assert_eq!(snapshot[0].0, "BTC");
         assert_eq!(snapshot[0].1, Decimal::new(1, 0));
     }
+
+    #[test]
+    fn test_close_position_with_hedge_qty() {
+        let mut pm = PositionManager::new();
+        pm.open_position(VirtualPosition {
+            coin: "BTC".to_string(),
+            upbit_entry_price: Decimal::new(100, 0),
+            bybit_entry_price: Decimal::new(101, 0),
+            qty: Decimal::new(10, 0),
+            bybit_qty: Some(Decimal::new(9, 0)),
+            ..Default::default()
+        })
+        .unwrap();
+
+        let closed = pm
+            .close_position(
+                "BTC",
+                0,
+                Utc::now(),
+                Decimal::new(110, 0),
+                Decimal::new(111, 0),
+                1380.0,
+                0.0,
+                0.0,
+                Decimal::ZERO,
+                Decimal::ZERO,
+                false,
+            )
+            .unwrap();
+        // Upbit: (110 - 100) × 10 = 100, Bybit: (101 - 111) × 9 = -90
+        assert_eq!(closed.upbit_pnl, Decimal::new(100, 0));
+        assert_eq!(closed.bybit_pnl, Decimal::new(-90, 0));
+        assert_eq!(closed.net_pnl, Decimal::new(10, 0));
+    }
+
+    #[test]
+    fn test_close_partial_keeps_hedge_ratio() {
+        let mut pm = PositionManager::new();
+        pm.open_position(VirtualPosition {
+            coin: "BTC".to_string(),
+            upbit_entry_price: Decimal::new(100, 0),
+            bybit_entry_price: Decimal::new(101, 0),
+            qty: Decimal::new(10, 0),
+            bybit_qty: Some(Decimal::new(9, 0)),
+            ..Default::default()
+        })
+        .unwrap();
+
+        let (_, remaining) = pm
+            .close_partial(
+                "BTC",
+                0,
+                Decimal::new(4, 0),
+                None,
+                Decimal::new(100, 0),
+                Decimal::new(101, 0),
+                1380.0,
+                0.0,
+                0.0,
+                Decimal::ZERO,
+                Decimal::ZERO,
+                false,
+            )
+            .unwrap();
+        let remaining = remaining.unwrap();
+        assert_eq!(remaining.qty, Decimal::new(6, 0));
+        // 9 - 4 × 0.9 = 5.4
+        assert_eq!(remaining.bybit_leg_qty(), Decimal::new(54, 1));
+    }
+
+    #[test]
+    fn test_bybit_leg_qty_defaults_to_qty() {
+        let pos = VirtualPosition {
+            qty: Decimal::new(3, 0),
+            ..Default::default()
+        };
+        assert_eq!(pos.bybit_leg_qty(), Decimal::new(3, 0));
+        assert_eq!(pos.bybit_qty_for(Decimal::ONE), Decimal::ONE);
+    }
 }
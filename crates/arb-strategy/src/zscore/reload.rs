@@ -0,0 +1,431 @@
+//! 전략 설정 핫 리로드.
+//!
+//! `strategy.toml` 변경(파일 mtime 폴링 또는 SIGHUP)을 감지하면
+//! `ZScoreConfig::from_file` + `validate`로 다시 읽고, 현재 설정과 비교합니다.
+//!
+//! - 라이브 적용 가능한 필드([`LIVE_RELOADABLE_FIELDS`])만 바뀌었으면
+//!   `watch` 채널로 `ZScoreMonitor`에, `update_config`로 `RiskManager`에 반영하고
+//!   변경 내역을 sessions 테이블에 기록합니다.
+//! - 재시작이 필요한 필드(캔들 간격, 윈도우, 주문/레버리지 설정 등)가 하나라도 바뀌었으면
+//!   전체 변경을 거부하고 현재 설정을 유지합니다.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use chrono::Utc;
+use serde::Serialize;
+use tokio::sync::watch;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+
+use arb_db::writer::{DbWriteRequest, DbWriter};
+
+use crate::error::StrategyError;
+use crate::zscore::config::ZScoreConfig;
+use crate::zscore::config_diff::diff_configs;
+use crate::zscore::risk::{RiskConfig, RiskManager};
+
+/// 설정 파일 mtime 폴링 주기.
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// 라이브 적용 가능한 필드 목록과 비교/복사 헬퍼를 함께 정의합니다.
+macro_rules! live_reloadable_fields {
+    ($($field:ident),* $(,)?) => {
+        /// 재시작 없이 적용 가능한 `ZScoreConfig` 필드.
+        ///
+        /// 틱/분 루프가 매번 설정에서 다시 읽는 시그널 임계값, 코인 선택 필터,
+        /// 리스크 한도만 포함합니다.
+        pub const LIVE_RELOADABLE_FIELDS: &[&str] = &[$(stringify!($field)),*];
+
+        fn live_field_changes(old: &ZScoreConfig, new: &ZScoreConfig) -> Vec<ConfigChange> {
+            let mut changes = Vec::new();
+            $(
+                if old.$field != new.$field {
+                    changes.push(ConfigChange {
+                        field: stringify!($field),
+                        old: format!("{:?}", old.$field),
+                        new: format!("{:?}", new.$field),
+                    });
+                }
+            )*
+            changes
+        }
+
+        fn copy_live_fields(from: &ZScoreConfig, to: &mut ZScoreConfig) {
+            $( to.$field = from.$field.clone(); )*
+        }
+    };
+}
+
+live_reloadable_fields!(
+    // 시그널
+    entry_z_threshold,
+    exit_z_threshold,
+    min_stddev_threshold,
+    min_expected_roi,
+    min_position_usdt,
+    entry_cooldown_sec,
+    max_cache_age_sec,
+    max_position_ratio,
+    position_ttl_hours,
+    grace_period_hours,
+    // 자본 배분 (다음 배치부터 적용)
+    allocator,
+    // 손절 규칙 (다음 틱부터 적용)
+    stop_loss,
+    // 코인 선택 (다음 재선택부터 적용)
+    blacklist,
+    max_coins,
+    min_volume_1h_usdt,
+    max_spread_stddev,
+    max_half_life_candles,
+    require_stationary_spread,
+    // 리스크 한도
+    kill_switch_enabled,
+    max_daily_loss_pct,
+    max_drawdown_pct,
+    max_single_loss_pct,
+    max_daily_loss_usdt,
+    max_drawdown_usdt,
+    max_single_loss_usdt,
+    max_order_size_usdt,
+    max_rolling_24h_loss_usdt,
+);
+
+/// 필드 하나의 변경 내역.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConfigChange {
+    /// `ZScoreConfig` 필드명.
+    pub field: &'static str,
+    /// 이전 값 (Debug 표기).
+    pub old: String,
+    /// 새 값 (Debug 표기).
+    pub new: String,
+}
+
+/// sessions 테이블에 기록하는 리로드 한 건.
+#[derive(Debug, Serialize)]
+struct AppliedReload<'a> {
+    at: chrono::DateTime<Utc>,
+    trigger: &'a str,
+    changes: &'a [ConfigChange],
+}
+
+/// 현재 설정과 새 설정을 비교해 라이브 적용할 변경 목록을 반환합니다.
+///
+/// 재시작이 필요한 필드가 바뀌었으면 해당 필드명을 담아 `StrategyError::Config`를 반환합니다.
+pub fn diff_live_config(
+    old: &ZScoreConfig,
+    new: &ZScoreConfig,
+) -> Result<Vec<ConfigChange>, StrategyError> {
+    let mut normalized = new.clone();
+    copy_live_fields(old, &mut normalized);
+    if normalized != *old {
+        let fields = restart_only_changes(old, &normalized);
+        return Err(StrategyError::Config(format!(
+            "fields require restart: {}",
+            fields.join(", ")
+        )));
+    }
+    Ok(live_field_changes(old, new))
+}
+
+/// 라이브 필드를 맞춘 두 설정에서 달라진 필드 경로를 찾습니다 (에러 메시지용).
+fn restart_only_changes(old: &ZScoreConfig, normalized: &ZScoreConfig) -> Vec<String> {
+    let mut changed: Vec<String> = diff_configs(old, normalized)
+        .map(|diffs| diffs.into_iter().map(|d| d.path).collect())
+        .unwrap_or_default();
+    if changed.is_empty() {
+        changed.push("<unknown>".to_string());
+    }
+    changed
+}
+
+/// 전략 설정 파일 감시 및 라이브 적용.
+///
+/// 재시작 필요 필드는 마지막으로 읽은 파일 내용(`baseline`)과 비교하고,
+/// 라이브 필드만 실행 중인 설정(`current`)에 덮어씁니다. 따라서 기동 시 코드에서
+/// 덮어쓴 값(예: 라이브 모드의 파일 출력 비활성화)은 리로드 후에도 유지됩니다.
+pub struct ConfigReloader {
+    path: PathBuf,
+    baseline: ZScoreConfig,
+    current: Arc<ZScoreConfig>,
+    config_tx: watch::Sender<Arc<ZScoreConfig>>,
+    risk: Option<Arc<RiskManager>>,
+    db: Option<(DbWriter, i64)>,
+    last_modified: Option<SystemTime>,
+}
+
+impl ConfigReloader {
+    /// 새 리로더와 모니터용 설정 구독 채널을 생성합니다.
+    ///
+    /// 비교 기준으로 쓰기 위해 설정 파일을 한 번 읽습니다.
+    ///
+    /// # 인자
+    ///
+    /// * `path` - 감시할 strategy.toml 경로
+    /// * `initial` - 현재 실행 중인 설정
+    pub fn new(
+        path: impl Into<PathBuf>,
+        initial: Arc<ZScoreConfig>,
+    ) -> Result<(Self, watch::Receiver<Arc<ZScoreConfig>>), StrategyError> {
+        let path = path.into();
+        let baseline = ZScoreConfig::from_file(&path)?;
+        let (config_tx, config_rx) = watch::channel(Arc::clone(&initial));
+        let last_modified = modified_at(&path);
+        let reloader = Self {
+            path,
+            baseline,
+            current: initial,
+            config_tx,
+            risk: None,
+            db: None,
+            last_modified,
+        };
+        Ok((reloader, config_rx))
+    }
+
+    /// 리스크 한도 갱신 대상을 연결합니다.
+    pub fn with_risk_manager(mut self, risk: Arc<RiskManager>) -> Self {
+        self.risk = Some(risk);
+        self
+    }
+
+    /// 적용된 변경을 sessions 테이블에 기록합니다.
+    pub fn with_db_writer(mut self, writer: DbWriter, session_id: i64) -> Self {
+        self.db = Some((writer, session_id));
+        self
+    }
+
+    /// 설정 파일을 다시 읽어 라이브 적용합니다.
+    ///
+    /// 변경이 없으면 빈 목록을 반환합니다. 파싱/검증 실패 또는 재시작 필요 필드 변경 시
+    /// 현재 설정을 유지하고 에러를 반환합니다.
+    pub fn reload(&mut self, trigger: &str) -> Result<Vec<ConfigChange>, StrategyError> {
+        let loaded = ZScoreConfig::from_file(&self.path)?;
+        loaded.validate()?;
+        diff_live_config(&self.baseline, &loaded)?;
+        let changes = live_field_changes(&self.current, &loaded);
+        if changes.is_empty() {
+            debug!(trigger, "전략 설정 변경 없음");
+            self.baseline = loaded;
+            return Ok(changes);
+        }
+
+        let mut applied = (*self.current).clone();
+        copy_live_fields(&loaded, &mut applied);
+        let applied = Arc::new(applied);
+        if let Some(risk) = &self.risk {
+            risk.update_config(RiskConfig::from_strategy_config(&applied));
+        }
+        self.config_tx.send_replace(Arc::clone(&applied));
+        self.current = applied;
+        self.baseline = loaded;
+
+        for change in &changes {
+            info!(
+                trigger,
+                field = change.field,
+                old = %change.old,
+                new = %change.new,
+                "전략 설정 라이브 적용"
+            );
+        }
+        if let Some((writer, session_id)) = &self.db {
+            let record = AppliedReload {
+                at: Utc::now(),
+                trigger,
+                changes: &changes,
+            };
+            match serde_json::to_string(&record) {
+                Ok(change_json) => writer.send(DbWriteRequest::AppendSessionConfigChange {
+                    session_id: *session_id,
+                    change_json,
+                }),
+                Err(e) => warn!(error = %e, "설정 변경 이력 직렬화 실패"),
+            }
+        }
+        Ok(changes)
+    }
+
+    /// 파일 변경/SIGHUP을 감시하며 리로드합니다. `cancel`이 취소되면 종료합니다.
+    pub async fn run(mut self, cancel: CancellationToken) {
+        info!(
+            path = %self.path.display(),
+            poll_sec = POLL_INTERVAL.as_secs(),
+            "전략 설정 핫 리로드 감시 시작"
+        );
+        let mut poll = tokio::time::interval(POLL_INTERVAL);
+        poll.tick().await;
+        let mut hangup = HangupSignal::new();
+
+        loop {
+            tokio::select! {
+                _ = cancel.cancelled() => {
+                    debug!("전략 설정 감시 task 종료");
+                    break;
+                }
+                _ = poll.tick() => {
+                    let modified = modified_at(&self.path);
+                    if modified.is_some() && modified != self.last_modified {
+                        self.last_modified = modified;
+                        self.reload_logged("file");
+                    }
+                }
+                _ = hangup.recv() => {
+                    self.reload_logged("sighup");
+                }
+            }
+        }
+    }
+
+    fn reload_logged(&mut self, trigger: &str) {
+        match self.reload(trigger) {
+            Ok(changes) if !changes.is_empty() => {
+                info!(trigger, count = changes.len(), "전략 설정 리로드 완료");
+            }
+            Ok(_) => {}
+            Err(e) => {
+                warn!(
+                    trigger,
+                    path = %self.path.display(),
+                    error = %e,
+                    "전략 설정 리로드 거부, 기존 설정 유지"
+                );
+            }
+        }
+    }
+}
+
+fn modified_at(path: &std::path::Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// SIGHUP 수신기 (Unix 외 플랫폼이나 등록 실패 시 영원히 대기).
+struct HangupSignal {
+    #[cfg(unix)]
+    inner: Option<tokio::signal::unix::Signal>,
+}
+
+impl HangupSignal {
+    fn new() -> Self {
+        #[cfg(unix)]
+        {
+            use tokio::signal::unix::{SignalKind, signal};
+            let inner = signal(SignalKind::hangup())
+                .map_err(|e| warn!(error = %e, "SIGHUP 핸들러 등록 실패, 파일 감시만 사용"))
+                .ok();
+            Self { inner }
+        }
+        #[cfg(not(unix))]
+        {
+            Self {}
+        }
+    }
+
+    async fn recv(&mut self) {
+        #[cfg(unix)]
+        if let Some(signal) = self.inner.as_mut() {
+            signal.recv().await;
+            return;
+        }
+        std::future::pending::<()>().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rust_decimal::Decimal;
+
+    fn write_config(dir: &tempfile::TempDir, body: &str) -> PathBuf {
+        let path = dir.path().join("strategy.toml");
+        std::fs::write(
+            &path,
+            format!("[zscore]\ncoins = [\"BTC\", \"ETH\"]\n{body}"),
+        )
+        .unwrap();
+        path
+    }
+
+    #[test]
+    fn test_diff_live_config_allowed_fields() {
+        let old = ZScoreConfig::default();
+        let new = ZScoreConfig {
+            entry_z_threshold: 2.5,
+            blacklist: vec!["DOGE".to_string()],
+            max_order_size_usdt: 1000.0,
+            ..old.clone()
+        };
+        let changes = diff_live_config(&old, &new).unwrap();
+        let fields: Vec<&str> = changes.iter().map(|c| c.field).collect();
+        assert_eq!(
+            fields,
+            vec!["entry_z_threshold", "blacklist", "max_order_size_usdt"]
+        );
+        assert_eq!(changes[0].old, "2.0");
+        assert_eq!(changes[0].new, "2.5");
+    }
+
+    #[test]
+    fn test_diff_live_config_rejects_restart_only_fields() {
+        let old = ZScoreConfig::default();
+        let new = ZScoreConfig {
+            entry_z_threshold: 2.5,
+            window_size: 720,
+            leverage: 2,
+            ..old.clone()
+        };
+        let err = diff_live_config(&old, &new).unwrap_err().to_string();
+        assert!(err.contains("window_size"));
+        assert!(err.contains("leverage"));
+        assert!(!err.contains("entry_z_threshold"));
+    }
+
+    #[test]
+    fn test_diff_live_config_no_change() {
+        let config = ZScoreConfig::default();
+        assert!(
+            diff_live_config(&config, &config.clone())
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_reloader_applies_to_monitor_and_risk() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_config(&dir, "entry_z_threshold = 2.0\n");
+        // 기동 시 코드에서 덮어쓴 값은 리로드 후에도 유지되어야 함
+        let mut initial = ZScoreConfig::from_file(&path).unwrap();
+        initial.output.enabled = false;
+        let initial = Arc::new(initial);
+        let risk = Arc::new(RiskManager::new(RiskConfig::from_strategy_config(&initial)));
+        let (reloader, mut rx) = ConfigReloader::new(&path, initial).unwrap();
+        let mut reloader = reloader.with_risk_manager(Arc::clone(&risk));
+
+        write_config(
+            &dir,
+            "entry_z_threshold = 2.4\nmax_order_size_usdt = 100000.0\n",
+        );
+        let changes = reloader.reload("test").unwrap();
+        assert_eq!(changes.len(), 2);
+        assert!(rx.has_changed().unwrap());
+        assert_eq!(rx.borrow_and_update().entry_z_threshold, 2.4);
+        assert!(!rx.borrow().output.enabled);
+        assert!(risk.validate_order_size(Decimal::from(50_000)));
+
+        // 재시작 필요 필드 변경은 거부되고 기존 설정 유지
+        write_config(&dir, "entry_z_threshold = 3.0\nwindow_size = 60\n");
+        assert!(reloader.reload("test").is_err());
+        assert!(!rx.has_changed().unwrap());
+        assert_eq!(reloader.current.entry_z_threshold, 2.4);
+
+        // 검증 실패도 거부
+        write_config(&dir, "entry_z_threshold = 0.1\nexit_z_threshold = 0.5\n");
+        assert!(reloader.reload("test").is_err());
+        assert_eq!(rx.borrow().entry_z_threshold, 2.4);
+    }
+}
@@ -9,11 +9,13 @@ use std::collections::VecDeque;
 use std::sync::atomic::{AtomicBool, Ordering};
 
 use chrono::{DateTime, Utc};
-use parking_lot::Mutex;
+use parking_lot::{Mutex, RwLock};
 use rust_decimal::Decimal;
 use rust_decimal::prelude::ToPrimitive;
 use tracing::{debug, error, info, warn};
 
+use crate::zscore::config::ZScoreConfig;
+
 /// 리스크 관리 설정.
 #[derive(Debug, Clone)]
 pub struct RiskConfig {
@@ -65,6 +67,37 @@ impl Default for RiskConfig {
     }
 }
 
+impl RiskConfig {
+    /// 전략 설정에서 리스크 한도를 구성합니다.
+    ///
+    /// USDT 한도가 Decimal로 변환되지 않으면 기본값을 사용합니다.
+    /// HWM 윈도우와 미실현 손실 한도는 기본값을 따릅니다.
+    pub fn from_strategy_config(config: &ZScoreConfig) -> Self {
+        let defaults = Self::default();
+        Self {
+            max_daily_loss_pct: config.max_daily_loss_pct,
+            max_daily_loss_usdt: Decimal::try_from(config.max_daily_loss_usdt)
+                .unwrap_or(defaults.max_daily_loss_usdt),
+            max_drawdown_pct: config.max_drawdown_pct,
+            max_drawdown_usdt: Decimal::try_from(config.max_drawdown_usdt)
+                .unwrap_or(defaults.max_drawdown_usdt),
+            max_single_loss_pct: config.max_single_loss_pct,
+            max_single_loss_usdt: Decimal::try_from(config.max_single_loss_usdt)
+                .unwrap_or(defaults.max_single_loss_usdt),
+            max_order_size_usdt: Decimal::try_from(config.max_order_size_usdt)
+                .unwrap_or(defaults.max_order_size_usdt),
+            max_concurrent_positions: config
+                .max_concurrent_positions
+                .unwrap_or(config.coins.len()),
+            max_rolling_24h_loss_usdt: Decimal::try_from(config.max_rolling_24h_loss_usdt)
+                .unwrap_or(defaults.max_rolling_24h_loss_usdt),
+            total_capital_usdt: config.total_capital_usdt,
+            kill_switch_enabled: config.kill_switch_enabled,
+            ..defaults
+        }
+    }
+}
+
 /// 미실현 PnL 스냅샷 (check_unrealized_exposure용).
 #[derive(Debug, Clone)]
 pub struct UnrealizedPnlSnapshot {
@@ -190,7 +223,8 @@ struct RiskState {
 /// `is_killed`는 AtomicBool로 lock 없이 확인 가능합니다.
 /// `inner`는 parking_lot::Mutex로 poisoning 없이 안전합니다.
 pub struct RiskManager {
-    config: RiskConfig,
+    /// 리스크 한도. 설정 핫 리로드 시 `update_config`로 교체됩니다.
+    config: RwLock<RiskConfig>,
     inner: Mutex<RiskState>,
     is_killed: AtomicBool,
 }
@@ -214,7 +248,7 @@ impl RiskManager {
         );
 
         Self {
-            config,
+            config: RwLock::new(config),
             inner: Mutex::new(RiskState {
                 daily_realized_pnl: Decimal::ZERO,
                 current_equity: equity,
@@ -231,6 +265,25 @@ impl RiskManager {
         }
     }
 
+    /// 리스크 한도를 교체합니다 (설정 핫 리로드).
+    ///
+    /// 누적 PnL, HWM, kill switch 상태는 그대로 두고 이후 체크부터 새 한도를 적용합니다.
+    /// 이미 발동된 kill switch는 한도를 완화해도 해제되지 않습니다.
+    pub fn update_config(&self, config: RiskConfig) {
+        info!(
+            max_daily_loss_pct = config.max_daily_loss_pct,
+            max_daily_loss_usdt = %config.max_daily_loss_usdt,
+            max_drawdown_pct = config.max_drawdown_pct,
+            max_drawdown_usdt = %config.max_drawdown_usdt,
+            max_single_loss_usdt = %config.max_single_loss_usdt,
+            max_order_size_usdt = %config.max_order_size_usdt,
+            max_rolling_24h_loss_usdt = %config.max_rolling_24h_loss_usdt,
+            kill_switch_enabled = config.kill_switch_enabled,
+            "리스크 한도 갱신"
+        );
+        *self.config.write() = config;
+    }
+
     /// Kill switch가 발동되었는지 확인합니다 (lock 불필요).
     pub fn is_killed(&self) -> bool {
         self.is_killed.load(Ordering::Acquire)
@@ -263,7 +316,8 @@ impl RiskManager {
     ///
     /// PnL이 음수이면 손실 한도를 확인하여 kill switch를 발동할 수 있습니다.
     pub fn record_trade(&self, pnl: Decimal) -> Option<KillSwitchReason> {
-        if !self.config.kill_switch_enabled {
+        let config = self.config.read().clone();
+        if !config.kill_switch_enabled {
             debug!(pnl = %pnl, "Kill switch 비활성화, 리스크 체크 스킵");
             return None;
         }
@@ -301,8 +355,8 @@ impl RiskManager {
         // ① 단건 손실 한도
         if pnl < Decimal::ZERO {
             let loss = pnl.abs();
-            let pct_limit = self.pct_to_usdt(self.config.max_single_loss_pct);
-            let effective_limit = pct_limit.min(self.config.max_single_loss_usdt);
+            let pct_limit = self.pct_to_usdt(config.max_single_loss_pct);
+            let effective_limit = pct_limit.min(config.max_single_loss_usdt);
 
             if loss > effective_limit {
                 let reason = KillSwitchReason::SingleLossExceeded {
@@ -319,8 +373,8 @@ impl RiskManager {
         // ② 일일 손실 한도
         if state.daily_realized_pnl < Decimal::ZERO {
             let daily_loss = state.daily_realized_pnl.abs();
-            let pct_limit = self.pct_to_usdt(self.config.max_daily_loss_pct);
-            let effective_limit = pct_limit.min(self.config.max_daily_loss_usdt);
+            let pct_limit = self.pct_to_usdt(config.max_daily_loss_pct);
+            let effective_limit = pct_limit.min(config.max_daily_loss_usdt);
 
             if daily_loss > effective_limit {
                 let reason = KillSwitchReason::DailyLossExceeded {
@@ -336,12 +390,12 @@ impl RiskManager {
 
         // ③ Rolling 24h 누적 손실 한도
         let rolling_loss = self.calc_rolling_24h_loss(&state, now);
-        if rolling_loss > self.config.max_rolling_24h_loss_usdt {
+        if rolling_loss > config.max_rolling_24h_loss_usdt {
             let reason = KillSwitchReason::Rolling24hLossExceeded {
                 rolling_loss,
-                limit: self.config.max_rolling_24h_loss_usdt,
+                limit: config.max_rolling_24h_loss_usdt,
             };
-            warn!(%rolling_loss, limit = %self.config.max_rolling_24h_loss_usdt, "Rolling 24h 손실 한도 초과 → kill switch");
+            warn!(%rolling_loss, limit = %config.max_rolling_24h_loss_usdt, "Rolling 24h 손실 한도 초과 → kill switch");
             drop(state);
             self.trigger_kill_switch_internal(&reason);
             return Some(reason);
@@ -354,8 +408,8 @@ impl RiskManager {
             let drawdown = hwm - state.current_equity;
 
             if drawdown > Decimal::ZERO {
-                let pct_limit = self.pct_to_usdt(self.config.max_drawdown_pct);
-                let effective_limit = pct_limit.min(self.config.max_drawdown_usdt);
+                let pct_limit = self.pct_to_usdt(config.max_drawdown_pct);
+                let effective_limit = pct_limit.min(config.max_drawdown_usdt);
 
                 debug!(
                     hwm = %hwm,
@@ -380,12 +434,12 @@ impl RiskManager {
             // cold start 중에는 절대값 한도만 적용
             let hwm = self.calc_hwm(&state, now);
             let drawdown = hwm - state.current_equity;
-            if drawdown > Decimal::ZERO && drawdown > self.config.max_drawdown_usdt {
+            if drawdown > Decimal::ZERO && drawdown > config.max_drawdown_usdt {
                 let reason = KillSwitchReason::DrawdownExceeded {
                     drawdown,
-                    limit: self.config.max_drawdown_usdt,
+                    limit: config.max_drawdown_usdt,
                 };
-                warn!(%drawdown, limit = %self.config.max_drawdown_usdt, "Cold start 절대값 드로다운 한도 초과 → kill switch");
+                warn!(%drawdown, limit = %config.max_drawdown_usdt, "Cold start 절대값 드로다운 한도 초과 → kill switch");
                 drop(state);
                 self.trigger_kill_switch_internal(&reason);
                 return Some(reason);
@@ -424,15 +478,16 @@ impl RiskManager {
 
     /// 단일 주문 크기가 상한 이내인지 확인합니다.
     pub fn validate_order_size(&self, size_usdt: Decimal) -> bool {
-        let valid = size_usdt <= self.config.max_order_size_usdt;
+        let max = self.config.read().max_order_size_usdt;
+        let valid = size_usdt <= max;
         if !valid {
             warn!(
                 size_usdt = %size_usdt,
-                max = %self.config.max_order_size_usdt,
+                max = %max,
                 "주문 크기 상한 초과"
             );
         } else {
-            debug!(size_usdt = %size_usdt, max = %self.config.max_order_size_usdt, "주문 크기 확인 통과");
+            debug!(size_usdt = %size_usdt, max = %max, "주문 크기 확인 통과");
         }
         valid
     }
@@ -445,7 +500,8 @@ impl RiskManager {
         &self,
         positions: &[UnrealizedPnlSnapshot],
     ) -> Option<KillSwitchReason> {
-        if !self.config.kill_switch_enabled || positions.is_empty() {
+        let config = self.config.read().clone();
+        if !config.kill_switch_enabled || positions.is_empty() {
             return None;
         }
 
@@ -468,7 +524,7 @@ impl RiskManager {
             })
             .sum();
 
-        let capital = self.config.total_capital_usdt;
+        let capital = config.total_capital_usdt;
         if capital == Decimal::ZERO {
             return None;
         }
@@ -484,19 +540,19 @@ impl RiskManager {
         debug!(
             total_unrealized = %total_unrealized,
             unrealized_loss_pct = unrealized_loss_pct,
-            limit_pct = self.config.max_unrealized_loss_pct,
+            limit_pct = config.max_unrealized_loss_pct,
             position_count = positions.len(),
             "미실현 손실 체크"
         );
 
-        if unrealized_loss_pct > self.config.max_unrealized_loss_pct {
+        if unrealized_loss_pct > config.max_unrealized_loss_pct {
             let reason = KillSwitchReason::UnrealizedLossExceeded {
                 unrealized_loss_pct,
-                limit_pct: self.config.max_unrealized_loss_pct,
+                limit_pct: config.max_unrealized_loss_pct,
             };
             warn!(
                 unrealized_loss_pct = unrealized_loss_pct,
-                limit = self.config.max_unrealized_loss_pct,
+                limit = config.max_unrealized_loss_pct,
                 "미실현 손실 한도 초과 → kill switch"
             );
             self.trigger_kill_switch_internal(&reason);
@@ -562,7 +618,7 @@ impl RiskManager {
         state.hwm_daily_peaks.push_back((now, peak_eq));
 
         // hwm_window_days 초과 엔트리 제거
-        let window_days = self.config.hwm_window_days as i64;
+        let window_days = self.config.read().hwm_window_days as i64;
         let cutoff = now - chrono::Duration::days(window_days);
         while let Some(front) = state.hwm_daily_peaks.front() {
             if front.0 < cutoff {
@@ -620,7 +676,8 @@ impl RiskManager {
 
     /// 비율을 USDT 금액으로 변환합니다.
     fn pct_to_usdt(&self, pct: f64) -> Decimal {
-        Decimal::try_from(pct / 100.0).unwrap_or(Decimal::ZERO) * self.config.total_capital_usdt
+        Decimal::try_from(pct / 100.0).unwrap_or(Decimal::ZERO)
+            * self.config.read().total_capital_usdt
     }
 
     /// Rolling 24h 손실 합계를 계산합니다.
@@ -638,7 +695,10 @@ impl RiskManager {
     ///
     /// Rolling 7d 내 최고 equity를 반환합니다.
     fn calc_hwm(&self, state: &RiskState, now: DateTime<Utc>) -> Decimal {
-        let window_days = self.config.hwm_window_days as i64;
+        let (window_days, capital) = {
+            let config = self.config.read();
+            (config.hwm_window_days as i64, config.total_capital_usdt)
+        };
         let cutoff = now - chrono::Duration::days(window_days);
 
         let historical_max = state
@@ -647,7 +707,7 @@ impl RiskManager {
             .filter(|(ts, _)| *ts >= cutoff)
             .map(|(_, peak)| *peak)
             .max()
-            .unwrap_or(self.config.total_capital_usdt);
+            .unwrap_or(capital);
 
         // 당일 peak_equity도 포함
         historical_max.max(state.peak_equity)
@@ -722,6 +782,45 @@ mod tests {
         assert!(!rm.is_entry_allowed());
     }
 
+    #[test]
+    fn test_update_config_applies_new_limits() {
+        let rm = RiskManager::new(test_config());
+        assert!(!rm.validate_order_size(Decimal::from(2500)));
+        rm.record_trade(Decimal::from(-5));
+
+        rm.update_config(RiskConfig {
+            max_order_size_usdt: Decimal::from(3000),
+            max_single_loss_pct: 1.0,
+            ..test_config()
+        });
+        assert!(rm.validate_order_size(Decimal::from(2500)));
+        // 누적 PnL은 유지
+        assert_eq!(rm.daily_pnl(), Decimal::from(-5));
+        // 단건 한도: min(1%*300=3, 15) = 3
+        assert!(rm.record_trade(Decimal::from(-4)).is_some());
+        assert!(rm.is_killed());
+    }
+
+    #[test]
+    fn test_risk_config_from_strategy_config() {
+        let strategy = ZScoreConfig {
+            max_daily_loss_usdt: 70.0,
+            max_order_size_usdt: 1500.0,
+            max_concurrent_positions: None,
+            coins: vec!["BTC".to_string(), "ETH".to_string()],
+            ..ZScoreConfig::default()
+        };
+        let config = RiskConfig::from_strategy_config(&strategy);
+        assert_eq!(config.max_daily_loss_usdt, Decimal::from(70));
+        assert_eq!(config.max_order_size_usdt, Decimal::from(1500));
+        assert_eq!(config.max_concurrent_positions, 2);
+        assert_eq!(config.total_capital_usdt, strategy.total_capital_usdt);
+        assert_eq!(
+            config.hwm_window_days,
+            RiskConfig::default().hwm_window_days
+        );
+    }
+
     #[test]
     fn test_daily_loss_kill_switch() {
         let config = RiskConfig {
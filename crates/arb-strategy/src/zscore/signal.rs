@@ -12,6 +12,7 @@ use tracing::{debug, info, trace};
 use crate::common::fee::roundtrip_fee_pct;
 use crate::common::statistics;
 use crate::error::{StatisticsError, StrategyError};
+use crate::zscore::coin_params::CoinParams;
 use crate::zscore::config::ZScoreConfig;
 
 /// 트레이딩 시그널.
@@ -41,7 +42,7 @@ pub enum Signal {
 
 /// 청산 시그널을 평가합니다.
 ///
-/// 포지션이 있고 Z-Score가 exit_z_threshold 이하면 청산 시그널을 생성합니다.
+/// 포지션이 있고 Z-Score가 코인별 exit_z_threshold 이하면 청산 시그널을 생성합니다.
 ///
 /// # 인자
 /// - `coin`: 코인 심볼
@@ -49,6 +50,7 @@ pub enum Signal {
 /// - `mean`: 분봉 기반 rolling mean (%)
 /// - `stddev`: 분봉 기반 rolling stddev
 /// - `has_positions`: 해당 코인에 포지션이 존재하는지 여부
+/// - `params`: 코인별 파라미터 (청산 임계값)
 /// - `config`: 전략 설정
 ///
 /// # 반환값
@@ -61,6 +63,7 @@ pub fn evaluate_exit_signal(
     mean: f64,
     stddev: f64,
     has_positions: bool,
+    params: &CoinParams,
     config: &ZScoreConfig,
 ) -> Result<Option<Signal>, StrategyError> {
     // 포지션이 없으면 청산 불가
@@ -94,11 +97,11 @@ pub fn evaluate_exit_signal(
     };
 
     // 청산 조건: z_score <= exit_z_threshold
-    if z <= config.exit_z_threshold {
+    if z <= params.exit_z_threshold {
         debug!(
             coin,
             z_score = z,
-            exit_z_threshold = config.exit_z_threshold,
+            exit_z_threshold = params.exit_z_threshold,
             spread_pct = current_spread,
             "청산 시그널 생성"
         );
@@ -126,6 +129,7 @@ pub fn evaluate_exit_signal(
 /// - `max_coin_capital`: 코인당 최대 허용 자본 (total_capital × max_position_ratio)
 /// - `current_open_count`: 현재 열린 포지션 총 수
 /// - `last_entry_at`: 해당 코인의 마지막 진입 시각 (cooldown 확인용)
+/// - `params`: 코인별 파라미터 (진입 임계값)
 /// - `config`: 전략 설정
 ///
 /// # 반환값
@@ -142,6 +146,7 @@ pub fn evaluate_entry_signal(
     max_coin_capital: Decimal,
     current_open_count: usize,
     last_entry_at: Option<DateTime<Utc>>,
+    params: &CoinParams,
     config: &ZScoreConfig,
 ) -> Result<Option<Signal>, StrategyError> {
     // Z-Score 계산 (min_stddev guard)
@@ -170,7 +175,7 @@ pub fn evaluate_entry_signal(
     };
 
     // 1. Z-Score 임계값 확인
-    if z < config.entry_z_threshold {
+    if z < params.entry_z_threshold {
         return Ok(None);
     }
 
@@ -287,7 +292,16 @@ mod tests {
 
         // mean=0.1, stddev=0.2, spread=0.15
         // z = (0.15 - 0.1) / 0.2 = 0.25 <= 0.5
-        let sig = evaluate_exit_signal("BTC", 0.15, 0.1, 0.2, true, &config).unwrap();
+        let sig = evaluate_exit_signal(
+            "BTC",
+            0.15,
+            0.1,
+            0.2,
+            true,
+            &CoinParams::from_config(&config),
+            &config,
+        )
+        .unwrap();
         match sig {
             Some(Signal::Exit { coin, z_score, .. }) => {
                 assert_eq!(coin, "BTC");
@@ -307,7 +321,16 @@ mod tests {
         };
 
         // z <= exit_z이지만 포지션 없음
-        let sig = evaluate_exit_signal("BTC", 0.15, 0.1, 0.2, false, &config).unwrap();
+        let sig = evaluate_exit_signal(
+            "BTC",
+            0.15,
+            0.1,
+            0.2,
+            false,
+            &CoinParams::from_config(&config),
+            &config,
+        )
+        .unwrap();
         assert!(sig.is_none());
     }
 
@@ -338,6 +361,7 @@ mod tests {
             max_coin_capital, // 최대 코인 자본
             0,                // 현재 포지션 수
             None,             // 마지막 진입 없음
+            &CoinParams::from_config(&config),
             &config,
         )
         .unwrap();
@@ -375,6 +399,7 @@ mod tests {
             max_coin_capital,
             0,
             None,
+            &CoinParams::from_config(&config),
             &config,
         )
         .unwrap();
@@ -405,6 +430,7 @@ mod tests {
             max_coin_capital,      // 2000
             0,
             None,
+            &CoinParams::from_config(&config),
             &config,
         )
         .unwrap();
@@ -435,6 +461,7 @@ mod tests {
             max_coin_capital,
             0,
             last_entry,
+            &CoinParams::from_config(&config),
             &config,
         )
         .unwrap();
@@ -462,6 +489,7 @@ mod tests {
             max_coin_capital,
             0,
             None,
+            &CoinParams::from_config(&config),
             &config,
         )
         .unwrap();
@@ -477,7 +505,16 @@ mod tests {
         };
 
         // stddev = 0.005 < 0.01 -> 청산도 None
-        let sig = evaluate_exit_signal("BTC", 0.15, 0.1, 0.005, true, &config).unwrap();
+        let sig = evaluate_exit_signal(
+            "BTC",
+            0.15,
+            0.1,
+            0.005,
+            true,
+            &CoinParams::from_config(&config),
+            &config,
+        )
+        .unwrap();
         assert!(sig.is_none());
     }
 
@@ -491,7 +528,16 @@ mod tests {
         };
 
         // z = (0.5 - 0.1) / 0.2 = 2.0 > 0.5
-        let sig = evaluate_exit_signal("BTC", 0.5, 0.1, 0.2, true, &config).unwrap();
+        let sig = evaluate_exit_signal(
+            "BTC",
+            0.5,
+            0.1,
+            0.2,
+            true,
+            &CoinParams::from_config(&config),
+            &config,
+        )
+        .unwrap();
         assert!(sig.is_none());
     }
 
@@ -518,6 +564,7 @@ mod tests {
             max_coin_capital,
             2, // 현재 2개 -> max 도달
             None,
+            &CoinParams::from_config(&config),
             &config,
         )
         .unwrap();
@@ -548,9 +595,60 @@ mod tests {
             max_coin_capital,
             0,
             last_entry,
+            &CoinParams::from_config(&config),
             &config,
         )
         .unwrap();
         assert!(sig.is_some());
     }
+
+    /// 코인별 진입 임계값이 전역 설정보다 우선
+    #[test]
+    fn test_entry_signal_uses_coin_params() {
+        let config = ZScoreConfig {
+            entry_z_threshold: 2.0,
+            min_stddev_threshold: 0.001,
+            entry_cooldown_sec: 0,
+            ..ZScoreConfig::default()
+        };
+        let max_coin_capital = config.total_capital_usdt * config.max_position_ratio;
+        let params = CoinParams {
+            entry_z_threshold: 3.0,
+            ..CoinParams::from_config(&config)
+        };
+
+        // z = 2.5: 전역 임계값(2.0)은 통과하지만 코인 임계값(3.0) 미달
+        let sig = evaluate_entry_signal(
+            "BTC",
+            0.6,
+            0.1,
+            0.2,
+            Decimal::ZERO,
+            max_coin_capital,
+            0,
+            None,
+            &params,
+            &config,
+        )
+        .unwrap();
+        assert!(sig.is_none());
+    }
+
+    /// 코인별 청산 임계값이 전역 설정보다 우선
+    #[test]
+    fn test_exit_signal_uses_coin_params() {
+        let config = ZScoreConfig {
+            exit_z_threshold: 0.5,
+            min_stddev_threshold: 0.001,
+            ..ZScoreConfig::default()
+        };
+        let params = CoinParams {
+            exit_z_threshold: 0.1,
+            ..CoinParams::from_config(&config)
+        };
+
+        // z = 0.25: 전역 임계값(0.5) 이하지만 코인 임계값(0.1) 초과
+        let sig = evaluate_exit_signal("BTC", 0.15, 0.1, 0.2, true, &params, &config).unwrap();
+        assert!(sig.is_none());
+    }
 }
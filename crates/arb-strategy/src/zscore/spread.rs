@@ -12,6 +12,8 @@ use tracing::{debug, trace, warn};
 use crate::common::candle_window::CandleWindow;
 use crate::common::convert::decimal_to_f64;
 use crate::error::StrategyError;
+use crate::zscore::kalman::{KalmanHedge, KalmanParams};
+use crate::zscore::trend::{TrendFilter, TrendParams};
 
 /// 연속 누락 경고 임계값 (분).
 ///
@@ -125,6 +127,15 @@ impl IncrementalStats {
         self.count += 1;
     }
 
+    /// 윈도우 데이터 전체로 통계를 새로 생성합니다 (윈도우 크기 변경 시 사용).
+    fn from_data<'a>(data: impl IntoIterator<Item = &'a f64>) -> Self {
+        let mut stats = Self::new();
+        for &v in data {
+            stats.push(v, None);
+        }
+        stats
+    }
+
     /// 현재 mean.
     fn mean(&self) -> f64 {
         if self.count == 0 {
@@ -177,8 +188,16 @@ impl StatsAccumulator for IncrementalStats {
     // rebuild는 기본 no-op 사용
 }
 
-/// 단기 regime change 감지용 윈도우 크기 (60분).
-const SHORT_WINDOW_SIZE: usize = 60;
+/// 단기 regime change 감지용 윈도우 길이 (분).
+const SHORT_WINDOW_MINUTES: u32 = 60;
+
+/// 단기 윈도우 최소 캔들 수 (긴 캔들 간격에서도 stddev 추정이 가능하도록 보장).
+const MIN_SHORT_WINDOW_SIZE: usize = 12;
+
+/// 캔들 간격(분)에 맞는 단기 윈도우 크기를 계산합니다.
+fn short_window_size_for(interval_minutes: u32) -> usize {
+    ((SHORT_WINDOW_MINUTES / interval_minutes.max(1)) as usize).max(MIN_SHORT_WINDOW_SIZE)
+}
 
 /// Welford's online algorithm 기반 rolling statistics.
 ///
@@ -240,6 +259,83 @@ impl StatsAccumulator for WelfordStats {
     }
 }
 
+/// 스프레드 통계(mean, stddev) 추정 방식.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StatsMode {
+    /// 윈도우 전체에 동일 가중치를 주는 롤링 통계 (기본값).
+    Rolling,
+    /// 지수가중이동평균(EWMA) 통계.
+    ///
+    /// 최근 캔들에 더 큰 가중치를 주어 regime 변화에 빠르게 반응하며,
+    /// 윈도우가 가득 차기 전에도 `min_samples` 이후 통계를 제공합니다.
+    Ewma {
+        /// 가중치가 절반이 되는 캔들 수.
+        half_life: f64,
+        /// 통계를 유효로 간주하기 위한 최소 캔들 수.
+        min_samples: usize,
+    },
+}
+
+/// 지수가중이동(EWMA) mean/variance 추정기.
+///
+/// 윈도우 데이터를 보관하지 않고 O(1)로 갱신합니다.
+/// `alpha = 1 - 0.5^(1/half_life)`이며, 첫 값으로 mean을 초기화합니다.
+#[derive(Debug, Clone)]
+struct EwmaStats {
+    alpha: f64,
+    mean_val: f64,
+    variance: f64,
+    count: usize,
+}
+
+impl EwmaStats {
+    fn new(half_life: f64) -> Self {
+        Self {
+            alpha: 1.0 - 0.5_f64.powf(1.0 / half_life),
+            mean_val: 0.0,
+            variance: 0.0,
+            count: 0,
+        }
+    }
+
+    /// 데이터 전체를 순서대로 반영한 추정기를 생성합니다.
+    fn from_data<'a>(half_life: f64, data: impl IntoIterator<Item = &'a f64>) -> Self {
+        let mut stats = Self::new(half_life);
+        for &v in data {
+            stats.push(v, None);
+        }
+        stats
+    }
+}
+
+impl StatsAccumulator for EwmaStats {
+    /// EWMA는 윈도우를 사용하지 않으므로 `popped`는 무시합니다.
+    fn push(&mut self, value: f64, _popped: Option<f64>) {
+        self.count += 1;
+        if self.count == 1 {
+            self.mean_val = value;
+            self.variance = 0.0;
+            return;
+        }
+        let diff = value - self.mean_val;
+        let incr = self.alpha * diff;
+        self.mean_val += incr;
+        self.variance = (1.0 - self.alpha) * (self.variance + diff * incr);
+    }
+
+    fn mean(&self) -> f64 {
+        self.mean_val
+    }
+
+    fn stddev(&self) -> f64 {
+        if self.variance < 0.0 {
+            0.0
+        } else {
+            self.variance.sqrt()
+        }
+    }
+}
+
 /// 윈도우와 통계에 값을 동시에 push합니다.
 ///
 /// IncrementalStats: push로 O(1) 갱신.
@@ -277,20 +373,34 @@ pub struct SpreadCalculator {
     spread_pct_windows: HashMap<String, CandleWindow>,
     /// 코인별 증분 통계 캐시 (스프레드 윈도우용).
     spread_stats: HashMap<String, IncrementalStats>,
-    /// 코인별 단기 스프레드 윈도우 (regime change 감지용, 60분).
+    /// 코인별 단기 스프레드 윈도우 (regime change 감지용, 약 60분).
     short_spread_windows: HashMap<String, CandleWindow>,
     /// 코인별 단기 통계 (Welford's online algorithm).
     short_spread_stats: HashMap<String, WelfordStats>,
+    /// 코인별 EWMA 통계 (`StatsMode::Ewma`일 때만 유지).
+    ewma_stats: HashMap<String, EwmaStats>,
+    /// 통계 추정 방식.
+    stats_mode: StatsMode,
+    /// 코인별 Kalman 헤지 추정기 (`kalman_params`가 `Some`일 때만 유지).
+    kalman: HashMap<String, KalmanHedge>,
+    /// Kalman 헤지 모델 파라미터 (`None`이면 고정 1:1 스프레드).
+    kalman_params: Option<KalmanParams>,
+    /// 코인별 상위 타임프레임 추세 필터 (`trend_params`가 `Some`일 때만 유지).
+    trend: HashMap<String, TrendFilter>,
+    /// 추세 필터 파라미터 (`None`이면 비활성화).
+    trend_params: Option<TrendParams>,
     /// 코인별 Upbit forward-fill 상태.
     upbit_ff: HashMap<String, ForwardFillState>,
     /// 코인별 Bybit forward-fill 상태.
     bybit_ff: HashMap<String, ForwardFillState>,
-    /// 윈도우 크기.
+    /// 기본 윈도우 크기 (코인별 크기는 `resize_coin_window`로 변경 가능).
     window_size: usize,
+    /// 단기 윈도우 크기 (캔들 수, 캔들 간격에 따라 결정).
+    short_window_size: usize,
 }
 
 impl SpreadCalculator {
-    /// 새 SpreadCalculator를 생성합니다.
+    /// 새 SpreadCalculator를 생성합니다 (1분봉 기준).
     pub fn new(coins: &[String], window_size: usize) -> Self {
         let mut upbit_coin_windows = HashMap::new();
         let mut bybit_windows = HashMap::new();
@@ -306,7 +416,7 @@ impl SpreadCalculator {
             bybit_windows.insert(coin.clone(), CandleWindow::new(window_size));
             spread_pct_windows.insert(coin.clone(), CandleWindow::new(window_size));
             spread_stats.insert(coin.clone(), IncrementalStats::new());
-            short_spread_windows.insert(coin.clone(), CandleWindow::new(SHORT_WINDOW_SIZE));
+            short_spread_windows.insert(coin.clone(), CandleWindow::new(short_window_size_for(1)));
             short_spread_stats.insert(coin.clone(), WelfordStats::new());
             upbit_ff.insert(coin.clone(), ForwardFillState::new());
             bybit_ff.insert(coin.clone(), ForwardFillState::new());
@@ -319,31 +429,149 @@ impl SpreadCalculator {
             spread_stats,
             short_spread_windows,
             short_spread_stats,
+            ewma_stats: HashMap::new(),
+            stats_mode: StatsMode::Rolling,
+            kalman: HashMap::new(),
+            kalman_params: None,
+            trend: HashMap::new(),
+            trend_params: None,
             upbit_ff,
             bybit_ff,
             window_size,
+            short_window_size: short_window_size_for(1),
+        }
+    }
+
+    /// 캔들 간격(분)을 지정합니다.
+    ///
+    /// 단기 윈도우가 약 60분을 덮도록 캔들 수를 조정하고,
+    /// 이미 등록된 코인은 스프레드 윈도우의 최근 데이터로 단기 윈도우를 다시 채웁니다.
+    pub fn with_candle_interval(mut self, interval_minutes: u32) -> Self {
+        self.short_window_size = short_window_size_for(interval_minutes);
+        for (coin, spread_window) in &self.spread_pct_windows {
+            let data = spread_window.data();
+            let mut short_window = CandleWindow::new(self.short_window_size);
+            for &v in data
+                .iter()
+                .skip(data.len().saturating_sub(self.short_window_size))
+            {
+                short_window.push(v);
+            }
+            let mut short_stats = WelfordStats::new();
+            short_stats.rebuild(&short_window.data().iter().copied().collect::<Vec<_>>());
+            self.short_spread_windows.insert(coin.clone(), short_window);
+            self.short_spread_stats.insert(coin.clone(), short_stats);
+        }
+        self
+    }
+
+    /// 상위 타임프레임 추세 필터를 지정합니다 (`None`이면 비활성화).
+    ///
+    /// 윈도우에는 timestamp가 없으므로 기존 코인도 빈 필터로 시작하며,
+    /// `seed_trend`로 상위 타임프레임 캔들을 채웁니다.
+    pub fn with_trend(mut self, params: Option<TrendParams>) -> Self {
+        self.trend_params = params;
+        self.trend.clear();
+        if let Some(params) = params {
+            for coin in self.spread_pct_windows.keys() {
+                self.trend.insert(coin.clone(), TrendFilter::new(params));
+            }
+        }
+        self
+    }
+
+    /// 추세 필터 파라미터를 반환합니다 (비활성 시 `None`).
+    pub fn trend_params(&self) -> Option<TrendParams> {
+        self.trend_params
+    }
+
+    /// 상위 타임프레임 스프레드 close로 코인의 추세 필터를 채웁니다.
+    ///
+    /// 추세 필터가 비활성이거나 등록되지 않은 코인이면 무시합니다.
+    pub fn seed_trend(&mut self, coin: &str, closes: &[f64]) {
+        if let Some(tf) = self.trend.get_mut(coin) {
+            tf.seed(closes);
+            debug!(coin = coin, closes = tf.len(), "추세 필터 워밍업 완료");
+        }
+    }
+
+    /// 통계 추정 방식을 지정합니다.
+    ///
+    /// 이미 등록된 코인은 현재 윈도우 데이터로 EWMA 통계를 다시 계산합니다.
+    pub fn with_stats_mode(mut self, mode: StatsMode) -> Self {
+        self.stats_mode = mode;
+        self.ewma_stats.clear();
+        if let StatsMode::Ewma { half_life, .. } = mode {
+            for (coin, window) in &self.spread_pct_windows {
+                self.ewma_stats
+                    .insert(coin.clone(), EwmaStats::from_data(half_life, window.data()));
+            }
         }
+        self
+    }
+
+    /// Kalman 헤지 모델을 지정합니다 (`None`이면 비활성화).
+    ///
+    /// 이미 등록된 코인은 현재 Upbit/Bybit 가격 윈도우로 추정기를 다시 계산합니다.
+    pub fn with_kalman(mut self, params: Option<KalmanParams>) -> Self {
+        self.kalman_params = params;
+        self.kalman.clear();
+        if let Some(params) = params {
+            for (coin, upbit_window) in &self.upbit_coin_windows {
+                if let Some(bybit_window) = self.bybit_windows.get(coin) {
+                    self.kalman.insert(
+                        coin.clone(),
+                        KalmanHedge::from_windows(params, upbit_window, bybit_window),
+                    );
+                }
+            }
+        }
+        self
+    }
+
+    /// 현재 통계 추정 방식을 반환합니다.
+    pub fn stats_mode(&self) -> StatsMode {
+        self.stats_mode
     }
 
     /// 새 코인을 추가하고 빈 윈도우를 초기화합니다.
     ///
     /// idempotent: 이미 존재하는 코인은 데이터를 유지하고 무시합니다.
     pub fn add_coin(&mut self, coin: &str) {
+        self.add_coin_with_window(coin, self.window_size);
+    }
+
+    /// 지정한 윈도우 크기로 새 코인을 추가합니다.
+    ///
+    /// idempotent: 이미 존재하는 코인은 데이터와 윈도우 크기를 유지하고 무시합니다.
+    pub fn add_coin_with_window(&mut self, coin: &str, window_size: usize) {
         if self.upbit_coin_windows.contains_key(coin) {
             return;
         }
         self.upbit_coin_windows
-            .insert(coin.to_string(), CandleWindow::new(self.window_size));
+            .insert(coin.to_string(), CandleWindow::new(window_size));
         self.bybit_windows
-            .insert(coin.to_string(), CandleWindow::new(self.window_size));
+            .insert(coin.to_string(), CandleWindow::new(window_size));
         self.spread_pct_windows
-            .insert(coin.to_string(), CandleWindow::new(self.window_size));
+            .insert(coin.to_string(), CandleWindow::new(window_size));
         self.spread_stats
             .insert(coin.to_string(), IncrementalStats::new());
         self.short_spread_windows
-            .insert(coin.to_string(), CandleWindow::new(SHORT_WINDOW_SIZE));
+            .insert(coin.to_string(), CandleWindow::new(self.short_window_size));
         self.short_spread_stats
             .insert(coin.to_string(), WelfordStats::new());
+        if let StatsMode::Ewma { half_life, .. } = self.stats_mode {
+            self.ewma_stats
+                .insert(coin.to_string(), EwmaStats::new(half_life));
+        }
+        if let Some(params) = self.kalman_params {
+            self.kalman
+                .insert(coin.to_string(), KalmanHedge::new(params));
+        }
+        if let Some(params) = self.trend_params {
+            self.trend
+                .insert(coin.to_string(), TrendFilter::new(params));
+        }
         self.upbit_ff
             .insert(coin.to_string(), ForwardFillState::new());
         self.bybit_ff
@@ -360,10 +588,112 @@ impl SpreadCalculator {
         self.spread_stats.remove(coin);
         self.short_spread_windows.remove(coin);
         self.short_spread_stats.remove(coin);
+        self.ewma_stats.remove(coin);
+        self.kalman.remove(coin);
+        self.trend.remove(coin);
         self.upbit_ff.remove(coin);
         self.bybit_ff.remove(coin);
     }
 
+    /// 코인의 윈도우 크기를 변경합니다.
+    ///
+    /// 축소 시 최근 데이터만 남기고 통계를 재계산합니다.
+    /// 확대 시 기존 데이터는 유지되지만 새 크기만큼 찰 때까지 `cached_stats`는 `None`입니다.
+    /// 단기 윈도우, EWMA 통계, Kalman 추정기, 추세 필터는 윈도우 크기와 무관하므로 변경하지 않습니다.
+    ///
+    /// # 에러
+    ///
+    /// 등록되지 않은 코인이면 `StrategyError::Config`.
+    pub fn resize_coin_window(&mut self, coin: &str, new_size: usize) -> Result<(), StrategyError> {
+        let spread_window = self
+            .spread_pct_windows
+            .get_mut(coin)
+            .ok_or_else(|| StrategyError::Config(format!("unknown coin: {coin}")))?;
+        spread_window.resize(new_size);
+        self.spread_stats.insert(
+            coin.to_string(),
+            IncrementalStats::from_data(spread_window.data()),
+        );
+        if let Some(w) = self.upbit_coin_windows.get_mut(coin) {
+            w.resize(new_size);
+        }
+        if let Some(w) = self.bybit_windows.get_mut(coin) {
+            w.resize(new_size);
+        }
+        debug!(coin = coin, new_size = new_size, "코인 윈도우 크기 변경");
+        Ok(())
+    }
+
+    /// 다른 SpreadCalculator에서 코인 상태 전체를 가져와 교체합니다.
+    ///
+    /// 별도 인스턴스에서 워밍업을 마친 뒤 한 번에 교체하여,
+    /// 워밍업 도중 기존 윈도우가 비는 구간을 없앱니다.
+    /// EWMA 통계와 Kalman 추정기는 `other`에 있으면 함께 가져오고, 없으면 교체된 윈도우로 재계산합니다.
+    /// 추세 필터는 윈도우 크기와 무관하므로 기존 상태를 유지합니다.
+    ///
+    /// # 에러
+    ///
+    /// `other`에 해당 코인이 없으면 `StrategyError::Config`.
+    pub fn replace_coin_from(
+        &mut self,
+        coin: &str,
+        other: &mut SpreadCalculator,
+    ) -> Result<(), StrategyError> {
+        let missing = || StrategyError::Config(format!("unknown coin: {coin}"));
+        let upbit_window = other.upbit_coin_windows.remove(coin).ok_or_else(missing)?;
+        let bybit_window = other.bybit_windows.remove(coin).ok_or_else(missing)?;
+        let spread_window = other.spread_pct_windows.remove(coin).ok_or_else(missing)?;
+        let spread_stats = other.spread_stats.remove(coin).ok_or_else(missing)?;
+        let short_window = other
+            .short_spread_windows
+            .remove(coin)
+            .ok_or_else(missing)?;
+        let short_stats = other.short_spread_stats.remove(coin).ok_or_else(missing)?;
+        let upbit_ff = other.upbit_ff.remove(coin).ok_or_else(missing)?;
+        let bybit_ff = other.bybit_ff.remove(coin).ok_or_else(missing)?;
+        let kalman = self.kalman_params.map(|params| {
+            other
+                .kalman
+                .remove(coin)
+                .unwrap_or_else(|| KalmanHedge::from_windows(params, &upbit_window, &bybit_window))
+        });
+
+        self.upbit_coin_windows
+            .insert(coin.to_string(), upbit_window);
+        self.bybit_windows.insert(coin.to_string(), bybit_window);
+        self.spread_stats.insert(coin.to_string(), spread_stats);
+        self.short_spread_windows
+            .insert(coin.to_string(), short_window);
+        self.short_spread_stats
+            .insert(coin.to_string(), short_stats);
+        if let StatsMode::Ewma { half_life, .. } = self.stats_mode {
+            let ewma = other
+                .ewma_stats
+                .remove(coin)
+                .unwrap_or_else(|| EwmaStats::from_data(half_life, spread_window.data()));
+            self.ewma_stats.insert(coin.to_string(), ewma);
+        }
+        if let Some(kf) = kalman {
+            self.kalman.insert(coin.to_string(), kf);
+        }
+        if let Some(params) = self.trend_params {
+            let staged = other.trend.remove(coin);
+            self.trend
+                .entry(coin.to_string())
+                .or_insert_with(|| staged.unwrap_or_else(|| TrendFilter::new(params)));
+        }
+        self.spread_pct_windows
+            .insert(coin.to_string(), spread_window);
+        self.upbit_ff.insert(coin.to_string(), upbit_ff);
+        self.bybit_ff.insert(coin.to_string(), bybit_ff);
+        Ok(())
+    }
+
+    /// 코인의 현재 윈도우 크기를 반환합니다.
+    pub fn coin_window_size(&self, coin: &str) -> Option<usize> {
+        self.spread_pct_windows.get(coin).map(|w| w.window_size())
+    }
+
     /// 현재 감시 중인 코인 목록을 반환합니다.
     pub fn active_coins(&self) -> Vec<&str> {
         self.spread_pct_windows.keys().map(|k| k.as_str()).collect()
@@ -453,6 +783,21 @@ impl SpreadCalculator {
                 push_to_window_and_stats(spread_window, stats, spread_pct);
             }
 
+            // EWMA stats push (O(1), 윈도우 없음)
+            if let Some(ewma) = self.ewma_stats.get_mut(coin) {
+                ewma.push(spread_pct, None);
+            }
+
+            // Kalman 헤지 추정 (O(1))
+            if let Some(kf) = self.kalman.get_mut(coin) {
+                kf.update(upbit_usd_f64, bybit_f64);
+            }
+
+            // 상위 타임프레임 추세 필터 (버킷 경계에서 close 확정)
+            if let Some(tf) = self.trend.get_mut(coin) {
+                tf.push(timestamp, spread_pct);
+            }
+
             // 단기 윈도우 + stats push (방어적: 없으면 skip)
             if let (Some(short_window), Some(short_stats)) = (
                 self.short_spread_windows.get_mut(coin),
@@ -490,8 +835,16 @@ impl SpreadCalculator {
 
     /// 특정 코인의 캐시된 통계(mean, stddev)를 O(1)로 반환합니다.
     ///
-    /// 윈도우가 준비되지 않았으면 `None` 반환.
+    /// `StatsMode::Rolling`: 윈도우가 준비되지 않았으면 `None` 반환.
+    /// `StatsMode::Ewma`: EWMA 샘플이 `min_samples` 미만이면 `None` 반환.
     pub fn cached_stats(&self, coin: &str) -> Option<(f64, f64)> {
+        if let StatsMode::Ewma { min_samples, .. } = self.stats_mode {
+            let ewma = self.ewma_stats.get(coin)?;
+            if ewma.count < min_samples {
+                return None;
+            }
+            return Some((ewma.mean(), ewma.stddev()));
+        }
         // 윈도우가 준비(가득 참)되어 있는지 확인
         let window = self.spread_pct_windows.get(coin)?;
         if !window.is_ready() {
@@ -501,9 +854,9 @@ impl SpreadCalculator {
         Some((stats.mean(), stats.stddev()))
     }
 
-    /// 단기 윈도우(60분)의 (mean, stddev)를 반환합니다.
+    /// 단기 윈도우(약 60분)의 (mean, stddev)를 반환합니다.
     ///
-    /// 윈도우 데이터가 `SHORT_WINDOW_SIZE`(60)개 미만이면 `None` 반환.
+    /// 윈도우가 가득 차지 않았으면 `None` 반환 (1분봉 60개, 5분봉 12개 등).
     /// 세션 시작 후 최초 60분간은 항상 `None`을 반환하므로,
     /// regime change 감지는 장기 stddev로 fallback됩니다.
     pub fn cached_short_stats(&self, coin: &str) -> Option<(f64, f64)> {
@@ -515,20 +868,38 @@ impl SpreadCalculator {
         Some((stats.mean(), stats.stddev()))
     }
 
+    /// 특정 코인의 Kalman 헤지 추정기를 반환합니다 (헤지 모델 비활성 시 `None`).
+    pub fn kalman(&self, coin: &str) -> Option<&KalmanHedge> {
+        self.kalman.get(coin)
+    }
+
+    /// 특정 코인의 상위 타임프레임 추세 필터를 반환합니다 (비활성 시 `None`).
+    pub fn trend(&self, coin: &str) -> Option<&TrendFilter> {
+        self.trend.get(coin)
+    }
+
     /// 특정 코인의 최신 스프레드(%)를 반환합니다.
     pub fn last_spread_pct(&self, coin: &str) -> Option<f64> {
         self.spread_pct_windows.get(coin)?.last()
     }
 
-    /// 특정 코인의 스프레드 윈도우가 Z-Score 계산에 필요한 만큼 데이터가 찼는지 확인합니다.
+    /// 특정 코인의 통계가 Z-Score 계산에 필요한 만큼 데이터가 찼는지 확인합니다.
+    ///
+    /// EWMA 모드에서는 윈도우 대신 EWMA 샘플 수(`min_samples`)를 기준으로 판단합니다.
     pub fn is_ready(&self, coin: &str) -> bool {
+        if let StatsMode::Ewma { min_samples, .. } = self.stats_mode {
+            return self
+                .ewma_stats
+                .get(coin)
+                .is_some_and(|s| s.count >= min_samples);
+        }
         self.spread_pct_windows
             .get(coin)
             .map(|w| w.is_ready())
             .unwrap_or(false)
     }
 
-    /// 윈도우 크기를 반환합니다.
+    /// 기본 윈도우 크기를 반환합니다.
     pub fn window_size(&self) -> usize {
         self.window_size
     }
@@ -1114,4 +1485,369 @@ mod tests {
         assert!(calc.short_spread_windows.contains_key("ETH"));
         assert!(calc.short_spread_stats.contains_key("ETH"));
     }
+
+    /// 테스트용: 코인에 스프레드가 변하는 분봉을 n개 push합니다.
+    fn push_minutes(calc: &mut SpreadCalculator, coin: &str, n: i64) {
+        let ts = Utc::now();
+        for i in 0..n {
+            calc.update(
+                coin,
+                ts + chrono::Duration::minutes(i),
+                Some(dec(138_000_000 + (i % 7) * 50_000, 0)),
+                1380.0,
+                Some(dec(100_000 + (i % 5) * 10, 0)),
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn test_add_coin_with_window() {
+        let mut calc = SpreadCalculator::new(&[], 1440);
+        calc.add_coin_with_window("ETH", 720);
+        assert_eq!(calc.coin_window_size("ETH"), Some(720));
+        assert_eq!(calc.window_size(), 1440);
+
+        // idempotent: 기존 크기 유지
+        calc.add_coin_with_window("ETH", 100);
+        assert_eq!(calc.coin_window_size("ETH"), Some(720));
+        assert_eq!(calc.coin_window_size("BTC"), None);
+    }
+
+    #[test]
+    fn test_resize_coin_window_shrink_rebuilds_stats() {
+        let coins = vec!["BTC".to_string()];
+        let mut calc = SpreadCalculator::new(&coins, 20);
+        push_minutes(&mut calc, "BTC", 20);
+        assert!(calc.is_ready("BTC"));
+
+        calc.resize_coin_window("BTC", 10).unwrap();
+        assert_eq!(calc.coin_window_size("BTC"), Some(10));
+        assert_eq!(calc.upbit_window("BTC").unwrap().len(), 10);
+        assert_eq!(calc.bybit_window("BTC").unwrap().len(), 10);
+
+        let (mean, stddev) = calc.cached_stats("BTC").expect("축소 후에도 준비 상태");
+        let data = calc.spread_window("BTC").unwrap().data();
+        let manual_mean = statistics::mean(data);
+        let manual_stddev = statistics::stddev(data, manual_mean);
+        assert!((mean - manual_mean).abs() < 1e-10);
+        assert!((stddev - manual_stddev).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_resize_coin_window_grow_not_ready() {
+        let coins = vec!["BTC".to_string()];
+        let mut calc = SpreadCalculator::new(&coins, 10);
+        push_minutes(&mut calc, "BTC", 10);
+        assert!(calc.cached_stats("BTC").is_some());
+
+        calc.resize_coin_window("BTC", 15).unwrap();
+        assert!(calc.cached_stats("BTC").is_none());
+        assert_eq!(calc.spread_window("BTC").unwrap().len(), 10);
+    }
+
+    #[test]
+    fn test_resize_coin_window_unknown_coin() {
+        let mut calc = SpreadCalculator::new(&[], 10);
+        assert!(calc.resize_coin_window("BTC", 5).is_err());
+    }
+
+    #[test]
+    fn test_replace_coin_from() {
+        let coins = vec!["BTC".to_string()];
+        let mut calc = SpreadCalculator::new(&coins, 5);
+        push_minutes(&mut calc, "BTC", 5);
+
+        let mut staging = SpreadCalculator::new(&[], 5);
+        staging.add_coin_with_window("BTC", 8);
+        push_minutes(&mut staging, "BTC", 8);
+
+        calc.replace_coin_from("BTC", &mut staging).unwrap();
+        assert_eq!(calc.coin_window_size("BTC"), Some(8));
+        assert!(calc.is_ready("BTC"));
+        assert!(staging.spread_window("BTC").is_none());
+
+        // 없는 코인은 에러
+        assert!(calc.replace_coin_from("ETH", &mut staging).is_err());
+    }
+
+    // --- EWMA 테스트 ---
+
+    #[test]
+    fn test_ewma_stats_constant_series() {
+        let mut ewma = EwmaStats::new(10.0);
+        for _ in 0..50 {
+            ewma.push(0.3, None);
+        }
+        assert!((ewma.mean() - 0.3).abs() < 1e-12);
+        assert!(ewma.stddev().abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_ewma_stats_half_life_weight() {
+        // 0에서 1로 계단 변화 후 half_life만큼 push하면 mean은 정확히 절반
+        let mut ewma = EwmaStats::new(10.0);
+        ewma.push(0.0, None);
+        for _ in 0..10 {
+            ewma.push(1.0, None);
+        }
+        assert!((ewma.mean() - 0.5).abs() < 1e-12, "mean={}", ewma.mean());
+    }
+
+    #[test]
+    fn test_ewma_close_to_rolling_on_stationary_series() {
+        // 정상 시계열에서는 롤링 통계와 비슷한 값을 추정해야 함
+        let mut window = CandleWindow::new(1440);
+        let mut rolling = IncrementalStats::new();
+        let mut ewma = EwmaStats::new(200.0);
+        for i in 0..3000 {
+            let x = i as f64;
+            let v = 0.2 + (x * 0.7).sin() + 0.5 * (x * 1.3).sin();
+            push_to_window_and_stats(&mut window, &mut rolling, v);
+            ewma.push(v, None);
+        }
+        assert!(
+            (ewma.mean() - rolling.mean()).abs() < 0.05,
+            "mean 불일치: ewma={}, rolling={}",
+            ewma.mean(),
+            rolling.mean()
+        );
+        let rel = (ewma.stddev() - rolling.stddev()).abs() / rolling.stddev();
+        assert!(
+            rel < 0.1,
+            "stddev 불일치: ewma={}, rolling={}",
+            ewma.stddev(),
+            rolling.stddev()
+        );
+    }
+
+    #[test]
+    fn test_ewma_reacts_faster_than_rolling() {
+        // 스프레드 레벨이 0 → 1로 이동한 직후 EWMA mean이 새 레벨에 더 가까워야 함
+        let mut window = CandleWindow::new(1440);
+        let mut rolling = IncrementalStats::new();
+        let mut ewma = EwmaStats::new(30.0);
+        for i in 0..1500 {
+            let v = if i < 1440 { 0.0 } else { 1.0 };
+            push_to_window_and_stats(&mut window, &mut rolling, v);
+            ewma.push(v, None);
+        }
+        assert!(rolling.mean() < 0.1, "rolling mean={}", rolling.mean());
+        assert!(ewma.mean() > 0.7, "ewma mean={}", ewma.mean());
+    }
+
+    #[test]
+    fn test_ewma_mode_ready_before_window_full() {
+        let coins = vec!["BTC".to_string()];
+        let mode = StatsMode::Ewma {
+            half_life: 60.0,
+            min_samples: 30,
+        };
+        let mut ewma_calc = SpreadCalculator::new(&coins, 1440).with_stats_mode(mode);
+        let mut rolling_calc = SpreadCalculator::new(&coins, 1440);
+
+        push_minutes(&mut ewma_calc, "BTC", 29);
+        assert!(!ewma_calc.is_ready("BTC"));
+        assert!(ewma_calc.cached_stats("BTC").is_none());
+
+        push_minutes(&mut ewma_calc, "BTC", 1);
+        push_minutes(&mut rolling_calc, "BTC", 30);
+        assert!(ewma_calc.is_ready("BTC"));
+        assert!(ewma_calc.cached_stats("BTC").is_some());
+        assert!(!rolling_calc.is_ready("BTC"));
+        assert!(rolling_calc.cached_stats("BTC").is_none());
+    }
+
+    #[test]
+    fn test_with_stats_mode_rebuilds_from_window() {
+        let coins = vec!["BTC".to_string()];
+        let mut calc = SpreadCalculator::new(&coins, 20);
+        push_minutes(&mut calc, "BTC", 12);
+
+        let calc = calc.with_stats_mode(StatsMode::Ewma {
+            half_life: 5.0,
+            min_samples: 10,
+        });
+        let expected = EwmaStats::from_data(5.0, calc.spread_window("BTC").unwrap().data());
+        let (mean, stddev) = calc.cached_stats("BTC").unwrap();
+        assert!((mean - expected.mean()).abs() < 1e-12);
+        assert!((stddev - expected.stddev()).abs() < 1e-12);
+
+        // 새로 추가한 코인은 빈 EWMA로 시작
+        let mut calc = calc;
+        calc.add_coin("ETH");
+        assert!(!calc.is_ready("ETH"));
+        calc.remove_coin("BTC");
+        assert!(calc.cached_stats("BTC").is_none());
+    }
+
+    #[test]
+    fn test_ewma_mode_resize_keeps_stats() {
+        let coins = vec!["BTC".to_string()];
+        let mut calc = SpreadCalculator::new(&coins, 20).with_stats_mode(StatsMode::Ewma {
+            half_life: 5.0,
+            min_samples: 10,
+        });
+        push_minutes(&mut calc, "BTC", 12);
+        let before = calc.cached_stats("BTC").unwrap();
+
+        // 윈도우 확대로 롤링 윈도우가 미준비여도 EWMA 통계는 유지
+        calc.resize_coin_window("BTC", 40).unwrap();
+        assert!(!calc.spread_window("BTC").unwrap().is_ready());
+        assert_eq!(calc.cached_stats("BTC"), Some(before));
+    }
+
+    // --- Kalman 헤지 테스트 ---
+
+    fn kalman_params() -> KalmanParams {
+        KalmanParams {
+            delta: 1e-5,
+            obs_var: 1e-6,
+        }
+    }
+
+    #[test]
+    fn test_kalman_disabled_by_default() {
+        let coins = vec!["BTC".to_string()];
+        let mut calc = SpreadCalculator::new(&coins, 5);
+        push_minutes(&mut calc, "BTC", 5);
+        assert!(calc.kalman("BTC").is_none());
+    }
+
+    #[test]
+    fn test_kalman_updated_with_spread() {
+        let coins = vec!["BTC".to_string()];
+        let mut calc = SpreadCalculator::new(&coins, 5).with_kalman(Some(kalman_params()));
+        push_minutes(&mut calc, "BTC", 8);
+        let kf = calc.kalman("BTC").unwrap();
+        assert_eq!(kf.count(), 8);
+
+        // 새 코인은 빈 추정기로 시작, 제거 시 함께 삭제
+        calc.add_coin("ETH");
+        assert_eq!(calc.kalman("ETH").unwrap().count(), 0);
+        calc.remove_coin("BTC");
+        assert!(calc.kalman("BTC").is_none());
+    }
+
+    #[test]
+    fn test_with_kalman_rebuilds_from_windows() {
+        let coins = vec!["BTC".to_string()];
+        let mut incremental = SpreadCalculator::new(&coins, 20).with_kalman(Some(kalman_params()));
+        let mut rebuilt = SpreadCalculator::new(&coins, 20);
+        push_minutes(&mut incremental, "BTC", 12);
+        push_minutes(&mut rebuilt, "BTC", 12);
+        let rebuilt = rebuilt.with_kalman(Some(kalman_params()));
+
+        let a = incremental.kalman("BTC").unwrap();
+        let b = rebuilt.kalman("BTC").unwrap();
+        assert_eq!(a.count(), b.count());
+        assert!((a.hedge_ratio() - b.hedge_ratio()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_replace_coin_from_rebuilds_kalman() {
+        let coins = vec!["BTC".to_string()];
+        let mut calc = SpreadCalculator::new(&coins, 5).with_kalman(Some(kalman_params()));
+        push_minutes(&mut calc, "BTC", 5);
+
+        // staging에 Kalman이 없으면 교체된 윈도우로 재계산
+        let mut staging = SpreadCalculator::new(&[], 8);
+        staging.add_coin("BTC");
+        push_minutes(&mut staging, "BTC", 8);
+        calc.replace_coin_from("BTC", &mut staging).unwrap();
+        assert_eq!(calc.kalman("BTC").unwrap().count(), 8);
+    }
+
+    // --- 캔들 간격 / 추세 필터 테스트 ---
+
+    #[test]
+    fn test_short_window_size_for_interval() {
+        assert_eq!(short_window_size_for(1), 60);
+        assert_eq!(short_window_size_for(5), 12);
+        assert_eq!(short_window_size_for(15), MIN_SHORT_WINDOW_SIZE);
+        assert_eq!(short_window_size_for(60), MIN_SHORT_WINDOW_SIZE);
+    }
+
+    #[test]
+    fn test_with_candle_interval_short_stats_ready_sooner() {
+        let coins = vec!["BTC".to_string()];
+        let mut calc = SpreadCalculator::new(&coins, 100).with_candle_interval(5);
+        push_minutes(&mut calc, "BTC", 11);
+        assert!(calc.cached_short_stats("BTC").is_none());
+        push_minutes(&mut calc, "BTC", 1);
+        assert!(calc.cached_short_stats("BTC").is_some());
+
+        // 이후 추가한 코인도 같은 단기 윈도우 크기 사용
+        calc.add_coin("ETH");
+        push_minutes(&mut calc, "ETH", 12);
+        assert!(calc.cached_short_stats("ETH").is_some());
+    }
+
+    #[test]
+    fn test_with_candle_interval_rebuilds_short_window() {
+        let coins = vec!["BTC".to_string()];
+        let mut calc = SpreadCalculator::new(&coins, 100);
+        push_minutes(&mut calc, "BTC", 30);
+        assert!(calc.cached_short_stats("BTC").is_none());
+
+        // 5분봉 기준으로 전환하면 최근 12개로 단기 윈도우를 재구성
+        let calc = calc.with_candle_interval(5);
+        let (mean, _) = calc.cached_short_stats("BTC").unwrap();
+        let data = calc.spread_window("BTC").unwrap().data();
+        let expected: f64 = data.iter().skip(data.len() - 12).sum::<f64>() / 12.0;
+        assert!((mean - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_trend_filter_fed_by_update() {
+        use crate::zscore::trend::TrendParams;
+        use arb_exchange::CandleInterval;
+
+        let coins = vec!["BTC".to_string()];
+        let params = TrendParams {
+            interval: CandleInterval::Minute5,
+            window: 12,
+            max_t_stat: 3.0,
+        };
+        let mut calc = SpreadCalculator::new(&coins, 5).with_trend(Some(params));
+        // 분봉 20개 = 5분 버킷 4개 (마지막 버킷은 진행 중)
+        let ts = chrono::TimeZone::with_ymd_and_hms(&Utc, 2026, 3, 1, 0, 0, 0).unwrap();
+        for i in 0..20 {
+            calc.update(
+                "BTC",
+                ts + chrono::Duration::minutes(i),
+                Some(dec(138_000_000 + i * 10_000, 0)),
+                1380.0,
+                Some(dec(100_050, 0)),
+            )
+            .unwrap();
+        }
+        assert_eq!(calc.trend("BTC").unwrap().len(), 3);
+
+        calc.seed_trend("BTC", &[0.1; 12]);
+        assert_eq!(calc.trend("BTC").unwrap().len(), 12);
+        calc.remove_coin("BTC");
+        assert!(calc.trend("BTC").is_none());
+    }
+
+    #[test]
+    fn test_replace_coin_from_keeps_trend() {
+        use crate::zscore::trend::TrendParams;
+        use arb_exchange::CandleInterval;
+
+        let coins = vec!["BTC".to_string()];
+        let params = TrendParams {
+            interval: CandleInterval::Minute60,
+            window: 12,
+            max_t_stat: 3.0,
+        };
+        let mut calc = SpreadCalculator::new(&coins, 5).with_trend(Some(params));
+        calc.seed_trend("BTC", &[0.1; 12]);
+
+        let mut staging = SpreadCalculator::new(&[], 8);
+        staging.add_coin("BTC");
+        push_minutes(&mut staging, "BTC", 8);
+        calc.replace_coin_from("BTC", &mut staging).unwrap();
+        assert_eq!(calc.trend("BTC").unwrap().len(), 12);
+    }
 }
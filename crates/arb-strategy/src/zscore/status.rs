@@ -0,0 +1,408 @@
+//! 읽기 전용 상태 조회 API.
+//!
+//! `StatusBoard`는 라이브 세션의 공유 상태(포지션, 스프레드 통계, 잔고, 리스크, 환율,
+//! 최근 알림)에 대한 참조만 들고 있다가, HTTP 요청이 올 때 스냅샷을 복사해 JSON으로
+//! 돌려줍니다. 트레이딩 루프에 쓰기 경로를 추가하지 않으며, 포지션/스프레드 lock은
+//! 복사하는 동안만 잡습니다.
+//!
+//! 라우트 (`GET`):
+//! - `/status/positions`: 열린 포지션
+//! - `/status/spreads`: 코인별 스프레드, mean/stddev, z-score
+//! - `/status/balance`: 가용/예약 잔고
+//! - `/status/risk`: 리스크 매니저 상태
+//! - `/status/forex`: USD/KRW, USDT/KRW 환율
+//! - `/status/alerts`: 최근 알림 (최신순)
+//!
+//! 전략 인스턴스가 여럿이면 인스턴스마다 `/status/<인스턴스>/positions`처럼
+//! 접두사를 달리해 [`StatusBoard::mount_at`]으로 등록합니다.
+
+use std::future::Future;
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use serde::Serialize;
+
+use arb_forex::{ForexCache, UsdtKrwCache};
+use arb_metrics::{HttpResponse, HttpServer};
+
+use crate::zscore::alert::{RecentAlert, RecentAlerts};
+use crate::zscore::balance::BalanceTracker;
+use crate::zscore::position::{PositionManager, VirtualPosition};
+use crate::zscore::risk::RiskManager;
+use crate::zscore::spread::SpreadCalculator;
+
+/// 코인별 스프레드 통계 스냅샷.
+#[derive(Debug, Clone, Serialize)]
+pub struct SpreadStatus {
+    /// 코인 심볼.
+    pub coin: String,
+    /// 최신 캔들 스프레드 (%).
+    pub spread_pct: Option<f64>,
+    /// 스프레드 평균 (`cached_stats`, 윈도우 미충족 시 None).
+    pub mean: Option<f64>,
+    /// 스프레드 표준편차.
+    pub stddev: Option<f64>,
+    /// (spread - mean) / stddev.
+    pub z_score: Option<f64>,
+    /// Z-Score 계산에 필요한 데이터가 찼는지 여부.
+    pub ready: bool,
+}
+
+/// 잔고 스냅샷.
+#[derive(Debug, Clone, Serialize)]
+pub struct BalanceStatus {
+    /// Upbit 가용 KRW.
+    pub upbit_available_krw: Decimal,
+    /// Bybit 가용 USDT.
+    pub bybit_available_usdt: Decimal,
+    /// Upbit 예약 KRW.
+    pub upbit_reserved_krw: Decimal,
+    /// Bybit 예약 USDT.
+    pub bybit_reserved_usdt: Decimal,
+    /// 활성 예약 수.
+    pub active_reservations: usize,
+}
+
+/// 리스크 매니저 스냅샷.
+#[derive(Debug, Clone, Serialize)]
+pub struct RiskStatus {
+    /// kill switch 발동 여부.
+    pub killed: bool,
+    /// 신규 진입 허용 여부.
+    pub entry_allowed: bool,
+    /// 당일 실현 PnL (USDT).
+    pub daily_pnl_usdt: Decimal,
+    /// 현재 equity (USDT).
+    pub equity_usdt: Decimal,
+    /// 최근 24시간 누적 손실 (USDT).
+    pub rolling_24h_loss_usdt: Decimal,
+    /// 누적 거래 수.
+    pub total_trade_count: u64,
+}
+
+/// 환율 스냅샷.
+#[derive(Debug, Clone, Serialize)]
+pub struct ForexStatus {
+    /// USD/KRW 캐시 환율 (TTL 만료 시 None).
+    pub usd_krw: Option<f64>,
+    /// USDT/KRW 시세 (stale 포함 마지막 값).
+    pub usdt_krw: Option<f64>,
+    /// USDT/KRW 시세가 stale 상태인지 여부.
+    pub usdt_krw_stale: bool,
+}
+
+/// 모니터 `run()`이 소유한 상태 참조.
+#[derive(Clone)]
+struct MonitorState {
+    position_mgr: Arc<tokio::sync::Mutex<PositionManager>>,
+    spread_calc: Arc<tokio::sync::RwLock<SpreadCalculator>>,
+}
+
+/// 상태 조회 API의 데이터 소스 모음.
+///
+/// 세션 전역 객체는 `with_*` 빌더로 주입하고, 모니터 내부 상태는
+/// `ZScoreMonitor::run()`이 시작할 때 바인딩합니다.
+#[derive(Clone, Default)]
+pub struct StatusBoard {
+    balance: Option<Arc<BalanceTracker>>,
+    risk: Option<Arc<RiskManager>>,
+    forex: Option<Arc<ForexCache>>,
+    usdt_krw: Option<Arc<UsdtKrwCache>>,
+    alerts: Option<RecentAlerts>,
+    monitor: Arc<parking_lot::RwLock<Option<MonitorState>>>,
+}
+
+impl StatusBoard {
+    /// 빈 StatusBoard 생성.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 잔고 추적기 연결.
+    pub fn with_balance(mut self, balance: Arc<BalanceTracker>) -> Self {
+        self.balance = Some(balance);
+        self
+    }
+
+    /// 리스크 매니저 연결.
+    pub fn with_risk(mut self, risk: Arc<RiskManager>) -> Self {
+        self.risk = Some(risk);
+        self
+    }
+
+    /// 환율 캐시 연결.
+    pub fn with_forex(mut self, forex: Arc<ForexCache>, usdt_krw: Arc<UsdtKrwCache>) -> Self {
+        self.forex = Some(forex);
+        self.usdt_krw = Some(usdt_krw);
+        self
+    }
+
+    /// 최근 알림 버퍼 연결 (`AlertService::recent_alerts()`).
+    pub fn with_alerts(mut self, alerts: RecentAlerts) -> Self {
+        self.alerts = Some(alerts);
+        self
+    }
+
+    /// 모니터 내부 상태를 바인딩합니다. 재실행 시 새 상태로 교체됩니다.
+    pub(crate) fn bind_monitor(
+        &self,
+        position_mgr: Arc<tokio::sync::Mutex<PositionManager>>,
+        spread_calc: Arc<tokio::sync::RwLock<SpreadCalculator>>,
+    ) {
+        *self.monitor.write() = Some(MonitorState {
+            position_mgr,
+            spread_calc,
+        });
+    }
+
+    fn monitor_state(&self) -> Option<MonitorState> {
+        self.monitor.read().clone()
+    }
+
+    /// 열린 포지션 (코인, ID 순). 모니터 미실행 시 None.
+    pub async fn positions(&self) -> Option<Vec<VirtualPosition>> {
+        let state = self.monitor_state()?;
+        let mut positions: Vec<VirtualPosition> = {
+            let pm = state.position_mgr.lock().await;
+            pm.open_positions.values().flatten().cloned().collect()
+        };
+        positions.sort_by(|a, b| a.coin.cmp(&b.coin).then(a.id.cmp(&b.id)));
+        Some(positions)
+    }
+
+    /// 코인별 스프레드 통계 (코인 순). 모니터 미실행 시 None.
+    pub async fn spreads(&self) -> Option<Vec<SpreadStatus>> {
+        let state = self.monitor_state()?;
+        let sc = state.spread_calc.read().await;
+        let mut coins: Vec<&str> = sc.active_coins();
+        coins.sort_unstable();
+        let spreads = coins
+            .into_iter()
+            .map(|coin| {
+                let spread_pct = sc.last_spread_pct(coin);
+                let stats = sc.cached_stats(coin);
+                let z_score = match (spread_pct, stats) {
+                    (Some(spread), Some((mean, stddev))) if stddev > 0.0 => {
+                        Some((spread - mean) / stddev)
+                    }
+                    _ => None,
+                };
+                SpreadStatus {
+                    coin: coin.to_string(),
+                    spread_pct,
+                    mean: stats.map(|(m, _)| m),
+                    stddev: stats.map(|(_, s)| s),
+                    z_score,
+                    ready: sc.is_ready(coin),
+                }
+            })
+            .collect();
+        Some(spreads)
+    }
+
+    /// 잔고 스냅샷. 미연결 시 None.
+    pub fn balance(&self) -> Option<BalanceStatus> {
+        let tracker = self.balance.as_ref()?;
+        let ((upbit_krw, bybit_usdt), (reserved_krw, reserved_usdt)) =
+            tracker.available_and_reserved();
+        Some(BalanceStatus {
+            upbit_available_krw: upbit_krw,
+            bybit_available_usdt: bybit_usdt,
+            upbit_reserved_krw: reserved_krw,
+            bybit_reserved_usdt: reserved_usdt,
+            active_reservations: tracker.active_reservation_count(),
+        })
+    }
+
+    /// 리스크 스냅샷. 미연결 시 None.
+    pub fn risk(&self) -> Option<RiskStatus> {
+        let risk = self.risk.as_ref()?;
+        Some(RiskStatus {
+            killed: risk.is_killed(),
+            entry_allowed: risk.is_entry_allowed(),
+            daily_pnl_usdt: risk.daily_pnl(),
+            equity_usdt: risk.current_equity(),
+            rolling_24h_loss_usdt: risk.rolling_24h_loss(),
+            total_trade_count: risk.total_trade_count(),
+        })
+    }
+
+    /// 환율 스냅샷.
+    pub fn forex(&self) -> ForexStatus {
+        ForexStatus {
+            usd_krw: self.forex.as_ref().and_then(|f| f.get_cached_rate()),
+            usdt_krw: self
+                .usdt_krw
+                .as_ref()
+                .and_then(|c| c.get_usdt_krw_with_stale()),
+            usdt_krw_stale: self.usdt_krw.as_ref().is_some_and(|c| c.is_stale()),
+        }
+    }
+
+    /// 최근 알림 (최신순). 미연결 시 None.
+    pub fn alerts(&self) -> Option<Vec<RecentAlert>> {
+        self.alerts.as_ref().map(RecentAlerts::snapshot)
+    }
+
+    /// `/status/*` 라우트를 HTTP 서버에 등록합니다.
+    pub fn mount(&self, server: HttpServer) -> HttpServer {
+        self.mount_at(server, "/status")
+    }
+
+    /// `prefix` 아래(e.g., "/status/btc")에 상태 라우트를 등록합니다.
+    pub fn mount_at(&self, server: HttpServer, prefix: &str) -> HttpServer {
+        let prefix = prefix.trim_end_matches('/');
+        let path = |name: &str| format!("{prefix}/{name}");
+        let server = self.route(server, &path("positions"), |b| async move {
+            b.positions()
+                .await
+                .map_or_else(monitor_not_running, |p| json_response(&p))
+        });
+        let server = self.route(server, &path("spreads"), |b| async move {
+            b.spreads()
+                .await
+                .map_or_else(monitor_not_running, |s| json_response(&s))
+        });
+        let server = self.route(server, &path("balance"), |b| async move {
+            b.balance()
+                .map_or_else(|| not_configured("balance"), |s| json_response(&s))
+        });
+        let server = self.route(server, &path("risk"), |b| async move {
+            b.risk()
+                .map_or_else(|| not_configured("risk"), |s| json_response(&s))
+        });
+        let server = self.route(server, &path("forex"), |b| async move {
+            json_response(&b.forex())
+        });
+        self.route(server, &path("alerts"), |b| async move {
+            b.alerts()
+                .map_or_else(|| not_configured("alerts"), |a| json_response(&a))
+        })
+    }
+
+    fn route<F, Fut>(&self, server: HttpServer, path: &str, handler: F) -> HttpServer
+    where
+        F: Fn(StatusBoard) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = HttpResponse> + Send + 'static,
+    {
+        let board = self.clone();
+        server.route(path, move || handler(board.clone()))
+    }
+}
+
+fn json_response<T: Serialize>(value: &T) -> HttpResponse {
+    match serde_json::to_string(value) {
+        Ok(body) => HttpResponse::json(body),
+        Err(e) => error_response(500, &format!("serialization failed: {e}")),
+    }
+}
+
+fn error_response(status: u16, message: &str) -> HttpResponse {
+    HttpResponse::json(serde_json::json!({ "error": message }).to_string()).with_status(status)
+}
+
+fn monitor_not_running() -> HttpResponse {
+    error_response(503, "monitor not running")
+}
+
+fn not_configured(source: &str) -> HttpResponse {
+    error_response(404, &format!("{source} source not configured"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::time::Duration;
+
+    fn position(coin: &str) -> VirtualPosition {
+        VirtualPosition {
+            coin: coin.to_string(),
+            qty: Decimal::ONE,
+            bybit_entry_price: Decimal::new(101, 0),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_status_board_unbound_monitor() {
+        let board = StatusBoard::new();
+        assert!(board.positions().await.is_none());
+        assert!(board.spreads().await.is_none());
+        assert!(board.balance().is_none());
+        assert!(board.risk().is_none());
+        assert!(board.alerts().is_none());
+        let forex = board.forex();
+        assert!(forex.usd_krw.is_none());
+        assert!(!forex.usdt_krw_stale);
+    }
+
+    #[tokio::test]
+    async fn test_status_board_positions_sorted() {
+        let board = StatusBoard::new();
+        let mut pm = PositionManager::new();
+        pm.open_position(position("XRP")).unwrap();
+        pm.open_position(position("BTC")).unwrap();
+        pm.open_position(position("BTC")).unwrap();
+        let spread_calc = SpreadCalculator::new(&["BTC".to_string()], 3);
+        board.bind_monitor(
+            Arc::new(tokio::sync::Mutex::new(pm)),
+            Arc::new(tokio::sync::RwLock::new(spread_calc)),
+        );
+
+        let positions = board.positions().await.unwrap();
+        let keys: Vec<(&str, u64)> = positions.iter().map(|p| (p.coin.as_str(), p.id)).collect();
+        assert_eq!(keys.len(), 3);
+        assert_eq!(keys[0].0, "BTC");
+        assert_eq!(keys[1].0, "BTC");
+        assert!(keys[0].1 < keys[1].1);
+        assert_eq!(keys[2].0, "XRP");
+
+        let spreads = board.spreads().await.unwrap();
+        assert_eq!(spreads.len(), 1);
+        assert_eq!(spreads[0].coin, "BTC");
+        assert!(!spreads[0].ready);
+        assert!(spreads[0].z_score.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_status_board_balance_risk_forex() {
+        let forex = Arc::new(ForexCache::new(Duration::from_secs(600)));
+        forex.update_cache_for_test(1350.0);
+        let usdt_krw = Arc::new(UsdtKrwCache::new());
+        usdt_krw.update_for_test(1380.0);
+
+        let board = StatusBoard::new()
+            .with_balance(Arc::new(BalanceTracker::new(
+                Decimal::new(1_000_000, 0),
+                Decimal::new(700, 0),
+            )))
+            .with_risk(Arc::new(RiskManager::new(
+                crate::zscore::risk::RiskConfig::default(),
+            )))
+            .with_forex(forex, usdt_krw);
+
+        let balance = board.balance().unwrap();
+        assert_eq!(balance.upbit_available_krw, Decimal::new(1_000_000, 0));
+        assert_eq!(balance.bybit_reserved_usdt, Decimal::ZERO);
+
+        let risk = board.risk().unwrap();
+        assert!(!risk.killed);
+        assert_eq!(risk.total_trade_count, 0);
+
+        let fx = board.forex();
+        assert_eq!(fx.usd_krw, Some(1350.0));
+        assert_eq!(fx.usdt_krw, Some(1380.0));
+
+        let body = json_response(&balance).body;
+        assert!(body.contains("\"upbit_available_krw\":\"1000000\""));
+    }
+
+    #[test]
+    fn test_error_response_shape() {
+        let resp = monitor_not_running();
+        assert_eq!(resp.status, 503);
+        assert_eq!(resp.body, r#"{"error":"monitor not running"}"#);
+        assert_eq!(not_configured("risk").status, 404);
+    }
+}
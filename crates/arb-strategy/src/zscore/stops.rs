@@ -0,0 +1,245 @@
+//! 포지션별 손절/트레일링 스탑.
+//!
+//! Z-Score 청산은 평균 회귀를 전제로 하므로, 프리미엄이 계속 벌어지는 구간에서는
+//! 손실이 `max_single_loss_pct` kill switch까지 누적될 수 있습니다.
+//! 이 모듈은 개별 포지션 단위로 다음 규칙을 평가합니다 ([`StopLossConfig`] 참고).
+//!
+//! - 스프레드 손절: 진입 대비 스프레드 확대폭이 `spread_stop_pct` 이상
+//! - Z-Score 손절: 현재 Z-Score가 `z_stop` 이상
+//! - 미실현 손실 손절: 미실현 손실률이 `max_loss_pct` 이상
+//! - 트레일링 스탑: 최고 미실현 수익률이 `trailing_activation_pct`에 도달한 뒤
+//!   `trailing_distance_pct` 이상 되돌림
+//!
+//! 미실현 수익률은 Upbit 현물 long + Bybit short 기준으로
+//! `진입 스프레드 - 현재 스프레드 - 왕복 수수료` (%)로 추정합니다.
+//! `monitor_core`가 매 틱 [`check_stops`]로 대상 포지션을 고르고,
+//! `ExitContext::position_ids`로 지정해 `ExecutionPolicy::on_exit_signal`로 청산합니다.
+
+use crate::zscore::config::StopLossConfig;
+use crate::zscore::position::{PositionManager, PositionState, VirtualPosition};
+
+/// 손절 발동 사유.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopReason {
+    /// 스프레드 확대폭 초과.
+    Spread,
+    /// Z-Score 초과.
+    ZScore,
+    /// 미실현 손실률 초과.
+    Loss,
+    /// 최고 수익률 대비 되돌림.
+    Trailing,
+}
+
+impl StopReason {
+    /// 로그/메트릭용 이름.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Spread => "spread",
+            Self::ZScore => "z_score",
+            Self::Loss => "loss",
+            Self::Trailing => "trailing",
+        }
+    }
+}
+
+/// 손절 대상 포지션 하나.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StopTrigger {
+    /// 포지션 ID.
+    pub position_id: u64,
+    /// 발동 사유.
+    pub reason: StopReason,
+    /// 평가 시점의 미실현 수익률 (%).
+    pub unrealized_pnl_pct: f64,
+}
+
+/// 스프레드 기반 미실현 수익률 (%)을 추정합니다.
+pub fn unrealized_pnl_pct(
+    entry_spread_pct: f64,
+    current_spread_pct: f64,
+    roundtrip_fee_pct: f64,
+) -> f64 {
+    entry_spread_pct - current_spread_pct - roundtrip_fee_pct
+}
+
+/// 포지션 하나에 손절 규칙을 적용합니다.
+///
+/// 트레일링 스탑이 켜져 있으면 최고 미실현 수익률(`peak_pnl_pct`)을 갱신합니다.
+/// `z_score`가 `None`(stddev 임계값 미달 등)이면 Z-Score 손절은 건너뜁니다.
+pub fn evaluate(
+    pos: &mut VirtualPosition,
+    current_spread_pct: f64,
+    z_score: Option<f64>,
+    roundtrip_fee_pct: f64,
+    config: &StopLossConfig,
+) -> Option<StopReason> {
+    let pnl_pct = unrealized_pnl_pct(pos.entry_spread_pct, current_spread_pct, roundtrip_fee_pct);
+
+    let trailing_enabled = config.trailing_activation_pct > 0.0;
+    if trailing_enabled {
+        pos.peak_pnl_pct = Some(pos.peak_pnl_pct.map_or(pnl_pct, |p| p.max(pnl_pct)));
+    }
+
+    if config.max_loss_pct > 0.0 && pnl_pct <= -config.max_loss_pct {
+        return Some(StopReason::Loss);
+    }
+    if config.spread_stop_pct > 0.0
+        && current_spread_pct - pos.entry_spread_pct >= config.spread_stop_pct
+    {
+        return Some(StopReason::Spread);
+    }
+    if config.z_stop > 0.0 && z_score.is_some_and(|z| z >= config.z_stop) {
+        return Some(StopReason::ZScore);
+    }
+    if trailing_enabled
+        && let Some(peak) = pos.peak_pnl_pct
+        && peak >= config.trailing_activation_pct
+        && pnl_pct <= peak - config.trailing_distance_pct
+    {
+        return Some(StopReason::Trailing);
+    }
+    None
+}
+
+/// 코인의 청산 가능한 포지션(Open, in_flight 아님)에 손절 규칙을 적용합니다.
+pub fn check_stops(
+    pm: &mut PositionManager,
+    coin: &str,
+    current_spread_pct: f64,
+    z_score: Option<f64>,
+    roundtrip_fee_pct: f64,
+    config: &StopLossConfig,
+) -> Vec<StopTrigger> {
+    if !config.enabled {
+        return Vec::new();
+    }
+    let Some(positions) = pm.open_positions.get_mut(coin) else {
+        return Vec::new();
+    };
+
+    positions
+        .iter_mut()
+        .filter(|p| p.state == PositionState::Open && !p.in_flight)
+        .filter_map(|p| {
+            let reason = evaluate(p, current_spread_pct, z_score, roundtrip_fee_pct, config)?;
+            Some(StopTrigger {
+                position_id: p.id,
+                reason,
+                unrealized_pnl_pct: unrealized_pnl_pct(
+                    p.entry_spread_pct,
+                    current_spread_pct,
+                    roundtrip_fee_pct,
+                ),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> StopLossConfig {
+        StopLossConfig {
+            enabled: true,
+            ..StopLossConfig::default()
+        }
+    }
+
+    fn position(id: u64, entry_spread_pct: f64) -> VirtualPosition {
+        VirtualPosition {
+            id,
+            coin: "BTC".to_string(),
+            entry_spread_pct,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_unrealized_pnl_pct() {
+        // 스프레드 0.5% → 0.2% 축소, 수수료 0.2% → +0.1%
+        assert!((unrealized_pnl_pct(0.5, 0.2, 0.2) - 0.1).abs() < 1e-9);
+        // 스프레드 확대 → 손실
+        assert!(unrealized_pnl_pct(0.5, 1.0, 0.2) < 0.0);
+    }
+
+    #[test]
+    fn test_spread_and_loss_stops() {
+        let mut cfg = config();
+        cfg.spread_stop_pct = 1.0;
+        let mut pos = position(1, 0.5);
+        assert_eq!(evaluate(&mut pos, 1.2, None, 0.2, &cfg), None);
+        assert_eq!(
+            evaluate(&mut pos, 1.5, None, 0.2, &cfg),
+            Some(StopReason::Spread)
+        );
+
+        let mut cfg = config();
+        cfg.max_loss_pct = 0.5;
+        // 0.5 - 0.8 - 0.2 = -0.5% → 손절
+        assert_eq!(
+            evaluate(&mut pos, 0.8, None, 0.2, &cfg),
+            Some(StopReason::Loss)
+        );
+    }
+
+    #[test]
+    fn test_z_stop_skipped_without_z() {
+        let mut cfg = config();
+        cfg.z_stop = 4.0;
+        let mut pos = position(1, 0.5);
+        assert_eq!(evaluate(&mut pos, 0.6, None, 0.2, &cfg), None);
+        assert_eq!(evaluate(&mut pos, 0.6, Some(3.9), 0.2, &cfg), None);
+        assert_eq!(
+            evaluate(&mut pos, 0.6, Some(4.0), 0.2, &cfg),
+            Some(StopReason::ZScore)
+        );
+    }
+
+    #[test]
+    fn test_trailing_stop_after_activation() {
+        let mut cfg = config();
+        cfg.trailing_activation_pct = 0.3;
+        cfg.trailing_distance_pct = 0.1;
+        let mut pos = position(1, 1.0);
+
+        // 수익 0.2%: 활성화 전이므로 되돌림이 있어도 유지
+        assert_eq!(evaluate(&mut pos, 0.6, None, 0.2, &cfg), None);
+        assert_eq!(evaluate(&mut pos, 0.75, None, 0.2, &cfg), None);
+
+        // 수익 0.4% 도달 → 최고점 갱신
+        assert_eq!(evaluate(&mut pos, 0.4, None, 0.2, &cfg), None);
+        assert!((pos.peak_pnl_pct.unwrap() - 0.4).abs() < 1e-9);
+
+        // 0.35%: 되돌림 0.05%p → 유지, 0.25%: 되돌림 0.15%p → 청산
+        assert_eq!(evaluate(&mut pos, 0.45, None, 0.2, &cfg), None);
+        assert_eq!(
+            evaluate(&mut pos, 0.55, None, 0.2, &cfg),
+            Some(StopReason::Trailing)
+        );
+    }
+
+    #[test]
+    fn test_check_stops_skips_in_flight_and_disabled() {
+        let mut cfg = config();
+        cfg.spread_stop_pct = 0.5;
+
+        let mut pm = PositionManager::new();
+        pm.open_position(position(0, 0.2)).unwrap();
+        pm.open_position(VirtualPosition {
+            in_flight: true,
+            ..position(0, 0.2)
+        })
+        .unwrap();
+
+        let triggers = check_stops(&mut pm, "BTC", 1.0, None, 0.2, &cfg);
+        assert_eq!(triggers.len(), 1);
+        assert_eq!(triggers[0].reason, StopReason::Spread);
+        assert_eq!(triggers[0].position_id, pm.open_positions["BTC"][0].id);
+
+        cfg.enabled = false;
+        assert!(check_stops(&mut pm, "BTC", 1.0, None, 0.2, &cfg).is_empty());
+        assert!(check_stops(&mut pm, "ETH", 1.0, None, 0.2, &cfg).is_empty());
+    }
+}
@@ -0,0 +1,342 @@
+//! 체결 품질 분석 (TCA, Transaction-Cost Analysis).
+//!
+//! 시그널 시점의 기대 가격(`EntryContext`/`ExitContext`)과 실제 체결 결과
+//! (`ExecutedEntry`/`ExecutedExit`)를 비교해 레그별 슬리피지, implementation
+//! shortfall, 체결 지연, 레그 간 체결 시차를 계산합니다.
+//! 결과는 포지션에 기록되어 세션 요약과 DB(`execution_quality`)로 전달되며,
+//! `max_slippage_pct`/주문 유형 튜닝의 근거로 사용합니다.
+//!
+//! 슬리피지 부호는 "불리한 방향이 양수"입니다 (매수는 기대가보다 비싸게,
+//! 매도는 기대가보다 싸게 체결될수록 커짐).
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive as _;
+use serde::{Deserialize, Serialize};
+
+use crate::zscore::execution_policy::EntryContext;
+use crate::zscore::live_executor::{
+    ENTRY_ORDER_TYPE, EXIT_ORDER_TYPE, ExecutedEntry, ExecutedExit,
+};
+
+/// 진입/청산 1회의 체결 품질 기록.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExecutionQuality {
+    /// 체결 구분 ("entry" / "exit").
+    pub side: String,
+    /// 실제 사용한 주문 유형 ("limit_ioc" / "market").
+    pub order_type: String,
+    /// IOC 지정가에 적용한 슬리피지 허용폭 (%, 시장가는 `None`).
+    #[serde(default)]
+    pub max_slippage_pct: Option<f64>,
+    /// 기대 Upbit 가격 (KRW).
+    #[serde(with = "rust_decimal::serde::str")]
+    pub expected_upbit_krw: Decimal,
+    /// 실제 Upbit 평균 체결가 (KRW).
+    #[serde(with = "rust_decimal::serde::str")]
+    pub actual_upbit_krw: Decimal,
+    /// 기대 Bybit 가격 (USDT).
+    #[serde(with = "rust_decimal::serde::str")]
+    pub expected_bybit: Decimal,
+    /// 실제 Bybit 평균 체결가 (USDT).
+    #[serde(with = "rust_decimal::serde::str")]
+    pub actual_bybit: Decimal,
+    /// Upbit 체결 수량.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub upbit_qty: Decimal,
+    /// Bybit 체결 수량.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub bybit_qty: Decimal,
+    /// USD/KRW 환율 (KRW 레그 USDT 환산용).
+    pub usd_krw: f64,
+    /// Upbit 레그 슬리피지 (bps, 불리한 방향이 양수).
+    pub upbit_slippage_bps: f64,
+    /// Bybit 레그 슬리피지 (bps, 불리한 방향이 양수).
+    pub bybit_slippage_bps: f64,
+    /// 양 레그 가격 슬리피지 비용 합계 (USDT).
+    #[serde(with = "rust_decimal::serde::str")]
+    pub slippage_cost_usdt: Decimal,
+    /// 양 레그 실 수수료 합계 (USDT 환산).
+    #[serde(with = "rust_decimal::serde::str")]
+    pub fees_usdt: Decimal,
+    /// Implementation shortfall (USDT) = 슬리피지 비용 + 수수료.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub implementation_shortfall_usdt: Decimal,
+    /// Implementation shortfall (bps, 기대가 기준 Bybit 레그 명목 대비).
+    pub implementation_shortfall_bps: f64,
+    /// 시그널 시점 기대 수익률 (%, 진입 전용: `adjusted_profit_pct`).
+    #[serde(default)]
+    pub expected_profit_pct: Option<f64>,
+    /// 시그널 시점 오더북 안전 볼륨 (USDT, 진입 전용).
+    #[serde(default)]
+    pub safe_volume_usdt: Option<f64>,
+    /// Upbit 레그 체결 지연 (ms).
+    pub upbit_latency_ms: u64,
+    /// Bybit 레그 체결 지연 (ms).
+    pub bybit_latency_ms: u64,
+    /// 양 레그 모두 체결 응답을 받기까지 걸린 시간 (ms).
+    pub fill_latency_ms: u64,
+    /// 레그 간 체결 시차 (ms, 헤지 공백 구간).
+    pub leg_gap_ms: u64,
+    /// 체결 시각.
+    pub executed_at: DateTime<Utc>,
+}
+
+/// 한 레그의 기대가/체결 결과.
+struct LegSample {
+    expected: Decimal,
+    actual: Decimal,
+    qty: Decimal,
+    latency: Duration,
+}
+
+impl ExecutionQuality {
+    /// 진입 체결 품질을 계산합니다 (Upbit 매수 + Bybit short).
+    ///
+    /// `max_slippage_pct`는 IOC 지정가 산출에 사용한 설정값입니다.
+    pub fn from_entry(ctx: &EntryContext, executed: &ExecutedEntry, max_slippage_pct: f64) -> Self {
+        let mut quality = Self::build(
+            "entry",
+            ENTRY_ORDER_TYPE,
+            ctx.usd_krw,
+            LegSample {
+                expected: ctx.upbit_price_krw,
+                actual: executed.upbit_avg_price_krw,
+                qty: executed.upbit_filled_qty,
+                latency: executed.upbit_latency,
+            },
+            LegSample {
+                expected: ctx.bybit_entry,
+                actual: executed.bybit_avg_price,
+                qty: executed.bybit_filled_qty,
+                latency: executed.bybit_latency,
+            },
+            true,
+            executed.upbit_fee,
+            executed.bybit_fee,
+        );
+        quality.max_slippage_pct = Some(max_slippage_pct);
+        quality.expected_profit_pct = Some(ctx.adjusted_profit_pct);
+        quality.safe_volume_usdt = Some(ctx.safe_volume_usdt);
+        quality
+    }
+
+    /// 청산 체결 품질을 계산합니다 (Upbit 매도 + Bybit close 매수).
+    ///
+    /// `expected_upbit_usd`는 시그널 시점 Upbit 가격(USD 환산)이며
+    /// `usd_krw`로 KRW 기대가를 복원합니다.
+    pub fn from_exit(
+        expected_upbit_usd: Decimal,
+        expected_bybit: Decimal,
+        usd_krw: f64,
+        executed: &ExecutedExit,
+    ) -> Self {
+        let rate = Decimal::try_from(usd_krw).unwrap_or(Decimal::ZERO);
+        Self::build(
+            "exit",
+            EXIT_ORDER_TYPE,
+            usd_krw,
+            LegSample {
+                expected: expected_upbit_usd * rate,
+                actual: executed.upbit_avg_price_krw,
+                qty: executed.upbit_filled_qty,
+                latency: executed.upbit_latency,
+            },
+            LegSample {
+                expected: expected_bybit,
+                actual: executed.bybit_avg_price,
+                qty: executed.bybit_filled_qty,
+                latency: executed.bybit_latency,
+            },
+            false,
+            executed.upbit_fee,
+            executed.bybit_fee,
+        )
+    }
+
+    /// 공통 계산. `upbit_buy`가 true면 Upbit 매수/Bybit 매도, false면 반대 방향입니다.
+    #[allow(clippy::too_many_arguments)]
+    fn build(
+        side: &str,
+        order_type: &str,
+        usd_krw: f64,
+        upbit: LegSample,
+        bybit: LegSample,
+        upbit_buy: bool,
+        upbit_fee_krw: Decimal,
+        bybit_fee: Decimal,
+    ) -> Self {
+        let rate = Decimal::try_from(usd_krw)
+            .ok()
+            .filter(|r| *r > Decimal::ZERO);
+
+        let upbit_adverse = adverse_diff(upbit.expected, upbit.actual, upbit_buy);
+        let bybit_adverse = adverse_diff(bybit.expected, bybit.actual, !upbit_buy);
+
+        let upbit_cost_usdt = rate.map_or(Decimal::ZERO, |r| upbit_adverse * upbit.qty / r);
+        let slippage_cost_usdt = upbit_cost_usdt + bybit_adverse * bybit.qty;
+        let fees_usdt = rate.map_or(Decimal::ZERO, |r| upbit_fee_krw / r) + bybit_fee;
+        let implementation_shortfall_usdt = slippage_cost_usdt + fees_usdt;
+        let implementation_shortfall_bps =
+            to_bps(implementation_shortfall_usdt, bybit.expected * bybit.qty);
+
+        let upbit_latency_ms = duration_ms(upbit.latency);
+        let bybit_latency_ms = duration_ms(bybit.latency);
+
+        Self {
+            side: side.to_string(),
+            order_type: order_type.to_string(),
+            max_slippage_pct: None,
+            expected_upbit_krw: upbit.expected,
+            actual_upbit_krw: upbit.actual,
+            expected_bybit: bybit.expected,
+            actual_bybit: bybit.actual,
+            upbit_qty: upbit.qty,
+            bybit_qty: bybit.qty,
+            usd_krw,
+            upbit_slippage_bps: to_bps(upbit_adverse, upbit.expected),
+            bybit_slippage_bps: to_bps(bybit_adverse, bybit.expected),
+            slippage_cost_usdt,
+            fees_usdt,
+            implementation_shortfall_usdt,
+            implementation_shortfall_bps,
+            expected_profit_pct: None,
+            safe_volume_usdt: None,
+            upbit_latency_ms,
+            bybit_latency_ms,
+            fill_latency_ms: upbit_latency_ms.max(bybit_latency_ms),
+            leg_gap_ms: upbit_latency_ms.abs_diff(bybit_latency_ms),
+            executed_at: Utc::now(),
+        }
+    }
+}
+
+/// 기대가 대비 불리한 방향의 가격 차이 (매수: 실제 - 기대, 매도: 기대 - 실제).
+fn adverse_diff(expected: Decimal, actual: Decimal, is_buy: bool) -> Decimal {
+    if is_buy {
+        actual - expected
+    } else {
+        expected - actual
+    }
+}
+
+/// `value / base`를 bps로 변환합니다 (base가 0 이하이면 0).
+fn to_bps(value: Decimal, base: Decimal) -> f64 {
+    if base <= Decimal::ZERO {
+        return 0.0;
+    }
+    (value / base * Decimal::from(10_000u64))
+        .to_f64()
+        .unwrap_or(0.0)
+}
+
+/// `Duration`을 밀리초(u64)로 변환합니다.
+fn duration_ms(d: Duration) -> u64 {
+    u64::try_from(d.as_millis()).unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zscore::instrument::InstrumentInfo;
+
+    fn make_entry_ctx() -> EntryContext {
+        EntryContext {
+            coin: "BTC".to_string(),
+            z_score: 2.5,
+            spread_pct: 0.4,
+            expected_profit_pct: 0.3,
+            adjusted_profit_pct: 0.25,
+            upbit_price_krw: Decimal::from(100_000_000),
+            upbit_entry_usd: Decimal::new(7_142_857, 2),
+            bybit_entry: Decimal::from(70_000),
+            qty: Decimal::new(1, 2),
+            bybit_qty: Decimal::new(1, 2),
+            hedge_ratio: 1.0,
+            usd_krw: 1_400.0,
+            mean: 0.1,
+            stddev: 0.05,
+            instrument_info: InstrumentInfo::default(),
+            safe_volume_usdt: 5_000.0,
+            volume_ratio: 0.7,
+            tier: 0,
+        }
+    }
+
+    #[test]
+    fn test_from_entry_adverse_slippage_is_positive() {
+        let executed = ExecutedEntry {
+            upbit_order_id: "u-1".to_string(),
+            bybit_order_id: "b-1".to_string(),
+            upbit_filled_qty: Decimal::new(1, 2),
+            bybit_filled_qty: Decimal::new(1, 2),
+            // 매수 10bps 비싸게, short 5bps 싸게 체결
+            upbit_avg_price_krw: Decimal::from(100_100_000),
+            bybit_avg_price: Decimal::new(6_996_500, 2),
+            upbit_fee: Decimal::from(500),
+            bybit_fee: Decimal::new(385, 3),
+            effective_qty: Decimal::new(1, 2),
+            effective_bybit_qty: Decimal::new(1, 2),
+            adjustment_cost: Decimal::ZERO,
+            upbit_latency: Duration::from_millis(180),
+            bybit_latency: Duration::from_millis(60),
+        };
+
+        let q = ExecutionQuality::from_entry(&make_entry_ctx(), &executed, 0.1);
+        assert_eq!(q.side, "entry");
+        assert_eq!(q.order_type, "limit_ioc");
+        assert_eq!(q.max_slippage_pct, Some(0.1));
+        assert!((q.upbit_slippage_bps - 10.0).abs() < 1e-9);
+        assert!((q.bybit_slippage_bps - 5.0).abs() < 1e-9);
+        // Upbit 100,000 KRW × 0.01 / 1,400 + Bybit 35 × 0.01 = 0.714285.. + 0.35
+        assert!(q.slippage_cost_usdt > Decimal::new(1_064, 3));
+        assert!(q.slippage_cost_usdt < Decimal::new(1_065, 3));
+        assert_eq!(
+            q.implementation_shortfall_usdt,
+            q.slippage_cost_usdt + q.fees_usdt
+        );
+        assert!(q.implementation_shortfall_bps > 0.0);
+        assert_eq!(q.fill_latency_ms, 180);
+        assert_eq!(q.leg_gap_ms, 120);
+        assert_eq!(q.expected_profit_pct, Some(0.25));
+    }
+
+    #[test]
+    fn test_from_exit_price_improvement_is_negative() {
+        let executed = ExecutedExit {
+            upbit_order_id: "u-2".to_string(),
+            bybit_order_id: "b-2".to_string(),
+            upbit_filled_qty: Decimal::new(1, 2),
+            bybit_filled_qty: Decimal::new(1, 2),
+            // 매도 기대가(70,000 USD × 1,400 = 98,000,000 KRW)보다 비싸게 체결 → 개선
+            upbit_avg_price_krw: Decimal::from(98_098_000),
+            bybit_avg_price: Decimal::from(70_000),
+            upbit_fee: Decimal::ZERO,
+            bybit_fee: Decimal::ZERO,
+            upbit_latency: Duration::from_millis(40),
+            bybit_latency: Duration::from_millis(90),
+        };
+
+        let q = ExecutionQuality::from_exit(
+            Decimal::from(70_000),
+            Decimal::from(70_000),
+            1_400.0,
+            &executed,
+        );
+        assert_eq!(q.side, "exit");
+        assert_eq!(q.order_type, "market");
+        assert_eq!(q.max_slippage_pct, None);
+        assert_eq!(q.expected_upbit_krw, Decimal::from(98_000_000));
+        assert!((q.upbit_slippage_bps + 10.0).abs() < 1e-9);
+        assert_eq!(q.bybit_slippage_bps, 0.0);
+        assert!(q.implementation_shortfall_usdt < Decimal::ZERO);
+        assert_eq!(q.leg_gap_ms, 50);
+    }
+
+    #[test]
+    fn test_to_bps_zero_base() {
+        assert_eq!(to_bps(Decimal::ONE, Decimal::ZERO), 0.0);
+    }
+}
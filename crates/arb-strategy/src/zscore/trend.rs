@@ -0,0 +1,191 @@
+//! 상위 타임프레임 스프레드 추세 필터.
+//!
+//! 기본 캔들로 계산한 스프레드를 상위 간격(예: 1시간) 버킷으로 묶어 버킷별 마지막 값을
+//! close로 유지하고, 최근 `window`개 close의 선형 추세 t-통계량으로 프리미엄이
+//! 평균 회귀 구간인지 추세 구간인지 판단합니다.
+//!
+//! 진입은 스프레드가 평균보다 높을 때(z ≥ 임계값) 발생하고 스프레드 하락으로 수익을 내므로,
+//! 상위 타임프레임에서 스프레드가 뚜렷하게 상승 중이면(t ≥ `max_t_stat`) 진입을 거부합니다.
+
+use arb_exchange::CandleInterval;
+use chrono::{DateTime, Utc};
+
+use crate::common::candle_window::CandleWindow;
+use crate::common::convert::truncate_to_interval;
+use crate::common::statistics;
+
+/// 추세 필터 파라미터.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrendParams {
+    /// 상위 타임프레임 캔들 간격.
+    pub interval: CandleInterval,
+    /// 추세 판단에 사용할 상위 타임프레임 close 수.
+    pub window: usize,
+    /// 진입 거부 기준 추세 t-통계량 (상승 방향).
+    pub max_t_stat: f64,
+}
+
+/// 코인별 상위 타임프레임 스프레드 close 윈도우.
+#[derive(Debug, Clone)]
+pub struct TrendFilter {
+    params: TrendParams,
+    /// 진행 중인 상위 타임프레임 버킷 시작 시각.
+    bucket: Option<DateTime<Utc>>,
+    /// 진행 중인 버킷의 마지막 스프레드 (버킷 종료 시 close로 확정).
+    bucket_last: Option<f64>,
+    /// 확정된 상위 타임프레임 close 윈도우.
+    closes: CandleWindow,
+}
+
+impl TrendFilter {
+    /// 빈 추세 필터를 생성합니다.
+    pub fn new(params: TrendParams) -> Self {
+        Self {
+            params,
+            bucket: None,
+            bucket_last: None,
+            closes: CandleWindow::new(params.window),
+        }
+    }
+
+    /// 기본 캔들의 스프레드를 반영합니다.
+    ///
+    /// 상위 타임프레임 버킷이 바뀌면 직전 버킷의 마지막 값을 close로 확정합니다.
+    /// 이전 버킷보다 과거 timestamp는 무시합니다.
+    pub fn push(&mut self, timestamp: DateTime<Utc>, spread_pct: f64) {
+        let bucket = truncate_to_interval(timestamp, self.params.interval.as_minutes());
+        match self.bucket {
+            Some(current) if bucket < current => return,
+            Some(current) if bucket > current => {
+                if let Some(close) = self.bucket_last.take() {
+                    self.closes.push(close);
+                }
+                self.bucket = Some(bucket);
+            }
+            Some(_) => {}
+            None => self.bucket = Some(bucket),
+        }
+        self.bucket_last = Some(spread_pct);
+    }
+
+    /// 워밍업으로 조회한 상위 타임프레임 close로 윈도우를 교체합니다.
+    ///
+    /// 진행 중인 버킷은 초기화되며, 다음 `push`부터 새 버킷을 시작합니다.
+    pub fn seed(&mut self, closes: &[f64]) {
+        self.closes = CandleWindow::new(self.params.window);
+        for &close in closes {
+            self.closes.push(close);
+        }
+        self.bucket = None;
+        self.bucket_last = None;
+    }
+
+    /// 확정된 close 수를 반환합니다.
+    pub fn len(&self) -> usize {
+        self.closes.len()
+    }
+
+    /// 확정된 close가 없는지 확인합니다.
+    pub fn is_empty(&self) -> bool {
+        self.closes.is_empty()
+    }
+
+    /// 추세 t-통계량을 반환합니다 (윈도우 미충족 또는 회귀 실패 시 `None`).
+    pub fn t_stat(&self) -> Option<f64> {
+        if !self.closes.is_ready() {
+            return None;
+        }
+        statistics::trend_t_stat(self.closes.data()).ok()
+    }
+
+    /// 스프레드가 진입 방향과 반대로(상승) 추세 중인지 확인합니다.
+    ///
+    /// 데이터가 부족하면 필터를 적용하지 않습니다 (`false`).
+    pub fn is_adverse(&self) -> bool {
+        self.t_stat().is_some_and(|t| t >= self.params.max_t_stat)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn params(window: usize) -> TrendParams {
+        TrendParams {
+            interval: CandleInterval::Minute60,
+            window,
+            max_t_stat: 3.0,
+        }
+    }
+
+    fn hour(h: u32, m: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 1, h, m, 0).unwrap()
+    }
+
+    #[test]
+    fn test_push_finalizes_bucket_on_rollover() {
+        let mut tf = TrendFilter::new(params(12));
+        tf.push(hour(0, 0), 0.1);
+        tf.push(hour(0, 30), 0.2);
+        tf.push(hour(0, 55), 0.3);
+        assert!(tf.is_empty());
+
+        // 다음 시간 버킷 진입 → 직전 버킷 마지막 값(0.3)이 close
+        tf.push(hour(1, 0), 0.4);
+        assert_eq!(tf.len(), 1);
+        assert_eq!(tf.closes.last(), Some(0.3));
+    }
+
+    #[test]
+    fn test_push_ignores_past_bucket() {
+        let mut tf = TrendFilter::new(params(12));
+        tf.push(hour(2, 0), 0.1);
+        tf.push(hour(1, 59), 0.9);
+        tf.push(hour(3, 0), 0.2);
+        assert_eq!(tf.closes.last(), Some(0.1));
+    }
+
+    #[test]
+    fn test_t_stat_requires_full_window() {
+        let mut tf = TrendFilter::new(params(12));
+        tf.seed(&[0.1; 11]);
+        assert!(tf.t_stat().is_none());
+        assert!(!tf.is_adverse());
+    }
+
+    #[test]
+    fn test_is_adverse_on_rising_premium() {
+        let mut tf = TrendFilter::new(params(24));
+        let rising: Vec<f64> = (0..24)
+            .map(|i| 0.02 * i as f64 + 0.01 * (i as f64 * 1.3).sin())
+            .collect();
+        tf.seed(&rising);
+        assert!(tf.is_adverse(), "t={:?}", tf.t_stat());
+    }
+
+    #[test]
+    fn test_not_adverse_on_falling_or_ranging_premium() {
+        let mut tf = TrendFilter::new(params(24));
+        let falling: Vec<f64> = (0..24)
+            .map(|i| -0.02 * i as f64 + 0.01 * (i as f64 * 1.3).sin())
+            .collect();
+        tf.seed(&falling);
+        assert!(!tf.is_adverse());
+
+        let ranging: Vec<f64> = (0..24).map(|i| 0.1 * (i as f64 * 2.1).sin()).collect();
+        tf.seed(&ranging);
+        assert!(!tf.is_adverse(), "t={:?}", tf.t_stat());
+    }
+
+    #[test]
+    fn test_seed_resets_bucket() {
+        let mut tf = TrendFilter::new(params(12));
+        tf.push(hour(0, 0), 0.5);
+        tf.seed(&[0.1, 0.2]);
+        // 진행 중 버킷이 초기화되어 0.5는 close로 확정되지 않음
+        tf.push(hour(1, 0), 0.3);
+        assert_eq!(tf.len(), 2);
+        assert_eq!(tf.closes.last(), Some(0.2));
+    }
+}
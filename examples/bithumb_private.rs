@@ -37,8 +37,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // 인증된 클라이언트 생성
-    let client =
-        BithumbClient::with_credentials(&config.bithumb.api_key, &config.bithumb.secret_key)?;
+    let client = BithumbClient::with_credentials(
+        config.bithumb.api_key.expose_secret(),
+        config.bithumb.secret_key.expose_secret(),
+    )?;
     println!("Created authenticated Bithumb client");
 
     // 계정 잔고 조회
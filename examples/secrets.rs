@@ -0,0 +1,97 @@
+//! # 암호화 시크릿 파일 도구
+//!
+//! 거래소 API 키를 담은 평문 TOML을 패스프레이즈로 암호화하거나,
+//! 암호화된 파일이 올바르게 열리는지 확인한다 (키 값은 출력하지 않음).
+//!
+//! 평문 형식은 `config.toml`의 거래소 섹션과 같다:
+//!
+//! ```toml
+//! [upbit]
+//! api_key = "..."
+//! secret_key = "..."
+//!
+//! [accounts.alt.bybit]
+//! api_key = "..."
+//! secret_key = "..."
+//! ```
+//!
+//! 패스프레이즈는 `ARB_SECRETS_PASSPHRASE` 환경변수 또는 표준 입력 첫 줄에서 읽는다.
+//!
+//! ```bash
+//! cargo run --example secrets -- seal secrets.toml secrets.enc && shred -u secrets.toml
+//! cargo run --example secrets -- check secrets.enc
+//! ARB_SECRETS_FILE=secrets.enc ARB_SECRETS_PASSPHRASE_FILE=/run/secrets/arb cargo run
+//! ```
+
+use std::io::BufRead;
+
+use arb_poc::config::{EXCHANGE_SECTIONS, SecretString, open_secrets, seal_secrets};
+
+const USAGE: &str = "usage: secrets seal <plain.toml> <out.enc> | secrets check <file.enc>";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        ["seal", input, output] => {
+            let plaintext = SecretString::from(std::fs::read_to_string(input)?);
+            let passphrase = read_passphrase()?;
+            let sealed = seal_secrets(plaintext.expose_secret(), passphrase.expose_secret())?;
+            // 검증: 다시 열어서 평문과 같은지 확인
+            let reopened = open_secrets(&sealed, passphrase.expose_secret())?;
+            if reopened != plaintext {
+                return Err("round-trip verification failed".into());
+            }
+            std::fs::write(output, sealed)?;
+            println!("sealed {input} -> {output}");
+        }
+        ["check", input] => {
+            let sealed = std::fs::read_to_string(input)?;
+            let passphrase = read_passphrase()?;
+            let plaintext = open_secrets(&sealed, passphrase.expose_secret())?;
+            println!("{input}: decrypted OK");
+            for section in EXCHANGE_SECTIONS {
+                let header = format!("[{section}]");
+                let present = plaintext
+                    .expose_secret()
+                    .lines()
+                    .any(|line| line.trim() == header);
+                println!("  {section}: {}", if present { "present" } else { "-" });
+            }
+            // 계정 프로필 섹션 ([accounts.<이름>.<거래소>])
+            for line in plaintext.expose_secret().lines() {
+                if let Some(section) = line
+                    .trim()
+                    .strip_prefix("[accounts.")
+                    .and_then(|s| s.strip_suffix(']'))
+                {
+                    println!("  accounts.{section}: present");
+                }
+            }
+        }
+        _ => return Err(USAGE.into()),
+    }
+    Ok(())
+}
+
+/// 환경변수 또는 표준 입력에서 패스프레이즈를 읽는다.
+fn read_passphrase() -> Result<SecretString, Box<dyn std::error::Error>> {
+    if let Ok(passphrase) = std::env::var("ARB_SECRETS_PASSPHRASE") {
+        return Ok(SecretString::from(passphrase));
+    }
+    eprintln!("passphrase (stdin): ");
+    let mut line = String::new();
+    std::io::stdin().lock().read_line(&mut line)?;
+    let passphrase = SecretString::from(line.trim_end_matches(['\r', '\n']));
+    zeroize_string(&mut line);
+    Ok(passphrase)
+}
+
+fn zeroize_string(s: &mut String) {
+    // SecretString drop과 같은 방식으로 입력 버퍼를 지운다
+    drop(SecretString::from(std::mem::take(s)));
+}
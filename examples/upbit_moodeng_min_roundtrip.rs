@@ -75,7 +75,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("=== Upbit MOODENG 최소 왕복 주문 테스트 ===");
     println!("market={market} target_total_krw={target_total_krw}");
 
-    let client = UpbitClient::with_credentials(&config.upbit.api_key, &config.upbit.secret_key)?;
+    let client = UpbitClient::with_credentials(
+        config.upbit.api_key.expose_secret(),
+        config.upbit.secret_key.expose_secret(),
+    )?;
 
     let krw_balance = client.get_balance("KRW").await?;
     if krw_balance.balance < target_total_krw {
@@ -53,7 +53,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // 인증된 클라이언트 생성
-    let client = UpbitClient::with_credentials(&config.upbit.api_key, &config.upbit.secret_key)?;
+    let client = UpbitClient::with_credentials(
+        config.upbit.api_key.expose_secret(),
+        config.upbit.secret_key.expose_secret(),
+    )?;
 
     println!("Exchange: {}", client.name());
     println!("Authentication: Enabled\n");
@@ -12,11 +12,13 @@
 //! - [`config`]: 설정 관리 (from `arb-config`)
 //! - [`logging`]: 구조화된 로깅 시스템 (from `arb-logging`)
 //! - [`telegram`]: Telegram 알림 시스템 (from `arb-telegram`)
+//! - [`alert`]: 알림 sink (Telegram/Slack/Discord/이메일/webhook) 및 라우팅 (from `arb-alert`)
 //! - [`exchange`]: 거래소 추상화를 위한 공통 trait 및 타입 (from `arb-exchange`)
 //! - [`exchanges`]: 특정 거래소 구현체 (from `arb-exchanges`)
 //! - [`forex`]: USD/KRW 환율 캐시 (from `arb-forex`)
 //! - [`strategy`]: 차익거래 전략 구현 (from `arb-strategy`)
 //! - [`db`]: MySQL 영속화 레이어 (from `arb-db`)
+//! - [`metrics`]: Prometheus 메트릭 레지스트리 및 `/metrics` exporter (from `arb-metrics`)
 //!
 //! ## 예제
 //!
@@ -39,11 +41,13 @@
 pub mod adapter;
 
 // Re-export workspace crates
+pub use arb_alert as alert;
 pub use arb_config as config;
 pub use arb_db as db;
 pub use arb_exchange as exchange;
 pub use arb_exchanges as exchanges;
 pub use arb_forex as forex;
 pub use arb_logging as logging;
+pub use arb_metrics as metrics;
 pub use arb_strategy as strategy;
 pub use arb_telegram as telegram;
@@ -25,7 +25,32 @@
 //!
 //! `Ctrl+C` (SIGINT) 또는 `SIGTERM`으로 graceful shutdown 합니다.
 //! shutdown_policy 설정에 따라 포지션을 유지하거나 청산합니다.
+//!
+//! ## 전략 설정 핫 리로드
+//!
+//! 실행 중 `strategy.toml`을 수정하거나 `SIGHUP`을 보내면 다시 읽습니다.
+//! 진입/청산 임계값, 블랙리스트, `max_coins`, 리스크 한도 등 라이브 적용 가능한 필드만
+//! 반영되며, 그 외 필드가 바뀌었으면 변경 전체를 거부합니다. 적용 내역은
+//! `sessions.config_changes`에 기록됩니다.
+//!
+//! ## API 키 관리
+//!
+//! API 키는 `config.toml` 외에 암호화 시크릿 파일(`ARB_SECRETS_FILE`), 시크릿 마운트 경로
+//! (`UPBIT_API_KEY_FILE` 등, `fd:N` 지원), 환경 변수에서 읽을 수 있습니다.
+//! 키를 교체한 뒤 `SIGHUP`을 보내면 클라이언트를 다시 만들지 않고 새 키로 서명합니다.
+//!
+//! ## 다중 전략 인스턴스
+//!
+//! `config.toml`에 `[strategies.<이름>]`을 정의하면 인스턴스마다 별도 전략 설정,
+//! 계정 프로필(`[accounts.<이름>.bybit]` 등), 세션, 잔고 추적기, 리스크 매니저로
+//! 한 프로세스에서 함께 실행합니다. 정의가 없으면 `STRATEGY_CONFIG`(기본 `strategy.toml`)와
+//! 기본 계정으로 `default` 인스턴스 하나만 실행합니다.
+//!
+//! 같은 거래소 계정을 여러 인스턴스가 나눠 쓰면 `total_capital_usdt` 비율로 잔고를 나누며,
+//! 포지션 reconciliation이 코인 단위로 이루어지므로 코인 목록이 겹치지 않아야 하고
+//! `auto_select`를 쓸 수 없습니다. 제약 없이 분리하려면 Bybit 서브 계정을 사용하세요.
 
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -34,37 +59,59 @@ use std::time::Duration;
 use chrono::Local;
 use rust_decimal::Decimal;
 use rust_decimal::prelude::ToPrimitive;
-use tracing::{debug, error, info, warn};
+use tokio::task::JoinHandle;
+use tracing::{Instrument, debug, error, info, info_span, warn};
 
 use arb_poc::adapter::DbPositionStoreAdapter;
-use arb_poc::config::Config;
+use arb_poc::alert::AlertRouter;
+use arb_poc::config::{
+    AccountProfile, Config, DEFAULT_ACCOUNT, ExchangeConfig, StrategyInstanceConfig,
+};
 use arb_poc::db::alerts::AlertRepository;
 use arb_poc::db::balance_snapshots::BalanceSnapshotRepository;
+use arb_poc::db::execution_quality::ExecutionQualityRepository;
 use arb_poc::db::funding::FundingRepository;
 use arb_poc::db::funding::FundingScheduleRecord;
 use arb_poc::db::minutes::MinuteRepository;
 use arb_poc::db::pool::{DbPool, DbPoolConfig};
 use arb_poc::db::positions::DbPositionStore;
 use arb_poc::db::sessions::SessionRepository;
+use arb_poc::db::spill::SpillJournal;
 use arb_poc::db::trades::TradeRepository;
 use arb_poc::db::writer::{DbWriteRequest, DbWriter};
 use arb_poc::exchange::{ExchangeAdapter, MarketData, OrderManagement};
-use arb_poc::exchanges::{BybitAdapter, BybitClient, UpbitAdapter, UpbitClient};
+use arb_poc::exchanges::{BybitAdapter, BybitClient, ServerTimeSync, UpbitAdapter, UpbitClient};
 use arb_poc::forex::{ForexCache, UsdtKrwCache};
 use arb_poc::logging::{LogConfig, init_logging};
+use arb_poc::metrics::HttpServer;
 use arb_poc::strategy::zscore::alert::{
-    AlertConsumer, AlertEvent, AlertService, DbAlertFn, TelegramSendFn, TripleFailureFn,
+    AlertConsumer, AlertEvent, AlertService, DbAlertFn, TripleFailureFn,
 };
 use arb_poc::strategy::zscore::balance::BalanceTracker;
-use arb_poc::strategy::zscore::balance_recorder::BalanceRecorderTask;
+use arb_poc::strategy::zscore::balance_recorder::{BalanceRecorderTask, BalanceSnapshotSender};
 use arb_poc::strategy::zscore::config::ZScoreConfig;
 use arb_poc::strategy::zscore::live_executor::LiveExecutor;
+use arb_poc::strategy::zscore::metrics::{register_balance_tracker, register_risk_manager};
 use arb_poc::strategy::zscore::monitor::ZScoreMonitor;
 use arb_poc::strategy::zscore::monitor_live::LivePolicy;
+use arb_poc::strategy::zscore::order_watchdog::{ClientOrderIdScope, OrderWatchdog};
 use arb_poc::strategy::zscore::pnl::ClosedPosition;
+use arb_poc::strategy::zscore::reload::ConfigReloader;
 use arb_poc::strategy::zscore::risk::{RiskConfig, RiskManager};
+use arb_poc::strategy::zscore::status::StatusBoard;
 use tokio_util::sync::CancellationToken;
 
+type BoxError = Box<dyn std::error::Error>;
+
+/// `[strategies]` 정의가 없을 때의 단일 인스턴스 이름.
+const DEFAULT_INSTANCE: &str = "default";
+
+type LiveMonitor = ZScoreMonitor<
+    UpbitClient,
+    BybitClient,
+    LivePolicy<UpbitClient, BybitClient, DbPositionStoreAdapter>,
+>;
+
 /// 실행 시점의 로그 파일 경로를 계산합니다.
 ///
 /// - 우선순위: `strategy.toml`의 `[output].dir` → 기본값 `"output"`
@@ -96,42 +143,456 @@ fn resolve_live_log_file_path(strategy_config_path: &str) -> std::path::PathBuf
     dir.join(format!("live_{}.log", Local::now().format("%Y%m%d_%H%M%S")))
 }
 
-#[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let strategy_config_path =
-        std::env::var("STRATEGY_CONFIG").unwrap_or_else(|_| "strategy.toml".into());
+/// 실행할 전략 인스턴스 하나의 계획.
+struct InstanceSpec {
+    name: String,
+    config_path: String,
+    account: String,
+    strategy_config: ZScoreConfig,
+    /// 계정 잔고 중 이 인스턴스 몫 (Upbit, Bybit). 전용 계정이면 1.
+    account_share: (Decimal, Decimal),
+}
 
-    // 로깅 초기화 (stdout + 파일 동시 출력)
-    let log_file_path = resolve_live_log_file_path(&strategy_config_path);
-    let mut log_config = LogConfig::from_env();
-    log_config.console_enabled = true;
-    log_config.file_enabled = true;
-    log_config.file_path = Some(log_file_path.clone());
-    init_logging(&log_config)?;
+/// `[strategies]` 정의로 인스턴스 목록을 만들고 계정 공유 제약을 검증합니다.
+///
+/// 정의가 없으면 `default_config_path`와 기본 계정으로 단일 인스턴스를 만듭니다.
+fn plan_instances(
+    config: &Config,
+    default_config_path: &str,
+) -> Result<Vec<InstanceSpec>, BoxError> {
+    let entries: Vec<(String, StrategyInstanceConfig)> = if config.strategies.is_empty() {
+        vec![(
+            DEFAULT_INSTANCE.to_string(),
+            StrategyInstanceConfig {
+                config: default_config_path.to_string(),
+                account: DEFAULT_ACCOUNT.to_string(),
+            },
+        )]
+    } else {
+        config.strategies.clone().into_iter().collect()
+    };
 
-    info!(log_file = %log_file_path.display(), "=== arb_poc 라이브 트레이딩 시작 ===");
+    let mut specs = Vec::with_capacity(entries.len());
+    for (name, instance) in entries {
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(
+                format!("strategy instance name '{name}' must be non-empty [A-Za-z0-9_-]").into(),
+            );
+        }
+        let profile = config.account(&instance.account).ok_or_else(|| {
+            format!(
+                "[{name}] 계정 프로필 '{}'이 없습니다. config.toml의 [accounts.{}.*]를 확인하세요.",
+                instance.account, instance.account
+            )
+        })?;
+        // API 키 검증 (라이브 모드 필수)
+        if !profile.upbit.has_credentials() {
+            return Err(format!(
+                "[{name}] Upbit API 키가 필요합니다 (account={}). config.toml을 확인하세요.",
+                instance.account
+            )
+            .into());
+        }
+        if !profile.bybit.has_credentials() {
+            return Err(format!(
+                "[{name}] Bybit API 키가 필요합니다 (account={}). config.toml을 확인하세요.",
+                instance.account
+            )
+            .into());
+        }
 
-    // ---------------------------------------------------------------
-    // 1. 설정 로드 + 검증
-    // ---------------------------------------------------------------
-    let config = Config::load_or_default();
+        let mut strategy_config = if Path::new(&instance.config).exists() {
+            info!(instance = %name, path = %instance.config, "전략 설정 파일 로드");
+            let cfg = ZScoreConfig::from_file(&instance.config)?;
+            cfg.validate()?;
+            cfg
+        } else {
+            return Err(format!(
+                "[{name}] {} 파일이 필요합니다. 라이브 모드에서는 기본값 사용 불가.",
+                instance.config
+            )
+            .into());
+        };
 
-    let mut strategy_config = if Path::new(&strategy_config_path).exists() {
-        info!(path = %strategy_config_path, "전략 설정 파일 로드");
-        let cfg = ZScoreConfig::from_file(&strategy_config_path)?;
-        cfg.validate()?;
-        cfg
-    } else {
-        return Err("strategy.toml 파일이 필요합니다. 라이브 모드에서는 기본값 사용 불가.".into());
+        // 라이브 모드에서는 DB-only 출력을 강제합니다.
+        if strategy_config.output.enabled {
+            info!(instance = %name, "라이브 모드 DB-only 정책 적용: 파일 출력 비활성화");
+            strategy_config.output.enabled = false;
+        }
+
+        specs.push(InstanceSpec {
+            name,
+            config_path: instance.config,
+            account: instance.account,
+            strategy_config,
+            account_share: (Decimal::ONE, Decimal::ONE),
+        });
+    }
+
+    Ok(specs)
+}
+
+/// 같은 거래소 계정을 쓰는 인스턴스끼리 `total_capital_usdt` 비율로
+/// 잔고 몫을 나누고, 공유 계정의 코인 중복/`auto_select`를 거부합니다.
+///
+/// 계정은 API 키가 아닌 계정 식별자로 묶습니다 ([`AccountClients::account_identity`]).
+/// 같은 계정의 서로 다른 API 키도 공유로 판단됩니다.
+fn assign_account_shares(
+    specs: &mut [InstanceSpec],
+    accounts: &BTreeMap<String, AccountClients>,
+) -> Result<(), BoxError> {
+    for exchange in ["upbit", "bybit"] {
+        let mut groups: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+        for (idx, spec) in specs.iter().enumerate() {
+            let identity = accounts
+                .get(&spec.account)
+                .map(|clients| clients.account_identity(exchange))
+                .unwrap_or_default();
+            groups.entry(identity).or_default().push(idx);
+        }
+
+        for members in groups.values().filter(|m| m.len() > 1) {
+            let names: Vec<String> = members.iter().map(|&i| specs[i].name.clone()).collect();
+            let mut seen: HashMap<&str, &str> = HashMap::new();
+            for &idx in members {
+                let spec = &specs[idx];
+                if spec.strategy_config.auto_select {
+                    return Err(format!(
+                        "[{}] auto_select는 계정을 공유하는 인스턴스에서 사용할 수 없습니다 \
+                         ({exchange} 공유: {names:?}). 서브 계정을 사용하세요.",
+                        spec.name
+                    )
+                    .into());
+                }
+                for coin in &spec.strategy_config.coins {
+                    if let Some(other) = seen.insert(coin.as_str(), spec.name.as_str()) {
+                        return Err(format!(
+                            "코인 {coin}이 {exchange} 계정을 공유하는 인스턴스 {other}, {}에 중복됩니다",
+                            spec.name
+                        )
+                        .into());
+                    }
+                }
+            }
+
+            let total: Decimal = members
+                .iter()
+                .map(|&i| specs[i].strategy_config.total_capital_usdt)
+                .sum();
+            for &idx in members {
+                let share = if total > Decimal::ZERO {
+                    specs[idx].strategy_config.total_capital_usdt / total
+                } else {
+                    Decimal::ONE / Decimal::from(members.len())
+                };
+                match exchange {
+                    "upbit" => specs[idx].account_share.0 = share,
+                    _ => specs[idx].account_share.1 = share,
+                }
+            }
+            info!(exchange, instances = ?names, "거래소 계정 공유 — 자본 비율로 잔고 분할");
+        }
+    }
+    Ok(())
+}
+
+/// 계정 프로필 하나의 인증 클라이언트.
+///
+/// 같은 API 키를 쓰는 프로필은 클라이언트(rate limiter 포함)를 공유합니다.
+struct AccountClients {
+    profile: AccountProfile,
+    upbit: UpbitClient,
+    bybit: BybitClient,
+    /// 기동 시 조회한 Bybit 계정 UID (조회 실패 시 None).
+    bybit_uid: Option<String>,
+}
+
+impl AccountClients {
+    /// 잔고 공유 판단에 쓰는 거래소 계정 식별자.
+    ///
+    /// Bybit은 조회한 UID, 그 외에는 [`ExchangeConfig::account_identity`]
+    /// (설정의 `account_uid`, 없으면 API 키 지문)를 사용합니다.
+    fn account_identity(&self, exchange: &str) -> String {
+        match (exchange, &self.bybit_uid) {
+            ("bybit", Some(uid)) => format!("uid:{uid}"),
+            _ => self
+                .profile
+                .exchange(exchange)
+                .map(ExchangeConfig::account_identity)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// 인스턴스가 사용하는 계정 프로필별 클라이언트를 생성하고 Bybit 서브 계정 UID를 확인합니다.
+async fn connect_accounts(
+    config: &Config,
+    specs: &[InstanceSpec],
+) -> Result<BTreeMap<String, AccountClients>, BoxError> {
+    // 평문 키 대신 키 지문으로 클라이언트 공유
+    let mut upbit_by_key: HashMap<String, UpbitClient> = HashMap::new();
+    let mut bybit_by_key: HashMap<String, BybitClient> = HashMap::new();
+    let mut accounts = BTreeMap::new();
+
+    for spec in specs {
+        if accounts.contains_key(&spec.account) {
+            continue;
+        }
+        let profile = config
+            .account(&spec.account)
+            .ok_or_else(|| format!("account profile '{}' not found", spec.account))?;
+
+        let upbit_key = profile.upbit.api_key.fingerprint();
+        let upbit = match upbit_by_key.get(&upbit_key) {
+            Some(client) => client.clone(),
+            None => {
+                let client = UpbitClient::with_credentials(
+                    profile.upbit.api_key.expose_secret(),
+                    profile.upbit.secret_key.expose_secret(),
+                )?;
+                upbit_by_key.insert(upbit_key, client.clone());
+                client
+            }
+        };
+        let bybit_key = profile.bybit.api_key.fingerprint();
+        let bybit = match bybit_by_key.get(&bybit_key) {
+            Some(client) => client.clone(),
+            None => {
+                let client = BybitClient::with_credentials(
+                    profile.bybit.api_key.expose_secret(),
+                    profile.bybit.secret_key.expose_secret(),
+                )?
+                .with_category("linear");
+                bybit_by_key.insert(bybit_key, client.clone());
+                client
+            }
+        };
+
+        // 첫 서명 요청 전에 서버 시각 오프셋을 맞춰 둠 (실패 시 로컬 시각 사용)
+        probe_server_clock(&upbit).await;
+        probe_server_clock(&bybit).await;
+
+        // Bybit 계정 UID 조회 (계정 공유 판단 + 서브 계정 키가 의도한 계정의 것인지 확인)
+        let bybit_uid = match bybit.get_account_uid().await {
+            Ok(uid) => {
+                if !profile.bybit.account_uid.is_empty() && uid != profile.bybit.account_uid {
+                    return Err(format!(
+                        "[{}] Bybit API 키의 계정 UID({uid})가 설정({})과 다릅니다",
+                        spec.account, profile.bybit.account_uid
+                    )
+                    .into());
+                }
+                info!(account = %spec.account, uid = %uid, "Bybit 계정 UID 확인");
+                Some(uid)
+            }
+            Err(e) if !profile.bybit.account_uid.is_empty() => {
+                return Err(format!("[{}] Bybit 계정 UID 조회 실패: {e}", spec.account).into());
+            }
+            Err(e) => {
+                warn!(
+                    account = %spec.account,
+                    error = %e,
+                    "Bybit 계정 UID 조회 실패, API 키 지문으로 계정 공유 판단"
+                );
+                None
+            }
+        };
+
+        info!(
+            account = %spec.account,
+            upbit = upbit.name(),
+            bybit = bybit.name(),
+            "거래소 클라이언트 생성 완료 (인증)"
+        );
+        accounts.insert(
+            spec.account.clone(),
+            AccountClients {
+                profile,
+                upbit,
+                bybit,
+                bybit_uid,
+            },
+        );
+    }
+    Ok(accounts)
+}
+
+/// 서버 시각을 한 번 probe해 오프셋(밀리초)을 반환합니다. 실패 시 경고만 남깁니다.
+async fn probe_server_clock<C: ServerTimeSync>(client: &C) -> Option<i64> {
+    let clock = client.server_clock();
+    match client.sync_server_clock().await {
+        Ok(sample) => {
+            debug!(
+                exchange = clock.exchange(),
+                offset_ms = clock.offset_ms(),
+                rtt_ms = sample.rtt_ms,
+                "서버 시각 probe 완료"
+            );
+            Some(clock.offset_ms())
+        }
+        Err(e) => {
+            warn!(exchange = clock.exchange(), error = %e, "서버 시각 probe 실패");
+            None
+        }
+    }
+}
+
+/// 서버 시각을 probe하고 오차가 임계값을 넘으면 알림을 보냅니다.
+///
+/// 임계값을 넘는 동안에는 한 번만 알리고, 정상 범위로 돌아오면 다시 알릴 수 있게 됩니다.
+async fn check_clock_skew<C: ServerTimeSync>(
+    client: &C,
+    threshold_ms: u64,
+    skewed: &mut bool,
+    alert_service: &AlertService,
+) {
+    let Some(offset_ms) = probe_server_clock(client).await else {
+        return;
+    };
+    let exchange = client.server_clock().exchange();
+    if offset_ms.unsigned_abs() > threshold_ms {
+        if !*skewed {
+            warn!(
+                exchange = exchange,
+                offset_ms = offset_ms,
+                threshold_ms = threshold_ms,
+                "거래소 서버 시각 오차 임계값 초과"
+            );
+            alert_service.send(AlertEvent::ClockSkew {
+                exchange: exchange.to_string(),
+                offset_ms,
+                threshold_ms,
+            });
+        }
+        *skewed = true;
+    } else if *skewed {
+        info!(
+            exchange = exchange,
+            offset_ms = offset_ms,
+            "거래소 서버 시각 오차 정상 범위 복귀"
+        );
+        *skewed = false;
+    }
+}
+
+/// `SIGHUP`마다 자격 증명을 다시 읽어 바뀐 계정 프로필의 API 키를 교체합니다.
+///
+/// 로드 실패나 키 누락 시에는 기존 키를 유지합니다.
+#[cfg(unix)]
+async fn watch_credential_rotation(
+    mut accounts: Vec<(String, AccountProfile, UpbitClient, BybitClient)>,
+    cancel: CancellationToken,
+) {
+    use tokio::signal::unix::{SignalKind, signal};
+
+    let mut hangup = match signal(SignalKind::hangup()) {
+        Ok(hangup) => hangup,
+        Err(e) => {
+            warn!(error = %e, "SIGHUP 핸들러 등록 실패, API 키 로테이션 비활성화");
+            return;
+        }
     };
 
-    // 라이브 모드에서는 DB-only 출력을 강제합니다.
-    if strategy_config.output.enabled {
-        info!("라이브 모드 DB-only 정책 적용: 파일 출력 비활성화");
-        strategy_config.output.enabled = false;
+    loop {
+        tokio::select! {
+            _ = cancel.cancelled() => break,
+            _ = hangup.recv() => {}
+        }
+        let loaded = match Config::load() {
+            Ok(loaded) => loaded,
+            Err(e) => {
+                warn!(error = %e, "자격 증명 리로드 실패, 기존 API 키 유지");
+                continue;
+            }
+        };
+        for (account, current, upbit, bybit) in &mut accounts {
+            let Some(profile) = loaded.account(account) else {
+                warn!(account = %account, "리로드한 설정에 계정 프로필 없음, 기존 API 키 유지");
+                continue;
+            };
+            if rotated(&mut current.upbit, profile.upbit) {
+                upbit.set_credentials(
+                    current.upbit.api_key.expose_secret(),
+                    current.upbit.secret_key.expose_secret(),
+                );
+                info!(account = %account, exchange = "upbit", "API 키 교체 완료");
+            }
+            if rotated(&mut current.bybit, profile.bybit) {
+                bybit.set_credentials(
+                    current.bybit.api_key.expose_secret(),
+                    current.bybit.secret_key.expose_secret(),
+                );
+                info!(account = %account, exchange = "bybit", "API 키 교체 완료");
+            }
+        }
     }
+}
+
+/// 새 자격 증명이 유효하고 기존과 다르면 교체하고 true를 반환합니다.
+#[cfg(unix)]
+fn rotated(
+    current: &mut arb_poc::config::ExchangeConfig,
+    loaded: arb_poc::config::ExchangeConfig,
+) -> bool {
+    if !loaded.has_credentials() || *current == loaded {
+        return false;
+    }
+    *current = loaded;
+    true
+}
+
+/// 모든 인스턴스가 공유하는 프로세스 단위 자원.
+struct SharedContext {
+    db_pool: DbPool,
+    db_writer: DbWriter,
+    forex_cache: Arc<ForexCache>,
+    usdt_krw_cache: Arc<UsdtKrwCache>,
+    /// 설정의 알림 sink/라우팅 규칙 (인스턴스마다 복제해 사용).
+    alert_router: AlertRouter,
+    status_enabled: bool,
+    /// 인스턴스가 여럿이면 알림 메시지에 `[인스턴스]` 접두사를 붙입니다.
+    tag_alerts: bool,
+    cancel_token: CancellationToken,
+}
+
+/// 기동 준비가 끝난 전략 인스턴스.
+struct PreparedInstance {
+    name: String,
+    session_id: i64,
+    session_repo: SessionRepository,
+    risk_manager: Arc<RiskManager>,
+    balance_tracker: Arc<BalanceTracker>,
+    alert_service: AlertService,
+    alert_consumer: AlertConsumer,
+    snapshot_sender: BalanceSnapshotSender,
+    recorder_task: JoinHandle<()>,
+    funding_task: JoinHandle<()>,
+    status_board: StatusBoard,
+    monitor: LiveMonitor,
+    cancel: CancellationToken,
+}
+
+/// 인스턴스 하나를 기동 직전까지 준비합니다 (세션, 잔고, 리스크, 알림, 정책, 모니터).
+async fn prepare_instance(
+    spec: InstanceSpec,
+    clients: &AccountClients,
+    shared: &SharedContext,
+) -> Result<PreparedInstance, BoxError> {
+    let InstanceSpec {
+        name,
+        config_path,
+        account,
+        strategy_config,
+        account_share,
+    } = spec;
+    let cancel = shared.cancel_token.child_token();
 
     info!(
+        account = %account,
         coins = ?strategy_config.coins,
         window = strategy_config.window_size,
         entry_z = strategy_config.entry_z_threshold,
@@ -140,53 +601,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         "전략 설정 로드 완료"
     );
 
-    // API 키 검증 (라이브 모드 필수)
-    if !config.upbit.has_credentials() {
-        return Err("Upbit API 키가 필요합니다. config.toml을 확인하세요.".into());
-    }
-    if !config.bybit.has_credentials() {
-        return Err("Bybit API 키가 필요합니다. config.toml을 확인하세요.".into());
-    }
-
-    // ---------------------------------------------------------------
-    // 2. DB 초기화
-    // ---------------------------------------------------------------
-    if !config.database.is_configured() {
-        return Err(
-            "DB URL이 필요합니다. config.toml의 [database] url 또는 DATABASE_URL 환경변수를 설정하세요."
-                .into(),
-        );
-    }
-    let database_url = &config.database.url;
-
-    info!("DB 연결 시도...");
-    let db_pool = DbPool::connect(database_url, &DbPoolConfig::default()).await?;
-    db_pool.health_check().await?;
-    info!("DB 연결 성공");
-
-    let session_repo = SessionRepository::new(db_pool.inner().clone());
-    let position_store = DbPositionStore::new(db_pool.inner().clone());
+    let session_repo = SessionRepository::new(shared.db_pool.clone());
+    let position_store = DbPositionStore::new(shared.db_pool.clone());
 
     // ---------------------------------------------------------------
     // 3. 세션 생성 + Crash Recovery
     // ---------------------------------------------------------------
-    // ZScoreConfig는 Serialize를 구현하지 않으므로,
-    // 주요 설정값을 JSON으로 수동 직렬화
-    let config_json = serde_json::to_string(&serde_json::json!({
-        "coins": strategy_config.coins,
-        "window_size": strategy_config.window_size,
-        "entry_z_threshold": strategy_config.entry_z_threshold,
-        "exit_z_threshold": strategy_config.exit_z_threshold,
-        "total_capital_usdt": strategy_config.total_capital_usdt.to_string(),
-        "leverage": strategy_config.leverage,
-        "auto_select": strategy_config.auto_select,
-        "max_coins": strategy_config.max_coins,
-        "kill_switch_enabled": strategy_config.kill_switch_enabled,
-        "order_type": strategy_config.order_type,
-    }))?;
-
-    // 이전 Running 세션 확인 (crash recovery)
-    let parent_session_id = match session_repo.find_last_running().await {
+    // 기동 시 덮어쓴 값까지 포함한 전체 유효 설정과 해시를 기록
+    let config_snapshot = strategy_config.snapshot()?;
+    info!(config_hash = %config_snapshot.hash, "전략 설정 스냅샷");
+
+    // 같은 인스턴스의 이전 Running 세션 확인 (crash recovery)
+    let parent_session_id = match session_repo.find_last_running(&name).await {
         Ok(Some(prev)) => {
             warn!(
                 prev_session_id = prev.id,
@@ -206,7 +632,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     };
 
     let session_id = session_repo
-        .create_session(&config_json, parent_session_id)
+        .create_session(
+            &name,
+            &config_snapshot.json,
+            &config_snapshot.hash,
+            parent_session_id,
+        )
         .await?;
     info!(session_id = session_id, "새 세션 생성 완료");
 
@@ -244,17 +675,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // ---------------------------------------------------------------
-    // 4. 거래소 클라이언트 생성 (인증)
+    // 4. 거래소 클라이언트 (계정 프로필 공유)
     // ---------------------------------------------------------------
-    let upbit = UpbitClient::with_credentials(&config.upbit.api_key, &config.upbit.secret_key)?;
-    let bybit = BybitClient::with_credentials(&config.bybit.api_key, &config.bybit.secret_key)?
-        .with_category("linear");
-
-    info!(
-        upbit = upbit.name(),
-        bybit = bybit.name(),
-        "거래소 클라이언트 생성 완료 (인증)"
-    );
+    // Clone 구현은 Arc 기반이므로 커넥션 풀, rate limiter, 자격 증명 슬롯을 공유합니다.
+    let upbit = clients.upbit.clone();
+    let bybit = clients.bybit.clone();
 
     // ---------------------------------------------------------------
     // 4-1. Bybit leverage/margin mode 검증
@@ -316,98 +741,53 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     };
 
-    let balance_tracker = Arc::new(BalanceTracker::new(upbit_krw_balance, bybit_usdt_balance));
+    let balance_tracker = Arc::new(if account_share == (Decimal::ONE, Decimal::ONE) {
+        BalanceTracker::new(upbit_krw_balance, bybit_usdt_balance)
+    } else {
+        BalanceTracker::new_shared(upbit_krw_balance, bybit_usdt_balance, account_share)
+    });
 
+    let (upbit_available, bybit_available) = balance_tracker.available();
     info!(
-        upbit_krw = %upbit_krw_balance,
-        bybit_usdt = %bybit_usdt_balance,
+        upbit_krw = %upbit_available,
+        bybit_usdt = %bybit_available,
+        upbit_share = %account_share.0,
+        bybit_share = %account_share.1,
         "BalanceTracker 초기화 완료"
     );
 
     // ---------------------------------------------------------------
     // 6. RiskManager 초기화
     // ---------------------------------------------------------------
-    let risk_config = RiskConfig {
-        max_daily_loss_pct: strategy_config.max_daily_loss_pct,
-        max_daily_loss_usdt: Decimal::try_from(strategy_config.max_daily_loss_usdt)
-            .unwrap_or(Decimal::from(50)),
-        max_drawdown_pct: strategy_config.max_drawdown_pct,
-        max_drawdown_usdt: Decimal::try_from(strategy_config.max_drawdown_usdt)
-            .unwrap_or(Decimal::from(25)),
-        max_single_loss_pct: strategy_config.max_single_loss_pct,
-        max_single_loss_usdt: Decimal::try_from(strategy_config.max_single_loss_usdt)
-            .unwrap_or(Decimal::from(15)),
-        max_order_size_usdt: Decimal::try_from(strategy_config.max_order_size_usdt)
-            .unwrap_or(Decimal::from(2000)),
-        max_concurrent_positions: strategy_config
-            .max_concurrent_positions
-            .unwrap_or(strategy_config.coins.len()),
-        max_rolling_24h_loss_usdt: Decimal::try_from(strategy_config.max_rolling_24h_loss_usdt)
-            .unwrap_or(Decimal::from(80)),
-        total_capital_usdt: strategy_config.total_capital_usdt,
-        kill_switch_enabled: strategy_config.kill_switch_enabled,
-        ..RiskConfig::default()
-    };
+    let risk_config = RiskConfig::from_strategy_config(&strategy_config);
 
     let risk_manager = Arc::new(RiskManager::new(risk_config));
     info!("RiskManager 초기화 완료");
 
     let strategy_config_arc = Arc::new(strategy_config);
-    let cancel_token = CancellationToken::new();
-
-    // ---------------------------------------------------------------
-    // 7. DbWriter + BalanceRecorderTask 생성
-    // ---------------------------------------------------------------
-
-    // DbWriter 생성 (background DB write 파이프라인)
-    let db_writer_session_repo = SessionRepository::new(db_pool.inner().clone());
-    let db_writer_position_store = DbPositionStore::new(db_pool.inner().clone());
-    let db_writer_trade_repo = TradeRepository::new(db_pool.inner().clone());
-    let db_writer_minute_repo = MinuteRepository::new(db_pool.inner().clone());
-    let db_writer_alert_repo = AlertRepository::new(db_pool.inner().clone());
-    let db_writer_funding_repo = FundingRepository::new(db_pool.inner().clone());
-    let db_writer_balance_repo = BalanceSnapshotRepository::new(db_pool.inner().clone());
-
-    let db_writer = DbWriter::new(
-        db_writer_session_repo,
-        db_writer_position_store,
-        db_writer_trade_repo,
-        db_writer_minute_repo,
-        db_writer_alert_repo,
-        db_writer_funding_repo,
-        db_writer_balance_repo,
-    );
-    info!("DbWriter 생성 완료");
 
     // ---------------------------------------------------------------
-    // 7-1. AlertService 생성 (DB always-write + Telegram best-effort)
+    // 7. AlertService 생성 (DB always-write + sink 라우팅 best-effort)
     // ---------------------------------------------------------------
-    let telegram_send_fn: TelegramSendFn =
-        if strategy_config_arc.telegram_enabled && config.telegram.is_configured() {
-            info!("텔레그램 알림 활성화");
-            let telegram_client = Arc::new(
-                arb_poc::telegram::TelegramClient::new(&config.telegram)
-                    .map_err(|e| format!("Telegram 클라이언트 생성 실패: {e}"))?,
-            );
-            let tg = Arc::clone(&telegram_client);
-            Box::new(move |msg: String| {
-                let tg = Arc::clone(&tg);
-                Box::pin(async move {
-                    tg.send_message(&msg).await.map_err(|e| e.to_string())?;
-                    Ok(())
-                })
-            })
-        } else if !strategy_config_arc.telegram_enabled {
-            info!("strategy.telegram_enabled=false — DB 전용 알림 모드");
-            Box::new(|_msg: String| Box::pin(async { Ok(()) }))
-        } else {
-            info!("텔레그램 미설정 — DB 전용 알림 모드");
-            Box::new(|_msg: String| Box::pin(async { Ok(()) }))
-        };
+    let mut alert_router = if strategy_config_arc.telegram_enabled {
+        shared.alert_router.clone()
+    } else {
+        info!("strategy.telegram_enabled=false — 텔레그램 sink 제외");
+        shared.alert_router.without_kind("telegram")
+    };
+    if shared.tag_alerts {
+        alert_router = alert_router.with_source(name.as_str());
+    }
+    if alert_router.is_empty() {
+        info!("알림 sink 없음 — DB 전용 알림 모드");
+    } else {
+        info!(sinks = ?alert_router.sink_names(), "알림 sink 활성화");
+    }
 
     let alert_trip_once = Arc::new(AtomicBool::new(false));
     let alert_trip_risk = Arc::clone(&risk_manager);
-    let alert_trip_cancel = cancel_token.clone();
+    // DB + 텔레그램 동시 장애는 프로세스 전체 문제이므로 모든 인스턴스를 중단
+    let alert_trip_cancel = shared.cancel_token.clone();
     let alert_trip_once_ref = Arc::clone(&alert_trip_once);
     let triple_failure_fn: TripleFailureFn = Box::new(
         move |sid: i64, level: String, event_type: String, message: String| {
@@ -435,7 +815,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         },
     );
 
-    let alert_db_writer = db_writer.clone();
+    // alerts 행은 session_id로 인스턴스(sessions.instance_name)에 연결됩니다.
+    let alert_db_writer = shared.db_writer.clone();
     let db_alert_fn: DbAlertFn = Box::new(
         move |sid: i64, level: &str, event_type: &str, message: &str, payload: Option<String>| {
             let writer = alert_db_writer.clone();
@@ -456,34 +837,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     );
 
     let (alert_service, alert_consumer): (AlertService, AlertConsumer) =
-        AlertService::new_with_triple_failure(
-            session_id,
-            telegram_send_fn,
-            db_alert_fn,
-            triple_failure_fn,
-        );
-    info!("AlertService 생성 완료 (DB always-write + Telegram best-effort + triple failure)");
-
-    // ForexCache 생성 (USD/KRW 공시 환율)
-    let forex_cache = Arc::new(ForexCache::new(Duration::from_secs(600)));
-
-    // UsdtKrwCache 생성 (USDT/KRW 거래소 시세)
-    let usdt_krw_cache = Arc::new(UsdtKrwCache::new());
-
-    // Upbit REST로 USDT/KRW 초기값 조회
-    match upbit.get_ticker(&["KRW-USDT"]).await {
-        Ok(tickers) if !tickers.is_empty() => {
-            let price = tickers[0].trade_price;
-            if let Some(price_f64) = price.to_f64() {
-                usdt_krw_cache.update(price_f64);
-                info!(usdt_krw = price_f64, "USDT/KRW 초기값 설정");
-            } else {
-                warn!(price = %price, "USDT/KRW Decimal->f64 변환 실패");
-            }
-        }
-        Ok(_) => warn!("USDT/KRW ticker 응답 비어있음"),
-        Err(e) => warn!(error = %e, "USDT/KRW 초기값 조회 실패"),
-    }
+        AlertService::with_router(session_id, alert_router, db_alert_fn, triple_failure_fn);
+    info!("AlertService 생성 완료 (DB always-write + sink best-effort + triple failure)");
 
     // ExchangeAdapter 생성 (잔고 조회용)
     let upbit_adapter: Arc<dyn ExchangeAdapter> = Arc::new(UpbitAdapter::new(upbit.clone()));
@@ -495,9 +850,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         session_id,
         upbit_adapter,
         bybit_adapter,
-        Arc::clone(&forex_cache),
-        Arc::clone(&usdt_krw_cache),
-        db_writer.clone(),
+        Arc::clone(&shared.forex_cache),
+        Arc::clone(&shared.usdt_krw_cache),
+        shared.db_writer.clone(),
         snapshot_interval,
     );
     info!(interval_sec = snapshot_interval, "BalanceRecorderTask 시작");
@@ -505,30 +860,43 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // ---------------------------------------------------------------
     // 8. LiveExecutor + LivePolicy 생성
     // ---------------------------------------------------------------
-    // 클라이언트를 clone하여 LiveExecutor용과 ZScoreMonitor용으로 분리.
-    // Clone 구현은 Arc 기반이므로 커넥션 풀과 rate limiter를 공유합니다.
-    let upbit_for_executor = upbit.clone();
-    let bybit_for_executor = bybit.clone();
-
     let executor = Arc::new(LiveExecutor::new(
-        Arc::new(upbit_for_executor),
-        Arc::new(bybit_for_executor),
+        Arc::new(upbit.clone()),
+        Arc::new(bybit.clone()),
         Arc::clone(&strategy_config_arc),
     ));
 
     let adapter = DbPositionStoreAdapter::new(position_store);
     let position_store_arc = Arc::new(adapter);
 
+    // client order ID에 인스턴스/세션 태그를 붙여, 같은 계정을 공유하는 인스턴스의
+    // watchdog가 서로의 주문을 취소하지 않게 함
+    let client_order_ids = ClientOrderIdScope::new(&name, session_id);
+
+    // Upbit 미체결 주문 watchdog (heartbeat는 LivePolicy가 분봉 마감마다 갱신)
+    // 재연결/주기 점검은 실행 엔진이 대기·재시도 중인 주문을 건드리지 않도록
+    // 체결 대기 타임아웃의 3배 이상 지난 주문만 취소
+    let upbit_watchdog = OrderWatchdog::new(
+        "upbit",
+        Arc::new(clients.upbit.clone()),
+        client_order_ids.clone(),
+        Duration::from_secs(strategy_config_arc.order_watchdog_interval_sec),
+        Duration::from_secs(strategy_config_arc.order_watchdog_heartbeat_timeout_sec),
+        Duration::from_secs(strategy_config_arc.order_timeout_sec * 3),
+    );
+
     let policy = LivePolicy::new(
         executor,
         Arc::clone(&balance_tracker),
         Arc::clone(&risk_manager),
         Arc::clone(&position_store_arc),
         session_id,
-        Some(db_writer.clone()),
+        Some(shared.db_writer.clone()),
         Some(alert_service.clone()),
         Some(snapshot_sender.clone()),
-    );
+    )
+    .with_watchdog_heartbeat(upbit_watchdog.heartbeat_handle())
+    .with_client_order_ids(client_order_ids.clone());
 
     info!(session_id = session_id, "LivePolicy 생성 완료");
 
@@ -537,188 +905,269 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // ---------------------------------------------------------------
     let config_for_monitor = (*strategy_config_arc).clone();
     let bybit_for_funding = bybit.clone();
-    let upbit_for_usdt_krw = upbit.clone();
-
-    let monitor = ZScoreMonitor::new(upbit, bybit, config_for_monitor, forex_cache, policy);
-
-    // ---------------------------------------------------------------
-    // 10. Graceful Shutdown 핸들러
-    // ---------------------------------------------------------------
-    let cancel_clone = cancel_token.clone();
-    tokio::spawn(async move {
-        // SIGINT (Ctrl+C)
-        tokio::signal::ctrl_c().await.ok();
-        info!("SIGINT 수신 — graceful shutdown 시작...");
-        cancel_clone.cancel();
-    });
 
-    // SIGTERM 핸들러 (Unix only)
-    #[cfg(unix)]
-    {
-        let cancel_term = cancel_token.clone();
-        tokio::spawn(async move {
-            let mut sigterm =
-                tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
-                    .expect("SIGTERM 핸들러 등록 실패");
-            sigterm.recv().await;
-            info!("SIGTERM 수신 — graceful shutdown 시작...");
-            cancel_term.cancel();
-        });
+    // 상태 API 스냅샷 소스 (모니터 내부 상태는 run() 시작 시 바인딩)
+    let status_board = StatusBoard::new()
+        .with_balance(Arc::clone(&balance_tracker))
+        .with_risk(Arc::clone(&risk_manager))
+        .with_forex(
+            Arc::clone(&shared.forex_cache),
+            Arc::clone(&shared.usdt_krw_cache),
+        )
+        .with_alerts(alert_service.recent_alerts());
+
+    // 전략 설정 핫 리로드 (파일 변경 / SIGHUP)
+    let (config_reloader, config_updates) =
+        ConfigReloader::new(&config_path, Arc::clone(&strategy_config_arc))?;
+    let config_reloader = config_reloader
+        .with_risk_manager(Arc::clone(&risk_manager))
+        .with_db_writer(shared.db_writer.clone(), session_id);
+
+    let mut monitor = ZScoreMonitor::new(
+        upbit,
+        bybit,
+        config_for_monitor,
+        Arc::clone(&shared.forex_cache),
+        policy,
+    )
+    .with_instance_name(name.clone())
+    .with_config_updates(config_updates);
+    if shared.status_enabled {
+        monitor = monitor.with_status_board(status_board.clone());
     }
 
     // ---------------------------------------------------------------
-    // 10-0. USDT/KRW 캐시 주기 갱신 task
-    // ---------------------------------------------------------------
-    let cancel_usdt_krw = cancel_token.clone();
-    let usdt_cache_for_task = Arc::clone(&usdt_krw_cache);
-    let usdt_krw_task = tokio::spawn(async move {
-        let mut interval = tokio::time::interval(Duration::from_secs(30));
-        loop {
-            tokio::select! {
-                _ = cancel_usdt_krw.cancelled() => break,
-                _ = interval.tick() => {
-                    match upbit_for_usdt_krw.get_ticker(&["KRW-USDT"]).await {
-                        Ok(tickers) => {
-                            if let Some(ticker) = tickers.first()
-                                && let Some(price_f64) = ticker.trade_price.to_f64()
-                            {
-                                usdt_cache_for_task.update(price_f64);
-                            }
-                        }
-                        Err(e) => {
-                            warn!(error = %e, "USDT/KRW 주기 갱신 실패");
-                        }
-                    }
-                }
-            }
-        }
-    });
-
-    // ---------------------------------------------------------------
-    // 10-1. funding_schedules 갱신 task
+    // 10. funding_schedules 갱신 task
     // ---------------------------------------------------------------
-    let cancel_funding = cancel_token.clone();
-    let funding_writer = db_writer.clone();
+    let cancel_funding = cancel.clone();
+    let funding_writer = shared.db_writer.clone();
     let funding_coins = strategy_config_arc.coins.clone();
     let funding_auto_select = strategy_config_arc.auto_select;
     let funding_symbols: Vec<String> = funding_coins
         .iter()
         .map(|coin| format!("{coin}USDT"))
         .collect();
-    let funding_task = tokio::spawn(async move {
-        let mut interval = tokio::time::interval(Duration::from_secs(60));
-        let mut warned_missing_symbols = false;
-        loop {
-            tokio::select! {
-                _ = cancel_funding.cancelled() => break,
-                _ = interval.tick() => {
-                    if funding_symbols.is_empty() {
-                        if !warned_missing_symbols {
-                            warned_missing_symbols = true;
-                            warn!(
-                                auto_select = funding_auto_select,
-                                "funding 코인 목록이 비어 있어 funding_schedules 갱신을 건너뜁니다"
-                            );
+    let funding_task = tokio::spawn(
+        async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(60));
+            let mut warned_missing_symbols = false;
+            loop {
+                tokio::select! {
+                    _ = cancel_funding.cancelled() => break,
+                    _ = interval.tick() => {
+                        if funding_symbols.is_empty() {
+                            if !warned_missing_symbols {
+                                warned_missing_symbols = true;
+                                warn!(
+                                    auto_select = funding_auto_select,
+                                    "funding 코인 목록이 비어 있어 funding_schedules 갱신을 건너뜁니다"
+                                );
+                            }
+                            continue;
                         }
-                        continue;
-                    }
 
-                    for symbol in &funding_symbols {
-                        match bybit_for_funding.get_tickers_linear(Some(symbol.as_str())).await {
-                            Ok(tickers) => {
-                                let Some(ticker) = tickers.into_iter().next() else {
-                                    continue;
-                                };
-                                let Some(coin) = ticker.symbol.strip_suffix("USDT") else {
-                                    continue;
-                                };
-
-                                if ticker.next_funding_time <= 0 {
-                                    warn!(
-                                        symbol = ticker.symbol.as_str(),
-                                        next_funding_time = ticker.next_funding_time,
-                                        "next_funding_time 비정상, funding upsert 스킵"
-                                    );
-                                    continue;
+                        for symbol in &funding_symbols {
+                            match bybit_for_funding.get_tickers_linear(Some(symbol.as_str())).await {
+                                Ok(tickers) => {
+                                    let Some(ticker) = tickers.into_iter().next() else {
+                                        continue;
+                                    };
+                                    let Some(coin) = ticker.symbol.strip_suffix("USDT") else {
+                                        continue;
+                                    };
+
+                                    if ticker.next_funding_time <= 0 {
+                                        warn!(
+                                            symbol = ticker.symbol.as_str(),
+                                            next_funding_time = ticker.next_funding_time,
+                                            "next_funding_time 비정상, funding upsert 스킵"
+                                        );
+                                        continue;
+                                    }
+
+                                    let Some(next_funding_time) =
+                                        chrono::DateTime::<chrono::Utc>::from_timestamp_millis(
+                                            ticker.next_funding_time,
+                                        )
+                                    else {
+                                        warn!(
+                                            symbol = ticker.symbol.as_str(),
+                                            next_funding_time = ticker.next_funding_time,
+                                            "next_funding_time 파싱 실패, funding upsert 스킵"
+                                        );
+                                        continue;
+                                    };
+
+                                    if ticker.funding_rate == 0.0 {
+                                        debug!(
+                                            symbol = ticker.symbol.as_str(),
+                                            "funding_rate=0.0 (누락 fallback 가능)"
+                                        );
+                                    }
+
+                                    funding_writer.send(DbWriteRequest::UpsertFunding(
+                                        FundingScheduleRecord {
+                                            id: None,
+                                            coin: coin.to_string(),
+                                            interval_hours: 8,
+                                            next_funding_time,
+                                            current_rate: ticker.funding_rate,
+                                        },
+                                    ));
                                 }
-
-                                let Some(next_funding_time) =
-                                    chrono::DateTime::<chrono::Utc>::from_timestamp_millis(
-                                        ticker.next_funding_time,
-                                    )
-                                else {
-                                    warn!(
-                                        symbol = ticker.symbol.as_str(),
-                                        next_funding_time = ticker.next_funding_time,
-                                        "next_funding_time 파싱 실패, funding upsert 스킵"
-                                    );
-                                    continue;
-                                };
-
-                                if ticker.funding_rate == 0.0 {
-                                    debug!(
-                                        symbol = ticker.symbol.as_str(),
-                                        "funding_rate=0.0 (누락 fallback 가능)"
-                                    );
+                                Err(e) => {
+                                    warn!(error = %e, symbol = symbol.as_str(), "Bybit funding ticker 조회 실패");
                                 }
-
-                                funding_writer.send(DbWriteRequest::UpsertFunding(
-                                    FundingScheduleRecord {
-                                        id: None,
-                                        coin: coin.to_string(),
-                                        interval_hours: 8,
-                                        next_funding_time,
-                                        current_rate: ticker.funding_rate,
-                                    },
-                                ));
-                            }
-                            Err(e) => {
-                                warn!(error = %e, symbol = symbol.as_str(), "Bybit funding ticker 조회 실패");
                             }
                         }
                     }
                 }
             }
         }
-    });
+        .in_current_span(),
+    );
+
+    // ---------------------------------------------------------------
+    // 10-1. 서버 시각 동기화 + 시계 오차 알림
+    // ---------------------------------------------------------------
+    let clock_upbit = clients.upbit.clone();
+    let clock_bybit = clients.bybit.clone();
+    let clock_alerts = alert_service.clone();
+    let clock_interval = Duration::from_secs(strategy_config_arc.clock_sync_interval_sec);
+    let skew_threshold_ms = strategy_config_arc.clock_skew_alert_ms;
+    let cancel_clock = cancel.clone();
+    tokio::spawn(
+        async move {
+            let mut interval = tokio::time::interval(clock_interval);
+            let mut upbit_skewed = false;
+            let mut bybit_skewed = false;
+            loop {
+                tokio::select! {
+                    _ = cancel_clock.cancelled() => break,
+                    _ = interval.tick() => {
+                        check_clock_skew(&clock_upbit, skew_threshold_ms, &mut upbit_skewed, &clock_alerts).await;
+                        check_clock_skew(&clock_bybit, skew_threshold_ms, &mut bybit_skewed, &clock_alerts).await;
+                    }
+                }
+            }
+        }
+        .in_current_span(),
+    );
+
+    // ---------------------------------------------------------------
+    // 10-2. 미체결 주문 보호 (Upbit watchdog, Bybit DCP)
+    // ---------------------------------------------------------------
+    // 점검 대상은 이 인스턴스 접두사의 주문뿐 (이전 세션 잔여 주문 포함, 다른 인스턴스 제외)
+    tokio::spawn(upbit_watchdog.run(cancel.clone()).in_current_span());
+
+    // Bybit은 DCP가 이전 세션 크래시를 덮지 못했을 수 있으므로 시작 시 한 번 정리
+    let bybit_startup_sweep = OrderWatchdog::new(
+        "bybit",
+        Arc::new(clients.bybit.clone()),
+        client_order_ids,
+        Duration::from_secs(strategy_config_arc.order_watchdog_interval_sec),
+        Duration::from_secs(strategy_config_arc.order_watchdog_heartbeat_timeout_sec),
+        Duration::ZERO,
+    );
+    match bybit_startup_sweep.sweep(Duration::ZERO, "startup").await {
+        Ok(cancelled) => info!(cancelled, "Bybit 시작 시 미체결 전략 주문 정리 완료"),
+        Err(e) => warn!(error = %e, "Bybit 시작 시 미체결 주문 점검 실패"),
+    }
+
+    let dcp_window_sec = strategy_config_arc.bybit_dcp_window_sec;
+    if dcp_window_sec > 0 {
+        let dcp_bybit = clients.bybit.clone();
+        let cancel_dcp = cancel.clone();
+        tokio::spawn(
+            async move {
+                dcp_bybit
+                    .run_dcp_keepalive(dcp_window_sec, cancel_dcp.cancelled())
+                    .await;
+            }
+            .in_current_span(),
+        );
+    } else {
+        warn!("Bybit DCP 비활성화 (bybit_dcp_window_sec = 0)");
+    }
 
     // ---------------------------------------------------------------
     // 11. 세션 heartbeat task
     // ---------------------------------------------------------------
-    let session_repo_hb = SessionRepository::new(db_pool.inner().clone());
+    let session_repo_hb = SessionRepository::new(shared.db_pool.clone());
     let session_id_hb = session_id;
-    let cancel_hb = cancel_token.clone();
-    tokio::spawn(async move {
-        let mut interval = tokio::time::interval(Duration::from_secs(60));
-        loop {
-            tokio::select! {
-                _ = cancel_hb.cancelled() => break,
-                _ = interval.tick() => {
-                    if let Err(e) = session_repo_hb.update_heartbeat(session_id_hb).await {
-                        warn!(error = %e, "heartbeat 갱신 실패");
+    let cancel_hb = cancel.clone();
+    tokio::spawn(
+        async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(60));
+            loop {
+                tokio::select! {
+                    _ = cancel_hb.cancelled() => break,
+                    _ = interval.tick() => {
+                        if let Err(e) = session_repo_hb.update_heartbeat(session_id_hb).await {
+                            warn!(error = %e, "heartbeat 갱신 실패");
+                        }
                     }
                 }
             }
         }
-    });
+        .in_current_span(),
+    );
+
+    // ---------------------------------------------------------------
+    // 11-1. 전략 설정 핫 리로드 감시
+    // ---------------------------------------------------------------
+    tokio::spawn(config_reloader.run(cancel.clone()).in_current_span());
+
+    Ok(PreparedInstance {
+        name,
+        session_id,
+        session_repo,
+        risk_manager,
+        balance_tracker,
+        alert_service,
+        alert_consumer,
+        snapshot_sender,
+        recorder_task,
+        funding_task,
+        status_board,
+        monitor,
+        cancel,
+    })
+}
+
+/// 인스턴스 모니터링을 실행하고 세션을 정리합니다. 모니터 에러 시 false를 반환합니다.
+async fn run_instance(instance: PreparedInstance) -> bool {
+    let PreparedInstance {
+        name,
+        session_id,
+        session_repo,
+        risk_manager,
+        balance_tracker: _,
+        alert_service,
+        alert_consumer,
+        snapshot_sender,
+        recorder_task,
+        funding_task,
+        status_board: _,
+        monitor,
+        cancel,
+    } = instance;
 
     // ---------------------------------------------------------------
     // 12. 모니터링 실행
     // ---------------------------------------------------------------
     info!("=== 실시간 모니터링 시작 (라이브 모드) ===");
 
-    let trades: Vec<ClosedPosition> = match monitor.run(cancel_token.clone()).await {
-        Ok(t) => t,
+    let result = monitor.run(cancel.clone()).await;
+    // 인스턴스 단위 task(funding, heartbeat, 핫 리로드) 종료
+    cancel.cancel();
+    let (trades, failed): (Vec<ClosedPosition>, bool) = match result {
+        Ok(t) => (t, false),
         Err(e) => {
             error!(error = %e, "모니터링 실행 실패");
-            cancel_token.cancel();
-            // 세션 상태 Errored로 업데이트
-            let _ = session_repo.end_session(session_id, "Errored").await;
-            let err: Box<dyn std::error::Error> = Box::new(e);
-            return Err(err);
+            (Vec::new(), true)
         }
     };
-    cancel_token.cancel();
 
     // ---------------------------------------------------------------
     // 13. BalanceRecorderTask 종료
@@ -739,17 +1188,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Err(_) => warn!("funding_schedules task 종료 타임아웃 (10초)"),
     }
 
-    // USDT/KRW 주기 갱신 task 종료 대기
-    match tokio::time::timeout(Duration::from_secs(10), usdt_krw_task).await {
-        Ok(Ok(())) => info!("usdt_krw refresh task 정상 종료"),
-        Ok(Err(e)) => warn!(error = %e, "usdt_krw refresh task 종료 에러"),
-        Err(_) => warn!("usdt_krw refresh task 종료 타임아웃 (10초)"),
-    }
-
     // ---------------------------------------------------------------
     // 14. 세션 종료
     // ---------------------------------------------------------------
-    let session_status = if risk_manager.is_killed() {
+    let session_status = if failed {
+        "Errored"
+    } else if risk_manager.is_killed() {
         "KillSwitched"
     } else {
         "GracefulStop"
@@ -786,7 +1230,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // AlertService sender를 모두 drop해야 consumer가 종료됩니다.
-    // - main scope의 alert_service
+    // - 이 함수가 보유한 alert_service
     // - monitor 내부 policy가 보유한 alert_service clone
     drop(monitor);
     drop(alert_service);
@@ -795,7 +1239,347 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("AlertService consumer 종료 대기...");
     alert_consumer.shutdown().await;
 
+    info!(
+        instance = %name,
+        session_id = session_id,
+        status = session_status,
+        "=== 인스턴스 종료 ==="
+    );
+    !failed
+}
+
+#[tokio::main]
+async fn main() -> Result<(), BoxError> {
+    let strategy_config_path =
+        std::env::var("STRATEGY_CONFIG").unwrap_or_else(|_| "strategy.toml".into());
+
+    // 로깅 초기화 (stdout + 파일 동시 출력)
+    let log_file_path = resolve_live_log_file_path(&strategy_config_path);
+    let mut log_config = LogConfig::from_env();
+    log_config.console_enabled = true;
+    log_config.file_enabled = true;
+    log_config.file_path = Some(log_file_path.clone());
+    init_logging(&log_config)?;
+
+    info!(log_file = %log_file_path.display(), "=== arb_poc 라이브 트레이딩 시작 ===");
+
+    // ---------------------------------------------------------------
+    // 1. 설정 로드 + 검증 (인스턴스별 전략 설정, 계정 공유 제약)
+    // ---------------------------------------------------------------
+    let config = Config::load()?;
+    let mut specs = plan_instances(&config, &strategy_config_path)?;
+    info!(
+        instances = ?specs.iter().map(|s| s.name.as_str()).collect::<Vec<_>>(),
+        "전략 인스턴스 구성"
+    );
+
+    // ---------------------------------------------------------------
+    // 2. DB 초기화 + DbWriter (인스턴스 공유)
+    // ---------------------------------------------------------------
+    if !config.database.is_configured() {
+        return Err(
+            "DB URL이 필요합니다. config.toml의 [database] url 또는 DATABASE_URL 환경변수를 설정하세요."
+                .into(),
+        );
+    }
+    let database_url = &config.database.url;
+
+    info!("DB 연결 시도...");
+    let db_pool = DbPool::connect(database_url, &DbPoolConfig::default()).await?;
+    db_pool.health_check().await?;
+    info!("DB 연결 성공");
+
+    // DbWriter 생성 (background DB write 파이프라인, DB 장애 시 spill journal에 보관)
+    let spill_journal = SpillJournal::open(config.database.spill_dir_or_default())?;
+    info!(
+        dir = %spill_journal.dir().display(),
+        pending = spill_journal.pending_len(),
+        "DB spill journal 열기"
+    );
+    let db_writer = DbWriter::with_spill_journal(
+        SessionRepository::new(db_pool.clone()),
+        DbPositionStore::new(db_pool.clone()),
+        TradeRepository::new(db_pool.clone()),
+        MinuteRepository::new(db_pool.clone()),
+        AlertRepository::new(db_pool.clone()),
+        FundingRepository::new(db_pool.clone()),
+        BalanceSnapshotRepository::new(db_pool.clone()),
+        ExecutionQualityRepository::new(db_pool.clone()),
+        spill_journal,
+    );
+    info!("DbWriter 생성 완료");
+
+    // ---------------------------------------------------------------
+    // 3. 계정 프로필별 거래소 클라이언트 생성 (인증)
+    // ---------------------------------------------------------------
+    let accounts = connect_accounts(&config, &specs).await?;
+    assign_account_shares(&mut specs, &accounts)?;
+
+    // ---------------------------------------------------------------
+    // 4. 환율 캐시 (인스턴스 공유)
+    // ---------------------------------------------------------------
+    // ForexCache 생성 (USD/KRW 공시 환율)
+    let forex_cache = Arc::new(ForexCache::new(Duration::from_secs(600)));
+
+    // UsdtKrwCache 생성 (USDT/KRW 거래소 시세)
+    let usdt_krw_cache = Arc::new(UsdtKrwCache::new());
+
+    let upbit_for_usdt_krw = accounts
+        .values()
+        .next()
+        .map(|clients| clients.upbit.clone())
+        .ok_or("no strategy instance configured")?;
+
+    // Upbit REST로 USDT/KRW 초기값 조회
+    match upbit_for_usdt_krw.get_ticker(&["KRW-USDT"]).await {
+        Ok(tickers) if !tickers.is_empty() => {
+            let price = tickers[0].trade_price;
+            if let Some(price_f64) = price.to_f64() {
+                usdt_krw_cache.update(price_f64);
+                info!(usdt_krw = price_f64, "USDT/KRW 초기값 설정");
+            } else {
+                warn!(price = %price, "USDT/KRW Decimal->f64 변환 실패");
+            }
+        }
+        Ok(_) => warn!("USDT/KRW ticker 응답 비어있음"),
+        Err(e) => warn!(error = %e, "USDT/KRW 초기값 조회 실패"),
+    }
+
+    let alert_router = AlertRouter::from_config(&config.alerts, &config.telegram)
+        .map_err(|e| format!("알림 sink 설정 오류: {e}"))?;
+
+    let cancel_token = CancellationToken::new();
+    let shared = SharedContext {
+        db_pool: db_pool.clone(),
+        db_writer: db_writer.clone(),
+        forex_cache: Arc::clone(&forex_cache),
+        usdt_krw_cache: Arc::clone(&usdt_krw_cache),
+        alert_router,
+        status_enabled: config.status.is_enabled(),
+        tag_alerts: specs.len() > 1,
+        cancel_token: cancel_token.clone(),
+    };
+
+    // ---------------------------------------------------------------
+    // 5. 인스턴스별 세션/잔고/리스크/알림/정책/모니터 준비
+    // ---------------------------------------------------------------
+    let mut instances = Vec::with_capacity(specs.len());
+    for spec in specs {
+        let span = info_span!("instance", name = %spec.name);
+        let clients = accounts
+            .get(&spec.account)
+            .ok_or_else(|| format!("account '{}' not connected", spec.account))?;
+        let prepared = match prepare_instance(spec, clients, &shared)
+            .instrument(span.clone())
+            .await
+        {
+            Ok(prepared) => prepared,
+            Err(e) => {
+                // 이미 준비된 인스턴스의 task를 정리하고 종료
+                cancel_token.cancel();
+                return Err(e);
+            }
+        };
+        instances.push((span, prepared));
+    }
+
+    // ---------------------------------------------------------------
+    // 10. Graceful Shutdown 핸들러
+    // ---------------------------------------------------------------
+    let cancel_clone = cancel_token.clone();
+    tokio::spawn(async move {
+        // SIGINT (Ctrl+C)
+        tokio::signal::ctrl_c().await.ok();
+        info!("SIGINT 수신 — graceful shutdown 시작...");
+        cancel_clone.cancel();
+    });
+
+    // SIGTERM 핸들러 (Unix only)
+    #[cfg(unix)]
+    {
+        let cancel_term = cancel_token.clone();
+        tokio::spawn(async move {
+            let mut sigterm =
+                tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+                    .expect("SIGTERM 핸들러 등록 실패");
+            sigterm.recv().await;
+            info!("SIGTERM 수신 — graceful shutdown 시작...");
+            cancel_term.cancel();
+        });
+    }
+
+    // ---------------------------------------------------------------
+    // 10-0. USDT/KRW 캐시 주기 갱신 task
+    // ---------------------------------------------------------------
+    let cancel_usdt_krw = cancel_token.clone();
+    let usdt_cache_for_task = Arc::clone(&usdt_krw_cache);
+    let usdt_krw_task = tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(30));
+        loop {
+            tokio::select! {
+                _ = cancel_usdt_krw.cancelled() => break,
+                _ = interval.tick() => {
+                    match upbit_for_usdt_krw.get_ticker(&["KRW-USDT"]).await {
+                        Ok(tickers) => {
+                            if let Some(ticker) = tickers.first()
+                                && let Some(price_f64) = ticker.trade_price.to_f64()
+                            {
+                                usdt_cache_for_task.update(price_f64);
+                            }
+                        }
+                        Err(e) => {
+                            warn!(error = %e, "USDT/KRW 주기 갱신 실패");
+                        }
+                    }
+                }
+            }
+        }
+    });
+
+    // ---------------------------------------------------------------
+    // 11-1. Prometheus /metrics exporter (선택)
+    // ---------------------------------------------------------------
+    // 인스턴스가 하나면 기존 경로(/status/*), 여럿이면 /status/<인스턴스>/*
+    let mount_status = |server: HttpServer| {
+        instances
+            .iter()
+            .fold(server, |server, (_, instance)| match instances.len() {
+                1 => instance.status_board.mount(server),
+                _ => instance
+                    .status_board
+                    .mount_at(server, &format!("/status/{}", instance.name)),
+            })
+    };
+
+    if config.metrics.is_enabled() {
+        for (_, instance) in &instances {
+            register_balance_tracker(&instance.name, &instance.balance_tracker);
+            register_risk_manager(&instance.name, &instance.risk_manager);
+        }
+        let writer_stats = db_writer.stats();
+        arb_poc::metrics::global().register_collector("db_writer", move |s| {
+            let processed = writer_stats.processed_count.load(Ordering::Relaxed);
+            let overflow = writer_stats.overflow_count.load(Ordering::Relaxed);
+            let failed = writer_stats.final_failure_count.load(Ordering::Relaxed);
+            let spilled = writer_stats.spilled_count.load(Ordering::Relaxed);
+            let replayed = writer_stats.replayed_count.load(Ordering::Relaxed);
+            const HELP: &str = "DbWriter 요청 처리 결과 누적 수";
+            s.counter(
+                "arb_db_writer_requests_total",
+                HELP,
+                &[("result", "processed")],
+                processed,
+            );
+            s.counter(
+                "arb_db_writer_requests_total",
+                HELP,
+                &[("result", "overflow")],
+                overflow,
+            );
+            s.counter(
+                "arb_db_writer_requests_total",
+                HELP,
+                &[("result", "final_failure")],
+                failed,
+            );
+            s.counter(
+                "arb_db_writer_requests_total",
+                HELP,
+                &[("result", "spilled")],
+                spilled,
+            );
+            s.counter(
+                "arb_db_writer_requests_total",
+                HELP,
+                &[("result", "replayed")],
+                replayed,
+            );
+            s.gauge(
+                "arb_db_writer_spill_pending",
+                "DbWriter spill journal 재생 대기 엔트리 수",
+                &[],
+                writer_stats.spill_pending.load(Ordering::Relaxed) as f64,
+            );
+        });
+
+        let mut server = HttpServer::bind(&config.metrics.listen_addr)
+            .await
+            .map_err(|e| format!("메트릭 서버 시작 실패: {e}"))?
+            .metrics(arb_poc::metrics::global());
+        // 같은 주소면 상태 API를 같은 서버에 붙임
+        if config.status.is_enabled() && config.status.listen_addr == config.metrics.listen_addr {
+            server = mount_status(server);
+            info!("상태 API를 /metrics 서버에 함께 노출");
+        }
+        info!(addr = ?server.local_addr().ok(), "Prometheus /metrics 엔드포인트 활성화");
+        let cancel_metrics = cancel_token.clone();
+        tokio::spawn(server.run(cancel_metrics.cancelled_owned()));
+    }
+
+    // ---------------------------------------------------------------
+    // 11-2. 읽기 전용 상태 API (선택)
+    // ---------------------------------------------------------------
+    if config.status.is_enabled()
+        && !(config.metrics.is_enabled() && config.status.listen_addr == config.metrics.listen_addr)
+    {
+        let server = HttpServer::bind(&config.status.listen_addr)
+            .await
+            .map_err(|e| format!("상태 API 서버 시작 실패: {e}"))?;
+        let server = mount_status(server);
+        info!(addr = ?server.local_addr().ok(), "상태 API 엔드포인트 활성화");
+        let cancel_status = cancel_token.clone();
+        tokio::spawn(server.run(cancel_status.cancelled_owned()));
+    }
+
+    // ---------------------------------------------------------------
+    // 11-4. API 키 로테이션 (SIGHUP)
+    // ---------------------------------------------------------------
+    // 복제본은 자격 증명 슬롯을 공유하므로 여기서 교체하면 executor/monitor에도 반영됨
+    #[cfg(unix)]
+    tokio::spawn(watch_credential_rotation(
+        accounts
+            .into_iter()
+            .map(|(name, clients)| (name, clients.profile, clients.upbit, clients.bybit))
+            .collect(),
+        cancel_token.clone(),
+    ));
+    #[cfg(not(unix))]
+    drop(accounts);
+
+    // ---------------------------------------------------------------
+    // 12. 인스턴스 동시 실행
+    // ---------------------------------------------------------------
+    // 인스턴스 하나가 실패해도 나머지는 계속 실행합니다.
+    let handles: Vec<(String, JoinHandle<bool>)> = instances
+        .into_iter()
+        .map(|(span, instance)| {
+            let name = instance.name.clone();
+            (name, tokio::spawn(run_instance(instance).instrument(span)))
+        })
+        .collect();
+
+    let mut failed_instances = Vec::new();
+    for (name, handle) in handles {
+        match handle.await {
+            Ok(true) => {}
+            Ok(false) => failed_instances.push(name),
+            Err(e) => {
+                error!(instance = %name, error = %e, "인스턴스 task 비정상 종료");
+                failed_instances.push(name);
+            }
+        }
+    }
+    cancel_token.cancel();
+
+    // USDT/KRW 주기 갱신 task 종료 대기
+    match tokio::time::timeout(Duration::from_secs(10), usdt_krw_task).await {
+        Ok(Ok(())) => info!("usdt_krw refresh task 정상 종료"),
+        Ok(Err(e)) => warn!(error = %e, "usdt_krw refresh task 종료 에러"),
+        Err(_) => warn!("usdt_krw refresh task 종료 타임아웃 (10초)"),
+    }
+
     info!("DB writer flush/shutdown 대기...");
+    drop(shared);
     match db_writer
         .shutdown_with_timeouts(Duration::from_secs(10), Duration::from_secs(30))
         .await
@@ -804,11 +1588,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Err(e) => warn!(error = e.as_str(), "DB writer graceful shutdown 실패"),
     }
 
-    info!(
-        session_id = session_id,
-        status = session_status,
-        "=== 라이브 트레이딩 종료 ==="
-    );
+    info!("=== 라이브 트레이딩 종료 ===");
 
-    Ok(())
+    if failed_instances.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("strategy instance(s) failed: {failed_instances:?}").into())
+    }
 }